@@ -17,7 +17,7 @@
 extern crate criterion;
 
 use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
-use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
+use snarkvm_curves::{bls12_377::Fr as Bls12_377_Fr, bls12_381::Fr as Bls12_381_Fr};
 use snarkvm_fields::PrimeField;
 
 use criterion::{criterion_group, criterion_main, Bencher, BenchmarkId, Criterion};
@@ -26,7 +26,7 @@ use std::cmp::min;
 
 /// Degree bounds to benchmark on
 /// e.g. degree bound of 2^{15}, means we do an FFT for a degree (2^{15} - 1) polynomial
-const BENCHMARK_MIN_DEGREE: usize = 1 << 15;
+const BENCHMARK_MIN_DEGREE: usize = 1 << 10;
 const BENCHMARK_MAX_DEGREE: usize = 1 << 22;
 const BENCHMARK_LOG_INTERVAL_DEGREE: usize = 1;
 
@@ -122,5 +122,9 @@ fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
-criterion_group!(benches, bench_bls12_377);
+fn bench_bls12_381(c: &mut Criterion) {
+    fft_benches::<Bls12_381_Fr>(c, "BLS12-381 - radix-2");
+}
+
+criterion_group!(benches, bench_bls12_377, bench_bls12_381);
 criterion_main!(benches);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use criterion::Criterion;
+use criterion::{BenchmarkId, Criterion};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use snarkvm_algorithms::msm::*;
@@ -45,10 +45,35 @@ fn variable_base(c: &mut Criterion) {
     });
 }
 
+fn variable_base_sizes(c: &mut Criterion) {
+    const MIN_LOG_SAMPLES: usize = 10;
+    const MAX_LOG_SAMPLES: usize = 20;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..1 << MAX_LOG_SAMPLES)
+        .map(|_| Fr::rand(&mut rng).to_repr())
+        .collect::<Vec<_>>();
+    let g = (0..1 << MAX_LOG_SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("MSM Variable Base - sizes");
+    for log_samples in (MIN_LOG_SAMPLES..=MAX_LOG_SAMPLES).step_by(2) {
+        let samples = 1 << log_samples;
+        group.bench_with_input(BenchmarkId::from_parameter(samples), &samples, |b, &samples| {
+            b.iter(|| {
+                VariableBaseMSM::multi_scalar_mul(&g[..samples], &v[..samples]);
+            })
+        });
+    }
+    group.finish();
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base
+    targets = variable_base, variable_base_sizes
 }
 
 criterion_main!(variable_base_group);
@@ -17,7 +17,7 @@
 use crate::{crh::BHPCRH, hash_to_curve::hash_to_curve, CommitmentError, CommitmentScheme, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{BitIteratorLE, FromBytes, ToBytes};
+use snarkvm_utilities::{vec_with_bounded_capacity, BitIteratorLE, FromBytes, ToBytes};
 
 use std::{
     fmt::Debug,
@@ -128,7 +128,7 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Fro
         let bhp = BHPCRH::read_le(&mut reader)?;
 
         let random_base_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut random_base = Vec::with_capacity(random_base_len as usize);
+        let mut random_base = vec_with_bounded_capacity(random_base_len as usize);
         for _ in 0..random_base_len {
             let g: G = FromBytes::read_le(&mut reader)?;
             random_base.push(g);
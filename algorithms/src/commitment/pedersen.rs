@@ -17,7 +17,7 @@
 use crate::{crh::PedersenCRH, hash_to_curve::hash_to_curve, CommitmentError, CommitmentScheme, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{BitIteratorLE, FromBytes, ToBytes};
+use snarkvm_utilities::{vec_with_bounded_capacity, BitIteratorLE, FromBytes, ToBytes};
 
 use std::io::{Read, Result as IoResult, Write};
 
@@ -116,10 +116,10 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Fro
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_bases: u32 = FromBytes::read_le(&mut reader)?;
-        let mut bases = Vec::with_capacity(num_bases as usize);
+        let mut bases = vec_with_bounded_capacity(num_bases as usize);
         for _ in 0..num_bases {
             let base_len: u32 = FromBytes::read_le(&mut reader)?;
-            let mut base = Vec::with_capacity(base_len as usize);
+            let mut base = vec_with_bounded_capacity(base_len as usize);
 
             for _ in 0..base_len {
                 let g: G = FromBytes::read_le(&mut reader)?;
@@ -129,7 +129,7 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Fro
         }
 
         let random_base_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut random_base = Vec::with_capacity(random_base_len as usize);
+        let mut random_base = vec_with_bounded_capacity(random_base_len as usize);
         for _ in 0..random_base_len {
             let g: G = FromBytes::read_le(&mut reader)?;
             random_base.push(g);
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{hash_to_curve::hash_to_curve, CommitmentError, CommitmentScheme};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{vec_with_bounded_capacity, BitIteratorLE, FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// An additively homomorphic Pedersen commitment `value * G + randomness * H`,
+/// where `G` and `H` are independent generators.
+///
+/// Commitments to `(v1, r1)` and `(v2, r2)` add up to a commitment to `(v1 + v2, r1 + r2)`,
+/// which allows value balances to be checked on commitments, and commitments to be rerandomized.
+/// The message of `commit` is interpreted as a little-endian integer.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PedersenHomomorphicCommitment<G: ProjectiveCurve> {
+    /// The powers of two of the value generator `G`.
+    pub value_base: Vec<G>,
+    /// The powers of two of the randomness generator `H`.
+    pub random_base: Vec<G>,
+}
+
+impl<G: ProjectiveCurve> PedersenHomomorphicCommitment<G> {
+    /// Returns the commitment to the given value with the given randomness.
+    pub fn commit_value(&self, value: &G::ScalarField, randomness: &G::ScalarField) -> G::Affine {
+        let mut output = G::zero();
+        Self::scalar_multiplication(&mut output, &self.value_base, value);
+        Self::scalar_multiplication(&mut output, &self.random_base, randomness);
+        output.into_affine()
+    }
+
+    /// Returns `true` if the given commitment opens to the given value and randomness.
+    pub fn open(&self, commitment: &G::Affine, value: &G::ScalarField, randomness: &G::ScalarField) -> bool {
+        self.commit_value(value, randomness) == *commitment
+    }
+
+    /// Returns the sum of the given commitments, which commits to the sum of their values and randomness.
+    pub fn add_commitments(&self, first: &G::Affine, second: &G::Affine) -> G::Affine {
+        (first.into_projective() + second.into_projective()).into_affine()
+    }
+
+    /// Returns the difference of the given commitments, which commits to the difference of their values and randomness.
+    pub fn sub_commitments(&self, first: &G::Affine, second: &G::Affine) -> G::Affine {
+        (first.into_projective() - second.into_projective()).into_affine()
+    }
+
+    /// Returns a commitment to the same value, with randomness increased by the given randomness.
+    pub fn rerandomize(&self, commitment: &G::Affine, randomness: &G::ScalarField) -> G::Affine {
+        let mut output = commitment.into_projective();
+        Self::scalar_multiplication(&mut output, &self.random_base, randomness);
+        output.into_affine()
+    }
+
+    /// Adds `scalar * base` to the given output, using the given powers of two of the base.
+    fn scalar_multiplication(output: &mut G, base_powers: &[G], scalar: &G::ScalarField) {
+        for (bit, power) in BitIteratorLE::new(scalar.to_repr()).zip(base_powers) {
+            if bit {
+                *output += power;
+            }
+        }
+    }
+
+    /// Returns the powers of two of a generator hashed from the given message.
+    fn setup_base(message: &str) -> Vec<G> {
+        let (generator, _, _) = hash_to_curve::<G::Affine>(message);
+        let mut base = generator.into_projective();
+        let mut powers = Vec::with_capacity(G::ScalarField::size_in_bits());
+        for _ in 0..G::ScalarField::size_in_bits() {
+            powers.push(base);
+            base.double_in_place();
+        }
+        powers
+    }
+}
+
+impl<G: ProjectiveCurve> CommitmentScheme for PedersenHomomorphicCommitment<G> {
+    type Output = G::Affine;
+    type Parameters = (Vec<G>, Vec<G>);
+    type Randomness = G::ScalarField;
+
+    fn setup(message: &str) -> Self {
+        Self {
+            value_base: Self::setup_base(&format!("{} for value base", message)),
+            random_base: Self::setup_base(&format!("{} for random base", message)),
+        }
+    }
+
+    fn commit(&self, input: &[u8], randomness: &Self::Randomness) -> Result<Self::Output, CommitmentError> {
+        // If the input does not fit in the scalar field, return an error.
+        if input.len() * 8 > self.value_base.len() {
+            return Err(CommitmentError::Message(format!(
+                "incorrect input length {} for a homomorphic commitment of {} bits",
+                input.len(),
+                self.value_base.len()
+            )));
+        }
+
+        // Compute g^v.
+        let mut output = G::zero();
+        let input_bits = input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1));
+        for (bit, power) in input_bits.zip(&self.value_base) {
+            if bit {
+                output += power;
+            }
+        }
+
+        // Compute h^r.
+        Self::scalar_multiplication(&mut output, &self.random_base, randomness);
+
+        Ok(output.into_affine())
+    }
+
+    fn parameters(&self) -> Self::Parameters {
+        (self.value_base.clone(), self.random_base.clone())
+    }
+}
+
+impl<G: ProjectiveCurve> From<(Vec<G>, Vec<G>)> for PedersenHomomorphicCommitment<G> {
+    fn from((value_base, random_base): (Vec<G>, Vec<G>)) -> Self {
+        Self {
+            value_base,
+            random_base,
+        }
+    }
+}
+
+impl<G: ProjectiveCurve> ToBytes for PedersenHomomorphicCommitment<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.value_base.len() as u32).write_le(&mut writer)?;
+        for g in &self.value_base {
+            g.write_le(&mut writer)?;
+        }
+
+        (self.random_base.len() as u32).write_le(&mut writer)?;
+        for g in &self.random_base {
+            g.write_le(&mut writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<G: ProjectiveCurve> FromBytes for PedersenHomomorphicCommitment<G> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let value_base_len: u32 = FromBytes::read_le(&mut reader)?;
+        let mut value_base = vec_with_bounded_capacity(value_base_len as usize);
+        for _ in 0..value_base_len {
+            let g: G = FromBytes::read_le(&mut reader)?;
+            value_base.push(g);
+        }
+
+        let random_base_len: u32 = FromBytes::read_le(&mut reader)?;
+        let mut random_base = vec_with_bounded_capacity(random_base_len as usize);
+        for _ in 0..random_base_len {
+            let g: G = FromBytes::read_le(&mut reader)?;
+            random_base.push(g);
+        }
+
+        Ok(Self {
+            value_base,
+            random_base,
+        })
+    }
+}
@@ -15,10 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    commitment::{BHPCommitment, PedersenCommitment, PedersenCompressedCommitment},
+    commitment::{BHPCommitment, PedersenCommitment, PedersenCompressedCommitment, PedersenHomomorphicCommitment},
     traits::CommitmentScheme,
 };
-use snarkvm_curves::edwards_bls12::EdwardsProjective;
+use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fr};
+use snarkvm_utilities::UniformRand;
+
+use rand::thread_rng;
 
 const PEDERSEN_NUM_WINDOWS: usize = 8;
 const PEDERSEN_WINDOW_SIZE: usize = 128;
@@ -50,3 +53,44 @@ fn pedersen_compressed_commitment_parameters_serialization() {
         PedersenCompressedCommitment<EdwardsProjective, PEDERSEN_NUM_WINDOWS, PEDERSEN_WINDOW_SIZE>,
     >();
 }
+
+#[test]
+fn pedersen_homomorphic_commitment_parameters_serialization() {
+    commitment_parameters_serialization::<PedersenHomomorphicCommitment<EdwardsProjective>>();
+}
+
+#[test]
+fn pedersen_homomorphic_commitment_test() {
+    let rng = &mut thread_rng();
+    let commitment_scheme = PedersenHomomorphicCommitment::<EdwardsProjective>::setup("homomorphic_commitment_test");
+
+    // Ensure a commitment to a byte-encoded value matches the commitment to the value.
+    let (first_value, first_randomness) = (Fr::from(1234u64), Fr::rand(rng));
+    let first = commitment_scheme
+        .commit(&1234u64.to_le_bytes(), &first_randomness)
+        .unwrap();
+    assert_eq!(first, commitment_scheme.commit_value(&first_value, &first_randomness));
+    assert!(commitment_scheme.open(&first, &first_value, &first_randomness));
+    assert!(!commitment_scheme.open(&first, &Fr::from(1235u64), &first_randomness));
+
+    // Ensure the sum and difference of commitments commit to the sum and difference of their openings.
+    let (second_value, second_randomness) = (Fr::rand(rng), Fr::rand(rng));
+    let second = commitment_scheme.commit_value(&second_value, &second_randomness);
+    assert_eq!(
+        commitment_scheme.add_commitments(&first, &second),
+        commitment_scheme.commit_value(&(first_value + second_value), &(first_randomness + second_randomness))
+    );
+    assert_eq!(
+        commitment_scheme.sub_commitments(&first, &second),
+        commitment_scheme.commit_value(&(first_value - second_value), &(first_randomness - second_randomness))
+    );
+
+    // Ensure a rerandomized commitment opens to the same value with the combined randomness.
+    let delta = Fr::rand(rng);
+    let rerandomized = commitment_scheme.rerandomize(&first, &delta);
+    assert_ne!(first, rerandomized);
+    assert!(commitment_scheme.open(&rerandomized, &first_value, &(first_randomness + delta)));
+
+    // Ensure an input that does not fit in the scalar field is rejected.
+    assert!(commitment_scheme.commit(&[0u8; 32], &first_randomness).is_err());
+}
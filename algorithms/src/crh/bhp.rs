@@ -17,7 +17,7 @@
 use crate::{hash_to_curve::hash_to_curve, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{BigInteger, FromBytes, ToBytes};
+use snarkvm_utilities::{vec_with_bounded_capacity, BigInteger, FromBytes, ToBytes};
 
 use once_cell::sync::OnceCell;
 use std::{
@@ -32,6 +32,8 @@ use rayon::prelude::*;
 
 // The stack is currently allocated with the following size
 // because we cannot specify them using the trait consts.
+// A window size above `MAX_WINDOW_SIZE` is a compile error, while a number of windows
+// above `MAX_NUM_WINDOWS` falls back to a buffer on the heap.
 const MAX_WINDOW_SIZE: usize = 256;
 const MAX_NUM_WINDOWS: usize = 2048;
 
@@ -51,6 +53,7 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
     type Parameters = Arc<Vec<Vec<G>>>;
 
     fn setup(message: &str) -> Self {
+        let () = Self::CHECK_WINDOW_SIZE;
         fn calculate_num_chunks_in_segment<F: PrimeField>() -> usize {
             let upper_limit = F::modulus_minus_one_div_two();
             let mut c = 0;
@@ -99,6 +102,21 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
 }
 
 impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Fails to compile when referenced by an instantiation with a window size above `MAX_WINDOW_SIZE`.
+    const CHECK_WINDOW_SIZE: () = assert!(
+        WINDOW_SIZE <= MAX_WINDOW_SIZE,
+        "BHP CRH window size exceeds MAX_WINDOW_SIZE"
+    );
+
+    /// The number of bits in the padded input, which is a multiple of `BOWE_HOPWOOD_CHUNK_SIZE`.
+    const PADDED_INPUT_BITS: usize = {
+        let num_bits = WINDOW_SIZE * NUM_WINDOWS;
+        match num_bits % BOWE_HOPWOOD_CHUNK_SIZE {
+            0 => num_bits,
+            remainder => num_bits + BOWE_HOPWOOD_CHUNK_SIZE - remainder,
+        }
+    };
+
     pub fn create_generators(message: &str) -> Vec<Vec<G>> {
         let mut generators = Vec::with_capacity(NUM_WINDOWS);
         for index in 0..NUM_WINDOWS {
@@ -155,51 +173,55 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHP
         input: impl Iterator<Item = S>,
         num_bits: usize,
     ) -> Result<G, CRHError> {
+        let () = Self::CHECK_WINDOW_SIZE;
+
         if num_bits > WINDOW_SIZE * NUM_WINDOWS {
             return Err(CRHError::IncorrectInputLength(num_bits, WINDOW_SIZE, NUM_WINDOWS));
         }
-        debug_assert!(WINDOW_SIZE <= MAX_WINDOW_SIZE);
-        debug_assert!(NUM_WINDOWS <= MAX_NUM_WINDOWS);
-
-        // overzealous but stack allocation
-        let mut buf_slice = [false; MAX_WINDOW_SIZE * MAX_NUM_WINDOWS + BOWE_HOPWOOD_CHUNK_SIZE + 1];
-        buf_slice[..num_bits]
-            .iter_mut()
-            .zip(input)
-            .for_each(|(b, i)| *b = *i.borrow());
-
-        let mut bit_len = WINDOW_SIZE * NUM_WINDOWS;
-        if bit_len % BOWE_HOPWOOD_CHUNK_SIZE != 0 {
-            bit_len += BOWE_HOPWOOD_CHUNK_SIZE - (bit_len % BOWE_HOPWOOD_CHUNK_SIZE);
-        }
 
-        debug_assert_eq!(bit_len % BOWE_HOPWOOD_CHUNK_SIZE, 0);
-
-        debug_assert_eq!(
-            self.bases.len(),
-            NUM_WINDOWS,
-            "Incorrect number of windows ({:?}) for BHP of {:?}x{:?}x{}",
-            self.bases.len(),
-            WINDOW_SIZE,
-            NUM_WINDOWS,
-            BOWE_HOPWOOD_CHUNK_SIZE,
-        );
-        for bases in self.bases.iter() {
-            debug_assert_eq!(bases.len(), WINDOW_SIZE);
+        if self.bases.len() != NUM_WINDOWS || self.bases.iter().any(|bases| bases.len() != WINDOW_SIZE) {
+            return Err(CRHError::IncorrectParameterSize(
+                self.bases.first().map_or(0, |bases| bases.len()),
+                self.bases.len(),
+                WINDOW_SIZE,
+                NUM_WINDOWS,
+            ));
         }
-        let base_lookup = self.base_lookup(&self.bases);
-        debug_assert_eq!(base_lookup.len(), NUM_WINDOWS);
-        for bases in base_lookup.iter() {
-            debug_assert_eq!(bases.len(), WINDOW_SIZE);
+
+        // Copy the input into a zero-padded buffer, on the stack if the parameters fit.
+        match NUM_WINDOWS <= MAX_NUM_WINDOWS {
+            true => {
+                // overzealous but stack allocation
+                let mut buf_slice = [false; MAX_WINDOW_SIZE * MAX_NUM_WINDOWS + BOWE_HOPWOOD_CHUNK_SIZE + 1];
+                buf_slice[..num_bits]
+                    .iter_mut()
+                    .zip(input)
+                    .for_each(|(b, i)| *b = *i.borrow());
+                Ok(self.hash_padded_bits(&buf_slice[..Self::PADDED_INPUT_BITS]))
+            }
+            false => {
+                let mut buf_vec = vec![false; Self::PADDED_INPUT_BITS];
+                buf_vec[..num_bits]
+                    .iter_mut()
+                    .zip(input)
+                    .for_each(|(b, i)| *b = *i.borrow());
+                Ok(self.hash_padded_bits(&buf_vec))
+            }
         }
+    }
+
+    /// Returns the hash of the given bits, which are padded to `PADDED_INPUT_BITS`.
+    fn hash_padded_bits(&self, bits: &[bool]) -> G {
+        debug_assert_eq!(bits.len(), Self::PADDED_INPUT_BITS);
         debug_assert_eq!(BOWE_HOPWOOD_CHUNK_SIZE, 3);
 
+        let base_lookup = self.base_lookup(&self.bases);
+
         // Compute sum of h_i^{sum of
         // (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
         // for all i. Described in section 5.4.1.7 in the Zcash protocol
         // specification.
-        let output = buf_slice[..bit_len]
-            .chunks(WINDOW_SIZE * BOWE_HOPWOOD_CHUNK_SIZE)
+        bits.chunks(WINDOW_SIZE * BOWE_HOPWOOD_CHUNK_SIZE)
             .zip(base_lookup)
             .map(|(segment_bits, segment_generators)| {
                 segment_bits
@@ -211,9 +233,7 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHP
                     })
                     .fold(G::zero(), |a, b| a + b)
             })
-            .fold(G::zero(), |a, b| a + b);
-
-        Ok(output)
+            .fold(G::zero(), |a, b| a + b)
     }
 }
 
@@ -249,11 +269,11 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Fro
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_bases: u32 = FromBytes::read_le(&mut reader)?;
-        let mut bases = Vec::with_capacity(num_bases as usize);
+        let mut bases = vec_with_bounded_capacity(num_bases as usize);
 
         for _ in 0..num_bases {
             let base_len: u32 = FromBytes::read_le(&mut reader)?;
-            let mut base = Vec::with_capacity(base_len as usize);
+            let mut base = vec_with_bounded_capacity(base_len as usize);
 
             for _ in 0..base_len {
                 let g: G = FromBytes::read_le(&mut reader)?;
@@ -297,4 +317,32 @@ mod tests {
             "2591648422993904809826711498838675948697848925001720514073745852367402669969"
         );
     }
+
+    #[test]
+    fn test_bhp_heap_fallback() {
+        const LARGE_NUM_WINDOWS: usize = MAX_NUM_WINDOWS + 1;
+        let large_crh = <BHPCRH<EdwardsProjective, LARGE_NUM_WINDOWS, 1> as CRH>::setup("test_bhp_heap_fallback");
+        let small_crh = <BHPCRH<EdwardsProjective, 8, 1> as CRH>::setup("test_bhp_heap_fallback");
+
+        // The windows of both hashes share their generators, and each zero chunk adds its generator,
+        // so the hashes differ by the generators of the chunks beyond the input of the small hash.
+        let input = [true, false, true, true, false, true, false, true];
+        let small_output = small_crh.hash_bits_inner(input.iter(), input.len()).unwrap();
+        let large_output = large_crh.hash_bits_inner(input.iter(), input.len()).unwrap();
+
+        let num_small_chunks = BHPCRH::<EdwardsProjective, 8, 1>::PADDED_INPUT_BITS / BOWE_HOPWOOD_CHUNK_SIZE;
+        let num_large_chunks =
+            BHPCRH::<EdwardsProjective, LARGE_NUM_WINDOWS, 1>::PADDED_INPUT_BITS / BOWE_HOPWOOD_CHUNK_SIZE;
+        let expected_output = large_crh.bases[num_small_chunks..num_large_chunks]
+            .iter()
+            .fold(small_output, |output, bases| output + bases[0]);
+        assert_eq!(expected_output, large_output);
+    }
+
+    #[test]
+    fn test_bhp_incorrect_parameter_size() {
+        let crh = <BHPCRH<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE> as CRH>::setup("test_bowe_pedersen");
+        let candidate_crh = BHPCRH::<EdwardsProjective, { NUM_WINDOWS * 2 }, WINDOW_SIZE>::from(crh.bases.clone());
+        assert!(candidate_crh.hash(&[127u8; 32]).is_err());
+    }
 }
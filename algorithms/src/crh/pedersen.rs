@@ -17,7 +17,7 @@
 use crate::{hash_to_curve::hash_to_curve, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
-use snarkvm_utilities::{FromBytes, ToBytes};
+use snarkvm_utilities::{vec_with_bounded_capacity, FromBytes, ToBytes};
 
 use std::{
     borrow::Cow,
@@ -131,11 +131,11 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Fro
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_bases: u32 = FromBytes::read_le(&mut reader)?;
-        let mut bases = Vec::with_capacity(num_bases as usize);
+        let mut bases = vec_with_bounded_capacity(num_bases as usize);
 
         for _ in 0..num_bases {
             let base_len: u32 = FromBytes::read_le(&mut reader)?;
-            let mut base = Vec::with_capacity(base_len as usize);
+            let mut base = vec_with_bounded_capacity(base_len as usize);
 
             for _ in 0..base_len {
                 let g: G = FromBytes::read_le(&mut reader)?;
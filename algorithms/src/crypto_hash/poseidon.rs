@@ -29,7 +29,7 @@ use snarkvm_fields::{
     PoseidonDefaultParameters,
     PrimeField,
 };
-use snarkvm_utilities::{FromBytes, ToBytes};
+use snarkvm_utilities::{vec_with_bounded_capacity, FromBytes, ToBytes};
 
 use smallvec::SmallVec;
 use std::{
@@ -111,10 +111,10 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> FromBytes for Pose
         let alpha: u64 = FromBytes::read_le(&mut reader)?;
 
         let ark_length: u32 = FromBytes::read_le(&mut reader)?;
-        let mut ark = Vec::with_capacity(ark_length as usize);
+        let mut ark = vec_with_bounded_capacity(ark_length as usize);
         for _ in 0..ark_length {
             let num_fields: u32 = FromBytes::read_le(&mut reader)?;
-            let mut fields = Vec::with_capacity(num_fields as usize);
+            let mut fields = vec_with_bounded_capacity(num_fields as usize);
 
             for _ in 0..num_fields {
                 let field: F = FromBytes::read_le(&mut reader)?;
@@ -124,10 +124,10 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> FromBytes for Pose
         }
 
         let mds_length: u32 = FromBytes::read_le(&mut reader)?;
-        let mut mds = Vec::with_capacity(mds_length as usize);
+        let mut mds = vec_with_bounded_capacity(mds_length as usize);
         for _ in 0..mds_length {
             let num_fields: u32 = FromBytes::read_le(&mut reader)?;
-            let mut fields = Vec::with_capacity(num_fields as usize);
+            let mut fields = vec_with_bounded_capacity(num_fields as usize);
 
             for _ in 0..num_fields {
                 let field: F = FromBytes::read_le(&mut reader)?;
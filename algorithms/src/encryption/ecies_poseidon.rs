@@ -153,6 +153,25 @@ where
         // Sample randomness.
         let randomness: Self::ScalarRandomness = UniformRand::rand(rng);
 
+        let (ciphertext_randomizer, symmetric_key) =
+            self.generate_asymmetric_key_from_randomness(public_key, randomness);
+
+        (randomness, ciphertext_randomizer, symmetric_key)
+    }
+
+    ///
+    /// Given the public key and randomness, returns the following:
+    ///
+    /// ```ignore
+    ///       ciphertext_randomizer := G^r
+    ///               symmetric_key := public_key^r == G^ar
+    /// ```
+    ///
+    fn generate_asymmetric_key_from_randomness(
+        &self,
+        public_key: &Self::PublicKey,
+        randomness: Self::ScalarRandomness,
+    ) -> (Self::CiphertextRandomizer, Self::SymmetricKey) {
         // Compute the randomizer := G^r
         let ciphertext_randomizer = self
             .generator
@@ -164,12 +183,10 @@ where
 
         let mut batch = [ciphertext_randomizer, symmetric_key];
         Projective::<TE>::batch_normalization(&mut batch);
-        let (ciphertext_randomizer, symmetric_key) = (
+        (
             batch[0].into_affine().to_x_coordinate(),
             batch[1].into_affine().to_x_coordinate(),
-        );
-
-        (randomness, ciphertext_randomizer, symmetric_key)
+        )
     }
 
     ///
@@ -26,9 +26,10 @@
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
 
-use crate::fft::{DomainCoeff, SparsePolynomial};
-use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
-use snarkvm_utilities::{errors::SerializationError, serialize::*};
+use crate::fft::{DensePolynomial, DomainCoeff, SparsePolynomial};
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field, PrimeField};
+use snarkvm_utilities::{errors::SerializationError, serialize::*, BitIteratorBE, ScratchVec};
 
 use rand::Rng;
 use std::fmt;
@@ -159,6 +160,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        let _span = debug_span!("FFT", size = self.size).entered();
         coeffs.resize(self.size(), T::zero());
         self.in_order_fft_in_place(&mut *coeffs);
     }
@@ -173,6 +175,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an IFFT, modifying the vector in place.
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let _span = debug_span!("IFFT", size = self.size).entered();
         evals.resize(self.size(), T::zero());
         self.in_order_ifft_in_place(&mut *evals);
     }
@@ -200,6 +203,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let _span = debug_span!("Coset IFFT", size = self.size).entered();
         evals.resize(self.size(), T::zero());
         self.in_order_coset_ifft_in_place(&mut *evals);
     }
@@ -277,6 +281,57 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Returns the polynomial of degree less than `points.len()` that passes through the given points,
+    /// or `None` if two of the points have the same x-coordinate.
+    ///
+    /// Unlike `ifft`, the x-coordinates need not be the elements of a domain. The polynomial is computed
+    /// in barycentric form in `O(n^2)` time, with one batch inversion for the barycentric weights.
+    pub fn interpolate_from_arbitrary_points(points: &[(F, F)]) -> Option<DensePolynomial<F>> {
+        // Compute the vanishing polynomial of the x-coordinates, `z(X) = prod_i (X - x_i)`.
+        let mut vanishing_polynomial = vec![F::one()];
+        for (x, _) in points {
+            vanishing_polynomial.push(F::zero());
+            for j in (1..vanishing_polynomial.len()).rev() {
+                vanishing_polynomial[j] = vanishing_polynomial[j - 1] - *x * vanishing_polynomial[j];
+            }
+            vanishing_polynomial[0] = -(*x * vanishing_polynomial[0]);
+        }
+
+        // Compute the barycentric weights, `w_i = 1 / prod_{j != i} (x_i - x_j)`.
+        let mut weights: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(i, (x_i, _))| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| i != *j)
+                    .map(|(_, (x_j, _))| *x_i - x_j)
+                    .product()
+            })
+            .collect();
+        if weights.iter().any(|weight| weight.is_zero()) {
+            return None;
+        }
+        batch_inversion(&mut weights);
+
+        // Sum `y_i * w_i * z(X) / (X - x_i)` over the points, dividing `z(X)` by `X - x_i` with synthetic division.
+        let mut coeffs = vec![F::zero(); points.len()];
+        for ((x, y), weight) in points.iter().zip(weights) {
+            if y.is_zero() {
+                continue;
+            }
+            let scalar = *y * weight;
+            let mut quotient_coeff = F::zero();
+            for k in (0..points.len()).rev() {
+                quotient_coeff = vanishing_polynomial[k + 1] + *x * quotient_coeff;
+                coeffs[k] += scalar * quotient_coeff;
+            }
+        }
+
+        Some(DensePolynomial::from_coefficients_vec(coeffs))
+    }
+
     /// Return the sparse vanishing polynomial.
     pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
         let coeffs = vec![(0, -F::one()), (self.size(), F::one())];
@@ -298,6 +353,60 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Return an iterator over the elements of the coset `offset * H` of the domain `H`.
+    pub fn coset_elements(&self, offset: F) -> Elements<F> {
+        Elements {
+            cur_elem: offset,
+            cur_pow: 0,
+            domain: *self,
+        }
+    }
+
+    /// Returns the element of the domain at the given index, `g^index`.
+    pub fn element(&self, index: usize) -> F {
+        self.group_gen.pow(&[index as u64])
+    }
+
+    /// Returns the element of the coset `offset * H` at the given index, `offset * g^index`.
+    pub fn coset_element(&self, offset: F, index: usize) -> F {
+        offset * self.element(index)
+    }
+
+    /// Return a parallel iterator over the elements of the domain, in order.
+    #[cfg(not(feature = "parallel"))]
+    pub fn par_elements(&self) -> impl Iterator<Item = F> {
+        self.elements()
+    }
+
+    /// Return a parallel iterator over the elements of the domain, in order.
+    #[cfg(feature = "parallel")]
+    pub fn par_elements(&self) -> impl ParallelIterator<Item = F> {
+        self.par_coset_elements(F::one())
+    }
+
+    /// Return a parallel iterator over the elements of the coset `offset * H` of the domain `H`, in order.
+    #[cfg(not(feature = "parallel"))]
+    pub fn par_coset_elements(&self, offset: F) -> impl Iterator<Item = F> {
+        self.coset_elements(offset)
+    }
+
+    /// Return a parallel iterator over the elements of the coset `offset * H` of the domain `H`, in order.
+    #[cfg(feature = "parallel")]
+    pub fn par_coset_elements(&self, offset: F) -> impl ParallelIterator<Item = F> {
+        let domain = *self;
+        let size = self.size();
+        let num_chunks = (size + MIN_PARALLEL_CHUNK_SIZE - 1) / MIN_PARALLEL_CHUNK_SIZE;
+
+        // Each chunk starts from its first element, and iterates over the consecutive elements.
+        (0..num_chunks).into_par_iter().flat_map_iter(move |i| {
+            let start = i * MIN_PARALLEL_CHUNK_SIZE;
+            let chunk_size = core::cmp::min(size - start, MIN_PARALLEL_CHUNK_SIZE);
+            domain
+                .coset_elements(domain.coset_element(offset, start))
+                .take(chunk_size)
+        })
+    }
+
     /// The target polynomial is the zero polynomial in our
     /// evaluation domain, so we must perform division over
     /// a coset.
@@ -451,8 +560,8 @@ impl<F: FftField> EvaluationDomain<F> {
         // recursive case:
         // 1. split log_powers in half
         let (lr_lo, lr_hi) = log_powers.split_at((1 + log_powers.len()) / 2);
-        let mut scr_lo = vec![F::default(); 1 << lr_lo.len()];
-        let mut scr_hi = vec![F::default(); 1 << lr_hi.len()];
+        let mut scr_lo = ScratchVec::from_elem(F::default(), 1 << lr_lo.len());
+        let mut scr_hi = ScratchVec::from_elem(F::default(), 1 << lr_hi.len());
         // 2. compute each half individually
         rayon::join(
             || Self::roots_of_unity_recursive(&mut scr_lo, lr_lo),
@@ -461,9 +570,9 @@ impl<F: FftField> EvaluationDomain<F> {
         // 3. recombine halves
         // At this point, out is a blank slice.
         out.par_chunks_mut(scr_lo.len())
-            .zip(&scr_hi)
+            .zip(scr_hi.par_iter())
             .for_each(|(out_chunk, scr_hi)| {
-                for (out_elem, scr_lo) in out_chunk.iter_mut().zip(&scr_lo) {
+                for (out_elem, scr_lo) in out_chunk.iter_mut().zip(scr_lo.iter()) {
                     *out_elem = *scr_hi * scr_lo;
                 }
             });
@@ -565,7 +674,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
         let compaction_max_size =
             core::cmp::min(roots_cache.len() / 2, roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION);
-        let mut compacted_roots = vec![F::default(); compaction_max_size];
+        let mut compacted_roots = ScratchVec::from_elem(F::default(), compaction_max_size);
 
         #[cfg(feature = "parallel")]
         let max_threads = rayon::current_num_threads();
@@ -606,6 +715,99 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 }
 
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Compute an FFT over group elements, whose scalar field is the field of the domain.
+    ///
+    /// The group elements are converted into affine form in a batch before each stage of the FFT,
+    /// so that each butterfly uses a scalar multiplication of an affine element, and mixed additions.
+    /// This is considerably faster than `fft` over projective elements.
+    pub fn fft_g1<G: ProjectiveCurve<ScalarField = F>>(&self, coeffs: &[G]) -> Vec<G> {
+        let _span = debug_span!("FFT over G1", size = self.size).entered();
+        let mut coeffs = coeffs.to_vec();
+        coeffs.resize(self.size(), G::zero());
+        self.group_fft_in_place(&mut coeffs, self.group_gen);
+        coeffs
+    }
+
+    /// Compute an IFFT over group elements, whose scalar field is the field of the domain.
+    ///
+    /// For the powers `{ \beta^i G }` of a structured reference string, this computes the
+    /// Lagrange basis `{ L_i(\beta) G }` of the domain.
+    pub fn ifft_g1<G: ProjectiveCurve<ScalarField = F>>(&self, evals: &[G]) -> Vec<G> {
+        let _span = debug_span!("IFFT over G1", size = self.size).entered();
+        let mut evals = evals.to_vec();
+        evals.resize(self.size(), G::zero());
+        self.group_fft_in_place(&mut evals, self.group_gen_inv);
+
+        // Divide the results by the size of the domain.
+        let size_inv = BitIteratorBE::new_without_leading_zeros(self.size_inv.to_repr()).collect::<Vec<_>>();
+        cfg_into_iter!(G::batch_normalization_into_affine(evals))
+            .map(|eval| eval.mul_bits(size_inv.iter().copied()))
+            .collect()
+    }
+
+    /// Performs an FFT with the given root of unity over group elements, which are in order.
+    fn group_fft_in_place<G: ProjectiveCurve<ScalarField = F>>(&self, xi: &mut [G], root: F) {
+        let roots = self.roots_of_unity(root);
+        derange(xi, log2(xi.len()));
+
+        #[cfg(feature = "parallel")]
+        let max_threads = rayon::current_num_threads();
+        #[cfg(not(feature = "parallel"))]
+        let max_threads = 1;
+
+        let mut gap = 1;
+        while gap < xi.len() {
+            // Convert the elements into affine form in a batch, which requires a single inversion.
+            let xi_affine = G::batch_normalization_into_affine(xi.to_vec());
+
+            // each butterfly cluster uses 2*gap positions
+            let chunk_size = 2 * gap;
+            let num_chunks = xi.len() / chunk_size;
+
+            cfg_chunks_mut!(xi, chunk_size)
+                .zip(cfg_chunks!(xi_affine, chunk_size))
+                .for_each(|(cxi, cxi_affine)| {
+                    let (lo, hi) = cxi.split_at_mut(gap);
+                    let (lo_affine, hi_affine) = cxi_affine.split_at(gap);
+
+                    if gap > MIN_GAP_SIZE_FOR_PARALLELISATION && num_chunks < max_threads {
+                        cfg_iter_mut!(lo)
+                            .zip(hi)
+                            .zip(cfg_iter!(lo_affine).zip(hi_affine))
+                            .zip(cfg_iter!(roots).step_by(num_chunks))
+                            .for_each(Self::group_butterfly);
+                    } else {
+                        lo.iter_mut()
+                            .zip(hi)
+                            .zip(lo_affine.iter().zip(hi_affine))
+                            .zip(roots.iter().step_by(num_chunks))
+                            .for_each(Self::group_butterfly);
+                    }
+                });
+
+            gap *= 2;
+        }
+    }
+
+    /// Sets `(lo, hi)` to `(lo + root * hi, lo - root * hi)`, given the affine forms of `lo` and `hi`.
+    #[inline(always)]
+    #[allow(clippy::type_complexity)]
+    fn group_butterfly<G: ProjectiveCurve<ScalarField = F>>(
+        (((lo, hi), (lo_affine, hi_affine)), root): (((&mut G, &mut G), (&G::Affine, &G::Affine)), &F),
+    ) {
+        let product = match root.is_one() {
+            true => hi_affine.into_projective(),
+            false => hi_affine.mul_bits(BitIteratorBE::new_without_leading_zeros(root.to_repr())),
+        };
+
+        *lo = product;
+        lo.add_assign_mixed(lo_affine);
+        *hi = -product;
+        hi.add_assign_mixed(lo_affine);
+    }
+}
+
 /// The minimum number of chunks at which root compaction
 /// is beneficial.
 const MIN_NUM_CHUNKS_FOR_COMPACTION: usize = 1 << 7;
@@ -715,6 +917,8 @@ mod tests {
     use snarkvm_utilities::UniformRand;
 
     use rand::{thread_rng, Rng};
+    #[cfg(feature = "parallel")]
+    use rayon::prelude::*;
 
     #[test]
     fn vanishing_polynomial_evaluation() {
@@ -761,6 +965,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn indexed_elements() {
+        for coeffs in 1..10 {
+            let size = 1 << coeffs;
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            for (i, element) in domain.elements().enumerate() {
+                assert_eq!(element, domain.element(i));
+            }
+            assert_eq!(domain.element(0), domain.element(size));
+        }
+    }
+
+    #[test]
+    fn coset_elements_contents() {
+        let offset = Fr::multiplicative_generator();
+        for coeffs in 1..10 {
+            let size = 1 << coeffs;
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            for (i, element) in domain.coset_elements(offset).enumerate() {
+                assert_eq!(element, offset * domain.group_gen.pow([i as u64]));
+                assert_eq!(element, domain.coset_element(offset, i));
+            }
+            assert_eq!(domain.size(), domain.coset_elements(offset).count());
+        }
+    }
+
+    #[test]
+    fn par_elements_contents() {
+        let offset = Fr::multiplicative_generator();
+        for coeffs in 1..12 {
+            let size = 1 << coeffs;
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+
+            let elements: Vec<Fr> = domain.par_elements().collect();
+            assert_eq!(domain.elements().collect::<Vec<_>>(), elements);
+
+            let coset_elements: Vec<Fr> = domain.par_coset_elements(offset).collect();
+            assert_eq!(domain.coset_elements(offset).collect::<Vec<_>>(), coset_elements);
+        }
+    }
+
     /// Test that lagrange interpolation for a random polynomial at a random point works.
     #[test]
     fn non_systematic_lagrange_coefficients_test() {
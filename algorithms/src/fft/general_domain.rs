@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains a `GeneralEvaluationDomain` abstraction, which
+//! selects the radix-2 `EvaluationDomain` whenever one is large enough,
+//! and otherwise falls back to a `MixedRadixEvaluationDomain`.
+
+use crate::fft::{
+    domain::Elements,
+    mixed_radix_domain::MixedRadixElements,
+    DomainCoeff,
+    EvaluationDomain,
+    MixedRadixEvaluationDomain,
+    SparsePolynomial,
+};
+use snarkvm_fields::FftField;
+
+/// Dispatches the given method call to the domain of the given variant.
+macro_rules! map {
+    ($self:expr, $method:ident $(, $argument:expr)*) => {
+        match $self {
+            Self::Radix2(domain) => domain.$method($($argument),*),
+            Self::MixedRadix(domain) => domain.$method($($argument),*),
+        }
+    };
+}
+
+/// Defines a domain over which finite field (I)FFTs can be performed, which is
+/// a radix-2 domain if the field supports one of the required size, and a
+/// mixed-radix domain otherwise.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum GeneralEvaluationDomain<F: FftField> {
+    /// A domain of size `2^k`.
+    Radix2(EvaluationDomain<F>),
+    /// A domain of size `2^k * q^s`, for the small subgroup base `q` of the field.
+    MixedRadix(MixedRadixEvaluationDomain<F>),
+}
+
+impl<F: FftField> GeneralEvaluationDomain<F> {
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// This returns a radix-2 domain if `num_coeffs` is at most `2^TWO_ADICITY`,
+    /// and a mixed-radix domain if the field defines a small subgroup that is large enough.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        match EvaluationDomain::new(num_coeffs) {
+            Some(domain) => Some(Self::Radix2(domain)),
+            None => MixedRadixEvaluationDomain::new(num_coeffs).map(Self::MixedRadix),
+        }
+    }
+
+    /// Return the size of a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        EvaluationDomain::<F>::compute_size_of_domain(num_coeffs)
+            .or_else(|| MixedRadixEvaluationDomain::<F>::compute_size_of_domain(num_coeffs))
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        map!(self, size)
+    }
+
+    /// Returns the inverse of the size in the field.
+    pub fn size_inv(&self) -> F {
+        match self {
+            Self::Radix2(domain) => domain.size_inv,
+            Self::MixedRadix(domain) => domain.size_inv,
+        }
+    }
+
+    /// Returns the generator of the subgroup.
+    pub fn group_gen(&self) -> F {
+        match self {
+            Self::Radix2(domain) => domain.group_gen,
+            Self::MixedRadix(domain) => domain.group_gen,
+        }
+    }
+
+    /// Compute an FFT.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        map!(self, fft, coeffs)
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        map!(self, fft_in_place, coeffs)
+    }
+
+    /// Compute an IFFT.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        map!(self, ifft, evals)
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        map!(self, ifft_in_place, evals)
+    }
+
+    /// Compute an FFT over a coset of the domain.
+    pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        map!(self, coset_fft, coeffs)
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        map!(self, coset_fft_in_place, coeffs)
+    }
+
+    /// Compute an IFFT over a coset of the domain.
+    pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        map!(self, coset_ifft, evals)
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        map!(self, coset_ifft_in_place, evals)
+    }
+
+    /// Evaluate all the lagrange polynomials defined by this domain at the point
+    /// `tau`.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        map!(self, evaluate_all_lagrange_coefficients, tau)
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        map!(self, vanishing_polynomial)
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        map!(self, evaluate_vanishing_polynomial, tau)
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub fn elements(&self) -> GeneralElements<F> {
+        match self {
+            Self::Radix2(domain) => GeneralElements::Radix2(domain.elements()),
+            Self::MixedRadix(domain) => GeneralElements::MixedRadix(domain.elements()),
+        }
+    }
+
+    /// Returns the element of the domain at the given index, `g^index`.
+    pub fn element(&self, index: usize) -> F {
+        map!(self, element, index)
+    }
+}
+
+impl<F: FftField> From<EvaluationDomain<F>> for GeneralEvaluationDomain<F> {
+    fn from(domain: EvaluationDomain<F>) -> Self {
+        Self::Radix2(domain)
+    }
+}
+
+impl<F: FftField> From<MixedRadixEvaluationDomain<F>> for GeneralEvaluationDomain<F> {
+    fn from(domain: MixedRadixEvaluationDomain<F>) -> Self {
+        Self::MixedRadix(domain)
+    }
+}
+
+/// An iterator over the elements of a general domain.
+pub enum GeneralElements<F: FftField> {
+    Radix2(Elements<F>),
+    MixedRadix(MixedRadixElements<F>),
+}
+
+impl<F: FftField> Iterator for GeneralElements<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        map!(self, next)
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains a `MixedRadixEvaluationDomain` abstraction for
+//! performing polynomial arithmetic over domains of size `2^k * q^s`,
+//! where `q` is the small subgroup base of the scalar field.
+//!
+//! The radix-2 `EvaluationDomain` is limited to sizes of at most
+//! `2^TWO_ADICITY`. Fields that also define a small multiplicative
+//! subgroup in their FFT parameters support larger domains, by
+//! combining radix-`q` and radix-2 butterflies in one FFT.
+
+use crate::fft::{domain::bitrev, DomainCoeff, SparsePolynomial};
+use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
+use snarkvm_utilities::{errors::SerializationError, serialize::*};
+
+use std::fmt;
+
+/// Defines a domain over which finite field (I)FFTs can be performed, whose size is
+/// `2^k * q^s` for the small subgroup base `q` of the field. Works only for fields that
+/// define a small multiplicative subgroup in their FFT parameters.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MixedRadixEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// The exponent of 2 in `self.size`.
+    pub two_adicity: u32,
+    /// The exponent of the small subgroup base in `self.size`.
+    pub q_adicity: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Inverse of the multiplicative generator of the finite field.
+    pub generator_inv: F,
+}
+
+impl<F: FftField> fmt::Debug for MixedRadixEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mixed-radix multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: FftField> MixedRadixEvaluationDomain<F> {
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, or `None` if the field does not define
+    /// a small multiplicative subgroup that is large enough.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)?;
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE? as usize;
+
+        // Compute the generator for the multiplicative subgroup.
+        // It should be the `2^two_adicity * q^q_adicity` root of unity.
+        let group_gen = F::get_root_of_unity(size)?;
+
+        // Check that it is indeed a root of unity of the size of the domain.
+        debug_assert_eq!(group_gen.pow([size as u64]), F::one());
+
+        let size_as_field_element = F::from(size as u64);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(MixedRadixEvaluationDomain {
+            size: size as u64,
+            two_adicity: F::k_adicity(2, size),
+            q_adicity: F::k_adicity(q, size),
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            generator_inv: F::multiplicative_generator().inverse()?,
+        })
+    }
+
+    /// Return the size of a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, which is the smallest `2^k * q^s` that is
+    /// at least `num_coeffs`, for `k <= TWO_ADICITY` and `s <= SMALL_SUBGROUP_BASE_ADICITY`.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE? as usize;
+        let q_adicity = F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY?;
+
+        (0..=q_adicity)
+            .filter_map(|s| {
+                let mut size = q.checked_pow(s)?;
+                let mut two_adicity = 0;
+                while size < num_coeffs {
+                    size = size.checked_mul(2)?;
+                    two_adicity += 1;
+                }
+                match two_adicity <= F::FftParameters::TWO_ADICITY {
+                    true => Some(size),
+                    false => None,
+                }
+            })
+            .min()
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Compute an FFT.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        let _span = debug_span!("Mixed-radix FFT", size = self.size).entered();
+        coeffs.resize(self.size(), T::zero());
+        self.serial_mixed_radix_fft(coeffs, self.group_gen);
+    }
+
+    /// Compute an IFFT.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let _span = debug_span!("Mixed-radix IFFT", size = self.size).entered();
+        evals.resize(self.size(), T::zero());
+        self.serial_mixed_radix_fft(evals, self.group_gen_inv);
+        evals.iter_mut().for_each(|eval| *eval *= self.size_inv);
+    }
+
+    /// Compute an FFT over a coset of the domain.
+    pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.coset_fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        Self::distribute_powers(coeffs, F::multiplicative_generator());
+        self.fft_in_place(coeffs);
+    }
+
+    /// Compute an IFFT over a coset of the domain.
+    pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.coset_ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        self.ifft_in_place(evals);
+        Self::distribute_powers(evals, self.generator_inv);
+    }
+
+    /// Multiply the `i`-th element of `coeffs` with `g^i`.
+    fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
+        let mut pow = F::one();
+        coeffs.iter_mut().for_each(|coeff| {
+            *coeff *= pow;
+            pow *= &g
+        })
+    }
+
+    /// Evaluate all the lagrange polynomials defined by this domain at the point
+    /// `tau`.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        let size = self.size();
+        let t_size = tau.pow(&[self.size]);
+        let one = F::one();
+        if t_size.is_one() {
+            let mut u = vec![F::zero(); size];
+            let mut omega_i = one;
+            for x in u.iter_mut() {
+                if omega_i == tau {
+                    *x = one;
+                    break;
+                }
+                omega_i *= &self.group_gen;
+            }
+            u
+        } else {
+            let mut l = (t_size - one) * self.size_inv;
+            let mut r = one;
+            let mut u = vec![F::zero(); size];
+            let mut ls = vec![F::zero(); size];
+            for i in 0..size {
+                u[i] = tau - r;
+                ls[i] = l;
+                l *= &self.group_gen;
+                r *= &self.group_gen;
+            }
+
+            batch_inversion(u.as_mut_slice());
+            u.iter_mut().zip(ls).for_each(|(tau_minus_r, l)| {
+                *tau_minus_r = l * *tau_minus_r;
+            });
+            u
+        }
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        let coeffs = vec![(0, -F::one()), (self.size(), F::one())];
+        SparsePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau.
+    /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size - 1`.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        tau.pow(&[self.size]) - F::one()
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub fn elements(&self) -> MixedRadixElements<F> {
+        MixedRadixElements {
+            cur_elem: F::one(),
+            cur_pow: 0,
+            domain: *self,
+        }
+    }
+
+    /// Returns the element of the domain at the given index, `g^index`.
+    pub fn element(&self, index: usize) -> F {
+        self.group_gen.pow(&[index as u64])
+    }
+
+    ///
+    /// Computes the FFT of `a` over the subgroup generated by `omega`, whose order is the size of the domain.
+    ///
+    /// The coefficients are permuted into the mixed-radix digit-reversed order, then combined with
+    /// `q_adicity` rounds of radix-`q` butterflies, followed by `two_adicity` rounds of radix-2 butterflies.
+    ///
+    fn serial_mixed_radix_fft<T: DomainCoeff<F>>(&self, a: &mut [T], omega: F) {
+        let n = a.len();
+        assert_eq!(n as u64, self.size);
+
+        let mut m = 1;
+        if self.q_adicity > 0 {
+            let q = F::FftParameters::SMALL_SUBGROUP_BASE.expect("Missing the small subgroup base") as usize;
+
+            // Swap the coefficients in place, by following the cycles of the permutation.
+            let mut seen = vec![false; n];
+            for k in 0..n {
+                let mut i = k;
+                let mut a_i = a[i];
+                while !seen[i] {
+                    let dest = mixed_radix_fft_permute(self.two_adicity, self.q_adicity, q, n, i);
+                    let a_dest = a[dest];
+                    a[dest] = a_i;
+                    seen[i] = true;
+                    a_i = a_dest;
+                    i = dest;
+                }
+            }
+
+            // Compute the q-th roots of unity, `omega_q^i = omega^(i * n / q)`.
+            let omega_q = omega.pow([(n / q) as u64]);
+            let mut qth_roots = Vec::with_capacity(q);
+            qth_roots.push(F::one());
+            for i in 1..q {
+                qth_roots.push(qth_roots[i - 1] * omega_q);
+            }
+
+            let mut terms = vec![T::zero(); q - 1];
+            for _ in 0..self.q_adicity {
+                // w_m is the (q * m)-th root of unity.
+                let w_m = omega.pow([(n / (q * m)) as u64]);
+
+                let mut k = 0;
+                while k < n {
+                    // w_j = w_m^j at the start of every loop iteration.
+                    let mut w_j = F::one();
+                    for j in 0..m {
+                        let base_term = a[k + j];
+                        let mut w_j_i = w_j;
+                        for (i, term) in terms.iter_mut().enumerate() {
+                            *term = a[k + j + (i + 1) * m];
+                            *term *= w_j_i;
+                            w_j_i *= w_j;
+                        }
+
+                        for i in 0..q {
+                            a[k + j + i * m] = base_term;
+                            for (l, term) in terms.iter().enumerate() {
+                                let mut term = *term;
+                                term *= qth_roots[(i * (l + 1)) % q];
+                                a[k + j + i * m] += term;
+                            }
+                        }
+
+                        w_j *= w_m;
+                    }
+
+                    k += q * m;
+                }
+                m *= q;
+            }
+        } else {
+            // Swap the coefficients in place, in bit-reversed order.
+            for k in 0..n {
+                let rk = bitrev(k as u64, self.two_adicity) as usize;
+                if k < rk {
+                    a.swap(k, rk);
+                }
+            }
+        }
+
+        for _ in 0..self.two_adicity {
+            // w_m is the (2 * m)-th root of unity.
+            let w_m = omega.pow([(n / (2 * m)) as u64]);
+
+            let mut k = 0;
+            while k < n {
+                // w = w_m^j at the start of every loop iteration.
+                let mut w = F::one();
+                for j in 0..m {
+                    let mut t = a[k + j + m];
+                    t *= w;
+                    let mut tmp = a[k + j];
+                    tmp -= t;
+                    a[k + j + m] = tmp;
+                    a[k + j] += t;
+                    w *= &w_m;
+                }
+
+                k += 2 * m;
+            }
+            m *= 2;
+        }
+    }
+}
+
+/// Returns the index to which the coefficient at index `i` is moved before the butterflies
+/// of a mixed-radix FFT, which reverses the digits of `i` in the mixed base `(2, ..., 2, q, ..., q)`.
+fn mixed_radix_fft_permute(two_adicity: u32, q_adicity: u32, q: usize, n: usize, mut i: usize) -> usize {
+    let mut result = 0;
+    let mut shift = n;
+
+    for _ in 0..two_adicity {
+        shift /= 2;
+        result += (i % 2) * shift;
+        i /= 2;
+    }
+
+    for _ in 0..q_adicity {
+        shift /= q;
+        result += (i % q) * shift;
+        i /= q;
+    }
+
+    result
+}
+
+/// An iterator over the elements of a mixed-radix domain.
+pub struct MixedRadixElements<F: FftField> {
+    cur_elem: F,
+    cur_pow: u64,
+    domain: MixedRadixEvaluationDomain<F>,
+}
+
+impl<F: FftField> Iterator for MixedRadixElements<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        if self.cur_pow == self.domain.size {
+            None
+        } else {
+            let cur_elem = self.cur_elem;
+            self.cur_elem *= &self.domain.group_gen;
+            self.cur_pow += 1;
+            Some(cur_elem)
+        }
+    }
+}
@@ -23,6 +23,12 @@ pub use domain::EvaluationDomain;
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod general_domain;
+pub use general_domain::GeneralEvaluationDomain;
+
+pub mod mixed_radix_domain;
+pub use mixed_radix_domain::MixedRadixEvaluationDomain;
+
 pub mod polynomial;
 pub use polynomial::{DenseOrSparsePolynomial, DensePolynomial, SparsePolynomial};
 
@@ -16,7 +16,13 @@
 
 //! A polynomial represented in coefficient form.
 
-use crate::fft::{DenseOrSparsePolynomial, EvaluationDomain, Evaluations};
+use crate::fft::{
+    polynomial::SubproductTree,
+    DenseOrSparsePolynomial,
+    EvaluationDomain,
+    Evaluations,
+    GeneralEvaluationDomain,
+};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
@@ -159,6 +165,15 @@ impl<F: PrimeField> DensePolynomial<F> {
         let vanishing_poly = DenseOrSparsePolynomial::from(domain.vanishing_polynomial());
         self_poly.divide_with_q_and_r(&vanishing_poly)
     }
+
+    /// Evaluates `self` at each of the given points.
+    /// For many points, this uses a product tree of the points, in `O(n log^2 n)` time.
+    pub fn batch_evaluate(&self, points: &[F]) -> Vec<F> {
+        if self.is_zero() {
+            return vec![F::zero(); points.len()];
+        }
+        SubproductTree::new(points).evaluate(self, points)
+    }
 }
 
 impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
@@ -353,12 +368,14 @@ impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
         } else {
-            let domain = EvaluationDomain::new(self.coeffs.len() + other.coeffs.len())
+            // Use a mixed-radix domain for products that exceed the two-adicity of the field.
+            let domain = GeneralEvaluationDomain::new(self.coeffs.len() + other.coeffs.len())
                 .expect("field is not smooth enough to construct domain");
-            let mut self_evals = self.evaluate_over_domain_by_ref(domain);
-            let other_evals = other.evaluate_over_domain_by_ref(domain);
-            self_evals *= &other_evals;
-            self_evals.interpolate()
+            let mut self_evals = domain.fft(&self.coeffs);
+            let other_evals = domain.fft(&other.coeffs);
+            cfg_iter_mut!(self_evals).zip(&other_evals).for_each(|(a, b)| *a *= b);
+            domain.ifft_in_place(&mut self_evals);
+            DensePolynomial::from_coefficients_vec(self_evals)
         }
     }
 }
@@ -513,4 +530,21 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn batch_evaluate() {
+        let rng = &mut thread_rng();
+        for num_points in [0, 1, 15, 16, 17, 100, 300].iter() {
+            for degree in [0, 10, 100, 500].iter() {
+                let p = DensePolynomial::<Fr>::rand(*degree, rng);
+                let points = (0..*num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+                let expected = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+                assert_eq!(expected, p.batch_evaluate(&points));
+            }
+        }
+        assert_eq!(
+            vec![Fr::zero(); 3],
+            DensePolynomial::zero().batch_evaluate(&[Fr::one(); 3])
+        );
+    }
 }
@@ -29,6 +29,9 @@ pub use dense::DensePolynomial;
 mod sparse;
 pub use sparse::SparsePolynomial;
 
+mod multipoint;
+use multipoint::SubproductTree;
+
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone)]
 pub enum DenseOrSparsePolynomial<'a, F: 'a + Field> {
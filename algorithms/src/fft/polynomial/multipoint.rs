@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Multipoint evaluation of polynomials, using a product tree of the evaluation points.
+
+use crate::fft::{DenseOrSparsePolynomial, DensePolynomial};
+use snarkvm_fields::PrimeField;
+
+/// The maximum number of points in a leaf of the product tree.
+/// Below this size, evaluating the polynomial at each point is faster than dividing further.
+const LEAF_SIZE: usize = 1 << 4;
+
+/// The maximum degree of a divisor for which the quotient is computed by long division,
+/// instead of by Newton iteration.
+const NAIVE_DIVISION_DEGREE: usize = 1 << 6;
+
+///
+/// A subproduct tree over a list of points `x_0, ..., x_{n-1}`.
+///
+/// Each node stores the product of `(X - x_i)` over the points of its subtree,
+/// so that the remainder of a polynomial modulo a node is equal to the polynomial on its points.
+///
+pub(crate) struct SubproductTree<F: PrimeField> {
+    /// The product of `(X - x_i)` over the points of this subtree.
+    product: DensePolynomial<F>,
+    /// The left and right subtrees, if this node is not a leaf.
+    children: Option<Box<(SubproductTree<F>, SubproductTree<F>)>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Returns the subproduct tree over the given points.
+    pub(crate) fn new(points: &[F]) -> Self {
+        if points.len() <= LEAF_SIZE {
+            let mut product = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+            for point in points {
+                product = naive_mul_by_linear(&product, *point);
+            }
+            Self {
+                product,
+                children: None,
+            }
+        } else {
+            let (left, right) = points.split_at(points.len() / 2);
+            let left = Self::new(left);
+            let right = Self::new(right);
+            Self {
+                product: &left.product * &right.product,
+                children: Some(Box::new((left, right))),
+            }
+        }
+    }
+
+    /// Returns the evaluations of the given polynomial at the given points,
+    /// which must be the points this subproduct tree was constructed from.
+    pub(crate) fn evaluate(&self, polynomial: &DensePolynomial<F>, points: &[F]) -> Vec<F> {
+        let mut evaluations = Vec::with_capacity(points.len());
+        self.evaluate_helper(polynomial, points, &mut evaluations);
+        evaluations
+    }
+
+    fn evaluate_helper(&self, polynomial: &DensePolynomial<F>, points: &[F], evaluations: &mut Vec<F>) {
+        let remainder = fast_remainder(polynomial, &self.product);
+        match &self.children {
+            None => evaluations.extend(points.iter().map(|point| remainder.evaluate(*point))),
+            Some(children) => {
+                let (left_points, right_points) = points.split_at(points.len() / 2);
+                children.0.evaluate_helper(&remainder, left_points, evaluations);
+                children.1.evaluate_helper(&remainder, right_points, evaluations);
+            }
+        }
+    }
+}
+
+/// Returns `polynomial * (X - point)`.
+fn naive_mul_by_linear<F: PrimeField>(polynomial: &DensePolynomial<F>, point: F) -> DensePolynomial<F> {
+    let mut result = vec![F::zero(); polynomial.coeffs.len() + 1];
+    for (i, coeff) in polynomial.coeffs.iter().enumerate() {
+        result[i + 1] += coeff;
+        result[i] -= &(point * coeff);
+    }
+    DensePolynomial::from_coefficients_vec(result)
+}
+
+/// Returns the remainder of the division of `dividend` by the monic polynomial `divisor`.
+fn fast_remainder<F: PrimeField>(dividend: &DensePolynomial<F>, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if dividend.is_zero() || dividend.degree() < divisor.degree() {
+        return dividend.clone();
+    }
+    if divisor.degree() <= NAIVE_DIVISION_DEGREE {
+        let dividend: DenseOrSparsePolynomial<_> = dividend.into();
+        let divisor: DenseOrSparsePolynomial<_> = divisor.into();
+        return dividend.divide_with_q_and_r(&divisor).expect("division failed").1;
+    }
+
+    // The reversed quotient is equal to the reversed dividend, divided by the reversed divisor,
+    // modulo `X^(dividend.degree() - divisor.degree() + 1)`.
+    let quotient_len = dividend.degree() - divisor.degree() + 1;
+    let reversed_dividend = reverse(dividend, dividend.degree() + 1);
+    let reversed_divisor = reverse(divisor, divisor.degree() + 1);
+    let reversed_divisor_inverse = inverse_mod_x_pow(&reversed_divisor, quotient_len);
+
+    let reversed_quotient = truncate(&(&reversed_dividend * &reversed_divisor_inverse), quotient_len);
+    let quotient = reverse(&reversed_quotient, quotient_len);
+
+    let remainder = dividend - &(&quotient * divisor);
+    debug_assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+    remainder
+}
+
+/// Returns the polynomial with the first `len` coefficients of the given polynomial in reverse order.
+fn reverse<F: PrimeField>(polynomial: &DensePolynomial<F>, len: usize) -> DensePolynomial<F> {
+    let mut coeffs = polynomial.coeffs.clone();
+    coeffs.resize(len, F::zero());
+    coeffs.reverse();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns the given polynomial modulo `X^len`.
+fn truncate<F: PrimeField>(polynomial: &DensePolynomial<F>, len: usize) -> DensePolynomial<F> {
+    let len = core::cmp::min(len, polynomial.coeffs.len());
+    DensePolynomial::from_coefficients_slice(&polynomial.coeffs[..len])
+}
+
+/// Returns the inverse of the given polynomial modulo `X^len`, using Newton iteration.
+/// The constant coefficient of the given polynomial must be nonzero.
+fn inverse_mod_x_pow<F: PrimeField>(polynomial: &DensePolynomial<F>, len: usize) -> DensePolynomial<F> {
+    let two = DensePolynomial::from_coefficients_vec(vec![F::one().double()]);
+    let mut inverse = DensePolynomial::from_coefficients_vec(vec![polynomial.coeffs[0].inverse().unwrap()]);
+
+    // Each iteration doubles the number of correct coefficients, with `g = g * (2 - f * g)`.
+    let mut precision = 1;
+    while precision < len {
+        precision = core::cmp::min(2 * precision, len);
+        let product = truncate(&(&truncate(polynomial, precision) * &inverse), precision);
+        inverse = truncate(&(&inverse * &(&two - &product)), precision);
+    }
+    inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_inverse_mod_x_pow() {
+        let rng = &mut thread_rng();
+        for len in 1..100 {
+            let polynomial = DensePolynomial::<Fr>::rand(len, rng);
+            let inverse = inverse_mod_x_pow(&polynomial, len);
+            let product = truncate(&(&polynomial * &inverse), len);
+            assert_eq!(DensePolynomial::from_coefficients_vec(vec![Fr::one()]), product);
+        }
+    }
+
+    #[test]
+    fn test_fast_remainder() {
+        let rng = &mut thread_rng();
+        for divisor_degree in [1, NAIVE_DIVISION_DEGREE, NAIVE_DIVISION_DEGREE + 1, 200].iter() {
+            for dividend_degree in [0, *divisor_degree, 2 * divisor_degree + 3].iter() {
+                let dividend = DensePolynomial::<Fr>::rand(*dividend_degree, rng);
+                let mut divisor = DensePolynomial::<Fr>::rand(*divisor_degree, rng);
+                *divisor.coeffs.last_mut().unwrap() = Fr::one();
+
+                let (_, expected) = DenseOrSparsePolynomial::from(&dividend)
+                    .divide_with_q_and_r(&(&divisor).into())
+                    .unwrap();
+                assert_eq!(expected, fast_remainder(&dividend, &divisor));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subproduct_tree() {
+        let rng = &mut thread_rng();
+        let points = (0..100).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let tree = SubproductTree::new(&points);
+        assert_eq!(points.len(), tree.product.degree());
+        for point in &points {
+            assert_eq!(Fr::zero(), tree.product.evaluate(*point));
+        }
+    }
+}
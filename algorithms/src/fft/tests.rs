@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, GeneralEvaluationDomain, MixedRadixEvaluationDomain};
 use rand::Rng;
-use snarkvm_curves::bls12_377::{Fr, G1Projective};
+use snarkvm_curves::bls12_377::{Fq, Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
 use snarkvm_utilities::{rand::UniformRand, test_rng};
 
@@ -336,3 +336,114 @@ fn fft_composition() {
     test_fft_composition::<Fr, Fr, _>(rng, 10);
     test_fft_composition::<Fr, G1Projective, _>(rng, 10);
 }
+
+#[test]
+fn fft_g1_consistency() {
+    let rng = &mut test_rng();
+
+    for log_d in 0..8 {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_d).unwrap();
+        let v = (0..domain.size()).map(|_| G1Projective::rand(rng)).collect::<Vec<_>>();
+
+        // Ensure the group FFTs match the generic FFTs over projective elements.
+        assert_eq!(domain.fft(&v), domain.fft_g1(&v));
+        assert_eq!(domain.ifft(&v), domain.ifft_g1(&v));
+        assert_eq!(v, domain.ifft_g1(&domain.fft_g1(&v)));
+    }
+}
+
+#[test]
+fn interpolate_from_arbitrary_points_test() {
+    let rng = &mut test_rng();
+
+    for num_points in 0..20 {
+        let polynomial = DensePolynomial::<Fr>::rand(num_points.max(1) - 1, rng);
+        let points = (0..num_points)
+            .map(|_| {
+                let x = Fr::rand(rng);
+                (x, polynomial.evaluate(x))
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the interpolation recovers the polynomial from its evaluations.
+        let candidate = EvaluationDomain::interpolate_from_arbitrary_points(&points).unwrap();
+        match num_points {
+            0 => assert!(candidate.is_zero()),
+            _ => assert_eq!(polynomial, candidate),
+        }
+    }
+
+    // Ensure the interpolation matches the inverse FFT over the elements of a domain.
+    let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+    let evaluations = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let points = domain.elements().zip(evaluations.iter().copied()).collect::<Vec<_>>();
+    assert_eq!(
+        DensePolynomial::from_coefficients_vec(domain.ifft(&evaluations)),
+        EvaluationDomain::interpolate_from_arbitrary_points(&points).unwrap()
+    );
+
+    // Ensure the interpolation fails for points with the same x-coordinate.
+    let x = Fr::rand(rng);
+    assert!(EvaluationDomain::interpolate_from_arbitrary_points(&[(x, Fr::one()), (x, Fr::zero())]).is_none());
+}
+
+#[test]
+fn mixed_radix_domain_sizes() {
+    // Ensure fields without a small subgroup do not support mixed-radix domains.
+    assert!(MixedRadixEvaluationDomain::<Fq>::new(6).is_none());
+    assert!(GeneralEvaluationDomain::<Fq>::new((1 << 46) + 1).is_none());
+
+    // Ensure the smallest domain of size `2^k * 3^s` is chosen.
+    for (num_coeffs, expected_size) in [(0, 1), (1, 1), (3, 3), (5, 6), (7, 8), (10, 12), (13, 16), (17, 24)] {
+        assert_eq!(
+            Some(expected_size),
+            MixedRadixEvaluationDomain::<Fr>::compute_size_of_domain(num_coeffs)
+        );
+    }
+
+    // Ensure the general domain is radix-2 up to the two-adicity, and mixed-radix beyond it.
+    match GeneralEvaluationDomain::<Fr>::new(1 << 47).unwrap() {
+        GeneralEvaluationDomain::Radix2(domain) => assert_eq!(1 << 47, domain.size()),
+        domain => panic!("Expected a radix-2 domain, found {:?}", domain),
+    }
+    match GeneralEvaluationDomain::<Fr>::new((1 << 47) + 1).unwrap() {
+        GeneralEvaluationDomain::MixedRadix(domain) => assert_eq!(3 << 46, domain.size()),
+        domain => panic!("Expected a mixed-radix domain, found {:?}", domain),
+    }
+    assert!(GeneralEvaluationDomain::<Fr>::new((3 << 46) + 1).is_none());
+}
+
+#[test]
+fn mixed_radix_fft_correctness() {
+    let rng = &mut test_rng();
+
+    for num_coeffs in [2, 3, 6, 12, 24, 48, 96] {
+        let domain = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+        assert_eq!(num_coeffs, domain.size());
+        assert_eq!(domain.size(), domain.elements().count());
+
+        let rand_poly = DensePolynomial::<Fr>::rand(num_coeffs - 1, rng);
+        let poly_evals = domain.fft(&rand_poly.coeffs);
+        let poly_coset_evals = domain.coset_fft(&rand_poly.coeffs);
+        for (i, x) in domain.elements().enumerate() {
+            let coset_x = Fr::multiplicative_generator() * x;
+
+            assert!(domain.evaluate_vanishing_polynomial(x).is_zero());
+            assert_eq!(poly_evals[i], rand_poly.evaluate(x));
+            assert_eq!(poly_coset_evals[i], rand_poly.evaluate(coset_x));
+        }
+
+        assert_eq!(rand_poly.coeffs, domain.ifft(&poly_evals));
+        assert_eq!(rand_poly.coeffs, domain.coset_ifft(&poly_coset_evals));
+
+        // Ensure lagrange interpolation at a random point matches the evaluation of the polynomial.
+        let rand_pt = Fr::rand(rng);
+        let interpolated_eval = domain
+            .evaluate_all_lagrange_coefficients(rand_pt)
+            .into_iter()
+            .zip(&poly_evals)
+            .map(|(coeff, eval)| coeff * eval)
+            .sum::<Fr>();
+        assert_eq!(rand_poly.evaluate(rand_pt), interpolated_eval);
+    }
+}
@@ -23,6 +23,9 @@ extern crate derivative;
 #[macro_use]
 extern crate snarkvm_profiler;
 
+#[macro_use]
+extern crate tracing;
+
 #[macro_use]
 extern crate thiserror;
 
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::MerkleError, merkle_tree::MerkleTreeDigest, traits::MerkleParameters};
+
+use std::sync::Arc;
+
+///
+/// A proof that the root of a Merkle tree of a larger depth commits to the same leaves
+/// as the root of a Merkle tree of a smaller depth, as obtained from `MerkleTree::migrate`.
+///
+/// As the leaves of a Merkle tree are padded to its depth with empty siblings, the root of the
+/// deeper tree is the root of the shallower tree, padded with an empty sibling at each new level.
+///
+#[derive(Clone, Debug)]
+pub struct MerkleMigrationProof<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    /// The depth of the Merkle tree that was migrated.
+    pub old_depth: usize,
+    /// The inner nodes, from the old root (exclusive) up to the new root (inclusive).
+    pub path: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> MerkleMigrationProof<P> {
+    ///
+    /// Returns `true` if the given new root is the migration of the given old root,
+    /// through the inner nodes of this proof.
+    ///
+    pub fn verify(&self, old_root: &MerkleTreeDigest<P>, new_root: &MerkleTreeDigest<P>) -> Result<bool, MerkleError> {
+        if self.old_depth > P::DEPTH || self.path.len() != P::DEPTH - self.old_depth {
+            return Ok(false);
+        }
+
+        let empty_hash = self.parameters.hash_empty()?;
+        let mut current_hash = *old_root;
+        for node in &self.path {
+            current_hash = self.parameters.hash_inner_node(&current_hash, &empty_hash)?;
+            if &current_hash != node {
+                return Ok(false);
+            }
+        }
+
+        Ok(&current_hash == new_root)
+    }
+}
@@ -18,10 +18,13 @@ use crate::{
     errors::MerkleError,
     traits::{MerkleParameters, CRH},
 };
-use snarkvm_utilities::{FromBytes, ToBytes};
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
+    fmt,
     io::{Read, Result as IoResult, Write},
+    str::FromStr,
     sync::Arc,
 };
 
@@ -108,15 +111,29 @@ impl<P: MerkleParameters> FromBytes for MerklePath<P> {
         let parameters = {
             let setup_message_length: u64 = FromBytes::read_le(&mut reader)?;
 
-            let mut setup_message_bytes = vec![0u8; setup_message_length as usize];
-            reader.read_exact(&mut setup_message_bytes)?;
-            let setup_message =
-                String::from_utf8(setup_message_bytes).expect("Failed to parse setup message for Merkle parameters");
+            // Read the setup message incrementally, as its length is untrusted.
+            let mut setup_message_bytes = Vec::new();
+            (&mut reader)
+                .take(setup_message_length)
+                .read_to_end(&mut setup_message_bytes)?;
+            if setup_message_bytes.len() as u64 != setup_message_length {
+                return Err(error("Failed to read setup message for Merkle parameters"));
+            }
+            let setup_message = String::from_utf8(setup_message_bytes).map_err(|_| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Failed to parse setup message for Merkle parameters",
+                )
+            })?;
 
             Arc::new(P::setup(&setup_message))
         };
 
         let path_length: u64 = FromBytes::read_le(&mut reader)?;
+        if path_length > P::DEPTH as u64 {
+            return Err(error("Merkle path exceeds the depth of the Merkle tree"));
+        }
+
         let mut path = Vec::with_capacity(path_length as usize);
         for _ in 0..path_length {
             path.push(FromBytes::read_le(&mut reader)?);
@@ -148,6 +165,46 @@ impl<P: MerkleParameters> ToBytes for MerklePath<P> {
     }
 }
 
+impl<P: MerkleParameters> FromStr for MerklePath<P> {
+    type Err = anyhow::Error;
+
+    #[inline]
+    fn from_str(path_hex: &str) -> Result<Self, Self::Err> {
+        Ok(Self::read_le(&hex::decode(path_hex)?[..])?)
+    }
+}
+
+impl<P: MerkleParameters> fmt::Display for MerklePath<P> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path_hex = hex::encode(self.to_bytes_le().expect("Failed to convert Merkle path to bytes"));
+        write!(f, "{}", path_hex)
+    }
+}
+
+impl<P: MerkleParameters> Serialize for MerklePath<P> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, P: MerkleParameters> Deserialize<'de> for MerklePath<P> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let s: String = Deserialize::deserialize(deserializer)?;
+                FromStr::from_str(&s).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle path"),
+        }
+    }
+}
+
 // TODO (howardwu): TEMPORARY - Deprecate this with a ledger rearchitecture.
 impl<P: MerkleParameters> Default for MerklePath<P> {
     fn default() -> Self {
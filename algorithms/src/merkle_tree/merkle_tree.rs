@@ -16,7 +16,7 @@
 
 use crate::{
     errors::MerkleError,
-    merkle_tree::{MerklePath, MerkleTreeDigest},
+    merkle_tree::{MerkleMigrationProof, MerklePath, MerkleTreeDigest},
     traits::{MerkleParameters, CRH},
 };
 use snarkvm_utilities::ToBytes;
@@ -126,6 +126,21 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         })
     }
 
+    ///
+    /// Returns the Merkle tree of the given leaves, and the Merkle path of each leaf, in order.
+    ///
+    /// The leaves and the paths are computed in parallel, and the paths are read from the
+    /// hashes in the tree, instead of hashing each leaf again as `generate_proof` does.
+    ///
+    pub fn new_with_paths<L: ToBytes + Send + Sync>(
+        parameters: Arc<P>,
+        leaves: &[L],
+    ) -> Result<(Self, Vec<MerklePath<P>>), MerkleError> {
+        let tree = Self::new(parameters, leaves)?;
+        let paths = tree.generate_proofs(leaves.len())?;
+        Ok((tree, paths))
+    }
+
     pub fn rebuild<L: ToBytes + Send + Sync>(&self, start_index: usize, new_leaves: &[L]) -> Result<Self, MerkleError> {
         let new_time = start_timer!(|| "MerkleTree::rebuild");
 
@@ -235,6 +250,66 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         })
     }
 
+    ///
+    /// Returns the Merkle tree of the same leaves at the depth of the given parameters,
+    /// and a proof that its root is the migration of the root of this tree.
+    ///
+    /// The hashes of the leaves and of the inner nodes are reused, so only the padding
+    /// from the top of the full tree up to the new depth is hashed.
+    ///
+    pub fn migrate<Q: MerkleParameters<H = P::H> + Send + Sync>(
+        &self,
+        parameters: Arc<Q>,
+    ) -> Result<(MerkleTree<Q>, MerkleMigrationProof<Q>), MerkleError> {
+        let migrate_time = start_timer!(|| "MerkleTree::migrate");
+
+        if Q::DEPTH < Self::DEPTH {
+            return Err(MerkleError::InvalidTreeDepth(Self::DEPTH, Q::DEPTH));
+        }
+        if self.parameters.crh().parameters() != parameters.crh().parameters() {
+            return Err(MerkleError::Message(
+                "Merkle trees may only be migrated with the same hash function".to_string(),
+            ));
+        }
+
+        // Compute the dummy nodes from the top of the full tree until we hit the new depth.
+        let empty_hash = parameters.hash_empty()?;
+        let mut current_depth = tree_depth(self.tree.len());
+        let mut padding_tree = Vec::with_capacity((Q::DEPTH).saturating_sub(current_depth + 1));
+        let mut path = Vec::with_capacity(Q::DEPTH - Self::DEPTH);
+        let mut current_hash = self.tree[0];
+        while current_depth < Q::DEPTH {
+            current_hash = parameters.hash_inner_node(&current_hash, &empty_hash)?;
+
+            // do not pad at the top-level of the tree
+            if current_depth < Q::DEPTH - 1 {
+                padding_tree.push((current_hash, empty_hash));
+            }
+            // store the nodes above the old root in the migration proof
+            if current_depth >= Self::DEPTH {
+                path.push(current_hash);
+            }
+            current_depth += 1;
+        }
+
+        end_timer!(migrate_time);
+
+        let proof = MerkleMigrationProof {
+            parameters: parameters.clone(),
+            old_depth: Self::DEPTH,
+            path,
+        };
+        let tree = MerkleTree {
+            root: current_hash,
+            tree: self.tree.clone(),
+            hashed_leaves_index: self.hashed_leaves_index,
+            padding_tree,
+            parameters,
+        };
+
+        Ok((tree, proof))
+    }
+
     #[inline]
     pub fn root(&self) -> &<P::H as CRH>::Output {
         &self.root
@@ -252,7 +327,6 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
 
     pub fn generate_proof<L: ToBytes>(&self, index: usize, leaf: &L) -> Result<MerklePath<P>, MerkleError> {
         let prove_time = start_timer!(|| "MerkleTree::generate_proof");
-        let mut path = vec![];
 
         let leaf_hash = self.parameters.hash_leaf(leaf)?;
 
@@ -264,6 +338,34 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
             return Err(MerkleError::IncorrectLeafIndex(tree_index));
         }
 
+        let path = self.path_at(index, tree_index);
+        end_timer!(prove_time);
+        path
+    }
+
+    /// Returns the Merkle paths of the first `num_leaves` leaves in the tree, in order.
+    pub fn generate_proofs(&self, num_leaves: usize) -> Result<Vec<MerklePath<P>>, MerkleError> {
+        let prove_time = start_timer!(|| "MerkleTree::generate_proofs");
+
+        let tree_depth = tree_depth(self.tree.len());
+        let paths = cfg_into_iter!(0..num_leaves)
+            .map(|index| {
+                let tree_index = convert_index_to_last_level(index, tree_depth);
+                match tree_index < self.tree.len() {
+                    true => self.path_at(index, tree_index),
+                    false => Err(MerkleError::IncorrectLeafIndex(tree_index)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        end_timer!(prove_time);
+        Ok(paths)
+    }
+
+    /// Returns the Merkle path of the leaf with the given index, which is at the given index in the tree.
+    fn path_at(&self, index: usize, tree_index: usize) -> Result<MerklePath<P>, MerkleError> {
+        let mut path = vec![];
+
         // Iterate from the leaf's parent up to the root, storing all intermediate hash values.
         let mut current_node = tree_index;
         while !is_root(current_node) {
@@ -285,7 +387,6 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
                 path.push(*sibling_hash);
             }
         }
-        end_timer!(prove_time);
 
         if path.len() != Self::DEPTH {
             Err(MerkleError::IncorrectPathLength(path.len()))
@@ -304,11 +405,9 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
     ) -> Result<Vec<Vec<<<P as MerkleParameters>::H as CRH>::Output>>, MerkleError> {
         match leaves.len() {
             0 => Ok(vec![]),
-            _ => Ok(vec![
-                cfg_iter!(leaves)
-                    .map(|leaf| parameters.hash_leaf(&leaf).unwrap())
-                    .collect::<Vec<_>>(),
-            ]),
+            _ => Ok(vec![cfg_iter!(leaves)
+                .map(|leaf| parameters.hash_leaf(&leaf))
+                .collect::<Result<Vec<_>, _>>()?]),
         }
     }
 }
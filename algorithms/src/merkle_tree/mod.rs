@@ -17,6 +17,9 @@
 pub mod masked_merkle_tree_parameters;
 pub use masked_merkle_tree_parameters::*;
 
+pub mod merkle_migration;
+pub use merkle_migration::*;
+
 pub mod merkle_path;
 pub use merkle_path::*;
 
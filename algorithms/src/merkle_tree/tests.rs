@@ -250,4 +250,59 @@ mod pedersen_compressed_crh_on_projective {
         assert_eq!(tree.root(), new_tree_1.root());
         assert_eq!(tree.root(), new_tree_2.root());
     }
+
+    #[test]
+    fn merkle_tree_new_with_paths_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        let parameters = &MTParameters::setup("merkle_tree_test");
+
+        for num_leaves in [0, 1, 4, 15] {
+            let leaves = generate_random_leaves!(num_leaves, 32);
+            let (tree, paths) =
+                MerkleTree::<MTParameters>::new_with_paths(Arc::new(parameters.clone()), &leaves[..]).unwrap();
+            assert_eq!(num_leaves, paths.len());
+
+            // Ensure each path matches the path generated for its leaf alone.
+            for (i, (leaf, path)) in leaves.iter().zip(paths.iter()).enumerate() {
+                let expected_path = tree.generate_proof(i, &leaf).unwrap();
+                assert_eq!(expected_path.path, path.path);
+                assert_eq!(expected_path.leaf_index, path.leaf_index);
+                assert!(path.verify(tree.root(), &leaf).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn merkle_tree_migrate_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        type DeeperMTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 40>;
+        let parameters = &MTParameters::setup("merkle_tree_test");
+        let deeper_parameters = Arc::new(DeeperMTParameters::setup("merkle_tree_test"));
+
+        for num_leaves in [0, 1, 4, 15] {
+            let leaves = generate_random_leaves!(num_leaves, 32);
+            let tree = MerkleTree::<MTParameters>::new(Arc::new(parameters.clone()), &leaves[..]).unwrap();
+            let (migrated_tree, proof) = tree.migrate(deeper_parameters.clone()).unwrap();
+
+            // Ensure the migrated tree matches the deeper tree of the same leaves.
+            let expected_tree = MerkleTree::<DeeperMTParameters>::new(deeper_parameters.clone(), &leaves[..]).unwrap();
+            assert_eq!(expected_tree.root(), migrated_tree.root());
+
+            // Ensure the migration proof binds the old root to the new root.
+            assert!(proof.verify(tree.root(), migrated_tree.root()).unwrap());
+            assert!(!proof.verify(tree.root(), &Default::default()).unwrap());
+            assert!(!proof.verify(&Default::default(), migrated_tree.root()).unwrap());
+
+            // Ensure the paths of the migrated tree verify against the new root.
+            for (i, leaf) in leaves.iter().enumerate() {
+                let path = migrated_tree.generate_proof(i, &leaf).unwrap();
+                assert_eq!(DeeperMTParameters::DEPTH, path.path.len());
+                assert!(path.verify(migrated_tree.root(), &leaf).unwrap());
+            }
+        }
+
+        // Ensure a tree may not be migrated to a smaller depth.
+        let tree = MerkleTree::<DeeperMTParameters>::new(deeper_parameters, &Vec::<[u8; 32]>::new()).unwrap();
+        assert!(tree.migrate(Arc::new(parameters.clone())).is_err());
+    }
 }
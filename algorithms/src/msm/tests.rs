@@ -17,6 +17,7 @@
 use crate::msm::*;
 use snarkvm_curves::{
     bls12_377::{Fr, G1Projective},
+    bls12_381,
     traits::{AffineCurve, ProjectiveCurve},
 };
 use snarkvm_fields::{PrimeField, Zero};
@@ -72,3 +73,22 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn variable_base_test_with_bls12_381() {
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES)
+        .map(|_| bls12_381::Fr::rand(&mut rng).to_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| bls12_381::G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
+
+    assert_eq!(naive.into_affine(), fast.into_affine());
+}
@@ -5,6 +5,7 @@
 static const uint32_t WINDOW_SIZE = 1024;
 // static const uint32_t BLST_WIDTH = 253;
 
+// the scalars are given as unsigned bits, unlike the signed digits of msm_standard on the CPU (see msm_cuda in cuda.rs)
 extern "C" __global__ void msm6_pixel(blst_p1* bucket_lists, const blst_p1_affine* bases_in, const blst_scalar* scalars, const uint32_t* window_lengths, const uint32_t window_count) {
     limb_t index = threadIdx.x / 64;
     size_t shift = threadIdx.x - (index * 64);
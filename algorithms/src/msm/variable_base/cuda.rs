@@ -15,23 +15,204 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::{
-    bls12_377::{Fq, Fr, G1Affine, G1Projective},
+    bls12_377::{Fq, G1Affine, G1Projective},
     traits::{AffineCurve, Group},
 };
 use snarkvm_fields::{PrimeField, Zero};
-use snarkvm_utilities::BitIteratorBE;
+use snarkvm_utilities::{BigInteger256, BitIteratorBE};
 
 use rust_gpu_tools::{cuda, program_closures, Device, GPUError, Program};
 
-use std::any::TypeId;
+use std::{
+    any::TypeId,
+    sync::{Mutex, RwLock},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The number of consecutive retryable failures after which CUDA is disabled for the rest of the process.
+pub const CUDA_MAX_CONSECUTIVE_FAILURES: u32 = 8;
+/// The time CUDA is skipped after a retryable failure, which doubles with each consecutive failure.
+pub const CUDA_BASE_COOLDOWN: Duration = Duration::from_millis(500);
+/// The longest time CUDA is skipped after a retryable failure.
+pub const CUDA_MAX_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum CudaError {
+    #[error("CUDA device {0} was not found")]
+    DeviceNotFound(usize),
+
+    #[error("Invalid CUDA device index in ALEO_CUDA_DEVICE: {0}")]
+    InvalidDeviceIndex(String),
+
+    #[error("CUDA kernel {0} was not found")]
+    KernelNotFound(String),
+
+    #[error("Failed to load the CUDA program: {0}")]
+    ProgramLoad(String),
+
+    #[error("The CUDA request handler has stopped")]
+    HandlerStopped,
+
+    #[error("CUDA MSM failed: {0}")]
+    Runtime(String),
+}
+
+impl CudaError {
+    /// Returns `true` if the error may be transient, such as the GPU running out of memory,
+    /// and `false` if CUDA can never succeed in this process, such as when there is no device.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, CudaError::Runtime(_))
+    }
+}
+
+impl From<GPUError> for CudaError {
+    fn from(error: GPUError) -> Self {
+        match error {
+            GPUError::KernelNotFound(name) => CudaError::KernelNotFound(name),
+            error => CudaError::Runtime(error.to_string()),
+        }
+    }
+}
+
+/// An outcome of an MSM that was eligible to run on the GPU, which is reported to the metrics hook.
+#[derive(Clone, Debug)]
+pub enum CudaEvent<'a> {
+    /// The MSM ran on the GPU in the given time.
+    Success { elapsed: Duration },
+    /// The MSM failed on the GPU, and runs on the CPU instead.
+    Failure { error: &'a CudaError },
+    /// The MSM runs on the CPU, as CUDA is cooling down after a failure.
+    Skipped,
+    /// CUDA is disabled for the rest of the process, after the given error.
+    Disabled { error: &'a CudaError },
+}
+
+lazy_static::lazy_static! {
+    static ref CUDA_METRICS_HOOK: RwLock<Option<fn(&CudaEvent)>> = RwLock::new(None);
+    static ref CUDA_RETRY_POLICY: Mutex<CudaRetryPolicy> = Mutex::new(CudaRetryPolicy::default());
+}
+
+/// Sets the function that is called with the outcome of each MSM that is eligible to run on the GPU,
+/// for example to count failures and fallbacks in a miner. Replaces any previous hook.
+pub fn set_cuda_metrics_hook(hook: fn(&CudaEvent)) {
+    if let Ok(mut current) = CUDA_METRICS_HOOK.write() {
+        *current = Some(hook);
+    }
+}
+
+/// Reports the given event to the metrics hook, if one is set.
+fn report(event: CudaEvent) {
+    if let Ok(hook) = CUDA_METRICS_HOOK.read() {
+        if let Some(hook) = *hook {
+            hook(&event);
+        }
+    }
+}
+
+///
+/// The policy that decides when to use CUDA after a failure.
+///
+/// A fatal error disables CUDA immediately. A retryable error skips CUDA for an exponentially
+/// growing cooldown, and disables it after `CUDA_MAX_CONSECUTIVE_FAILURES` consecutive failures,
+/// so a device that is persistently broken does not slow down every MSM.
+///
+#[derive(Clone, Debug, Default)]
+struct CudaRetryPolicy {
+    consecutive_failures: u32,
+    retry_at: Option<Instant>,
+    is_disabled: bool,
+}
+
+impl CudaRetryPolicy {
+    /// Returns `true` if CUDA may be used at the given time.
+    fn is_available(&self, now: Instant) -> bool {
+        !self.is_disabled && self.retry_at.map_or(true, |retry_at| now >= retry_at)
+    }
+
+    /// Returns the cooldown after the given number of consecutive failures.
+    fn cooldown(consecutive_failures: u32) -> Duration {
+        let exponent = consecutive_failures.saturating_sub(1).min(16);
+        CUDA_BASE_COOLDOWN
+            .checked_mul(1 << exponent)
+            .map_or(CUDA_MAX_COOLDOWN, |cooldown| cooldown.min(CUDA_MAX_COOLDOWN))
+    }
+
+    /// Resets the policy after a successful MSM.
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.retry_at = None;
+    }
+
+    /// Updates the policy after the given error at the given time,
+    /// and returns `true` if CUDA is now disabled.
+    fn record_failure(&mut self, error: &CudaError, now: Instant) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if !error.is_retryable() || self.consecutive_failures >= CUDA_MAX_CONSECUTIVE_FAILURES {
+            self.is_disabled = true;
+        } else {
+            self.retry_at = Some(now + Self::cooldown(self.consecutive_failures));
+        }
+        self.is_disabled
+    }
+}
+
+/// Returns `true` if CUDA may be used for the next MSM, and reports a skipped MSM otherwise.
+pub(super) fn is_cuda_available() -> bool {
+    let is_available = match CUDA_RETRY_POLICY.lock() {
+        Ok(policy) => policy.is_available(Instant::now()),
+        Err(_) => false,
+    };
+    if !is_available {
+        report(CudaEvent::Skipped);
+    }
+    is_available
+}
+
+/// Updates the retry policy with the outcome of an MSM that was dispatched to the GPU.
+/// The metrics hook is called after the retry policy is unlocked, so the hook may not block other MSMs.
+fn record_outcome<T>(outcome: &Result<T, CudaError>, elapsed: Duration) {
+    match outcome {
+        Ok(_) => {
+            match CUDA_RETRY_POLICY.lock() {
+                Ok(mut policy) => policy.record_success(),
+                Err(_) => return,
+            };
+            report(CudaEvent::Success { elapsed });
+        }
+        Err(error) => {
+            let (is_newly_disabled, consecutive_failures) = match CUDA_RETRY_POLICY.lock() {
+                Ok(mut policy) => {
+                    let was_disabled = policy.is_disabled;
+                    let is_disabled = policy.record_failure(error, Instant::now());
+                    (is_disabled && !was_disabled, policy.consecutive_failures)
+                }
+                Err(_) => return,
+            };
+            report(CudaEvent::Failure { error });
+            if is_newly_disabled {
+                eprintln!("Disabling CUDA after {} failure(s). Error: {}", consecutive_failures, error);
+                report(CudaEvent::Disabled { error });
+            }
+        }
+    }
+}
+
+/// Returns the index of the CUDA device to use, which is set by `ALEO_CUDA_DEVICE` and defaults to the first device.
+fn cuda_device_index() -> Result<usize, CudaError> {
+    match std::env::var("ALEO_CUDA_DEVICE") {
+        Ok(index) => index.trim().parse().map_err(|_| CudaError::InvalidDeviceIndex(index)),
+        Err(_) => Ok(0),
+    }
+}
+
 pub struct CudaRequest {
     bases: Vec<G1Affine>,
-    scalars: Vec<Fr>,
-    response: crossbeam_channel::Sender<Result<G1Projective, GPUError>>,
+    scalars: Vec<BigInteger256>,
+    response: crossbeam_channel::Sender<Result<G1Projective, CudaError>>,
 }
 
 struct CudaContext {
@@ -54,12 +235,16 @@ struct CudaAffine {
     y: Fq,
 }
 
-/// Loads the msm.fatbin into an executable CUDA program.
-fn load_cuda_program() -> Result<Program, GPUError> {
-    let devices: Vec<_> = Device::all();
-    let device = match devices.first() {
+/// Loads the msm.fatbin into an executable CUDA program on the device selected by `ALEO_CUDA_DEVICE`.
+fn load_cuda_program() -> Result<Program, CudaError> {
+    let index = cuda_device_index()?;
+    let devices: Vec<_> = Device::all()
+        .into_iter()
+        .filter(|device| device.cuda_device().is_some())
+        .collect();
+    let device = match devices.get(index) {
         Some(device) => device,
-        None => return Err(GPUError::DeviceNotFound),
+        None => return Err(CudaError::DeviceNotFound(index)),
     };
 
     // The executable was compiled with (from build.sh):
@@ -67,21 +252,23 @@ fn load_cuda_program() -> Result<Program, GPUError> {
     let cuda_kernel = include_bytes!("./blst_377_cuda/msm.fatbin");
     let cuda_device = match device.cuda_device() {
         Some(device) => device,
-        None => return Err(GPUError::DeviceNotFound),
+        None => return Err(CudaError::DeviceNotFound(index)),
     };
 
     eprintln!(
-        "\nUsing '{}' as CUDA device with {} bytes of memory",
+        "\nUsing '{}' as CUDA device {} with {} bytes of memory",
         device.name(),
+        index,
         device.memory()
     );
 
-    let cuda_program = cuda::Program::from_bytes(cuda_device, cuda_kernel)?;
+    let cuda_program = cuda::Program::from_bytes(cuda_device, cuda_kernel)
+        .map_err(|error| CudaError::ProgramLoad(error.to_string()))?;
     Ok(Program::Cuda(cuda_program))
 }
 
 /// Run the CUDA MSM operation for a given request.
-fn handle_cuda_request(context: &mut CudaContext, request: &CudaRequest) -> Result<G1Projective, GPUError> {
+fn handle_cuda_request(context: &mut CudaContext, request: &CudaRequest) -> Result<G1Projective, CudaError> {
     let mapped_bases: Vec<_> = cfg_iter!(request.bases)
         .map(|affine| CudaAffine {
             x: affine.x,
@@ -186,6 +373,7 @@ fn initialize_cuda_request_handler(input: crossbeam_channel::Receiver<CudaReques
 
             let mut context = CudaContext {
                 num_groups: num_groups as u32,
+                // This kernel takes the scalars as unsigned bits. See `msm_cuda`.
                 pixel_func_name: "msm6_pixel".to_string(),
                 row_func_name: "msm6_collapse_rows".to_string(),
                 program,
@@ -199,9 +387,9 @@ fn initialize_cuda_request_handler(input: crossbeam_channel::Receiver<CudaReques
             }
         }
         Err(err) => {
-            // If the cuda program fails to load, notify the cuda request dispatcher.
-            if let Ok(request) = input.recv() {
-                request.response.send(Err(err)).ok();
+            // If the cuda program fails to load, notify every cuda request, so none waits on a handler that is gone.
+            while let Ok(request) = input.recv() {
+                request.response.send(Err(err.clone())).ok();
             }
         }
     }
@@ -215,10 +403,18 @@ lazy_static::lazy_static! {
     };
 }
 
+///
+/// Runs the MSM on the GPU, and updates the retry policy with the outcome.
+///
+/// Note: Unlike `msm_standard`, the CUDA kernel does not use signed digits, and adds the base
+/// of every set bit of each scalar. The kernel is loaded from the checked-in `msm.fatbin`,
+/// so signed digits are not supported until the kernel and its binaries are rebuilt together
+/// with `blst_377_cuda/build.sh`.
+///
 pub(super) fn msm_cuda<G: AffineCurve>(
     mut bases: &[G],
     mut scalars: &[<G::ScalarField as PrimeField>::BigInteger],
-) -> Result<G::Projective, GPUError> {
+) -> Result<G::Projective, CudaError> {
     if TypeId::of::<G>() != TypeId::of::<G1Affine>() {
         unimplemented!("trying to use cuda for unsupported curve");
     }
@@ -237,18 +433,20 @@ pub(super) fn msm_cuda<G: AffineCurve>(
         return Ok(acc);
     }
 
+    let timer = Instant::now();
     let (sender, receiver) = crossbeam_channel::bounded(1);
-    CUDA_DISPATCH
-        .send(CudaRequest {
-            bases: unsafe { std::mem::transmute(bases.to_vec()) },
-            scalars: unsafe { std::mem::transmute(scalars.to_vec()) },
-            response: sender,
-        })
-        .map_err(|_| GPUError::DeviceNotFound)?;
-    match receiver.recv() {
-        Ok(x) => unsafe { std::mem::transmute_copy(&x) },
-        Err(_) => Err(GPUError::DeviceNotFound),
-    }
+    let outcome = match CUDA_DISPATCH.send(CudaRequest {
+        bases: unsafe { std::mem::transmute(bases.to_vec()) },
+        scalars: unsafe { std::mem::transmute(scalars.to_vec()) },
+        response: sender,
+    }) {
+        Ok(()) => receiver.recv().unwrap_or(Err(CudaError::HandlerStopped)),
+        Err(_) => Err(CudaError::HandlerStopped),
+    };
+    record_outcome(&outcome, timer.elapsed());
+
+    // `G` is `G1Affine`, so only the `G1Projective` result is reinterpreted, which is `Copy` and has no destructor.
+    outcome.map(|result| unsafe { std::mem::transmute_copy::<G1Projective, G::Projective>(&result) })
 }
 
 #[cfg(test)]
@@ -346,6 +544,50 @@ mod tests {
         inputs
     }
 
+    #[test]
+    fn test_cuda_error_classification() {
+        assert!(CudaError::from(GPUError::Generic("out of memory".to_string())).is_retryable());
+        assert!(!CudaError::from(GPUError::KernelNotFound("msm6_pixel".to_string())).is_retryable());
+        assert!(!CudaError::DeviceNotFound(1).is_retryable());
+        assert!(!CudaError::InvalidDeviceIndex("gpu0".to_string()).is_retryable());
+        assert!(!CudaError::ProgramLoad("invalid image".to_string()).is_retryable());
+        assert!(!CudaError::HandlerStopped.is_retryable());
+    }
+
+    #[test]
+    fn test_cuda_retry_policy() {
+        let now = Instant::now();
+        let retryable = CudaError::Runtime("out of memory".to_string());
+
+        // Ensure a retryable failure skips CUDA for a cooldown, which doubles with each consecutive failure.
+        let mut policy = CudaRetryPolicy::default();
+        assert!(policy.is_available(now));
+        assert!(!policy.record_failure(&retryable, now));
+        assert!(!policy.is_available(now));
+        assert!(policy.is_available(now + CUDA_BASE_COOLDOWN));
+        assert!(!policy.record_failure(&retryable, now));
+        assert!(!policy.is_available(now + CUDA_BASE_COOLDOWN));
+        assert!(policy.is_available(now + 2 * CUDA_BASE_COOLDOWN));
+
+        // Ensure a success resets the cooldown.
+        policy.record_success();
+        assert!(policy.is_available(now));
+        assert_eq!(0, policy.consecutive_failures);
+
+        // Ensure the cooldown is bounded, and CUDA is disabled after too many consecutive failures.
+        assert_eq!(CUDA_MAX_COOLDOWN, CudaRetryPolicy::cooldown(u32::MAX));
+        for _ in 1..CUDA_MAX_CONSECUTIVE_FAILURES {
+            assert!(!policy.record_failure(&retryable, now));
+        }
+        assert!(policy.record_failure(&retryable, now));
+        assert!(!policy.is_available(now + CUDA_MAX_COOLDOWN));
+
+        // Ensure a fatal failure disables CUDA immediately.
+        let mut policy = CudaRetryPolicy::default();
+        assert!(policy.record_failure(&CudaError::DeviceNotFound(0), now));
+        assert!(!policy.is_available(now + CUDA_MAX_COOLDOWN));
+    }
+
     #[test]
     fn test_cuda_mul() {
         let inputs = make_tests(1000, 2);
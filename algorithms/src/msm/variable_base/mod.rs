@@ -16,9 +16,6 @@
 
 use std::any::TypeId;
 
-#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-use std::sync::atomic::{AtomicBool, Ordering};
-
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::BitIteratorBE;
@@ -27,9 +24,15 @@ mod standard;
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 mod cuda;
-
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-static HAS_CUDA_FAILED: AtomicBool = AtomicBool::new(false);
+pub use cuda::{
+    set_cuda_metrics_hook,
+    CudaError,
+    CudaEvent,
+    CUDA_BASE_COOLDOWN,
+    CUDA_MAX_CONSECUTIVE_FAILURES,
+    CUDA_MAX_COOLDOWN,
+};
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 pub fn is_cuda_enabled() -> bool {
@@ -58,19 +61,18 @@ impl VariableBaseMSM {
         bases: &[G],
         scalars: &[<G::ScalarField as PrimeField>::BigInteger],
     ) -> G::Projective {
+        let _span = debug_span!("MSM", size = bases.len()).entered();
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             {
                 if !is_cuda_enabled() {
                     return standard::msm_standard(bases, scalars);
                 }
-                if !HAS_CUDA_FAILED.load(Ordering::SeqCst) {
+                // After a failure, CUDA is retried once its cooldown elapses, unless the failure was fatal.
+                if cuda::is_cuda_available() {
                     match cuda::msm_cuda(bases, scalars) {
                         Ok(x) => return x,
-                        Err(e) => {
-                            HAS_CUDA_FAILED.store(true, Ordering::SeqCst);
-                            eprintln!("CUDA failed, moving to next msm method. Error: {:?}", e);
-                        }
+                        Err(e) => eprintln!("CUDA failed, moving to next msm method. Error: {}", e),
                     }
                 }
             }
@@ -88,7 +90,7 @@ mod tests {
         bls12_377::{Fr, G1Affine, G1Projective},
         traits::ProjectiveCurve,
     };
-    use snarkvm_fields::PrimeField;
+    use snarkvm_fields::{One, PrimeField};
     use snarkvm_utilities::{rand::UniformRand, BigInteger256};
 
     fn test_data(seed: u64, samples: usize) -> (Vec<G1Affine>, Vec<BigInteger256>) {
@@ -110,6 +112,25 @@ mod tests {
         assert_eq!(rust, naive);
     }
 
+    #[test]
+    fn test_naive_signed_digits() {
+        for samples in [1, 31, 32, 1 << 10].iter() {
+            let (bases, mut scalars) = test_data(334563456 + *samples as u64, *samples);
+
+            // Include scalars with all-zero, unit, and maximal digits.
+            scalars[0] = (-Fr::one()).to_repr();
+            if *samples > 3 {
+                scalars[1] = BigInteger256::from(0);
+                scalars[2] = BigInteger256::from(1);
+                scalars[3] = BigInteger256::from(u64::MAX);
+            }
+
+            let rust = standard::msm_standard(bases.as_slice(), scalars.as_slice());
+            let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+            assert_eq!(rust, naive);
+        }
+    }
+
     #[test]
     fn test_multi_scalar_mul() {
         let (bases, scalars) = test_data(334563456, 100);
@@ -16,11 +16,43 @@
 
 use snarkvm_curves::{traits::AffineCurve, Group, ProjectiveCurve};
 use snarkvm_fields::{FieldParameters, One, PrimeField, Zero};
-use snarkvm_utilities::BigInteger;
+use snarkvm_utilities::{BigInteger, ScratchVec};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Writes the signed digits of the given scalar in windows of `c` bits into `digits`, one per window,
+/// from the lowest window to the highest.
+///
+/// Each digit is recentered into `[-2^(c-1), 2^(c-1))` by carrying into the next window,
+/// except for the highest window, which absorbs the final carry and is at most `2^(c-1)`.
+/// This requires the highest window to have a spare bit, i.e. `c * digits.len() > num_bits`, and `c < 32`.
+fn make_digits<B: BigInteger>(scalar: &B, c: usize, digits: &mut [i32]) {
+    let radix = 1i32 << c;
+    let window_mask = (radix - 1) as u64;
+    let limbs = scalar.as_ref();
+    let num_windows = digits.len();
+
+    let mut carry = 0i32;
+    for (window, digit_out) in digits.iter_mut().enumerate() {
+        // Read the `c` bits of the window, which may span two limbs.
+        let limb = (window * c) / 64;
+        let shift = (window * c) % 64;
+        let mut bits = limbs.get(limb).map_or(0, |limb| limb >> shift);
+        if shift + c > 64 {
+            bits |= limbs.get(limb + 1).map_or(0, |limb| limb << (64 - shift));
+        }
+
+        let mut digit = (bits & window_mask) as i32 + carry;
+        carry = 0;
+        if digit >= radix / 2 && window + 1 < num_windows {
+            digit -= radix;
+            carry = 1;
+        }
+        *digit_out = digit;
+    }
+}
+
 pub fn msm_standard<G: AffineCurve>(
     bases: &[G],
     scalars: &[<G::ScalarField as PrimeField>::BigInteger],
@@ -35,49 +67,53 @@ pub fn msm_standard<G: AffineCurve>(
     let fr_one = G::ScalarField::one().to_repr();
 
     let zero = G::zero().into_projective();
-    let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+
+    // The highest window needs a spare bit to absorb the final carry of the signed digits.
+    let num_windows = num_bits / c + 1;
+
+    // The digits of all scalars are written into one buffer, with `num_windows` digits per scalar.
+    // Each digit is at most `2^(c-1)` in absolute value, so it fits in an `i32`.
+    let mut scalar_digits = vec![0i32; scalars.len() * num_windows];
+    cfg_chunks_mut!(scalar_digits, num_windows)
+        .zip(scalars)
+        .for_each(|(digits, scalar)| make_digits(scalar, c, digits));
 
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
     // in parallel process each such window.
-    // window_starts.into_iter() //
-    let window_sums: Vec<_> = cfg_into_iter!(window_starts)
-        .map(|w_start| {
+    let window_sums: Vec<_> = cfg_into_iter!(0..num_windows)
+        .map(|window| {
             let mut res = zero;
-            // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-            let mut buckets = vec![zero; (1 << c) - 1];
+            // We don't need the "zero" bucket, and a negative digit shares the bucket
+            // of its absolute value, so we only have 2^(c-1) buckets.
+            let mut buckets = ScratchVec::from_elem(zero, 1 << (c - 1));
             scalars
                 .iter()
                 .zip(bases)
-                .filter(|(s, _)| !s.is_zero())
-                .for_each(|(&scalar, base)| {
+                .zip(scalar_digits.chunks(num_windows))
+                .filter(|((s, _), _)| !s.is_zero())
+                .for_each(|((&scalar, base), digits)| {
                     if scalar == fr_one {
                         // We only process unit scalars once in the first window.
-                        if w_start == 0 {
+                        if window == 0 {
                             res.add_assign_mixed(base);
                         }
                     } else {
-                        let mut scalar = scalar;
-
-                        // We right-shift by w_start, thus getting rid of the
-                        // lower bits.
-                        scalar.divn(w_start as u32);
-
-                        // We mod the remaining bits by the window size.
-                        let scalar = scalar.as_ref()[0] % (1 << c);
-
-                        // If the scalar is non-zero, we update the corresponding
-                        // bucket.
+                        // If the digit is non-zero, we update the corresponding bucket,
+                        // with the negated base for a negative digit.
                         // (Recall that `buckets` doesn't have a zero bucket.)
-                        if scalar != 0 {
-                            buckets[(scalar - 1) as usize].add_assign_mixed(base);
+                        let digit = digits[window];
+                        if digit > 0 {
+                            buckets[(digit - 1) as usize].add_assign_mixed(base);
+                        } else if digit < 0 {
+                            buckets[(-digit - 1) as usize].add_assign_mixed(&-*base);
                         }
                     }
                 });
             // G::Projective::batch_normalization(&mut buckets);
 
             let mut running_sum = G::Projective::zero();
-            for b in buckets.into_iter().rev() {
+            for b in buckets.iter().rev() {
                 running_sum += b;
                 // running_sum.add_assign_mixed(&b);
                 res += running_sum;
@@ -99,3 +135,43 @@ pub fn msm_standard<G: AffineCurve>(
         total
     }) + lowest
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{rand::UniformRand, BigInteger256};
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_make_digits() {
+        let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+        let num_bits = <Fr as PrimeField>::Parameters::MODULUS_BITS as usize;
+        let mut scalars = vec![BigInteger256::from(0), BigInteger256::from(1), (-Fr::one()).to_repr()];
+        scalars.extend((0..100).map(|_| Fr::rand(&mut rng).to_repr()));
+
+        for c in 2..20 {
+            let num_windows = num_bits / c + 1;
+            let mut digits = vec![0i32; num_windows];
+            for scalar in &scalars {
+                make_digits(scalar, c, &mut digits);
+
+                // Ensure every digit fits in the 2^(c-1) buckets.
+                assert!(digits.iter().all(|digit| digit.abs() <= 1 << (c - 1)));
+
+                // Ensure the digits recompose into the scalar.
+                let recomposed = digits.iter().rev().fold(Fr::zero(), |total, &digit| {
+                    let digit = match digit < 0 {
+                        true => -Fr::from((-digit) as u64),
+                        false => Fr::from(digit as u64),
+                    };
+                    total * Fr::from(1u64 << c) + digit
+                });
+                assert_eq!(*scalar, recomposed.to_repr());
+            }
+        }
+    }
+}
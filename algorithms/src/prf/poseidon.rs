@@ -15,13 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    crypto_hash::{PoseidonCryptoHash, PoseidonDefaultParametersField},
+    crypto_hash::{PoseidonDefaultParametersField, PoseidonSponge},
     errors::PRFError,
-    traits::{CryptoHash, PRF},
+    traits::{AlgebraicSponge, PRF},
 };
 use snarkvm_fields::PrimeField;
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 #[derive(Clone)]
 pub struct PoseidonPRF<
@@ -30,16 +30,16 @@ pub struct PoseidonPRF<
     const OPTIMIZED_FOR_WEIGHTS: bool,
 >(PhantomData<F>);
 
-impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> PRF
-    for PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS>
+impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool>
+    PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS>
 {
-    type Input = Vec<F>;
-    type Output = F;
-    type Seed = F;
-
-    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, PRFError> {
-        let timer = start_timer!(|| "PoseidonPRF::evaluate");
-
+    ///
+    /// Returns `num_outputs` field elements, squeezed from a Poseidon sponge that absorbs
+    /// the seed, the input length, and the input.
+    ///
+    /// The first output element is the output of `PRF::evaluate`.
+    ///
+    pub fn evaluate_many(seed: &F, input: &[F], num_outputs: usize) -> Result<Vec<F>, PRFError> {
         // Construct the input length as a field element.
         let input_length = {
             let mut buffer = input.len().to_le_bytes().to_vec();
@@ -53,8 +53,44 @@ impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OP
         preimage.extend_from_slice(input);
 
         // Evaluate the preimage.
-        let output = PoseidonCryptoHash::<F, RATE, OPTIMIZED_FOR_WEIGHTS>::setup().evaluate(preimage.as_slice());
+        let parameters = F::get_default_poseidon_parameters::<RATE>(OPTIMIZED_FOR_WEIGHTS)
+            .ok_or_else(|| PRFError::Message(format!("Poseidon parameters are unavailable for rate {}", RATE)))?;
+        let mut sponge = PoseidonSponge::<F, RATE, 1>::with_parameters(&Arc::new(parameters));
+        sponge.absorb(&preimage);
+        Ok(sponge.squeeze_field_elements(num_outputs).to_vec())
+    }
+
+    ///
+    /// Returns the ciphertext of the given plaintext, by adding the keystream
+    /// that is output by the PRF on the given key and nonce.
+    ///
+    /// The nonce must not be reused with the same key.
+    ///
+    pub fn encrypt(key: &F, nonce: &[F], plaintext: &[F]) -> Result<Vec<F>, PRFError> {
+        let keystream = Self::evaluate_many(key, nonce, plaintext.len())?;
+        Ok(plaintext.iter().zip(keystream).map(|(p, k)| *p + k).collect())
+    }
 
+    ///
+    /// Returns the plaintext of the given ciphertext, by subtracting the keystream
+    /// that is output by the PRF on the given key and nonce.
+    ///
+    pub fn decrypt(key: &F, nonce: &[F], ciphertext: &[F]) -> Result<Vec<F>, PRFError> {
+        let keystream = Self::evaluate_many(key, nonce, ciphertext.len())?;
+        Ok(ciphertext.iter().zip(keystream).map(|(c, k)| *c - k).collect())
+    }
+}
+
+impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> PRF
+    for PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS>
+{
+    type Input = Vec<F>;
+    type Output = F;
+    type Seed = F;
+
+    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, PRFError> {
+        let timer = start_timer!(|| "PoseidonPRF::evaluate");
+        let output = Self::evaluate_many(seed, input, 1)?[0];
         end_timer!(timer);
         Ok(output)
     }
@@ -332,11 +332,15 @@ where
     type ScalarField = TE::ScalarField;
     type Signature = AleoSignature<TE>;
 
-    fn pk_sig(signature: &Self::Signature) -> Result<Self::AffineCurve> {
+    fn pk_sig_scalar(&self, sk_sig: &Self::ScalarField) -> Result<Self::ScalarField> {
+        Ok(*sk_sig)
+    }
+
+    fn pk_sig(&self, signature: &Self::Signature) -> Result<Self::AffineCurve> {
         Self::recover_from_x_coordinate(&signature.root_public_key)
     }
 
-    fn pr_sig(signature: &Self::Signature) -> Result<Self::AffineCurve> {
+    fn pr_sig(&self, signature: &Self::Signature) -> Result<Self::AffineCurve> {
         Self::recover_from_x_coordinate(&signature.root_randomizer)
     }
 
@@ -426,6 +430,11 @@ where
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let g_bases_length: u32 = FromBytes::read_le(&mut reader)?;
+        if g_bases_length != <TE::ScalarField as PrimeField>::Parameters::MODULUS_BITS {
+            let error = format!("Invalid number of signature bases ({})", g_bases_length);
+            return Err(SignatureError::Message(error).into());
+        }
+
         let mut g_bases = Vec::with_capacity(g_bases_length as usize);
         for _ in 0..g_bases_length {
             let g: TEAffine<TE> = FromBytes::read_le(&mut reader)?;
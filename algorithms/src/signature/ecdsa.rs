@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SignatureError, SignatureScheme};
+use snarkvm_curves::{
+    secp256k1::{Fr, Secp256k1Affine},
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The number of bytes in the message hash that is signed by an ECDSA signature.
+pub const ECDSA_MESSAGE_HASH_SIZE: usize = 32;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECDSASignature {
+    r: Fr,
+    s: Fr,
+}
+
+impl ECDSASignature {
+    /// Initializes a new signature from its `r` and `s` scalars.
+    pub fn new(r: Fr, s: Fr) -> Self {
+        Self { r, s }
+    }
+
+    /// Returns the `r` scalar, which is the x-coordinate of the signature nonce point.
+    #[inline]
+    pub fn r(&self) -> Fr {
+        self.r
+    }
+
+    /// Returns the `s` scalar of the signature.
+    #[inline]
+    pub fn s(&self) -> Fr {
+        self.s
+    }
+}
+
+impl FromBytes for ECDSASignature {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let r = FromBytes::read_le(&mut reader)?;
+        let s = FromBytes::read_le(&mut reader)?;
+        Ok(Self { r, s })
+    }
+}
+
+impl ToBytes for ECDSASignature {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.r.write_le(&mut writer)?;
+        self.s.write_le(&mut writer)
+    }
+}
+
+///
+/// An ECDSA signature scheme over secp256k1, as used by Bitcoin and Ethereum.
+///
+/// The message is the 32-byte hash of the signed data, as each chain hashes its data differently,
+/// such as Keccak-256 for Ethereum and double SHA-256 for Bitcoin. Signatures are produced with
+/// a low `s`, as those chains require, while verification accepts either `s`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ECDSASignatureScheme {
+    generator: Secp256k1Affine,
+}
+
+impl ECDSASignatureScheme {
+    /// Returns the given message hash as a scalar, by reading it in big-endian, as specified in SEC 1.
+    pub fn message_hash_to_scalar(message_hash: &[u8]) -> Result<Fr> {
+        if message_hash.len() != ECDSA_MESSAGE_HASH_SIZE {
+            return Err(SignatureError::Message(format!(
+                "ECDSA signs a {}-byte message hash, found {} bytes",
+                ECDSA_MESSAGE_HASH_SIZE,
+                message_hash.len()
+            ))
+            .into());
+        }
+        Ok(Fr::from_bytes_be_mod_order(message_hash))
+    }
+
+    /// Returns the x-coordinate of the given point, reduced modulo the order of the curve.
+    pub fn x_coordinate_to_scalar(point: &Secp256k1Affine) -> Result<Fr> {
+        Ok(Fr::from_bytes_le_mod_order(&point.to_x_coordinate().to_bytes_le()?))
+    }
+}
+
+impl SignatureScheme for ECDSASignatureScheme {
+    type Parameters = Secp256k1Affine;
+    type PrivateKey = Fr;
+    type PublicKey = Secp256k1Affine;
+    type Signature = ECDSASignature;
+
+    ///
+    /// Returns the signature scheme with the standard secp256k1 generator.
+    /// The message is ignored, as ECDSA signatures are only interoperable with the standard generator.
+    ///
+    fn setup(_message: &str) -> Self {
+        Self {
+            generator: Secp256k1Affine::prime_subgroup_generator(),
+        }
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.generator
+    }
+
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        loop {
+            let private_key = Fr::rand(rng);
+            if !private_key.is_zero() {
+                return private_key;
+            }
+        }
+    }
+
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        self.generator * *private_key
+    }
+
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Signature> {
+        let message_hash = Self::message_hash_to_scalar(message)?;
+
+        loop {
+            let nonce = Fr::rand(rng);
+            if nonce.is_zero() {
+                continue;
+            }
+
+            let r = Self::x_coordinate_to_scalar(&(self.generator * nonce))?;
+            if r.is_zero() {
+                continue;
+            }
+
+            let s = (message_hash + r * private_key) * nonce.inverse().unwrap();
+            if s.is_zero() {
+                continue;
+            }
+
+            // Use the low `s`, as `(r, -s)` is also a valid signature.
+            let s = std::cmp::min(s, -s);
+            return Ok(ECDSASignature { r, s });
+        }
+    }
+
+    fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool> {
+        let message_hash = Self::message_hash_to_scalar(message)?;
+
+        if signature.r.is_zero() || signature.s.is_zero() || public_key.is_zero() || !public_key.is_on_curve() {
+            return Ok(false);
+        }
+
+        // Compute (z / s) * G + (r / s) * Q, whose x-coordinate must equal `r`.
+        let s_inverse = signature.s.inverse().unwrap();
+        let point = (self.generator * (message_hash * s_inverse)).into_projective()
+            + &(*public_key * (signature.r * s_inverse)).into_projective();
+        if point.is_zero() {
+            return Ok(false);
+        }
+
+        Ok(Self::x_coordinate_to_scalar(&point.into_affine())? == signature.r)
+    }
+}
+
+impl From<Secp256k1Affine> for ECDSASignatureScheme {
+    fn from(generator: Secp256k1Affine) -> Self {
+        Self { generator }
+    }
+}
+
+impl FromBytes for ECDSASignatureScheme {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            generator: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl ToBytes for ECDSASignatureScheme {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.generator.write_le(&mut writer)
+    }
+}
@@ -17,5 +17,20 @@
 pub mod aleo;
 pub use aleo::*;
 
+pub mod ecdsa;
+pub use ecdsa::*;
+
+pub mod schnorr;
+pub use schnorr::*;
+
+pub mod winternitz;
+pub use winternitz::*;
+
+pub mod winternitz_account;
+pub use winternitz_account::*;
+
+pub mod winternitz_tree;
+pub use winternitz_tree::*;
+
 #[cfg(test)]
 mod tests;
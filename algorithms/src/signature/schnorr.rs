@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SignatureError, SignatureScheme, CRH};
+use snarkvm_curves::{
+    templates::short_weierstrass_jacobian::Affine,
+    AffineCurve,
+    ShortWeierstrassParameters,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: ShortWeierstrassParameters"),
+    Clone(bound = "P: ShortWeierstrassParameters"),
+    Debug(bound = "P: ShortWeierstrassParameters"),
+    Default(bound = "P: ShortWeierstrassParameters"),
+    PartialEq(bound = "P: ShortWeierstrassParameters"),
+    Eq(bound = "P: ShortWeierstrassParameters")
+)]
+pub struct SchnorrSignature<P: ShortWeierstrassParameters> {
+    r: Affine<P>,
+    s: P::ScalarField,
+}
+
+impl<P: ShortWeierstrassParameters> SchnorrSignature<P> {
+    /// Initializes a new signature from its nonce point `r` and its scalar `s`.
+    pub fn new(r: Affine<P>, s: P::ScalarField) -> Self {
+        Self { r, s }
+    }
+
+    /// Returns the nonce point `r` of the signature.
+    #[inline]
+    pub fn r(&self) -> Affine<P> {
+        self.r
+    }
+
+    /// Returns the `s` scalar of the signature.
+    #[inline]
+    pub fn s(&self) -> P::ScalarField {
+        self.s
+    }
+}
+
+impl<P: ShortWeierstrassParameters> FromBytes for SchnorrSignature<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let r = FromBytes::read_le(&mut reader)?;
+        let s = FromBytes::read_le(&mut reader)?;
+        Ok(Self { r, s })
+    }
+}
+
+impl<P: ShortWeierstrassParameters> ToBytes for SchnorrSignature<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.r.write_le(&mut writer)?;
+        self.s.write_le(&mut writer)
+    }
+}
+
+///
+/// A Schnorr signature scheme over a short Weierstrass curve, for signatures from systems outside of Aleo.
+///
+/// A signature `(R, s)` on a message is valid for the public key `Q` if `s * G = R + e * Q`,
+/// where the challenge `e` is the hash of `R`, `Q`, and the message, reduced modulo the order of the curve.
+/// The hash function is generic so that an algebraic hash, such as Poseidon, may be used to verify
+/// signatures in a circuit whose field differs from the fields of the curve.
+///
+/// The public key and the nonce point are only checked to be on the curve, and are expected to be
+/// in the prime-order subgroup, which holds for every point of a curve with a cofactor of one.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: ShortWeierstrassParameters, H: CRH"),
+    Debug(bound = "P: ShortWeierstrassParameters, H: CRH")
+)]
+pub struct SchnorrSignatureScheme<P: ShortWeierstrassParameters, H: CRH>
+where
+    P::BaseField: PrimeField,
+{
+    generator: Affine<P>,
+    crh: H,
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH> SchnorrSignatureScheme<P, H>
+where
+    P::BaseField: PrimeField,
+{
+    /// Returns the generator of the curve, which is the standard generator of the prime-order subgroup.
+    pub fn generator(&self) -> &Affine<P> {
+        &self.generator
+    }
+
+    /// Returns the hash function of the scheme.
+    pub fn crh(&self) -> &H {
+        &self.crh
+    }
+
+    /// Returns the little-endian bytes of the given coordinate, in the fewest bytes that fit every coordinate.
+    pub fn coordinate_to_bytes(coordinate: &P::BaseField) -> Result<Vec<u8>> {
+        let mut bytes = coordinate.to_bytes_le()?;
+        bytes.truncate((P::BaseField::size_in_bits() + 7) / 8);
+        Ok(bytes)
+    }
+
+    /// Returns the challenge for the given nonce point, public key, and message,
+    /// as the hash of their bytes, reduced modulo the order of the curve.
+    pub fn challenge(&self, r: &Affine<P>, public_key: &Affine<P>, message: &[u8]) -> Result<P::ScalarField> {
+        let mut preimage = Vec::with_capacity(message.len());
+        for point in &[r, public_key] {
+            preimage.extend(Self::coordinate_to_bytes(&point.x)?);
+            preimage.extend(Self::coordinate_to_bytes(&point.y)?);
+        }
+        preimage.extend_from_slice(message);
+
+        let challenge = self.crh.hash(&preimage)?;
+        Ok(P::ScalarField::from_bytes_le_mod_order(&challenge.to_bytes_le()?))
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH> SignatureScheme for SchnorrSignatureScheme<P, H>
+where
+    P::BaseField: PrimeField,
+{
+    type Parameters = H::Parameters;
+    type PrivateKey = P::ScalarField;
+    type PublicKey = Affine<P>;
+    type Signature = SchnorrSignature<P>;
+
+    fn setup(message: &str) -> Self {
+        Self {
+            generator: Affine::prime_subgroup_generator(),
+            crh: H::setup(message),
+        }
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        self.crh.parameters()
+    }
+
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        loop {
+            let private_key = P::ScalarField::rand(rng);
+            if !private_key.is_zero() {
+                return private_key;
+            }
+        }
+    }
+
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        self.generator * *private_key
+    }
+
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Signature> {
+        if private_key.is_zero() {
+            return Err(SignatureError::Message("A Schnorr private key must be nonzero".into()).into());
+        }
+        let public_key = self.generate_public_key(private_key);
+
+        loop {
+            let nonce = P::ScalarField::rand(rng);
+            if nonce.is_zero() {
+                continue;
+            }
+
+            let r = self.generator * nonce;
+            let s = nonce + self.challenge(&r, &public_key, message)? * private_key;
+            return Ok(SchnorrSignature { r, s });
+        }
+    }
+
+    fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool> {
+        for point in &[public_key, &signature.r] {
+            if point.is_zero() || !point.is_on_curve() {
+                return Ok(false);
+            }
+        }
+
+        // Check that `s * G = R + e * Q`.
+        let challenge = self.challenge(&signature.r, public_key, message)?;
+        let left = (self.generator * signature.s).into_projective();
+        let right = signature.r.into_projective() + &(*public_key * challenge).into_projective();
+        Ok(left == right)
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH> From<H::Parameters> for SchnorrSignatureScheme<P, H>
+where
+    P::BaseField: PrimeField,
+{
+    fn from(parameters: H::Parameters) -> Self {
+        Self {
+            generator: Affine::prime_subgroup_generator(),
+            crh: H::from(parameters),
+        }
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH> PartialEq for SchnorrSignatureScheme<P, H>
+where
+    P::BaseField: PrimeField,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.crh.parameters() == other.crh.parameters()
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH> Eq for SchnorrSignatureScheme<P, H> where P::BaseField: PrimeField {}
+
+impl<P: ShortWeierstrassParameters, H: CRH> FromBytes for SchnorrSignatureScheme<P, H>
+where
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            generator: Affine::prime_subgroup_generator(),
+            crh: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH> ToBytes for SchnorrSignatureScheme<P, H>
+where
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.crh.write_le(&mut writer)
+    }
+}
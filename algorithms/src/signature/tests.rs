@@ -52,6 +52,7 @@ mod aleo {
         edwards_bls12::EdwardsParameters as EdwardsBls12,
         edwards_bw6::EdwardsParameters as EdwardsBW6,
     };
+    use snarkvm_utilities::ToBytes;
 
     #[test]
     fn test_aleo_signature_on_edwards_bls12_377() {
@@ -76,4 +77,348 @@ mod aleo {
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBls12>>();
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBW6>>();
     }
+
+    #[test]
+    fn test_aleo_signature_scheme_rejects_invalid_number_of_bases() {
+        let signature_scheme = AleoSignatureScheme::<EdwardsBls12>::setup("invalid_number_of_bases");
+        let mut bytes = signature_scheme.to_bytes_le().unwrap();
+
+        // Ensure a length encoding that exceeds the number of bases fails to read.
+        bytes[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(AleoSignatureScheme::<EdwardsBls12>::read_le(&bytes[..]).is_err());
+    }
+}
+
+mod ecdsa {
+    use super::*;
+    use crate::signature::{ECDSASignature, ECDSASignatureScheme};
+    use snarkvm_curves::{
+        secp256k1::{Fq, Fr, Secp256k1Affine},
+        AffineCurve,
+    };
+    use snarkvm_utilities::ToBytes;
+
+    use std::str::FromStr;
+
+    /// The hash of the message "sample", which is signed by the test vector.
+    const MESSAGE_HASH: [u8; 32] = [
+        0xaf, 0x2b, 0xdb, 0xe1, 0xaa, 0x9b, 0x6e, 0xc1, 0xe2, 0xad, 0xe1, 0xd6, 0x94, 0xf4, 0x1f, 0xc7, 0x1a, 0x83,
+        0x1d, 0x02, 0x68, 0xe9, 0x89, 0x15, 0x62, 0x11, 0x3d, 0x8a, 0x62, 0xad, 0xd1, 0xbf,
+    ];
+
+    #[test]
+    fn test_ecdsa_signature_on_secp256k1() {
+        sign_and_verify::<ECDSASignatureScheme>(&MESSAGE_HASH);
+        failed_verification::<ECDSASignatureScheme>(&MESSAGE_HASH, &[7u8; 32]);
+    }
+
+    #[test]
+    fn test_ecdsa_signature_test_vector() {
+        let signature_scheme = ECDSASignatureScheme::setup("ecdsa_signature_test_vector");
+
+        let private_key =
+            Fr::from_str("91225253027397101270059260515990221874496108017261222445699397644687913215777").unwrap();
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        assert_eq!(
+            Secp256k1Affine::new(
+                Fq::from_str("20149468923017862635785269351026469201343513335253737999994330121872194856517").unwrap(),
+                Fq::from_str("45558802482409728232371975206855032011893935284936184167394243449917294149765").unwrap(),
+                false
+            ),
+            public_key
+        );
+
+        let signature = ECDSASignature::new(
+            Fr::from_str("30366917054904991249349557757615711045386803376476662406910637249813244520136").unwrap(),
+            Fr::from_str("37544014275429169701540982636181096397351935915263210499287933874359616121705").unwrap(),
+        );
+        assert!(signature_scheme.verify(&public_key, &MESSAGE_HASH, &signature).unwrap());
+
+        // Ensure the high `s` is also accepted, and a different `r` is rejected.
+        let high_s_signature = ECDSASignature::new(signature.r(), -signature.s());
+        assert!(signature_scheme
+            .verify(&public_key, &MESSAGE_HASH, &high_s_signature)
+            .unwrap());
+        let invalid_signature = ECDSASignature::new(-signature.r(), signature.s());
+        assert!(!signature_scheme
+            .verify(&public_key, &MESSAGE_HASH, &invalid_signature)
+            .unwrap());
+
+        // Ensure a message that is not a 32-byte hash is rejected.
+        assert!(signature_scheme.verify(&public_key, b"sample", &signature).is_err());
+        assert!(signature_scheme
+            .sign(&private_key, b"sample", &mut thread_rng())
+            .is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_signature_serialization() {
+        let rng = &mut thread_rng();
+        let signature_scheme = ECDSASignatureScheme::setup("ecdsa_signature_serialization");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, &MESSAGE_HASH, rng).unwrap();
+        assert!(signature.s() <= -signature.s());
+
+        let candidate_signature = ECDSASignature::read_le(&signature.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(signature, candidate_signature);
+        assert!(signature_scheme
+            .verify(&public_key, &MESSAGE_HASH, &candidate_signature)
+            .unwrap());
+        assert!(public_key.is_on_curve());
+    }
+
+    #[test]
+    fn ecdsa_signature_scheme_serialization() {
+        signature_scheme_serialization::<ECDSASignatureScheme>();
+    }
+}
+
+mod schnorr {
+    use super::*;
+    use crate::{
+        crh::PoseidonCRH,
+        signature::{SchnorrSignature, SchnorrSignatureScheme},
+    };
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377G1Parameters, Fr},
+        secp256k1::Secp256k1Parameters,
+    };
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::ToBytes;
+
+    type TestCRH = PoseidonCRH<Fr, 8>;
+    type TestSignature = SchnorrSignatureScheme<Secp256k1Parameters, TestCRH>;
+
+    #[test]
+    fn test_schnorr_signature_on_secp256k1() {
+        let message = "Hi, I am a Schnorr signature!";
+        sign_and_verify::<TestSignature>(message.as_bytes());
+        failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+        sign_and_verify::<TestSignature>(&[]);
+    }
+
+    #[test]
+    fn test_schnorr_signature_on_bls12_377_g1() {
+        type TestSignature = SchnorrSignatureScheme<Bls12_377G1Parameters, TestCRH>;
+
+        let message = "Hi, I am a Schnorr signature!";
+        sign_and_verify::<TestSignature>(message.as_bytes());
+        failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+    }
+
+    #[test]
+    fn test_schnorr_signature_serialization() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("schnorr_signature_serialization");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+
+        let candidate_signature = SchnorrSignature::read_le(&signature.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(signature, candidate_signature);
+        assert!(signature_scheme
+            .verify(&public_key, b"message", &candidate_signature)
+            .unwrap());
+
+        // Ensure a signature does not verify under a different public key, or with a different nonce point.
+        let other_public_key = signature_scheme.generate_public_key(&signature_scheme.generate_private_key(rng));
+        assert!(!signature_scheme
+            .verify(&other_public_key, b"message", &signature)
+            .unwrap());
+        let invalid_signature = SchnorrSignature::new(-signature.r(), signature.s());
+        assert!(!signature_scheme
+            .verify(&public_key, b"message", &invalid_signature)
+            .unwrap());
+
+        // Ensure a nonce point at infinity is rejected.
+        let invalid_signature = SchnorrSignature::new(Default::default(), signature.s());
+        assert!(invalid_signature.r().is_zero());
+        assert!(!signature_scheme
+            .verify(&public_key, b"message", &invalid_signature)
+            .unwrap());
+    }
+
+    #[test]
+    fn schnorr_signature_scheme_serialization() {
+        signature_scheme_serialization::<TestSignature>();
+    }
+}
+
+mod winternitz {
+    use super::*;
+    use crate::{crh::PoseidonCRH, signature::WinternitzSignatureScheme};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::ToBytes;
+
+    type TestSignature = WinternitzSignatureScheme<PoseidonCRH<Fr, 3>>;
+
+    #[test]
+    fn test_winternitz_signature_on_poseidon() {
+        let message = "Hi, I am a Winternitz signature!";
+        sign_and_verify::<TestSignature>(message.as_bytes());
+        failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+
+        // Ensure messages longer than one chunk, and the empty message, can be signed.
+        sign_and_verify::<TestSignature>(&[7u8; 100]);
+        sign_and_verify::<TestSignature>(&[]);
+    }
+
+    #[test]
+    fn test_winternitz_signature_serialization() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("winternitz_signature_serialization");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+
+        let signature_bytes = signature.to_bytes_le().unwrap();
+        let candidate_signature = FromBytes::read_le(&signature_bytes[..]).unwrap();
+        assert_eq!(signature, candidate_signature);
+        assert!(signature_scheme
+            .verify(&public_key, b"message", &candidate_signature)
+            .unwrap());
+
+        // Ensure a signature does not verify under a different public key.
+        let other_public_key = signature_scheme.generate_public_key(&signature_scheme.generate_private_key(rng));
+        assert!(!signature_scheme
+            .verify(&other_public_key, b"message", &signature)
+            .unwrap());
+    }
+
+    #[test]
+    fn winternitz_signature_scheme_serialization() {
+        signature_scheme_serialization::<TestSignature>();
+    }
+}
+
+mod winternitz_tree {
+    use super::*;
+    use crate::{crh::PoseidonCRH, signature::WinternitzTreeSignatureScheme};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::ToBytes;
+
+    type TestSignature = WinternitzTreeSignatureScheme<PoseidonCRH<Fr, 3>, 4>;
+
+    #[test]
+    fn test_winternitz_tree_signature_on_poseidon() {
+        let message = "Hi, I am a Winternitz tree signature!";
+        sign_and_verify::<TestSignature>(message.as_bytes());
+        failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+    }
+
+    #[test]
+    fn test_winternitz_tree_signature_is_stateless() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("winternitz_tree_signature_is_stateless");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+
+        // Ensure the same message is always signed by the same leaf with the same signature.
+        let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+        assert_eq!(signature, signature_scheme.sign(&private_key, b"message", rng).unwrap());
+
+        // Ensure many messages may be signed under the same public key.
+        for i in 0..8u8 {
+            let message = [i; 8];
+            let signature = signature_scheme.sign(&private_key, &message, rng).unwrap();
+            assert!(signature_scheme.verify(&public_key, &message, &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_winternitz_tree_signature_serialization() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("winternitz_tree_signature_serialization");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+
+        let mut signature_bytes = signature.to_bytes_le().unwrap();
+        let candidate_signature = FromBytes::read_le(&signature_bytes[..]).unwrap();
+        assert_eq!(signature, candidate_signature);
+        assert!(signature_scheme
+            .verify(&public_key, b"message", &candidate_signature)
+            .unwrap());
+
+        // Ensure the signature does not verify for another leaf.
+        signature_bytes[0] ^= 1;
+        let other_leaf_signature = FromBytes::read_le(&signature_bytes[..]).unwrap();
+        assert!(!signature_scheme
+            .verify(&public_key, b"message", &other_leaf_signature)
+            .unwrap());
+
+        // Ensure a leaf index outside of the tree is rejected.
+        signature_bytes[0] |= 1 << 4;
+        assert!(<TestSignature as SignatureScheme>::Signature::read_le(&signature_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn winternitz_tree_signature_scheme_serialization() {
+        signature_scheme_serialization::<TestSignature>();
+    }
+}
+
+mod winternitz_account {
+    use super::*;
+    use crate::{crh::PoseidonCRH, signature::WinternitzAccountSignatureScheme, SignatureSchemeOperations};
+    use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsParameters as EdwardsBls12};
+    use snarkvm_utilities::ToBytes;
+
+    type TestSignature = WinternitzAccountSignatureScheme<EdwardsBls12, PoseidonCRH<Fr, 3>, 2>;
+
+    #[test]
+    fn test_winternitz_account_signature_on_edwards_bls12_377() {
+        let message = "Hi, I am a Winternitz account signature!";
+        sign_and_verify::<TestSignature>(message.as_bytes());
+        failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+    }
+
+    #[test]
+    fn test_winternitz_account_signature_operations() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("winternitz_account_signature_operations");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+
+        // Ensure the account keys derived from the private key match those recovered from the signature.
+        let (sk_sig, r_sig) = private_key;
+        let pk_sig_scalar = signature_scheme.pk_sig_scalar(&sk_sig).unwrap();
+        let pk_sig = signature_scheme.pk_sig(&signature).unwrap();
+        let pr_sig = signature_scheme.pr_sig(&signature).unwrap();
+        assert_eq!(signature_scheme.g_scalar_multiply(&pk_sig_scalar), pk_sig);
+        assert_eq!(signature_scheme.g_scalar_multiply(&r_sig), pr_sig);
+
+        // Ensure the public key is the sum of the account keys.
+        let sk_prf = signature_scheme.hash_to_scalar_field(&[pk_sig.x, pr_sig.x]);
+        assert_eq!(
+            pk_sig + pr_sig + signature_scheme.g_scalar_multiply(&sk_prf),
+            public_key
+        );
+    }
+
+    #[test]
+    fn test_winternitz_account_signature_serialization() {
+        let rng = &mut thread_rng();
+        let signature_scheme = TestSignature::setup("winternitz_account_signature_serialization");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+
+        let signature_bytes = signature.to_bytes_le().unwrap();
+        let candidate_signature = FromBytes::read_le(&signature_bytes[..]).unwrap();
+        assert_eq!(signature, candidate_signature);
+    }
+
+    #[test]
+    fn winternitz_account_signature_scheme_serialization() {
+        signature_scheme_serialization::<TestSignature>();
+    }
 }
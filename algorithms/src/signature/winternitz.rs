@@ -0,0 +1,326 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SignatureError, SignatureScheme, CRH};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The number of bits in each digit of the message digest.
+pub const WINTERNITZ_DIGIT_BITS: usize = 4;
+/// The number of digits signed from the message digest.
+pub const WINTERNITZ_MESSAGE_DIGITS: usize = 62;
+/// The number of digits signed from the checksum of the message digits.
+pub const WINTERNITZ_CHECKSUM_DIGITS: usize = 3;
+/// The number of hash chains in a key pair, which is one for each signed digit.
+pub const WINTERNITZ_NUM_CHAINS: usize = WINTERNITZ_MESSAGE_DIGITS + WINTERNITZ_CHECKSUM_DIGITS;
+
+/// The largest value of a digit, which is the number of steps in each hash chain.
+const MAX_DIGIT: usize = (1 << WINTERNITZ_DIGIT_BITS) - 1;
+
+// The domain separators of the hashes in the scheme.
+pub const WINTERNITZ_CHAIN_START_DOMAIN: u8 = 0;
+pub const WINTERNITZ_CHAIN_DOMAIN: u8 = 1;
+pub const WINTERNITZ_MESSAGE_DOMAIN: u8 = 2;
+pub const WINTERNITZ_PUBLIC_KEY_DOMAIN: u8 = 3;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WinternitzPrivateKey {
+    seed: [u8; 32],
+}
+
+impl WinternitzPrivateKey {
+    /// Returns the private key with the given seed.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the seed from which the start of each hash chain is derived.
+    pub(crate) fn seed(&self) -> &[u8; 32] {
+        &self.seed
+    }
+}
+
+impl FromBytes for WinternitzPrivateKey {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            seed: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl ToBytes for WinternitzPrivateKey {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.seed.write_le(&mut writer)
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "H: CRH"),
+    Debug(bound = "H: CRH"),
+    PartialEq(bound = "H: CRH"),
+    Eq(bound = "H: CRH")
+)]
+pub struct WinternitzSignature<H: CRH> {
+    chain_values: Vec<H::Output>,
+}
+
+impl<H: CRH> WinternitzSignature<H> {
+    /// Returns the value of each hash chain at the position of its signed digit.
+    #[inline]
+    pub fn chain_values(&self) -> &[H::Output] {
+        &self.chain_values
+    }
+}
+
+impl<H: CRH> Default for WinternitzSignature<H> {
+    fn default() -> Self {
+        Self {
+            chain_values: vec![H::Output::default(); WINTERNITZ_NUM_CHAINS],
+        }
+    }
+}
+
+impl<H: CRH> FromBytes for WinternitzSignature<H> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut chain_values = Vec::with_capacity(WINTERNITZ_NUM_CHAINS);
+        for _ in 0..WINTERNITZ_NUM_CHAINS {
+            chain_values.push(FromBytes::read_le(&mut reader)?);
+        }
+        Ok(Self { chain_values })
+    }
+}
+
+impl<H: CRH> ToBytes for WinternitzSignature<H> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.chain_values.write_le(&mut writer)
+    }
+}
+
+///
+/// A Winternitz one-time signature scheme over a collision-resistant hash function.
+///
+/// The security of the scheme relies only on the hash function, which makes it a candidate for
+/// post-quantum accounts. Each private key must sign at most one message, as the signatures of two
+/// messages reveal enough of the hash chains to forge signatures on other messages.
+///
+/// The hash function is generic so that an algebraic hash, such as Poseidon, may be used to verify
+/// signatures in a circuit. Every hash in the scheme takes at most 65 bytes of input.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "H: CRH"), Debug(bound = "H: CRH"))]
+pub struct WinternitzSignatureScheme<H: CRH> {
+    crh: H,
+}
+
+impl<H: CRH> WinternitzSignatureScheme<H> {
+    /// Returns the hash function of the scheme.
+    pub fn crh(&self) -> &H {
+        &self.crh
+    }
+
+    /// Returns the start of the hash chain with the given index.
+    fn chain_start(&self, private_key: &WinternitzPrivateKey, index: usize) -> Result<H::Output> {
+        let mut preimage = private_key.seed.to_vec();
+        preimage.extend_from_slice(&[WINTERNITZ_CHAIN_START_DOMAIN, index as u8]);
+        Ok(self.crh.hash(&preimage)?)
+    }
+
+    /// Returns the value of the hash chain with the given index, the given number of steps after the given value.
+    fn chain(&self, index: usize, value: H::Output, steps: usize) -> Result<H::Output> {
+        let mut value = value;
+        for _ in 0..steps {
+            let mut preimage = value.to_bytes_le()?;
+            preimage.extend_from_slice(&[WINTERNITZ_CHAIN_DOMAIN, index as u8]);
+            value = self.crh.hash(&preimage)?;
+        }
+        Ok(value)
+    }
+
+    /// Returns the hash of the given digest, domain separator, and input.
+    fn compress(&self, digest: H::Output, domain: u8, input: &[u8]) -> Result<H::Output> {
+        let mut preimage = digest.to_bytes_le()?;
+        preimage.push(domain);
+        preimage.extend_from_slice(input);
+        Ok(self.crh.hash(&preimage)?)
+    }
+
+    /// Returns the public key for the given ends of the hash chains.
+    fn compress_chain_ends(&self, chain_ends: &[H::Output]) -> Result<H::Output> {
+        let mut public_key = H::Output::default();
+        for chain_end in chain_ends {
+            public_key = self.compress(public_key, WINTERNITZ_PUBLIC_KEY_DOMAIN, &chain_end.to_bytes_le()?)?;
+        }
+        Ok(public_key)
+    }
+
+    /// Returns the digest of the given message, as the hash of its length followed by its chunks of 32 bytes.
+    pub(crate) fn hash_message(&self, message: &[u8]) -> Result<H::Output> {
+        let mut digest = self.compress(
+            H::Output::default(),
+            WINTERNITZ_MESSAGE_DOMAIN,
+            &(message.len() as u32).to_le_bytes(),
+        )?;
+        for chunk in message.chunks(32) {
+            digest = self.compress(digest, WINTERNITZ_MESSAGE_DOMAIN, chunk)?;
+        }
+        Ok(digest)
+    }
+
+    /// Returns the digits to sign for the given message, which are the digits of its hash followed by the checksum.
+    fn to_digits(&self, message: &[u8]) -> Result<Vec<usize>> {
+        let digest_bytes = self.hash_message(message)?.to_bytes_le()?;
+        if digest_bytes.len() * 8 < WINTERNITZ_MESSAGE_DIGITS * WINTERNITZ_DIGIT_BITS {
+            return Err(
+                SignatureError::Message("The hash output is too small for a Winternitz signature".into()).into(),
+            );
+        }
+
+        let mut digits = Vec::with_capacity(WINTERNITZ_NUM_CHAINS);
+        for byte in &digest_bytes[..WINTERNITZ_MESSAGE_DIGITS / 2] {
+            digits.push((byte & 0x0f) as usize);
+            digits.push((byte >> 4) as usize);
+        }
+
+        // The checksum ensures the digits of a forged message cannot all be larger than those of the signed message.
+        let checksum: usize = digits.iter().map(|digit| MAX_DIGIT - digit).sum();
+        for i in 0..WINTERNITZ_CHECKSUM_DIGITS {
+            digits.push((checksum >> (i * WINTERNITZ_DIGIT_BITS)) & MAX_DIGIT);
+        }
+
+        Ok(digits)
+    }
+
+    ///
+    /// Returns the public key under which the given signature verifies for the given message,
+    /// or `None` if the signature is malformed.
+    ///
+    pub fn recover_public_key(&self, message: &[u8], signature: &WinternitzSignature<H>) -> Result<Option<H::Output>> {
+        if signature.chain_values.len() != WINTERNITZ_NUM_CHAINS {
+            return Ok(None);
+        }
+
+        // Complete each hash chain from the position of its digit.
+        let chain_ends = self
+            .to_digits(message)?
+            .into_iter()
+            .zip(&signature.chain_values)
+            .enumerate()
+            .map(|(index, (digit, value))| self.chain(index, *value, MAX_DIGIT - digit))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(self.compress_chain_ends(&chain_ends)?))
+    }
+}
+
+impl<H: CRH> SignatureScheme for WinternitzSignatureScheme<H> {
+    type Parameters = H::Parameters;
+    type PrivateKey = WinternitzPrivateKey;
+    type PublicKey = H::Output;
+    type Signature = WinternitzSignature<H>;
+
+    fn setup(message: &str) -> Self {
+        Self { crh: H::setup(message) }
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        self.crh.parameters()
+    }
+
+    ///
+    /// Returns a private key as a random seed, from which the start of each hash chain is derived.
+    ///
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        WinternitzPrivateKey { seed: rng.gen() }
+    }
+
+    ///
+    /// Returns the public key as the hash of the ends of the hash chains.
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        let chain_ends = (0..WINTERNITZ_NUM_CHAINS)
+            .map(|index| self.chain(index, self.chain_start(private_key, index)?, MAX_DIGIT))
+            .collect::<Result<Vec<_>>>()
+            .expect("Failed to compute the Winternitz hash chains");
+        self.compress_chain_ends(&chain_ends)
+            .expect("Failed to compute the Winternitz public key")
+    }
+
+    ///
+    /// Returns the signature as the value of each hash chain at the position of its digit.
+    ///
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        _rng: &mut R,
+    ) -> Result<Self::Signature> {
+        let chain_values = self
+            .to_digits(message)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, digit)| self.chain(index, self.chain_start(private_key, index)?, digit))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(WinternitzSignature { chain_values })
+    }
+
+    fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool> {
+        Ok(self.recover_public_key(message, signature)? == Some(*public_key))
+    }
+}
+
+impl<H: CRH> From<H::Parameters> for WinternitzSignatureScheme<H> {
+    fn from(parameters: H::Parameters) -> Self {
+        Self {
+            crh: H::from(parameters),
+        }
+    }
+}
+
+impl<H: CRH> PartialEq for WinternitzSignatureScheme<H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.crh.parameters() == other.crh.parameters()
+    }
+}
+
+impl<H: CRH> Eq for WinternitzSignatureScheme<H> {}
+
+impl<H: CRH> FromBytes for WinternitzSignatureScheme<H> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            crh: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<H: CRH> ToBytes for WinternitzSignatureScheme<H> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.crh.write_le(&mut writer)
+    }
+}
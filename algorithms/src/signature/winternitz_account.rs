@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::PoseidonDefaultParametersField,
+    signature::{AleoSignatureScheme, WinternitzPrivateKey, WinternitzTreeSignature, WinternitzTreeSignatureScheme},
+    SignatureError,
+    SignatureScheme,
+    SignatureSchemeOperations,
+    CRH,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters, H: CRH"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters, H: CRH"),
+    Eq(bound = "TE: TwistedEdwardsParameters, H: CRH"),
+    Debug(bound = "TE: TwistedEdwardsParameters, H: CRH"),
+    Default(bound = "TE: TwistedEdwardsParameters, H: CRH")
+)]
+pub struct WinternitzAccountSignature<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> {
+    tree_signature: WinternitzTreeSignature<H, DEPTH>,
+    tree_root: H::Output,
+    root_randomizer: TE::BaseField,
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> WinternitzAccountSignature<TE, H, DEPTH> {
+    /// Returns the signature of the message under the tree of one-time keys.
+    #[inline]
+    pub fn tree_signature(&self) -> &WinternitzTreeSignature<H, DEPTH> {
+        &self.tree_signature
+    }
+
+    /// Returns the public key of the root of the tree of one-time keys.
+    #[inline]
+    pub fn tree_root(&self) -> &H::Output {
+        &self.tree_root
+    }
+
+    #[inline]
+    pub fn root_randomizer(&self) -> Result<TEAffine<TE>> {
+        if let Some(element) = TEAffine::<TE>::from_x_coordinate(self.root_randomizer, true) {
+            if element.is_in_correct_subgroup_assuming_on_curve() {
+                return Ok(element);
+            }
+        }
+
+        if let Some(element) = TEAffine::<TE>::from_x_coordinate(self.root_randomizer, false) {
+            if element.is_in_correct_subgroup_assuming_on_curve() {
+                return Ok(element);
+            }
+        }
+
+        Err(SignatureError::Message("Failed to read the signature root randomizer".into()).into())
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> FromBytes for WinternitzAccountSignature<TE, H, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            tree_signature: FromBytes::read_le(&mut reader)?,
+            tree_root: FromBytes::read_le(&mut reader)?,
+            root_randomizer: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> ToBytes for WinternitzAccountSignature<TE, H, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.tree_signature.write_le(&mut writer)?;
+        self.tree_root.write_le(&mut writer)?;
+        self.root_randomizer.write_le(&mut writer)
+    }
+}
+
+///
+/// A hash-based account signature scheme, which signs messages with a `WinternitzTreeSignatureScheme`
+/// and derives the account keys as in `AleoSignatureScheme`.
+///
+/// The signature root public key is `pk_sig := G^s`, where `s` is a hash of the root of the tree of one-time keys,
+/// and the tree is seeded by `sk_sig`. The address is `pk_sig * G^r_sig * G^sk_prf` as in `AleoSignatureScheme`,
+/// so the account compute key and view key are unchanged, while the authority to spend records rests on the
+/// hash-based signature of the transaction.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters, H: CRH"),
+    Debug(bound = "TE: TwistedEdwardsParameters, H: CRH")
+)]
+pub struct WinternitzAccountSignatureScheme<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    account: AleoSignatureScheme<TE>,
+    tree: WinternitzTreeSignatureScheme<H, DEPTH>,
+    parameters: (Vec<TEProjective<TE>>, H::Parameters),
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the scheme from which the account keys are derived.
+    pub fn account_scheme(&self) -> &AleoSignatureScheme<TE> {
+        &self.account
+    }
+
+    /// Returns the scheme with which messages are signed.
+    pub fn tree_scheme(&self) -> &WinternitzTreeSignatureScheme<H, DEPTH> {
+        &self.tree
+    }
+
+    /// Returns the private key of the root of the tree of one-time keys, which is seeded by the signature secret key.
+    fn tree_private_key(&self, sk_sig: &TE::ScalarField) -> Result<WinternitzPrivateKey> {
+        let hash = self
+            .tree
+            .one_time_scheme()
+            .crh()
+            .hash(&sk_sig.to_bytes_le()?)?
+            .to_bytes_le()?;
+        if hash.len() < 32 {
+            return Err(SignatureError::Message("The hash output is too small for a Winternitz tree".into()).into());
+        }
+
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&hash[..32]);
+        Ok(WinternitzPrivateKey::from_seed(seed))
+    }
+
+    /// Returns the scalar `s` of the signature root public key `G^s` for the given root of the tree of one-time keys.
+    fn tree_root_to_scalar(&self, tree_root: &H::Output) -> Result<TE::ScalarField> {
+        Ok(self
+            .account
+            .hash_to_scalar_field(&tree_root.to_bytes_le()?.to_field_elements()?))
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> SignatureScheme
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    type Parameters = (Vec<TEProjective<TE>>, H::Parameters);
+    type PrivateKey = (TE::ScalarField, TE::ScalarField);
+    type PublicKey = TEAffine<TE>;
+    type Signature = WinternitzAccountSignature<TE, H, DEPTH>;
+
+    fn setup(message: &str) -> Self {
+        let account = AleoSignatureScheme::setup(message);
+        let tree = WinternitzTreeSignatureScheme::setup(message);
+        let parameters = (account.parameters().clone(), tree.parameters().clone());
+
+        Self {
+            account,
+            tree,
+            parameters,
+        }
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.parameters
+    }
+
+    ///
+    /// Returns private key as (sk_sig, r_sig).
+    ///
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        (TE::ScalarField::rand(rng), TE::ScalarField::rand(rng))
+    }
+
+    ///
+    /// Returns public key as (pk_sig G^r_sig G^sk_prf), where pk_sig := G^Hash(tree_root).
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        // Extract (sk_sig, r_sig).
+        let (sk_sig, r_sig) = private_key;
+
+        // Compute pk_sig.
+        let pk_sig_scalar = self
+            .pk_sig_scalar(sk_sig)
+            .expect("Failed to derive the Winternitz tree root");
+        let pk_sig = self.g_scalar_multiply(&pk_sig_scalar);
+
+        // Compute G^r_sig.
+        let g_r_sig = self.g_scalar_multiply(r_sig);
+
+        // Compute sk_prf := RO(pk_sig || G^r_sig).
+        let sk_prf = self.hash_to_scalar_field(&[pk_sig.x, g_r_sig.x]);
+
+        // Compute G^sk_prf.
+        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
+
+        // Compute pk_sig G^r_sig G^sk_prf.
+        pk_sig + g_r_sig + g_sk_prf
+    }
+
+    ///
+    /// Returns signature (tree_signature, tree_root, G^r_sig), where tree_signature is the signature
+    /// of the message under the tree of one-time keys seeded by sk_sig.
+    ///
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Signature> {
+        // Extract (sk_sig, r_sig).
+        let (sk_sig, r_sig) = private_key;
+
+        // Sign the message under the tree of one-time keys.
+        let tree_private_key = self.tree_private_key(sk_sig)?;
+        let tree_root = self.tree.generate_public_key(&tree_private_key);
+        let tree_signature = self.tree.sign(&tree_private_key, message, rng)?;
+
+        // Compute G^r_sig.
+        let g_r_sig = self.g_scalar_multiply(r_sig);
+
+        Ok(WinternitzAccountSignature {
+            tree_signature,
+            tree_root,
+            root_randomizer: g_r_sig.x,
+        })
+    }
+
+    ///
+    /// Verifies the tree signature under tree_root, and (public_key == pk_sig G^r_sig G^sk_prf) where:
+    ///     pk_sig := G^Hash(tree_root)
+    ///
+    fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool> {
+        // Recover pk_sig.
+        let pk_sig = self.pk_sig(signature)?;
+
+        // Recover G^r_sig.
+        let g_r_sig = self.pr_sig(signature)?;
+
+        // Compute the candidate public key as (pk_sig G^r_sig G^sk_prf).
+        let candidate_public_key = {
+            // Compute sk_prf := RO(pk_sig || G^r_sig).
+            let sk_prf = self.hash_to_scalar_field(&[pk_sig.x, g_r_sig.x]);
+
+            // Compute G^sk_prf.
+            let g_sk_prf = self.g_scalar_multiply(&sk_prf);
+
+            // Compute pk_sig G^r_sig G^sk_prf.
+            pk_sig + g_r_sig + g_sk_prf
+        };
+
+        Ok(*public_key == candidate_public_key
+            && self
+                .tree
+                .verify(&signature.tree_root, message, &signature.tree_signature)?)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> SignatureSchemeOperations
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    type AffineCurve = TEAffine<TE>;
+    type BaseField = TE::BaseField;
+    type ScalarField = TE::ScalarField;
+    type Signature = WinternitzAccountSignature<TE, H, DEPTH>;
+
+    fn pk_sig_scalar(&self, sk_sig: &Self::ScalarField) -> Result<Self::ScalarField> {
+        let tree_root = self.tree.generate_public_key(&self.tree_private_key(sk_sig)?);
+        self.tree_root_to_scalar(&tree_root)
+    }
+
+    fn pk_sig(&self, signature: &Self::Signature) -> Result<Self::AffineCurve> {
+        Ok(self.g_scalar_multiply(&self.tree_root_to_scalar(&signature.tree_root)?))
+    }
+
+    fn pr_sig(&self, signature: &Self::Signature) -> Result<Self::AffineCurve> {
+        signature.root_randomizer()
+    }
+
+    fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> Self::AffineCurve {
+        self.account.g_scalar_multiply(scalar)
+    }
+
+    fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField {
+        self.account.hash_to_scalar_field(input)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> From<(Vec<TEProjective<TE>>, H::Parameters)>
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn from(parameters: (Vec<TEProjective<TE>>, H::Parameters)) -> Self {
+        Self {
+            account: AleoSignatureScheme::from(parameters.0.clone()),
+            tree: WinternitzTreeSignatureScheme::from(parameters.1.clone()),
+            parameters,
+        }
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> PartialEq
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.account == other.account && self.tree == other.tree
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> Eq
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> ToBytes
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.account.write_le(&mut writer)?;
+        self.tree.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, H: CRH, const DEPTH: usize> FromBytes
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let account: AleoSignatureScheme<TE> = FromBytes::read_le(&mut reader)?;
+        let tree: WinternitzTreeSignatureScheme<H, DEPTH> = FromBytes::read_le(&mut reader)?;
+        let parameters = (account.parameters().clone(), tree.parameters().clone());
+
+        Ok(Self {
+            account,
+            tree,
+            parameters,
+        })
+    }
+}
+
+impl<F: Field, TE: TwistedEdwardsParameters + ToConstraintField<F>, H: CRH, const DEPTH: usize> ToConstraintField<F>
+    for WinternitzAccountSignatureScheme<TE, H, DEPTH>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        Ok(Vec::new())
+    }
+}
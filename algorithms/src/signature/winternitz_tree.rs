@@ -0,0 +1,337 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    signature::{WinternitzPrivateKey, WinternitzSignature, WinternitzSignatureScheme},
+    SignatureError,
+    SignatureScheme,
+    CRH,
+};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+// The domain separators of the hashes in the scheme, which follow those of the one-time scheme.
+pub const WINTERNITZ_TREE_NODE_DOMAIN: u8 = 4;
+pub const WINTERNITZ_TREE_LEAF_INDEX_DOMAIN: u8 = 5;
+
+/// Returns the mask of the leaf indices in a tree of the given depth.
+#[inline]
+fn leaf_index_mask(depth: usize) -> u64 {
+    u64::MAX >> (64 - depth)
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "H: CRH"),
+    Debug(bound = "H: CRH"),
+    PartialEq(bound = "H: CRH"),
+    Eq(bound = "H: CRH")
+)]
+pub struct WinternitzTreeSignature<H: CRH, const DEPTH: usize> {
+    /// The index of the leaf whose one-time key signs the message.
+    leaf_index: u64,
+    /// The one-time signature of the message by the leaf.
+    message_signature: WinternitzSignature<H>,
+    /// For each node from the parent of the leaf up to the root, the one-time signature of the public keys
+    /// of its children, and the public key of its child that is not on the path.
+    path: Vec<(WinternitzSignature<H>, H::Output)>,
+}
+
+impl<H: CRH, const DEPTH: usize> WinternitzTreeSignature<H, DEPTH> {
+    /// Returns the index of the leaf whose one-time key signs the message.
+    #[inline]
+    pub fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// Returns the one-time signature of the message by the leaf.
+    #[inline]
+    pub fn message_signature(&self) -> &WinternitzSignature<H> {
+        &self.message_signature
+    }
+
+    /// Returns the one-time signature and sibling public key of each node from the parent of the leaf up to the root.
+    #[inline]
+    pub fn path(&self) -> &[(WinternitzSignature<H>, H::Output)] {
+        &self.path
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> Default for WinternitzTreeSignature<H, DEPTH> {
+    fn default() -> Self {
+        Self {
+            leaf_index: 0,
+            message_signature: Default::default(),
+            path: vec![Default::default(); DEPTH],
+        }
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> FromBytes for WinternitzTreeSignature<H, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let () = WinternitzTreeSignatureScheme::<H, DEPTH>::CHECK_DEPTH;
+
+        let leaf_index: u64 = FromBytes::read_le(&mut reader)?;
+        if leaf_index & !leaf_index_mask(DEPTH) != 0 {
+            return Err(SignatureError::Message(format!("Leaf index {} is out of range", leaf_index)).into());
+        }
+
+        let message_signature = FromBytes::read_le(&mut reader)?;
+        let mut path = Vec::with_capacity(DEPTH);
+        for _ in 0..DEPTH {
+            path.push((FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?));
+        }
+
+        Ok(Self {
+            leaf_index,
+            message_signature,
+            path,
+        })
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> ToBytes for WinternitzTreeSignature<H, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.leaf_index.write_le(&mut writer)?;
+        self.message_signature.write_le(&mut writer)?;
+        for (node_signature, sibling_public_key) in &self.path {
+            node_signature.write_le(&mut writer)?;
+            sibling_public_key.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// A stateless hash-based signature scheme, from a binary tree of `2^DEPTH` Winternitz one-time keys.
+///
+/// The one-time key of each node is derived from the seed of its parent, and signs the public keys of its two
+/// children, so every node signature is deterministic and a node key never signs two different messages.
+/// Each message is signed by a leaf selected by a hash of the private key and the message, so the signer
+/// keeps no state. A leaf signs two different messages with probability about `q^2 / 2^(DEPTH + 1)`
+/// after `q` signatures, so the depth should be chosen with the expected number of signatures in mind,
+/// e.g. a depth of 64 for an account.
+///
+/// The public key is the public key of the root.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "H: CRH"), Debug(bound = "H: CRH"))]
+pub struct WinternitzTreeSignatureScheme<H: CRH, const DEPTH: usize> {
+    one_time: WinternitzSignatureScheme<H>,
+}
+
+impl<H: CRH, const DEPTH: usize> WinternitzTreeSignatureScheme<H, DEPTH> {
+    /// Fails to compile when referenced by an instantiation with a depth of 0 or above 64.
+    const CHECK_DEPTH: () = assert!(
+        DEPTH > 0 && DEPTH <= 64,
+        "Winternitz tree depth must be between 1 and 64"
+    );
+
+    /// Returns the one-time signature scheme of the nodes.
+    pub fn one_time_scheme(&self) -> &WinternitzSignatureScheme<H> {
+        &self.one_time
+    }
+
+    /// Returns the private key of the child of the given node on the given side.
+    fn child_private_key(&self, private_key: &WinternitzPrivateKey, is_right: bool) -> Result<WinternitzPrivateKey> {
+        let mut preimage = private_key.seed().to_vec();
+        preimage.extend_from_slice(&[WINTERNITZ_TREE_NODE_DOMAIN, is_right as u8]);
+        let hash = self.one_time.crh().hash(&preimage)?.to_bytes_le()?;
+        if hash.len() < 32 {
+            return Err(SignatureError::Message("The hash output is too small for a Winternitz tree".into()).into());
+        }
+
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&hash[..32]);
+        Ok(WinternitzPrivateKey::from_seed(seed))
+    }
+
+    /// Returns the index of the leaf that signs the given message.
+    fn leaf_index(&self, private_key: &WinternitzPrivateKey, message: &[u8]) -> Result<u64> {
+        let mut preimage = private_key.seed().to_vec();
+        preimage.push(WINTERNITZ_TREE_LEAF_INDEX_DOMAIN);
+        preimage.extend_from_slice(&self.one_time.hash_message(message)?.to_bytes_le()?);
+        let hash = self.one_time.crh().hash(&preimage)?.to_bytes_le()?;
+        if hash.len() < 8 {
+            return Err(SignatureError::Message("The hash output is too small for a Winternitz tree".into()).into());
+        }
+
+        let mut index_bytes = [0u8; 8];
+        index_bytes.copy_from_slice(&hash[..8]);
+        Ok(u64::from_le_bytes(index_bytes) & leaf_index_mask(DEPTH))
+    }
+
+    /// Returns the message signed by a node, which is the public keys of its left and right children.
+    pub(crate) fn children_message(left: &H::Output, right: &H::Output) -> Result<Vec<u8>> {
+        let mut message = left.to_bytes_le()?;
+        message.extend_from_slice(&right.to_bytes_le()?);
+        Ok(message)
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> SignatureScheme for WinternitzTreeSignatureScheme<H, DEPTH> {
+    type Parameters = H::Parameters;
+    type PrivateKey = WinternitzPrivateKey;
+    type PublicKey = H::Output;
+    type Signature = WinternitzTreeSignature<H, DEPTH>;
+
+    fn setup(message: &str) -> Self {
+        let () = Self::CHECK_DEPTH;
+
+        Self {
+            one_time: WinternitzSignatureScheme::setup(message),
+        }
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        self.one_time.parameters()
+    }
+
+    ///
+    /// Returns a private key as a random seed, which is the seed of the root.
+    ///
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        self.one_time.generate_private_key(rng)
+    }
+
+    ///
+    /// Returns the public key as the one-time public key of the root.
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        self.one_time.generate_public_key(private_key)
+    }
+
+    ///
+    /// Returns the signature as the one-time signature of the message by its leaf, followed by
+    /// the one-time signature of the children of each node from the leaf up to the root.
+    ///
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Signature> {
+        let leaf_index = self.leaf_index(private_key, message)?;
+
+        // Walk down from the root to the leaf, signing the children of each node.
+        let mut node_private_key = private_key.clone();
+        let mut path = Vec::with_capacity(DEPTH);
+        for level in 0..DEPTH {
+            let is_right = (leaf_index >> (DEPTH - 1 - level)) & 1 == 1;
+
+            let left_private_key = self.child_private_key(&node_private_key, false)?;
+            let right_private_key = self.child_private_key(&node_private_key, true)?;
+            let left_public_key = self.one_time.generate_public_key(&left_private_key);
+            let right_public_key = self.one_time.generate_public_key(&right_private_key);
+
+            let children_message = Self::children_message(&left_public_key, &right_public_key)?;
+            let node_signature = self.one_time.sign(&node_private_key, &children_message, rng)?;
+
+            match is_right {
+                true => {
+                    path.push((node_signature, left_public_key));
+                    node_private_key = right_private_key;
+                }
+                false => {
+                    path.push((node_signature, right_public_key));
+                    node_private_key = left_private_key;
+                }
+            }
+        }
+        path.reverse();
+
+        let message_signature = self.one_time.sign(&node_private_key, message, rng)?;
+
+        Ok(WinternitzTreeSignature {
+            leaf_index,
+            message_signature,
+            path,
+        })
+    }
+
+    fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool> {
+        if signature.path.len() != DEPTH || signature.leaf_index & !leaf_index_mask(DEPTH) != 0 {
+            return Ok(false);
+        }
+
+        // Recover the public key of the leaf.
+        let mut candidate_public_key = match self
+            .one_time
+            .recover_public_key(message, &signature.message_signature)?
+        {
+            Some(public_key) => public_key,
+            None => return Ok(false),
+        };
+
+        // Walk up from the leaf to the root, recovering the public key of each node.
+        for (height, (node_signature, sibling_public_key)) in signature.path.iter().enumerate() {
+            let children_message = match (signature.leaf_index >> height) & 1 == 1 {
+                true => Self::children_message(sibling_public_key, &candidate_public_key)?,
+                false => Self::children_message(&candidate_public_key, sibling_public_key)?,
+            };
+            candidate_public_key = match self.one_time.recover_public_key(&children_message, node_signature)? {
+                Some(public_key) => public_key,
+                None => return Ok(false),
+            };
+        }
+
+        Ok(candidate_public_key == *public_key)
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> From<H::Parameters> for WinternitzTreeSignatureScheme<H, DEPTH> {
+    fn from(parameters: H::Parameters) -> Self {
+        let () = Self::CHECK_DEPTH;
+
+        Self {
+            one_time: WinternitzSignatureScheme::from(parameters),
+        }
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> PartialEq for WinternitzTreeSignatureScheme<H, DEPTH> {
+    fn eq(&self, other: &Self) -> bool {
+        self.one_time == other.one_time
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> Eq for WinternitzTreeSignatureScheme<H, DEPTH> {}
+
+impl<H: CRH, const DEPTH: usize> FromBytes for WinternitzTreeSignatureScheme<H, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let () = Self::CHECK_DEPTH;
+
+        Ok(Self {
+            one_time: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> ToBytes for WinternitzTreeSignatureScheme<H, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.one_time.write_le(&mut writer)
+    }
+}
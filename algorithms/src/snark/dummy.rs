@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A SNARK that skips proving and verification, for application development.
+//!
+//! Proofs produced by [`DummySNARK`] carry a validity flag that verification trusts.
+//! They have their own proof type, so they cannot be mistaken for production proofs.
+
+use crate::{SNARKError, SNARKProtocolID, SNARK, SRS};
+use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use rand::{CryptoRng, Rng};
+use std::{
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A proof produced by the dummy SNARK.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DummyProof {
+    /// `true` if the proof is accepted by the verifier.
+    is_valid: bool,
+    /// A random nonce, so that distinct proofs have distinct bytes.
+    nonce: u64,
+}
+
+impl DummyProof {
+    /// Initializes a new instance of a dummy proof.
+    pub fn new(is_valid: bool, nonce: u64) -> Self {
+        Self { is_valid, nonce }
+    }
+
+    /// Returns `true` if the proof is accepted by the verifier.
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+}
+
+impl ToBytes for DummyProof {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.is_valid.write_le(&mut writer)?;
+        self.nonce.write_le(&mut writer)
+    }
+}
+
+impl FromBytes for DummyProof {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let is_valid = bool::read_le(&mut reader)?;
+        let nonce = u64::read_le(&mut reader)?;
+        Ok(Self { is_valid, nonce })
+    }
+}
+
+///
+/// A SNARK with the keys of `S` that does not synthesize the circuit when proving,
+/// and trusts the validity flag of the proof when verifying.
+///
+/// The proving key is the verifying key of `S`, so that large proving keys are never loaded.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DummySNARK<S: SNARK> {
+    _phantom: PhantomData<S>,
+}
+
+impl<S: SNARK> SNARK for DummySNARK<S> {
+    const PROTOCOL_ID: SNARKProtocolID = SNARKProtocolID::Dummy;
+
+    type BaseField = S::BaseField;
+    type PreparedVerifyingKey = S::PreparedVerifyingKey;
+    type Proof = DummyProof;
+    type ProvingKey = S::VerifyingKey;
+    type ScalarField = S::ScalarField;
+    type UniversalSetupConfig = S::UniversalSetupConfig;
+    type UniversalSetupParameters = S::UniversalSetupParameters;
+    type VerifierInput = S::VerifierInput;
+    type VerifyingKey = S::VerifyingKey;
+
+    fn universal_setup<R: Rng + CryptoRng>(
+        config: &Self::UniversalSetupConfig,
+        rng: &mut R,
+    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        S::universal_setup(config, rng)
+    }
+
+    fn setup<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        let (_, verifying_key) = S::setup(circuit, srs)?;
+        Ok((verifying_key.clone(), verifying_key))
+    }
+
+    fn prove_with_terminator<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        _proving_key: &Self::ProvingKey,
+        _input_and_witness: &C,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        match terminator.load(Ordering::SeqCst) {
+            true => Err(SNARKError::Terminated),
+            false => Ok(DummyProof::new(true, rng.gen())),
+        }
+    }
+
+    fn verify_prepared(
+        _prepared_verifying_key: &Self::PreparedVerifyingKey,
+        _input: &Self::VerifierInput,
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        Ok(proof.is_valid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snark::groth16::Groth16;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+    use rand::thread_rng;
+
+    struct TestCircuit;
+
+    impl ConstraintSynthesizer<Fr> for TestCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let x = cs.alloc_input(|| "x", || Ok(Fr::from(1u64)))?;
+            let y = cs.alloc(|| "y", || Ok(Fr::from(1u64)))?;
+            cs.enforce(|| "x * y = x", |lc| lc + x, |lc| lc + y, |lc| lc + x);
+            Ok(())
+        }
+    }
+
+    type TestSNARK = DummySNARK<Groth16<Bls12_377, Vec<Fr>>>;
+
+    #[test]
+    fn test_dummy_snark() {
+        let rng = &mut thread_rng();
+
+        let (proving_key, verifying_key) = TestSNARK::setup(&TestCircuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+        let proof = TestSNARK::prove(&proving_key, &TestCircuit, rng).unwrap();
+        assert!(TestSNARK::verify(&verifying_key, &vec![Fr::from(1u64)], &proof).unwrap());
+        assert!(!TestSNARK::verify(&verifying_key, &vec![], &DummyProof::new(false, 0)).unwrap());
+
+        let candidate = DummyProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(proof, candidate);
+
+        assert!(TestSNARK::prove_with_terminator(&proving_key, &TestCircuit, &AtomicBool::new(true), rng).is_err());
+    }
+}
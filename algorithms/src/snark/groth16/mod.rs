@@ -26,6 +26,7 @@ use snarkvm_utilities::{
     fmt,
     serialize::*,
     str::FromStr,
+    vec_with_bounded_capacity,
     FromBytes,
     FromBytesDeserializer,
     ToBytes,
@@ -255,13 +256,15 @@ impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
     }
 }
 
+/// The version 1 encoding of a verifying key is the concatenation of the encodings of
+/// `alpha_g1`, `beta_g2`, `gamma_g2`, `delta_g2`, and each element of `gamma_abc_g1`.
 impl<E: PairingEngine> ToMinimalBits for VerifyingKey<E> {
-    fn to_minimal_bits(&self) -> Vec<bool> {
-        let alpha_g1_bits = self.alpha_g1.to_minimal_bits();
-        let beta_g2_bits = self.beta_g2.to_minimal_bits();
-        let gamma_g2_bits = self.gamma_g2.to_minimal_bits();
-        let delta_g2_bits = self.delta_g2.to_minimal_bits();
-        let gamma_abc_g1_bits = self.gamma_abc_g1.to_minimal_bits();
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
+        let alpha_g1_bits = self.alpha_g1.to_minimal_bits_v1();
+        let beta_g2_bits = self.beta_g2.to_minimal_bits_v1();
+        let gamma_g2_bits = self.gamma_g2.to_minimal_bits_v1();
+        let delta_g2_bits = self.delta_g2.to_minimal_bits_v1();
+        let gamma_abc_g1_bits = self.gamma_abc_g1.to_minimal_bits_v1();
 
         [
             alpha_g1_bits,
@@ -329,7 +332,7 @@ impl<E: PairingEngine> VerifyingKey<E> {
         let delta_g2: E::G2Affine = FromBytes::read_le(&mut reader)?;
 
         let gamma_abc_g1_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut gamma_abc_g1: Vec<E::G1Affine> = Vec::with_capacity(gamma_abc_g1_len as usize);
+        let mut gamma_abc_g1: Vec<E::G1Affine> = vec_with_bounded_capacity(gamma_abc_g1_len as usize);
         for _ in 0..gamma_abc_g1_len {
             let gamma_abc_g1_element: E::G1Affine = FromBytes::read_le(&mut reader)?;
             gamma_abc_g1.push(gamma_abc_g1_element);
@@ -445,31 +448,31 @@ impl<E: PairingEngine> ProvingKey<E> {
         let delta_g1: E::G1Affine = FromBytes::read_le(&mut reader)?;
 
         let a_query_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut a_query = Vec::with_capacity(a_query_len as usize);
+        let mut a_query = vec_with_bounded_capacity(a_query_len as usize);
         for _ in 0..a_query_len {
             a_query.push(read_g1_affine(&mut reader)?);
         }
 
         let b_g1_query_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut b_g1_query = Vec::with_capacity(b_g1_query_len as usize);
+        let mut b_g1_query = vec_with_bounded_capacity(b_g1_query_len as usize);
         for _ in 0..b_g1_query_len {
             b_g1_query.push(read_g1_affine(&mut reader)?);
         }
 
         let b_g2_query_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut b_g2_query = Vec::with_capacity(b_g2_query_len as usize);
+        let mut b_g2_query = vec_with_bounded_capacity(b_g2_query_len as usize);
         for _ in 0..b_g2_query_len {
             b_g2_query.push(read_g2_affine(&mut reader)?);
         }
 
         let h_query_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut h_query = Vec::with_capacity(h_query_len as usize);
+        let mut h_query = vec_with_bounded_capacity(h_query_len as usize);
         for _ in 0..h_query_len {
             h_query.push(read_g1_affine(&mut reader)?);
         }
 
         let l_query_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut l_query = Vec::with_capacity(l_query_len as usize);
+        let mut l_query = vec_with_bounded_capacity(l_query_len as usize);
         for _ in 0..l_query_len {
             l_query.push(read_g1_affine(&mut reader)?);
         }
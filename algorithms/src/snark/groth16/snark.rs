@@ -18,12 +18,13 @@ use super::{
     create_random_proof,
     generate_random_parameters,
     verify_proof,
+    verify_proofs,
     PreparedVerifyingKey,
     Proof,
     ProvingKey,
     VerifyingKey,
 };
-use crate::{SNARKError, SNARK, SRS};
+use crate::{SNARKError, SNARKProtocolID, SNARK, SRS};
 use snarkvm_curves::traits::PairingEngine;
 use snarkvm_fields::ToConstraintField;
 use snarkvm_r1cs::ConstraintSynthesizer;
@@ -39,6 +40,8 @@ pub struct Groth16<E: PairingEngine, V: ToConstraintField<E::Fr> + Clone> {
 }
 
 impl<E: PairingEngine, V: ToConstraintField<E::Fr> + Clone> SNARK for Groth16<E, V> {
+    const PROTOCOL_ID: SNARKProtocolID = SNARKProtocolID::Groth16;
+
     type BaseField = E::Fq;
     type PreparedVerifyingKey = PreparedVerifyingKey<E>;
     type Proof = Proof<E>;
@@ -91,4 +94,26 @@ impl<E: PairingEngine, V: ToConstraintField<E::Fr> + Clone> SNARK for Groth16<E,
         end_timer!(verify_time);
         Ok(result)
     }
+
+    fn verify_batch_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let verify_time = start_timer!(|| format!("{{Groth 2016}}::VerifyBatch w/ {} proofs", inputs_and_proofs.len()));
+        let conversion_time = start_timer!(|| "Convert inputs to E::Fr");
+        let inputs = inputs_and_proofs
+            .iter()
+            .map(|(input, _)| input.to_field_elements())
+            .collect::<Result<Vec<_>, _>>()?;
+        end_timer!(conversion_time);
+        let proofs_and_inputs: Vec<_> = inputs_and_proofs
+            .iter()
+            .zip(&inputs)
+            .map(|((_, proof), input)| (*proof, input.as_slice()))
+            .collect();
+        let result = verify_proofs(prepared_verifying_key, &proofs_and_inputs, rng)?;
+        end_timer!(verify_time);
+        Ok(result)
+    }
 }
@@ -55,6 +55,7 @@ mod bls12_377 {
         generate_random_parameters,
         prepare_verifying_key,
         verify_proof,
+        verify_proofs,
         Proof,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
@@ -80,6 +81,38 @@ mod bls12_377 {
         }
     }
 
+    #[test]
+    fn batch_verify() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let mut proofs = vec![];
+        let mut inputs = vec![];
+        for _ in 0..10 {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            proofs.push(create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap());
+            inputs.push([a * b]);
+        }
+
+        let batch: Vec<_> = proofs
+            .iter()
+            .zip(&inputs)
+            .map(|(proof, input)| (proof, &input[..]))
+            .collect();
+        assert!(verify_proofs(&pvk, &batch, rng).unwrap());
+        assert!(verify_proofs(&pvk, &[], rng).unwrap());
+
+        // A single invalid input must invalidate the batch.
+        let invalid_input = [Fr::rand(rng)];
+        for i in 0..batch.len() {
+            let mut invalid_batch = batch.clone();
+            invalid_batch[i].1 = &invalid_input[..];
+            assert!(!verify_proofs(&pvk, &invalid_batch, rng).unwrap());
+        }
+    }
+
     #[test]
     fn test_serde_json() {
         let expected_proof = {
@@ -303,3 +336,57 @@ mod serialization {
         assert!(!recovered_proof.compressed);
     }
 }
+
+mod minimal_bits {
+    use super::*;
+    use crate::snark::groth16::VerifyingKey;
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, G1Affine, G2Affine},
+        AffineCurve,
+    };
+    use snarkvm_utilities::ToMinimalBits;
+
+    /// The version 1 encoding of the BLS12-377 G1 generator, packed into little-endian bytes.
+    const G1_GENERATOR_MINIMAL_BITS_V1: &str =
+        "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29402";
+    /// The number of bits in the version 1 encoding of a BLS12-377 G1 point.
+    const G1_MINIMAL_BITS_V1_SIZE: usize = 379;
+
+    #[test]
+    fn test_to_minimal_bits_v1() {
+        let g1_generator_bits: Vec<bool> = hex::decode(G1_GENERATOR_MINIMAL_BITS_V1)
+            .unwrap()
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .take(G1_MINIMAL_BITS_V1_SIZE)
+            .collect();
+        assert_eq!(
+            g1_generator_bits,
+            G1Affine::prime_subgroup_generator().to_minimal_bits_v1()
+        );
+
+        // The point at infinity has a zero x-coordinate, a y-coordinate of one, and a set infinity flag.
+        let g2_zero_bits = [vec![false; 754], vec![true, true]].concat();
+        assert_eq!(g2_zero_bits, G2Affine::zero().to_minimal_bits_v1());
+
+        // Ensure the encoding of a verifying key is the concatenation of the encodings of its elements.
+        let verifying_key = VerifyingKey::<Bls12_377> {
+            alpha_g1: G1Affine::prime_subgroup_generator(),
+            beta_g2: G2Affine::zero(),
+            gamma_g2: G2Affine::zero(),
+            delta_g2: G2Affine::zero(),
+            gamma_abc_g1: vec![G1Affine::prime_subgroup_generator(); 2],
+        };
+        let expected_bits = [
+            g1_generator_bits.clone(),
+            g2_zero_bits.clone(),
+            g2_zero_bits.clone(),
+            g2_zero_bits,
+            g1_generator_bits.clone(),
+            g1_generator_bits,
+        ]
+        .concat();
+        assert_eq!(expected_bits, verifying_key.to_minimal_bits_v1());
+        assert_eq!(expected_bits, verifying_key.to_minimal_bits());
+    }
+}
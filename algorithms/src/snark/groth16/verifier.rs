@@ -15,10 +15,12 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
-use snarkvm_curves::traits::{PairingCurve, PairingEngine};
+use snarkvm_curves::traits::{MillerAccumulator, PairingCurve, PairingEngine};
+use snarkvm_fields::Zero;
 use snarkvm_r1cs::errors::SynthesisError;
 
 use core::ops::{AddAssign, Mul, Neg};
+use rand::{CryptoRng, Rng};
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: VerifyingKey<E>) -> PreparedVerifyingKey<E> {
     let alpha_g1_beta_g2 = E::pairing(vk.alpha_g1, vk.beta_g2);
@@ -38,17 +40,7 @@ pub fn verify_proof<E: PairingEngine>(
     proof: &Proof<E>,
     public_inputs: &[E::Fr],
 ) -> Result<bool, SynthesisError> {
-    if (public_inputs.len() + 1) != pvk.gamma_abc_g1().len() {
-        return Err(SynthesisError::MalformedVerifyingKey(
-            public_inputs.len() + 1,
-            pvk.gamma_abc_g1().len(),
-        ));
-    }
-
-    let mut g_ic = pvk.gamma_abc_g1()[0];
-    for (i, b) in public_inputs.iter().zip(pvk.gamma_abc_g1().iter().skip(1)) {
-        g_ic.add_assign(b.mul(*i));
-    }
+    let g_ic = prepare_inputs(pvk, public_inputs)?;
 
     let qap = E::miller_loop(
         [
@@ -64,3 +56,62 @@ pub fn verify_proof<E: PairingEngine>(
 
     Ok(test == pvk.alpha_g1_beta_g2)
 }
+
+///
+/// Returns `true` if all of the given proofs are valid for their public inputs, with overwhelming probability.
+///
+/// The verification equation of each proof is scaled by an independent randomizer, and the Miller loops
+/// of all equations are accumulated, so that the batch is checked with a single final exponentiation.
+///
+pub fn verify_proofs<E: PairingEngine, R: Rng + CryptoRng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_public_inputs: &[(&Proof<E>, &[E::Fr])],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    if proofs_and_public_inputs.is_empty() {
+        return Ok(true);
+    }
+
+    let mut accumulator = MillerAccumulator::<E>::new();
+    let mut randomizer_sum = E::Fr::zero();
+    for (proof, public_inputs) in proofs_and_public_inputs {
+        let g_ic = prepare_inputs(pvk, public_inputs)?;
+        let b = proof.b.prepare();
+
+        let randomizer = MillerAccumulator::<E>::randomizer(rng);
+        accumulator.add_randomized(
+            vec![
+                (proof.a, &b),
+                (g_ic, &pvk.gamma_g2_neg_pc),
+                (proof.c, &pvk.delta_g2_neg_pc),
+            ],
+            randomizer,
+        );
+        randomizer_sum += randomizer;
+    }
+
+    // Each equation is checked against `e(alpha, beta)`, raised to the sum of the randomizers.
+    let beta_g2 = pvk.vk.beta_g2.prepare();
+    accumulator.add_randomized(vec![(pvk.vk.alpha_g1.neg(), &beta_g2)], randomizer_sum);
+
+    Ok(accumulator.verify())
+}
+
+/// Returns the linear combination of the verifying key elements for the given public inputs.
+fn prepare_inputs<E: PairingEngine>(
+    pvk: &PreparedVerifyingKey<E>,
+    public_inputs: &[E::Fr],
+) -> Result<E::G1Affine, SynthesisError> {
+    if (public_inputs.len() + 1) != pvk.gamma_abc_g1().len() {
+        return Err(SynthesisError::MalformedVerifyingKey(
+            public_inputs.len() + 1,
+            pvk.gamma_abc_g1().len(),
+        ));
+    }
+
+    let mut g_ic = pvk.gamma_abc_g1()[0];
+    for (i, b) in public_inputs.iter().zip(pvk.gamma_abc_g1().iter().skip(1)) {
+        g_ic.add_assign(b.mul(*i));
+    }
+    Ok(g_ic)
+}
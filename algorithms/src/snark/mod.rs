@@ -14,4 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "dev")]
+pub mod dummy;
+
 pub mod groth16;
+
+pub mod registry;
+pub use registry::*;
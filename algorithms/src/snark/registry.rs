@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SNARKError, SNARKMetadata, SNARKProtocolID, SNARK};
+
+use std::collections::BTreeMap;
+
+///
+/// A registry of the proof systems in use, by protocol ID.
+///
+/// Code that handles proofs of several proof systems may tag each proof with its protocol ID,
+/// and use the registry to look up the metadata of the proof system that a proof is routed to.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SNARKRegistry {
+    metadata: BTreeMap<SNARKProtocolID, SNARKMetadata>,
+}
+
+impl SNARKRegistry {
+    /// Initializes an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Registers the proof system `S`, with the metadata of the given verifying key and a proof under it.
+    ///
+    /// Returns an error if a different instantiation of the proof system is already registered.
+    ///
+    pub fn register<S: SNARK>(&mut self, verifying_key: &S::VerifyingKey, proof: &S::Proof) -> Result<(), SNARKError> {
+        let metadata = S::metadata(verifying_key, proof)?;
+        match self.metadata.get(&S::PROTOCOL_ID) {
+            Some(registered) if *registered != metadata => Err(SNARKError::Message(format!(
+                "A different instantiation of {:?} is already registered",
+                S::PROTOCOL_ID
+            ))),
+            _ => {
+                self.metadata.insert(S::PROTOCOL_ID, metadata);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the metadata of the proof system with the given protocol ID, if it is registered.
+    pub fn get(&self, protocol_id: SNARKProtocolID) -> Option<&SNARKMetadata> {
+        self.metadata.get(&protocol_id)
+    }
+
+    /// Returns `true` if the proof system with the given protocol ID is registered.
+    pub fn contains(&self, protocol_id: SNARKProtocolID) -> bool {
+        self.metadata.contains_key(&protocol_id)
+    }
+
+    /// Returns the metadata of the registered proof systems, in order of protocol ID.
+    pub fn iter(&self) -> impl Iterator<Item = &SNARKMetadata> {
+        self.metadata.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{snark::groth16::Groth16, SRS};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_fields::{Field, Zero};
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+    use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+
+    use rand::thread_rng;
+
+    struct SquareCircuit<F: Field>(F);
+
+    impl<F: Field> ConstraintSynthesizer<F> for SquareCircuit<F> {
+        fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let a = cs.alloc(|| "a", || Ok(self.0))?;
+            let b = cs.alloc_input(|| "b", || Ok(self.0.square()))?;
+            cs.enforce(|| "a * a = b", |lc| lc + a, |lc| lc + a, |lc| lc + b);
+            Ok(())
+        }
+    }
+
+    type TestSNARK = Groth16<Bls12_377, Vec<Fr>>;
+
+    #[test]
+    fn test_snark_registry() {
+        let rng = &mut thread_rng();
+        let a = Fr::rand(rng);
+
+        let (proving_key, verifying_key) =
+            TestSNARK::setup(&SquareCircuit(Fr::zero()), &mut SRS::CircuitSpecific(rng)).unwrap();
+        let proof = TestSNARK::prove(&proving_key, &SquareCircuit(a), rng).unwrap();
+
+        let mut registry = SNARKRegistry::new();
+        assert!(!registry.contains(SNARKProtocolID::Groth16));
+        registry.register::<TestSNARK>(&verifying_key, &proof).unwrap();
+
+        let metadata = registry.get(SNARKProtocolID::Groth16).unwrap();
+        assert_eq!(SNARKProtocolID::Groth16, metadata.protocol_id);
+        assert_eq!(std::any::type_name::<Fr>(), metadata.curve);
+        assert_eq!(proof.to_bytes_le().unwrap().len(), metadata.proof_size_in_bytes);
+        assert_eq!(
+            verifying_key.to_bytes_le().unwrap().len(),
+            metadata.verifying_key_size_in_bytes
+        );
+        assert!(registry.get(SNARKProtocolID::Marlin).is_none());
+
+        // Ensure registering the same instantiation again succeeds.
+        registry.register::<TestSNARK>(&verifying_key, &proof).unwrap();
+        assert_eq!(1, registry.iter().count());
+    }
+
+    #[test]
+    fn test_snark_protocol_id_bytes() {
+        for protocol_id in [
+            SNARKProtocolID::Groth16,
+            SNARKProtocolID::Marlin,
+            SNARKProtocolID::MarlinHiding,
+            SNARKProtocolID::Dummy,
+        ] {
+            let bytes = protocol_id.to_bytes_le().unwrap();
+            assert_eq!(protocol_id, SNARKProtocolID::read_le(&bytes[..]).unwrap());
+        }
+        assert!(SNARKProtocolID::read_le(&[0u8][..]).is_err());
+    }
+}
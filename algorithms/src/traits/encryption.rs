@@ -43,6 +43,12 @@ pub trait EncryptionScheme:
         rng: &mut R,
     ) -> (Self::ScalarRandomness, Self::CiphertextRandomizer, Self::SymmetricKey);
 
+    fn generate_asymmetric_key_from_randomness(
+        &self,
+        public_key: &Self::PublicKey,
+        randomness: Self::ScalarRandomness,
+    ) -> (Self::CiphertextRandomizer, Self::SymmetricKey);
+
     fn generate_symmetric_key(
         &self,
         private_key: &Self::PrivateKey,
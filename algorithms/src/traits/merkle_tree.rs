@@ -19,6 +19,9 @@ use snarkvm_utilities::{to_bytes_le, ToBytes};
 
 use std::fmt::Debug;
 
+/// The number of zero bytes hashed for an empty leaf of a Merkle tree.
+pub const MERKLE_EMPTY_LEAF_SIZE_IN_BYTES: usize = 64;
+
 pub trait MerkleParameters: Clone + Debug + Send + Sync {
     type H: CRH;
 
@@ -59,7 +62,7 @@ pub trait MerkleParameters: Clone + Debug + Send + Sync {
         //  One needs to define a `LeafCRH` and a `TwoToOneCRH` in order to set proper size expectations.
         //  64 bytes was chosen as a temporary fix, to at least ensure the `TwoToOneCRH` preimage size fits,
         //  however this temporary fix does not technically address the issue in a meaningful sense.
-        let empty_buffer = &[0u8; 64];
+        let empty_buffer = &[0u8; MERKLE_EMPTY_LEAF_SIZE_IN_BYTES];
         Ok(self.crh().hash(&*empty_buffer)?)
     }
 }
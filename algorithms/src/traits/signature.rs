@@ -46,14 +46,25 @@ pub trait SignatureScheme:
     fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool>;
 }
 
+///
+/// The operations of a signature scheme for accounts, from which the account compute key
+/// `(pk_sig, pr_sig, sk_prf)` and address `pk_sig * pr_sig * G^sk_prf` are derived.
+///
+/// A signature scheme that implements these operations may be used as the account signature scheme
+/// of a network, regardless of how its signatures are computed.
+///
 pub trait SignatureSchemeOperations {
     type AffineCurve: Clone + Debug + Default + ToBytes + FromBytes + Hash + Eq + Send + Sync;
     type BaseField: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq;
     type ScalarField: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq;
     type Signature: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq;
 
-    fn pk_sig(signature: &Self::Signature) -> Result<Self::AffineCurve>;
-    fn pr_sig(signature: &Self::Signature) -> Result<Self::AffineCurve>;
+    /// Returns the scalar `s` of the signature root public key `pk_sig := G^s`, for the given signature secret key.
+    fn pk_sig_scalar(&self, sk_sig: &Self::ScalarField) -> Result<Self::ScalarField>;
+    /// Returns the signature root public key `pk_sig` of the signer of the given signature.
+    fn pk_sig(&self, signature: &Self::Signature) -> Result<Self::AffineCurve>;
+    /// Returns the signature root randomizer `pr_sig` of the signer of the given signature.
+    fn pr_sig(&self, signature: &Self::Signature) -> Result<Self::AffineCurve>;
     fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> Self::AffineCurve;
     fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField;
 }
@@ -20,7 +20,11 @@ use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits};
 use rand::{CryptoRng, Rng};
 use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_r1cs::ConstraintSynthesizer;
-use std::{fmt::Debug, sync::atomic::AtomicBool};
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    sync::atomic::AtomicBool,
+};
 
 /// Defines a trait that describes preparing from an unprepared version to a prepare version.
 pub trait Prepare<T> {
@@ -34,7 +38,64 @@ pub enum SRS<'a, R: Rng + CryptoRng, T> {
     Universal(&'a T),
 }
 
+/// The identifier of a proof system, used to tag proofs and route them to their verifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum SNARKProtocolID {
+    Groth16 = 1,
+    /// Marlin without zero knowledge.
+    Marlin = 2,
+    /// Marlin with zero knowledge, which hides the witness.
+    MarlinHiding = 3,
+    /// The dummy SNARK, which does not produce sound proofs.
+    Dummy = 255,
+}
+
+impl SNARKProtocolID {
+    /// Returns the protocol ID with the given byte, if any.
+    pub fn from_u8(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Self::Groth16),
+            2 => Some(Self::Marlin),
+            3 => Some(Self::MarlinHiding),
+            255 => Some(Self::Dummy),
+            _ => None,
+        }
+    }
+}
+
+impl FromBytes for SNARKProtocolID {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let id = u8::read_le(&mut reader)?;
+        Self::from_u8(id).ok_or_else(|| Error::new(ErrorKind::Other, format!("Unknown SNARK protocol ID {}", id)))
+    }
+}
+
+impl ToBytes for SNARKProtocolID {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (*self as u8).write_le(&mut writer)
+    }
+}
+
+/// The metadata of an instantiation of a proof system, which is exchanged to agree on a proof system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SNARKMetadata {
+    /// The identifier of the proof system.
+    pub protocol_id: SNARKProtocolID,
+    /// The type name of the scalar field, which identifies the curve of the proof system.
+    pub curve: &'static str,
+    /// The size of a proof in bytes.
+    pub proof_size_in_bytes: usize,
+    /// The size of the verifying key in bytes.
+    pub verifying_key_size_in_bytes: usize,
+}
+
 pub trait SNARK: Clone + Debug {
+    /// The identifier of the proof system.
+    const PROTOCOL_ID: SNARKProtocolID;
+
     type ScalarField: Clone + PrimeField;
     type BaseField: Clone + PrimeField;
 
@@ -99,4 +160,31 @@ pub trait SNARK: Clone + Debug {
         let processed_verifying_key = verifying_key.prepare();
         Self::verify_prepared(&processed_verifying_key, input, proof)
     }
+
+    /// Returns `true` if all of the given proofs are valid for their inputs.
+    ///
+    /// Proof systems that can amortize the verification of many proofs override this method;
+    /// by default, each proof is verified individually.
+    fn verify_batch_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        _rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        for (input, proof) in inputs_and_proofs {
+            if !Self::verify_prepared(prepared_verifying_key, input, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns the metadata of the proof system, for the given verifying key and a proof under it.
+    fn metadata(verifying_key: &Self::VerifyingKey, proof: &Self::Proof) -> Result<SNARKMetadata, SNARKError> {
+        Ok(SNARKMetadata {
+            protocol_id: Self::PROTOCOL_ID,
+            curve: std::any::type_name::<Self::ScalarField>(),
+            proof_size_in_bytes: proof.to_bytes_le()?.len(),
+            verifying_key_size_in_bytes: verifying_key.to_bytes_le()?.len(),
+        })
+    }
 }
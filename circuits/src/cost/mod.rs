@@ -0,0 +1,444 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Environment, Mode};
+use snarkvm_fields::PrimeField;
+
+use std::{fmt, ops::Add};
+
+/// The number of variables and constraints synthesized by an operation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Count {
+    pub num_constants: usize,
+    pub num_public: usize,
+    pub num_private: usize,
+    pub num_constraints: usize,
+}
+
+impl Count {
+    /// Initializes a new count.
+    pub const fn new(num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) -> Self {
+        Self {
+            num_constants,
+            num_public,
+            num_private,
+            num_constraints,
+        }
+    }
+
+    /// Returns the count of an operation that synthesizes nothing.
+    pub const fn zero() -> Self {
+        Self::new(0, 0, 0, 0)
+    }
+}
+
+impl Add for Count {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(
+            self.num_constants + other.num_constants,
+            self.num_public + other.num_public,
+            self.num_private + other.num_private,
+            self.num_constraints + other.num_constraints,
+        )
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.num_constants, self.num_public, self.num_private, self.num_constraints
+        )
+    }
+}
+
+/// An operation on circuit types, whose cost depends only on the modes of its operands.
+pub trait Operation {
+    /// The name of the operation, as it appears in the cost table.
+    const NAME: &'static str;
+    /// The number of operands of the operation.
+    const NUM_OPERANDS: usize;
+
+    /// Returns the number of variables and constraints synthesized by the operation,
+    /// excluding the operands themselves.
+    fn count<E: Environment>(modes: &[Mode]) -> Count;
+}
+
+///
+/// Returns the number of variables and constraints synthesized by `Op` on operands with the given modes.
+///
+/// This allows estimating the size of a circuit without synthesizing it.
+///
+pub fn cost_of<E: Environment, Op: Operation>(modes: &[Mode]) -> Count {
+    assert_eq!(
+        Op::NUM_OPERANDS,
+        modes.len(),
+        "{} expects {} operands",
+        Op::NAME,
+        Op::NUM_OPERANDS
+    );
+    Op::count::<E>(modes)
+}
+
+/// Returns `1` private variable and `2` constraints if all operands are variables, and nothing otherwise.
+fn binary_boolean(modes: &[Mode]) -> Count {
+    match modes.iter().any(Mode::is_constant) {
+        true => Count::zero(),
+        false => Count::new(0, 0, 1, 2),
+    }
+}
+
+/// Returns the given count if `first` and `second` are not both constant, and `condition` is not constant.
+fn ternary(modes: &[Mode], count: Count) -> Count {
+    match (modes[0], modes[1], modes[2]) {
+        (Mode::Constant, _, _) | (_, Mode::Constant, Mode::Constant) => Count::zero(),
+        _ => count,
+    }
+}
+
+macro_rules! operation {
+    ($(#[$doc:meta])* $name:ident, $label:expr, $num_operands:expr, |$e:ident, $modes:ident| $count:expr) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name;
+
+        impl Operation for $name {
+            const NAME: &'static str = $label;
+            const NUM_OPERANDS: usize = $num_operands;
+
+            #[allow(unused_variables)]
+            fn count<$e: Environment>($modes: &[Mode]) -> Count {
+                $count
+            }
+        }
+    };
+}
+
+operation!(
+    /// `!a` on booleans.
+    BooleanNot, "boolean.not", 1, |E, modes| Count::zero()
+);
+operation!(
+    /// `a & b` on booleans.
+    BooleanAnd, "boolean.and", 2, |E, modes| binary_boolean(modes)
+);
+operation!(
+    /// `a | b` on booleans.
+    BooleanOr, "boolean.or", 2, |E, modes| binary_boolean(modes)
+);
+operation!(
+    /// `!(a & b)` on booleans.
+    BooleanNand, "boolean.nand", 2, |E, modes| binary_boolean(modes)
+);
+operation!(
+    /// `!(a | b)` on booleans.
+    BooleanNor, "boolean.nor", 2, |E, modes| binary_boolean(modes)
+);
+operation!(
+    /// `a ^ b` on booleans.
+    BooleanXor, "boolean.xor", 2, |E, modes| binary_boolean(modes)
+);
+operation!(
+    /// `a == b` on booleans.
+    BooleanEqual, "boolean.equal", 2, |E, modes| binary_boolean(modes)
+);
+operation!(
+    /// `condition ? a : b` on booleans.
+    BooleanTernary, "boolean.ternary", 3, |E, modes| ternary(modes, Count::new(0, 0, 1, 2))
+);
+operation!(
+    /// `a + b` on base fields.
+    BaseFieldAdd, "base_field.add", 2, |E, modes| Count::zero()
+);
+operation!(
+    /// `a - b` on base fields.
+    BaseFieldSub, "base_field.sub", 2, |E, modes| Count::zero()
+);
+operation!(
+    /// `-a` on base fields.
+    BaseFieldNeg, "base_field.neg", 1, |E, modes| Count::zero()
+);
+operation!(
+    /// `a + a` on base fields.
+    BaseFieldDouble, "base_field.double", 1, |E, modes| Count::zero()
+);
+operation!(
+    /// `a * b` on base fields.
+    BaseFieldMul, "base_field.mul", 2, |E, modes| match modes.iter().any(Mode::is_constant) {
+        true => Count::zero(),
+        false => Count::new(0, 0, 1, 1),
+    }
+);
+operation!(
+    /// `a * a` on base fields.
+    BaseFieldSquare, "base_field.square", 1, |E, modes| match modes[0].is_constant() {
+        true => Count::zero(),
+        false => Count::new(0, 0, 1, 1),
+    }
+);
+operation!(
+    /// `1 / a` on base fields.
+    BaseFieldInv, "base_field.inv", 1, |E, modes| match modes[0].is_constant() {
+        true => Count::new(1, 0, 0, 0),
+        false => Count::new(0, 0, 1, 1),
+    }
+);
+operation!(
+    /// `a / b` on base fields.
+    BaseFieldDiv, "base_field.div", 2, |E, modes| match (modes[0].is_constant(), modes[1].is_constant()) {
+        (_, true) => Count::new(1, 0, 0, 0),
+        (true, false) => Count::new(0, 0, 1, 1),
+        (false, false) => Count::new(0, 0, 2, 2),
+    }
+);
+operation!(
+    /// `a == b` on base fields.
+    BaseFieldEqual, "base_field.equal", 2, |E, modes| match modes.iter().all(Mode::is_constant) {
+        true => Count::new(1, 0, 0, 0),
+        false => Count::new(0, 0, 2, 3),
+    }
+);
+operation!(
+    /// `condition ? a : b` on base fields.
+    BaseFieldTernary, "base_field.ternary", 3, |E, modes| ternary(modes, Count::new(0, 0, 1, 1))
+);
+operation!(
+    /// The little-endian bits of a base field.
+    BaseFieldToBits, "base_field.to_bits", 1, |E, modes| {
+        let num_bits = <E::BaseField as PrimeField>::size_in_bits();
+        match modes[0].is_constant() {
+            true => Count::new(num_bits, 0, 0, 0),
+            false => Count::new(0, 0, num_bits, num_bits + 1),
+        }
+    }
+);
+
+macro_rules! operations {
+    ($($name:ident),*) => {
+        /// Returns the name, number of operands, and count function of every supported operation.
+        fn operations<E: Environment>() -> Vec<(&'static str, usize, fn(&[Mode]) -> Count)> {
+            vec![$(($name::NAME, $name::NUM_OPERANDS, $name::count::<E>)),*]
+        }
+    };
+}
+
+operations!(
+    BooleanNot,
+    BooleanAnd,
+    BooleanOr,
+    BooleanNand,
+    BooleanNor,
+    BooleanXor,
+    BooleanEqual,
+    BooleanTernary,
+    BaseFieldAdd,
+    BaseFieldSub,
+    BaseFieldNeg,
+    BaseFieldDouble,
+    BaseFieldMul,
+    BaseFieldSquare,
+    BaseFieldInv,
+    BaseFieldDiv,
+    BaseFieldEqual,
+    BaseFieldTernary,
+    BaseFieldToBits
+);
+
+///
+/// Returns the number of variables and constraints synthesized by the operation with the given name,
+/// on operands with the given modes.
+///
+/// Returns `None` if the operation is unknown, or if the number of modes does not match its number of operands.
+///
+pub fn cost_of_name<E: Environment>(name: &str, modes: &[Mode]) -> Option<Count> {
+    operations::<E>()
+        .into_iter()
+        .find(|(operation, num_operands, _)| *operation == name && *num_operands == modes.len())
+        .map(|(_, _, count)| count(modes))
+}
+
+///
+/// Returns the cost of every supported operation, for every combination of operand modes, as CSV.
+///
+/// The modes of each row are separated by `|`, in the order of the operands.
+///
+pub fn cost_table<E: Environment>() -> String {
+    let mut table = String::from("operation,modes,num_constants,num_public,num_private,num_constraints\n");
+    for (name, num_operands, count) in operations::<E>() {
+        for modes in mode_combinations(num_operands) {
+            let labels: Vec<_> = modes.iter().map(|mode| format!("{:?}", mode)).collect();
+            table.push_str(&format!("{},{},{}\n", name, labels.join("|"), count(&modes)));
+        }
+    }
+    table
+}
+
+/// Returns every combination of modes for the given number of operands.
+fn mode_combinations(num_operands: usize) -> Vec<Vec<Mode>> {
+    (0..num_operands).fold(vec![vec![]], |combinations, _| {
+        combinations
+            .into_iter()
+            .flat_map(|modes| {
+                [Mode::Constant, Mode::Public, Mode::Private].iter().map(move |mode| {
+                    let mut modes = modes.clone();
+                    modes.push(*mode);
+                    modes
+                })
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::*, BaseField, Boolean, Circuit};
+    use snarkvm_utilities::UniformRand;
+
+    use num_traits::Inv;
+    use rand::thread_rng;
+
+    /// Synthesizes the given operation on operands of every combination of modes,
+    /// and checks the synthesized counts match the cost table.
+    fn check_operation<Op: Operation, T, F>(new: impl Fn(Mode) -> T, operate: F)
+    where
+        F: Fn(&[T]),
+    {
+        for modes in mode_combinations(Op::NUM_OPERANDS) {
+            let operands: Vec<_> = modes.iter().map(|mode| new(*mode)).collect();
+
+            Circuit::scoped(&format!("{} {:?}", Op::NAME, modes), |scope| {
+                operate(&operands);
+
+                let candidate = Count::new(
+                    scope.num_constants_in_scope(),
+                    scope.num_public_in_scope(),
+                    scope.num_private_in_scope(),
+                    scope.num_constraints_in_scope(),
+                );
+                assert_eq!(cost_of::<Circuit, Op>(&modes), candidate, "{} {:?}", Op::NAME, modes);
+            });
+        }
+    }
+
+    fn new_boolean(mode: Mode) -> Boolean<Circuit> {
+        Boolean::new(mode, UniformRand::rand(&mut thread_rng()))
+    }
+
+    fn new_base_field(mode: Mode) -> BaseField<Circuit> {
+        BaseField::new(mode, UniformRand::rand(&mut thread_rng()))
+    }
+
+    #[test]
+    fn test_boolean_costs() {
+        check_operation::<BooleanNot, _, _>(new_boolean, |a| {
+            let _ = !&a[0];
+        });
+        check_operation::<BooleanAnd, _, _>(new_boolean, |a| {
+            a[0].and(&a[1]);
+        });
+        check_operation::<BooleanOr, _, _>(new_boolean, |a| {
+            a[0].or(&a[1]);
+        });
+        check_operation::<BooleanNand, _, _>(new_boolean, |a| {
+            a[0].nand(&a[1]);
+        });
+        check_operation::<BooleanNor, _, _>(new_boolean, |a| {
+            a[0].nor(&a[1]);
+        });
+        check_operation::<BooleanXor, _, _>(new_boolean, |a| {
+            a[0].xor(&a[1]);
+        });
+        check_operation::<BooleanEqual, _, _>(new_boolean, |a| {
+            a[0].is_eq(&a[1]);
+        });
+        check_operation::<BooleanTernary, _, _>(new_boolean, |a| {
+            Boolean::ternary(&a[0], &a[1], &a[2]);
+        });
+    }
+
+    #[test]
+    fn test_base_field_costs() {
+        check_operation::<BaseFieldAdd, _, _>(new_base_field, |a| {
+            let _ = &a[0] + &a[1];
+        });
+        check_operation::<BaseFieldSub, _, _>(new_base_field, |a| {
+            let _ = &a[0] - &a[1];
+        });
+        check_operation::<BaseFieldNeg, _, _>(new_base_field, |a| {
+            let _ = -&a[0];
+        });
+        check_operation::<BaseFieldDouble, _, _>(new_base_field, |a| {
+            (&a[0]).double();
+        });
+        check_operation::<BaseFieldMul, _, _>(new_base_field, |a| {
+            let _ = &a[0] * &a[1];
+        });
+        check_operation::<BaseFieldSquare, _, _>(new_base_field, |a| {
+            a[0].square();
+        });
+        check_operation::<BaseFieldInv, _, _>(new_base_field, |a| {
+            let _ = (&a[0]).inv();
+        });
+        check_operation::<BaseFieldDiv, _, _>(new_base_field, |a| {
+            let _ = a[0].clone() / &a[1];
+        });
+        check_operation::<BaseFieldEqual, _, _>(new_base_field, |a| {
+            a[0].is_eq(&a[1]);
+        });
+        check_operation::<BaseFieldToBits, _, _>(new_base_field, |a| {
+            a[0].to_bits_le();
+        });
+
+        // The condition of a ternary is a boolean.
+        for modes in mode_combinations(BaseFieldTernary::NUM_OPERANDS) {
+            let condition = new_boolean(modes[0]);
+            let first = new_base_field(modes[1]);
+            let second = new_base_field(modes[2]);
+
+            Circuit::scoped(&format!("{} {:?}", BaseFieldTernary::NAME, modes), |scope| {
+                BaseField::ternary(&condition, &first, &second);
+
+                let candidate = Count::new(
+                    scope.num_constants_in_scope(),
+                    scope.num_public_in_scope(),
+                    scope.num_private_in_scope(),
+                    scope.num_constraints_in_scope(),
+                );
+                assert_eq!(cost_of::<Circuit, BaseFieldTernary>(&modes), candidate, "{:?}", modes);
+            });
+        }
+    }
+
+    #[test]
+    fn test_cost_of_name() {
+        let modes = [Mode::Private, Mode::Public];
+        assert_eq!(
+            Some(cost_of::<Circuit, BaseFieldDiv>(&modes)),
+            cost_of_name::<Circuit>("base_field.div", &modes)
+        );
+        assert_eq!(None, cost_of_name::<Circuit>("base_field.div", &modes[..1]));
+        assert_eq!(None, cost_of_name::<Circuit>("base_field.pow", &modes));
+    }
+
+    #[test]
+    fn test_cost_table() {
+        assert_eq!(include_str!("costs.csv"), cost_table::<Circuit>());
+    }
+}
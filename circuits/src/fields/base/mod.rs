@@ -26,6 +26,7 @@ pub mod square;
 pub mod sub;
 pub mod ternary;
 pub mod to_bits;
+pub mod to_lower_bits;
 pub mod zero;
 
 use crate::{traits::*, Boolean, Environment, LinearCombination, Mode};
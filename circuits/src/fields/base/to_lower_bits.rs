@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_fields::PrimeField;
+
+impl<E: Environment> BaseField<E> {
+    ///
+    /// Outputs the lower `k` bits of `self` in little-endian form,
+    /// and ensures `self` is less than `2^k`.
+    ///
+    /// This uses `k + 1` constraints, and is used to range check values such as 64-bit amounts.
+    ///
+    pub fn to_lower_bits_le(&self, k: usize) -> Vec<Boolean<E>> {
+        // Ensure the bit decomposition is unique, by requiring `2^k` to be less than the modulus.
+        if k >= E::BaseField::size_in_bits() {
+            E::halt(format!("Attempted to range check {} bits of a base field element", k))
+        }
+
+        let mode = match self.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        let value_bits = self.eject_value().to_bits_le();
+        if mode.is_constant() && value_bits[k..].iter().any(|bit| *bit) {
+            E::halt(format!("Constant base field element exceeds {} bits", k))
+        }
+
+        let bits = value_bits[..k]
+            .iter()
+            .map(|bit| Boolean::new(mode, *bit))
+            .collect::<Vec<_>>();
+
+        let mut accumulator = BaseField::zero();
+        let mut coefficient = BaseField::one();
+
+        for bit in &bits {
+            accumulator += BaseField::from(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+
+        // Ensure value * 1 == (2^(k-1) * b_(k-1) + ... + 2^0 * b_0)
+        E::enforce(|| (self, E::one(), accumulator));
+
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_lower_bits_le() {
+        for i in 0..ITERATIONS {
+            let value: u64 = thread_rng().gen();
+            let expected = (0..64).map(|j| (value >> j) & 1 == 1).collect::<Vec<_>>();
+
+            // Constant
+            let candidate = BaseField::<Circuit>::new(Mode::Constant, (value as u128).into());
+            Circuit::scoped(&format!("Constant {}", i), |scope| {
+                let candidate = candidate.to_lower_bits_le(64);
+                assert_eq!(
+                    expected,
+                    candidate.iter().map(|bit| bit.eject_value()).collect::<Vec<_>>()
+                );
+
+                assert_eq!(64, scope.num_constants_in_scope());
+                assert_eq!(0, scope.num_public_in_scope());
+                assert_eq!(0, scope.num_private_in_scope());
+                assert_eq!(0, scope.num_constraints_in_scope());
+            });
+
+            // Private
+            let candidate = BaseField::<Circuit>::new(Mode::Private, (value as u128).into());
+            Circuit::scoped(&format!("Private {}", i), |scope| {
+                let candidate = candidate.to_lower_bits_le(64);
+                assert_eq!(
+                    expected,
+                    candidate.iter().map(|bit| bit.eject_value()).collect::<Vec<_>>()
+                );
+
+                assert_eq!(0, scope.num_constants_in_scope());
+                assert_eq!(0, scope.num_public_in_scope());
+                assert_eq!(64, scope.num_private_in_scope());
+                assert_eq!(65, scope.num_constraints_in_scope());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_to_lower_bits_le_out_of_range() {
+        // Ensure a private value of 2^64 does not satisfy a 64-bit range check.
+        let candidate = BaseField::<Circuit>::new(Mode::Private, (u64::MAX as u128 + 1).into());
+        let violations = Circuit::diagnose(|| {
+            candidate.to_lower_bits_le(64);
+        });
+        assert!(!violations.is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::ops::{Add, AddAssign};
+
+impl<E: Environment, const I: usize, const F: usize> Add<Self> for FixedPoint<E, I, F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Add<&Self> for FixedPoint<E, I, F> {
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Add<&FixedPoint<E, I, F>> for &FixedPoint<E, I, F> {
+    type Output = FixedPoint<E, I, F>;
+
+    /// Returns `(a + b)`, and ensures the sum does not overflow.
+    fn add(self, other: &FixedPoint<E, I, F>) -> Self::Output {
+        FixedPoint::from_base_field(&self.value + &other.value)
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> AddAssign<Self> for FixedPoint<E, I, F> {
+    fn add_assign(&mut self, other: Self) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> AddAssign<&Self> for FixedPoint<E, I, F> {
+    fn add_assign(&mut self, other: &Self) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    type Fixed = FixedPoint<Circuit, 32, 16>;
+
+    fn check_add(name: &str, first: u128, second: u128, mode_a: Mode, mode_b: Mode, num_constraints: usize) {
+        let a = Fixed::new(mode_a, first);
+        let b = Fixed::new(mode_b, second);
+        Circuit::scoped(name, |scope| {
+            let candidate = &a + &b;
+            assert_eq!(first + second, candidate.eject_value());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+        });
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_add() {
+        for i in 0..ITERATIONS {
+            let first = thread_rng().gen_range(0..1u128 << 47);
+            let second = thread_rng().gen_range(0..1u128 << 47);
+
+            check_add(
+                &format!("Constant + Constant {}", i),
+                first,
+                second,
+                Mode::Constant,
+                Mode::Constant,
+                0,
+            );
+            check_add(
+                &format!("Constant + Private {}", i),
+                first,
+                second,
+                Mode::Constant,
+                Mode::Private,
+                49,
+            );
+            check_add(
+                &format!("Private + Private {}", i),
+                first,
+                second,
+                Mode::Private,
+                Mode::Private,
+                49,
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_overflow() {
+        let a = Fixed::new(Mode::Private, (1 << 48) - 1);
+        let b = Fixed::new(Mode::Private, 1);
+        let violations = Circuit::diagnose(|| {
+            let _ = &a + &b;
+        });
+        assert!(!violations.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_constant() {
+        let a = Fixed::new(Mode::Constant, (1 << 48) - 1);
+        let _ = a + Fixed::new(Mode::Constant, 1);
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::ops::{Div, DivAssign};
+
+impl<E: Environment, const I: usize, const F: usize> FixedPoint<E, I, F> {
+    ///
+    /// Returns `(a / b)`, rounded to `F` fractional bits with the given rounding mode,
+    /// and ensures the divisor is nonzero and the quotient does not overflow.
+    ///
+    pub fn div_with_rounding(&self, other: &Self, mode: RoundingMode) -> Self {
+        let witness_mode = self.witness_mode(other);
+
+        // Scale the dividend by `2^F`, so that the quotient of the scaled values has `F` fractional bits.
+        let scale = BaseField::new(Mode::Constant, (1u128 << F).into());
+        let dividend = &self.value * &scale;
+
+        let (dividend_value, divisor_value) = (self.eject_value() << F, other.eject_value());
+        if other.is_constant() && divisor_value == 0 {
+            E::halt("Attempted to divide a fixed-point number by zero")
+        }
+        let (quotient_value, remainder_value) = match divisor_value {
+            0 => (0, 0),
+            _ => (dividend_value / divisor_value, dividend_value % divisor_value),
+        };
+        let quotient = BaseField::new(witness_mode, quotient_value.into());
+        let remainder = BaseField::new(witness_mode, remainder_value.into());
+
+        // Ensure quotient * divisor == dividend - remainder.
+        E::enforce(|| (&quotient, &other.value, &dividend - &remainder));
+
+        // Ensure 0 <= remainder < divisor, which also ensures the divisor is nonzero.
+        remainder.to_lower_bits_le(Self::SIZE_IN_BITS);
+        (&other.value - &remainder - BaseField::one()).to_lower_bits_le(Self::SIZE_IN_BITS);
+
+        // As the dividend is less than 2^128, the range check on the result
+        // also ensures the quotient is the integer quotient of the dividend.
+        let increment = Self::round(mode, &remainder, &other.value, witness_mode);
+        Self::from_base_field(quotient + BaseField::from(&increment))
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Div<Self> for FixedPoint<E, I, F> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Div<&Self> for FixedPoint<E, I, F> {
+    type Output = Self;
+
+    fn div(self, other: &Self) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Div<&FixedPoint<E, I, F>> for &FixedPoint<E, I, F> {
+    type Output = FixedPoint<E, I, F>;
+
+    /// Returns `(a / b)`, rounded down to `F` fractional bits, and ensures the quotient does not overflow.
+    fn div(self, other: &FixedPoint<E, I, F>) -> Self::Output {
+        self.div_with_rounding(other, RoundingMode::Floor)
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> DivAssign<Self> for FixedPoint<E, I, F> {
+    fn div_assign(&mut self, other: Self) {
+        *self /= &other;
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> DivAssign<&Self> for FixedPoint<E, I, F> {
+    fn div_assign(&mut self, other: &Self) {
+        *self = &*self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    type Fixed = FixedPoint<Circuit, 32, 16>;
+
+    fn expected_div(first: u128, second: u128, mode: RoundingMode) -> u128 {
+        let (quotient, remainder) = ((first << 16) / second, (first << 16) % second);
+        match mode {
+            RoundingMode::Floor => quotient,
+            RoundingMode::Ceiling => quotient + (remainder != 0) as u128,
+            RoundingMode::HalfUp => quotient + (2 * remainder >= second) as u128,
+        }
+    }
+
+    fn check_div(first: u128, second: u128, mode_a: Mode, mode_b: Mode) {
+        for mode in [RoundingMode::Floor, RoundingMode::Ceiling, RoundingMode::HalfUp] {
+            let a = Fixed::new(mode_a, first);
+            let b = Fixed::new(mode_b, second);
+            let candidate = a.div_with_rounding(&b, mode);
+            assert_eq!(expected_div(first, second, mode), candidate.eject_value(), "{:?}", mode);
+            assert!(Circuit::is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_div() {
+        for _ in 0..ITERATIONS {
+            let first = thread_rng().gen_range(0..1u128 << 40);
+            let second = thread_rng().gen_range(1u128 << 16..1u128 << 48);
+
+            check_div(first, second, Mode::Constant, Mode::Constant);
+            check_div(first, second, Mode::Constant, Mode::Private);
+            check_div(first, second, Mode::Private, Mode::Private);
+        }
+    }
+
+    #[test]
+    fn test_div_rounding() {
+        // 2 / 3 is 43690.67 * 2^-16, which rounds up to the nearest value, and 1 / 3 is 21845.33 * 2^-16, which rounds down.
+        let one = Fixed::from_integer(Mode::Private, 1);
+        let two = Fixed::from_integer(Mode::Private, 2);
+        let three = Fixed::from_integer(Mode::Private, 3);
+        assert_eq!(43690, (&two / &three).eject_value());
+        assert_eq!(
+            43691,
+            two.div_with_rounding(&three, RoundingMode::Ceiling).eject_value()
+        );
+        assert_eq!(43691, two.div_with_rounding(&three, RoundingMode::HalfUp).eject_value());
+        assert_eq!(21845, one.div_with_rounding(&three, RoundingMode::HalfUp).eject_value());
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let a = Fixed::from_integer(Mode::Private, 1);
+        let b = Fixed::new(Mode::Private, 0);
+        let violations = Circuit::diagnose(|| {
+            let _ = &a / &b;
+        });
+        assert!(!violations.is_empty());
+    }
+
+    #[test]
+    fn test_div_overflow() {
+        let a = Fixed::from_integer(Mode::Private, 1 << 16);
+        let b = Fixed::new(Mode::Private, 1);
+        let violations = Circuit::diagnose(|| {
+            let _ = &a / &b;
+        });
+        assert!(!violations.is_empty());
+    }
+}
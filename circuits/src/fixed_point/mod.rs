@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod add;
+pub use add::*;
+
+pub mod div;
+pub use div::*;
+
+pub mod mul;
+pub use mul::*;
+
+pub mod sub;
+pub use sub::*;
+
+use crate::{traits::*, BaseField, Boolean, Environment, Mode};
+use snarkvm_utilities::ToBits as TBits;
+
+use std::fmt;
+
+/// The rounding mode of a fixed-point multiplication or division, whose exact result may have more fractional bits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds down, towards zero.
+    Floor,
+    /// Rounds up, away from zero.
+    Ceiling,
+    /// Rounds to the nearest value, and rounds halfway values up.
+    HalfUp,
+}
+
+///
+/// An unsigned fixed-point number with `I` integer bits and `F` fractional bits.
+///
+/// The number is stored as the base field element `v` in `[0, 2^(I + F))`, and represents `v / 2^F`.
+/// Every operation ensures its result is in range, so an overflow or underflow
+/// halts on constants, and does not satisfy the circuit on variables.
+///
+/// The number of bits `I + F` is at most 64, so that the product of two numbers fits in 128 bits,
+/// and never wraps around the modulus of the base field.
+///
+#[derive(Clone)]
+pub struct FixedPoint<E: Environment, const I: usize, const F: usize> {
+    value: BaseField<E>,
+}
+
+impl<E: Environment, const I: usize, const F: usize> FixedPoint<E, I, F> {
+    /// The number of bits of a fixed-point number.
+    pub const SIZE_IN_BITS: usize = I + F;
+
+    ///
+    /// Initializes a new fixed-point number from its scaled value `v`, which represents `v / 2^F`.
+    ///
+    pub fn new(mode: Mode, value: u128) -> Self {
+        Self::check_size();
+        Self::from_base_field(BaseField::new(mode, value.into()))
+    }
+
+    ///
+    /// Initializes a new fixed-point number from the given integer.
+    ///
+    pub fn from_integer(mode: Mode, integer: u64) -> Self {
+        Self::check_size();
+        if (integer as u128) >> I != 0 {
+            E::halt(format!("Integer {} exceeds {} integer bits", integer, I))
+        }
+        Self::new(mode, (integer as u128) << F)
+    }
+
+    ///
+    /// Returns `true` if the fixed-point number is a constant.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.value.is_constant()
+    }
+
+    ///
+    /// Ejects the fixed-point number as its scaled value `v`, which represents `v / 2^F`.
+    ///
+    pub fn eject_value(&self) -> u128 {
+        to_u128::<E>(self.value.eject_value())
+    }
+
+    ///
+    /// Returns the fixed-point number with the given scaled value, and ensures it is less than `2^(I + F)`.
+    ///
+    fn from_base_field(value: BaseField<E>) -> Self {
+        value.to_lower_bits_le(Self::SIZE_IN_BITS);
+        Self { value }
+    }
+
+    ///
+    /// Halts if the fixed-point number does not fit in 64 bits.
+    ///
+    fn check_size() {
+        if Self::SIZE_IN_BITS > 64 {
+            E::halt(format!(
+                "Fixed-point numbers of {} bits are not supported",
+                Self::SIZE_IN_BITS
+            ))
+        }
+    }
+
+    ///
+    /// Returns the mode of a witness computed from `self` and `other`.
+    ///
+    fn witness_mode(&self, other: &Self) -> Mode {
+        match self.is_constant() && other.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+
+    ///
+    /// Returns the rounding increment, given the remainder of a division by `divisor`,
+    /// which is in `[0, divisor)`, and is less than `2^SIZE_IN_BITS`.
+    ///
+    fn round(mode: RoundingMode, remainder: &BaseField<E>, divisor: &BaseField<E>, witness_mode: Mode) -> Boolean<E> {
+        match mode {
+            RoundingMode::Floor => Boolean::new(Mode::Constant, false),
+            RoundingMode::Ceiling => !remainder.is_zero(),
+            RoundingMode::HalfUp => {
+                let double_remainder = remainder.clone().double();
+                let is_half_or_more = Boolean::new(
+                    witness_mode,
+                    to_u128::<E>(double_remainder.eject_value()) >= to_u128::<E>(divisor.eject_value()),
+                );
+
+                // Ensure `2 * remainder >= divisor` if the increment is one, and `2 * remainder < divisor` otherwise,
+                // by checking the difference is in range.
+                let difference = BaseField::ternary(
+                    &is_half_or_more,
+                    &(&double_remainder - divisor),
+                    &(divisor - &double_remainder - BaseField::one()),
+                );
+                difference.to_lower_bits_le(Self::SIZE_IN_BITS + 1);
+
+                is_half_or_more
+            }
+        }
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> fmt::Debug for FixedPoint<E, I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.eject_value();
+        write!(f, "{}", value >> F)?;
+
+        // Print the fraction as a decimal, which terminates after at most `F` digits.
+        let mask = (1u128 << F) - 1;
+        let mut fraction = value & mask;
+        if fraction != 0 {
+            write!(f, ".")?;
+        }
+        while fraction != 0 {
+            fraction *= 10;
+            write!(f, "{}", fraction >> F)?;
+            fraction &= mask;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Returns the lower 128 bits of the given base field element.
+///
+fn to_u128<E: Environment>(value: E::BaseField) -> u128 {
+    value
+        .to_bits_le()
+        .iter()
+        .take(128)
+        .enumerate()
+        .fold(0u128, |output, (i, bit)| output | ((*bit as u128) << i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type Fixed = FixedPoint<Circuit, 32, 16>;
+
+    #[test]
+    fn test_new() {
+        Circuit::scoped("Private", |scope| {
+            let candidate = Fixed::new(Mode::Private, 3 << 15);
+            assert_eq!(3 << 15, candidate.eject_value());
+            assert_eq!("1.5", format!("{:?}", candidate));
+
+            assert_eq!(0, scope.num_constants_in_scope());
+            assert_eq!(0, scope.num_public_in_scope());
+            assert_eq!(49, scope.num_private_in_scope());
+            assert_eq!(49, scope.num_constraints_in_scope());
+        });
+
+        let candidate = Fixed::from_integer(Mode::Constant, 7);
+        assert_eq!(7 << 16, candidate.eject_value());
+        assert_eq!("7", format!("{:?}", candidate));
+
+        let candidate = Fixed::new(Mode::Constant, 1);
+        assert_eq!("0.0000152587890625", format!("{:?}", candidate));
+
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_new_out_of_range() {
+        let violations = Circuit::diagnose(|| {
+            Fixed::new(Mode::Private, 1 << 48);
+        });
+        assert!(!violations.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_integer_out_of_range() {
+        Fixed::from_integer(Mode::Constant, 1 << 32);
+    }
+}
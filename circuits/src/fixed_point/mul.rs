@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::ops::{Mul, MulAssign};
+
+impl<E: Environment, const I: usize, const F: usize> FixedPoint<E, I, F> {
+    ///
+    /// Returns `(a * b)`, rounded to `F` fractional bits with the given rounding mode,
+    /// and ensures the product does not overflow.
+    ///
+    pub fn mul_with_rounding(&self, other: &Self, mode: RoundingMode) -> Self {
+        let product = &self.value * &other.value;
+        let witness_mode = self.witness_mode(other);
+
+        // Divide the product by `2^F`, as the product of the scaled values has `2F` fractional bits.
+        let product_value = self.eject_value().wrapping_mul(other.eject_value());
+        let quotient = BaseField::new(witness_mode, (product_value >> F).into());
+        let remainder = BaseField::new(witness_mode, (product_value & ((1u128 << F) - 1)).into());
+        let scale = BaseField::new(Mode::Constant, (1u128 << F).into());
+
+        // Ensure product == quotient * 2^F + remainder, where remainder < 2^F.
+        E::assert_eq(&product, &quotient * &scale + &remainder);
+        remainder.to_lower_bits_le(F);
+
+        // As the product is less than 2^128, the range check on the result
+        // also ensures the quotient is the integer quotient of the product.
+        let increment = Self::round(mode, &remainder, &scale, witness_mode);
+        Self::from_base_field(quotient + BaseField::from(&increment))
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Mul<Self> for FixedPoint<E, I, F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Mul<&Self> for FixedPoint<E, I, F> {
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> Mul<&FixedPoint<E, I, F>> for &FixedPoint<E, I, F> {
+    type Output = FixedPoint<E, I, F>;
+
+    /// Returns `(a * b)`, rounded down to `F` fractional bits, and ensures the product does not overflow.
+    fn mul(self, other: &FixedPoint<E, I, F>) -> Self::Output {
+        self.mul_with_rounding(other, RoundingMode::Floor)
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> MulAssign<Self> for FixedPoint<E, I, F> {
+    fn mul_assign(&mut self, other: Self) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, const I: usize, const F: usize> MulAssign<&Self> for FixedPoint<E, I, F> {
+    fn mul_assign(&mut self, other: &Self) {
+        *self = &*self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    type Fixed = FixedPoint<Circuit, 32, 16>;
+
+    fn expected_mul(first: u128, second: u128, mode: RoundingMode) -> u128 {
+        let product = first * second;
+        match mode {
+            RoundingMode::Floor => product >> 16,
+            RoundingMode::Ceiling => (product + (1 << 16) - 1) >> 16,
+            RoundingMode::HalfUp => (product + (1 << 15)) >> 16,
+        }
+    }
+
+    fn check_mul(first: u128, second: u128, mode_a: Mode, mode_b: Mode) {
+        for mode in [RoundingMode::Floor, RoundingMode::Ceiling, RoundingMode::HalfUp] {
+            let a = Fixed::new(mode_a, first);
+            let b = Fixed::new(mode_b, second);
+            let candidate = a.mul_with_rounding(&b, mode);
+            assert_eq!(expected_mul(first, second, mode), candidate.eject_value(), "{:?}", mode);
+            assert!(Circuit::is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        for _ in 0..ITERATIONS {
+            let first = thread_rng().gen_range(0..1u128 << 32);
+            let second = thread_rng().gen_range(0..1u128 << 32);
+
+            check_mul(first, second, Mode::Constant, Mode::Constant);
+            check_mul(first, second, Mode::Constant, Mode::Private);
+            check_mul(first, second, Mode::Private, Mode::Private);
+        }
+    }
+
+    #[test]
+    fn test_mul_rounding() {
+        // 0.75 * 2^-16 is three quarters of the last bit, which rounds down to 0 or up to 1.
+        let three_quarters = Fixed::new(Mode::Private, 3 << 14);
+        let epsilon = Fixed::new(Mode::Private, 1);
+        assert_eq!(0, (&three_quarters * &epsilon).eject_value());
+        assert_eq!(
+            1,
+            three_quarters
+                .mul_with_rounding(&epsilon, RoundingMode::Ceiling)
+                .eject_value()
+        );
+        assert_eq!(
+            1,
+            three_quarters
+                .mul_with_rounding(&epsilon, RoundingMode::HalfUp)
+                .eject_value()
+        );
+
+        // A quarter of the last bit rounds to the nearest value of 0.
+        let quarter = Fixed::new(Mode::Private, 1 << 14);
+        assert_eq!(
+            0,
+            quarter.mul_with_rounding(&epsilon, RoundingMode::HalfUp).eject_value()
+        );
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_mul_overflow() {
+        let a = Fixed::from_integer(Mode::Private, 1 << 16);
+        let b = Fixed::from_integer(Mode::Private, 1 << 16);
+        let violations = Circuit::diagnose(|| {
+            let _ = &a * &b;
+        });
+        assert!(!violations.is_empty());
+    }
+}
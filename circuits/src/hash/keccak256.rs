@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Mode;
+
+use std::marker::PhantomData;
+
+/// The round constants of Keccak-f[1600].
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of Keccak-f[1600], indexed by `x + 5 * y`.
+const ROTATIONS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The number of bits absorbed into the state for each block of Keccak-256.
+const RATE_IN_BITS: usize = 1088;
+
+/// A 64-bit lane of the state, as little-endian bits.
+type Lane<E> = Vec<Boolean<E>>;
+
+///
+/// The Keccak-256 hash function, as used by Ethereum.
+///
+/// This is the original Keccak submission with a capacity of 512 bits, whose padding differs
+/// from the SHA3-256 hash function standardized in FIPS 202.
+///
+pub struct Keccak256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Keccak256<E> {
+    /// The number of bits in a Keccak-256 digest.
+    pub const DIGEST_SIZE_IN_BITS: usize = 256;
+
+    ///
+    /// Returns the Keccak-256 digest of the given bytes, where each byte is 8 little-endian bits.
+    /// The digest is returned as 32 bytes of the same form.
+    ///
+    /// Halts if the number of bits is not a multiple of 8.
+    ///
+    pub fn hash_bytes(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        if input.len() % 8 != 0 {
+            E::halt(format!("Expected a whole number of bytes, found {} bits", input.len()))
+        }
+        Self::hash_bits(input)
+    }
+
+    ///
+    /// Returns the Keccak-256 digest of the given message, as bit strings in the order of the specification,
+    /// where each byte is from its least significant bit to its most significant bit.
+    ///
+    pub fn hash_bits(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Pad the message with a one bit, zero bits, and a one bit, to a multiple of the rate.
+        let mut message = input.to_vec();
+        message.push(Boolean::new(Mode::Constant, true));
+        while message.len() % RATE_IN_BITS != RATE_IN_BITS - 1 {
+            message.push(Boolean::new(Mode::Constant, false));
+        }
+        message.push(Boolean::new(Mode::Constant, true));
+
+        let mut state: Vec<Lane<E>> = (0..25)
+            .map(|_| (0..64).map(|_| Boolean::new(Mode::Constant, false)).collect())
+            .collect();
+        for block in message.chunks(RATE_IN_BITS) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                *lane = xor_bits(lane, bits);
+            }
+            state = Self::permute(state);
+        }
+
+        // Squeeze the digest from the first lanes of the state.
+        state.concat()[..Self::DIGEST_SIZE_IN_BITS].to_vec()
+    }
+
+    ///
+    /// Returns the state after applying the Keccak-f[1600] permutation.
+    ///
+    fn permute(mut state: Vec<Lane<E>>) -> Vec<Lane<E>> {
+        for round_constant in ROUND_CONSTANTS.iter() {
+            // θ: XOR each lane with the parities of two neighbouring columns.
+            let parities: Vec<Lane<E>> = (0..5)
+                .map(|x| (1..5).fold(state[x].clone(), |parity, y| xor_bits(&parity, &state[x + 5 * y])))
+                .collect();
+            for x in 0..5 {
+                let d = xor_bits(&parities[(x + 4) % 5], &rotate_left(&parities[(x + 1) % 5], 1));
+                for y in 0..5 {
+                    state[x + 5 * y] = xor_bits(&state[x + 5 * y], &d);
+                }
+            }
+
+            // ρ and π: rotate each lane, and move it to a new position.
+            let mut permuted = state.clone();
+            for x in 0..5 {
+                for y in 0..5 {
+                    permuted[y + 5 * ((2 * x + 3 * y) % 5)] = rotate_left(&state[x + 5 * y], ROTATIONS[x + 5 * y]);
+                }
+            }
+
+            // χ: XOR each lane with the AND of the negation of the next lane and the lane after it.
+            for x in 0..5 {
+                for y in 0..5 {
+                    let next = &permuted[(x + 1) % 5 + 5 * y];
+                    let after_next = &permuted[(x + 2) % 5 + 5 * y];
+                    state[x + 5 * y] = (0..64)
+                        .map(|i| permuted[x + 5 * y][i].xor(&(!&next[i]).and(&after_next[i])))
+                        .collect();
+                }
+            }
+
+            // ι: XOR the first lane with the round constant, by negating the bits where the constant is set.
+            state[0] = state[0]
+                .iter()
+                .enumerate()
+                .map(|(i, bit)| match (round_constant >> i) & 1 == 1 {
+                    true => !bit,
+                    false => bit.clone(),
+                })
+                .collect();
+        }
+        state
+    }
+}
+
+///
+/// Returns the given lane rotated left by `n` bits.
+///
+fn rotate_left<E: Environment>(lane: &[Boolean<E>], n: usize) -> Lane<E> {
+    (0..64).map(|i| lane[(i + 64 - n) % 64].clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hash::tests::{bits_to_bytes, bytes_to_bits, hex_to_bytes},
+        Circuit,
+    };
+
+    fn check_hash_bytes(
+        name: &str,
+        mode: Mode,
+        message: &[u8],
+        expected: &str,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let input = bytes_to_bits(mode, message);
+        Circuit::scoped(name, |scope| {
+            let candidate = Keccak256::<Circuit>::hash_bytes(&input);
+            assert_eq!(Keccak256::<Circuit>::DIGEST_SIZE_IN_BITS, candidate.len());
+            assert_eq!(hex_to_bytes(expected), bits_to_bytes(&candidate));
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    #[test]
+    fn test_keccak256_constant() {
+        check_hash_bytes(
+            "Empty",
+            Mode::Constant,
+            b"",
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            2688,
+            0,
+            0,
+            0,
+        );
+        check_hash_bytes(
+            "abc",
+            Mode::Constant,
+            b"abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            2664,
+            0,
+            0,
+            0,
+        );
+    }
+
+    #[test]
+    fn test_keccak256_private() {
+        check_hash_bytes(
+            "abc",
+            Mode::Private,
+            b"abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            2664,
+            0,
+            145053,
+            290106,
+        );
+        // Ensure a message longer than the rate is absorbed in two blocks.
+        let message: Vec<u8> = (0..200).map(|i| (i % 251) as u8).collect();
+        check_hash_bytes(
+            "Two blocks",
+            Mode::Private,
+            &message,
+            "bfb0aa97863e797943cf7c33bb7e880bb4543f3d2703c0923c6901c2af57b890",
+            2176,
+            0,
+            306688,
+            613376,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_keccak256_partial_byte() {
+        Keccak256::<Circuit>::hash_bytes(&bytes_to_bits(Mode::Private, b"abc")[..20]);
+    }
+}
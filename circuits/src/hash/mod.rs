@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod keccak256;
+pub use keccak256::*;
+
+pub mod sha256;
+pub use sha256::*;
+
+use crate::{traits::*, Boolean, Environment};
+
+///
+/// Returns the bitwise XOR of the given bit vectors, which have the same length.
+///
+fn xor_bits<E: Environment>(a: &[Boolean<E>], b: &[Boolean<E>]) -> Vec<Boolean<E>> {
+    a.iter().zip(b).map(|(a, b)| a.xor(b)).collect()
+}
+
+///
+/// Returns the given bytes with the order of the bits in each byte reversed.
+/// Halts if the number of bits is not a multiple of 8.
+///
+fn reverse_bits_in_bytes<E: Environment>(bits: &[Boolean<E>]) -> Vec<Boolean<E>> {
+    if bits.len() % 8 != 0 {
+        E::halt(format!("Expected a whole number of bytes, found {} bits", bits.len()))
+    }
+    bits.chunks(8).flat_map(|byte| byte.iter().rev().cloned()).collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{Boolean, Circuit, Mode};
+
+    /// Returns the given bytes as little-endian bits of each byte, in the given mode.
+    pub(crate) fn bytes_to_bits(mode: Mode, bytes: &[u8]) -> Vec<Boolean<Circuit>> {
+        bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| Boolean::new(mode, (byte >> i) & 1 == 1)))
+            .collect()
+    }
+
+    /// Returns the bytes of the given little-endian bits of each byte.
+    pub(crate) fn bits_to_bytes(bits: &[Boolean<Circuit>]) -> Vec<u8> {
+        bits.chunks(8)
+            .map(|byte| {
+                byte.iter()
+                    .enumerate()
+                    .fold(0u8, |acc, (i, bit)| acc | ((bit.eject_value() as u8) << i))
+            })
+            .collect()
+    }
+
+    /// Returns the bytes of the given hexadecimal string.
+    pub(crate) fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{BaseField, Mode};
+
+use std::marker::PhantomData;
+
+/// The initial hash value of SHA-256.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The round constants of SHA-256.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// The number of bits in a block of SHA-256.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+
+/// A 32-bit word, as little-endian bits.
+type Word<E> = Vec<Boolean<E>>;
+
+///
+/// The SHA-256 hash function, as specified in FIPS 180-4.
+///
+/// Words are added modulo 2^32 by summing them in the base field, and decomposing the sum into bits,
+/// so that each addition costs one constraint per bit of the sum, however many words are added.
+///
+pub struct Sha256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Sha256<E> {
+    /// The number of bits in a SHA-256 digest.
+    pub const DIGEST_SIZE_IN_BITS: usize = 256;
+
+    ///
+    /// Returns the SHA-256 digest of the given bytes, where each byte is 8 little-endian bits.
+    /// The digest is returned as 32 bytes of the same form.
+    ///
+    /// Halts if the number of bits is not a multiple of 8.
+    ///
+    pub fn hash_bytes(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        reverse_bits_in_bytes(&Self::hash_bits(&reverse_bits_in_bytes(input)))
+    }
+
+    ///
+    /// Returns the SHA-256 digest of the given message, as bit strings in the order of the specification,
+    /// where each byte is from its most significant bit to its least significant bit.
+    ///
+    pub fn hash_bits(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Pad the message with a one bit, zero bits, and the length of the message as a 64-bit big-endian integer.
+        let mut message = input.to_vec();
+        message.push(Boolean::new(Mode::Constant, true));
+        while message.len() % BLOCK_SIZE_IN_BITS != BLOCK_SIZE_IN_BITS - 64 {
+            message.push(Boolean::new(Mode::Constant, false));
+        }
+        let length = input.len() as u64;
+        message.extend(
+            (0..64)
+                .rev()
+                .map(|i| Boolean::new(Mode::Constant, (length >> i) & 1 == 1)),
+        );
+
+        let mut state: Vec<Word<E>> = IV.iter().map(|word| constant_word(*word)).collect();
+        for block in message.chunks(BLOCK_SIZE_IN_BITS) {
+            state = Self::compress(&state, block);
+        }
+
+        // Output the words of the state in big-endian.
+        state.iter().flat_map(|word| word.iter().rev().cloned()).collect()
+    }
+
+    ///
+    /// Returns the state after compressing the given 512-bit block into the given state.
+    ///
+    fn compress(state: &[Word<E>], block: &[Boolean<E>]) -> Vec<Word<E>> {
+        // Prepare the message schedule, from the big-endian words of the block.
+        let mut schedule: Vec<Word<E>> = block
+            .chunks(32)
+            .map(|word| word.iter().rev().cloned().collect())
+            .collect();
+        for t in 16..64 {
+            let sigma_0 = xor3(
+                &rotate_right(&schedule[t - 15], 7),
+                &rotate_right(&schedule[t - 15], 18),
+                &shift_right(&schedule[t - 15], 3),
+            );
+            let sigma_1 = xor3(
+                &rotate_right(&schedule[t - 2], 17),
+                &rotate_right(&schedule[t - 2], 19),
+                &shift_right(&schedule[t - 2], 10),
+            );
+            let sum =
+                to_field(&schedule[t - 16]) + to_field(&sigma_0) + to_field(&schedule[t - 7]) + to_field(&sigma_1);
+            schedule.push(from_field(&sum, 4));
+        }
+
+        let mut a = state[0].clone();
+        let mut b = state[1].clone();
+        let mut c = state[2].clone();
+        let mut d = state[3].clone();
+        let mut e = state[4].clone();
+        let mut f = state[5].clone();
+        let mut g = state[6].clone();
+        let mut h = state[7].clone();
+
+        for t in 0..64 {
+            let sum_1 = xor3(&rotate_right(&e, 6), &rotate_right(&e, 11), &rotate_right(&e, 25));
+            // Ch(e, f, g) chooses the bit of `f` where `e` is set, and the bit of `g` otherwise.
+            let choose: Word<E> = (0..32).map(|i| Boolean::ternary(&e[i], &f[i], &g[i])).collect();
+            let sum_0 = xor3(&rotate_right(&a, 2), &rotate_right(&a, 13), &rotate_right(&a, 22));
+            // Maj(a, b, c) is the bit of `c` where `a` and `b` differ, and the bit of `a` otherwise.
+            let majority: Word<E> = (0..32)
+                .map(|i| Boolean::ternary(&a[i].xor(&b[i]), &c[i], &a[i]))
+                .collect();
+
+            // The temporary words are only decomposed into bits as part of the new words `e` and `a`.
+            let temp_1 = to_field(&h)
+                + to_field(&sum_1)
+                + to_field(&choose)
+                + BaseField::new(Mode::Constant, (ROUND_CONSTANTS[t] as u128).into())
+                + to_field(&schedule[t]);
+            let temp_2 = to_field(&sum_0) + to_field(&majority);
+
+            h = g;
+            g = f;
+            f = e;
+            e = from_field(&(to_field(&d) + &temp_1), 6);
+            d = c;
+            c = b;
+            b = a;
+            a = from_field(&(temp_1 + temp_2), 7);
+        }
+
+        [a, b, c, d, e, f, g, h]
+            .iter()
+            .zip(state)
+            .map(|(word, previous)| from_field(&(to_field(word) + to_field(previous)), 2))
+            .collect()
+    }
+}
+
+///
+/// Returns the given word as constant bits.
+///
+fn constant_word<E: Environment>(word: u32) -> Word<E> {
+    (0..32)
+        .map(|i| Boolean::new(Mode::Constant, (word >> i) & 1 == 1))
+        .collect()
+}
+
+///
+/// Returns the given word as a base field element.
+///
+fn to_field<E: Environment>(word: &[Boolean<E>]) -> BaseField<E> {
+    let mut accumulator = BaseField::zero();
+    let mut coefficient = BaseField::one();
+    for bit in word {
+        accumulator += BaseField::from(bit) * &coefficient;
+        coefficient = coefficient.double();
+    }
+    accumulator
+}
+
+///
+/// Returns the given sum of `num_words` words, modulo 2^32.
+///
+fn from_field<E: Environment>(sum: &BaseField<E>, num_words: usize) -> Word<E> {
+    // The sum of `n` words is less than `2^(32 + ceil(log2(n)))`.
+    let mut num_carry_bits = 0;
+    while (1 << num_carry_bits) < num_words {
+        num_carry_bits += 1;
+    }
+    let mut bits = sum.to_lower_bits_le(32 + num_carry_bits);
+    bits.truncate(32);
+    bits
+}
+
+///
+/// Returns the bitwise XOR of the given words.
+///
+fn xor3<E: Environment>(a: &[Boolean<E>], b: &[Boolean<E>], c: &[Boolean<E>]) -> Word<E> {
+    xor_bits(&xor_bits(a, b), c)
+}
+
+///
+/// Returns the given word rotated right by `n` bits.
+///
+fn rotate_right<E: Environment>(word: &[Boolean<E>], n: usize) -> Word<E> {
+    (0..32).map(|i| word[(i + n) % 32].clone()).collect()
+}
+
+///
+/// Returns the given word shifted right by `n` bits.
+///
+fn shift_right<E: Environment>(word: &[Boolean<E>], n: usize) -> Word<E> {
+    (0..32)
+        .map(|i| match i + n < 32 {
+            true => word[i + n].clone(),
+            false => Boolean::new(Mode::Constant, false),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hash::tests::{bits_to_bytes, bytes_to_bits, hex_to_bytes},
+        Circuit,
+    };
+
+    fn check_hash_bytes(
+        name: &str,
+        mode: Mode,
+        message: &[u8],
+        expected: &str,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let input = bytes_to_bits(mode, message);
+        Circuit::scoped(name, |scope| {
+            let candidate = Sha256::<Circuit>::hash_bytes(&input);
+            assert_eq!(Sha256::<Circuit>::DIGEST_SIZE_IN_BITS, candidate.len());
+            assert_eq!(hex_to_bytes(expected), bits_to_bytes(&candidate));
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    #[test]
+    fn test_sha256_constant() {
+        check_hash_bytes(
+            "Empty",
+            Mode::Constant,
+            b"",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            7832,
+            0,
+            0,
+            0,
+        );
+        check_hash_bytes(
+            "abc",
+            Mode::Constant,
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            7808,
+            0,
+            0,
+            0,
+        );
+    }
+
+    #[test]
+    fn test_sha256_private() {
+        check_hash_bytes(
+            "abc",
+            Mode::Private,
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            1534,
+            0,
+            24474,
+            42855,
+        );
+        // Ensure a message whose padding does not fit in its last block is hashed in two blocks.
+        check_hash_bytes(
+            "Two blocks",
+            Mode::Private,
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            3840,
+            0,
+            44794,
+            78788,
+        );
+    }
+
+    #[test]
+    fn test_sha256_hash_bits() {
+        // Ensure the bit interface reads each byte from its most significant bit.
+        let input: Vec<_> = bytes_to_bits(Mode::Private, b"abc")
+            .chunks(8)
+            .flat_map(|byte| byte.iter().rev().cloned().collect::<Vec<_>>())
+            .collect();
+        let candidate = Sha256::<Circuit>::hash_bits(&input);
+        let candidate: Vec<_> = candidate
+            .chunks(8)
+            .flat_map(|byte| byte.iter().rev().cloned())
+            .collect();
+        assert_eq!(
+            hex_to_bytes("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            bits_to_bytes(&candidate)
+        );
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sha256_partial_byte() {
+        Sha256::<Circuit>::hash_bytes(&bytes_to_bits(Mode::Private, b"abc")[..20]);
+    }
+}
@@ -26,12 +26,20 @@ pub use address::*;
 pub mod boolean;
 pub use boolean::*;
 
+pub mod cost;
+
 pub mod fields;
 pub use fields::*;
 
+pub mod fixed_point;
+pub use fixed_point::*;
+
 pub mod group;
 pub use group::*;
 
+pub mod hash;
+pub use hash::*;
+
 // TODO (howardwu): This is temporary until the models interface is stabilized.
 #[allow(unused)]
 pub mod models;
@@ -132,6 +132,29 @@ impl Environment for Circuit {
         Self::cs().is_satisfied()
     }
 
+    fn diagnose<Fn>(logic: Fn) -> Vec<Violation<Self::BaseField>>
+    where
+        Fn: FnOnce(),
+    {
+        let cs = Self::cs().cs;
+
+        // Enable diagnostic mode, and run the logic.
+        let (previous, start) = {
+            let mut cs = cs.borrow_mut();
+            (cs.set_diagnostics(true), cs.num_violations())
+        };
+        logic();
+
+        // Restore the previous mode, and return the violations found by the logic.
+        // If this call is nested, the violations are also kept for the enclosing call.
+        let mut cs = cs.borrow_mut();
+        cs.set_diagnostics(previous);
+        match previous {
+            true => cs.violations()[start..].to_vec(),
+            false => cs.take_violations(start),
+        }
+    }
+
     fn num_constants() -> usize {
         Self::cs().num_constants()
     }
@@ -189,3 +212,40 @@ impl Environment for Circuit {
         panic!("{}", &error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Boolean;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_diagnose() {
+        let violations = Circuit::diagnose(|| {
+            Circuit::scoped("first", |_| {
+                let candidate = Boolean::<Circuit>::new(Mode::Private, false);
+                Circuit::assert(&candidate);
+            });
+            Circuit::scoped("second", |_| {
+                Circuit::assert_eq(Circuit::one(), Circuit::zero());
+            });
+            Circuit::scoped("third", |_| {
+                let candidate = Boolean::<Circuit>::new(Mode::Public, true);
+                Circuit::assert(&candidate);
+            });
+        });
+
+        assert_eq!(2, violations.len());
+        assert!(violations[0].scope.ends_with("/first"));
+        assert_eq!(Fq::zero(), violations[0].a);
+        assert_eq!(Fq::one(), violations[0].c);
+        assert!(violations[1].scope.ends_with("/second"));
+        assert_eq!(Fq::zero(), violations[1].c);
+        assert!(!Circuit::is_satisfied());
+
+        // Ensure constraints outside of diagnostic mode are not reported.
+        let candidate = Boolean::<Circuit>::new(Mode::Private, false);
+        Circuit::assert(&candidate);
+        assert!(Circuit::diagnose(|| {}).is_empty());
+    }
+}
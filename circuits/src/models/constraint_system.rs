@@ -28,6 +28,8 @@ pub(super) struct ConstraintSystem<F: PrimeField> {
     transcript: HashMap<Variable<F>, Scope>,
     scopes: HashSet<Scope>,
     counter: CircuitCounter,
+    diagnostics: bool,
+    violations: Vec<Violation<F>>,
 }
 
 impl<F: PrimeField> ConstraintSystem<F> {
@@ -40,6 +42,8 @@ impl<F: PrimeField> ConstraintSystem<F> {
             transcript: Default::default(),
             scopes: Default::default(),
             counter: Default::default(),
+            diagnostics: false,
+            violations: Default::default(),
         }
     }
 
@@ -77,6 +81,20 @@ impl<F: PrimeField> ConstraintSystem<F> {
         let (a, b, c) = constraint();
         let (a, b, c) = (a.into(), b.into(), c.into());
 
+        // In diagnostic mode, check the constraint as it is enforced, so that every
+        // violated constraint is reported with the scope it was enforced in.
+        if self.diagnostics {
+            let (a, b, c) = (a.to_value(), b.to_value(), c.to_value());
+            if a * b != c {
+                self.violations.push(Violation {
+                    scope: scope.clone(),
+                    a,
+                    b,
+                    c,
+                });
+            }
+        }
+
         if !(a.is_constant() && b.is_constant() && c.is_constant()) {
             self.constraints.push((a, b, c));
             self.counter.increment_constraints(&scope);
@@ -96,6 +114,26 @@ impl<F: PrimeField> ConstraintSystem<F> {
         true
     }
 
+    /// Sets whether constraints are checked as they are enforced, and returns the previous setting.
+    pub(super) fn set_diagnostics(&mut self, diagnostics: bool) -> bool {
+        std::mem::replace(&mut self.diagnostics, diagnostics)
+    }
+
+    /// Returns the violated constraints found in diagnostic mode.
+    pub(super) fn violations(&self) -> &[Violation<F>] {
+        &self.violations
+    }
+
+    /// Returns the number of violated constraints found in diagnostic mode.
+    pub(super) fn num_violations(&self) -> usize {
+        self.violations.len()
+    }
+
+    /// Removes and returns the violated constraints found in diagnostic mode, starting from the given index.
+    pub(super) fn take_violations(&mut self, start: usize) -> Vec<Violation<F>> {
+        self.violations.split_off(start)
+    }
+
     pub(super) fn num_constants(&self) -> usize {
         self.constants.len()
     }
@@ -75,6 +75,16 @@ pub trait Environment: Clone {
 
     fn is_satisfied() -> bool;
 
+    ///
+    /// Runs the given logic in diagnostic mode, and returns every constraint it violated.
+    ///
+    /// Each constraint is checked as it is enforced, including constraints on constants,
+    /// and synthesis continues past a violated constraint, so that all violations are reported at once.
+    ///
+    fn diagnose<Fn>(logic: Fn) -> Vec<Violation<Self::BaseField>>
+    where
+        Fn: FnOnce();
+
     fn num_constants() -> usize;
     fn num_public() -> usize;
     fn num_private() -> usize;
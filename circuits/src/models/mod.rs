@@ -36,3 +36,6 @@ pub use linear_combination::*;
 
 pub mod variable;
 pub use variable::*;
+
+pub mod violation;
+pub use violation::*;
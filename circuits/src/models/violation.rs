@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::models::*;
+use snarkvm_fields::traits::*;
+
+use std::fmt;
+
+/// A constraint `(A * B) == C` that does not hold for the ejected values of its variables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation<F: PrimeField> {
+    /// The path of the scope in which the constraint was enforced.
+    pub scope: Scope,
+    /// The ejected value of `A`.
+    pub a: F,
+    /// The ejected value of `B`.
+    pub b: F,
+    /// The ejected value of `C`.
+    pub c: F,
+}
+
+impl<F: PrimeField> fmt::Display for Violation<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} * {} != {}", self.scope, self.a, self.b, self.c)
+    }
+}
@@ -22,7 +22,7 @@ use snarkvm_utilities::{
 };
 
 use criterion::Criterion;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::ops::{AddAssign, MulAssign, SubAssign};
 
@@ -208,6 +208,46 @@ pub(crate) fn bench_fr_double(c: &mut Criterion) {
     });
 }
 
+pub(crate) fn bench_fr_add_assign_near_modulus(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Sample elements near the modulus, so that each addition is reduced.
+    let v: Vec<(Fr, Fr)> = (0..SAMPLES)
+        .map(|_| (-Fr::from(rng.gen::<u64>()), -Fr::from(rng.gen::<u64>())))
+        .collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fr_add_assign_near_modulus", |c| {
+        c.iter(|| {
+            let mut tmp = v[count].0;
+            tmp.add_assign(&v[count].1);
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
+pub(crate) fn bench_fr_double_near_modulus(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Sample elements near the modulus, so that each doubling is reduced.
+    let v: Vec<Fr> = (0..SAMPLES).map(|_| -Fr::from(rng.gen::<u64>())).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fr_double_near_modulus", |c| {
+        c.iter(|| {
+            let mut tmp = v[count];
+            tmp.double_in_place();
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
 pub(crate) fn bench_fr_square(c: &mut Criterion) {
     const SAMPLES: usize = 1000;
 
@@ -84,6 +84,8 @@ criterion_group!(
     bls12_377::fr::bench_fr_sub_assign,
     bls12_377::fr::bench_fr_mul_assign,
     bls12_377::fr::bench_fr_double,
+    bls12_377::fr::bench_fr_add_assign_near_modulus,
+    bls12_377::fr::bench_fr_double_near_modulus,
     bls12_377::fr::bench_fr_square,
     bls12_377::fr::bench_fr_inverse,
     bls12_377::fr::bench_fr_negate,
@@ -46,6 +46,11 @@ use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
 /// print("2-adic gen (g2 % q): ", g2 % q)
 /// print("2-adic gen (g2 * R % q): ", g2 * R % q)
 /// print("2-adic gen into_chunks(g2 * R % q): ", into_chunks(g2 * R % q, 64, 4))
+/// g3 = g ** (o/(2**s * 3))
+/// assert g3.multiplicative_order() == 2**s * 3
+/// print("Large subgroup gen (g3 % q): ", g3 % q)
+/// print("Large subgroup gen (g3 * R % q): ", g3 * R % q)
+/// print("Large subgroup gen into_chunks(g3 * R % q): ", into_chunks(g3 * R % q, 64, 4))
 /// ```
 pub type Fr = Fp256<FrParameters>;
 
@@ -69,6 +74,22 @@ impl FftParameters for FrParameters {
         268534165941069093u64,
         1121515446318641358u64,
     ]);
+    /// SMALL_SUBGROUP_BASE = 3, as (q - 1) = 2^47 * 3 * t' for some t' that is not divisible by 3.
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = 4745010758872139845238200295841730218141082559516036141034422680643841032105
+    /// Encoded in Montgomery form, the value is
+    /// (4745010758872139845238200295841730218141082559516036141034422680643841032105 * R % q) =
+    /// 6305670270485671394103200713230422010059347173612490824610048904823110729716
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        16312050644684472308u64,
+        16226885886700552844u64,
+        8981803609415491252u64,
+        1004551230217910552u64,
+    ]));
 }
 
 impl FieldParameters for FrParameters {
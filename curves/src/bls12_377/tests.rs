@@ -28,6 +28,7 @@ use crate::{
         Fq6Parameters,
         FqParameters,
         Fr,
+        FrParameters,
         G1Affine,
         G1Projective,
         G2Affine,
@@ -83,6 +84,40 @@ fn test_bls12_377_fr() {
     }
 }
 
+#[test]
+fn test_bls12_377_fr_reduction() {
+    // Ensure the reduction of additions, doublings, and inversions near the modulus is unchanged,
+    // for a modulus that leaves the top bit of its backing representation unused.
+    let minus_one = -Fr::one();
+    let minus_two = -Fr::from(2u64);
+    assert_eq!(minus_two, minus_one + minus_one);
+    assert_eq!(minus_two, minus_one.double());
+    assert_eq!(Fr::one(), minus_one * minus_one);
+    assert_eq!(Fr::one(), minus_one.square());
+    assert_eq!(minus_one, minus_one.inverse().unwrap());
+    assert_eq!(Fr::one(), minus_two * minus_two.inverse().unwrap());
+    assert_eq!(Fr::one(), Fr::from(2u64).inverse().unwrap().double());
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..1000 {
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        assert_eq!(a + a, a.double());
+        assert_eq!(a, (a + b) - b);
+
+        // Compare the sum to the sum of the representations, reduced by the modulus.
+        let mut sum = a.to_repr();
+        sum.add_nocarry(&b.to_repr());
+        if sum >= FrParameters::MODULUS {
+            sum.sub_noborrow(&FrParameters::MODULUS);
+        }
+        assert_eq!(a + b, Fr::from_repr(sum).unwrap());
+        if !a.is_zero() {
+            assert_eq!(Fr::one(), a * a.inverse().unwrap());
+        }
+    }
+}
+
 #[test]
 fn test_bls12_377_fq() {
     for _ in 0..ITERATIONS {
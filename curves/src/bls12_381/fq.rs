@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp384,
+    Fp384Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger384 as BigInteger;
+
+pub type Fq = Fp384<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp384Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = 4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x43f5fffffffcaaae,
+        0x32b7fff2ed47fffd,
+        0x7e83a49a2e99d69,
+        0xeca8f3318332bb7a,
+        0xef148d1ea0f4c069,
+        0x40ab3263eff0206,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 2
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x321300000006554f,
+        0xb93c0018d6c40005,
+        0x57605e0db0ddbb51,
+        0x8b256521ed1f9bcb,
+        0x6cf28d7901622c03,
+        0x11ebab9dbb81e28c,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 9940570264628428797u64;
+    /// MODULUS = 4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xb9feffffffffaaab,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 381;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xdcff7fffffffd555,
+        0xf55ffff58a9ffff,
+        0xb39869507b587b12,
+        0xb23ba5c279c2895f,
+        0x258dd3db21a5d66b,
+        0xd0088f51cbff34d,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xf4df1f341c341746,
+        0xa76e6a609d104f1,
+        0x8de5476c4c95b6d5,
+        0x67eb88a9939d83c0,
+        0x9a793e85b519952d,
+        0x11988fe592cae3aa,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 3;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) // 2^S =
+    /// 2001204777610833696708894912867952078278441409969503942666029068062015825245418932221343814564507832018947136279893
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xdcff7fffffffd555,
+        0xf55ffff58a9ffff,
+        0xb39869507b587b12,
+        0xb23ba5c279c2895f,
+        0x258dd3db21a5d66b,
+        0xd0088f51cbff34d,
+    ]);
+    /// (T - 1) // 2 =
+    /// 1000602388805416848354447456433976039139220704984751971333014534031007912622709466110671907282253916009473568139946
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xee7fbfffffffeaaa,
+        0x7aaffffac54ffff,
+        0xd9cc34a83dac3d89,
+        0xd91dd2e13ce144af,
+        0x92c6e9ed90d2eb35,
+        0x680447a8e5ff9a6,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+    const PARAMS_OPT_FOR_WEIGHTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(3, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(4, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(5, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(6, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(7, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(8, 257, 8, 13, 0),
+    ];
+}
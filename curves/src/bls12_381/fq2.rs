@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+
+use snarkvm_fields::{field, Field, Fp2, Fp2Parameters};
+use snarkvm_utilities::biginteger::BigInteger384 as BigInteger;
+
+use crate::bls12_381::Fq;
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: [Fq; 2] = [
+        // NONRESIDUE**(((q^0) - 1) / 2)
+        field!(
+            Fq,
+            BigInteger([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])
+        ),
+        // NONRESIDUE**(((q^1) - 1) / 2)
+        field!(
+            Fq,
+            BigInteger([
+                0x43f5fffffffcaaae,
+                0x32b7fff2ed47fffd,
+                0x7e83a49a2e99d69,
+                0xeca8f3318332bb7a,
+                0xef148d1ea0f4c069,
+                0x40ab3263eff0206,
+            ])
+        ),
+    ];
+    /// NONRESIDUE = -1
+    const NONRESIDUE: Fq = field!(
+        Fq,
+        BigInteger([
+            0x43f5fffffffcaaae,
+            0x32b7fff2ed47fffd,
+            0x7e83a49a2e99d69,
+            0xeca8f3318332bb7a,
+            0xef148d1ea0f4c069,
+            0x40ab3263eff0206,
+        ])
+    );
+    /// QUADRATIC_NONRESIDUE = U + 1
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (
+        field!(
+            Fq,
+            BigInteger([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])
+        ),
+    );
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
+        -(*fe)
+    }
+}
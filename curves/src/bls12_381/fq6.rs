@@ -0,0 +1,288 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    field,
+    fp6_3over2::{Fp6, Fp6Parameters},
+};
+use snarkvm_utilities::biginteger::BigInteger384;
+
+use crate::bls12_381::{Fq, Fq2, Fq2Parameters};
+
+pub type Fq6 = Fp6<Fq6Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq6Parameters;
+
+impl Fp6Parameters for Fq6Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    const FROBENIUS_COEFF_FP6_C1: [Fq2; 6] = [
+        // Fp2::NONRESIDUE^(((q^0) - 1) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x760900000002fffd,
+                    0xebf4000bc40c0002,
+                    0x5f48985753c758ba,
+                    0x77ce585370525745,
+                    0x5c071a97a256ec6d,
+                    0x15f65ec3fa80e493,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^(((q^1) - 1) / 3)
+        field!(
+            Fq2,
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xcd03c9e48671f071,
+                    0x5dab22461fcda5d2,
+                    0x587042afd3851b95,
+                    0x8eb60ebe01bacb9e,
+                    0x3f97d6e83d050d2,
+                    0x18f0206554638741,
+                ])
+            ),
+        ),
+        // Fp2::NONRESIDUE^(((q^2) - 1) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x30f1361b798a64e8,
+                    0xf3b8ddab7ece5a2a,
+                    0x16a8ca3ac61577f7,
+                    0xc26a2ff874fd029b,
+                    0x3636b76660701c6e,
+                    0x51ba4ab241b6160,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^(((q^3) - 1) / 3)
+        field!(
+            Fq2,
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x760900000002fffd,
+                    0xebf4000bc40c0002,
+                    0x5f48985753c758ba,
+                    0x77ce585370525745,
+                    0x5c071a97a256ec6d,
+                    0x15f65ec3fa80e493,
+                ])
+            ),
+        ),
+        // Fp2::NONRESIDUE^(((q^4) - 1) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xcd03c9e48671f071,
+                    0x5dab22461fcda5d2,
+                    0x587042afd3851b95,
+                    0x8eb60ebe01bacb9e,
+                    0x3f97d6e83d050d2,
+                    0x18f0206554638741,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^(((q^5) - 1) / 3)
+        field!(
+            Fq2,
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x30f1361b798a64e8,
+                    0xf3b8ddab7ece5a2a,
+                    0x16a8ca3ac61577f7,
+                    0xc26a2ff874fd029b,
+                    0x3636b76660701c6e,
+                    0x51ba4ab241b6160,
+                ])
+            ),
+        ),
+    ];
+    const FROBENIUS_COEFF_FP6_C2: [Fq2; 6] = [
+        // Fp2::NONRESIDUE^((2*(q^0) - 2) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x760900000002fffd,
+                    0xebf4000bc40c0002,
+                    0x5f48985753c758ba,
+                    0x77ce585370525745,
+                    0x5c071a97a256ec6d,
+                    0x15f65ec3fa80e493,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^1) - 2) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x890dc9e4867545c3,
+                    0x2af322533285a5d5,
+                    0x50880866309b7e2c,
+                    0xa20d1b8c7e881024,
+                    0x14e4f04fe2db9068,
+                    0x14e56d3f1564853a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^2) - 2) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xcd03c9e48671f071,
+                    0x5dab22461fcda5d2,
+                    0x587042afd3851b95,
+                    0x8eb60ebe01bacb9e,
+                    0x3f97d6e83d050d2,
+                    0x18f0206554638741,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^3) - 2) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x7e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x40ab3263eff0206,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^4) - 2) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x30f1361b798a64e8,
+                    0xf3b8ddab7ece5a2a,
+                    0x16a8ca3ac61577f7,
+                    0xc26a2ff874fd029b,
+                    0x3636b76660701c6e,
+                    0x51ba4ab241b6160,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^5) - 2) / 3)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xecfb361b798dba3a,
+                    0xc100ddb891865a2c,
+                    0xec08ff1232bda8e,
+                    0xd5c13cc6f1ca4721,
+                    0x47222a47bf7b5c04,
+                    0x110f184e51c5f59,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+    /// NONRESIDUE = U + 1
+    const NONRESIDUE: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])
+        ),
+    );
+
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue(fe: &Fq2) -> Fq2 {
+        // (c0 + c1 * u) * (1 + u) = (c0 - c1) + (c0 + c1) * u
+        field!(Fq2, fe.c0 - fe.c1, fe.c0 + fe.c1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::UniformRand;
+
+    use super::*;
+
+    #[test]
+    fn test_fq2_mul_nonresidue() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let nqr = Fq2::new(Fq::one(), Fq::one());
+
+        for _ in 0..1000 {
+            let mut a = Fq2::rand(&mut rng);
+            let mut b = a;
+            a = Fq6Parameters::mul_fp2_by_nonresidue(&a);
+            b *= &nqr;
+
+            assert_eq!(a, b);
+        }
+    }
+}
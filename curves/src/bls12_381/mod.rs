@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The BLS12-381 pairing-friendly curve, used by Zcash, Ethereum, and Filecoin.
+//!
+//! `x = -0xd201000000010000`, with `G1: y^2 = x^3 + 4` over `Fq`, and the M-type sextic twist
+//! `G2: y^2 = x^3 + 4(u + 1)` over `Fq2 = Fq[u] / (u^2 + 1)`.
+
+pub mod fr;
+#[doc(inline)]
+pub use fr::*;
+
+pub mod fq;
+#[doc(inline)]
+pub use fq::*;
+
+pub mod fq2;
+#[doc(inline)]
+pub use fq2::*;
+
+pub mod fq6;
+#[doc(inline)]
+pub use fq6::*;
+
+pub mod fq12;
+#[doc(inline)]
+pub use fq12::*;
+
+pub mod g1;
+#[doc(inline)]
+pub use g1::*;
+
+pub mod g2;
+#[doc(inline)]
+pub use g2::*;
+
+pub mod parameters;
+#[doc(inline)]
+pub use parameters::*;
+
+#[cfg(test)]
+mod tests;
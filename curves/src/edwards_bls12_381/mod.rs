@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The twisted Edwards curve embedded in the scalar field of BLS12-381 (Jubjub),
+//! `-x^2 + y^2 = 1 - (10240 / 10241) x^2 y^2`, with a cofactor of 8.
+
+pub mod fq;
+#[doc(inline)]
+pub use fq::*;
+
+pub mod fr;
+#[doc(inline)]
+pub use fr::*;
+
+pub mod parameters;
+#[doc(inline)]
+pub use parameters::*;
+
+#[cfg(test)]
+mod tests;
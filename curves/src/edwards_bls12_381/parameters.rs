@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    edwards_bls12_381::{Fq, Fr},
+    errors::GroupError,
+    templates::twisted_edwards_extended::{Affine, Projective},
+    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+};
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use std::str::FromStr;
+
+pub type EdwardsAffine = Affine<EdwardsParameters>;
+pub type EdwardsProjective = Projective<EdwardsParameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsParameters;
+
+impl ModelParameters for EdwardsParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TwistedEdwardsParameters for EdwardsParameters {
+    type MontgomeryParameters = EdwardsParameters;
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// COEFF_A = -1
+    const COEFF_A: Fq = field!(
+        Fq,
+        BigInteger256([
+            0xfffffffd00000003,
+            0xfb38ec08fffb13fc,
+            0x99ad88181ce5880f,
+            0x5bc8f5f97cd877d8,
+        ])
+    );
+    /// COEFF_D = -(10240 / 10241) =
+    /// 19257038036680949359750312669786877991949435402254120286184196891950884077233
+    const COEFF_D: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x2a522455b974f6b0,
+            0xfc6cc9ef0d9acab3,
+            0x7a08fb94c27628d1,
+            0x57f8f6a8fe0e262e,
+        ])
+    );
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[8];
+    /// COFACTOR_INV =
+    /// 819310549611346726241370945440405716213240158234039660170669895299022906775
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([
+            0x5ed1e3425211a692,
+            0xb32fbed8666fdefa,
+            0xf33189fdfd9789fe,
+            0x304962b3598a0ad,
+        ])
+    );
+
+    /// Multiplication by `a` is just negation.
+    #[inline(always)]
+    fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
+        -*elem
+    }
+}
+
+impl MontgomeryParameters for EdwardsParameters {
+    type TwistedEdwardsParameters = EdwardsParameters;
+
+    /// COEFF_A = 2 * (a + d) / (a - d) = 0xA002
+    const COEFF_A: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x16155fffe9eaa,
+            0x5f22c40043b27956,
+            0x7ae580498c215bd,
+            0x5a701daddb575b1c,
+        ])
+    );
+    /// COEFF_B = 4 / (a - d) = 0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFEFFFF5FFD
+    const COEFF_B: Fq = field!(
+        Fq,
+        BigInteger256([
+            0xfffe9ea40001615c,
+            0x974f1411bc43aea3,
+            0x2bacb82ba108fa62,
+            0x5d21ce451e599495,
+        ])
+    );
+}
+
+impl FromStr for EdwardsAffine {
+    type Err = GroupError;
+
+    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
+        s = s.trim();
+        if s.is_empty() {
+            return Err(GroupError::ParsingEmptyString);
+        }
+        if s.len() < 3 {
+            return Err(GroupError::InvalidString);
+        }
+        if !(s.starts_with('(') && s.ends_with(')')) {
+            return Err(GroupError::InvalidString);
+        }
+        let mut point = Vec::new();
+        for substr in s.split(|c| c == '(' || c == ')' || c == ',' || c == ' ') {
+            if !substr.is_empty() {
+                point.push(Fq::from_str(substr)?);
+            }
+        }
+        if point.len() != 2 {
+            return Err(GroupError::InvalidGroupElement);
+        }
+        let point = EdwardsAffine::new(point[0], point[1]);
+
+        if !point.is_on_curve() {
+            Err(GroupError::InvalidGroupElement)
+        } else {
+            Ok(point)
+        }
+    }
+}
+
+/// GENERATOR_X =
+/// 8076246640662884909881801758704306714034609987455869804520522091855516602923
+const GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger256([
+        0x36d32c5896c2f1c5,
+        0x56f8ca079b9bc5f6,
+        0x98e8d1d8a8683c8d,
+        0x15ad7c5e2e165cc,
+    ])
+);
+
+/// GENERATOR_Y =
+/// 13262374693698910701929044844600465831413122818447359594527400194675274060458
+const GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256([
+        0xfe0637f4ed09b09f,
+        0x5ad237c95e4b2d2c,
+        0xd943ede0fed9a448,
+        0x5e2655b261c9d780,
+    ])
+);
@@ -29,15 +29,21 @@ extern crate thiserror;
 
 pub mod bls12_377;
 
+pub mod bls12_381;
+
 pub mod bw6_761;
 
 pub mod edwards_bls12;
 
+pub mod edwards_bls12_381;
+
 pub mod edwards_bw6;
 
 pub mod errors;
 pub use errors::*;
 
+pub mod secp256k1;
+
 pub mod templates;
 
 #[cfg_attr(test, macro_use)]
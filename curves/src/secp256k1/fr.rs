@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The scalar field of secp256k1, whose modulus uses all 256 bits.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 6;
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x944cf2a220910e04,
+        0x815c829c780589f4,
+        0x55980b07bc222113,
+        0xc702b0d248825b36,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x8,
+        0x0,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 5408259542528602431u64;
+    /// MODULUS = 115792089237316195423570985008687907852837564279074904382605163141518161494337
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 256;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xdfe92f46681b20a0,
+        0x5d576e7357a4501d,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x1,
+        0x0,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x896cf21467d7d140,
+        0x741496c20e7cf878,
+        0xe697f5e45bcd07c6,
+        0x9d671cd581c69bc5,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 0;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) // 2^S =
+    /// 1809251394333065553493296640760748560200586941860545380978205674086221273349
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xeeff497a3340d905,
+        0xfaeabb739abd2280,
+        0xffffffffffffffff,
+        0x3ffffffffffffff,
+    ]);
+    /// (T - 1) // 2 =
+    /// 904625697166532776746648320380374280100293470930272690489102837043110636674
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x777fa4bd19a06c82,
+        0xfd755db9cd5e9140,
+        0xffffffffffffffff,
+        0x1ffffffffffffff,
+    ]);
+}
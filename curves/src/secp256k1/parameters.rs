@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    secp256k1::{Fq, Fr},
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+pub type Secp256k1Affine = Affine<Secp256k1Parameters>;
+pub type Secp256k1Projective = Projective<Secp256k1Parameters>;
+
+///
+/// The secp256k1 curve `y^2 = x^3 + 7`, as specified in SEC 2, which is used for ECDSA signatures
+/// in Bitcoin and Ethereum. It is not pairing-friendly, and is provided for verifying such signatures.
+///
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1Parameters;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Secp256k1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 7
+    const COEFF_B: Fq = field!(Fq, BigInteger256([0x700001ab7, 0x0, 0x0, 0x0]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([0x402da1732fc9bebf, 0x4551231950b75fc4, 0x1, 0x0])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+///
+/// GENERATOR_X =
+/// 55066263022277343669578718895168534326250603453777594175500187360389116729240
+///
+pub const GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger256::new([
+        0xd7362e5a487e2097,
+        0x231e295329bc66db,
+        0x979f48c033fd129c,
+        0x9981e643e9089f48
+    ])
+);
+
+///
+/// GENERATOR_Y =
+/// 32670510020758816978083085130507043184471273380659243275938904335757337482424
+///
+pub const GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256::new([
+        0xb15ea6d2d3dbabe2,
+        0x8dfc5d5d1f1dc64d,
+        0x70b6b59aac19c136,
+        0xcf3f851fd4a582d6
+    ])
+);
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    secp256k1::*,
+    traits::{
+        tests_curve::curve_tests,
+        tests_field::{field_test, primefield_test, sqrt_field_test},
+        tests_group::group_test,
+        AffineCurve,
+        Group,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{FftField, Field, One, SquareRootField, Zero};
+
+use std::str::FromStr;
+
+#[test]
+fn test_secp256k1_fr() {
+    let a: Fr = rand::random();
+    let b: Fr = rand::random();
+    field_test(a, b);
+    primefield_test::<Fr>();
+    sqrt_field_test(a);
+}
+
+#[test]
+fn test_secp256k1_fq() {
+    let a: Fq = rand::random();
+    let b: Fq = rand::random();
+    field_test(a, b);
+    primefield_test::<Fq>();
+    sqrt_field_test(a);
+}
+
+#[test]
+fn test_full_width_modulus() {
+    // Ensure arithmetic is correct when intermediate values exceed 256 bits.
+    let minus_one = -Fq::one();
+    let minus_two = -Fq::from(2u64);
+    assert_eq!(minus_two, minus_one + minus_one);
+    assert_eq!(minus_two, minus_one.double());
+    assert_eq!(Fq::one(), minus_one * minus_one);
+    assert_eq!(Fq::one(), minus_one.square());
+    assert_eq!(Fq::one(), minus_two * minus_two.inverse().unwrap());
+    assert_eq!(
+        Fq::from_str("115792089237316195423570985008687907853269984665640564039457584007908834671662").unwrap(),
+        minus_one
+    );
+
+    let minus_one = -Fr::one();
+    assert_eq!(-Fr::from(2u64), minus_one + minus_one);
+    assert_eq!(Fr::one(), minus_one * minus_one);
+    assert_eq!(Fr::one(), minus_one * minus_one.inverse().unwrap());
+}
+
+#[test]
+fn test_root_of_unity() {
+    assert_eq!(
+        Fq::two_adic_root_of_unity().pow([1 << FqParameters::TWO_ADICITY]),
+        Fq::one()
+    );
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+
+    assert_eq!(
+        Fr::two_adic_root_of_unity().pow([1 << FrParameters::TWO_ADICITY]),
+        Fr::one()
+    );
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<Secp256k1Projective>();
+}
+
+#[test]
+fn test_projective_group() {
+    for _i in 0..10 {
+        let a = rand::random();
+        let b = rand::random();
+        group_test::<Secp256k1Projective>(a, b);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+
+    // Ensure the generator matches the test vector for 2G.
+    let double = generator.into_projective().double().into_affine();
+    assert_eq!(
+        Fq::from_str("89565891926547004231252920425935692360644145829622209833684329913297188986597").unwrap(),
+        double.x
+    );
+    assert_eq!(
+        Fq::from_str("12158399299693830322967808612713398636155367887041628176798871954788371653930").unwrap(),
+        double.y
+    );
+
+    // Ensure the generator has order equal to the scalar field modulus.
+    assert!((generator * -Fr::one() + &generator).is_zero());
+}
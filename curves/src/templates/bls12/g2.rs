@@ -22,7 +22,13 @@ use crate::{
     traits::{AffineCurve, ShortWeierstrassParameters},
 };
 use snarkvm_fields::{Field, Fp2, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, errors::SerializationError, serialize::*, ToBytes};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    errors::SerializationError,
+    serialize::*,
+    vec_with_bounded_capacity,
+    ToBytes,
+};
 
 use std::io::{Result as IoResult, Write};
 
@@ -77,7 +83,7 @@ impl<P: Bls12Parameters> ToBytes for G2Prepared<P> {
 impl<P: Bls12Parameters> FromBytes for G2Prepared<P> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let ell_coeffs_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut ell_coeffs = Vec::with_capacity(ell_coeffs_len as usize);
+        let mut ell_coeffs = vec_with_bounded_capacity(ell_coeffs_len as usize);
         for _ in 0..ell_coeffs_len {
             let coeff_1: Fp2<P::Fp2Params> = FromBytes::read_le(&mut reader)?;
             let coeff_2: Fp2<P::Fp2Params> = FromBytes::read_le(&mut reader)?;
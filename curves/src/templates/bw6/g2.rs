@@ -22,7 +22,13 @@ use crate::{
     traits::{AffineCurve, ShortWeierstrassParameters},
 };
 use snarkvm_fields::{Field, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, errors::SerializationError, serialize::*, ToBytes};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    errors::SerializationError,
+    serialize::*,
+    vec_with_bounded_capacity,
+    ToBytes,
+};
 
 use std::{
     io::{Result as IoResult, Write},
@@ -87,7 +93,7 @@ impl<P: BW6Parameters> ToBytes for G2Prepared<P> {
 impl<P: BW6Parameters> FromBytes for G2Prepared<P> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let ell_coeffs_1_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut ell_coeffs_1 = Vec::with_capacity(ell_coeffs_1_len as usize);
+        let mut ell_coeffs_1 = vec_with_bounded_capacity(ell_coeffs_1_len as usize);
         for _ in 0..ell_coeffs_1_len {
             let coeff_1: P::Fp = FromBytes::read_le(&mut reader)?;
             let coeff_2: P::Fp = FromBytes::read_le(&mut reader)?;
@@ -96,7 +102,7 @@ impl<P: BW6Parameters> FromBytes for G2Prepared<P> {
         }
 
         let ell_coeffs_2_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut ell_coeffs_2 = Vec::with_capacity(ell_coeffs_2_len as usize);
+        let mut ell_coeffs_2 = vec_with_bounded_capacity(ell_coeffs_2_len as usize);
         for _ in 0..ell_coeffs_2_len {
             let coeff_1: P::Fp = FromBytes::read_le(&mut reader)?;
             let coeff_2: P::Fp = FromBytes::read_le(&mut reader)?;
@@ -175,8 +175,10 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+/// The version 1 encoding of a point is the little-endian bits of its x-coordinate,
+/// followed by the lowest bit of its y-coordinate, and the infinity flag.
 impl<P: Parameters> ToMinimalBits for Affine<P> {
-    fn to_minimal_bits(&self) -> Vec<bool> {
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
         let mut res_bits = self.x.to_bits_le();
         res_bits.push(*self.y.to_bits_le().first().unwrap());
         res_bits.push(self.infinity);
@@ -217,8 +217,9 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+/// The version 1 encoding of a point is the little-endian bits of its x-coordinate.
 impl<P: Parameters> ToMinimalBits for Affine<P> {
-    fn to_minimal_bits(&self) -> Vec<bool> {
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
         self.x.to_bits_le()
     }
 }
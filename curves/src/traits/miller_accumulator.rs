@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::{PairingCurve, PairingEngine};
+use snarkvm_fields::{One, Zero};
+
+use rand::Rng;
+
+/// Accumulates the Miller loops of many pairing product equations of the form
+/// `e(a_1, b_1) * ... * e(a_n, b_n) == 1`, and checks all of them with a single
+/// final exponentiation.
+///
+/// To ensure one invalid equation cannot be cancelled out by another, each equation
+/// must be added with an independent randomizer, sampled with `MillerAccumulator::randomizer`.
+#[derive(Derivative)]
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+pub struct MillerAccumulator<E: PairingEngine> {
+    /// The product of the Miller loops of all added equations.
+    accumulator: E::Fqk,
+    /// The number of equations added to the accumulator.
+    num_equations: usize,
+}
+
+impl<E: PairingEngine> MillerAccumulator<E> {
+    /// Initializes a new, empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            accumulator: E::Fqk::one(),
+            num_equations: 0,
+        }
+    }
+
+    /// Samples a nonzero 128-bit randomizer for an equation.
+    pub fn randomizer<R: Rng + ?Sized>(rng: &mut R) -> E::Fr {
+        loop {
+            let randomizer = E::Fr::from(rng.gen::<u128>());
+            if !randomizer.is_zero() {
+                return randomizer;
+            }
+        }
+    }
+
+    /// Adds the equation `e(a_1, b_1) * ... * e(a_n, b_n) == 1`, with each `a_i` scaled by the given randomizer.
+    pub fn add_randomized<'a, I>(&mut self, pairs: I, randomizer: E::Fr)
+    where
+        I: IntoIterator<Item = (E::G1Affine, &'a <E::G2Affine as PairingCurve>::Prepared)>,
+    {
+        let pairs: Vec<_> = pairs
+            .into_iter()
+            .map(|(a, b)| ((a * randomizer).prepare(), b))
+            .collect();
+
+        self.accumulator *= E::miller_loop(pairs.iter().map(|(a, b)| (a, *b)));
+        self.num_equations += 1;
+    }
+
+    /// Adds the equation `e(a_1, b_1) * ... * e(a_n, b_n) == 1`, without a randomizer.
+    ///
+    /// This is only sound if no other equation is added, or if the `a_i` have already been randomized.
+    pub fn add_prepared<'a, I>(&mut self, pairs: I)
+    where
+        I: Iterator<
+            Item = (
+                &'a <E::G1Affine as PairingCurve>::Prepared,
+                &'a <E::G2Affine as PairingCurve>::Prepared,
+            ),
+        >,
+    {
+        self.accumulator *= E::miller_loop(pairs);
+        self.num_equations += 1;
+    }
+
+    /// Adds the equations of the given accumulator to this accumulator.
+    pub fn merge(&mut self, other: &Self) {
+        self.accumulator *= other.accumulator;
+        self.num_equations += other.num_equations;
+    }
+
+    /// Returns the number of equations added to the accumulator.
+    pub fn num_equations(&self) -> usize {
+        self.num_equations
+    }
+
+    /// Returns `true` if all of the added equations hold, with overwhelming probability.
+    pub fn verify(&self) -> bool {
+        match E::final_exponentiation(&self.accumulator) {
+            Some(result) => result.is_one(),
+            None => false,
+        }
+    }
+}
+
+impl<E: PairingEngine> Default for MillerAccumulator<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bls12_377::{Bls12_377, G1Affine, G1Projective, G2Affine, G2Projective},
+        traits::{Group, ProjectiveCurve},
+    };
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    #[test]
+    fn test_miller_accumulator() {
+        let rng = &mut test_rng();
+
+        let a: G1Affine = G1Projective::rand(rng).into_affine();
+        let b: G2Affine = G2Projective::rand(rng).into_affine();
+        let b_prepared = b.prepare();
+        let double_b_prepared = b.double().prepare();
+
+        // e(a, b) * e(-a, b) == 1.
+        let valid = vec![(a, &b_prepared), (-a, &b_prepared)];
+        // e(a, b) * e(-a, 2b) != 1.
+        let invalid = vec![(a, &b_prepared), (-a, &double_b_prepared)];
+
+        let mut accumulator = MillerAccumulator::<Bls12_377>::new();
+        assert!(accumulator.verify());
+
+        for _ in 0..3 {
+            let randomizer = MillerAccumulator::<Bls12_377>::randomizer(rng);
+            accumulator.add_randomized(valid.clone(), randomizer);
+        }
+        assert_eq!(3, accumulator.num_equations());
+        assert!(accumulator.verify());
+
+        let mut candidate = accumulator;
+        candidate.add_randomized(invalid, MillerAccumulator::<Bls12_377>::randomizer(rng));
+        assert!(!candidate.verify());
+
+        // e(2a, b) * e(-a, 2b) == 1, without a randomizer.
+        let double_a_prepared = a.double().prepare();
+        let neg_a_prepared = (-a).prepare();
+        let mut other = MillerAccumulator::<Bls12_377>::default();
+        other.add_prepared(vec![(&double_a_prepared, &b_prepared), (&neg_a_prepared, &double_b_prepared)].into_iter());
+
+        accumulator.merge(&other);
+        assert_eq!(4, accumulator.num_equations());
+        assert!(accumulator.verify());
+    }
+}
@@ -17,6 +17,9 @@
 pub mod group;
 pub use group::*;
 
+pub mod miller_accumulator;
+pub use miller_accumulator::*;
+
 pub mod pairing_engine;
 pub use pairing_engine::*;
 
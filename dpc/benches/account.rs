@@ -56,10 +56,22 @@ fn account_address(c: &mut Criterion) {
     });
 }
 
+fn account_derive_addresses(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    c.bench_function("account_derive_addresses_1000", move |b| {
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+
+        b.iter(|| {
+            let _addresses = private_key.derive_addresses(0..1000).unwrap();
+        })
+    });
+}
+
 criterion_group! {
     name = account;
     config = Criterion::default().sample_size(20);
-    targets = account_private_key, account_view_key, account_address
+    targets = account_private_key, account_view_key, account_address, account_derive_addresses
 }
 
 criterion_main!(account);
@@ -29,14 +29,14 @@ fn noop_program_setup(c: &mut Criterion) {
     c.bench_function("NoopProgram::setup", move |b| {
         b.iter(|| {
             // Compute the proving key and verifying key.
-            let (_proving_key, verifying_key) = <<Testnet2 as Network>::ProgramSNARK as SNARK>::setup(
-                &SynthesizedCircuit::<Testnet2>::Noop(Default::default()),
+            let (_proving_key, verifying_key) = <<Testnet2 as NetworkParameters>::ProgramSNARK as SNARK>::setup(
+                &SynthesizedCircuit::<Testnet2>::Noop(ProgramPublicVariables::blank()),
                 &mut *Testnet2::program_srs(rng).borrow_mut(),
             )
             .unwrap();
 
             // Compute the circuit ID.
-            let _circuit_id = <Testnet2 as Network>::function_id(&verifying_key).unwrap();
+            let _circuit_id = <Testnet2 as NetworkParameters>::function_id(&verifying_key).unwrap();
         })
     });
 }
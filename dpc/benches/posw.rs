@@ -16,7 +16,7 @@
 
 use std::sync::atomic::AtomicBool;
 
-use snarkvm_dpc::{testnet2::Testnet2, BlockTemplate, Network, PoSWScheme};
+use snarkvm_dpc::{testnet2::Testnet2, BlockTemplate, NetworkParameters, PoSWScheme, ProofOfSuccinctWork};
 
 use criterion::{criterion_group, criterion_main, Criterion};
 use rand::SeedableRng;
@@ -54,6 +54,20 @@ fn marlin_posw(c: &mut Criterion) {
             let _is_valid = Testnet2::posw().verify_from_block_header(Testnet2::genesis_block().header());
         });
     });
+
+    // Benchmark the PoSW algorithm of each version through the same interface.
+    let registry = Testnet2::posw_registry();
+    for version in registry.versions() {
+        let algorithm = registry.get(version).unwrap();
+
+        group.bench_function(format!("prove_once_unchecked_{}", version), |b| {
+            b.iter(|| {
+                algorithm
+                    .prove_once_unchecked(&block_template, &AtomicBool::new(false), rng)
+                    .unwrap();
+            });
+        });
+    }
 }
 
 criterion_group! {
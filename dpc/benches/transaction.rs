@@ -21,6 +21,7 @@ use snarkvm_dpc::{prelude::*, testnet1::*, testnet2::*};
 
 use criterion::Criterion;
 use rand::thread_rng;
+use std::sync::atomic::AtomicBool;
 
 fn testnet1_coinbase_transaction(c: &mut Criterion) {
     let rng = &mut thread_rng();
@@ -48,10 +49,45 @@ fn testnet2_coinbase_transaction(c: &mut Criterion) {
     });
 }
 
+fn testnet2_transfer_transaction(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let account = Account::<Testnet2>::new(rng);
+    let recipient = Account::<Testnet2>::new(rng).address();
+
+    // Mine a block to the account, to obtain a spendable record.
+    let mut ledger = Ledger::<Testnet2>::new().unwrap();
+    let record = ledger
+        .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+        .unwrap();
+    let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+
+    let amount = AleoAmount::from_aleo(1);
+    let fee = record.value().sub(amount);
+    let request = Request::new_transfer(
+        account.private_key(),
+        vec![record],
+        vec![ledger_proof, LedgerProof::default()],
+        recipient,
+        amount,
+        fee,
+        true,
+        rng,
+    )
+    .unwrap();
+    let ledger_tree = ledger.to_ledger_tree().clone();
+
+    c.bench_function("testnet2_transfer_transaction", move |b| {
+        b.iter(|| {
+            let _transaction = Transaction::<Testnet2>::new(ledger_tree.clone(), &request, rng).unwrap();
+        })
+    });
+}
+
 criterion_group! {
     name = transaction;
     config = Criterion::default().sample_size(10);
-    targets = testnet1_coinbase_transaction, testnet2_coinbase_transaction
+    targets = testnet1_coinbase_transaction, testnet2_coinbase_transaction, testnet2_transfer_transaction
 }
 
 criterion_main!(transaction);
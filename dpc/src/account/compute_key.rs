@@ -56,21 +56,29 @@ impl<N: Network> ComputeKey<N> {
 
     /// Derives the account compute key from an account private key.
     pub fn from_private_key(private_key: &PrivateKey<N>) -> Self {
-        // Compute G^sk_sig.
-        let pk_sig = N::account_signature_scheme().g_scalar_multiply(&private_key.sk_sig);
+        private_key.to_compute_key()
+    }
+
+    /// Derives the account compute key from the signature secret key and randomizer of a private key.
+    pub(super) fn from_signature_secret_key(sk_sig: &N::ProgramScalarField, r_sig: &N::ProgramScalarField) -> Self {
+        // Compute pk_sig, which is G^sk_sig for Schnorr-style signature schemes.
+        let pk_sig_scalar = N::account_signature_scheme()
+            .pk_sig_scalar(sk_sig)
+            .expect("Failed to derive the signature root public key");
+        let pk_sig = N::account_signature_scheme().g_scalar_multiply(&pk_sig_scalar);
 
         // Compute G^r_sig.
-        let pr_sig = N::account_signature_scheme().g_scalar_multiply(&private_key.r_sig);
+        let pr_sig = N::account_signature_scheme().g_scalar_multiply(r_sig);
 
         Self::new(pk_sig, pr_sig)
     }
 
     pub fn from_signature(signature: &N::AccountSignature) -> Result<Self, AccountError> {
-        // Extract G^sk_sig.
-        let pk_sig = N::AccountSignatureScheme::pk_sig(signature)?;
+        // Extract pk_sig.
+        let pk_sig = N::account_signature_scheme().pk_sig(signature)?;
 
         // Extract G^r_sig.
-        let pr_sig = N::AccountSignatureScheme::pr_sig(signature)?;
+        let pr_sig = N::account_signature_scheme().pr_sig(signature)?;
 
         Ok(Self::new(pk_sig, pr_sig))
     }
@@ -20,6 +20,7 @@ use crate::{
     Address,
     ComputeKey,
     Network,
+    ACCOUNT_SEED_CHILD_DOMAIN,
     ACCOUNT_SEED_R_SIG_DOMAIN,
     ACCOUNT_SEED_SK_SIG_DOMAIN,
 };
@@ -29,7 +30,8 @@ use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
 use base58::{FromBase58, ToBase58};
 use rand::{CryptoRng, Rng};
-use std::{fmt, str::FromStr};
+use rayon::prelude::*;
+use std::{fmt, ops::Range, str::FromStr};
 
 #[derive(Derivative)]
 #[derivative(
@@ -41,6 +43,8 @@ pub struct PrivateKey<N: Network> {
     seed: N::AccountSeed,
     pub(super) sk_sig: N::ProgramScalarField,
     pub(super) r_sig: N::ProgramScalarField,
+    /// The compute key is derived once, as it is needed for the address, view key, and serial numbers.
+    compute_key: ComputeKey<N>,
 }
 
 impl<N: Network> PrivateKey<N> {
@@ -67,14 +71,51 @@ impl<N: Network> PrivateKey<N> {
         Address::from_private_key(self)
     }
 
-    /// Returns a reference to the account compute key.
+    /// Returns the account compute key.
     pub fn to_compute_key(&self) -> ComputeKey<N> {
-        ComputeKey::from_private_key(self)
+        self.compute_key.clone()
     }
 
-    /// Returns the decryption key.
+    /// Returns the decryption key, which is the discrete logarithm of the address.
     pub fn to_decryption_key(&self) -> N::ProgramScalarField {
-        self.sk_sig + self.r_sig + self.to_compute_key().sk_prf()
+        let pk_sig_scalar = N::account_signature_scheme()
+            .pk_sig_scalar(&self.sk_sig)
+            .expect("Failed to derive the signature root public key");
+        pk_sig_scalar + self.r_sig + self.compute_key.sk_prf()
+    }
+
+    /// Returns the child private key at the given index.
+    ///
+    /// The child seed is derived as PRF(seed, child_domain || index), so a wallet
+    /// can recover every child private key from this private key alone.
+    pub fn derive_child(&self, index: u32) -> Result<Self, AccountError> {
+        Self::derive_child_with_domain(&self.seed, Self::child_domain(), index)
+    }
+
+    /// Returns the addresses of the child private keys at the given indices.
+    ///
+    /// The child domain separator is computed once, and the children are derived in parallel.
+    pub fn derive_addresses(&self, indices: Range<u32>) -> Result<Vec<Address<N>>, AccountError> {
+        let child_domain = Self::child_domain();
+        indices
+            .into_par_iter()
+            .map(|index| Ok(Self::derive_child_with_domain(&self.seed, child_domain, index)?.to_address()))
+            .collect()
+    }
+
+    /// Returns the child domain separator.
+    fn child_domain() -> N::ProgramScalarField {
+        N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_CHILD_DOMAIN.as_bytes())
+    }
+
+    /// Returns the child private key at the given index, for the given seed and child domain separator.
+    fn derive_child_with_domain(
+        seed: &N::AccountSeed,
+        child_domain: N::ProgramScalarField,
+        index: u32,
+    ) -> Result<Self, AccountError> {
+        let child_seed = N::AccountSeedPRF::evaluate(seed, &vec![child_domain, N::ProgramScalarField::from(index)])?;
+        Ok(Self::from(&FromBytes::read_le(&child_seed.to_bytes_le()?[..])?))
     }
 }
 
@@ -89,12 +130,16 @@ impl<N: Network> From<&N::AccountSeed> for PrivateKey<N> {
         let r_sig_input = format!("{}_{}", ACCOUNT_SEED_R_SIG_DOMAIN, 0);
         let r_sig_domain = N::ProgramScalarField::from_bytes_le_mod_order(r_sig_input.as_bytes());
 
+        let sk_sig = N::AccountSeedPRF::evaluate(seed, &vec![sk_sig_domain])
+            .expect("Failed to derive private key component for PRF(seed, sk_sig_domain)");
+        let r_sig = N::AccountSeedPRF::evaluate(seed, &vec![r_sig_domain])
+            .expect("Failed to derive private key component for PRF(seed, r_sig_domain)");
+
         Self {
             seed: seed.clone(),
-            sk_sig: N::AccountSeedPRF::evaluate(seed, &vec![sk_sig_domain])
-                .expect("Failed to derive private key component for PRF(seed, sk_sig_domain)"),
-            r_sig: N::AccountSeedPRF::evaluate(seed, &vec![r_sig_domain])
-                .expect("Failed to derive private key component for PRF(seed, r_sig_domain)"),
+            sk_sig,
+            r_sig,
+            compute_key: ComputeKey::from_signature_secret_key(&sk_sig, &r_sig),
         }
     }
 }
@@ -16,7 +16,16 @@
 
 #[cfg(test)]
 mod testnet1 {
-    use crate::{testnet1::Testnet1, Account, AccountScheme, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet1::Testnet1,
+        Account,
+        AccountScheme,
+        Address,
+        NetworkConstants,
+        NetworkCrypto,
+        PrivateKey,
+        ViewKey,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
     use snarkvm_utilities::{FromBytes, ToBytes};
@@ -255,7 +264,7 @@ mod testnet1 {
             assert_eq!(expected_signature, serde_json::from_str(&candidate_string).unwrap());
             assert_eq!(
                 expected_signature,
-                <Testnet1 as Network>::AccountSignature::from_str(expected_string).unwrap()
+                <Testnet1 as NetworkCrypto>::AccountSignature::from_str(expected_string).unwrap()
             );
         }
     }
@@ -282,7 +291,7 @@ mod testnet1 {
             assert_eq!(expected_signature, bincode::deserialize(&expected_bytes[..]).unwrap());
             assert_eq!(
                 expected_signature,
-                <Testnet1 as Network>::AccountSignature::read_le(&expected_bytes[..]).unwrap()
+                <Testnet1 as NetworkCrypto>::AccountSignature::read_le(&expected_bytes[..]).unwrap()
             );
         }
     }
@@ -290,7 +299,16 @@ mod testnet1 {
 
 #[cfg(test)]
 mod testnet2 {
-    use crate::{testnet2::Testnet2, Account, AccountScheme, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet2::Testnet2,
+        Account,
+        AccountScheme,
+        Address,
+        NetworkConstants,
+        NetworkCrypto,
+        PrivateKey,
+        ViewKey,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
     use snarkvm_utilities::{FromBytes, ToBytes};
@@ -342,6 +360,30 @@ mod testnet2 {
         assert_eq!(ALEO_TESTNET2_ADDRESS, address.to_string());
     }
 
+    #[test]
+    fn test_child_derivation() {
+        let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
+        let addresses = private_key.derive_addresses(0..10).unwrap();
+        assert_eq!(10, addresses.len());
+
+        for (index, address) in addresses.iter().enumerate() {
+            let child = private_key.derive_child(index as u32).unwrap();
+            assert!(child.is_valid());
+            assert_eq!(*address, child.to_address());
+            assert_ne!(private_key, child);
+
+            // Ensure the child private key round-trips through its string representation.
+            let candidate = PrivateKey::<Testnet2>::from_str(&child.to_string()).unwrap();
+            assert_eq!(child, candidate);
+            assert_eq!(*address, candidate.to_address());
+        }
+
+        // Ensure the child addresses are distinct.
+        for (i, address) in addresses.iter().enumerate() {
+            assert!(addresses[i + 1..].iter().all(|other| other != address));
+        }
+    }
+
     #[test]
     fn test_private_key_from_str() {
         let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
@@ -530,7 +572,7 @@ mod testnet2 {
             assert_eq!(expected_signature, serde_json::from_str(&candidate_string).unwrap());
             assert_eq!(
                 expected_signature,
-                <Testnet2 as Network>::AccountSignature::from_str(expected_string).unwrap()
+                <Testnet2 as NetworkCrypto>::AccountSignature::from_str(expected_string).unwrap()
             );
         }
     }
@@ -557,8 +599,50 @@ mod testnet2 {
             assert_eq!(expected_signature, bincode::deserialize(&expected_bytes[..]).unwrap());
             assert_eq!(
                 expected_signature,
-                <Testnet2 as Network>::AccountSignature::read_le(&expected_bytes[..]).unwrap()
+                <Testnet2 as NetworkCrypto>::AccountSignature::read_le(&expected_bytes[..]).unwrap()
             );
         }
     }
+
+    #[test]
+    fn test_hash_based_account_signature_scheme() {
+        use snarkvm_algorithms::{crh::PoseidonCRH, signature::WinternitzAccountSignatureScheme};
+        use snarkvm_gadgets::{
+            algorithms::{crh::PoseidonCRHGadget, signature::WinternitzAccountSignatureSchemeGadget},
+            traits::algorithms::SignatureGadget,
+        };
+
+        type ProgramCurveParameters = <Testnet2 as NetworkCrypto>::ProgramCurveParameters;
+        type InnerScalarField = <Testnet2 as NetworkCrypto>::InnerScalarField;
+
+        // Ensure a hash-based signature scheme may be used as the account signature scheme of a network.
+        fn check_account_signature_scheme<S, G>()
+        where
+            S: SignatureScheme<
+                    PrivateKey = (
+                        <Testnet2 as NetworkCrypto>::ProgramScalarField,
+                        <Testnet2 as NetworkCrypto>::ProgramScalarField,
+                    ),
+                    PublicKey = <Testnet2 as NetworkCrypto>::ProgramAffineCurve,
+                > + SignatureSchemeOperations<
+                    AffineCurve = <Testnet2 as NetworkCrypto>::ProgramAffineCurve,
+                    BaseField = <Testnet2 as NetworkCrypto>::ProgramBaseField,
+                    ScalarField = <Testnet2 as NetworkCrypto>::ProgramScalarField,
+                    Signature = <S as SignatureScheme>::Signature,
+                >,
+            G: SignatureGadget<S, InnerScalarField>,
+        {
+        }
+
+        check_account_signature_scheme::<
+            WinternitzAccountSignatureScheme<ProgramCurveParameters, PoseidonCRH<InnerScalarField, 3>, 64>,
+            WinternitzAccountSignatureSchemeGadget<
+                ProgramCurveParameters,
+                PoseidonCRH<InnerScalarField, 3>,
+                PoseidonCRHGadget<InnerScalarField, 3>,
+                InnerScalarField,
+                64,
+            >,
+        >();
+    }
 }
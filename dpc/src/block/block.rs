@@ -24,8 +24,12 @@ use crate::{
     LedgerTree,
     LedgerTreeScheme,
     Network,
+    SizeBreakdown,
     Transaction,
     Transactions,
+    BLOCK_JSON_SCHEMA,
+    JSON_SCHEMA_VERSION,
+    JSON_SCHEMA_VERSION_FIELD,
 };
 use snarkvm_algorithms::CRH;
 use snarkvm_utilities::{to_bytes_le, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
@@ -163,6 +167,33 @@ impl<N: Network> Block<N> {
             return false;
         }
 
+        // Ensure the transactions only carry public data if the network upgrade at this block height accepts it.
+        if !self
+            .transactions
+            .iter()
+            .all(|transaction| transaction.is_data_accepted_at(self.height()))
+        {
+            eprintln!("Block contains public transaction data before it is accepted");
+            return false;
+        }
+
+        // Ensure the block does not exceed the maximum block size.
+        match self.size_in_bytes() {
+            Ok(size) if size <= N::MAX_BLOCK_SIZE_IN_BYTES => (),
+            Ok(size) => {
+                eprintln!(
+                    "Block is {} bytes, exceeding the limit of {} bytes",
+                    size,
+                    N::MAX_BLOCK_SIZE_IN_BYTES
+                );
+                return false;
+            }
+            Err(error) => {
+                eprintln!("Failed to compute the block size: {}", error);
+                return false;
+            }
+        }
+
         // Ensure the transactions root matches the computed root from the transactions list.
         if self.header.transactions_root() != self.transactions.transactions_root() {
             eprintln!("Invalid block transactions does not match transactions root in header");
@@ -199,6 +230,23 @@ impl<N: Network> Block<N> {
         true
     }
 
+    /// Returns the size of the serialized block in bytes.
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        let header_size = to_bytes_le![self.block_hash, self.previous_block_hash, self.header]?.len();
+        Ok(header_size + self.transactions.size_in_bytes()?)
+    }
+
+    /// Returns the number of bytes used by each component of the serialized block.
+    pub fn size_breakdown(&self) -> Result<SizeBreakdown> {
+        let header_size = to_bytes_le![self.block_hash, self.previous_block_hash, self.header]?.len();
+        let header_proof = self.header.proof().to_bytes_le()?.len();
+
+        let mut breakdown = self.transactions.size_breakdown()?;
+        breakdown.header = header_size - header_proof;
+        breakdown.header_proof = header_proof;
+        Ok(breakdown)
+    }
+
     /// Returns `true` if the block is a genesis block.
     pub fn is_genesis(&self) -> bool {
         // Ensure the header is a genesis block header.
@@ -276,33 +324,10 @@ impl<N: Network> Block<N> {
     }
 
     ///
-    /// Returns the block reward for the given block height.
+    /// Returns the block reward for the given block height, as defined by the network.
     ///
     pub fn block_reward(height: u32) -> AleoAmount {
-        match height == 0 {
-            true => {
-                // Output the starting supply as the genesis block reward.
-                AleoAmount::from_i64(N::ALEO_STARTING_SUPPLY_IN_CREDITS * AleoAmount::ONE_CREDIT.0)
-            }
-            false => {
-                // The initial blocks that aren't taken into account with the halving calculation.
-                // The time it takes before the halving - 4,730,400 blocks (approximately 3 years).
-                let expected_blocks_per_hour: u32 = 3600 / (N::ALEO_BLOCK_TIME_IN_SECS as u32);
-                let num_years = 3;
-                let block_segments = num_years * 365 * 24 * expected_blocks_per_hour;
-
-                // The block reward halves at most 2 times - minimum is 25 ALEO.
-                // The reward will halve at blocks `4,730,400` and `9,460,800`.
-                // Blocks 1 to 4,730,400         - 100 CREDITS
-                // Blocks 4,730,401 to 9,460,800 - 50 CREDITS
-                // Blocks 9,460,801+             - 25 CREDITS
-                let initial_reward = 100i64 * AleoAmount::ONE_CREDIT.0;
-                let num_halves = u32::min(height.saturating_sub(1) / block_segments, 2);
-                let reward = initial_reward / (2_u64.pow(num_halves)) as i64;
-
-                AleoAmount::from_i64(reward)
-            }
-        }
+        N::block_reward(height)
     }
 }
 
@@ -354,7 +379,8 @@ impl<N: Network> Serialize for Block<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut block = serializer.serialize_struct("Block", 4)?;
+                let mut block = serializer.serialize_struct("Block", 5)?;
+                block.serialize_field(JSON_SCHEMA_VERSION_FIELD, &JSON_SCHEMA_VERSION)?;
                 block.serialize_field("block_hash", &self.block_hash)?;
                 block.serialize_field("previous_block_hash", &self.previous_block_hash)?;
                 block.serialize_field("header", &self.header)?;
@@ -371,6 +397,7 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
         match deserializer.is_human_readable() {
             true => {
                 let block = serde_json::Value::deserialize(deserializer)?;
+                BLOCK_JSON_SCHEMA.read_version(&block).map_err(de::Error::custom)?;
                 let block_hash: N::BlockHash =
                     serde_json::from_value(block["block_hash"].clone()).map_err(de::Error::custom)?;
 
@@ -390,7 +417,11 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
                     }
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "block"),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "block",
+                N::MAX_BLOCK_SIZE_IN_BYTES,
+            ),
         }
     }
 }
@@ -398,7 +429,7 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet2::Testnet2, Account, AccountScheme};
+    use crate::{assert_json_field_order, testnet2::Testnet2, Account, AccountScheme, NetworkConstants, NetworkCrypto};
     use snarkvm_utilities::UniformRand;
 
     use rand::{thread_rng, Rng};
@@ -492,6 +523,7 @@ mod tests {
             supply,
             AleoAmount::from_i64(supply_at_first_halving * AleoAmount::ONE_CREDIT.0)
         );
+        assert_eq!(supply, Testnet2::scheduled_supply_at(first_halving));
 
         // Phase 2 - 50 credits per block.
         let phase_2_sum = AleoAmount::from_i64(
@@ -507,6 +539,22 @@ mod tests {
             supply,
             AleoAmount::from_i64(supply_at_second_halving * AleoAmount::ONE_CREDIT.0)
         );
+        assert_eq!(supply, Testnet2::scheduled_supply_at(second_halving));
+
+        // Ensure the scheduled supply sums the block rewards within and across halving intervals.
+        let reward_at_first_halving = Block::<Testnet2>::block_reward(first_halving);
+        assert_eq!(
+            Testnet2::scheduled_supply_at(first_halving - 1),
+            Testnet2::scheduled_supply_at(first_halving).sub(reward_at_first_halving)
+        );
+        assert_eq!(
+            Testnet2::scheduled_supply_at(second_halving + 10),
+            supply.add(AleoAmount::from_i64(
+                10 * Block::<Testnet2>::block_reward(second_halving + 1).0
+            ))
+        );
+        assert_eq!(first_halving, Testnet2::block_reward_halving_height(1));
+        assert_eq!(2, Testnet2::block_reward_halvings(u32::MAX));
     }
 
     #[test]
@@ -518,8 +566,9 @@ mod tests {
         // Serialize
         let expected_string = expected_block.to_string();
         let candidate_string = serde_json::to_string(&expected_block).unwrap();
-        assert_eq!(4180, candidate_string.len(), "Update me if serialization has changed");
+        assert_eq!(4247, candidate_string.len(), "Update me if serialization has changed");
         assert_eq!(expected_string, candidate_string);
+        assert_json_field_order(&BLOCK_JSON_SCHEMA, &candidate_string);
 
         // Deserialize
         assert_eq!(expected_block, Block::<Testnet2>::from_str(&candidate_string).unwrap());
@@ -536,6 +585,7 @@ mod tests {
         let expected_bytes = expected_block.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_block).unwrap();
         assert_eq!(2090, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(expected_bytes.len(), expected_block.size_in_bytes().unwrap());
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -544,11 +594,34 @@ mod tests {
         assert_eq!(expected_block, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
 
+    #[test]
+    fn test_block_size_breakdown() {
+        let block = Testnet2::genesis_block();
+
+        // Ensure the size breakdown accounts for every byte of the block.
+        let breakdown = block.size_breakdown().unwrap();
+        assert_eq!(block.size_in_bytes().unwrap(), breakdown.total());
+        assert_eq!(
+            block.header().proof().to_bytes_le().unwrap().len(),
+            breakdown.header_proof
+        );
+        assert_eq!(
+            block.size_in_bytes().unwrap() - block.transactions().size_in_bytes().unwrap(),
+            breakdown.header + breakdown.header_proof
+        );
+
+        // Ensure the transactions account for the remaining components.
+        let transactions_breakdown = block.transactions().size_breakdown().unwrap();
+        assert_eq!(transactions_breakdown.ciphertexts, breakdown.ciphertexts);
+        assert_eq!(transactions_breakdown.transition_proofs, breakdown.transition_proofs);
+        assert!(breakdown.transition_proofs > 0);
+    }
+
     /// A bech32-encoded representation of the block hash.
     #[test]
     fn test_block_hash_serde_json() {
         let rng = &mut thread_rng();
-        let expected_block_hash: <Testnet2 as Network>::BlockHash = UniformRand::rand(rng);
+        let expected_block_hash: <Testnet2 as NetworkCrypto>::BlockHash = UniformRand::rand(rng);
 
         // Serialize
         let expected_string = &expected_block_hash.to_string();
@@ -566,7 +639,7 @@ mod tests {
         // Deserialize
         assert_eq!(
             expected_block_hash,
-            <Testnet2 as Network>::BlockHash::from_str(expected_string).unwrap()
+            <Testnet2 as NetworkCrypto>::BlockHash::from_str(expected_string).unwrap()
         );
         assert_eq!(expected_block_hash, serde_json::from_str(&candidate_string).unwrap());
     }
@@ -574,7 +647,7 @@ mod tests {
     #[test]
     fn test_block_hash_bincode() {
         let rng = &mut thread_rng();
-        let expected_block_hash: <Testnet2 as Network>::BlockHash = UniformRand::rand(rng);
+        let expected_block_hash: <Testnet2 as NetworkCrypto>::BlockHash = UniformRand::rand(rng);
 
         // Serialize
         let expected_bytes = expected_block_hash.to_bytes_le().unwrap();
@@ -587,7 +660,7 @@ mod tests {
         // Deserialize
         assert_eq!(
             expected_block_hash,
-            <Testnet2 as Network>::BlockHash::read_le(&expected_bytes[..]).unwrap()
+            <Testnet2 as NetworkCrypto>::BlockHash::read_le(&expected_bytes[..]).unwrap()
         );
         assert_eq!(expected_block_hash, bincode::deserialize(&expected_bytes[..]).unwrap());
     }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BlockError, BlockTemplate, Network, PoSWCircuit, PoSWProof, PoSWScheme};
+use crate::{BlockError, BlockTemplate, Network, PoSWProof, PoSWVersion};
 use snarkvm_algorithms::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_utilities::{
     fmt,
@@ -24,7 +24,6 @@ use snarkvm_utilities::{
     FromBytesDeserializer,
     ToBytes,
     ToBytesSerializer,
-    UniformRand,
 };
 
 use anyhow::{anyhow, Result};
@@ -130,8 +129,8 @@ impl<N: Network> BlockHeader<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<Self> {
-        // Mine the block.
-        let block_header = N::posw().mine(block_template, terminator, rng)?;
+        // Mine the block with the PoSW algorithm scheduled for the block height.
+        let block_header = N::posw_registry().mine(block_template, terminator, rng)?;
 
         // Ensure the block header is valid.
         match block_header.is_valid() {
@@ -149,21 +148,30 @@ impl<N: Network> BlockHeader<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<Self> {
-        // Instantiate the circuit.
-        let mut circuit = PoSWCircuit::<N>::new(block_template, UniformRand::rand(rng))?;
-
         // Run one iteration of PoSW.
         // Warning: this operation is unchecked.
-        let proof = N::posw().prove_once_unchecked(&mut circuit, block_template, terminator, rng)?;
+        let (nonce, proof) = N::posw_registry().prove_once_unchecked(block_template, terminator, rng)?;
 
         // Construct a block header.
-        Ok(Self {
+        Ok(Self::from_template_unchecked(block_template, nonce, proof))
+    }
+
+    ///
+    /// Returns a new unchecked instance of a block header for the given block template, nonce, and PoSW proof.
+    /// WARNING - This method does *not* enforce the block header is valid.
+    ///
+    pub fn from_template_unchecked(
+        block_template: &BlockTemplate<N>,
+        nonce: N::PoSWNonce,
+        proof: PoSWProof<N>,
+    ) -> Self {
+        Self {
             previous_ledger_root: block_template.previous_ledger_root(),
             transactions_root: block_template.transactions().transactions_root(),
             metadata: BlockHeaderMetadata::new(block_template),
-            nonce: circuit.nonce(),
+            nonce,
             proof,
-        })
+        }
     }
 
     /// Returns `true` if the block header is well-formed.
@@ -193,7 +201,7 @@ impl<N: Network> BlockHeader<N> {
                 // Ensure the timestamp in the block is greater than 0.
                 self.metadata.timestamp > 0i64
                     // Ensure the PoSW proof is valid.
-                    && N::posw().verify_from_block_header(self)
+                    && N::posw_registry().verify_from_block_header(self)
             }
         }
     }
@@ -209,7 +217,7 @@ impl<N: Network> BlockHeader<N> {
             // Ensure the cumulative weight in the genesis block is 0u128.
             && self.metadata.cumulative_weight == 0u128
             // Ensure the PoSW proof is valid.
-            && N::posw().verify_from_block_header(self)
+            && N::posw_registry().verify_from_block_header(self)
     }
 
     /// Returns the previous ledger root from the block header.
@@ -252,6 +260,11 @@ impl<N: Network> BlockHeader<N> {
         &self.proof
     }
 
+    /// Returns the version of the PoSW proof.
+    pub fn posw_version(&self) -> PoSWVersion {
+        self.proof.version()
+    }
+
     /// Returns the block header size in bytes.
     pub fn size() -> usize {
         N::HEADER_SIZE_IN_BYTES
@@ -278,6 +291,18 @@ impl<N: Network> BlockHeader<N> {
     pub fn to_header_root(&self) -> Result<N::BlockHeaderRoot> {
         Ok((*self.to_header_tree()?.root()).into())
     }
+
+    /// Returns the block header root, and the Merkle path of each leaf of the block header tree, in order.
+    pub fn to_header_root_and_paths(
+        &self,
+    ) -> Result<(N::BlockHeaderRoot, Vec<MerklePath<N::BlockHeaderRootParameters>>)> {
+        let (tree, paths) = BlockTemplate::<N>::compute_block_header_tree_with_paths(
+            self.previous_ledger_root,
+            self.transactions_root,
+            &self.metadata,
+        )?;
+        Ok(((*tree.root()).into(), paths))
+    }
 }
 
 impl<N: Network> FromBytes for BlockHeader<N> {
@@ -301,8 +326,8 @@ impl<N: Network> FromBytes for BlockHeader<N> {
 
         // Read the header nonce.
         let nonce = FromBytes::read_le(&mut reader)?;
-        // Read the header proof.
-        let proof = FromBytes::read_le(&mut reader)?;
+        // Read the header proof, of the size scheduled for the block height.
+        let proof = PoSWProof::read_le_for_height(metadata.height, &mut reader)?;
 
         // Construct the block header.
         Ok(Self::from(
@@ -392,7 +417,7 @@ impl<'de, N: Network> Deserialize<'de> for BlockHeader<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet1::Testnet1, testnet2::Testnet2, PoSWScheme};
+    use crate::{testnet1::Testnet1, testnet2::Testnet2, NetworkConstants, NetworkParameters, PoSWScheme};
 
     use rand::thread_rng;
 
@@ -483,6 +508,23 @@ mod tests {
         assert_ne!(block_header.transactions_root, Default::default());
     }
 
+    #[test]
+    fn test_block_header_root_and_paths() {
+        let block_header = Testnet2::genesis_block().header();
+        let (block_header_root, paths) = block_header.to_header_root_and_paths().unwrap();
+        assert_eq!(block_header.to_header_root().unwrap(), block_header_root);
+        assert_eq!(usize::pow(2, Testnet2::HEADER_TREE_DEPTH as u32), paths.len());
+
+        // Ensure the path of the transactions root matches its inclusion proof.
+        let inclusion_proof = block_header
+            .to_header_inclusion_proof(1, block_header.transactions_root)
+            .unwrap();
+        assert_eq!(inclusion_proof.path, paths[1].path);
+        assert_eq!(inclusion_proof.leaf_index, paths[1].leaf_index);
+        let leaf = block_header.transactions_root.to_bytes_le().unwrap();
+        assert!(paths[1].verify(&block_header_root, &leaf).unwrap());
+    }
+
     #[test]
     fn test_block_header_difficulty_target() {
         // Construct the block template.
@@ -20,6 +20,9 @@ pub use block::*;
 pub mod header;
 pub use header::*;
 
+pub mod size_breakdown;
+pub use size_breakdown::*;
+
 pub mod template;
 pub use template::*;
 
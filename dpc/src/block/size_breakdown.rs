@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{iter::Sum, ops::Add};
+
+///
+/// The number of bytes used by each component of a serialized block, transaction, or transition.
+///
+/// The components sum up to the serialized size, so the impact of a format change,
+/// such as a more compact record ciphertext, may be measured on each component.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SizeBreakdown {
+    /// The bytes of the block hashes and the block header, excluding the header proof.
+    pub header: usize,
+    /// The bytes of the PoSW proof in the block header.
+    pub header_proof: usize,
+    /// The bytes of the serial numbers of the input records.
+    pub serial_numbers: usize,
+    /// The bytes of the ciphertexts of the output records.
+    pub ciphertexts: usize,
+    /// The bytes of the transition events.
+    pub events: usize,
+    /// The bytes of the transition proofs.
+    pub transition_proofs: usize,
+    /// The bytes of the public transaction data.
+    pub data: usize,
+    /// The remaining bytes, such as IDs, ledger roots, value balances, and length prefixes.
+    pub other: usize,
+}
+
+impl SizeBreakdown {
+    /// Returns the total number of bytes, which is the sum of all components.
+    pub fn total(&self) -> usize {
+        self.header
+            + self.header_proof
+            + self.serial_numbers
+            + self.ciphertexts
+            + self.events
+            + self.transition_proofs
+            + self.data
+            + self.other
+    }
+}
+
+impl Add for SizeBreakdown {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            header: self.header + other.header,
+            header_proof: self.header_proof + other.header_proof,
+            serial_numbers: self.serial_numbers + other.serial_numbers,
+            ciphertexts: self.ciphertexts + other.ciphertexts,
+            events: self.events + other.events,
+            transition_proofs: self.transition_proofs + other.transition_proofs,
+            data: self.data + other.data,
+            other: self.other + other.other,
+        }
+    }
+}
+
+impl Sum for SizeBreakdown {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// The size statistics of a list of transactions.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SizeStatistics {
+    /// The number of transactions.
+    pub num_transactions: usize,
+    /// The number of transitions in all transactions.
+    pub num_transitions: usize,
+    /// The sum of the size breakdowns of all transactions.
+    pub breakdown: SizeBreakdown,
+    /// The size of the smallest transaction in bytes.
+    pub min_transaction_size: usize,
+    /// The size of the largest transaction in bytes.
+    pub max_transaction_size: usize,
+}
+
+impl SizeStatistics {
+    /// Returns the mean size of a transaction in bytes, or zero if there are no transactions.
+    pub fn mean_transaction_size(&self) -> usize {
+        match self.num_transactions {
+            0 => 0,
+            num_transactions => self.breakdown.total() / num_transactions,
+        }
+    }
+}
@@ -15,10 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{BlockHeaderMetadata, Network, Record, Transaction, Transactions};
-use snarkvm_algorithms::merkle_tree::MerkleTree;
+use snarkvm_algorithms::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use serde::{de, ser, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
@@ -131,6 +131,34 @@ impl<N: Network> BlockTemplate<N> {
         transactions_root: N::TransactionsRoot,
         metadata: &BlockHeaderMetadata,
     ) -> Result<MerkleTree<N::BlockHeaderRootParameters>> {
+        Ok(MerkleTree::<N::BlockHeaderRootParameters>::new(
+            Arc::new(N::block_header_root_parameters().clone()),
+            &Self::compute_block_header_leaves(previous_ledger_root, transactions_root, metadata)?,
+        )?)
+    }
+
+    /// Returns an instance of the block header tree, and the Merkle path of each leaf, in order.
+    /// The leaves and the paths are hashed in parallel.
+    pub fn compute_block_header_tree_with_paths(
+        previous_ledger_root: N::LedgerRoot,
+        transactions_root: N::TransactionsRoot,
+        metadata: &BlockHeaderMetadata,
+    ) -> Result<(
+        MerkleTree<N::BlockHeaderRootParameters>,
+        Vec<MerklePath<N::BlockHeaderRootParameters>>,
+    )> {
+        Ok(MerkleTree::<N::BlockHeaderRootParameters>::new_with_paths(
+            Arc::new(N::block_header_root_parameters().clone()),
+            &Self::compute_block_header_leaves(previous_ledger_root, transactions_root, metadata)?,
+        )?)
+    }
+
+    /// Returns the leaves of the block header tree.
+    fn compute_block_header_leaves(
+        previous_ledger_root: N::LedgerRoot,
+        transactions_root: N::TransactionsRoot,
+        metadata: &BlockHeaderMetadata,
+    ) -> Result<Vec<Vec<u8>>> {
         let previous_ledger_root = previous_ledger_root.to_bytes_le()?;
         assert_eq!(previous_ledger_root.len(), 32);
 
@@ -149,10 +177,44 @@ impl<N: Network> BlockTemplate<N> {
         // Sanity check that the correct number of leaves are allocated.
         assert_eq!(num_leaves, leaves.len());
 
-        Ok(MerkleTree::<N::BlockHeaderRootParameters>::new(
-            Arc::new(N::block_header_root_parameters().clone()),
-            &leaves,
-        )?)
+        Ok(leaves)
+    }
+}
+
+#[cfg(any(test, feature = "dev"))]
+impl<N: Network> BlockTemplate<N> {
+    ///
+    /// Returns a block template with the contents of the genesis block and the given difficulty target.
+    ///
+    /// The block template is the same on every call, so tests may construct it
+    /// in several places without sharing it.
+    ///
+    pub fn new_test(difficulty_target: u64) -> Result<Self> {
+        let block = N::genesis_block();
+        let coinbase_record = block
+            .to_coinbase_transaction()?
+            .to_records()
+            .next()
+            .ok_or_else(|| anyhow!("The genesis block is missing a coinbase record"))?;
+
+        Ok(Self::new(
+            block.previous_block_hash(),
+            block.height(),
+            block.timestamp(),
+            difficulty_target,
+            block.cumulative_weight(),
+            block.previous_ledger_root(),
+            block.transactions().clone(),
+            coinbase_record,
+        ))
+    }
+
+    /// Returns a copy of the block template with the given difficulty target.
+    pub fn with_difficulty_target(&self, difficulty_target: u64) -> Self {
+        Self {
+            difficulty_target,
+            ..self.clone()
+        }
     }
 }
 
@@ -264,7 +326,11 @@ impl<'de, N: Network> Deserialize<'de> for BlockTemplate<N> {
                     coinbase_record,
                 ))
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "block template"),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "block template",
+                N::MAX_BLOCK_SIZE_IN_BYTES,
+            ),
         }
     }
 }
@@ -313,7 +379,7 @@ impl<N: Network> CoinbaseTemplate<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testnet2::Testnet2;
+    use crate::{testnet2::Testnet2, NetworkParameters};
 
     #[test]
     fn test_template_serde_json() {
@@ -333,7 +399,7 @@ mod tests {
         // Serialize
         let expected_string = expected_template.to_string();
         let candidate_string = serde_json::to_string(&expected_template).unwrap();
-        assert_eq!(3396, candidate_string.len(), "Update me if serialization has changed");
+        assert_eq!(3453, candidate_string.len(), "Update me if serialization has changed");
         assert_eq!(expected_string, candidate_string);
 
         // Deserialize
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, BlockError, DecryptionKey, Network, Record, Transaction};
+use crate::{AleoAmount, BlockError, DecryptionKey, Network, Record, SizeBreakdown, SizeStatistics, Transaction};
 use snarkvm_algorithms::merkle_tree::*;
 use snarkvm_utilities::{has_duplicates, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
@@ -81,6 +81,16 @@ impl<N: Network> Transactions<N> {
             return false;
         }
 
+        // Ensure the number of transactions is within the limit.
+        if self.transactions.len() > N::MAX_TRANSACTIONS_PER_BLOCK {
+            eprintln!(
+                "Transactions list contains {} transactions, exceeding the limit of {}",
+                self.transactions.len(),
+                N::MAX_TRANSACTIONS_PER_BLOCK
+            );
+            return false;
+        }
+
         // Ensure each transaction is well-formed.
         if !self
             .transactions
@@ -118,6 +128,48 @@ impl<N: Network> Transactions<N> {
         true
     }
 
+    /// Returns the size of the serialized transactions list in bytes.
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        // Account for the `u16` length prefix of the transactions list.
+        self.transactions
+            .iter()
+            .try_fold(2, |size, transaction| Ok(size + transaction.size_in_bytes()?))
+    }
+
+    /// Returns the number of bytes used by each component of the serialized transactions list.
+    pub fn size_breakdown(&self) -> Result<SizeBreakdown> {
+        let mut breakdown = self.size_statistics()?.breakdown;
+        // Account for the `u16` length prefix of the transactions list.
+        breakdown.other += 2;
+        Ok(breakdown)
+    }
+
+    /// Returns the size statistics of the transactions, aggregated over all transactions.
+    pub fn size_statistics(&self) -> Result<SizeStatistics> {
+        let mut statistics = SizeStatistics {
+            num_transactions: self.transactions.len(),
+            min_transaction_size: usize::MAX,
+            ..Default::default()
+        };
+
+        for transaction in &self.transactions {
+            let breakdown = transaction.size_breakdown()?;
+            let size = breakdown.total();
+
+            statistics.num_transitions += transaction.transitions().len();
+            statistics.breakdown = statistics.breakdown + breakdown;
+            statistics.min_transaction_size = statistics.min_transaction_size.min(size);
+            statistics.max_transaction_size = statistics.max_transaction_size.max(size);
+        }
+
+        // Report a minimum of zero if there are no transactions.
+        if self.transactions.is_empty() {
+            statistics.min_transaction_size = 0;
+        }
+
+        Ok(statistics)
+    }
+
     /// Returns the transaction IDs, by constructing a flattened list of transaction IDs from all transactions.
     pub fn transaction_ids(&self) -> impl Iterator<Item = N::TransactionID> + '_ {
         self.transactions.iter().map(Transaction::transaction_id)
@@ -208,6 +260,13 @@ impl<N: Network> FromBytes for Transactions<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_transactions: u16 = FromBytes::read_le(&mut reader)?;
+        if num_transactions as usize > N::MAX_TRANSACTIONS_PER_BLOCK {
+            return Err(BlockError::Message(format!(
+                "Transactions list contains {} transactions, exceeding the limit",
+                num_transactions
+            ))
+            .into());
+        }
         let mut transactions = Vec::with_capacity(num_transactions as usize);
         for _ in 0..num_transactions {
             transactions.push(FromBytes::read_le(&mut reader)?);
@@ -272,7 +331,11 @@ impl<'de, N: Network> Deserialize<'de> for Transactions<N> {
                     serde_json::from_value(transactions["transactions"].clone()).map_err(de::Error::custom)?;
                 Ok(Self::from(&transactions).map_err(de::Error::custom)?)
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transactions"),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "transactions",
+                N::MAX_BLOCK_SIZE_IN_BYTES,
+            ),
         }
     }
 }
@@ -288,7 +351,7 @@ impl<N: Network> Deref for Transactions<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet2::Testnet2, Account, AccountScheme};
+    use crate::{testnet2::Testnet2, Account, AccountScheme, NetworkConstants, NetworkParameters};
     use rand::thread_rng;
 
     #[test]
@@ -323,6 +386,29 @@ mod tests {
         assert!(Transactions::from(&[transaction.clone(), transaction]).is_err());
     }
 
+    #[test]
+    fn test_size_statistics() {
+        let transactions = Testnet2::genesis_block().transactions();
+        let transaction_size = transactions[0].size_in_bytes().unwrap();
+
+        let statistics = transactions.size_statistics().unwrap();
+        assert_eq!(1, statistics.num_transactions);
+        assert_eq!(transactions[0].transitions().len(), statistics.num_transitions);
+        assert_eq!(transaction_size, statistics.min_transaction_size);
+        assert_eq!(transaction_size, statistics.max_transaction_size);
+        assert_eq!(transaction_size, statistics.mean_transaction_size());
+
+        // Ensure the size breakdown accounts for every byte of the transactions.
+        let breakdown = transactions.size_breakdown().unwrap();
+        assert_eq!(transactions.size_in_bytes().unwrap(), breakdown.total());
+        assert_eq!(statistics.breakdown.ciphertexts, breakdown.ciphertexts);
+        assert_eq!(
+            transactions.commitments().count() * Testnet2::RECORD_CIPHERTEXT_SIZE_IN_BYTES,
+            breakdown.ciphertexts
+        );
+        assert_eq!(0, breakdown.header + breakdown.header_proof);
+    }
+
     #[test]
     fn test_transactions_serde_json() {
         let expected_transactions = Testnet2::genesis_block().transactions().clone();
@@ -330,7 +416,7 @@ mod tests {
         // Serialize
         let expected_string = expected_transactions.to_string();
         let candidate_string = serde_json::to_string(&expected_transactions).unwrap();
-        assert_eq!(2378, candidate_string.len(), "Update me if serialization has changed");
+        assert_eq!(2416, candidate_string.len(), "Update me if serialization has changed");
         assert_eq!(expected_string, candidate_string);
 
         // Deserialize
@@ -349,6 +435,7 @@ mod tests {
         let expected_bytes = expected_transactions.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_transactions).unwrap();
         assert_eq!(1123, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(expected_bytes.len(), expected_transactions.size_in_bytes().unwrap());
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -0,0 +1,503 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BalancePrivateVariables, BalancePublicVariables, Network};
+use snarkvm_algorithms::traits::*;
+use snarkvm_gadgets::{
+    algorithms::merkle_tree::merkle_path::MerklePathGadget,
+    bits::{Boolean, ToBytesGadget},
+    integers::{int::Int64, uint::UInt8},
+    traits::{
+        algorithms::{CRHGadget, EncryptionGadget, PRFGadget, SignatureGadget},
+        alloc::AllocGadget,
+        eq::EqGadget,
+        integers::{add::Add, integer::Integer},
+    },
+    ComparatorGadget,
+    ToBitsLEGadget,
+    ToConstraintFieldGadget,
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::ToBytes;
+
+use itertools::Itertools;
+
+///
+/// A circuit that proves the records with the given serial numbers are owned by one account,
+/// are in the ledger with the given ledger root, and have a total value of at least the given threshold.
+///
+/// The records themselves are private. The serial numbers must be distinct, so no record is counted twice,
+/// and the verifier checks that the serial numbers are not in the ledger, to ensure the records are unspent.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"))]
+pub struct BalanceCircuit<N: Network> {
+    public: BalancePublicVariables<N>,
+    private: BalancePrivateVariables<N>,
+}
+
+impl<N: Network> BalanceCircuit<N> {
+    pub fn blank(num_records: usize) -> Self {
+        Self {
+            public: BalancePublicVariables::blank(num_records),
+            private: BalancePrivateVariables::blank(num_records),
+        }
+    }
+
+    pub fn new(public: BalancePublicVariables<N>, private: BalancePrivateVariables<N>) -> Self {
+        Self { public, private }
+    }
+}
+
+impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for BalanceCircuit<N> {
+    fn generate_constraints<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        let public = &self.public;
+        let private = &self.private;
+
+        // Ensure the number of records is consistent. Note: This is *not* enforced in the circuit.
+        assert!(!private.records.is_empty());
+        assert_eq!(private.records.len(), public.serial_numbers().len());
+
+        let (
+            account_encryption_parameters,
+            account_signature_parameters,
+            record_commitment_parameters,
+            transition_id_crh,
+            transaction_id_crh,
+            transactions_root_crh,
+            block_header_root_crh,
+            block_hash_crh,
+            ledger_root_crh,
+        ) = {
+            let cs = &mut cs.ns(|| "Declare parameters");
+
+            let account_encryption_parameters = N::AccountEncryptionGadget::alloc_constant(
+                &mut cs.ns(|| "Declare account encryption parameters"),
+                || Ok(N::account_encryption_scheme().clone()),
+            )?;
+
+            let account_signature_parameters = N::AccountSignatureGadget::alloc_constant(
+                &mut cs.ns(|| "Declare account signature parameters"),
+                || Ok(N::account_signature_scheme().clone()),
+            )?;
+
+            let record_commitment_parameters =
+                N::CommitmentGadget::alloc_constant(&mut cs.ns(|| "Declare record commitment parameters"), || {
+                    Ok(N::commitment_scheme().clone())
+                })?;
+
+            let transition_id_crh = N::TransitionIDCRHGadget::alloc_constant(
+                &mut cs.ns(|| "Declare the transition ID CRH parameters"),
+                || Ok(N::transition_id_parameters().crh()),
+            )?;
+
+            let transaction_id_crh = N::TransactionIDCRHGadget::alloc_constant(
+                &mut cs.ns(|| "Declare the transaction CRH parameters"),
+                || Ok(N::transaction_id_parameters().crh()),
+            )?;
+
+            let transactions_root_crh = N::TransactionsRootCRHGadget::alloc_constant(
+                &mut cs.ns(|| "Declare the transactions root CRH parameters"),
+                || Ok(N::transactions_root_parameters().crh()),
+            )?;
+
+            let block_header_root_crh = N::BlockHeaderRootCRHGadget::alloc_constant(
+                &mut cs.ns(|| "Declare the block header root CRH parameters"),
+                || Ok(N::block_header_root_parameters().crh()),
+            )?;
+
+            let block_hash_crh =
+                N::BlockHashCRHGadget::alloc_constant(&mut cs.ns(|| "Declare the block hash CRH parameters"), || {
+                    Ok(N::block_hash_crh().clone())
+                })?;
+
+            let ledger_root_crh = N::LedgerRootCRHGadget::alloc_constant(
+                &mut cs.ns(|| "Declare the ledger root CRH parameters"),
+                || Ok(N::ledger_root_parameters().crh()),
+            )?;
+
+            (
+                account_encryption_parameters,
+                account_signature_parameters,
+                record_commitment_parameters,
+                transition_id_crh,
+                transaction_id_crh,
+                transactions_root_crh,
+                block_header_root_crh,
+                block_hash_crh,
+                ledger_root_crh,
+            )
+        };
+
+        // Declare the ledger root.
+        let ledger_root = <N::LedgerRootCRHGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(
+            &mut cs.ns(|| "Declare the ledger root"),
+            || Ok(public.ledger_root()),
+        )?;
+
+        // Declare the threshold.
+        let threshold = {
+            let cs = &mut cs.ns(|| "Declare the threshold");
+
+            let given_threshold = Int64::alloc(&mut cs.ns(|| "given_threshold"), || Ok(public.threshold().as_i64()))?;
+            let given_threshold_field_elements = given_threshold
+                .to_bytes(&mut cs.ns(|| "Convert given_threshold to bytes"))?
+                .to_constraint_field(&mut cs.ns(|| "convert given threshold to field elements"))?;
+
+            let threshold_bytes =
+                UInt8::alloc_input_vec_le(&mut cs.ns(|| "Allocate threshold"), &public.threshold().to_bytes_le()?)?;
+            let threshold_field_elements =
+                threshold_bytes.to_constraint_field(&mut cs.ns(|| "convert threshold to field elements"))?;
+
+            given_threshold_field_elements.enforce_equal(
+                &mut cs.ns(|| "enforce the threshold is equal"),
+                &threshold_field_elements,
+            )?;
+
+            given_threshold
+        };
+
+        // Declare the signature of the record owner.
+        let signature = <N::AccountSignatureGadget as SignatureGadget<
+            N::AccountSignatureScheme,
+            N::InnerScalarField,
+        >>::SignatureGadget::alloc(&mut cs.ns(|| "Declare the signature"), || {
+            Ok(&*private.signature)
+        })?;
+
+        // Derive the serial number PRF seed of the record owner.
+        let sk_prf = {
+            let sk_prf_cs = &mut cs.ns(|| "Derive the serial number PRF seed");
+
+            let compute_key = N::AccountSignatureGadget::compute_key(
+                &account_signature_parameters,
+                &mut sk_prf_cs.ns(|| "Compute key"),
+                &signature,
+            )?;
+            let sk_prf_bits = compute_key.to_bits_le_strict(&mut sk_prf_cs.ns(|| "Compute key to bits"))?;
+
+            Boolean::le_bits_to_fp_var(&mut sk_prf_cs.ns(|| "Bits to FpGadget"), &sk_prf_bits)?
+        };
+
+        let mut commitments_bytes = Vec::with_capacity(private.records.len() * 32);
+        let mut owners = Vec::with_capacity(private.records.len());
+        let mut serial_numbers_field_elements = Vec::with_capacity(private.records.len());
+        let mut total_value = Int64::zero();
+
+        for (i, ((record, ledger_proof), serial_number)) in private
+            .records
+            .iter()
+            .zip_eq(private.ledger_proofs.iter())
+            .zip_eq(public.serial_numbers().iter())
+            .enumerate()
+        {
+            let cs = &mut cs.ns(|| format!("Process record {}", i));
+
+            // Declare record contents
+            let (given_owner, given_value, given_payload, given_program_id, given_randomizer, given_record_view_key) = {
+                let declare_cs = &mut cs.ns(|| "Declare record");
+
+                let given_owner = <N::AccountSignatureGadget as SignatureGadget<
+                    N::AccountSignatureScheme,
+                    N::InnerScalarField,
+                >>::PublicKeyGadget::alloc(
+                    &mut declare_cs.ns(|| "given_record_owner"), || Ok(*record.owner())
+                )?;
+
+                let given_value = Int64::alloc(&mut declare_cs.ns(|| "given_value"), || Ok(record.value().as_i64()))?;
+
+                let given_payload =
+                    UInt8::alloc_vec(&mut declare_cs.ns(|| "given_payload"), &record.payload().to_bytes_le()?)?;
+
+                let given_program_id = UInt8::alloc_vec(
+                    &mut declare_cs.ns(|| "given_program_id"),
+                    &record.program_id().to_bytes_le()?,
+                )?;
+
+                let given_randomizer = <N::AccountEncryptionGadget as EncryptionGadget<
+                    N::AccountEncryptionScheme,
+                    N::InnerScalarField,
+                >>::CiphertextRandomizer::alloc(
+                    &mut declare_cs.ns(|| "given_randomizer"), || Ok(record.randomizer())
+                )?;
+
+                let given_record_view_key = <N::AccountEncryptionGadget as EncryptionGadget<
+                    N::AccountEncryptionScheme,
+                    N::InnerScalarField,
+                >>::SymmetricKeyGadget::alloc(
+                    &mut declare_cs.ns(|| "given_record_view_key"),
+                    || Ok(*record.record_view_key().clone()),
+                )?;
+
+                (
+                    given_owner,
+                    given_value,
+                    given_payload,
+                    given_program_id,
+                    given_randomizer,
+                    given_record_view_key,
+                )
+            };
+
+            // *******************************************************************
+            // Compute the record commitment. The record must not be a dummy record.
+            // *******************************************************************
+            let commitment = {
+                let commitment_cs = &mut cs.ns(|| "Compute the record commitment");
+
+                let given_owner_bytes =
+                    given_owner.to_bytes(&mut commitment_cs.ns(|| "Convert given_owner to bytes"))?;
+                let is_dummy_bytes =
+                    Boolean::constant(false).to_bytes(&mut commitment_cs.ns(|| "Convert is_dummy to bytes"))?;
+                let given_value_bytes =
+                    given_value.to_bytes(&mut commitment_cs.ns(|| "Convert given_value to bytes"))?;
+
+                let mut plaintext = Vec::new();
+                plaintext.extend_from_slice(&given_owner_bytes);
+                plaintext.extend_from_slice(&is_dummy_bytes);
+                plaintext.extend_from_slice(&given_value_bytes);
+                plaintext.extend_from_slice(&given_payload);
+                plaintext.extend_from_slice(&given_program_id);
+
+                let ciphertext = account_encryption_parameters.check_encryption_from_symmetric_key(
+                    &mut commitment_cs.ns(|| format!("record {} check_encryption_gadget", i)),
+                    &given_record_view_key,
+                    &plaintext,
+                )?;
+
+                let record_view_key_commitment = account_encryption_parameters.check_symmetric_key_commitment(
+                    &mut commitment_cs.ns(|| format!("record {} check_symmetric_key_commitment", i)),
+                    &given_record_view_key,
+                )?;
+
+                let given_randomizer_bytes =
+                    given_randomizer.to_bytes(&mut commitment_cs.ns(|| "Convert given_randomizer to bytes"))?;
+                let record_view_key_commitment_bytes = record_view_key_commitment
+                    .to_bytes(&mut commitment_cs.ns(|| "Convert record_view_key_commitment to bytes"))?;
+
+                let mut commitment_input = Vec::with_capacity(
+                    given_randomizer_bytes.len() + record_view_key_commitment_bytes.len() + ciphertext.len(),
+                );
+                commitment_input.extend_from_slice(&given_randomizer_bytes);
+                commitment_input.extend_from_slice(&record_view_key_commitment_bytes);
+                commitment_input.extend_from_slice(&ciphertext);
+
+                let candidate_commitment = record_commitment_parameters
+                    .check_evaluation_gadget(&mut commitment_cs.ns(|| "Compute record commitment"), commitment_input)?;
+
+                let candidate_commitment_bytes =
+                    candidate_commitment.to_bytes(&mut commitment_cs.ns(|| "Convert candidate_commitment to bytes"))?;
+
+                owners.push(given_owner);
+                commitments_bytes.extend_from_slice(&candidate_commitment_bytes);
+
+                candidate_commitment
+            };
+
+            // ********************************************************************
+            // Check that the serial number is derived correctly.
+            // ********************************************************************
+            {
+                let sn_cs = &mut cs.ns(|| "Check that sn is derived correctly");
+
+                let candidate_serial_number = <N::SerialNumberPRFGadget as PRFGadget<
+                    N::SerialNumberPRF,
+                    N::InnerScalarField,
+                >>::check_evaluation_gadget(
+                    &mut sn_cs.ns(|| "Compute serial number"),
+                    &sk_prf,
+                    &vec![commitment.clone()],
+                )?;
+                let candidate_serial_number_field_elements = candidate_serial_number
+                    .to_bytes(&mut sn_cs.ns(|| "Convert candidate serial number to bytes"))?
+                    .to_constraint_field(&mut sn_cs.ns(|| "convert candidate serial number to field elements"))?;
+
+                let given_serial_number_bytes = UInt8::alloc_input_vec_le(
+                    &mut sn_cs.ns(|| "Allocate given serial number"),
+                    &serial_number.to_bytes_le()?,
+                )?;
+                let given_serial_number_field_elements = given_serial_number_bytes
+                    .to_constraint_field(&mut sn_cs.ns(|| "convert given serial number to field elements"))?;
+
+                candidate_serial_number_field_elements.enforce_equal(
+                    &mut sn_cs.ns(|| "enforce the serial number is equal"),
+                    &given_serial_number_field_elements,
+                )?;
+
+                serial_numbers_field_elements.push(candidate_serial_number_field_elements);
+            }
+
+            // **********************************************************************************
+            // Check that the commitment appears on the ledger,
+            // i.e., the membership witness is valid with respect to the ledger root.
+            // **********************************************************************************
+            {
+                let ledger_cs = &mut cs.ns(|| "Check ledger proof");
+
+                // Compute the transition ID.
+                let transition_inclusion_proof = MerklePathGadget::<_, N::TransitionIDCRHGadget, _>::alloc(
+                    &mut ledger_cs.ns(|| "Declare the transition ID inclusion proof"),
+                    || Ok(ledger_proof.transition_inclusion_proof()),
+                )?;
+                let candidate_transition_id = transition_inclusion_proof.calculate_root(
+                    &mut ledger_cs.ns(|| "Perform the transition inclusion proof computation"),
+                    &transition_id_crh,
+                    &commitment,
+                )?;
+
+                // Compute the transaction ID.
+                let transaction_id_inclusion_proof = MerklePathGadget::<_, N::TransactionIDCRHGadget, _>::alloc(
+                    &mut ledger_cs.ns(|| "Declare the transaction ID inclusion proof"),
+                    || Ok(ledger_proof.transaction_inclusion_proof()),
+                )?;
+                let candidate_transaction_id = transaction_id_inclusion_proof.calculate_root(
+                    &mut ledger_cs.ns(|| "Perform the transaction ID inclusion proof computation"),
+                    &transaction_id_crh,
+                    &candidate_transition_id,
+                )?;
+
+                // Compute the transactions root.
+                let ledger_transactions_root_inclusion_proof =
+                    MerklePathGadget::<_, N::TransactionsRootCRHGadget, _>::alloc(
+                        &mut ledger_cs.ns(|| "Declare the ledger transactions root inclusion proof"),
+                        || Ok(ledger_proof.transactions_inclusion_proof()),
+                    )?;
+                let candidate_ledger_transactions_root = ledger_transactions_root_inclusion_proof.calculate_root(
+                    &mut ledger_cs.ns(|| "Perform the ledger transactions root inclusion proof computation"),
+                    &transactions_root_crh,
+                    &candidate_transaction_id,
+                )?;
+
+                // Compute the block header root.
+                let block_header_root_inclusion_proof = MerklePathGadget::<_, N::BlockHeaderRootCRHGadget, _>::alloc(
+                    &mut ledger_cs.ns(|| "Declare the block header root inclusion proof"),
+                    || Ok(ledger_proof.block_header_inclusion_proof()),
+                )?;
+                let candidate_block_header_root = block_header_root_inclusion_proof.calculate_root(
+                    &mut ledger_cs.ns(|| "Perform the block header root inclusion proof computation"),
+                    &block_header_root_crh,
+                    &candidate_ledger_transactions_root,
+                )?;
+
+                // Declare the previous block hash.
+                let previous_block_hash = UInt8::alloc_vec(
+                    &mut ledger_cs.ns(|| "Allocate previous block hash"),
+                    &ledger_proof.previous_block_hash().to_bytes_le()?,
+                )?;
+
+                // Construct the block hash preimage.
+                let mut preimage = Vec::new();
+                preimage.extend_from_slice(&previous_block_hash);
+                preimage.extend_from_slice(
+                    &candidate_block_header_root.to_bytes(&mut ledger_cs.ns(|| "block_header_root"))?,
+                );
+
+                // Compute the block hash.
+                let candidate_block_hash =
+                    block_hash_crh.check_evaluation_gadget(&mut ledger_cs.ns(|| "Compute the block hash"), preimage)?;
+
+                // Ensure the ledger root inclusion proof is valid.
+                let ledger_root_inclusion_proof = MerklePathGadget::<_, N::LedgerRootCRHGadget, _>::alloc(
+                    &mut ledger_cs.ns(|| "Declare the ledger root inclusion proof"),
+                    || Ok(ledger_proof.ledger_root_inclusion_proof()),
+                )?;
+                ledger_root_inclusion_proof.check_membership(
+                    &mut ledger_cs.ns(|| "Perform the ledger root inclusion proof check"),
+                    &ledger_root_crh,
+                    &ledger_root,
+                    &candidate_block_hash,
+                )?;
+            }
+
+            // ********************************************************************
+            // Add the record value to the total value.
+            // ********************************************************************
+            {
+                let value_cs = &mut cs.ns(|| "Add the record value");
+
+                // Enforce the record value is non-negative and less than 2^62.
+                for (j, bit) in given_value.to_bits_be()[0..2].iter().enumerate() {
+                    bit.enforce_equal(
+                        value_cs.ns(|| format!("enforce record {} bit {} is 0", i, j)),
+                        &Boolean::constant(false),
+                    )?;
+                }
+
+                total_value = total_value
+                    .add(value_cs.ns(|| format!("add record {} value", i)), &given_value)
+                    .map_err(|_| SynthesisError::Unsatisfiable)?;
+            }
+        }
+
+        // *******************************************************************
+        // Check that the serial numbers are distinct, so no record is counted twice.
+        // *******************************************************************
+        {
+            let distinct_cs = &mut cs.ns(|| "Check that the serial numbers are distinct");
+
+            for (i, j) in (0..serial_numbers_field_elements.len()).tuple_combinations() {
+                let is_equal = serial_numbers_field_elements[i].is_eq(
+                    distinct_cs.ns(|| format!("Compare serial numbers {} and {}", i, j)),
+                    &serial_numbers_field_elements[j],
+                )?;
+                is_equal.enforce_equal(
+                    distinct_cs.ns(|| format!("Enforce serial numbers {} and {} are distinct", i, j)),
+                    &Boolean::constant(false),
+                )?;
+            }
+        }
+
+        // *******************************************************************
+        // Check that the records are owned by the signer.
+        // *******************************************************************
+        {
+            let signature_cs = &mut cs.ns(|| "Check that the signature is valid");
+
+            // Enforce that the record owners are the same address.
+            for (i, next_owner) in owners.iter().enumerate().skip(1) {
+                owners[0].enforce_equal(signature_cs.ns(|| format!("check_owners_match_{}", i)), next_owner)?;
+            }
+
+            let signature_verification = account_signature_parameters.verify(
+                signature_cs.ns(|| "signature_verify"),
+                &owners[0],
+                &commitments_bytes,
+                &signature,
+            )?;
+
+            signature_verification.enforce_equal(signature_cs.ns(|| "check_verification"), &Boolean::constant(true))?;
+        }
+
+        // *******************************************************************
+        // Check that the total value is at least the threshold.
+        // *******************************************************************
+        {
+            let threshold_cs = &mut cs.ns(|| "Check that the total value is at least the threshold");
+
+            let is_sufficient =
+                total_value.greater_than_or_equal(&mut threshold_cs.ns(|| "Compare the total value"), &threshold)?;
+            is_sufficient.enforce_equal(
+                &mut threshold_cs.ns(|| "Enforce the total value is sufficient"),
+                &Boolean::constant(true),
+            )?;
+        }
+
+        Ok(())
+    }
+}
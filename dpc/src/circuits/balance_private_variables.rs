@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LedgerProof, Network, Record};
+use snarkvm_algorithms::traits::SignatureScheme;
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"))]
+pub struct BalancePrivateVariables<N: Network> {
+    pub(super) records: Vec<Record<N>>,
+    pub(super) ledger_proofs: Vec<LedgerProof<N>>,
+    /// The signature of the record owner on the record commitments.
+    pub(super) signature: N::AccountSignature,
+}
+
+impl<N: Network> BalancePrivateVariables<N> {
+    pub(crate) fn blank(num_records: usize) -> Self {
+        Self {
+            records: vec![Record::default(); num_records],
+            ledger_proofs: vec![Default::default(); num_records],
+            signature: <N::AccountSignatureScheme as SignatureScheme>::Signature::default().into(),
+        }
+    }
+
+    pub(crate) fn new(
+        records: Vec<Record<N>>,
+        ledger_proofs: Vec<LedgerProof<N>>,
+        signature: N::AccountSignature,
+    ) -> Self {
+        Self {
+            records,
+            ledger_proofs,
+            signature,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AleoAmount, Network};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_utilities::ToBytes;
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct BalancePublicVariables<N: Network> {
+    /// The ledger root that the records are proven to be in.
+    ledger_root: N::LedgerRoot,
+    /// The minimum total value of the records.
+    threshold: AleoAmount,
+    /// The serial numbers of the records, which the verifier checks are unspent.
+    serial_numbers: Vec<N::SerialNumber>,
+}
+
+impl<N: Network> BalancePublicVariables<N> {
+    pub(crate) fn blank(num_records: usize) -> Self {
+        Self {
+            ledger_root: N::LedgerRoot::default(),
+            threshold: AleoAmount::ZERO,
+            serial_numbers: vec![Default::default(); num_records],
+        }
+    }
+
+    pub(crate) fn new(ledger_root: N::LedgerRoot, threshold: AleoAmount, serial_numbers: Vec<N::SerialNumber>) -> Self {
+        Self {
+            ledger_root,
+            threshold,
+            serial_numbers,
+        }
+    }
+
+    /// Returns the ledger root.
+    pub(crate) fn ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_root
+    }
+
+    /// Returns the minimum total value of the records.
+    pub(crate) fn threshold(&self) -> AleoAmount {
+        self.threshold
+    }
+
+    /// Returns the serial numbers of the records.
+    pub(crate) fn serial_numbers(&self) -> &Vec<N::SerialNumber> {
+        &self.serial_numbers
+    }
+}
+
+impl<N: Network> ToConstraintField<N::InnerScalarField> for BalancePublicVariables<N> {
+    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
+        let mut v = Vec::new();
+        v.extend_from_slice(&self.ledger_root.to_field_elements()?);
+        v.extend_from_slice(&self.threshold.to_bytes_le()?.to_field_elements()?);
+
+        for serial_number in &self.serial_numbers {
+            v.extend_from_slice(&serial_number.to_bytes_le()?.to_field_elements()?);
+        }
+
+        Ok(v)
+    }
+}
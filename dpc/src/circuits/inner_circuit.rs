@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{InnerPrivateVariables, InnerPublicVariables, Network, Payload};
+use crate::{InnerPrivateVariables, InnerPublicVariables, Locktime, Network, Payload, ProgramFee};
 use snarkvm_algorithms::traits::*;
 use snarkvm_gadgets::{
     algorithms::merkle_tree::merkle_path::MerklePathGadget,
@@ -65,8 +65,9 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
         let public = &self.public;
         let private = &self.private;
 
-        // In the inner circuit, this variable must be allocated as public input.
+        // In the inner circuit, these variables must be allocated as public input.
         debug_assert!(public.program_id.is_some());
+        debug_assert!(public.payload_commitments.is_some());
 
         let (
             account_encryption_parameters,
@@ -179,11 +180,15 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
         /* ///////////////////////////// INPUT RECORDS //////////////////////////////// */
         /* //////////////////////////////////////////////////////////////////////////// */
 
+        // The inputs to the payload commitments of the input and output records, in order.
+        let mut payload_commitment_inputs = Vec::with_capacity(N::NUM_TOTAL_RECORDS);
+
         let mut input_serial_numbers_bytes = Vec::with_capacity(N::NUM_INPUT_RECORDS);
         let mut input_commitments_bytes = Vec::with_capacity(N::NUM_INPUT_RECORDS * 32);
         let mut input_owners = Vec::with_capacity(N::NUM_INPUT_RECORDS);
         let mut input_values = Vec::with_capacity(N::NUM_INPUT_RECORDS);
         let mut input_program_ids = Vec::with_capacity(N::NUM_INPUT_RECORDS);
+        let mut input_locktimes = Vec::with_capacity(N::NUM_INPUT_RECORDS);
 
         for (i, (record, ledger_proof)) in private
             .input_records
@@ -200,6 +205,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                 given_value,
                 given_payload,
                 given_program_id,
+                given_locktime,
                 given_randomizer,
                 given_record_view_key,
             ) = {
@@ -230,6 +236,14 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     &record.program_id().to_bytes_le()?,
                 )?;
 
+                let given_locktime = match N::RECORD_LOCKTIMES_ENABLED {
+                    true => UInt8::alloc_vec(
+                        &mut declare_cs.ns(|| "given_locktime"),
+                        &Locktime::to_bounds(record.locktime()).to_bytes_le()?,
+                    )?,
+                    false => vec![],
+                };
+
                 let given_randomizer = <N::AccountEncryptionGadget as EncryptionGadget<
                     N::AccountEncryptionScheme,
                     N::InnerScalarField,
@@ -251,6 +265,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     given_value,
                     given_payload,
                     given_program_id,
+                    given_locktime,
                     given_randomizer,
                     given_record_view_key,
                 )
@@ -314,6 +329,8 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                 plaintext.extend_from_slice(&given_value_bytes);
                 plaintext.extend_from_slice(&given_payload);
                 plaintext.extend_from_slice(&given_program_id);
+                plaintext.extend_from_slice(&given_locktime);
+                input_locktimes.push(given_locktime);
 
                 let ciphertext = account_encryption_parameters.check_encryption_from_symmetric_key(
                     &mut commitment_cs.ns(|| format!("input record {} check_encryption_gadget", i)),
@@ -328,6 +345,11 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
 
                 let given_randomizer_bytes =
                     given_randomizer.to_bytes(&mut commitment_cs.ns(|| "Convert given_randomizer to bytes"))?;
+
+                let mut payload_commitment_input = given_randomizer_bytes.clone();
+                payload_commitment_input.extend_from_slice(&given_payload);
+                payload_commitment_inputs.push(payload_commitment_input);
+
                 let record_view_key_commitment_bytes = record_view_key_commitment
                     .to_bytes(&mut commitment_cs.ns(|| "Convert record_view_key_commitment to bytes"))?;
 
@@ -520,6 +542,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
         let mut output_commitments_bytes = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
         let mut output_values = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
         let mut output_program_ids = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
+        let mut output_payees = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
 
         for (j, (record, encryption_randomness)) in private
             .output_records
@@ -529,7 +552,15 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
         {
             let cs = &mut cs.ns(|| format!("Process output record {}", j));
 
-            let (given_owner, given_is_dummy, given_value, given_payload, given_program_id, given_randomizer) = {
+            let (
+                given_owner,
+                given_is_dummy,
+                given_value,
+                given_payload,
+                given_program_id,
+                given_locktime,
+                given_randomizer,
+            ) = {
                 let declare_cs = &mut cs.ns(|| "Declare output record");
 
                 let given_owner = <N::AccountEncryptionGadget as EncryptionGadget<
@@ -551,6 +582,14 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     &record.program_id().to_bytes_le()?,
                 )?;
 
+                let given_locktime = match N::RECORD_LOCKTIMES_ENABLED {
+                    true => UInt8::alloc_vec(
+                        &mut declare_cs.ns(|| "given_locktime"),
+                        &Locktime::to_bounds(record.locktime()).to_bytes_le()?,
+                    )?,
+                    false => vec![],
+                };
+
                 let given_randomizer = <N::AccountEncryptionGadget as EncryptionGadget<
                     N::AccountEncryptionScheme,
                     N::InnerScalarField,
@@ -564,6 +603,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     given_value,
                     given_payload,
                     given_program_id,
+                    given_locktime,
                     given_randomizer,
                 )
             };
@@ -615,6 +655,16 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     )?;
 
                     output_program_ids.push(given_program_id_field_elements);
+
+                    if N::PROGRAM_FEES_ENABLED {
+                        let given_owner_field_elements = given_owner_bytes
+                            .to_constraint_field(&mut commitment_cs.ns(|| "convert given owner to field elements"))?;
+                        output_payees.push((
+                            given_owner_field_elements,
+                            given_value_field_elements,
+                            given_is_dummy.clone(),
+                        ));
+                    }
                 }
 
                 // *******************************************************************
@@ -627,6 +677,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                 plaintext.extend_from_slice(&given_value_bytes);
                 plaintext.extend_from_slice(&given_payload);
                 plaintext.extend_from_slice(&given_program_id);
+                plaintext.extend_from_slice(&given_locktime);
 
                 let encryption_randomness = <N::AccountEncryptionGadget as EncryptionGadget<
                     N::AccountEncryptionScheme,
@@ -661,6 +712,11 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
 
                 let given_randomizer_bytes =
                     given_randomizer.to_bytes(&mut commitment_cs.ns(|| "Convert given_randomizer to bytes"))?;
+
+                let mut payload_commitment_input = given_randomizer_bytes.clone();
+                payload_commitment_input.extend_from_slice(&given_payload);
+                payload_commitment_inputs.push(payload_commitment_input);
+
                 let record_view_key_commitment_bytes = record_view_key_commitment
                     .to_bytes(&mut commitment_cs.ns(|| "Convert record_view_key_commitment to bytes"))?;
 
@@ -885,6 +941,137 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                 .enforce_equal(&mut cs.ns(|| "Check that transition ID is valid"), &given_transition_id)?;
         }
 
+        // ********************************************************************
+        // Check the input records are unlocked at the locktime cutoff.
+        // ********************************************************************
+        if N::RECORD_LOCKTIMES_ENABLED {
+            let mut cs = cs.ns(|| "Check that the input records are unlocked.");
+
+            let locktime_cutoff_bytes = UInt8::alloc_input_vec_le(
+                &mut cs.ns(|| "Allocate the locktime cutoff"),
+                &public.locktime_cutoff().to_bytes_le()?,
+            )?;
+            let (cutoff_block_height, cutoff_timestamp) = to_locktime_bounds(&locktime_cutoff_bytes);
+
+            for (i, input_locktime) in input_locktimes.iter().enumerate() {
+                let (block_height, timestamp) = to_locktime_bounds(input_locktime);
+
+                let is_block_height_unlocked = block_height.less_than_or_equal(
+                    &mut cs.ns(|| format!("Compare the block height of input record {}", i)),
+                    &cutoff_block_height,
+                )?;
+                is_block_height_unlocked.enforce_equal(
+                    &mut cs.ns(|| format!("Check that input record {} is unlocked at the cutoff height", i)),
+                    &Boolean::constant(true),
+                )?;
+
+                let is_timestamp_unlocked = timestamp.less_than_or_equal(
+                    &mut cs.ns(|| format!("Compare the timestamp of input record {}", i)),
+                    &cutoff_timestamp,
+                )?;
+                is_timestamp_unlocked.enforce_equal(
+                    &mut cs.ns(|| format!("Check that input record {} is unlocked at the cutoff timestamp", i)),
+                    &Boolean::constant(true),
+                )?;
+            }
+        }
+
+        // ********************************************************************
+        // Check the program fee is paid to the program owner by an output record.
+        // ********************************************************************
+        if N::PROGRAM_FEES_ENABLED {
+            let mut cs = cs.ns(|| "Check that the program fee is paid.");
+
+            let program_fee_bytes = UInt8::alloc_input_vec_le(
+                &mut cs.ns(|| "Allocate the program fee"),
+                &ProgramFee::to_input_bytes(public.program_fee())?,
+            )?;
+            let (program_fee_owner, program_fee_amount) = program_fee_bytes.split_at(N::ADDRESS_SIZE_IN_BYTES);
+            let program_fee_owner_field_elements = program_fee_owner
+                .to_constraint_field(&mut cs.ns(|| "convert program fee owner to field elements"))?;
+            let program_fee_amount_field_elements = program_fee_amount
+                .to_constraint_field(&mut cs.ns(|| "convert program fee amount to field elements"))?;
+
+            // A program fee of zero does not require an output record.
+            let mut is_program_fee_paid = program_fee_amount_field_elements
+                .is_eq(&mut cs.ns(|| "Check if the program fee is zero"), &zero_value_field_elements)?;
+
+            for (j, (owner, value, is_dummy)) in output_payees.iter().enumerate() {
+                let is_program_owner = owner.is_eq(
+                    &mut cs.ns(|| format!("Check if output record {} is owned by the program owner", j)),
+                    &program_fee_owner_field_elements,
+                )?;
+                let is_program_fee_amount = value.is_eq(
+                    &mut cs.ns(|| format!("Check if output record {} has the program fee amount", j)),
+                    &program_fee_amount_field_elements,
+                )?;
+                let is_program_fee_record = Boolean::kary_and(
+                    &mut cs.ns(|| format!("Check if output record {} pays the program fee", j)),
+                    &[is_program_owner, is_program_fee_amount, is_dummy.not()],
+                )?;
+                is_program_fee_paid = Boolean::or(
+                    &mut cs.ns(|| format!("Accumulate the program fee payment of output record {}", j)),
+                    &is_program_fee_paid,
+                    &is_program_fee_record,
+                )?;
+            }
+
+            is_program_fee_paid.enforce_equal(
+                &mut cs.ns(|| "Check that the program fee is paid by an output record"),
+                &Boolean::constant(true),
+            )?;
+        }
+
+        // ********************************************************************
+        // Check the payload commitments are well-formed.
+        // ********************************************************************
+        {
+            let mut cs = cs.ns(|| "Check that the payload commitments are valid.");
+
+            let payload_commitments = match &public.payload_commitments {
+                Some(payload_commitments) if payload_commitments.len() == N::NUM_PAYLOAD_COMMITMENTS => {
+                    payload_commitments
+                }
+                _ => return Err(SynthesisError::AssignmentMissing),
+            };
+
+            for (i, (payload_commitment, payload_commitment_input)) in payload_commitments
+                .iter()
+                .zip_eq(payload_commitment_inputs.into_iter().take(N::NUM_PAYLOAD_COMMITMENTS))
+                .enumerate()
+            {
+                let candidate_payload_commitment = record_commitment_parameters.check_evaluation_gadget(
+                    &mut cs.ns(|| format!("Compute payload commitment {}", i)),
+                    payload_commitment_input,
+                )?;
+
+                let given_payload_commitment = <N::CommitmentGadget as CRHGadget<
+                    N::CommitmentScheme,
+                    N::InnerScalarField,
+                >>::OutputGadget::alloc_input(
+                    &mut cs.ns(|| format!("Allocate given payload commitment {}", i)),
+                    || Ok(*payload_commitment),
+                )?;
+
+                candidate_payload_commitment.enforce_equal(
+                    &mut cs.ns(|| format!("Check that payload commitment {} is valid", i)),
+                    &given_payload_commitment,
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
+
+/// Returns the block height and timestamp of the given locktime bounds, as 64-bit integers.
+/// The bounds are encoded as a 32-bit block height followed by a 64-bit timestamp, in little-endian order.
+fn to_locktime_bounds(bytes: &[UInt8]) -> (Int64, Int64) {
+    let bits = bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+    let mut block_height_bits = bits[..32].to_vec();
+    block_height_bits.resize(64, Boolean::constant(false));
+    (
+        Int64::from_bits_le(&block_height_bits),
+        Int64::from_bits_le(&bits[32..96]),
+    )
+}
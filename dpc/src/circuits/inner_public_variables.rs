@@ -14,23 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, Network};
+use crate::{AleoAmount, LocktimeCutoff, Network, ProgramFee};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 use snarkvm_utilities::ToBytes;
 
 use anyhow::Result;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct InnerPublicVariables<N: Network> {
     /// Transition ID
     transition_id: N::TransitionID,
     value_balance: AleoAmount,
     ledger_root: N::LedgerRoot,
     local_transitions_root: N::TransactionID,
+    /// The cutoff that the locktimes of the input records are checked against.
+    /// This is only a public input if `N::RECORD_LOCKTIMES_ENABLED` is `true`.
+    locktime_cutoff: LocktimeCutoff,
+    /// The program fee paid by the transition, if any.
+    /// This is only a public input if `N::PROGRAM_FEES_ENABLED` is `true`.
+    program_fee: Option<ProgramFee<N>>,
     // These are required in natively verifying an inner circuit proof.
     // However for verification in the outer circuit, these must be provided as witness.
     /// Program ID
     pub(super) program_id: Option<N::ProgramID>,
+    /// Payload commitments
+    pub(super) payload_commitments: Option<Vec<N::Commitment>>,
 }
 
 impl<N: Network> InnerPublicVariables<N> {
@@ -40,7 +48,10 @@ impl<N: Network> InnerPublicVariables<N> {
             value_balance: AleoAmount::ZERO,
             ledger_root: N::LedgerRoot::default(),
             local_transitions_root: Default::default(),
+            locktime_cutoff: Default::default(),
+            program_fee: None,
             program_id: Some(N::ProgramID::default()),
+            payload_commitments: Some(vec![Default::default(); N::NUM_PAYLOAD_COMMITMENTS]),
         }
     }
 
@@ -49,14 +60,20 @@ impl<N: Network> InnerPublicVariables<N> {
         value_balance: AleoAmount,
         ledger_root: N::LedgerRoot,
         local_transitions_root: N::TransactionID,
+        locktime_cutoff: LocktimeCutoff,
+        program_fee: Option<ProgramFee<N>>,
         program_id: Option<N::ProgramID>,
+        payload_commitments: Option<Vec<N::Commitment>>,
     ) -> Self {
         Self {
             transition_id,
             value_balance,
             ledger_root,
             local_transitions_root,
+            locktime_cutoff,
+            program_fee,
             program_id,
+            payload_commitments,
         }
     }
 
@@ -78,6 +95,16 @@ impl<N: Network> InnerPublicVariables<N> {
     pub(crate) fn local_transitions_root(&self) -> N::TransactionID {
         self.local_transitions_root
     }
+
+    /// Returns the locktime cutoff of the transition.
+    pub(crate) fn locktime_cutoff(&self) -> LocktimeCutoff {
+        self.locktime_cutoff
+    }
+
+    /// Returns the program fee paid by the transition, if any.
+    pub(crate) fn program_fee(&self) -> Option<&ProgramFee<N>> {
+        self.program_fee.as_ref()
+    }
 }
 
 impl<N: Network> ToConstraintField<N::InnerScalarField> for InnerPublicVariables<N> {
@@ -93,6 +120,20 @@ impl<N: Network> ToConstraintField<N::InnerScalarField> for InnerPublicVariables
         v.extend_from_slice(&self.value_balance.to_bytes_le()?.to_field_elements()?);
         v.extend_from_slice(&self.transition_id.to_field_elements()?);
 
+        if N::RECORD_LOCKTIMES_ENABLED {
+            v.extend_from_slice(&self.locktime_cutoff.to_bytes_le()?.to_field_elements()?);
+        }
+
+        if N::PROGRAM_FEES_ENABLED {
+            v.extend_from_slice(&ProgramFee::to_input_bytes(self.program_fee())?.to_field_elements()?);
+        }
+
+        if let Some(payload_commitments) = &self.payload_commitments {
+            for payload_commitment in payload_commitments {
+                v.extend_from_slice(&payload_commitment.to_field_elements()?);
+            }
+        }
+
         Ok(v)
     }
 }
@@ -14,6 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod balance_circuit;
+pub use balance_circuit::*;
+
+pub(crate) mod balance_private_variables;
+pub(crate) use balance_private_variables::*;
+
+pub mod balance_public_variables;
+pub use balance_public_variables::*;
+
 pub mod inner_circuit;
 pub use inner_circuit::*;
 
@@ -23,6 +32,15 @@ pub(crate) use inner_private_variables::*;
 pub(crate) mod inner_public_variables;
 pub(crate) use inner_public_variables::*;
 
+pub mod non_ownership_circuit;
+pub use non_ownership_circuit::*;
+
+pub(crate) mod non_ownership_private_variables;
+pub(crate) use non_ownership_private_variables::*;
+
+pub mod non_ownership_public_variables;
+pub use non_ownership_public_variables::*;
+
 pub mod outer_circuit;
 pub use outer_circuit::*;
 
@@ -32,5 +50,8 @@ pub(crate) use outer_private_variables::*;
 pub(crate) mod outer_public_variables;
 pub(crate) use outer_public_variables::*;
 
+pub mod record_decryption;
+pub use record_decryption::*;
+
 #[cfg(test)]
 mod tests;
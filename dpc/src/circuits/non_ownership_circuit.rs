@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, NonOwnershipPrivateVariables, NonOwnershipPublicVariables};
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{algorithms::EncryptionGadget, alloc::AllocGadget, eq::EqGadget},
+    ToConstraintFieldGadget,
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::ToBytes;
+
+use std::ops::Deref;
+
+///
+/// A circuit that proves the account with the given address does not own the record ciphertext
+/// with the given ciphertext randomizer and record view key commitment.
+///
+/// The account view key is private. It must derive the given address, and the record view key
+/// that it derives from the ciphertext randomizer must not match the record view key commitment,
+/// which is the check in `Ciphertext::is_owner`.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"))]
+pub struct NonOwnershipCircuit<N: Network> {
+    public: NonOwnershipPublicVariables<N>,
+    private: NonOwnershipPrivateVariables<N>,
+}
+
+impl<N: Network> NonOwnershipCircuit<N> {
+    pub fn blank() -> Self {
+        Self {
+            public: NonOwnershipPublicVariables::blank(),
+            private: NonOwnershipPrivateVariables::blank(),
+        }
+    }
+
+    pub fn new(public: NonOwnershipPublicVariables<N>, private: NonOwnershipPrivateVariables<N>) -> Self {
+        Self { public, private }
+    }
+}
+
+impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for NonOwnershipCircuit<N> {
+    fn generate_constraints<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        let public = &self.public;
+        let private = &self.private;
+
+        let account_encryption_parameters =
+            N::AccountEncryptionGadget::alloc_constant(&mut cs.ns(|| "Declare account encryption parameters"), || {
+                Ok(N::account_encryption_scheme().clone())
+            })?;
+
+        // Declare the account view key.
+        let account_view_key = <N::AccountEncryptionGadget as EncryptionGadget<
+            N::AccountEncryptionScheme,
+            N::InnerScalarField,
+        >>::PrivateKeyGadget::alloc(
+            &mut cs.ns(|| "Declare the account view key"),
+            || Ok(*private.account_view_key.deref()),
+        )?;
+
+        // ********************************************************************
+        // Check that the account view key derives the address.
+        // ********************************************************************
+        {
+            let address_cs = &mut cs.ns(|| "Check that the address is derived correctly");
+
+            let candidate_address = account_encryption_parameters
+                .check_public_key_gadget(&mut address_cs.ns(|| "Compute the address"), &account_view_key)?;
+            let candidate_address_field_elements = candidate_address
+                .to_bytes(&mut address_cs.ns(|| "Convert candidate address to bytes"))?
+                .to_constraint_field(&mut address_cs.ns(|| "convert candidate address to field elements"))?;
+
+            let given_address_bytes = UInt8::alloc_input_vec_le(
+                &mut address_cs.ns(|| "Allocate given address"),
+                &public.address().to_bytes_le()?,
+            )?;
+            let given_address_field_elements = given_address_bytes
+                .to_constraint_field(&mut address_cs.ns(|| "convert given address to field elements"))?;
+
+            candidate_address_field_elements.enforce_equal(
+                &mut address_cs.ns(|| "enforce the address is equal"),
+                &given_address_field_elements,
+            )?;
+        }
+
+        // ********************************************************************
+        // Check that the record view key does not match the record view key commitment.
+        // ********************************************************************
+        {
+            let ownership_cs = &mut cs.ns(|| "Check that the record is not owned");
+
+            let given_randomizer = <N::AccountEncryptionGadget as EncryptionGadget<
+                N::AccountEncryptionScheme,
+                N::InnerScalarField,
+            >>::CiphertextRandomizer::alloc(
+                &mut ownership_cs.ns(|| "Declare the ciphertext randomizer"),
+                || Ok(*public.randomizer()),
+            )?;
+            let given_randomizer_field_elements = given_randomizer
+                .to_bytes(&mut ownership_cs.ns(|| "Convert given randomizer to bytes"))?
+                .to_constraint_field(&mut ownership_cs.ns(|| "convert given randomizer to field elements"))?;
+
+            let randomizer_bytes = UInt8::alloc_input_vec_le(
+                &mut ownership_cs.ns(|| "Allocate randomizer"),
+                &public.randomizer().to_bytes_le()?,
+            )?;
+            let randomizer_field_elements = randomizer_bytes
+                .to_constraint_field(&mut ownership_cs.ns(|| "convert randomizer to field elements"))?;
+
+            given_randomizer_field_elements.enforce_equal(
+                &mut ownership_cs.ns(|| "enforce the randomizer is equal"),
+                &randomizer_field_elements,
+            )?;
+
+            let candidate_record_view_key = account_encryption_parameters
+                .check_symmetric_key_from_ciphertext_randomizer(
+                    &mut ownership_cs.ns(|| "Compute the record view key"),
+                    &given_randomizer,
+                    &account_view_key,
+                )?;
+            let candidate_record_view_key_commitment = account_encryption_parameters.check_symmetric_key_commitment(
+                &mut ownership_cs.ns(|| "Compute the record view key commitment"),
+                &candidate_record_view_key,
+            )?;
+
+            // The strict encoding is unique, so that unequal bytes imply unequal commitments.
+            let candidate_record_view_key_commitment_bytes = candidate_record_view_key_commitment
+                .to_bytes_strict(&mut ownership_cs.ns(|| "Convert candidate record view key commitment to bytes"))?;
+            let given_record_view_key_commitment_bytes = UInt8::alloc_input_vec_le(
+                &mut ownership_cs.ns(|| "Allocate given record view key commitment"),
+                &public.record_view_key_commitment().to_bytes_le()?,
+            )?;
+
+            let is_owner = candidate_record_view_key_commitment_bytes.is_eq(
+                &mut ownership_cs.ns(|| "Check if the record view key commitment is equal"),
+                &given_record_view_key_commitment_bytes,
+            )?;
+            is_owner.enforce_equal(
+                &mut ownership_cs.ns(|| "enforce the record view key commitment is not equal"),
+                &Boolean::constant(false),
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, ViewKey};
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"))]
+pub struct NonOwnershipPrivateVariables<N: Network> {
+    /// The view key of the account that does not own the record.
+    pub(super) account_view_key: ViewKey<N>,
+}
+
+impl<N: Network> NonOwnershipPrivateVariables<N> {
+    pub(crate) fn blank() -> Self {
+        Self {
+            account_view_key: Default::default(),
+        }
+    }
+
+    pub(crate) fn new(account_view_key: ViewKey<N>) -> Self {
+        Self { account_view_key }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, Network};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_utilities::ToBytes;
+
+use std::ops::Deref;
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct NonOwnershipPublicVariables<N: Network> {
+    /// The address of the account that does not own the record.
+    address: Address<N>,
+    /// The ciphertext randomizer of the record ciphertext.
+    randomizer: N::RecordRandomizer,
+    /// The record view key commitment of the record ciphertext.
+    record_view_key_commitment: N::RecordViewKeyCommitment,
+}
+
+impl<N: Network> NonOwnershipPublicVariables<N> {
+    pub(crate) fn blank() -> Self {
+        Self {
+            address: Default::default(),
+            randomizer: Default::default(),
+            record_view_key_commitment: Default::default(),
+        }
+    }
+
+    pub(crate) fn new(address: Address<N>, ciphertext: &N::RecordCiphertext) -> Self {
+        Self {
+            address,
+            randomizer: ciphertext.deref().randomizer(),
+            record_view_key_commitment: *ciphertext.deref().record_view_key_commitment(),
+        }
+    }
+
+    /// Returns the address.
+    pub(crate) fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the ciphertext randomizer.
+    pub(crate) fn randomizer(&self) -> N::RecordRandomizer {
+        self.randomizer
+    }
+
+    /// Returns the record view key commitment.
+    pub(crate) fn record_view_key_commitment(&self) -> N::RecordViewKeyCommitment {
+        self.record_view_key_commitment
+    }
+}
+
+impl<N: Network> ToConstraintField<N::InnerScalarField> for NonOwnershipPublicVariables<N> {
+    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
+        let mut v = Vec::new();
+        v.extend_from_slice(&self.address.to_bytes_le()?.to_field_elements()?);
+        v.extend_from_slice(&self.randomizer.to_bytes_le()?.to_field_elements()?);
+        v.extend_from_slice(&self.record_view_key_commitment.to_bytes_le()?.to_field_elements()?);
+        Ok(v)
+    }
+}
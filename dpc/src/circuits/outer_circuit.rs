@@ -14,8 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Execution, Network, OuterPrivateVariables, OuterPublicVariables};
-use snarkvm_algorithms::traits::{MerkleParameters, SNARK};
+use crate::{
+    Execution,
+    Network,
+    OuterPrivateVariables,
+    OuterPublicVariables,
+    Program,
+    ProgramFee,
+    ProgramPublicVariables,
+};
+use snarkvm_algorithms::traits::{MerkleParameters, CRH, SNARK};
 use snarkvm_fields::ToConstraintField;
 use snarkvm_gadgets::{
     algorithms::merkle_tree::MerklePathGadget,
@@ -23,6 +31,7 @@ use snarkvm_gadgets::{
         algorithms::{CRHGadget, SNARKVerifierGadget},
         alloc::AllocGadget,
         eq::EqGadget,
+        integers::integer::Integer,
     },
     MergeGadget,
     ToBitsLEGadget,
@@ -30,8 +39,10 @@ use snarkvm_gadgets::{
     ToMinimalBitsGadget,
     UInt8,
 };
-use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
-use snarkvm_utilities::ToBytes;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, TestConstraintSystem};
+use snarkvm_utilities::{ToBytes, ToMinimalBits};
+
+use std::fmt;
 
 #[derive(Derivative)]
 #[derivative(Clone(bound = "N: Network"))]
@@ -66,6 +77,198 @@ impl<N: Network> ConstraintSynthesizer<N::OuterScalarField> for OuterCircuit<N>
     }
 }
 
+/// A named sub-check of the outer circuit, whose constraints are synthesized in a namespace of the same label.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OuterCircuitCheck {
+    /// Checks the transition ID in the inner and program circuit inputs are equal.
+    TransitionID,
+    /// Checks the program fee in the inner circuit input is equal to the program fee hashed into the function ID.
+    ProgramFee,
+    /// Checks the payload commitments in the inner and program circuit inputs are equal.
+    PayloadCommitments,
+    /// Checks the inner circuit proof is valid.
+    InnerProof,
+    /// Checks the function of the program circuit verifying key is in the program, by its program path.
+    ProgramPath,
+    /// Checks the program circuit proof is valid.
+    ProgramProof,
+    /// Checks the inner circuit ID is derived from the inner circuit verifying key.
+    InnerCircuitID,
+}
+
+impl OuterCircuitCheck {
+    /// The sub-checks of the outer circuit, in the order they are synthesized.
+    pub const ALL: [Self; 7] = [
+        Self::TransitionID,
+        Self::ProgramFee,
+        Self::PayloadCommitments,
+        Self::InnerProof,
+        Self::ProgramPath,
+        Self::ProgramProof,
+        Self::InnerCircuitID,
+    ];
+
+    /// Returns the label of the namespace of the sub-check.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TransitionID => "Check the transition ID",
+            Self::ProgramFee => "Check the program fee",
+            Self::PayloadCommitments => "Check the payload commitments",
+            Self::InnerProof => "Check the inner circuit proof",
+            Self::ProgramPath => "Check the program path",
+            Self::ProgramProof => "Check the program proof",
+            Self::InnerCircuitID => "Check the inner circuit ID",
+        }
+    }
+
+    /// Returns the sub-check of the constraint with the given path, if it is in a sub-check.
+    pub fn from_constraint_path(path: &str) -> Option<Self> {
+        path.split('/')
+            .find_map(|segment| Self::ALL.iter().find(|check| segment.starts_with(check.label())))
+            .copied()
+    }
+
+    /// Returns the names and values checked by the sub-check, computed natively from the given variables.
+    fn eject_values<N: Network>(
+        &self,
+        public: &OuterPublicVariables<N>,
+        private: &OuterPrivateVariables<N>,
+    ) -> Vec<(&'static str, String)> {
+        let execution = &private.execution;
+        let payload_commitments = || {
+            execution
+                .payload_commitments
+                .iter()
+                .map(|commitment| commitment.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let program_fee = || match public.program_fee() {
+            Some(program_fee) => format!("{} to {}", program_fee.amount(), program_fee.owner()),
+            None => "none".to_string(),
+        };
+
+        match self {
+            Self::TransitionID => vec![("transition ID", public.transition_id().to_string())],
+            Self::ProgramFee => vec![("program fee", program_fee())],
+            Self::PayloadCommitments => vec![("payload commitments", payload_commitments())],
+            Self::InnerProof => vec![
+                ("ledger root", public.ledger_root().to_string()),
+                ("local transitions root", public.local_transitions_root().to_string()),
+                ("program ID", execution.program_id.to_string()),
+                ("value balance", public.value_balance().to_string()),
+                ("transition ID", public.transition_id().to_string()),
+                ("program fee", program_fee()),
+                ("payload commitments", payload_commitments()),
+            ],
+            Self::ProgramPath => {
+                let function_id = N::function_id_with_program_fee(&execution.verifying_key, public.program_fee());
+                let is_valid = function_id.as_ref().map_or(false, |function_id| {
+                    Program::<N>::verify_program_path(&execution.program_id, function_id, &execution.program_path)
+                });
+                vec![
+                    ("program ID", execution.program_id.to_string()),
+                    ("function ID", format_result(function_id)),
+                    ("program path is valid", is_valid.to_string()),
+                ]
+            }
+            Self::ProgramProof => {
+                let program_public =
+                    ProgramPublicVariables::<N>::new(public.transition_id(), execution.payload_commitments.clone());
+                let is_valid = N::ProgramSNARK::verify(&execution.verifying_key, &program_public, &execution.proof);
+                vec![
+                    (
+                        "function ID",
+                        format_result(N::function_id_with_program_fee(&execution.verifying_key, public.program_fee())),
+                    ),
+                    ("transition ID", public.transition_id().to_string()),
+                    ("program proof is valid", format_result(is_valid)),
+                ]
+            }
+            Self::InnerCircuitID => {
+                let candidate_inner_circuit_id = N::inner_circuit_id_crh()
+                    .hash_bits(&private.inner_verifying_key.to_minimal_bits_v1())
+                    .map(N::InnerCircuitID::from);
+                vec![
+                    ("given inner circuit ID", public.inner_circuit_id().to_string()),
+                    ("candidate inner circuit ID", format_result(candidate_inner_circuit_id)),
+                ]
+            }
+        }
+    }
+}
+
+impl fmt::Display for OuterCircuitCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// The failure of the outer circuit, as found by `debug_outer_circuit`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OuterCircuitFailure {
+    /// The sub-check that failed, if the failure is in a sub-check.
+    pub check: Option<OuterCircuitCheck>,
+    /// The synthesis error, or the path of the first unsatisfied constraint.
+    pub reason: String,
+    /// The names and values checked by the sub-check that failed.
+    pub values: Vec<(&'static str, String)>,
+}
+
+impl fmt::Display for OuterCircuitFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.check {
+            Some(check) => write!(f, "{} failed: {}", check, self.reason)?,
+            None => write!(f, "Outer circuit failed: {}", self.reason)?,
+        }
+        for (name, value) in &self.values {
+            write!(f, "\n  {}: {}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for OuterCircuitFailure {}
+
+///
+/// Executes the outer circuit in a test constraint system, returning the named sub-check
+/// of the first unsatisfied constraint and the values it checks, if the circuit is not satisfied.
+///
+/// This is a debug mode of `execute_outer_circuit`, for use when an outer proof fails to generate.
+///
+pub fn debug_outer_circuit<N: Network>(
+    public: &OuterPublicVariables<N>,
+    private: &OuterPrivateVariables<N>,
+) -> Result<(), OuterCircuitFailure> {
+    let mut cs = TestConstraintSystem::<N::OuterScalarField>::new();
+    if let Err(error) = execute_outer_circuit::<N, _>(&mut cs.ns(|| "Outer circuit"), public, private) {
+        return Err(OuterCircuitFailure {
+            check: None,
+            reason: error.to_string(),
+            values: vec![],
+        });
+    }
+
+    match cs.which_is_unsatisfied() {
+        Some(constraint_path) => {
+            let check = OuterCircuitCheck::from_constraint_path(&constraint_path);
+            Err(OuterCircuitFailure {
+                check,
+                reason: format!("Unsatisfied constraint {}", constraint_path),
+                values: check.map_or(vec![], |check| check.eject_values(public, private)),
+            })
+        }
+        None => Ok(()),
+    }
+}
+
+fn format_result<T: fmt::Display, E: fmt::Display>(result: Result<T, E>) -> String {
+    match result {
+        Ok(value) => value.to_string(),
+        Err(error) => format!("error ({})", error),
+    }
+}
+
 pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarField>>(
     cs: &mut CS,
     public: &OuterPublicVariables<N>,
@@ -123,6 +326,8 @@ pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarFiel
     let transition_id_fe_program_snark =
         alloc_program_snark_field_element::<N, _, _>(cs, &public.transition_id(), "transition ID program snark")?;
     {
+        let cs = &mut cs.ns(|| OuterCircuitCheck::TransitionID.label());
+
         // Construct inner snark input as bits
         let transition_id_input_inner_snark_bits =
             transition_id_fe_inner_snark.to_bits_le(cs.ns(|| "transition ID inner snark to bits"))?;
@@ -134,32 +339,115 @@ pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarFiel
         )?;
     }
 
-    let inner_snark_input =
-        <N::InnerSNARKGadget as SNARKVerifierGadget<_>>::InputGadget::merge_many(cs.ns(|| "inner_snark_input"), &[
-            ledger_root_fe_inner_snark,
-            local_transitions_root_fe_inner_snark,
-            program_id_fe,
-            value_balance_fe,
-            transition_id_fe_inner_snark,
-        ])?;
+    // The locktime cutoff is only an input of the inner circuit if the network supports record locktimes.
+    let locktime_cutoff_fe = match N::RECORD_LOCKTIMES_ENABLED {
+        true => Some(alloc_inner_snark_input_field_element::<N, _, _>(
+            cs,
+            &public.locktime_cutoff().to_bytes_le()?[..],
+            "locktime cutoff",
+        )?),
+        false => None,
+    };
+
+    // The payload commitments are allocated as witness, as they are not included in the transaction.
+    let mut payload_commitments_fe_inner_snark = Vec::with_capacity(N::NUM_PAYLOAD_COMMITMENTS);
+    let mut payload_commitments_fe_program_snark = Vec::with_capacity(N::NUM_PAYLOAD_COMMITMENTS);
+    for i in 0..N::NUM_PAYLOAD_COMMITMENTS {
+        let cs = &mut cs.ns(|| format!("{} {}", OuterCircuitCheck::PayloadCommitments.label(), i));
+
+        let payload_commitment = private
+            .execution
+            .payload_commitments
+            .get(i)
+            .ok_or(SynthesisError::AssignmentMissing)?;
+
+        let payload_commitment_fe_inner_snark = alloc_inner_snark_field_element::<N, _, _>(
+            cs,
+            payload_commitment,
+            &format!("payload commitment {} inner snark", i),
+        )?;
+        let payload_commitment_fe_program_snark = alloc_program_snark_field_element::<N, _, _>(
+            cs,
+            payload_commitment,
+            &format!("payload commitment {} program snark", i),
+        )?;
+        {
+            let payload_commitment_inner_snark_bits = payload_commitment_fe_inner_snark
+                .to_bits_le(cs.ns(|| format!("payload commitment {} inner snark to bits", i)))?;
+            let payload_commitment_program_snark_bits = payload_commitment_fe_program_snark
+                .to_bits_le(cs.ns(|| format!("payload commitment {} program snark to bits", i)))?;
+            payload_commitment_inner_snark_bits.enforce_equal(
+                cs.ns(|| format!("payload commitment {} equality", i)),
+                &payload_commitment_program_snark_bits,
+            )?;
+        }
+
+        payload_commitments_fe_inner_snark.push(payload_commitment_fe_inner_snark);
+        payload_commitments_fe_program_snark.push(payload_commitment_fe_program_snark);
+    }
+
+    // The program fee is only an input of the inner circuit if the network supports program fees.
+    // Its bytes are hashed into the function ID, so the program fee is the one declared by the function.
+    let (program_fee_fe_inner_snark, program_fee_bytes) = match N::PROGRAM_FEES_ENABLED {
+        true => {
+            let cs = &mut cs.ns(|| OuterCircuitCheck::ProgramFee.label());
+
+            let program_fee = ProgramFee::to_input_bytes(public.program_fee())?;
+            let program_fee_fe_inner_snark =
+                alloc_inner_snark_input_field_element::<N, _, _>(cs, &program_fee[..], "program fee inner snark")?;
+            let program_fee_bytes = UInt8::alloc_vec(&mut cs.ns(|| "Given program fee bytes"), &program_fee)?;
+            let program_fee_fe =
+                N::InnerSNARKGadget::input_gadget_from_bytes(cs.ns(|| "Given program fee fe"), &program_fee_bytes)?;
+
+            let program_fee_inner_snark_bits =
+                program_fee_fe_inner_snark.to_bits_le(cs.ns(|| "program fee inner snark to bits"))?;
+            let program_fee_bits = program_fee_fe.to_bits_le(cs.ns(|| "program fee to bits"))?;
+            program_fee_inner_snark_bits.enforce_equal(cs.ns(|| "program fee equality"), &program_fee_bits)?;
+
+            (Some(program_fee_fe_inner_snark), program_fee_bytes)
+        }
+        false => (None, vec![]),
+    };
+
+    let mut inner_snark_inputs = vec![
+        ledger_root_fe_inner_snark,
+        local_transitions_root_fe_inner_snark,
+        program_id_fe,
+        value_balance_fe,
+        transition_id_fe_inner_snark,
+    ];
+    inner_snark_inputs.extend(locktime_cutoff_fe);
+    inner_snark_inputs.extend(program_fee_fe_inner_snark);
+    inner_snark_inputs.extend(payload_commitments_fe_inner_snark);
+
+    let inner_snark_input = <N::InnerSNARKGadget as SNARKVerifierGadget<_>>::InputGadget::merge_many(
+        cs.ns(|| "inner_snark_input"),
+        &inner_snark_inputs,
+    )?;
 
     // ************************************************************************
     // Verify the inner circuit proof.
     // ************************************************************************
 
-    let inner_verifying_key = <N::InnerSNARKGadget as SNARKVerifierGadget<_>>::VerificationKeyGadget::alloc(
-        &mut cs.ns(|| "Allocate inner circuit verifying key"),
+    // The inner circuit verifying key is fixed for the network, so it is declared as a constant,
+    // and prepared natively to save the constraints of preparing it in the circuit.
+    let inner_verifying_key = <N::InnerSNARKGadget as SNARKVerifierGadget<_>>::VerificationKeyGadget::alloc_constant(
+        &mut cs.ns(|| "Declare inner circuit verifying key"),
         || Ok(&private.inner_verifying_key),
     )?;
+    let prepared_inner_verifying_key = N::InnerSNARKGadget::prepare_constant_verification_key(
+        &mut cs.ns(|| "Prepare inner circuit verifying key"),
+        &private.inner_verifying_key,
+    )?;
 
     let inner_snark_proof = <N::InnerSNARKGadget as SNARKVerifierGadget<_>>::ProofGadget::alloc(
         &mut cs.ns(|| "Allocate inner circuit proof"),
         || Ok(&*private.inner_proof),
     )?;
 
-    N::InnerSNARKGadget::check_verify(
-        &mut cs.ns(|| "Check that the inner circuit proof is satisfied"),
-        &inner_verifying_key,
+    N::InnerSNARKGadget::prepared_check_verify(
+        &mut cs.ns(|| OuterCircuitCheck::InnerProof.label()),
+        &prepared_inner_verifying_key,
         &inner_snark_input,
         &inner_snark_proof,
     )?;
@@ -178,10 +466,15 @@ pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarFiel
 
         // Check that the program ID is derived correctly.
         {
+            let cs = &mut cs.ns(|| OuterCircuitCheck::ProgramPath.label());
+
             // Verify that the claimed circuit ID is a valid Merkle path in the program circuits tree.
-            let program_circuit_verifying_key_bits = program_circuit_verifying_key
+            let mut program_circuit_verifying_key_bits = program_circuit_verifying_key
                 .to_minimal_bits(cs.ns(|| "alloc_program_circuit_verifying_key_field_elements"))?;
 
+            // The function ID commits to the program fee, if the network supports program fees.
+            program_circuit_verifying_key_bits.extend(program_fee_bytes.iter().flat_map(|byte| byte.to_bits_le()));
+
             let claimed_circuit_id = function_id_crh.check_evaluation_gadget_on_bits(
                 &mut cs.ns(|| "Compute circuit ID"),
                 program_circuit_verifying_key_bits,
@@ -213,7 +506,14 @@ pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarFiel
             &mut cs.ns(|| "Allocate position"),
             || Ok(vec![N::InnerScalarField::from(0u128)]),
         )?;
-        let program_input = position_fe.merge(cs.ns(|| "Allocate program input"), &transition_id_fe_program_snark)?;
+
+        let mut program_inputs = vec![position_fe, transition_id_fe_program_snark];
+        program_inputs.extend(payload_commitments_fe_program_snark);
+
+        let program_input = <N::ProgramSNARKGadget as SNARKVerifierGadget<_>>::InputGadget::merge_many(
+            cs.ns(|| "Allocate program input"),
+            &program_inputs,
+        )?;
 
         let program_circuit_proof = <N::ProgramSNARKGadget as SNARKVerifierGadget<_>>::ProofGadget::alloc(
             &mut cs.ns(|| "Allocate program circuit proof"),
@@ -221,7 +521,7 @@ pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarFiel
         )?;
 
         N::ProgramSNARKGadget::check_verify(
-            &mut cs.ns(|| "Check that the program proof is satisfied"),
+            &mut cs.ns(|| OuterCircuitCheck::ProgramProof.label()),
             &program_circuit_verifying_key,
             &program_input,
             &program_circuit_proof,
@@ -234,6 +534,8 @@ pub fn execute_outer_circuit<N: Network, CS: ConstraintSystem<N::OuterScalarFiel
     // Check that the inner circuit ID is derived correctly.
     // ********************************************************************
 
+    let cs = &mut cs.ns(|| OuterCircuitCheck::InnerCircuitID.label());
+
     let inner_verifying_key_bits =
         inner_verifying_key.to_minimal_bits(&mut cs.ns(|| "Convert inner snark vk to bits"))?;
 
@@ -280,6 +582,22 @@ fn alloc_inner_snark_input_field_element<
     )
 }
 
+fn alloc_inner_snark_field_element<
+    N: Network,
+    V: ToConstraintField<N::InnerScalarField>,
+    CS: ConstraintSystem<N::OuterScalarField>,
+>(
+    cs: &mut CS,
+    var: &V,
+    name: &str,
+) -> Result<<N::InnerSNARKGadget as SNARKVerifierGadget<N::InnerSNARK>>::InputGadget, SynthesisError> {
+    let field_elements = var.to_field_elements().map_err(|_| SynthesisError::AssignmentMissing)?;
+    <N::InnerSNARKGadget as SNARKVerifierGadget<_>>::InputGadget::alloc(
+        cs.ns(|| format!("alloc_field_element_{}", name)),
+        || Ok(field_elements),
+    )
+}
+
 fn alloc_program_snark_field_element<
     N: Network,
     V: ToConstraintField<N::InnerScalarField>,
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, InnerPublicVariables, Network};
+use crate::{AleoAmount, InnerPublicVariables, LocktimeCutoff, Network, ProgramFee};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 use snarkvm_utilities::ToBits;
 
@@ -32,6 +32,7 @@ impl<N: Network> OuterPublicVariables<N> {
         // as they are not included in the transaction broadcast to the ledger.
         let mut inner_public_variables = InnerPublicVariables::blank();
         inner_public_variables.program_id = None;
+        inner_public_variables.payload_commitments = None;
 
         Self {
             inner_public_variables,
@@ -44,6 +45,7 @@ impl<N: Network> OuterPublicVariables<N> {
         // as they are not included in the transaction broadcast to the ledger.
         let mut inner_public_variables: InnerPublicVariables<N> = inner_public_variables;
         inner_public_variables.program_id = None;
+        inner_public_variables.payload_commitments = None;
 
         Self {
             inner_public_variables,
@@ -70,6 +72,16 @@ impl<N: Network> OuterPublicVariables<N> {
         self.inner_public_variables.local_transitions_root()
     }
 
+    /// Returns the locktime cutoff of the transition.
+    pub(crate) fn locktime_cutoff(&self) -> LocktimeCutoff {
+        self.inner_public_variables.locktime_cutoff()
+    }
+
+    /// Returns the program fee paid by the transition, if any.
+    pub(crate) fn program_fee(&self) -> Option<&ProgramFee<N>> {
+        self.inner_public_variables.program_fee()
+    }
+
     pub(crate) fn inner_circuit_id(&self) -> N::InnerCircuitID {
         self.inner_circuit_id
     }
@@ -77,9 +89,10 @@ impl<N: Network> OuterPublicVariables<N> {
 
 impl<N: Network> ToConstraintField<N::OuterScalarField> for OuterPublicVariables<N> {
     fn to_field_elements(&self) -> Result<Vec<N::OuterScalarField>, ConstraintFieldError> {
-        // In the outer circuit, these two variables must be allocated as witness,
+        // In the outer circuit, these variables must be allocated as witness,
         // as they are not included in the transaction.
         debug_assert!(self.inner_public_variables.program_id.is_none());
+        debug_assert!(self.inner_public_variables.payload_commitments.is_none());
 
         let mut v = Vec::new();
 
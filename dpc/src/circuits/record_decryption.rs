@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, Record};
+use snarkvm_gadgets::{
+    integers::uint::UInt8,
+    traits::{algorithms::EncryptionGadget, alloc::AllocGadget},
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::ops::Deref;
+
+/// The gadget for an account view key, used to decrypt record ciphertexts in-circuit.
+pub type AccountViewKeyGadget<N> = <<N as Network>::AccountEncryptionGadget as EncryptionGadget<
+    <N as Network>::AccountEncryptionScheme,
+    <N as Network>::InnerScalarField,
+>>::PrivateKeyGadget;
+
+///
+/// Decrypts the given record ciphertext in-circuit with the given account view key,
+/// and returns the record plaintext, as encoded by `Record`.
+///
+/// The ciphertext randomizer and the encrypted record bytes are allocated as private witnesses.
+/// To prove a statement about a specific record, the caller must additionally bind these witnesses
+/// to the record commitment, as the inner circuit does for input records.
+///
+pub fn decrypt_record_ciphertext<N: Network, CS: ConstraintSystem<N::InnerScalarField>>(
+    mut cs: CS,
+    account_view_key: &AccountViewKeyGadget<N>,
+    ciphertext: &N::RecordCiphertext,
+) -> Result<Vec<UInt8>, SynthesisError> {
+    let account_encryption_parameters =
+        N::AccountEncryptionGadget::alloc_constant(&mut cs.ns(|| "Declare account encryption parameters"), || {
+            Ok(N::account_encryption_scheme().clone())
+        })?;
+
+    let ciphertext_randomizer = <N::AccountEncryptionGadget as EncryptionGadget<
+        N::AccountEncryptionScheme,
+        N::InnerScalarField,
+    >>::CiphertextRandomizer::alloc(
+        &mut cs.ns(|| "Declare ciphertext randomizer"),
+        || Ok(*ciphertext.deref().randomizer()),
+    )?;
+
+    let record_bytes = UInt8::alloc_vec(
+        &mut cs.ns(|| "Declare encrypted record bytes"),
+        ciphertext.deref().record_bytes(),
+    )?;
+
+    account_encryption_parameters.check_decryption_from_ciphertext_randomizer(
+        &mut cs.ns(|| "Decrypt the record ciphertext"),
+        &ciphertext_randomizer,
+        account_view_key,
+        &record_bytes,
+        Record::<N>::plaintext_size_in_bytes(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme, AleoAmount, Payload};
+    use snarkvm_r1cs::TestConstraintSystem;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_decrypt_record_ciphertext() {
+        let rng = &mut thread_rng();
+
+        let account = Account::<Testnet2>::new(rng);
+        let payload = Payload::from(&[7u8; 32]);
+        let record = Record::new(
+            account.address(),
+            AleoAmount::from_i64(1234),
+            payload,
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap();
+        let expected_plaintext = record.ciphertext().to_plaintext(record.record_view_key()).unwrap();
+
+        // Ensure the owner decrypts the record ciphertext.
+        let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+        let account_view_key =
+            AccountViewKeyGadget::<Testnet2>::alloc(&mut cs.ns(|| "Declare account view key"), || {
+                Ok(*account.view_key().deref())
+            })
+            .unwrap();
+        let plaintext =
+            decrypt_record_ciphertext::<Testnet2, _>(cs.ns(|| "Decrypt"), &account_view_key, record.ciphertext())
+                .unwrap();
+
+        assert!(cs.is_satisfied());
+        assert_eq!(
+            expected_plaintext,
+            plaintext.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>()
+        );
+
+        // Ensure a different account does not decrypt the record ciphertext.
+        let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+        let stranger = Account::<Testnet2>::new(rng);
+        let account_view_key =
+            AccountViewKeyGadget::<Testnet2>::alloc(&mut cs.ns(|| "Declare account view key"), || {
+                Ok(*stranger.view_key().deref())
+            })
+            .unwrap();
+        decrypt_record_ciphertext::<Testnet2, _>(cs.ns(|| "Decrypt"), &account_view_key, record.ciphertext()).unwrap();
+
+        assert!(!cs.is_satisfied());
+    }
+}
@@ -16,11 +16,32 @@
 
 use crate::{circuits::*, prelude::*};
 use snarkvm_algorithms::prelude::*;
+use snarkvm_fields::Field;
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
 use snarkvm_utilities::{ToBytes, ToMinimalBits};
 
 use rand::thread_rng;
 
+/// The maximum number of private variables to mutate in each circuit.
+const MAX_PRIVATE_MUTATIONS: usize = 256;
+
+/// Checks that the constraint system rejects a mutation of each public variable outside of the given namespaces.
+fn check_mutations<F: Field>(name: &str, cs: &TestConstraintSystem<F>, exempt_namespaces: &[&str]) {
+    let statistics = cs.mutate_assignments(MAX_PRIVATE_MUTATIONS);
+
+    let accepted_public_mutations = statistics
+        .accepted_public_mutations
+        .iter()
+        .filter(|path| !exempt_namespaces.iter().any(|namespace| path.contains(namespace)))
+        .collect::<Vec<_>>();
+    assert!(
+        accepted_public_mutations.is_empty(),
+        "{} circuit accepted mutations of public variables: {:?}",
+        name,
+        accepted_public_mutations
+    );
+}
+
 fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize, expected_outer_num_constraints: usize) {
     let rng = &mut thread_rng();
 
@@ -60,6 +81,15 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
 
     //////////////////////////////////////////////////////////////////////////
 
+    // Compute the payload commitments.
+    let payload_commitments = request
+        .records()
+        .iter()
+        .chain(response.records().iter())
+        .take(N::NUM_PAYLOAD_COMMITMENTS)
+        .map(|record| record.to_payload_commitment().unwrap())
+        .collect::<Vec<_>>();
+
     // Compute the noop execution
     let execution = Execution {
         program_id: *N::noop_program_id(),
@@ -67,10 +97,11 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
         verifying_key: N::noop_circuit_verifying_key().clone(),
         proof: Noop::<N>::new()
             .execute(
-                ProgramPublicVariables::new(transition_id),
+                ProgramPublicVariables::new(transition_id, payload_commitments.clone()),
                 &NoopPrivateVariables::<N>::new_blank().unwrap(),
             )
             .unwrap(),
+        payload_commitments: payload_commitments.clone(),
     };
     assert_eq!(
         N::PROGRAM_PROOF_SIZE_IN_BYTES,
@@ -85,14 +116,17 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
         value_balance,
         ledger_root,
         local_transitions_root,
+        LocktimeCutoff::default(),
+        None,
         Some(program_id),
+        Some(payload_commitments.clone()),
     );
     let inner_private = InnerPrivateVariables::new(&request, &response).unwrap();
 
     // Check that the core check constraint system was satisfied.
     let mut inner_cs = TestConstraintSystem::<N::InnerScalarField>::new();
 
-    let inner_circuit = InnerCircuit::new(inner_public, inner_private);
+    let inner_circuit = InnerCircuit::new(inner_public.clone(), inner_private);
     inner_circuit
         .generate_constraints(&mut inner_cs.ns(|| "Inner circuit"))
         .unwrap();
@@ -113,28 +147,63 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
 
     assert!(inner_cs.is_satisfied());
 
+    // Check that the inner circuit rejects mutations of its public variables. The ledger root is only checked
+    // for the membership of input records that are not dummies, so a coinbase request leaves it unchecked.
+    check_mutations("Inner", &inner_cs, &["Declare the ledger root"]);
+
     //////////////////////////////////////////////////////////////////////////
 
     // Generate inner circuit parameters and proof for verification in the outer circuit.
     let (inner_proving_key, inner_verifying_key) =
-        <N as Network>::InnerSNARK::setup(&InnerCircuit::<N>::blank(), &mut SRS::CircuitSpecific(rng)).unwrap();
+        N::InnerSNARK::setup(&InnerCircuit::<N>::blank(), &mut SRS::CircuitSpecific(rng)).unwrap();
 
     // NOTE: Do not change this to `N::inner_circuit_id()` as that will load the *saved* inner circuit VK.
-    let inner_circuit_id = <N as Network>::inner_circuit_id_crh()
-        .hash_bits(&inner_verifying_key.to_minimal_bits())
+    let inner_circuit_id = N::inner_circuit_id_crh()
+        .hash_bits(&inner_verifying_key.to_minimal_bits_v1())
         .unwrap()
         .into();
 
-    let inner_proof = <N as Network>::InnerSNARK::prove(&inner_proving_key, &inner_circuit, rng).unwrap();
+    let inner_proof = N::InnerSNARK::prove(&inner_proving_key, &inner_circuit, rng).unwrap();
     assert_eq!(N::INNER_PROOF_SIZE_IN_BYTES, inner_proof.to_bytes_le().unwrap().len());
 
     // Verify that the inner circuit proof passes.
-    assert!(<N as Network>::InnerSNARK::verify(&inner_verifying_key, &inner_public, &inner_proof).unwrap());
+    assert!(N::InnerSNARK::verify(&inner_verifying_key, &inner_public, &inner_proof).unwrap());
+
+    // Verify that the inner circuit proof fails on mutated public variables.
+    let mutate_inner_public = |transition_id: N::TransitionID,
+                               value_balance: AleoAmount,
+                               ledger_root: N::LedgerRoot,
+                               local_transitions_root: N::TransactionID| {
+        InnerPublicVariables::<N>::new(
+            transition_id,
+            value_balance,
+            ledger_root,
+            local_transitions_root,
+            LocktimeCutoff::default(),
+            None,
+            Some(program_id),
+            Some(payload_commitments.clone()),
+        )
+    };
+    let mutated_inner_publics = vec![
+        mutate_inner_public(Default::default(), value_balance, ledger_root, local_transitions_root),
+        mutate_inner_public(
+            transition_id,
+            value_balance.add(AleoAmount::from_i64(1)),
+            ledger_root,
+            local_transitions_root,
+        ),
+        mutate_inner_public(transition_id, value_balance, Default::default(), local_transitions_root),
+        mutate_inner_public(transition_id, value_balance, ledger_root, Default::default()),
+    ];
+    for mutated_inner_public in &mutated_inner_publics {
+        assert!(!N::InnerSNARK::verify(&inner_verifying_key, mutated_inner_public, &inner_proof).unwrap());
+    }
 
     //////////////////////////////////////////////////////////////////////////
 
     // Construct the outer circuit public and private variables.
-    let outer_public = OuterPublicVariables::new(inner_public, &inner_circuit_id);
+    let outer_public = OuterPublicVariables::new(inner_public.clone(), &inner_circuit_id);
     let outer_private = OuterPrivateVariables::new(inner_verifying_key, inner_proof.into(), execution);
 
     // Check that the proof check constraint system was satisfied.
@@ -157,6 +226,16 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
     println!("=========================================================");
 
     assert!(outer_cs.is_satisfied());
+    check_mutations("Outer", &outer_cs, &[]);
+    assert!(debug_outer_circuit(&outer_public, &outer_private).is_ok());
+
+    // Check that the debug mode of the outer circuit names the sub-check that fails.
+    let invalid_outer_public = OuterPublicVariables::new(inner_public, &Default::default());
+    let failure = debug_outer_circuit(&invalid_outer_public, &outer_private).unwrap_err();
+    assert_eq!(Some(OuterCircuitCheck::InnerCircuitID), failure.check);
+    assert_eq!("given inner circuit ID", failure.values[0].0);
+    assert_eq!("candidate inner circuit ID", failure.values[1].0);
+    assert_eq!(inner_circuit_id.to_string(), failure.values[1].1);
 
     //////////////////////////////////////////////////////////////////////////
 
@@ -164,19 +243,26 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
 
     // Generate outer circuit parameters and proof.
     let (outer_proving_key, outer_verifying_key) =
-        <N as Network>::OuterSNARK::setup(&outer_circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+        N::OuterSNARK::setup(&outer_circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
 
     // // NOTE: Do not change this to `N::inner_circuit_id()` as that will load the *saved* inner circuit VK.
-    // let inner_circuit_id = <N as Network>::inner_circuit_id_crh()
+    // let inner_circuit_id = N::inner_circuit_id_crh()
     //     .hash_bits(&outer_verifying_key.to_minimal_bits())
     //     .unwrap()
     //     .into();
 
-    let outer_proof = <N as Network>::OuterSNARK::prove(&outer_proving_key, &outer_circuit, rng).unwrap();
+    let outer_proof = N::OuterSNARK::prove(&outer_proving_key, &outer_circuit, rng).unwrap();
     assert_eq!(N::OUTER_PROOF_SIZE_IN_BYTES, outer_proof.to_bytes_le().unwrap().len());
 
     // Verify that the outer circuit proof passes.
-    assert!(<N as Network>::OuterSNARK::verify(&outer_verifying_key, &outer_public, &outer_proof).unwrap());
+    assert!(N::OuterSNARK::verify(&outer_verifying_key, &outer_public, &outer_proof).unwrap());
+
+    // Verify that the outer circuit proof fails on mutated public variables.
+    for mutated_inner_public in mutated_inner_publics {
+        let mutated_outer_public = OuterPublicVariables::new(mutated_inner_public, &inner_circuit_id);
+        assert!(!N::OuterSNARK::verify(&outer_verifying_key, &mutated_outer_public, &outer_proof).unwrap());
+    }
+    assert!(!N::OuterSNARK::verify(&outer_verifying_key, &invalid_outer_public, &outer_proof).unwrap());
 }
 
 mod testnet1 {
@@ -198,3 +284,195 @@ mod testnet2 {
         dpc_execute_circuits_test::<Testnet2>(253822, 242379);
     }
 }
+
+mod testnet2_mini {
+    use super::*;
+    use crate::testnet2_mini::*;
+
+    /// Returns `true` if the inner circuit is satisfied for a coinbase of the given amount,
+    /// the program fee derived from the address of the coinbase recipient,
+    /// and the payload commitments of the records of the coinbase, as modified by `mutate_payload_commitments`.
+    fn is_inner_circuit_satisfied(
+        amount: AleoAmount,
+        program_fee: impl FnOnce(Address<Testnet2Mini>) -> Option<ProgramFee<Testnet2Mini>>,
+        mutate_payload_commitments: impl FnOnce(&mut Vec<<Testnet2Mini as Network>::Commitment>),
+    ) -> bool {
+        let rng = &mut thread_rng();
+
+        let recipient = Account::<Testnet2Mini>::new(rng);
+        let request = Request::new_coinbase(recipient.address(), amount, false, rng).unwrap();
+        let response = ResponseBuilder::new()
+            .add_request(request.clone())
+            .add_output(Output::new(recipient.address(), amount, Default::default(), None).unwrap())
+            .build(rng)
+            .unwrap();
+
+        is_transition_satisfied(
+            &request,
+            &response,
+            LocktimeCutoff::default(),
+            program_fee(recipient.address()),
+            mutate_payload_commitments,
+        )
+    }
+
+    /// Returns `true` if the inner circuit is satisfied for the given request and response at the given
+    /// locktime cutoff, with the given program fee, and the payload commitments of the records of the transition,
+    /// as modified by `mutate_payload_commitments`.
+    fn is_transition_satisfied(
+        request: &Request<Testnet2Mini>,
+        response: &Response<Testnet2Mini>,
+        locktime_cutoff: LocktimeCutoff,
+        program_fee: Option<ProgramFee<Testnet2Mini>>,
+        mutate_payload_commitments: impl FnOnce(&mut Vec<<Testnet2Mini as Network>::Commitment>),
+    ) -> bool {
+        let serial_numbers = request.to_serial_numbers().unwrap();
+        let transition_id =
+            Transition::<Testnet2Mini>::compute_transition_id(&serial_numbers, &response.commitments()).unwrap();
+
+        let mut payload_commitments = request
+            .records()
+            .iter()
+            .chain(response.records().iter())
+            .take(Testnet2Mini::NUM_PAYLOAD_COMMITMENTS)
+            .map(|record| record.to_payload_commitment().unwrap())
+            .collect::<Vec<_>>();
+        mutate_payload_commitments(&mut payload_commitments);
+
+        let inner_public = InnerPublicVariables::new(
+            transition_id,
+            request.fee(),
+            LedgerTree::<Testnet2Mini>::new().unwrap().root(),
+            Transitions::<Testnet2Mini>::new().unwrap().root(),
+            locktime_cutoff,
+            program_fee,
+            Some(request.to_program_id().unwrap()),
+            Some(payload_commitments),
+        );
+        let inner_private = InnerPrivateVariables::new(request, response).unwrap();
+
+        let mut inner_cs = TestConstraintSystem::<<Testnet2Mini as Network>::InnerScalarField>::new();
+        InnerCircuit::new(inner_public, inner_private)
+            .generate_constraints(&mut inner_cs.ns(|| "Inner circuit"))
+            .unwrap();
+        inner_cs.is_satisfied()
+    }
+
+    #[test]
+    fn test_inner_circuit_program_fee() {
+        let rng = &mut thread_rng();
+        let amount = AleoAmount::from_i64(10);
+
+        // The inner circuit is satisfied without a program fee, and with a program fee paid by an output record.
+        assert!(is_inner_circuit_satisfied(amount, |_| None, |_| ()));
+        assert!(is_inner_circuit_satisfied(
+            amount,
+            |recipient| Some(ProgramFee::new(recipient, amount)),
+            |_| ()
+        ));
+
+        // The inner circuit is unsatisfied if no output record pays the owner the amount of the program fee.
+        let other = Account::<Testnet2Mini>::new(rng).address();
+        assert!(!is_inner_circuit_satisfied(
+            amount,
+            |recipient| Some(ProgramFee::new(recipient, amount.add(amount))),
+            |_| ()
+        ));
+        assert!(!is_inner_circuit_satisfied(
+            amount,
+            |_| Some(ProgramFee::new(other, amount)),
+            |_| ()
+        ));
+    }
+
+    #[test]
+    fn test_inner_circuit_payload_commitments() {
+        let amount = AleoAmount::from_i64(10);
+        assert!(Testnet2Mini::NUM_PAYLOAD_COMMITMENTS > Testnet2Mini::NUM_INPUT_RECORDS);
+
+        // The inner circuit is satisfied with the payload commitments of the input records and the first output record.
+        assert!(is_inner_circuit_satisfied(amount, |_| None, |_| ()));
+
+        // The inner circuit is unsatisfied if a payload commitment does not match its record.
+        let output_index = Testnet2Mini::NUM_INPUT_RECORDS;
+        assert!(!is_inner_circuit_satisfied(
+            amount,
+            |_| None,
+            |payload_commitments| payload_commitments[output_index] = Default::default()
+        ));
+        assert!(!is_inner_circuit_satisfied(
+            amount,
+            |_| None,
+            |payload_commitments| payload_commitments.swap(0, output_index)
+        ));
+    }
+
+    #[test]
+    fn test_inner_circuit_locktime() {
+        let rng = &mut thread_rng();
+        assert!(Testnet2Mini::RECORD_LOCKTIMES_ENABLED);
+
+        // Craft a noop request, whose input records may not be consumed before block 10.
+        let caller = PrivateKey::<Testnet2Mini>::new(rng);
+        let records = (0..Testnet2Mini::NUM_INPUT_RECORDS)
+            .map(|_| {
+                Record::new_with_locktime(
+                    caller.to_address(),
+                    AleoAmount::ZERO,
+                    Default::default(),
+                    *Testnet2Mini::noop_program_id(),
+                    Some(Locktime::BlockHeight(10)),
+                    rng,
+                )
+                .unwrap()
+            })
+            .collect();
+        let ledger_proofs = vec![LedgerProof::default(); Testnet2Mini::NUM_INPUT_RECORDS];
+        let request = Request::new(
+            &caller,
+            records,
+            ledger_proofs,
+            Operation::Noop,
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+        let response = ResponseBuilder::new().add_request(request.clone()).build(rng).unwrap();
+        let is_satisfied_at = |block_height| {
+            is_transition_satisfied(&request, &response, LocktimeCutoff::new(block_height, 0), None, |_| ())
+        };
+
+        // The inner circuit is satisfied at or after the locktime of the input records.
+        assert!(is_satisfied_at(10));
+        assert!(is_satisfied_at(11));
+
+        // The inner circuit is unsatisfied before the locktime of the input records.
+        assert!(!is_satisfied_at(9));
+    }
+
+    #[test]
+    fn test_function_id_commits_to_program_fee() {
+        let rng = &mut thread_rng();
+        let verifying_key = Testnet2Mini::noop_circuit_verifying_key();
+        let program_fee = ProgramFee::new(Account::<Testnet2Mini>::new(rng).address(), AleoAmount::from_i64(1));
+
+        let function_id = Testnet2Mini::function_id(verifying_key).unwrap();
+        assert_eq!(*Testnet2Mini::noop_function_id(), function_id);
+        assert_ne!(
+            function_id,
+            Testnet2Mini::function_id_with_program_fee(verifying_key, Some(&program_fee)).unwrap()
+        );
+
+        // Networks without program fees reject functions that declare a program fee.
+        let program_fee = ProgramFee::new(
+            Account::<crate::testnet2::Testnet2>::new(rng).address(),
+            AleoAmount::ZERO,
+        );
+        assert!(crate::testnet2::Testnet2::function_id_with_program_fee(
+            crate::testnet2::Testnet2::noop_circuit_verifying_key(),
+            Some(&program_fee)
+        )
+        .is_err());
+    }
+}
@@ -30,17 +30,35 @@ pub enum Bech32mError {
     #[error("invalid byte length: {}", _0)]
     InvalidByteLength(usize),
 
-    #[error("invalid character length: {}", _0)]
-    InvalidCharacterLength(usize),
+    #[error("invalid character {:?} at index {}", _0, _1)]
+    InvalidCharacter(char, usize),
 
-    #[error("invalid prefix: {:?}", _0)]
-    InvalidPrefix(String),
+    #[error("invalid character length: expected {}, found {}", _0, _1)]
+    InvalidCharacterLength(usize, usize),
+
+    #[error("invalid padding")]
+    InvalidPadding,
+
+    #[error("invalid checksum")]
+    InvalidChecksum,
+
+    #[error("invalid checksum, check for typos in the characters at indices {:?}", _0)]
+    InvalidChecksumAt(Vec<usize>),
+
+    #[error("invalid prefix: expected {:?}, found {:?}", _0, _1)]
+    InvalidPrefix(String, String),
 
     #[error("invalid variant")]
     InvalidVariant,
 
     #[error("{}", _0)]
     Message(String),
+
+    #[error("missing separator")]
+    MissingSeparator,
+
+    #[error("mixed case")]
+    MixedCase,
 }
 
 impl From<std::io::Error> for Bech32mError {
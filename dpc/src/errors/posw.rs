@@ -44,6 +44,14 @@ pub enum PoSWError {
     /// Thrown when there's an internal error in the underlying SNARK
     #[error(transparent)]
     SNARKError(#[from] SNARKError),
+
+    /// Thrown when no valid proof is found before the mining deadline
+    #[error("failed to mine a block before the deadline")]
+    Timeout,
+
+    /// Thrown when no PoSW algorithm is registered for the PoSW version of a block
+    #[error("no PoSW algorithm is registered for {} proofs", _0)]
+    UnregisteredVersion(crate::PoSWVersion),
 }
 
 impl From<std::io::Error> for PoSWError {
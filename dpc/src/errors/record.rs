@@ -45,6 +45,9 @@ pub enum RecordError {
     #[error("Invalid commitment. Expected {}, found {}", _0, _1)]
     InvalidCommitment(String, String),
 
+    #[error("Record locktimes are not enabled on this network")]
+    LocktimesDisabled,
+
     #[error("{}", _0)]
     PRFError(#[from] PRFError),
 
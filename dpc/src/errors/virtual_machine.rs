@@ -70,6 +70,9 @@ pub enum VMError {
     #[error("{}", _0)]
     MerkleError(#[from] MerkleError),
 
+    #[error("{}", _0)]
+    Message(String),
+
     #[error("Missing caller {}", _0)]
     MissingCaller(String),
 
@@ -79,11 +82,23 @@ pub enum VMError {
     #[error("{}", _0)]
     PRFError(#[from] PRFError),
 
+    #[error("Insufficient fee to pay the program fee")]
+    ProgramFeeInsufficient,
+
+    #[error("Program fees are not enabled in this network")]
+    ProgramFeesDisabled,
+
+    #[error("Input record {} is locked at the locktime cutoff", _0)]
+    RecordLocked(String),
+
     #[error("{}", _0)]
     SignatureError(#[from] SignatureError),
 
     #[error("{}", _0)]
     SNARKError(#[from] SNARKError),
+
+    #[error("The function may not be upgraded, as {}", _0)]
+    UpgradeNotPermitted(String),
 }
 
 impl From<std::io::Error> for VMError {
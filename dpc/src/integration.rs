@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! A harness of integration tests that exercise a network end to end, from accounts and records
+//! to transactions, blocks, and the ledger.
+//!
+//! The tests are generic over the network, and `network_integration_tests!` declares each of them
+//! for a given network, so that every network shipped by the crate is covered by the same suite.
+//!
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use chrono::Utc;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::{str::FromStr, sync::atomic::AtomicBool};
+
+/// The seed of the random number generator, which makes a failing test reproducible.
+const TEST_SEED: u64 = 1231275789u64;
+
+/// Declares a module with the given name, containing the integration tests for the given network.
+/// The network type is resolved in the scope that invokes the macro.
+#[macro_export]
+macro_rules! network_integration_tests {
+    ($module:ident, $network:ty) => {
+        mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn test_account_round_trip() {
+                $crate::integration::test_account_round_trip::<$network>();
+            }
+
+            #[test]
+            fn test_record_round_trip() {
+                $crate::integration::test_record_round_trip::<$network>();
+            }
+
+            #[test]
+            fn test_transaction_round_trip() {
+                $crate::integration::test_transaction_round_trip::<$network>();
+            }
+
+            #[test]
+            fn test_block_round_trip() {
+                $crate::integration::test_block_round_trip::<$network>();
+            }
+
+            #[test]
+            fn test_ledger_round_trip() {
+                $crate::integration::test_ledger_round_trip::<$network>();
+            }
+        }
+    };
+}
+
+/// Returns the seeded random number generator for a test.
+fn test_rng() -> ChaChaRng {
+    ChaChaRng::seed_from_u64(TEST_SEED)
+}
+
+/// Checks that an account and its keys round-trip through their string encodings.
+pub fn test_account_round_trip<N: Network>() {
+    let account = Account::<N>::new(&mut test_rng());
+
+    let private_key = PrivateKey::<N>::from_str(&account.private_key().to_string()).unwrap();
+    assert_eq!(account.private_key(), &private_key);
+
+    let view_key = ViewKey::<N>::from_str(&account.view_key().to_string()).unwrap();
+    assert_eq!(account.view_key(), &view_key);
+    assert_eq!(ViewKey::from_private_key(&private_key), view_key);
+
+    let address = Address::<N>::from_str(&account.address().to_string()).unwrap();
+    assert_eq!(account.address(), address);
+    assert_eq!(Address::from_view_key(&view_key), address);
+}
+
+/// Checks that a record round-trips through bytes, JSON, and its ciphertext.
+pub fn test_record_round_trip<N: Network>() {
+    let rng = &mut test_rng();
+    let account = Account::<N>::new(rng);
+
+    let payload: Vec<u8> = (0..N::RECORD_PAYLOAD_SIZE_IN_BYTES).map(|_| rng.gen()).collect();
+    let record = Record::new(
+        account.address(),
+        AleoAmount::from_i64(1234),
+        Payload::from_bytes_le(&payload).unwrap(),
+        *N::noop_program_id(),
+        rng,
+    )
+    .unwrap();
+    assert!(record.is_well_formed());
+
+    assert_eq!(record, Record::read_le(&record.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(record, Record::from_str(&record.to_string()).unwrap());
+
+    let decryption_key = DecryptionKey::from(account.view_key());
+    assert_eq!(record, Record::decrypt(&decryption_key, record.ciphertext()).unwrap());
+}
+
+/// Checks that a coinbase transaction is valid, round-trips through bytes and JSON,
+/// and reveals its record to the recipient.
+pub fn test_transaction_round_trip<N: Network>() {
+    let rng = &mut test_rng();
+    let recipient = Account::<N>::new(rng);
+    let amount = Block::<N>::block_reward(1);
+
+    let (transaction, record) = Transaction::<N>::new_coinbase(recipient.address(), amount, true, rng).unwrap();
+    assert!(transaction.is_valid());
+
+    assert_eq!(
+        transaction,
+        Transaction::read_le(&transaction.to_bytes_le().unwrap()[..]).unwrap()
+    );
+    assert_eq!(
+        transaction,
+        serde_json::from_str(&serde_json::to_string(&transaction).unwrap()).unwrap()
+    );
+
+    let decryption_key = DecryptionKey::from(recipient.view_key());
+    let decrypted_records: Vec<_> = transaction.to_decrypted_records(&decryption_key).collect();
+    assert_eq!(vec![record.clone()], decrypted_records);
+    assert_eq!(recipient.address(), record.owner());
+    assert_eq!(amount, record.value());
+
+    // The coinbase transaction is public, so the record is also revealed to everyone.
+    assert_eq!(vec![record], transaction.to_records().collect::<Vec<_>>());
+}
+
+/// Checks that the genesis block is valid, and round-trips through bytes and JSON.
+pub fn test_block_round_trip<N: Network>() {
+    let block = N::genesis_block();
+    assert!(block.is_valid());
+    assert_eq!(0, block.height());
+
+    assert_eq!(block, &Block::read_le(&block.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(block, &Block::from_str(&block.to_string()).unwrap());
+    assert_eq!(
+        block.to_coinbase_transaction().unwrap(),
+        block.transactions().to_coinbase_transaction().unwrap()
+    );
+}
+
+/// Checks that a ledger starts at the genesis block, and accepts a mined block that extends it.
+pub fn test_ledger_round_trip<N: Network>() {
+    let rng = &mut test_rng();
+
+    let mut ledger = Ledger::<N>::new().unwrap();
+    assert_eq!(ledger.latest_block_height(), 0);
+    assert_eq!(ledger.latest_block_hash(), N::genesis_block().hash());
+    assert_eq!(&ledger.latest_block().unwrap(), N::genesis_block());
+    assert_eq!((*ledger.latest_block_transactions().unwrap()).len(), 1);
+
+    // Construct the previous block hash and new block height.
+    let previous_block = ledger.latest_block().unwrap();
+    let block_height = previous_block.height() + 1;
+
+    // Construct the new block transactions.
+    let recipient = Account::<N>::new(rng);
+    let amount = Block::<N>::block_reward(block_height);
+    let (coinbase_transaction, coinbase_record) =
+        Transaction::<N>::new_coinbase(recipient.address(), amount, true, rng).unwrap();
+    let transactions = Transactions::from(&[coinbase_transaction]).unwrap();
+
+    let timestamp = std::cmp::max(Utc::now().timestamp(), previous_block.timestamp() + 1);
+    let difficulty_target = Blocks::<N>::compute_difficulty_target(previous_block.header(), timestamp, block_height);
+    let cumulative_weight = previous_block
+        .cumulative_weight()
+        .saturating_add((u64::MAX / difficulty_target) as u128);
+
+    // Construct the block template, and mine the new block.
+    let template = BlockTemplate::new(
+        previous_block.hash(),
+        block_height,
+        timestamp,
+        difficulty_target,
+        cumulative_weight,
+        ledger.latest_ledger_root(),
+        transactions,
+        coinbase_record,
+    );
+    let block = Block::mine(&template, &AtomicBool::new(false), rng).unwrap();
+    assert_eq!(block, Block::read_le(&block.to_bytes_le().unwrap()[..]).unwrap());
+
+    ledger.add_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_block_height(), 1);
+    assert_eq!(ledger.latest_block_hash(), block.hash());
+
+    // Ensure the ledger rejects the same block twice.
+    assert!(ledger.add_next_block(&block).is_err());
+}
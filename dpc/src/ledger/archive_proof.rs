@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_algorithms::{merkle_tree::MerklePath, prelude::*};
+use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// A proof that a transaction is in a block of the ledger with the given ledger root.
+///
+/// The proof only commits to the block headers, through the ledger root and the block header root,
+/// so it remains verifiable by a node that has pruned the transactions of the block.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct ArchiveProof<N: Network> {
+    ledger_root: N::LedgerRoot,
+    ledger_root_inclusion_proof: MerklePath<N::LedgerRootParameters>,
+    block_hash: N::BlockHash,
+    previous_block_hash: N::BlockHash,
+    block_header_root: N::BlockHeaderRoot,
+    block_header_inclusion_proof: MerklePath<N::BlockHeaderRootParameters>,
+    transactions_root: N::TransactionsRoot,
+    transactions_inclusion_proof: MerklePath<N::TransactionsRootParameters>,
+    transaction_id: N::TransactionID,
+}
+
+impl<N: Network> ArchiveProof<N> {
+    ///
+    /// Initializes a new instance of `ArchiveProof`.
+    ///
+    pub fn new(
+        ledger_root: N::LedgerRoot,
+        ledger_root_inclusion_proof: MerklePath<N::LedgerRootParameters>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        block_header_root: N::BlockHeaderRoot,
+        block_header_inclusion_proof: MerklePath<N::BlockHeaderRootParameters>,
+        transactions_root: N::TransactionsRoot,
+        transactions_inclusion_proof: MerklePath<N::TransactionsRootParameters>,
+        transaction_id: N::TransactionID,
+    ) -> Result<Self> {
+        // Ensure the transactions inclusion proof is valid.
+        if !transactions_inclusion_proof.verify(&transactions_root, &transaction_id)? {
+            return Err(anyhow!(
+                "Transaction {} does not belong to transactions root {}",
+                transaction_id,
+                transactions_root
+            ));
+        }
+
+        // Ensure the block header inclusion proof is valid.
+        if !block_header_inclusion_proof.verify(&block_header_root, &transactions_root)? {
+            return Err(anyhow!(
+                "Transactions root {} does not belong to block header {}",
+                transactions_root,
+                block_header_root
+            ));
+        }
+
+        // Ensure the block hash is valid.
+        let candidate_block_hash: N::BlockHash = N::block_hash_crh()
+            .hash(&to_bytes_le![previous_block_hash, block_header_root]?)?
+            .into();
+        if candidate_block_hash != block_hash {
+            return Err(anyhow!(
+                "Candidate block hash {} does not match given block hash {}",
+                candidate_block_hash,
+                block_hash
+            ));
+        }
+
+        // Ensure the ledger root inclusion proof is valid.
+        if !ledger_root_inclusion_proof.verify(&ledger_root, &block_hash)? {
+            return Err(anyhow!(
+                "Block hash {} does not belong to ledger root {}",
+                block_hash,
+                ledger_root
+            ));
+        }
+
+        Ok(Self {
+            ledger_root,
+            ledger_root_inclusion_proof,
+            block_hash,
+            previous_block_hash,
+            block_header_root,
+            block_header_inclusion_proof,
+            transactions_root,
+            transactions_inclusion_proof,
+            transaction_id,
+        })
+    }
+
+    /// Returns the ledger root that the block of the transaction is proven to be in.
+    pub fn ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_root
+    }
+
+    /// Returns the ledger root inclusion proof.
+    pub fn ledger_root_inclusion_proof(&self) -> &MerklePath<N::LedgerRootParameters> {
+        &self.ledger_root_inclusion_proof
+    }
+
+    /// Returns the block hash.
+    pub fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub fn block_header_root(&self) -> N::BlockHeaderRoot {
+        self.block_header_root
+    }
+
+    /// Returns the block header inclusion proof.
+    pub fn block_header_inclusion_proof(&self) -> &MerklePath<N::BlockHeaderRootParameters> {
+        &self.block_header_inclusion_proof
+    }
+
+    /// Returns the transactions root.
+    pub fn transactions_root(&self) -> N::TransactionsRoot {
+        self.transactions_root
+    }
+
+    /// Returns the transactions inclusion proof.
+    pub fn transactions_inclusion_proof(&self) -> &MerklePath<N::TransactionsRootParameters> {
+        &self.transactions_inclusion_proof
+    }
+
+    /// Returns the transaction ID.
+    pub fn transaction_id(&self) -> N::TransactionID {
+        self.transaction_id
+    }
+}
+
+impl<N: Network> FromBytes for ArchiveProof<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let ledger_root = FromBytes::read_le(&mut reader)?;
+        let ledger_root_inclusion_proof = FromBytes::read_le(&mut reader)?;
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        let previous_block_hash = FromBytes::read_le(&mut reader)?;
+        let block_header_root = FromBytes::read_le(&mut reader)?;
+        let block_header_inclusion_proof = FromBytes::read_le(&mut reader)?;
+        let transactions_root = FromBytes::read_le(&mut reader)?;
+        let transactions_inclusion_proof = FromBytes::read_le(&mut reader)?;
+        let transaction_id = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self::new(
+            ledger_root,
+            ledger_root_inclusion_proof,
+            block_hash,
+            previous_block_hash,
+            block_header_root,
+            block_header_inclusion_proof,
+            transactions_root,
+            transactions_inclusion_proof,
+            transaction_id,
+        )
+        .map_err(BlockError::from)?)
+    }
+}
+
+impl<N: Network> ToBytes for ArchiveProof<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.ledger_root.write_le(&mut writer)?;
+        self.ledger_root_inclusion_proof.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.block_header_root.write_le(&mut writer)?;
+        self.block_header_inclusion_proof.write_le(&mut writer)?;
+        self.transactions_root.write_le(&mut writer)?;
+        self.transactions_inclusion_proof.write_le(&mut writer)?;
+        self.transaction_id.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_archive_proof() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        // Mine two blocks, so the archive proof is for a block before the latest block.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let block = ledger.latest_block().unwrap();
+        ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+
+        let transaction_id = block.to_coinbase_transaction().unwrap().transaction_id();
+        let archive_proof = ledger.archive_proof(transaction_id).unwrap();
+        assert_eq!(transaction_id, archive_proof.transaction_id());
+        assert_eq!(block.hash(), archive_proof.block_hash());
+        assert_eq!(ledger.latest_ledger_root(), archive_proof.ledger_root());
+        assert!(ledger.verify_archive_proof(&archive_proof));
+
+        // Ensure the archive proof round-trips through bytes.
+        let archive_proof_bytes = archive_proof.to_bytes_le().unwrap();
+        let candidate = ArchiveProof::<Testnet2>::read_le(&archive_proof_bytes[..]).unwrap();
+        assert_eq!(archive_proof_bytes, candidate.to_bytes_le().unwrap());
+
+        // Ensure an archive proof cannot be created for a different transaction.
+        assert!(ArchiveProof::<Testnet2>::new(
+            archive_proof.ledger_root(),
+            archive_proof.ledger_root_inclusion_proof().clone(),
+            archive_proof.block_hash(),
+            archive_proof.previous_block_hash(),
+            archive_proof.block_header_root(),
+            archive_proof.block_header_inclusion_proof().clone(),
+            archive_proof.transactions_root(),
+            archive_proof.transactions_inclusion_proof().clone(),
+            Default::default(),
+        )
+        .is_err());
+
+        // Ensure an archive proof is not created for an unknown transaction.
+        assert!(ledger.archive_proof(Default::default()).is_err());
+    }
+}
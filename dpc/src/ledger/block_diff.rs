@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use std::collections::HashMap;
+
+/// A block diff contains all state changes implied by adding a block to the ledger.
+/// It is computed from a validated block with `Blocks::to_block_diff`, applied to the canon chain
+/// with `Blocks::add_next`, and rolled back with `Blocks::revert`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockDiff<N: Network> {
+    /// The height of the block.
+    height: u32,
+    /// The hash of the block, which is inserted into the ledger tree.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_block_hash: N::BlockHash,
+    /// The header of the block.
+    header: BlockHeader<N>,
+    /// The transactions of the block.
+    transactions: Transactions<N>,
+    /// The serial numbers introduced by the block.
+    serial_numbers: Vec<N::SerialNumber>,
+    /// The commitments introduced by the block.
+    commitments: Vec<N::Commitment>,
+    /// The migrated ledger roots of the chain before the block, which are restored when the block is reverted.
+    previous_migrated_ledger_roots: HashMap<N::LedgerRoot, (N::LedgerRoot, usize)>,
+}
+
+impl<N: Network> BlockDiff<N> {
+    /// Returns the block diff for the given block, without validating the block.
+    /// Use `Blocks::to_block_diff` to compute the block diff of a valid next block.
+    pub(crate) fn new(block: &Block<N>) -> Self {
+        Self {
+            height: block.height(),
+            block_hash: block.hash(),
+            previous_block_hash: block.previous_block_hash(),
+            header: block.header().clone(),
+            transactions: block.transactions().clone(),
+            serial_numbers: block.serial_numbers().cloned().collect(),
+            commitments: block.commitments().cloned().collect(),
+            previous_migrated_ledger_roots: Default::default(),
+        }
+    }
+
+    /// Sets the migrated ledger roots of the chain before the block.
+    pub(crate) fn with_previous_migrated_ledger_roots(
+        mut self,
+        previous_migrated_ledger_roots: HashMap<N::LedgerRoot, (N::LedgerRoot, usize)>,
+    ) -> Self {
+        self.previous_migrated_ledger_roots = previous_migrated_ledger_roots;
+        self
+    }
+
+    /// Returns the block height.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash.
+    pub fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header.
+    pub fn header(&self) -> &BlockHeader<N> {
+        &self.header
+    }
+
+    /// Returns the block transactions.
+    pub fn transactions(&self) -> &Transactions<N> {
+        &self.transactions
+    }
+
+    /// Returns the serial numbers introduced by the block.
+    pub fn serial_numbers(&self) -> &[N::SerialNumber] {
+        &self.serial_numbers
+    }
+
+    /// Returns the commitments introduced by the block.
+    pub fn commitments(&self) -> &[N::Commitment] {
+        &self.commitments
+    }
+
+    /// Returns the migrated ledger roots of the chain before the block.
+    pub fn previous_migrated_ledger_roots(&self) -> &HashMap<N::LedgerRoot, (N::LedgerRoot, usize)> {
+        &self.previous_migrated_ledger_roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_apply_and_revert() {
+        let rng = &mut thread_rng();
+
+        // Mine the next block on a separate ledger.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let block = ledger.latest_block().unwrap();
+
+        let mut blocks = Blocks::<Testnet2>::new().unwrap();
+        let genesis_block = blocks.latest_block().unwrap();
+        let genesis_ledger_root = blocks.latest_ledger_root();
+
+        // Apply the block diff.
+        let diff = blocks.to_block_diff(&block).unwrap();
+        assert_eq!(diff, BlockDiff::new(&block));
+        blocks.apply(&diff).unwrap();
+        assert_eq!(1, blocks.latest_block_height());
+        assert_eq!(block, blocks.latest_block().unwrap());
+        assert_eq!(ledger.latest_ledger_root(), blocks.latest_ledger_root());
+        assert!(diff.commitments().iter().all(|c| blocks.contains_commitment(c)));
+
+        // Ensure the same diff cannot be applied twice.
+        assert!(blocks.apply(&diff).is_err());
+
+        // Revert the block diff.
+        blocks.revert(&diff).unwrap();
+        assert_eq!(0, blocks.latest_block_height());
+        assert_eq!(genesis_block, blocks.latest_block().unwrap());
+        assert_eq!(genesis_ledger_root, blocks.latest_ledger_root());
+        assert!(!blocks.contains_height(1));
+        assert!(!blocks.contains_block_hash(&block.hash()));
+        assert!(!diff.commitments().iter().any(|c| blocks.contains_commitment(c)));
+
+        // Ensure the genesis block cannot be reverted.
+        assert!(blocks.revert(&BlockDiff::new(&genesis_block)).is_err());
+
+        // Ensure the block can be added again after it is reverted.
+        blocks.add_next(&block).unwrap();
+        assert_eq!(ledger.latest_ledger_root(), blocks.latest_ledger_root());
+    }
+}
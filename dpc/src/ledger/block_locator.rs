@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+
+/// The maximum number of consecutive block locators, counting down from the latest block.
+pub const MAXIMUM_LINEAR_BLOCK_LOCATORS: u32 = 64;
+/// The maximum number of exponentially-spaced block locators, below the consecutive block locators.
+pub const MAXIMUM_QUADRATIC_BLOCK_LOCATORS: u32 = 32;
+/// The maximum number of block locators, including the genesis block.
+pub const MAXIMUM_BLOCK_LOCATORS: u32 = MAXIMUM_LINEAR_BLOCK_LOCATORS + MAXIMUM_QUADRATIC_BLOCK_LOCATORS + 1;
+
+///
+/// A block locator summarizes a chain with the block hashes of the latest blocks,
+/// followed by block hashes at exponentially-increasing distances, down to the genesis block.
+///
+/// A peer that receives a block locator finds the latest common ancestor of the two chains
+/// with `Blocks::find_common_ancestor`, and syncs the blocks that follow it.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockLocator<N: Network> {
+    /// The block hashes of the locator, keyed by block height.
+    locators: BTreeMap<u32, N::BlockHash>,
+}
+
+impl<N: Network> BlockLocator<N> {
+    /// Returns the block locator of the given chain of blocks.
+    pub fn new(blocks: &Blocks<N>) -> Result<Self> {
+        let locators = Self::locator_heights(blocks.latest_block_height())
+            .into_iter()
+            .map(|height| Ok((height, blocks.get_block_hash(height)?)))
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        Ok(Self { locators })
+    }
+
+    /// Returns a block locator from the given pairs of block heights and block hashes.
+    pub fn from_locators(locators: &[(u32, N::BlockHash)]) -> Result<Self> {
+        let locator = Self {
+            locators: locators.iter().copied().collect(),
+        };
+
+        match locator.locators.len() == locators.len() && locator.is_valid() {
+            true => Ok(locator),
+            false => Err(anyhow!("Invalid block locator")),
+        }
+    }
+
+    /// Returns the block heights of a block locator for a chain with the given latest block height.
+    pub fn locator_heights(latest_block_height: u32) -> Vec<u32> {
+        // Add the consecutive block heights, counting down from the latest block height.
+        let num_linear = std::cmp::min(latest_block_height, MAXIMUM_LINEAR_BLOCK_LOCATORS - 1) + 1;
+        let mut heights = (0..num_linear).map(|i| latest_block_height - i).collect::<Vec<_>>();
+
+        // Add the exponentially-spaced block heights, down to the genesis block.
+        let mut height = latest_block_height - (num_linear - 1);
+        let mut step = 2u32;
+        while height > 0 && heights.len() < (MAXIMUM_BLOCK_LOCATORS - 1) as usize {
+            height = height.saturating_sub(step);
+            step = step.saturating_mul(2);
+            heights.push(height);
+        }
+
+        // Ensure the genesis block height is included.
+        if heights.last() != Some(&0) {
+            heights.push(0);
+        }
+
+        heights
+    }
+
+    /// Returns the latest block height in the block locator.
+    pub fn latest_block_height(&self) -> u32 {
+        self.locators.keys().next_back().copied().unwrap_or_default()
+    }
+
+    /// Returns the block hash of the given block height, if it is in the block locator.
+    pub fn get_block_hash(&self, height: u32) -> Option<N::BlockHash> {
+        self.locators.get(&height).copied()
+    }
+
+    /// Returns the number of block locators.
+    pub fn len(&self) -> usize {
+        self.locators.len()
+    }
+
+    /// Returns `true` if there are no block locators.
+    pub fn is_empty(&self) -> bool {
+        self.locators.is_empty()
+    }
+
+    /// Returns an iterator over the block heights and block hashes, from the latest block height.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, N::BlockHash)> + '_ {
+        self.locators
+            .iter()
+            .rev()
+            .map(|(height, block_hash)| (*height, *block_hash))
+    }
+
+    /// Returns `true` if the block locator is well-formed.
+    pub fn is_valid(&self) -> bool {
+        // Ensure the number of block locators is within the limit.
+        if self.locators.len() > MAXIMUM_BLOCK_LOCATORS as usize {
+            eprintln!("Block locator exceeds the maximum number of locators");
+            return false;
+        }
+
+        // Ensure the block locator starts at the genesis block.
+        match self.locators.get(&0) {
+            Some(block_hash) if *block_hash == N::genesis_block().hash() => (),
+            _ => {
+                eprintln!("Block locator does not contain the genesis block");
+                return false;
+            }
+        }
+
+        // Ensure the latest block heights are consecutive.
+        let latest_block_height = self.latest_block_height();
+        let num_linear = std::cmp::min(latest_block_height, MAXIMUM_LINEAR_BLOCK_LOCATORS - 1) + 1;
+        if !(0..num_linear).all(|i| self.locators.contains_key(&(latest_block_height - i))) {
+            eprintln!("Block locator is missing the latest consecutive block heights");
+            return false;
+        }
+
+        true
+    }
+}
+
+impl<N: Network> FromBytes for BlockLocator<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_locators: u32 = FromBytes::read_le(&mut reader)?;
+        if num_locators > MAXIMUM_BLOCK_LOCATORS {
+            return Err(error("Block locator exceeds the maximum number of locators"));
+        }
+
+        let mut locators = Vec::with_capacity(num_locators as usize);
+        for _ in 0..num_locators {
+            let height: u32 = FromBytes::read_le(&mut reader)?;
+            let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+            locators.push((height, block_hash));
+        }
+
+        Self::from_locators(&locators).map_err(|_| error("Invalid block locator"))
+    }
+}
+
+impl<N: Network> ToBytes for BlockLocator<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.locators.len() as u32).write_le(&mut writer)?;
+        for (height, block_hash) in &self.locators {
+            height.write_le(&mut writer)?;
+            block_hash.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_locator_heights() {
+        assert_eq!(vec![0], BlockLocator::<Testnet2>::locator_heights(0));
+        assert_eq!(vec![2, 1, 0], BlockLocator::<Testnet2>::locator_heights(2));
+
+        // Ensure the heights are consecutive, then exponentially spaced, down to the genesis block.
+        let heights = BlockLocator::<Testnet2>::locator_heights(1000);
+        assert_eq!(MAXIMUM_LINEAR_BLOCK_LOCATORS as usize + 9, heights.len());
+        assert_eq!(1000, heights[0]);
+        assert_eq!(937, heights[MAXIMUM_LINEAR_BLOCK_LOCATORS as usize - 1]);
+        assert_eq!(935, heights[MAXIMUM_LINEAR_BLOCK_LOCATORS as usize]);
+        assert_eq!(931, heights[MAXIMUM_LINEAR_BLOCK_LOCATORS as usize + 1]);
+        assert_eq!(Some(&0), heights.last());
+        assert!(heights.windows(2).all(|pair| pair[0] > pair[1]));
+
+        // Ensure the number of heights is bounded.
+        let heights = BlockLocator::<Testnet2>::locator_heights(u32::MAX);
+        assert!(heights.len() <= MAXIMUM_BLOCK_LOCATORS as usize);
+        assert_eq!(Some(&0), heights.last());
+    }
+
+    #[test]
+    fn test_block_locator() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        for _ in 0..2 {
+            ledger
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .unwrap();
+        }
+
+        let locator = ledger.get_block_locator().unwrap();
+        assert!(locator.is_valid());
+        assert_eq!(3, locator.len());
+        assert_eq!(2, locator.latest_block_height());
+        assert_eq!(Some(ledger.latest_block_hash()), locator.get_block_hash(2));
+
+        // Ensure the block locator round-trips through bytes.
+        let locator_bytes = locator.to_bytes_le().unwrap();
+        assert_eq!(locator, BlockLocator::read_le(&locator_bytes[..]).unwrap());
+
+        // Ensure the common ancestor is the latest block shared by both chains.
+        let genesis_ledger = Ledger::<Testnet2>::new().unwrap();
+        let genesis_locator = genesis_ledger.get_block_locator().unwrap();
+        let genesis_hash = Testnet2::genesis_block().hash();
+        assert_eq!(
+            (0, genesis_hash),
+            genesis_ledger.find_common_ancestor(&locator).unwrap()
+        );
+        assert_eq!(
+            (0, genesis_hash),
+            ledger.find_common_ancestor(&genesis_locator).unwrap()
+        );
+        assert_eq!(
+            (2, ledger.latest_block_hash()),
+            ledger.find_common_ancestor(&locator).unwrap()
+        );
+
+        // Ensure a block locator with a different genesis block is rejected.
+        let invalid_locators = [(0, UniformRand::rand(rng)), (1, UniformRand::rand(rng))];
+        assert!(BlockLocator::<Testnet2>::from_locators(&invalid_locators).is_err());
+
+        // Ensure a block locator with a gap in the latest block heights is rejected.
+        let invalid_locators = [(0, Testnet2::genesis_block().hash()), (2, UniformRand::rand(rng))];
+        assert!(BlockLocator::<Testnet2>::from_locators(&invalid_locators).is_err());
+    }
+}
@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::prelude::*;
+use crate::{prelude::*, DifficultyAnchor, DifficultyRetarget, LedgerRootWindow};
 use snarkvm_algorithms::merkle_tree::*;
+use snarkvm_utilities::biginteger::{BigInteger, BigInteger128};
 
 use anyhow::{anyhow, Result};
 use chrono::Utc;
@@ -30,12 +31,20 @@ pub struct Blocks<N: Network> {
     current_hash: N::BlockHash,
     /// The current ledger tree.
     ledger_tree: LedgerTree<N>,
+    /// The ledger tree of mature blocks, which lags the current ledger tree by `N::COINBASE_MATURITY` blocks.
+    mature_ledger_tree: LedgerTree<N>,
+    /// The block height of each block header, indexed by the previous ledger root in the block header.
+    ledger_root_heights: HashMap<N::LedgerRoot, u32>,
     /// The chain of previous block hashes.
     previous_hashes: HashMap<u32, N::BlockHash>,
     /// The chain of block headers.
     headers: HashMap<u32, BlockHeader<N>>,
     /// The chain of block transactions.
     transactions: HashMap<u32, Transactions<N>>,
+    /// The total supply after each block.
+    total_supplies: HashMap<u32, AleoAmount>,
+    /// The ledger roots migrated in a ledger tree transition, mapped to their ledger root and depth.
+    migrated_ledger_roots: HashMap<N::LedgerRoot, (N::LedgerRoot, usize)>,
 }
 
 impl<N: Network> Blocks<N> {
@@ -48,9 +57,13 @@ impl<N: Network> Blocks<N> {
             current_height: height,
             current_hash: genesis_block.hash(),
             ledger_tree: LedgerTree::<N>::new()?,
+            mature_ledger_tree: LedgerTree::<N>::new()?,
+            ledger_root_heights: Default::default(),
             previous_hashes: Default::default(),
             headers: Default::default(),
             transactions: Default::default(),
+            total_supplies: Default::default(),
+            migrated_ledger_roots: Default::default(),
         };
 
         blocks.ledger_tree.add(&genesis_block.hash())?;
@@ -58,7 +71,15 @@ impl<N: Network> Blocks<N> {
             .previous_hashes
             .insert(height, genesis_block.previous_block_hash());
         blocks.headers.insert(height, genesis_block.header().clone());
+        blocks
+            .ledger_root_heights
+            .insert(genesis_block.header().previous_ledger_root(), height);
         blocks.transactions.insert(height, genesis_block.transactions().clone());
+        blocks.add_mature_block(height)?;
+        blocks
+            .total_supplies
+            .insert(height, Self::issued_supply(genesis_block.transactions()));
+        blocks.update_migrated_ledger_roots()?;
 
         Ok(blocks)
     }
@@ -154,6 +175,87 @@ impl<N: Network> Blocks<N> {
         }
     }
 
+    ///
+    /// Returns the total supply after the block with the given height,
+    /// which is the sum of the supply issued by each block from the genesis block.
+    ///
+    pub fn total_supply_at(&self, height: u32) -> Result<AleoAmount> {
+        match self.total_supplies.get(&height) {
+            Some(total_supply) => Ok(*total_supply),
+            None => Err(anyhow!("Missing total supply for height {}", height)),
+        }
+    }
+
+    ///
+    /// Returns the supply audit up to and including the block with the given height.
+    ///
+    /// The audit recomputes the total supply from the transactions of each block since the genesis block,
+    /// and reports each block that issues more than its block reward, or whose recorded total supply
+    /// differs from the recomputed total supply.
+    ///
+    pub fn audit_supply(&self, height: u32) -> Result<SupplyAudit> {
+        if height > self.current_height {
+            return Err(anyhow!("Given block height {} is greater than current height", height));
+        }
+
+        let mut total_supply = AleoAmount::ZERO;
+        let mut discrepancies = Vec::new();
+        for block_height in 0..=height {
+            let issued_supply = Self::issued_supply(self.get_block_transactions(block_height)?);
+            total_supply = total_supply.add(issued_supply);
+
+            // Ensure the block issues at most its block reward.
+            let block_reward = N::block_reward(block_height);
+            if issued_supply > block_reward {
+                discrepancies.push(SupplyDiscrepancy::ExcessIssuance {
+                    height: block_height,
+                    issued_supply,
+                    block_reward,
+                });
+            }
+
+            // Ensure the recorded total supply matches the recomputed total supply.
+            let recorded_supply = self.total_supply_at(block_height)?;
+            if recorded_supply != total_supply {
+                discrepancies.push(SupplyDiscrepancy::TotalSupplyMismatch {
+                    height: block_height,
+                    recorded_supply,
+                    recomputed_supply: total_supply,
+                });
+            }
+        }
+
+        Ok(SupplyAudit::new(
+            height,
+            total_supply,
+            N::scheduled_supply_at(height),
+            discrepancies,
+        ))
+    }
+
+    /// Returns the block locator of the canon chain.
+    pub fn get_block_locator(&self) -> Result<BlockLocator<N>> {
+        BlockLocator::new(self)
+    }
+
+    /// Returns the block height and block hash of the latest block in the given block locator
+    /// that is also in the canon chain.
+    pub fn find_common_ancestor(&self, block_locator: &BlockLocator<N>) -> Result<(u32, N::BlockHash)> {
+        if !block_locator.is_valid() {
+            return Err(anyhow!("The given block locator is invalid"));
+        }
+
+        for (height, block_hash) in block_locator.iter() {
+            if height <= self.current_height && self.get_block_hash(height)? == block_hash {
+                return Ok((height, block_hash));
+            }
+        }
+
+        Err(anyhow!(
+            "The given block locator has no common ancestor with the canon chain"
+        ))
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_height(&self, height: u32) -> bool {
         self.previous_hashes.contains_key(&height)
@@ -171,6 +273,107 @@ impl<N: Network> Blocks<N> {
                 .any(|root| root == *ledger_root)
     }
 
+    ///
+    /// Returns `true` if the given ledger root may be referenced by a new transaction in the next block.
+    ///
+    /// If the network upgrade of the next block bounds the ledger root window, a ledger root is acceptable
+    /// if it is at most that many blocks behind the latest block, or if it is the root of the empty ledger,
+    /// which is referenced by transactions that do not spend any records, such as coinbase transactions.
+    /// Otherwise, any ledger root of the canon chain is acceptable.
+    ///
+    /// In addition, the ledger root must be mature, so that it does not contain an immature coinbase record.
+    ///
+    pub fn is_acceptable_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+        let is_acceptable = match N::UPGRADE_SCHEDULE
+            .get(self.current_height.saturating_add(1))
+            .ledger_root_window
+        {
+            LedgerRootWindow::Unbounded => self.contains_ledger_root(ledger_root),
+            LedgerRootWindow::Blocks(maximum_age) => self.is_recent_root(ledger_root, maximum_age),
+        } && self.is_mature_root(ledger_root);
+
+        // During a ledger tree transition, the migration of an acceptable ledger root is also acceptable.
+        is_acceptable
+            || match self.migrated_ledger_roots.get(ledger_root) {
+                Some((original_root, _)) => self.is_acceptable_root(original_root),
+                None => false,
+            }
+    }
+
+    /// Returns `true` if the given ledger root is at most `maximum_age` blocks behind the latest block,
+    /// or if it is the root of the empty ledger.
+    fn is_recent_root(&self, ledger_root: &N::LedgerRoot, maximum_age: u32) -> bool {
+        if *ledger_root == self.latest_ledger_root() {
+            return true;
+        }
+
+        // The header at height `h` contains the ledger root as of block `h - 1`.
+        let oldest_height = self.current_height.saturating_sub(maximum_age);
+        self.headers.iter().any(|(height, header)| {
+            (*height == 0 || *height > oldest_height) && header.previous_ledger_root() == *ledger_root
+        })
+    }
+
+    ///
+    /// Returns `true` if a coinbase record from the block at the given height may be spent in the next block.
+    ///
+    /// Serial numbers do not reveal the records they spend, so maturity is enforced on the ledger root instead.
+    /// See `Blocks::is_mature_root`.
+    ///
+    pub fn is_coinbase_mature(&self, block_height: u32) -> bool {
+        self.current_height >= block_height.saturating_add(N::COINBASE_MATURITY)
+    }
+
+    ///
+    /// Returns `true` if the given ledger root contains no block whose coinbase record is immature.
+    ///
+    /// A new transaction may only reference a mature ledger root, so every record it spends,
+    /// including a coinbase record, is at least `N::COINBASE_MATURITY` blocks old.
+    ///
+    fn is_mature_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+        if *ledger_root == self.latest_ledger_root() {
+            return self.is_coinbase_mature(self.current_height);
+        }
+
+        // The header at height `h` contains the ledger root as of block `h - 1`,
+        // and the header at height `0` contains the root of the empty ledger.
+        match self.ledger_root_heights.get(ledger_root) {
+            Some(0) => true,
+            Some(height) => self.is_coinbase_mature(height - 1),
+            None => false,
+        }
+    }
+
+    ///
+    /// Returns the mature ledger tree, which contains every block whose coinbase record is mature.
+    ///
+    /// If the network has no coinbase maturity, this is the current ledger tree.
+    ///
+    fn to_mature_ledger_tree(&self) -> &LedgerTree<N> {
+        match N::COINBASE_MATURITY {
+            0 => &self.ledger_tree,
+            _ => &self.mature_ledger_tree,
+        }
+    }
+
+    /// Returns the height of the block that becomes mature at the given block height, if there is one.
+    fn mature_block_height(height: u32) -> Option<u32> {
+        match N::COINBASE_MATURITY {
+            // The mature ledger tree is the current ledger tree, and is not tracked separately.
+            0 => None,
+            coinbase_maturity => height.checked_sub(coinbase_maturity),
+        }
+    }
+
+    /// Adds the block that becomes mature at the given block height to the mature ledger tree.
+    fn add_mature_block(&mut self, height: u32) -> Result<()> {
+        if let Some(mature_height) = Self::mature_block_height(height) {
+            let block_hash = self.get_block_hash(mature_height)?;
+            self.mature_ledger_tree.add(&block_hash)?;
+        }
+        Ok(())
+    }
+
     /// Returns `true` if the given block hash exists.
     pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
         self.current_hash == *block_hash || self.previous_hashes.values().any(|hash| *hash == *block_hash)
@@ -202,6 +405,12 @@ impl<N: Network> Blocks<N> {
 
     /// Adds the given block as the next block in the chain.
     pub fn add_next(&mut self, block: &Block<N>) -> Result<()> {
+        let diff = self.to_block_diff(block)?;
+        self.apply(&diff)
+    }
+
+    /// Returns the block diff for the given block, if it is a valid next block in the chain.
+    pub fn to_block_diff(&self, block: &Block<N>) -> Result<BlockDiff<N>> {
         // Ensure the block itself is valid.
         if !block.is_valid() {
             return Err(anyhow!("The given block is invalid"));
@@ -242,15 +451,9 @@ impl<N: Network> Blocks<N> {
         }
 
         // Ensure the expected difficulty target is met.
+        let anchor_block_header = self.get_difficulty_anchor(block.height())?;
         let expected_difficulty_target =
-            if N::NETWORK_ID == 2 && block.height() <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-                Blocks::<N>::compute_difficulty_target(current_block.header(), block.timestamp(), block.height())
-            } else if N::NETWORK_ID == 2 {
-                let anchor_block_header = self.get_block_header(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT)?;
-                Blocks::<N>::compute_difficulty_target(anchor_block_header, block.timestamp(), block.height())
-            } else {
-                Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block.timestamp(), block.height())
-            };
+            Blocks::<N>::compute_difficulty_target(anchor_block_header, block.timestamp(), block.height());
         if block.difficulty_target() != expected_difficulty_target {
             return Err(anyhow!(
                 "The given block difficulty target is incorrect. Found {}, but expected {}",
@@ -276,13 +479,20 @@ impl<N: Network> Blocks<N> {
             if self.contains_transaction(transaction) {
                 return Err(anyhow!("The given block has a duplicate transaction in the ledger"));
             }
-            // Ensure the transaction in the block references a valid past or current ledger root.
-            if !self.contains_ledger_root(&transaction.ledger_root()) {
+            // Ensure the transaction in the block references a recent or current ledger root.
+            if !self.is_acceptable_root(&transaction.ledger_root()) {
                 return Err(anyhow!(
-                    "The given transaction references a non-existent ledger root {}",
+                    "The given transaction references an unknown or expired ledger root {}",
                     &transaction.ledger_root()
                 ));
             }
+            // Ensure the block reaches the locktime cutoff of each transition in the transaction.
+            if !transaction.is_unlocked_at(height, block.timestamp()) {
+                return Err(anyhow!(
+                    "The given transaction {} consumes records that are locked at this block",
+                    transaction.transaction_id()
+                ));
+            }
         }
 
         // Ensure the ledger does not already contain a given serial numbers.
@@ -299,16 +509,77 @@ impl<N: Network> Blocks<N> {
             }
         }
 
+        Ok(BlockDiff::new(block).with_previous_migrated_ledger_roots(self.migrated_ledger_roots.clone()))
+    }
+
+    /// Applies the given block diff as the next block in the chain.
+    /// Note: This method assumes the diff was computed with `Blocks::to_block_diff`,
+    /// which validates the block, and is therefore only exposed to the crate.
+    pub(crate) fn apply(&mut self, diff: &BlockDiff<N>) -> Result<()> {
+        // Ensure the block diff extends the current block.
+        if self.current_height + 1 != diff.height() || self.current_hash != diff.previous_block_hash() {
+            return Err(anyhow!("The given block diff does not extend the current block"));
+        }
+
         // Add the block to the ledger. This code section executes atomically.
         {
             let mut blocks = self.clone();
 
-            blocks.current_height = height;
-            blocks.current_hash = block_hash;
-            blocks.ledger_tree.add(&block.hash())?;
-            blocks.previous_hashes.insert(height, block.previous_block_hash());
-            blocks.headers.insert(height, block.header().clone());
-            blocks.transactions.insert(height, block.transactions().clone());
+            blocks.current_height = diff.height();
+            blocks.current_hash = diff.block_hash();
+            blocks.ledger_tree.add(&diff.block_hash())?;
+            blocks.previous_hashes.insert(diff.height(), diff.previous_block_hash());
+            blocks.headers.insert(diff.height(), diff.header().clone());
+            blocks
+                .ledger_root_heights
+                .insert(diff.header().previous_ledger_root(), diff.height());
+            blocks.transactions.insert(diff.height(), diff.transactions().clone());
+            blocks.add_mature_block(diff.height())?;
+
+            let total_supply = blocks
+                .total_supply_at(self.current_height)?
+                .add(Self::issued_supply(diff.transactions()));
+            blocks.total_supplies.insert(diff.height(), total_supply);
+            blocks.update_migrated_ledger_roots()?;
+
+            *self = blocks;
+        }
+
+        Ok(())
+    }
+
+    /// Reverts the given block diff, which must correspond to the latest block in the chain.
+    pub fn revert(&mut self, diff: &BlockDiff<N>) -> Result<()> {
+        // Ensure the block diff is for the latest block.
+        if self.current_height != diff.height() || self.current_hash != diff.block_hash() {
+            return Err(anyhow!("The given block diff is not for the latest block"));
+        }
+
+        // Ensure the genesis block is not reverted.
+        if diff.height() == 0 {
+            return Err(anyhow!("The genesis block cannot be reverted"));
+        }
+
+        // Remove the block from the ledger. This code section executes atomically.
+        {
+            let mut blocks = self.clone();
+
+            // Restore the migrated ledger roots, including any that were discarded when the block was applied.
+            blocks.migrated_ledger_roots = diff.previous_migrated_ledger_roots().clone();
+
+            blocks.current_height = diff.height() - 1;
+            blocks.current_hash = diff.previous_block_hash();
+            blocks.ledger_tree.remove_last(&diff.block_hash())?;
+            if let Some(mature_height) = Self::mature_block_height(diff.height()) {
+                blocks
+                    .mature_ledger_tree
+                    .remove_last(&self.get_block_hash(mature_height)?)?;
+            }
+            blocks.ledger_root_heights.remove(&diff.header().previous_ledger_root());
+            blocks.previous_hashes.remove(&diff.height());
+            blocks.headers.remove(&diff.height());
+            blocks.transactions.remove(&diff.height());
+            blocks.total_supplies.remove(&diff.height());
 
             *self = blocks;
         }
@@ -316,6 +587,39 @@ impl<N: Network> Blocks<N> {
         Ok(())
     }
 
+    ///
+    /// Records the migration of the latest ledger root, if the next block is in a ledger tree transition,
+    /// and otherwise discards the migrated ledger roots of a transition that has ended.
+    ///
+    fn update_migrated_ledger_roots(&mut self) -> Result<()> {
+        let next_height = self.current_height.saturating_add(1);
+        match N::UPGRADE_SCHEDULE.get(next_height).ledger_root_migration.depth() {
+            Some(depth) => self.add_migrated_ledger_root(depth),
+            None => {
+                self.migrated_ledger_roots.clear();
+                Ok(())
+            }
+        }
+    }
+
+    /// Records the migration of the latest ledger root to the given depth.
+    fn add_migrated_ledger_root(&mut self, depth: usize) -> Result<()> {
+        // Discard the migrated ledger roots of a transition to a different depth.
+        self.migrated_ledger_roots
+            .retain(|_, (_, migration_depth)| *migration_depth == depth);
+
+        let ledger_root = self.latest_ledger_root();
+        let migrated_root = LedgerTree::<N>::migrate_root(ledger_root, depth)?;
+        self.migrated_ledger_roots.insert(migrated_root, (ledger_root, depth));
+        Ok(())
+    }
+
+    /// Returns the supply issued by a block with the given transactions, which is the block reward
+    /// claimed by the coinbase transaction, less the transaction fees that it does not claim.
+    fn issued_supply(transactions: &Transactions<N>) -> AleoAmount {
+        AleoAmount::ZERO.sub(transactions.net_value_balance()) // Make it a positive number.
+    }
+
     /// Returns the ledger tree.
     pub fn to_ledger_tree(&self) -> &LedgerTree<N> {
         &self.ledger_tree
@@ -368,6 +672,17 @@ impl<N: Network> Blocks<N> {
         let transactions = self.get_block_transactions(block_height)?;
         let block_header = self.get_block_header(block_height)?;
 
+        // Ensure the record is contained in a mature ledger root.
+        // Note: This applies to every record, and not only to coinbase records.
+        if !self.is_coinbase_mature(block_height) {
+            return Err(anyhow!(
+                "The record {} may not be spent until block {}, as every record must be {} blocks old to be spent",
+                commitment,
+                block_height.saturating_add(N::COINBASE_MATURITY).saturating_add(1),
+                N::COINBASE_MATURITY
+            ));
+        }
+
         // Compute the transactions inclusion proof.
         let transactions_inclusion_proof = {
             // TODO (howardwu): Optimize this operation.
@@ -385,13 +700,16 @@ impl<N: Network> Blocks<N> {
 
         // Compute the block header inclusion proof.
         let transactions_root = transactions.transactions_root();
-        let block_header_inclusion_proof = block_header.to_header_inclusion_proof(1, transactions_root)?;
-        let block_header_root = block_header.to_header_root()?;
-        let previous_block_hash = self.get_previous_block_hash(self.current_height)?;
-        let current_block_hash = self.current_hash;
+        let (block_header_root, block_header_paths) = block_header.to_header_root_and_paths()?;
+        let block_header_inclusion_proof = block_header_paths
+            .into_iter()
+            .nth(1)
+            .ok_or_else(|| anyhow!("Missing the block header inclusion proof of the transactions root"))?;
+        let block_hash = self.get_block_hash(block_height)?;
+        let previous_block_hash = self.get_previous_block_hash(block_height)?;
 
         let record_proof = RecordProof::new(
-            current_block_hash,
+            block_hash,
             previous_block_hash,
             block_header_root,
             block_header_inclusion_proof,
@@ -400,34 +718,102 @@ impl<N: Network> Blocks<N> {
             local_proof,
         )?;
 
+        // Compute the ledger root inclusion proof against the latest mature ledger root.
+        let mature_ledger_tree = self.to_mature_ledger_tree();
+        let ledger_root_inclusion_proof = mature_ledger_tree.to_ledger_inclusion_proof(&block_hash)?;
+
+        LedgerProof::new(mature_ledger_tree.root(), ledger_root_inclusion_proof, record_proof)
+    }
+
+    ///
+    /// Returns an archive proof for the given transaction ID.
+    ///
+    /// The archive proof is serializable, and commits to the transaction only through the block header chain,
+    /// so it may be stored and served to verify the transaction after the block transactions are pruned.
+    ///
+    pub fn to_archive_proof(&self, transaction_id: N::TransactionID) -> Result<ArchiveProof<N>> {
+        // Find the block height and index of the transaction.
+        let (block_height, index) = self
+            .transactions
+            .iter()
+            .find_map(|(block_height, transactions)| {
+                transactions
+                    .transaction_ids()
+                    .position(|id| id == transaction_id)
+                    .map(|index| (*block_height, index))
+            })
+            .ok_or_else(|| anyhow!("Transaction {} does not exist in the ledger", transaction_id))?;
+        let transactions = self.get_block_transactions(block_height)?;
+        let transactions_inclusion_proof = transactions.to_transactions_inclusion_proof(index, transaction_id)?;
+
+        // Compute the block header inclusion proof.
+        let (block_header_root, block_header_paths) =
+            self.get_block_header(block_height)?.to_header_root_and_paths()?;
+        let block_header_inclusion_proof = block_header_paths
+            .into_iter()
+            .nth(1)
+            .ok_or_else(|| anyhow!("Missing the block header inclusion proof of the transactions root"))?;
+
+        let block_hash = self.get_block_hash(block_height)?;
         let ledger_root = self.latest_ledger_root();
-        let ledger_root_inclusion_proof = self.to_ledger_root_inclusion_proof(&current_block_hash)?;
+        let ledger_root_inclusion_proof = self.to_ledger_root_inclusion_proof(&block_hash)?;
 
-        LedgerProof::new(ledger_root, ledger_root_inclusion_proof, record_proof)
+        ArchiveProof::new(
+            ledger_root,
+            ledger_root_inclusion_proof,
+            block_hash,
+            self.get_previous_block_hash(block_height)?,
+            block_header_root,
+            block_header_inclusion_proof,
+            transactions.transactions_root(),
+            transactions_inclusion_proof,
+            transaction_id,
+        )
     }
 
-    /// Returns the expected difficulty target given the previous block and expected next block details.
+    ///
+    /// Returns `true` if the given archive proof is with respect to a ledger root in the ledger.
+    /// The archive proof is otherwise valid by construction.
+    ///
+    pub fn verify_archive_proof(&self, archive_proof: &ArchiveProof<N>) -> bool {
+        self.contains_ledger_root(&archive_proof.ledger_root())
+    }
+
+    /// Returns the block header that anchors the difficulty target of the given block height.
+    pub fn get_difficulty_anchor(&self, block_height: u32) -> Result<&BlockHeader<N>> {
+        match N::UPGRADE_SCHEDULE.get(block_height).difficulty_anchor {
+            DifficultyAnchor::PreviousBlock => self.get_block_header(block_height.saturating_sub(1)),
+            DifficultyAnchor::Height(anchor_height) => self.get_block_header(anchor_height),
+        }
+    }
+
+    /// Returns the expected difficulty target given the anchor block and expected next block details.
     pub fn compute_difficulty_target(
         anchor_block_header: &BlockHeader<N>,
         block_timestamp: i64,
         block_height: u32,
     ) -> u64 {
-        if N::NETWORK_ID == 2 && block_height <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-            Self::bitcoin_retarget(
+        match N::UPGRADE_SCHEDULE.get(block_height).difficulty_retarget {
+            DifficultyRetarget::Bitcoin => Self::bitcoin_retarget(
                 anchor_block_header.timestamp(),
                 anchor_block_header.difficulty_target(),
                 block_timestamp,
                 N::ALEO_BLOCK_TIME_IN_SECS,
-            )
-        } else {
-            Self::asert_retarget(
+            ),
+            DifficultyRetarget::BitcoinExact => Self::bitcoin_retarget_exact(
+                anchor_block_header.timestamp(),
+                anchor_block_header.difficulty_target(),
+                block_timestamp,
+                N::ALEO_BLOCK_TIME_IN_SECS,
+            ),
+            DifficultyRetarget::Asert => Self::asert_retarget(
                 anchor_block_header.timestamp(),
                 anchor_block_header.difficulty_target(),
                 anchor_block_header.height(),
                 block_timestamp,
                 block_height,
                 N::ALEO_BLOCK_TIME_IN_SECS,
-            )
+            ),
         }
     }
 
@@ -436,6 +822,8 @@ impl<N: Network> Blocks<N> {
     ///     M = Number of blocks per retarget.
     ///     B = Expected time per block.
     ///     S = Time elapsed between the last M blocks.
+    /// The difficulty factor is computed in floating-point arithmetic, as in the blocks that precede
+    /// `DifficultyRetarget::BitcoinExact`.
     fn bitcoin_retarget(
         previous_timestamp: i64,
         previous_difficulty: u64,
@@ -460,6 +848,36 @@ impl<N: Network> Blocks<N> {
         }
     }
 
+    /// Bitcoin difficulty retarget algorithm, as in `bitcoin_retarget`, computed in exact integer arithmetic.
+    fn bitcoin_retarget_exact(
+        previous_timestamp: i64,
+        previous_difficulty: u64,
+        block_timestamp: i64,
+        target_block_time: i64,
+    ) -> u64 {
+        const NUM_BLOCKS_PER_RETARGET: i64 = 1i64;
+
+        let time_elapsed = block_timestamp.saturating_sub(previous_timestamp);
+        let time_elapsed = match time_elapsed > 0 {
+            true => time_elapsed,
+            false => 1,
+        };
+
+        let expected_time_elapsed = core::cmp::max(NUM_BLOCKS_PER_RETARGET.saturating_mul(target_block_time), 1);
+
+        // Compute `previous_difficulty * time_elapsed / expected_time_elapsed` in 128-bit arithmetic.
+        // As both factors are at most 64 bits, the product fits in the low half.
+        let (numerator, _) =
+            BigInteger128::from(previous_difficulty).mul_wide(&BigInteger128::from(time_elapsed as u64));
+        let new_difficulty = numerator
+            .div_rem(&BigInteger128::from(expected_time_elapsed as u64))
+            .and_then(|(quotient, _)| quotient.to_u128_checked())
+            .unwrap_or(u128::MAX);
+
+        // Cap the difficulty target at `u64::MAX` if it has overflowed.
+        core::cmp::min(new_difficulty, u64::MAX as u128) as u64
+    }
+
     /// ASERT difficulty retarget algorithm based on https://www.reference.cash/protocol/forks/2020-11-15-asert.
     ///     T_{i+1} = T_anchor * 2^((S - B * N) / tau).
     ///     T_anchor = Anchor target of a specific block height
@@ -526,20 +944,31 @@ impl<N: Network> Blocks<N> {
                 + 2_u128.pow(RBITS * 3 - 1))
                 >> (RBITS * 3));
 
-        // Cast the anchor difficulty target from a u64 to a u128.
-        // The difficulty target must allow for leading zeros to account for overflows;
-        // an additional 64-bits for the leading zeros suffices.
-        let candidate_difficulty_target = (anchor_difficulty_target as u128).saturating_mul(fractional_multiplier);
-
         // Calculate the new difficulty.
         // Shift the target to multiply by 2^(integer) / RADIX.
-        let shifts = integral - RBITS as i128;
+        Self::scale_difficulty_target(anchor_difficulty_target, fractional_multiplier, integral - RBITS as i128)
+    }
+
+    /// Returns `difficulty_target * multiplier * 2^shifts`, rounded down, at least 1, and capped at `u64::MAX`.
+    /// The multiplier must be less than 2^64, so the product fits in 128 bits.
+    fn scale_difficulty_target(difficulty_target: u64, multiplier: u128, shifts: i128) -> u64 {
+        // Multiply the difficulty target by the multiplier in 128-bit arithmetic.
+        // The difficulty target must allow for leading zeros to account for overflows;
+        // as both factors are less than 2^64, the product fits in the low half.
+        let (candidate_difficulty_target, _) =
+            BigInteger128::from(difficulty_target).mul_wide(&BigInteger128::from_u128(multiplier));
+
         let mut candidate_difficulty_target = if shifts < 0 {
-            match candidate_difficulty_target.checked_shr((-shifts) as u32) {
-                Some(target) => core::cmp::max(target, 1),
+            // Divide by 2^(-shifts), which rounds down as a right shift does.
+            match 1u128.checked_shl((-shifts) as u32) {
+                Some(divisor) => candidate_difficulty_target
+                    .div_rem(&BigInteger128::from_u128(divisor))
+                    .and_then(|(quotient, _)| quotient.to_u128_checked())
+                    .map_or(1, |target| core::cmp::max(target, 1)),
                 None => 1,
             }
         } else {
+            let candidate_difficulty_target = candidate_difficulty_target.to_u128_checked().unwrap_or(u128::MAX);
             match candidate_difficulty_target.checked_shl(shifts as u32) {
                 Some(target) => core::cmp::max(target, 1),
                 None => u64::MAX as u128,
@@ -560,9 +989,178 @@ impl<N: Network> Blocks<N> {
 #[allow(clippy::comparison_chain)]
 mod tests {
     use super::*;
-    use crate::testnet2::Testnet2;
+    use crate::{testnet2::Testnet2, testnet2_dev::Testnet2Dev};
+    use snarkvm_algorithms::traits::MerkleParameters;
 
     use rand::{thread_rng, Rng};
+    use std::sync::{atomic::AtomicBool, Arc};
+
+    #[test]
+    fn test_coinbase_maturity() {
+        let blocks = Blocks::<Testnet2>::new().unwrap();
+        assert_eq!(0, Testnet2::COINBASE_MATURITY);
+        assert!(blocks.is_coinbase_mature(0));
+        assert!(!blocks.is_coinbase_mature(1));
+
+        // Ensure a ledger proof is created for a mature coinbase record.
+        let genesis_block = Testnet2::genesis_block();
+        let commitment = genesis_block.commitments().next().unwrap();
+        let ledger_proof = blocks.to_ledger_proof(*commitment).unwrap();
+        assert_eq!(blocks.latest_ledger_root(), ledger_proof.ledger_root());
+    }
+
+    #[test]
+    fn test_mature_ledger_tree() {
+        let rng = &mut thread_rng();
+
+        // Mine three blocks on a separate ledger.
+        let mut ledger = Ledger::<Testnet2Dev>::new().unwrap();
+        let recipient = Account::<Testnet2Dev>::new(rng);
+        let mut blocks = Blocks::<Testnet2Dev>::new().unwrap();
+        let mut diffs = Vec::new();
+        for _ in 0..3 {
+            ledger
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .unwrap();
+            let diff = blocks.to_block_diff(&ledger.latest_block().unwrap()).unwrap();
+            blocks.apply(&diff).unwrap();
+            diffs.push(diff);
+        }
+
+        // Ensure the mature ledger tree lags the current ledger tree by the coinbase maturity.
+        assert_eq!(2, Testnet2Dev::COINBASE_MATURITY);
+        let expected_root = blocks.get_block_header(2).unwrap().previous_ledger_root();
+        assert_eq!(expected_root, blocks.to_mature_ledger_tree().root());
+        assert!(blocks.is_mature_root(&expected_root));
+        assert!(!blocks.is_mature_root(&blocks.get_block_header(3).unwrap().previous_ledger_root()));
+
+        // Ensure the mature ledger tree is restored when a block is reverted.
+        blocks.revert(&diffs[2]).unwrap();
+        let expected_root = blocks.get_block_header(1).unwrap().previous_ledger_root();
+        assert_eq!(expected_root, blocks.to_mature_ledger_tree().root());
+        assert!(!blocks.is_mature_root(&blocks.get_block_header(2).unwrap().previous_ledger_root()));
+
+        // Ensure the mature ledger tree is empty once every block is reverted.
+        blocks.revert(&diffs[1]).unwrap();
+        blocks.revert(&diffs[0]).unwrap();
+        let empty_ledger_root = LedgerTree::<Testnet2Dev>::new().unwrap().root();
+        assert_eq!(empty_ledger_root, blocks.to_mature_ledger_tree().root());
+        assert_eq!(blocks.ledger_root_heights.len(), 1);
+    }
+
+    #[test]
+    fn test_migrated_ledger_root() {
+        let mut blocks = Blocks::<Testnet2>::new().unwrap();
+        let ledger_root = blocks.latest_ledger_root();
+        let migrated_root = LedgerTree::<Testnet2>::migrate_root(ledger_root, 40).unwrap();
+        assert!(!blocks.is_acceptable_root(&migrated_root));
+
+        // Ensure the migrated ledger root is the root of the migrated ledger tree.
+        type DeeperLedgerRootParameters = MerkleTreeParameters<<Testnet2 as Network>::LedgerRootCRH, 40>;
+        let parameters = DeeperLedgerRootParameters::setup(Testnet2::ledger_root_parameters().setup_message());
+        let (tree, proof) = blocks.to_ledger_tree().migrate(Arc::new(parameters)).unwrap();
+        assert_eq!(*migrated_root, *tree.root());
+        assert!(proof.verify(&ledger_root, tree.root()).unwrap());
+
+        // Ensure both ledger roots are acceptable during a ledger tree transition.
+        blocks.add_migrated_ledger_root(40).unwrap();
+        assert!(blocks.is_acceptable_root(&ledger_root));
+        assert!(blocks.is_acceptable_root(&migrated_root));
+
+        // Ensure the migrated ledger root is not acceptable once the transition has ended.
+        blocks.update_migrated_ledger_roots().unwrap();
+        assert!(blocks.is_acceptable_root(&ledger_root));
+        assert!(!blocks.is_acceptable_root(&migrated_root));
+
+        // Ensure a ledger root may not be migrated to a smaller depth.
+        assert!(LedgerTree::<Testnet2>::migrate_root(ledger_root, 16).is_err());
+    }
+
+    #[test]
+    fn test_ledger_root_window() {
+        let rng = &mut thread_rng();
+
+        // Mine two blocks on a separate ledger.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        let mut blocks = Blocks::<Testnet2>::new().unwrap();
+        let genesis_ledger_root = blocks.latest_ledger_root();
+        let empty_ledger_root = LedgerTree::<Testnet2>::new().unwrap().root();
+        for _ in 0..2 {
+            ledger
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .unwrap();
+            blocks.add_next(&ledger.latest_block().unwrap()).unwrap();
+        }
+
+        // Ensure a ledger root leaves a bounded window once it is too old, except for the empty ledger root.
+        assert!(blocks.is_recent_root(&genesis_ledger_root, 2));
+        assert!(!blocks.is_recent_root(&genesis_ledger_root, 1));
+        assert!(blocks.is_recent_root(&empty_ledger_root, 1));
+        assert!(blocks.is_recent_root(&blocks.latest_ledger_root(), 0));
+
+        // Ensure the window is unbounded before a network upgrade bounds it.
+        assert_eq!(
+            LedgerRootWindow::Unbounded,
+            Testnet2::UPGRADE_SCHEDULE.get(blocks.latest_block_height() + 1).ledger_root_window
+        );
+        assert!(blocks.is_acceptable_root(&genesis_ledger_root));
+    }
+
+    #[test]
+    fn test_revert_restores_migrated_ledger_roots() {
+        let rng = &mut thread_rng();
+
+        // Mine the next block on a separate ledger.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let block = ledger.latest_block().unwrap();
+
+        // Start a ledger tree transition at the genesis block.
+        let mut blocks = Blocks::<Testnet2>::new().unwrap();
+        blocks.add_migrated_ledger_root(40).unwrap();
+        let migrated_root = LedgerTree::<Testnet2>::migrate_root(blocks.latest_ledger_root(), 40).unwrap();
+        assert!(blocks.is_acceptable_root(&migrated_root));
+
+        // Ensure the migrated ledger root is discarded once the block ends the transition.
+        let diff = blocks.to_block_diff(&block).unwrap();
+        blocks.apply(&diff).unwrap();
+        assert!(!blocks.is_acceptable_root(&migrated_root));
+
+        // Ensure the migrated ledger root is restored when the block is reverted.
+        blocks.revert(&diff).unwrap();
+        assert!(blocks.is_acceptable_root(&migrated_root));
+    }
+
+    #[test]
+    fn test_audit_supply() {
+        let mut blocks = Blocks::<Testnet2>::new().unwrap();
+        let starting_supply = Testnet2::block_reward(0);
+
+        let audit = blocks.audit_supply(0).unwrap();
+        assert!(audit.is_consistent());
+        assert_eq!(starting_supply, audit.total_supply());
+        assert_eq!(starting_supply, audit.scheduled_supply());
+
+        // Ensure the audit reports a recorded total supply that differs from the recomputed total supply.
+        let recorded_supply = starting_supply.add(AleoAmount::ONE_CREDIT);
+        blocks.total_supplies.insert(0, recorded_supply);
+
+        let audit = blocks.audit_supply(0).unwrap();
+        assert!(!audit.is_consistent());
+        assert_eq!(starting_supply, audit.total_supply());
+        assert_eq!(
+            &[SupplyDiscrepancy::TotalSupplyMismatch {
+                height: 0,
+                recorded_supply,
+                recomputed_supply: starting_supply,
+            }],
+            audit.discrepancies()
+        );
+    }
 
     #[test]
     fn test_bitcoin_difficulty_target() {
@@ -597,6 +1195,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bitcoin_exact_difficulty_target() {
+        let rng = &mut thread_rng();
+
+        let mut block_difficulty_target = u64::MAX;
+        let mut current_timestamp = 0;
+
+        for _ in 0..1000 {
+            // Simulate a random block time.
+            let simulated_block_time =
+                rng.gen_range(Testnet2::ALEO_BLOCK_TIME_IN_SECS / 2..Testnet2::ALEO_BLOCK_TIME_IN_SECS * 2);
+            let new_timestamp = current_timestamp + simulated_block_time;
+
+            let new_target = Blocks::<Testnet2>::bitcoin_retarget_exact(
+                current_timestamp,
+                block_difficulty_target,
+                new_timestamp,
+                Testnet2::ALEO_BLOCK_TIME_IN_SECS,
+            );
+
+            if simulated_block_time < Testnet2::ALEO_BLOCK_TIME_IN_SECS {
+                // If the block was found faster than expected, the difficulty should increase.
+                assert!(new_target < block_difficulty_target);
+            } else if simulated_block_time >= Testnet2::ALEO_BLOCK_TIME_IN_SECS {
+                // If the block was found slower than expected, the difficulty should decrease.
+                assert!(new_target >= block_difficulty_target);
+            }
+
+            current_timestamp = new_timestamp;
+            block_difficulty_target = new_target;
+        }
+
+        // Ensure the exact retarget preserves a difficulty target that is not representable as a float,
+        // while the floating-point retarget of the earlier blocks rounds it.
+        let block_time = Testnet2::ALEO_BLOCK_TIME_IN_SECS;
+        let difficulty_target = (1u64 << 60) + 1;
+        assert_eq!(
+            difficulty_target,
+            Blocks::<Testnet2>::bitcoin_retarget_exact(0, difficulty_target, block_time, block_time)
+        );
+        assert_eq!(
+            1u64 << 60,
+            Blocks::<Testnet2>::bitcoin_retarget(0, difficulty_target, block_time, block_time)
+        );
+    }
+
     #[test]
     fn test_asert_difficulty_target_simple() {
         let anchor_timestamp = 1640179531i64;
@@ -743,6 +1387,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scale_difficulty_target() {
+        // The scaling of the ASERT difficulty target in native 128-bit shifts.
+        fn expected(difficulty_target: u64, multiplier: u128, shifts: i128) -> u64 {
+            let candidate = (difficulty_target as u128).saturating_mul(multiplier);
+            let candidate = if shifts < 0 {
+                candidate.checked_shr((-shifts) as u32).map_or(1, |target| core::cmp::max(target, 1))
+            } else {
+                candidate.checked_shl(shifts as u32).map_or(u64::MAX as u128, |target| core::cmp::max(target, 1))
+            };
+            core::cmp::min(candidate, u64::MAX as u128) as u64
+        }
+
+        let rng = &mut thread_rng();
+
+        for &(difficulty_target, multiplier, shifts) in &[
+            (1u64, 1u128, 0i128),
+            (u64::MAX, 1 << 16, -16),
+            (u64::MAX, (1 << 17) - 1, -16),
+            (u64::MAX, (1 << 17) - 1, -127),
+            (u64::MAX, (1 << 17) - 1, -128),
+            (u64::MAX, (1 << 17) - 1, 1),
+            (u64::MAX, (1 << 17) - 1, 128),
+            (1, 1 << 16, i128::MIN + 1),
+        ] {
+            assert_eq!(
+                expected(difficulty_target, multiplier, shifts),
+                Blocks::<Testnet2>::scale_difficulty_target(difficulty_target, multiplier, shifts)
+            );
+        }
+
+        for _ in 0..100_000 {
+            let difficulty_target = rng.gen_range(1..u64::MAX);
+            let multiplier = rng.gen_range(1 << 16..1 << 17);
+            let shifts = rng.gen_range(-200..200);
+            assert_eq!(
+                expected(difficulty_target, multiplier, shifts),
+                Blocks::<Testnet2>::scale_difficulty_target(difficulty_target, multiplier, shifts)
+            );
+        }
+    }
+
     #[test]
     fn test_asert_difficulty_target_random() {
         let rng = &mut thread_rng();
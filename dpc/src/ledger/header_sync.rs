@@ -0,0 +1,457 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{prelude::*, DifficultyAnchor};
+use snarkvm_algorithms::CRH;
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// A block header received ahead of the contiguous chain of verified headers.
+#[derive(Clone, Debug)]
+struct PendingHeader<N: Network> {
+    /// The previous block hash, as claimed by the sender of the header.
+    previous_block_hash: N::BlockHash,
+    /// The block hash, computed from the previous block hash and the header.
+    block_hash: N::BlockHash,
+    /// The block header.
+    header: BlockHeader<N>,
+    /// `true` if the block header and its PoSW proof have been verified.
+    is_verified: bool,
+}
+
+///
+/// Verifies block headers ahead of their block transactions, so that header validation
+/// does not serialize behind the download of full blocks.
+///
+/// Headers may be added in any order. Their PoSW proofs are verified in parallel batches
+/// with `verify_pending`, and `advance` then links the verified headers that are contiguous
+/// with the synced chain, enforcing the previous block hash, timestamp, difficulty target,
+/// and cumulative weight of each header. Checkpoints pin the block hash at given heights,
+/// so that headers from a competing chain are rejected as soon as they are received.
+///
+#[derive(Clone, Debug)]
+pub struct HeaderSync<N: Network> {
+    /// The latest block height of the synced chain.
+    current_height: u32,
+    /// The latest block hash of the synced chain.
+    current_hash: N::BlockHash,
+    /// The block hashes of the synced chain.
+    hashes: HashMap<u32, N::BlockHash>,
+    /// The block headers of the synced chain.
+    headers: HashMap<u32, BlockHeader<N>>,
+    /// The block headers received ahead of the synced chain.
+    pending: BTreeMap<u32, PendingHeader<N>>,
+    /// The maximum number of block headers received ahead of the synced chain.
+    maximum_pending: usize,
+    /// The expected block hashes at given block heights.
+    checkpoints: HashMap<u32, N::BlockHash>,
+}
+
+impl<N: Network> HeaderSync<N> {
+    /// The default maximum number of block headers received ahead of the synced chain.
+    pub const MAXIMUM_PENDING_HEADERS: usize = 4096;
+
+    /// Initializes a new instance of `HeaderSync` with the genesis block and the given checkpoints.
+    pub fn new(checkpoints: &[(u32, N::BlockHash)]) -> Result<Self> {
+        let genesis_block = N::genesis_block();
+        let height = genesis_block.height();
+
+        let checkpoints = checkpoints.iter().copied().collect::<HashMap<_, _>>();
+        if let Some(checkpoint) = checkpoints.get(&height) {
+            if *checkpoint != genesis_block.hash() {
+                return Err(anyhow!(
+                    "The checkpoint at height {} does not match the genesis block",
+                    height
+                ));
+            }
+        }
+
+        let mut sync = Self {
+            current_height: height,
+            current_hash: genesis_block.hash(),
+            hashes: Default::default(),
+            headers: Default::default(),
+            pending: Default::default(),
+            maximum_pending: Self::MAXIMUM_PENDING_HEADERS,
+            checkpoints,
+        };
+
+        sync.hashes.insert(height, genesis_block.hash());
+        sync.headers.insert(height, genesis_block.header().clone());
+
+        Ok(sync)
+    }
+
+    /// Sets the maximum number of block headers received ahead of the synced chain.
+    pub fn with_maximum_pending(mut self, maximum_pending: usize) -> Self {
+        self.maximum_pending = maximum_pending;
+        self
+    }
+
+    /// Returns the latest block height of the synced chain.
+    pub fn latest_block_height(&self) -> u32 {
+        self.current_height
+    }
+
+    /// Returns the latest block hash of the synced chain.
+    pub fn latest_block_hash(&self) -> N::BlockHash {
+        self.current_hash
+    }
+
+    /// Returns the block hash of the synced chain given the block height.
+    pub fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
+        match self.hashes.get(&height) {
+            Some(block_hash) => Ok(*block_hash),
+            None => Err(anyhow!("Missing block hash for height {}", height)),
+        }
+    }
+
+    /// Returns the block header of the synced chain given the block height.
+    pub fn get_block_header(&self, height: u32) -> Result<&BlockHeader<N>> {
+        match self.headers.get(&height) {
+            Some(header) => Ok(header),
+            None => Err(anyhow!("Missing block header for height {}", height)),
+        }
+    }
+
+    /// Returns the number of block headers received ahead of the synced chain.
+    pub fn num_pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns `true` if a block header for the given height has been received.
+    pub fn contains_height(&self, height: u32) -> bool {
+        self.headers.contains_key(&height) || self.pending.contains_key(&height)
+    }
+
+    /// Adds the given block header, which may be ahead of the synced chain.
+    pub fn add_header(&mut self, previous_block_hash: N::BlockHash, header: BlockHeader<N>) -> Result<()> {
+        // Ensure the block header is not already synced.
+        let height = header.height();
+        if height <= self.current_height {
+            return Err(anyhow!("The given block header at height {} is already synced", height));
+        }
+
+        // Ensure the block header does not conflict with a pending block header.
+        let block_hash: N::BlockHash = N::block_hash_crh()
+            .hash(&to_bytes_le![previous_block_hash, header.to_header_root()?]?)?
+            .into();
+        if let Some(pending) = self.pending.get(&height) {
+            return match pending.block_hash == block_hash {
+                true => Ok(()),
+                false => Err(anyhow!(
+                    "A different block header at height {} is already pending",
+                    height
+                )),
+            };
+        }
+
+        // Ensure the block hash and the previous block hash match the checkpoints.
+        if let Some(checkpoint) = self.checkpoints.get(&height) {
+            if *checkpoint != block_hash {
+                return Err(anyhow!(
+                    "The given block header does not match the checkpoint at height {}",
+                    height
+                ));
+            }
+        }
+        if let Some(checkpoint) = self.checkpoints.get(&(height - 1)) {
+            if *checkpoint != previous_block_hash {
+                return Err(anyhow!(
+                    "The given previous block hash does not match the checkpoint at height {}",
+                    height - 1
+                ));
+            }
+        }
+
+        // Ensure the pending block headers do not exceed the maximum, by dropping the block
+        // header that is furthest ahead of the synced chain, or the given block header if none is further.
+        if self.pending.len() >= self.maximum_pending {
+            match self.pending.keys().next_back().copied() {
+                Some(furthest_height) if furthest_height > height => {
+                    self.pending.remove(&furthest_height);
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "Dropping the block header at height {}, as the pending block headers are full",
+                        height
+                    ));
+                }
+            }
+        }
+
+        self.pending.insert(
+            height,
+            PendingHeader {
+                previous_block_hash,
+                block_hash,
+                header,
+                is_verified: false,
+            },
+        );
+
+        Ok(())
+    }
+
+    ///
+    /// Verifies the pending block headers and their PoSW proofs in parallel.
+    ///
+    /// Invalid block headers are discarded, and their heights are returned,
+    /// so that they may be requested again from another peer.
+    ///
+    pub fn verify_pending(&mut self) -> Vec<u32> {
+        let invalid_heights = self
+            .pending
+            .par_iter_mut()
+            .filter(|(_, pending)| !pending.is_verified)
+            .filter_map(|(height, pending)| match pending.header.is_valid() {
+                true => {
+                    pending.is_verified = true;
+                    None
+                }
+                false => Some(*height),
+            })
+            .collect::<Vec<_>>();
+
+        for height in &invalid_heights {
+            self.pending.remove(height);
+        }
+
+        invalid_heights
+    }
+
+    ///
+    /// Links the verified pending block headers that are contiguous with the synced chain,
+    /// and returns the latest block height of the synced chain.
+    ///
+    /// If a block header does not link to the synced chain, it is discarded and an error is returned.
+    ///
+    pub fn advance(&mut self) -> Result<u32> {
+        while let Some(pending) = self.pending.get(&(self.current_height + 1)) {
+            if !pending.is_verified {
+                break;
+            }
+
+            // Remove the block header from the pending block headers, as it is
+            // either linked to the synced chain or discarded.
+            let pending = match self.pending.remove(&(self.current_height + 1)) {
+                Some(pending) => pending,
+                None => break,
+            };
+            self.check_next(&pending)?;
+
+            let height = pending.header.height();
+            self.current_height = height;
+            self.current_hash = pending.block_hash;
+            self.hashes.insert(height, pending.block_hash);
+            self.headers.insert(height, pending.header);
+        }
+
+        Ok(self.current_height)
+    }
+
+    /// Returns the block header that anchors the difficulty target of the given block height.
+    fn get_difficulty_anchor(&self, block_height: u32) -> Result<&BlockHeader<N>> {
+        match N::UPGRADE_SCHEDULE.get(block_height).difficulty_anchor {
+            DifficultyAnchor::PreviousBlock => self.get_block_header(block_height.saturating_sub(1)),
+            DifficultyAnchor::Height(anchor_height) => self.get_block_header(anchor_height),
+        }
+    }
+
+    /// Ensures the given verified block header is a valid successor of the synced chain.
+    fn check_next(&self, pending: &PendingHeader<N>) -> Result<()> {
+        let header = &pending.header;
+        let height = header.height();
+
+        // Ensure the previous block hash is correct.
+        if self.current_hash != pending.previous_block_hash {
+            return Err(anyhow!(
+                "The block header at height {} has an incorrect previous block hash",
+                height
+            ));
+        }
+
+        // Ensure the block timestamp is within the declared time limit.
+        let now = Utc::now().timestamp();
+        if header.timestamp() > (now + N::ALEO_FUTURE_TIME_LIMIT_IN_SECS) {
+            return Err(anyhow!("The block header at height {} exceeds the time limit", height));
+        }
+
+        // Ensure the block timestamp is after the current block timestamp.
+        let current_header = self.get_block_header(self.current_height)?;
+        if header.timestamp() <= current_header.timestamp() {
+            return Err(anyhow!(
+                "The block header at height {} is before the current timestamp",
+                height
+            ));
+        }
+
+        // Ensure the expected difficulty target is met.
+        let anchor_block_header = self.get_difficulty_anchor(height)?;
+        let expected_difficulty_target =
+            Blocks::<N>::compute_difficulty_target(anchor_block_header, header.timestamp(), height);
+        if header.difficulty_target() != expected_difficulty_target {
+            return Err(anyhow!(
+                "The block header at height {} has an incorrect difficulty target. Found {}, but expected {}",
+                height,
+                header.difficulty_target(),
+                expected_difficulty_target
+            ));
+        }
+
+        // Ensure the expected cumulative weight is computed correctly.
+        let expected_cumulative_weight = current_header
+            .cumulative_weight()
+            .saturating_add((u64::MAX / expected_difficulty_target) as u128);
+        if header.cumulative_weight() != expected_cumulative_weight {
+            return Err(anyhow!(
+                "The block header at height {} has an incorrect cumulative weight. Found {}, but expected {}",
+                height,
+                header.cumulative_weight(),
+                expected_cumulative_weight
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_new() {
+        let genesis_block = Testnet2::genesis_block();
+
+        let sync = HeaderSync::<Testnet2>::new(&[(0, genesis_block.hash())]).unwrap();
+        assert_eq!(0, sync.latest_block_height());
+        assert_eq!(genesis_block.hash(), sync.latest_block_hash());
+
+        let rng = &mut thread_rng();
+        assert!(HeaderSync::<Testnet2>::new(&[(0, UniformRand::rand(rng))]).is_err());
+    }
+
+    #[test]
+    fn test_out_of_order_headers() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Mine a chain of blocks.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let mut blocks = Vec::new();
+        for _ in 0..3 {
+            ledger
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .unwrap();
+            blocks.push(ledger.latest_block().unwrap());
+        }
+
+        // Add the block headers in reverse order.
+        let mut sync = HeaderSync::<Testnet2>::new(&[(2, blocks[1].hash())]).unwrap();
+        for block in blocks.iter().rev() {
+            sync.add_header(block.previous_block_hash(), block.header().clone())
+                .unwrap();
+        }
+        assert_eq!(3, sync.num_pending());
+
+        // Ensure nothing is linked before the PoSW proofs are verified.
+        assert_eq!(0, sync.advance().unwrap());
+
+        assert!(sync.verify_pending().is_empty());
+        assert_eq!(3, sync.advance().unwrap());
+        assert_eq!(0, sync.num_pending());
+        assert_eq!(blocks[2].hash(), sync.latest_block_hash());
+        assert_eq!(blocks[0].hash(), sync.get_block_hash(1).unwrap());
+    }
+
+    #[test]
+    fn test_maximum_pending() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Mine a chain of blocks.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let mut blocks = Vec::new();
+        for _ in 0..3 {
+            ledger
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .unwrap();
+            blocks.push(ledger.latest_block().unwrap());
+        }
+
+        // Add the block headers in reverse order, with room for only two pending block headers.
+        let mut sync = HeaderSync::<Testnet2>::new(&[]).unwrap().with_maximum_pending(2);
+        for block in blocks.iter().rev() {
+            sync.add_header(block.previous_block_hash(), block.header().clone())
+                .unwrap();
+        }
+
+        // Ensure the block header furthest ahead was dropped, and is not re-added while full.
+        assert_eq!(2, sync.num_pending());
+        assert!(!sync.contains_height(3));
+        assert!(sync
+            .add_header(blocks[2].previous_block_hash(), blocks[2].header().clone())
+            .is_err());
+        assert_eq!(2, sync.num_pending());
+
+        assert!(sync.verify_pending().is_empty());
+        assert_eq!(2, sync.advance().unwrap());
+        assert_eq!(0, sync.num_pending());
+
+        // Ensure the dropped block header is accepted once there is room.
+        sync.add_header(blocks[2].previous_block_hash(), blocks[2].header().clone())
+            .unwrap();
+        assert!(sync.verify_pending().is_empty());
+        assert_eq!(3, sync.advance().unwrap());
+    }
+
+    #[test]
+    fn test_checkpoint_mismatch() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let block = ledger.latest_block().unwrap();
+
+        // Ensure a block header that conflicts with a checkpoint is rejected upfront.
+        let mut sync = HeaderSync::<Testnet2>::new(&[(1, UniformRand::rand(rng))]).unwrap();
+        assert!(sync
+            .add_header(block.previous_block_hash(), block.header().clone())
+            .is_err());
+        assert_eq!(0, sync.num_pending());
+
+        // Ensure a block header with an incorrect previous block hash is discarded on linkage.
+        let mut sync = HeaderSync::<Testnet2>::new(&[]).unwrap();
+        sync.add_header(UniformRand::rand(rng), block.header().clone()).unwrap();
+        assert!(sync.verify_pending().is_empty());
+        assert!(sync.advance().is_err());
+        assert_eq!(0, sync.latest_block_height());
+        assert_eq!(0, sync.num_pending());
+    }
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::prelude::*;
+use snarkvm_algorithms::traits::SNARK;
 
 use anyhow::{anyhow, Result};
 use chrono::Utc;
@@ -81,11 +82,41 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.latest_block()
     }
 
+    ///
+    /// Returns the total supply after the block with the given height.
+    ///
+    pub fn total_supply_at(&self, height: u32) -> Result<AleoAmount> {
+        self.canon_blocks.total_supply_at(height)
+    }
+
+    ///
+    /// Returns the supply audit of the canon chain up to and including the block with the given height.
+    ///
+    pub fn audit_supply(&self, height: u32) -> Result<SupplyAudit> {
+        self.canon_blocks.audit_supply(height)
+    }
+
+    /// Returns the block locator of the canon chain.
+    pub fn get_block_locator(&self) -> Result<BlockLocator<N>> {
+        self.canon_blocks.get_block_locator()
+    }
+
+    /// Returns the block height and block hash of the latest block in the given block locator
+    /// that is also in the canon chain.
+    pub fn find_common_ancestor(&self, block_locator: &BlockLocator<N>) -> Result<(u32, N::BlockHash)> {
+        self.canon_blocks.find_common_ancestor(block_locator)
+    }
+
     /// Returns `true` if the given ledger root exists on the canon chain.
     pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
         self.canon_blocks.contains_ledger_root(ledger_root)
     }
 
+    /// Returns `true` if the given ledger root may be referenced by a new transaction.
+    pub fn is_acceptable_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+        self.canon_blocks.is_acceptable_root(ledger_root)
+    }
+
     /// Returns `true` if the given block hash exists on the canon chain.
     pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
         self.canon_blocks.contains_block_hash(block_hash)
@@ -96,12 +127,26 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
+    /// Returns `true` if the given serial number exists on the canon chain.
+    pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
+        self.canon_blocks.contains_serial_number(serial_number)
+    }
+
     /// Adds the given canon block, if it is well-formed and does not already exist.
     /// Note: This method requires blocks to be added in order of canon block height.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
         // Attempt to insert the block into canon.
         self.canon_blocks.add_next(block)?;
 
+        // Evict the unconfirmed transactions whose ledger root is no longer acceptable.
+        let expired_transactions = self
+            .memory_pool
+            .transactions()
+            .into_iter()
+            .filter(|transaction| !self.canon_blocks.is_acceptable_root(&transaction.ledger_root()))
+            .collect::<Vec<_>>();
+        self.memory_pool.remove_transactions(&expired_transactions);
+
         Ok(())
     }
 
@@ -120,9 +165,21 @@ impl<N: Network> Ledger<N> {
 
     /// Adds the given unconfirmed transaction to the memory pool.
     pub fn add_unconfirmed_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
-        // Ensure the transaction contains ledger roots from the canon chain.
-        if !self.canon_blocks.contains_ledger_root(&transaction.ledger_root()) {
-            return Err(anyhow!("Transaction references a non-existent ledger root"));
+        // Ensure the transaction references a recent ledger root from the canon chain.
+        if !self.canon_blocks.is_acceptable_root(&transaction.ledger_root()) {
+            return Err(anyhow!("Transaction references an unknown or expired ledger root"));
+        }
+
+        // Ensure the public data of the transaction is accepted in the next block.
+        if !transaction.is_data_accepted_at(self.latest_block_height() + 1) {
+            return Err(anyhow!("Transaction carries public data before it is accepted"));
+        }
+
+        // Ensure the transaction may be included in a next block mined at the current time.
+        if !transaction.is_unlocked_at(self.latest_block_height() + 1, Utc::now().timestamp()) {
+            return Err(anyhow!(
+                "Transaction consumes records that are locked at the next block"
+            ));
         }
 
         // Ensure the transaction does not contain serial numbers already in the canon chain.
@@ -161,16 +218,9 @@ impl<N: Network> Ledger<N> {
         let block_timestamp = std::cmp::max(Utc::now().timestamp(), self.latest_block_timestamp()?.saturating_add(1));
 
         // Compute the block difficulty target.
-        let difficulty_target = if N::NETWORK_ID == 2 && block_height <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-            Blocks::<N>::compute_difficulty_target(self.latest_block()?.header(), block_timestamp, block_height)
-        } else if N::NETWORK_ID == 2 {
-            let anchor_block_header = self
-                .canon_blocks
-                .get_block_header(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT)?;
-            Blocks::<N>::compute_difficulty_target(anchor_block_header, block_timestamp, block_height)
-        } else {
-            Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block_timestamp, block_height)
-        };
+        let anchor_block_header = self.canon_blocks.get_difficulty_anchor(block_height)?;
+        let difficulty_target =
+            Blocks::<N>::compute_difficulty_target(anchor_block_header, block_timestamp, block_height);
 
         // Compute the cumulative weight.
         let cumulative_weight = self
@@ -181,7 +231,15 @@ impl<N: Network> Ledger<N> {
         let amount = Block::<N>::block_reward(block_height);
         let (coinbase_transaction, coinbase_record) =
             Transaction::<N>::new_coinbase(recipient, amount, is_public, rng)?;
-        let transactions = Transactions::from(&[vec![coinbase_transaction], self.memory_pool.transactions()].concat())?;
+        let unconfirmed_transactions = self
+            .memory_pool
+            .transactions()
+            .into_iter()
+            .filter(|transaction| self.canon_blocks.is_acceptable_root(&transaction.ledger_root()))
+            .filter(|transaction| transaction.is_data_accepted_at(block_height))
+            .filter(|transaction| transaction.is_unlocked_at(block_height, block_timestamp))
+            .collect();
+        let transactions = Transactions::from(&[vec![coinbase_transaction], unconfirmed_transactions].concat())?;
 
         // Retrieve the current ledger root.
         let previous_ledger_root = self.canon_blocks.latest_ledger_root();
@@ -221,14 +279,56 @@ impl<N: Network> Ledger<N> {
     pub fn to_ledger_proof(&self, commitment: N::Commitment) -> Result<LedgerProof<N>> {
         self.canon_blocks.to_ledger_proof(commitment)
     }
+
+    ///
+    /// Returns the archive proof for the given transaction ID with the current ledger root.
+    ///
+    pub fn archive_proof(&self, transaction_id: N::TransactionID) -> Result<ArchiveProof<N>> {
+        self.canon_blocks.to_archive_proof(transaction_id)
+    }
+
+    ///
+    /// Returns `true` if the given archive proof is with respect to a ledger root on the canon chain.
+    ///
+    pub fn verify_archive_proof(&self, archive_proof: &ArchiveProof<N>) -> bool {
+        self.canon_blocks.verify_archive_proof(archive_proof)
+    }
+
+    ///
+    /// Returns `true` if the given balance proof is valid with respect to a ledger root on the canon chain,
+    /// and none of its records have been spent.
+    ///
+    pub fn verify_balance_proof(
+        &self,
+        balance_proof: &BalanceProof<N>,
+        verifying_key: &<BalanceSNARK<N> as SNARK>::VerifyingKey,
+    ) -> bool {
+        // Ensure the ledger root exists on the canon chain.
+        if !self.contains_ledger_root(&balance_proof.ledger_root()) {
+            eprintln!("Balance proof references an unknown ledger root");
+            return false;
+        }
+
+        // Ensure the records are unspent.
+        if balance_proof
+            .serial_numbers()
+            .iter()
+            .any(|serial_number| self.contains_serial_number(serial_number))
+        {
+            eprintln!("Balance proof contains a spent record");
+            return false;
+        }
+
+        balance_proof.verify(verifying_key)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet1::Testnet1, testnet2::Testnet2};
+    use crate::{testnet1::Testnet1, testnet2::Testnet2, testnet2_dev::Testnet2Dev};
 
-    use rand::thread_rng;
+    use rand::{thread_rng, Rng};
 
     #[test]
     fn test_new() {
@@ -239,6 +339,62 @@ mod tests {
         assert_eq!(0, ledger.latest_block_height());
     }
 
+    #[test]
+    fn test_is_acceptable_root() {
+        let rng = &mut thread_rng();
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let genesis_ledger_root = ledger.latest_ledger_root();
+        let empty_ledger_root = LedgerTree::<Testnet2>::new().unwrap().root();
+        assert!(ledger.is_acceptable_root(&genesis_ledger_root));
+        assert!(ledger.is_acceptable_root(&empty_ledger_root));
+
+        // Ensure past ledger roots within the recency window remain acceptable.
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        assert!(ledger.is_acceptable_root(&ledger.latest_ledger_root()));
+        assert!(ledger.is_acceptable_root(&genesis_ledger_root));
+        assert!(ledger.is_acceptable_root(&empty_ledger_root));
+
+        // Ensure an unknown ledger root is not acceptable.
+        let unknown_ledger_root =
+            <Testnet2 as Network>::LedgerRoot::from(rng.gen::<<Testnet2 as Network>::InnerScalarField>());
+        assert!(!ledger.is_acceptable_root(&unknown_ledger_root));
+    }
+
+    #[test]
+    fn test_coinbase_maturity() {
+        let rng = &mut thread_rng();
+        let mut ledger = Ledger::<Testnet2Dev>::new().unwrap();
+        let recipient = Account::<Testnet2Dev>::new(rng);
+        assert_eq!(2, Testnet2Dev::COINBASE_MATURITY);
+
+        // Ensure the ledger root containing the genesis coinbase record is not acceptable, and cannot be proven.
+        let genesis_commitment = *Testnet2Dev::genesis_block().commitments().next().unwrap();
+        let genesis_ledger_root = ledger.latest_ledger_root();
+        let empty_ledger_root = LedgerTree::<Testnet2Dev>::new().unwrap().root();
+        assert!(!ledger.is_acceptable_root(&genesis_ledger_root));
+        assert!(ledger.is_acceptable_root(&empty_ledger_root));
+        assert!(ledger.to_ledger_proof(genesis_commitment).is_err());
+
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        assert!(!ledger.is_acceptable_root(&genesis_ledger_root));
+        assert!(ledger.to_ledger_proof(genesis_commitment).is_err());
+
+        // Ensure the genesis coinbase record is spendable once it is mature, while the latest ledger root is not.
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        assert!(ledger.is_acceptable_root(&genesis_ledger_root));
+        assert!(!ledger.is_acceptable_root(&ledger.latest_ledger_root()));
+        let ledger_proof = ledger.to_ledger_proof(genesis_commitment).unwrap();
+        assert_eq!(genesis_ledger_root, ledger_proof.ledger_root());
+    }
+
     #[test]
     fn test_mine_next_block() {
         let rng = &mut thread_rng();
@@ -263,4 +419,33 @@ mod tests {
             assert_eq!(1, ledger.latest_block_height());
         }
     }
+
+    #[test]
+    fn test_total_supply_at() {
+        let rng = &mut thread_rng();
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Ensure the genesis block issues the starting supply.
+        let starting_supply = Testnet2::scheduled_supply_at(0);
+        assert_eq!(starting_supply, ledger.total_supply_at(0).unwrap());
+        assert!(ledger.total_supply_at(1).is_err());
+
+        // Ensure the next block issues its block reward.
+        ledger
+            .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        assert_eq!(starting_supply, ledger.total_supply_at(0).unwrap());
+        assert_eq!(Testnet2::scheduled_supply_at(1), ledger.total_supply_at(1).unwrap());
+
+        // Ensure the audit recomputes the same total supply, without discrepancies.
+        let audit = ledger.audit_supply(1).unwrap();
+        assert!(audit.is_consistent());
+        assert_eq!(ledger.total_supply_at(1).unwrap(), audit.total_supply());
+        assert_eq!(Testnet2::scheduled_supply_at(1), audit.scheduled_supply());
+        assert_eq!(AleoAmount::ZERO, audit.unissued_supply());
+
+        // Ensure an audit beyond the latest block fails.
+        assert!(ledger.audit_supply(2).is_err());
+    }
 }
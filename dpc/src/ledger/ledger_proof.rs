@@ -141,8 +141,7 @@ impl<N: Network> FromBytes for LedgerProof<N> {
         let ledger_root_inclusion_proof = FromBytes::read_le(&mut reader)?;
         let record_proof = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self::new(ledger_root, ledger_root_inclusion_proof, record_proof)
-            .expect("Failed to deserialize a ledger inclusion proof"))
+        Ok(Self::new(ledger_root, ledger_root_inclusion_proof, record_proof).map_err(BlockError::from)?)
     }
 }
 
@@ -16,7 +16,7 @@
 
 use crate::prelude::*;
 use snarkvm_algorithms::{
-    merkle_tree::{MerklePath, MerkleTree},
+    merkle_tree::{MerkleMigrationProof, MerklePath, MerkleTree},
     prelude::*,
 };
 use snarkvm_utilities::has_duplicates;
@@ -128,6 +128,57 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
     }
 }
 
+impl<N: Network> LedgerTree<N> {
+    /// Removes the given block hash from the tree, if it is the last block hash in the tree.
+    pub(crate) fn remove_last(&mut self, block_hash: &N::BlockHash) -> Result<()> {
+        // Ensure the block hash is the last leaf in the tree.
+        match self.get_block_hash_index(block_hash) {
+            Some(index) if *index + 1 == self.current_index => (),
+            Some(_) => return Err(anyhow!("{} is not the last block hash in the ledger tree", block_hash)),
+            None => return Err(MerkleError::MissingLeaf(format!("{}", block_hash)).into()),
+        }
+
+        let last_index = self.current_index as usize - 1;
+        self.tree = Arc::new(self.tree.rebuild::<N::BlockHash>(last_index, &[])?);
+        self.block_hashes.remove(block_hash);
+        self.current_index -= 1;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the ledger tree as a Merkle tree of the given deeper parameters,
+    /// and a proof that its root is the migration of the ledger root.
+    ///
+    pub fn migrate<P: MerkleParameters<H = N::LedgerRootCRH>>(
+        &self,
+        parameters: Arc<P>,
+    ) -> Result<(MerkleTree<P>, MerkleMigrationProof<P>)> {
+        Ok(self.tree.migrate(parameters)?)
+    }
+
+    ///
+    /// Returns the root of the ledger tree with the given ledger root, when migrated to the given depth.
+    ///
+    /// The ledger tree is padded to its depth with empty siblings,
+    /// so the migrated root only depends on the ledger root.
+    ///
+    pub fn migrate_root(ledger_root: N::LedgerRoot, depth: usize) -> Result<N::LedgerRoot> {
+        let ledger_tree_depth = <N::LedgerRootParameters as MerkleParameters>::DEPTH;
+        if depth < ledger_tree_depth {
+            return Err(MerkleError::InvalidTreeDepth(ledger_tree_depth, depth).into());
+        }
+
+        let parameters = N::ledger_root_parameters();
+        let empty_hash = N::ledger_root_empty_hash();
+        let mut current_hash = *ledger_root;
+        for _ in ledger_tree_depth..depth {
+            current_hash = parameters.hash_inner_node(&current_hash, empty_hash)?;
+        }
+        Ok(current_hash.into())
+    }
+}
+
 impl<N: Network> Default for LedgerTree<N> {
     fn default() -> Self {
         Self::new().unwrap()
@@ -14,9 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod archive_proof;
+pub use archive_proof::*;
+
+pub mod block_diff;
+pub use block_diff::*;
+
+pub mod block_locator;
+pub use block_locator::*;
+
 pub mod blocks;
 pub use blocks::*;
 
+pub mod header_sync;
+pub use header_sync::*;
+
 pub mod ledger;
 pub use ledger::*;
 
@@ -28,3 +40,9 @@ pub use ledger_tree::*;
 
 pub mod memory_pool;
 pub use memory_pool::*;
+
+pub mod supply_audit;
+pub use supply_audit::*;
+
+pub mod verification_executor;
+pub use verification_executor::*;
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use std::fmt;
+
+/// A discrepancy in the supply of a block, as found by a supply audit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SupplyDiscrepancy {
+    /// The block issues more supply than its block reward.
+    ExcessIssuance {
+        height: u32,
+        issued_supply: AleoAmount,
+        block_reward: AleoAmount,
+    },
+    /// The recorded total supply after the block differs from the total supply recomputed from genesis.
+    TotalSupplyMismatch {
+        height: u32,
+        recorded_supply: AleoAmount,
+        recomputed_supply: AleoAmount,
+    },
+}
+
+impl SupplyDiscrepancy {
+    /// Returns the height of the block with the discrepancy.
+    pub fn height(&self) -> u32 {
+        match self {
+            Self::ExcessIssuance { height, .. } | Self::TotalSupplyMismatch { height, .. } => *height,
+        }
+    }
+}
+
+impl fmt::Display for SupplyDiscrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ExcessIssuance {
+                height,
+                issued_supply,
+                block_reward,
+            } => write!(
+                f,
+                "Block {} issues {}, which exceeds its block reward of {}",
+                height, issued_supply, block_reward
+            ),
+            Self::TotalSupplyMismatch {
+                height,
+                recorded_supply,
+                recomputed_supply,
+            } => write!(
+                f,
+                "Block {} records a total supply of {}, but the recomputed total supply is {}",
+                height, recorded_supply, recomputed_supply
+            ),
+        }
+    }
+}
+
+///
+/// The result of recomputing the total supply from the genesis block up to a given block height.
+///
+/// The total supply may be less than the scheduled supply, as a coinbase transaction
+/// may claim less than the block reward and transaction fees of its block.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SupplyAudit {
+    /// The height of the last audited block.
+    height: u32,
+    /// The total supply recomputed from the genesis block.
+    total_supply: AleoAmount,
+    /// The supply scheduled for issuance by the block rewards.
+    scheduled_supply: AleoAmount,
+    /// The discrepancies found in the audited blocks.
+    discrepancies: Vec<SupplyDiscrepancy>,
+}
+
+impl SupplyAudit {
+    /// Initializes a new instance of a supply audit.
+    pub fn new(
+        height: u32,
+        total_supply: AleoAmount,
+        scheduled_supply: AleoAmount,
+        discrepancies: Vec<SupplyDiscrepancy>,
+    ) -> Self {
+        Self {
+            height,
+            total_supply,
+            scheduled_supply,
+            discrepancies,
+        }
+    }
+
+    /// Returns the height of the last audited block.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the total supply recomputed from the genesis block.
+    pub fn total_supply(&self) -> AleoAmount {
+        self.total_supply
+    }
+
+    /// Returns the supply scheduled for issuance by the block rewards.
+    pub fn scheduled_supply(&self) -> AleoAmount {
+        self.scheduled_supply
+    }
+
+    /// Returns the scheduled supply that was not issued, as the coinbase transactions did not claim it.
+    pub fn unissued_supply(&self) -> AleoAmount {
+        self.scheduled_supply.sub(self.total_supply)
+    }
+
+    /// Returns the discrepancies found in the audited blocks.
+    pub fn discrepancies(&self) -> &[SupplyDiscrepancy] {
+        &self.discrepancies
+    }
+
+    /// Returns `true` if no discrepancies were found in the audited blocks.
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
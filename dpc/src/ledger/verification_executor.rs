@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_algorithms::{Prepare, SNARK};
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use rand::thread_rng;
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+///
+/// Verifies the proofs of many transactions and block headers on a dedicated thread pool.
+///
+/// The transition proofs of all given transactions are flattened into a single queue,
+/// and verified in parallel against an outer circuit verifying key that is prepared once
+/// for the lifetime of the executor. Each thread batch-verifies its share of the proofs,
+/// which amortizes the final exponentiation of the pairing checks across the batch.
+/// At most `max_in_flight` proofs are verified at a time, across all callers sharing
+/// the executor; callers beyond this bound block until proofs in flight have been verified.
+///
+pub struct VerificationExecutor<N: Network> {
+    /// The thread pool used to verify proofs.
+    thread_pool: ThreadPool,
+    /// The maximum number of proofs verified at a time.
+    max_in_flight: usize,
+    /// The number of proofs currently being verified.
+    in_flight: Mutex<usize>,
+    /// Notifies blocked callers when proofs in flight have been verified.
+    released: Condvar,
+    /// The prepared outer circuit verifying key.
+    outer_verifying_key: <N::OuterSNARK as SNARK>::PreparedVerifyingKey,
+}
+
+impl<N: Network> VerificationExecutor<N>
+where
+    <N::OuterSNARK as SNARK>::PreparedVerifyingKey: Send + Sync,
+{
+    /// Initializes a new verification executor with the given number of threads,
+    /// and the given maximum number of proofs verified at a time.
+    pub fn new(num_threads: usize, max_in_flight: usize) -> Result<Self> {
+        if num_threads == 0 {
+            return Err(anyhow!("Verification executor requires at least 1 thread"));
+        }
+        if max_in_flight == 0 {
+            return Err(anyhow!("Verification executor requires at least 1 proof in flight"));
+        }
+
+        let thread_pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|index| format!("verification-executor-{}", index))
+            .build()?;
+
+        Ok(Self {
+            thread_pool,
+            max_in_flight,
+            in_flight: Mutex::new(0),
+            released: Condvar::new(),
+            outer_verifying_key: N::outer_verifying_key().prepare(),
+        })
+    }
+
+    /// Returns the number of threads in the executor.
+    pub fn num_threads(&self) -> usize {
+        self.thread_pool.current_num_threads()
+    }
+
+    /// Returns the maximum number of proofs verified at a time.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    ///
+    /// Returns `true` for each given transaction that is valid, as defined by `Transaction::is_valid`.
+    ///
+    /// The transactions are checked to be well-formed first, and the transition proofs
+    /// of the well-formed transactions are then verified as one batch.
+    ///
+    pub fn verify_transactions(&self, transactions: &[Transaction<N>]) -> Vec<bool> {
+        // Compute the local transitions roots of each transaction, if it is well-formed.
+        let local_transitions_roots: Vec<_> = self.thread_pool.install(|| {
+            transactions
+                .par_iter()
+                .map(Transaction::to_local_transitions_roots)
+                .collect()
+        });
+
+        // Collect the transitions of all well-formed transactions.
+        let mut is_valid: Vec<bool> = local_transitions_roots.iter().map(Option::is_some).collect();
+        let transitions: Vec<_> = local_transitions_roots
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, roots)| roots.map(|roots| (index, roots)))
+            .flat_map(|(index, roots)| {
+                transactions[index]
+                    .transitions()
+                    .iter()
+                    .zip_eq(roots)
+                    .map(move |(transition, root)| (index, transition, root))
+            })
+            .collect();
+
+        // Verify the transition proofs, at most `max_in_flight` at a time.
+        for batch in transitions.chunks(self.max_in_flight) {
+            // Skip the proofs of transactions that were found to be invalid in a previous batch.
+            let batch: Vec<_> = batch.iter().filter(|(index, ..)| is_valid[*index]).collect();
+
+            let _permit = self.acquire(batch.len());
+            let num_proofs_per_thread = (batch.len() + self.num_threads() - 1) / self.num_threads();
+            let results: Vec<bool> = self.thread_pool.install(|| {
+                batch
+                    .par_chunks(num_proofs_per_thread.max(1))
+                    .flat_map_iter(|transitions| self.verify_transitions(transactions, transitions))
+                    .collect()
+            });
+
+            for ((index, ..), result) in batch.iter().zip_eq(results) {
+                if !result {
+                    is_valid[*index] = false;
+                }
+            }
+        }
+
+        is_valid
+    }
+
+    ///
+    /// Returns `true` for each given transition whose proof is valid.
+    ///
+    /// The proofs are batch-verified first, so that valid batches incur a single final exponentiation.
+    /// If the batch is invalid, each proof is verified individually to determine which proofs are invalid.
+    ///
+    fn verify_transitions(
+        &self,
+        transactions: &[Transaction<N>],
+        transitions: &[&(usize, &Transition<N>, N::TransactionID)],
+    ) -> Vec<bool> {
+        // Compute the public inputs of each transition proof.
+        let public_variables: Result<Vec<_>> = transitions
+            .iter()
+            .map(|(index, transition, local_transitions_root)| {
+                let transaction = &transactions[*index];
+                transition.to_outer_public_variables(
+                    transaction.inner_circuit_id(),
+                    transaction.ledger_root(),
+                    *local_transitions_root,
+                )
+            })
+            .collect();
+
+        // Batch-verify the transition proofs.
+        if let Ok(public_variables) = public_variables {
+            let inputs_and_proofs: Vec<_> = public_variables
+                .iter()
+                .zip_eq(transitions)
+                .map(|(public_variables, (_, transition, _))| (public_variables, &**transition.proof()))
+                .collect();
+
+            if let Ok(true) =
+                N::OuterSNARK::verify_batch_prepared(&self.outer_verifying_key, &inputs_and_proofs, &mut thread_rng())
+            {
+                return vec![true; transitions.len()];
+            }
+        }
+
+        // Verify each transition proof individually.
+        transitions
+            .iter()
+            .map(|(index, transition, local_transitions_root)| {
+                let transaction = &transactions[*index];
+                transition.verify_prepared(
+                    &self.outer_verifying_key,
+                    transaction.inner_circuit_id(),
+                    transaction.ledger_root(),
+                    *local_transitions_root,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns `true` for each given block header that is valid, as defined by `BlockHeader::is_valid`.
+    ///
+    pub fn verify_block_headers(&self, block_headers: &[BlockHeader<N>]) -> Vec<bool> {
+        block_headers
+            .chunks(self.max_in_flight)
+            .flat_map(|batch| self.verify_batch(batch, BlockHeader::is_valid))
+            .collect()
+    }
+
+    /// Verifies the given batch on the thread pool, once the batch is within the in-flight bound.
+    fn verify_batch<T: Sync, F: Fn(&T) -> bool + Send + Sync>(&self, batch: &[T], verify: F) -> Vec<bool> {
+        let _permit = self.acquire(batch.len());
+        self.thread_pool.install(|| batch.par_iter().map(verify).collect())
+    }
+
+    /// Blocks until the given number of proofs may be verified, and returns a permit that
+    /// releases them when dropped.
+    fn acquire(&self, num_proofs: usize) -> InFlightPermit<'_, N> {
+        debug_assert!(num_proofs <= self.max_in_flight);
+
+        let mut in_flight = self.lock_in_flight();
+        while *in_flight + num_proofs > self.max_in_flight {
+            in_flight = self.released.wait(in_flight).unwrap_or_else(|error| error.into_inner());
+        }
+        *in_flight += num_proofs;
+
+        InFlightPermit {
+            executor: self,
+            num_proofs,
+        }
+    }
+
+    fn lock_in_flight(&self) -> MutexGuard<'_, usize> {
+        self.in_flight.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+/// A reservation of proofs in flight, released when dropped.
+struct InFlightPermit<'a, N: Network> {
+    executor: &'a VerificationExecutor<N>,
+    num_proofs: usize,
+}
+
+impl<'a, N: Network> Drop for InFlightPermit<'a, N> {
+    fn drop(&mut self) {
+        let mut in_flight = self
+            .executor
+            .in_flight
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        *in_flight -= self.num_proofs;
+        self.executor.released.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_new() {
+        assert!(VerificationExecutor::<Testnet2>::new(0, 1).is_err());
+        assert!(VerificationExecutor::<Testnet2>::new(1, 0).is_err());
+
+        let executor = VerificationExecutor::<Testnet2>::new(2, 4).unwrap();
+        assert_eq!(2, executor.num_threads());
+        assert_eq!(4, executor.max_in_flight());
+    }
+
+    #[test]
+    fn test_verify_transactions() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let (first, _) = Transaction::<Testnet2>::new_coinbase(account.address(), AleoAmount(1), true, rng).unwrap();
+        let (second, _) = Transaction::<Testnet2>::new_coinbase(account.address(), AleoAmount(2), true, rng).unwrap();
+
+        let transactions = vec![first, second.clone(), second];
+        let expected = vec![true; 3];
+        assert_eq!(
+            expected,
+            transactions.iter().map(Transaction::is_valid).collect::<Vec<_>>()
+        );
+
+        // Ensure the results are independent of the batch size and number of concurrent callers.
+        let executor = Arc::new(VerificationExecutor::<Testnet2>::new(2, 1).unwrap());
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let executor = executor.clone();
+                let transactions = transactions.clone();
+                std::thread::spawn(move || executor.verify_transactions(&transactions))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(expected, handle.join().unwrap());
+        }
+        assert_eq!(0, *executor.lock_in_flight());
+
+        // Ensure the transition proofs are batch-verified, within and across threads.
+        for num_threads in 1..=2 {
+            let executor = VerificationExecutor::<Testnet2>::new(num_threads, 4).unwrap();
+            assert_eq!(expected, executor.verify_transactions(&transactions));
+        }
+    }
+
+    #[test]
+    fn test_verify_block_headers() {
+        let genesis_header = Testnet2::genesis_block().header().clone();
+
+        let executor = VerificationExecutor::<Testnet2>::new(2, 2).unwrap();
+        assert_eq!(vec![true; 3], executor.verify_block_headers(&vec![genesis_header; 3]));
+        assert!(executor.verify_block_headers(&[]).is_empty());
+    }
+}
@@ -40,6 +40,9 @@ pub use circuits::*;
 pub mod errors;
 pub use errors::*;
 
+#[cfg(any(test, feature = "integration"))]
+pub mod integration;
+
 pub mod ledger;
 pub use ledger::*;
 
@@ -0,0 +1,283 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::{any::Any, fmt::Debug};
+
+///
+/// A network-agnostic view of a transaction, for tools that handle several networks at once.
+///
+/// Identifiers are returned in their bech32m string representation, and the underlying
+/// `Transaction<N>` may be recovered with `downcast_ref`.
+///
+pub trait AnyTransaction: Any + Debug + Send + Sync {
+    /// Returns the ID of the network of the transaction.
+    fn network_id(&self) -> u16;
+    /// Returns the name of the network of the transaction.
+    fn network_name(&self) -> &'static str;
+    /// Returns the transaction ID.
+    fn transaction_id(&self) -> String;
+    /// Returns the ledger root used to prove inclusion of the input records.
+    fn ledger_root(&self) -> String;
+    /// Returns the transition IDs.
+    fn transition_ids(&self) -> Vec<String>;
+    /// Returns the serial numbers.
+    fn serial_numbers(&self) -> Vec<String>;
+    /// Returns the commitments.
+    fn commitments(&self) -> Vec<String>;
+    /// Returns the value balance.
+    fn value_balance(&self) -> AleoAmount;
+    /// Returns `true` if the transaction is well-formed and its proofs are valid.
+    fn is_valid(&self) -> bool;
+    /// Returns the transaction in little-endian bytes.
+    fn to_bytes_le(&self) -> Result<Vec<u8>>;
+    /// Returns the transaction as `Any`, for downcasting.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl dyn AnyTransaction {
+    /// Returns `true` if the transaction belongs to the network `N`.
+    pub fn is<N: Network>(&self) -> bool {
+        self.as_any().is::<Transaction<N>>()
+    }
+
+    /// Returns the underlying transaction, if it belongs to the network `N`.
+    pub fn downcast_ref<N: Network>(&self) -> Option<&Transaction<N>> {
+        self.as_any().downcast_ref::<Transaction<N>>()
+    }
+}
+
+impl<N: Network> AnyTransaction for Transaction<N> {
+    fn network_id(&self) -> u16 {
+        N::NETWORK_ID
+    }
+
+    fn network_name(&self) -> &'static str {
+        N::NETWORK_NAME
+    }
+
+    fn transaction_id(&self) -> String {
+        Transaction::transaction_id(self).to_string()
+    }
+
+    fn ledger_root(&self) -> String {
+        Transaction::ledger_root(self).to_string()
+    }
+
+    fn transition_ids(&self) -> Vec<String> {
+        Transaction::transition_ids(self).map(|id| id.to_string()).collect()
+    }
+
+    fn serial_numbers(&self) -> Vec<String> {
+        Transaction::serial_numbers(self).map(ToString::to_string).collect()
+    }
+
+    fn commitments(&self) -> Vec<String> {
+        Transaction::commitments(self).map(ToString::to_string).collect()
+    }
+
+    fn value_balance(&self) -> AleoAmount {
+        Transaction::value_balance(self)
+    }
+
+    fn is_valid(&self) -> bool {
+        Transaction::is_valid(self)
+    }
+
+    fn to_bytes_le(&self) -> Result<Vec<u8>> {
+        ToBytes::to_bytes_le(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+///
+/// A network-agnostic view of a block, for tools that handle several networks at once.
+///
+/// Identifiers are returned in their bech32m string representation, and the underlying
+/// `Block<N>` may be recovered with `downcast_ref`.
+///
+pub trait AnyBlock: Any + Debug + Send + Sync {
+    /// Returns the ID of the network of the block.
+    fn network_id(&self) -> u16;
+    /// Returns the name of the network of the block.
+    fn network_name(&self) -> &'static str;
+    /// Returns the block hash.
+    fn hash(&self) -> String;
+    /// Returns the previous block hash.
+    fn previous_block_hash(&self) -> String;
+    /// Returns the block height.
+    fn height(&self) -> u32;
+    /// Returns the block timestamp.
+    fn timestamp(&self) -> i64;
+    /// Returns the block difficulty target.
+    fn difficulty_target(&self) -> u64;
+    /// Returns the cumulative weight up to this block (inclusive).
+    fn cumulative_weight(&self) -> u128;
+    /// Returns the transactions in the block.
+    fn transactions(&self) -> Vec<&dyn AnyTransaction>;
+    /// Returns `true` if the block is well-formed.
+    fn is_valid(&self) -> bool;
+    /// Returns the block in little-endian bytes.
+    fn to_bytes_le(&self) -> Result<Vec<u8>>;
+    /// Returns the block as `Any`, for downcasting.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl dyn AnyBlock {
+    /// Returns `true` if the block belongs to the network `N`.
+    pub fn is<N: Network>(&self) -> bool {
+        self.as_any().is::<Block<N>>()
+    }
+
+    /// Returns the underlying block, if it belongs to the network `N`.
+    pub fn downcast_ref<N: Network>(&self) -> Option<&Block<N>> {
+        self.as_any().downcast_ref::<Block<N>>()
+    }
+}
+
+impl<N: Network> AnyBlock for Block<N> {
+    fn network_id(&self) -> u16 {
+        N::NETWORK_ID
+    }
+
+    fn network_name(&self) -> &'static str {
+        N::NETWORK_NAME
+    }
+
+    fn hash(&self) -> String {
+        Block::hash(self).to_string()
+    }
+
+    fn previous_block_hash(&self) -> String {
+        Block::previous_block_hash(self).to_string()
+    }
+
+    fn height(&self) -> u32 {
+        Block::height(self)
+    }
+
+    fn timestamp(&self) -> i64 {
+        Block::timestamp(self)
+    }
+
+    fn difficulty_target(&self) -> u64 {
+        Block::difficulty_target(self)
+    }
+
+    fn cumulative_weight(&self) -> u128 {
+        Block::cumulative_weight(self)
+    }
+
+    fn transactions(&self) -> Vec<&dyn AnyTransaction> {
+        Block::transactions(self)
+            .iter()
+            .map(|transaction| transaction as &dyn AnyTransaction)
+            .collect()
+    }
+
+    fn is_valid(&self) -> bool {
+        Block::is_valid(self)
+    }
+
+    fn to_bytes_le(&self) -> Result<Vec<u8>> {
+        ToBytes::to_bytes_le(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+macro_rules! from_network_id {
+    ($network_id:expr, |$network:ident| $logic:expr) => {
+        match $network_id {
+            #[cfg(feature = "testnet1")]
+            id if id == <crate::testnet1::Testnet1 as NetworkConstants>::NETWORK_ID => {
+                type $network = crate::testnet1::Testnet1;
+                $logic
+            }
+            #[cfg(feature = "testnet2")]
+            id if id == <crate::testnet2::Testnet2 as NetworkConstants>::NETWORK_ID => {
+                type $network = crate::testnet2::Testnet2;
+                $logic
+            }
+            id => Err(anyhow!("Unsupported network ID {}", id)),
+        }
+    };
+}
+
+/// Returns the transaction of the network with the given ID, from little-endian bytes.
+pub fn transaction_from_bytes_le(network_id: u16, bytes: &[u8]) -> Result<Box<dyn AnyTransaction>> {
+    from_network_id!(network_id, |N| Ok(
+        Box::new(Transaction::<N>::from_bytes_le(bytes)?) as Box<dyn AnyTransaction>
+    ))
+}
+
+/// Returns the block of the network with the given ID, from little-endian bytes.
+pub fn block_from_bytes_le(network_id: u16, bytes: &[u8]) -> Result<Box<dyn AnyBlock>> {
+    from_network_id!(network_id, |N| Ok(
+        Box::new(Block::<N>::from_bytes_le(bytes)?) as Box<dyn AnyBlock>
+    ))
+}
+
+#[cfg(all(test, feature = "testnet1", feature = "testnet2"))]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, testnet2::Testnet2};
+
+    #[test]
+    fn test_any_block() {
+        let blocks: Vec<Box<dyn AnyBlock>> = vec![
+            Box::new(Testnet1::genesis_block().clone()),
+            Box::new(Testnet2::genesis_block().clone()),
+        ];
+
+        for block in &blocks {
+            let candidate = block_from_bytes_le(block.network_id(), &block.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(block.hash(), candidate.hash());
+            assert_eq!(0, candidate.height());
+            assert_eq!(1, candidate.transactions().len());
+            assert_eq!(block.network_id(), candidate.transactions()[0].network_id());
+        }
+
+        assert!(blocks[0].is::<Testnet1>());
+        assert!(!blocks[0].is::<Testnet2>());
+        assert_eq!(
+            Testnet2::genesis_block().hash(),
+            blocks[1].downcast_ref::<Testnet2>().unwrap().hash()
+        );
+        assert!(blocks[1].downcast_ref::<Testnet1>().is_none());
+    }
+
+    #[test]
+    fn test_any_transaction() {
+        let expected = &Testnet2::genesis_block().transactions()[0];
+        let bytes = AnyTransaction::to_bytes_le(expected).unwrap();
+
+        let candidate = transaction_from_bytes_le(Testnet2::NETWORK_ID, &bytes).unwrap();
+        assert_eq!("testnet2", candidate.network_name());
+        assert_eq!(expected.transaction_id().to_string(), candidate.transaction_id());
+        assert_eq!(expected, candidate.downcast_ref::<Testnet2>().unwrap());
+
+        assert!(transaction_from_bytes_le(0, &bytes).is_err());
+    }
+}
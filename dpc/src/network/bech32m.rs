@@ -0,0 +1,495 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Bech32mError;
+
+/// The bech32 alphabet, in order of the 5-bit values of its characters.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The generators of the BCH code of the bech32 checksum.
+const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// The number of characters in a bech32m checksum.
+pub const CHECKSUM_STRING_LENGTH: usize = 6;
+
+/// The maximum number of data characters for which the typos of an invalid checksum are located,
+/// as the search substitutes every value at every index, and its cost grows quadratically with the length.
+pub const MAXIMUM_TYPO_SEARCH_LENGTH: usize = 128;
+
+/// The variant of the bech32 checksum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Bech32Variant {
+    /// The original checksum of BIP-173, which is only accepted for compatibility.
+    Bech32,
+    /// The checksum of BIP-350, which is used to encode all strings.
+    Bech32m,
+}
+
+impl Bech32Variant {
+    /// Returns the constant that a valid checksum of this variant evaluates to.
+    const fn constant(self) -> u32 {
+        match self {
+            Self::Bech32 => 1,
+            Self::Bech32m => 0x2bc830a3,
+        }
+    }
+
+    /// Returns the variant of a valid checksum with the given residue, if any.
+    fn from_residue(residue: u32) -> Option<Self> {
+        match residue {
+            residue if residue == Self::Bech32m.constant() => Some(Self::Bech32m),
+            residue if residue == Self::Bech32.constant() => Some(Self::Bech32),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes the given bytes as a bech32m string with the given prefix.
+///
+/// The data characters are computed in constant time with respect to the bytes.
+pub fn encode_bech32m(prefix: &str, data: &[u8]) -> String {
+    let prefix = prefix.to_ascii_lowercase();
+    let mut values = convert_bits(data, 8, 5);
+
+    let mut checksum_input = expand_prefix(&prefix);
+    checksum_input.extend_from_slice(&values);
+    checksum_input.extend_from_slice(&[0u8; CHECKSUM_STRING_LENGTH]);
+    let checksum = polymod(&checksum_input) ^ Bech32Variant::Bech32m.constant();
+    values.extend((0..CHECKSUM_STRING_LENGTH).map(|i| ((checksum >> (5 * (5 - i))) & 0x1f) as u8));
+
+    let mut string = String::with_capacity(prefix.len() + 1 + values.len());
+    string.push_str(&prefix);
+    string.push('1');
+    string.extend(values.into_iter().map(|value| to_character(value) as char));
+    string
+}
+
+/// Decodes the given bech32m string into bytes, checking that it uses the given prefix and,
+/// if `expected_length` is provided, that it contains exactly that many characters.
+/// If `accepts_bech32` is `true`, strings with a checksum of the original bech32 variant are also accepted.
+///
+/// The data characters are decoded in constant time with respect to their values, so strings of
+/// secret data may be decoded. If the string is invalid, the error reports where, and if the checksum
+/// does not match, the error lists the indices of the characters which, if replaced, would make the
+/// string valid. These are the likely typos.
+pub fn decode_bech32m(
+    string: &str,
+    prefix: &str,
+    expected_length: Option<usize>,
+    accepts_bech32: bool,
+) -> Result<Vec<u8>, Bech32mError> {
+    if let Some(expected_length) = expected_length {
+        if string.len() != expected_length {
+            return Err(Bech32mError::InvalidCharacterLength(expected_length, string.len()));
+        }
+    }
+
+    let separator = string.rfind('1').ok_or(Bech32mError::MissingSeparator)?;
+    let (hrp, data) = (&string[..separator], &string[separator + 1..]);
+    if !hrp.eq_ignore_ascii_case(prefix) {
+        return Err(Bech32mError::InvalidPrefix(prefix.to_string(), hrp.to_string()));
+    }
+    if data.len() < CHECKSUM_STRING_LENGTH {
+        return Err(Bech32mError::InvalidCharacterLength(
+            separator + 1 + CHECKSUM_STRING_LENGTH,
+            string.len(),
+        ));
+    }
+
+    // Decode every character before checking the result, so the running time does not depend on the data.
+    let mut has_lowercase = mask(hrp.bytes().any(|character| character.is_ascii_lowercase()));
+    let mut has_uppercase = mask(hrp.bytes().any(|character| character.is_ascii_uppercase()));
+    let mut is_valid = 0xffu8;
+    let mut values = Vec::with_capacity(data.len());
+    for &character in data.as_bytes() {
+        has_lowercase |= mask(character.is_ascii_lowercase());
+        has_uppercase |= mask(character.is_ascii_uppercase());
+        let (value, is_valid_character) = from_character(character.to_ascii_lowercase());
+        is_valid &= is_valid_character;
+        values.push(value);
+    }
+    if is_valid == 0 {
+        // Locate the invalid character for the error, which reveals nothing of the valid characters.
+        let (index, character) = data
+            .char_indices()
+            .find(|(_, character)| !character.is_ascii() || from_character(character.to_ascii_lowercase() as u8).1 == 0)
+            .expect("An invalid character was found");
+        return Err(Bech32mError::InvalidCharacter(character, separator + 1 + index));
+    }
+    if has_lowercase & has_uppercase != 0 {
+        return Err(Bech32mError::MixedCase);
+    }
+
+    let prefix = hrp.to_ascii_lowercase();
+    match verify_checksum(&prefix, &values) {
+        Some(Bech32Variant::Bech32m) => (),
+        Some(Bech32Variant::Bech32) if accepts_bech32 => (),
+        Some(Bech32Variant::Bech32) => return Err(Bech32mError::InvalidVariant),
+        None => match locate_typos(&prefix, &values, accepts_bech32) {
+            typos if typos.is_empty() => return Err(Bech32mError::InvalidChecksum),
+            typos => {
+                return Err(Bech32mError::InvalidChecksumAt(
+                    typos.into_iter().map(|index| separator + 1 + index).collect(),
+                ));
+            }
+        },
+    }
+
+    let values = &values[..values.len() - CHECKSUM_STRING_LENGTH];
+    if values.is_empty() {
+        return Err(Bech32mError::InvalidByteLength(0));
+    }
+
+    // Ensure the padding of the last character is fewer than 5 bits, and is all zeros.
+    let num_padding_bits = (values.len() * 5) % 8;
+    let padding = values[values.len() - 1] & ((1 << num_padding_bits) - 1);
+    if num_padding_bits >= 5 || padding != 0 {
+        return Err(Bech32mError::InvalidPadding);
+    }
+
+    let mut bytes = convert_bits(values, 5, 8);
+    bytes.truncate(values.len() * 5 / 8);
+    Ok(bytes)
+}
+
+/// Returns `0xff` if the given condition is `true`, and `0x00` otherwise.
+#[inline]
+fn mask(condition: bool) -> u8 {
+    0u8.wrapping_sub(condition as u8)
+}
+
+/// Returns the 5-bit value of the given lowercase character, and `0xff` if it is in the alphabet or `0x00` otherwise.
+/// The alphabet is scanned in full, so the running time does not depend on the character.
+fn from_character(character: u8) -> (u8, u8) {
+    let (mut value, mut is_valid) = (0u8, 0u8);
+    for (index, &candidate) in CHARSET.iter().enumerate() {
+        let is_equal = mask(candidate == character);
+        value |= index as u8 & is_equal;
+        is_valid |= is_equal;
+    }
+    (value, is_valid)
+}
+
+/// Returns the character of the given 5-bit value.
+/// The alphabet is scanned in full, so the running time does not depend on the value.
+fn to_character(value: u8) -> u8 {
+    CHARSET.iter().enumerate().fold(0u8, |character, (index, &candidate)| {
+        character | (candidate & mask(index as u8 == value))
+    })
+}
+
+/// Returns the bech32 checksum polynomial of the given 5-bit values, without branching on the values.
+fn polymod(values: &[u8]) -> u32 {
+    let mut checksum = 1u32;
+    for &value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ff_ffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            checksum ^= generator & 0u32.wrapping_sub((top >> i) & 1);
+        }
+    }
+    checksum
+}
+
+/// Returns the given prefix as 5-bit values, for the checksum.
+fn expand_prefix(prefix: &str) -> Vec<u8> {
+    let mut values = Vec::with_capacity(prefix.len() * 2 + 1);
+    values.extend(prefix.bytes().map(|byte| byte >> 5));
+    values.push(0);
+    values.extend(prefix.bytes().map(|byte| byte & 0x1f));
+    values
+}
+
+/// Returns the variant of the checksum at the end of the given 5-bit values, if it is valid.
+fn verify_checksum(prefix: &str, values: &[u8]) -> Option<Bech32Variant> {
+    let mut checksum_input = expand_prefix(prefix);
+    checksum_input.extend_from_slice(values);
+    Bech32Variant::from_residue(polymod(&checksum_input))
+}
+
+/// Regroups the given values of `from` bits into values of `to` bits, padding the last value with zeros.
+fn convert_bits(data: &[u8], from: u32, to: u32) -> Vec<u8> {
+    let mut accumulator = 0u32;
+    let mut num_bits = 0u32;
+    let max_value = (1u32 << to) - 1;
+
+    let mut values = Vec::with_capacity((data.len() * from as usize + to as usize - 1) / to as usize);
+    for &value in data {
+        accumulator = (accumulator << from) | value as u32;
+        num_bits += from;
+        while num_bits >= to {
+            num_bits -= to;
+            values.push(((accumulator >> num_bits) & max_value) as u8);
+        }
+    }
+    if num_bits > 0 {
+        values.push(((accumulator << (to - num_bits)) & max_value) as u8);
+    }
+    values
+}
+
+/// Returns the indices of the given data values which, when substituted with another 5-bit value,
+/// yield a valid checksum. No typos are located if there are more than `MAXIMUM_TYPO_SEARCH_LENGTH` values.
+fn locate_typos(prefix: &str, values: &[u8], accepts_bech32: bool) -> Vec<usize> {
+    if values.len() > MAXIMUM_TYPO_SEARCH_LENGTH {
+        return Vec::new();
+    }
+
+    let mut candidate = values.to_vec();
+
+    let mut typos = Vec::new();
+    for index in 0..candidate.len() {
+        let original = candidate[index];
+        for value in (0..32).filter(|&value| value != original) {
+            candidate[index] = value;
+            match verify_checksum(prefix, &candidate) {
+                Some(Bech32Variant::Bech32m) => {
+                    typos.push(index);
+                    break;
+                }
+                Some(Bech32Variant::Bech32) if accepts_bech32 => {
+                    typos.push(index);
+                    break;
+                }
+                _ => (),
+            }
+        }
+        candidate[index] = original;
+    }
+    typos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testnet1::Testnet1,
+        testnet2::Testnet2,
+        Bech32Locator,
+        Bech32Object,
+        NetworkConstants,
+        NetworkCrypto,
+        PrivateKey,
+    };
+    use snarkvm_utilities::{ToBytes, UniformRand};
+
+    use bech32::ToBase32;
+    use rand::{thread_rng, Rng};
+    use std::str::FromStr;
+
+    macro_rules! test_locator_round_trip {
+        ($($locator:ident),*) => {
+            $({
+                let expected: <Testnet2 as NetworkCrypto>::$locator = UniformRand::rand(&mut thread_rng());
+                let string = expected.to_string();
+                assert!(string.starts_with(&<Testnet2 as NetworkCrypto>::$locator::prefix()));
+                assert_eq!(expected, <Testnet2 as NetworkCrypto>::$locator::from_str(&string).unwrap());
+            })*
+        };
+    }
+
+    /// Returns the given string with the character at the given index replaced.
+    fn replace(string: &str, index: usize) -> String {
+        let mut bytes = string.as_bytes().to_vec();
+        bytes[index] = *CHARSET.iter().find(|&&character| character != bytes[index]).unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_locator_from_str() {
+        test_locator_round_trip!(
+            BlockHash,
+            BlockHeaderRoot,
+            Commitment,
+            FunctionID,
+            FunctionInputsHash,
+            InnerCircuitID,
+            LedgerRoot,
+            PoSWNonce,
+            ProgramID,
+            RecordRandomizer,
+            RecordViewKeyCommitment,
+            SerialNumber,
+            TransactionsRoot,
+            TransactionID,
+            TransitionID
+        );
+    }
+
+    #[test]
+    fn test_object_from_str() {
+        let expected = <Testnet2 as NetworkCrypto>::RecordViewKey::default();
+        let string = expected.to_string();
+        assert!(string.starts_with(&<Testnet2 as NetworkCrypto>::RecordViewKey::prefix()));
+        assert_eq!(
+            expected,
+            <Testnet2 as NetworkCrypto>::RecordViewKey::from_str(&string).unwrap()
+        );
+
+        let rng = &mut thread_rng();
+        let expected = PrivateKey::<Testnet2>::new(rng).sign(b"message", rng).unwrap();
+        let string = expected.to_string();
+        assert!(string.starts_with(&<Testnet2 as NetworkCrypto>::AccountSignature::prefix()));
+        assert_eq!(
+            expected,
+            <Testnet2 as NetworkCrypto>::AccountSignature::from_str(&string).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let string = <Testnet2 as NetworkCrypto>::BlockHash::default().to_string();
+        let expected_length = string.len();
+
+        match <Testnet2 as NetworkCrypto>::BlockHash::from_str(&string[1..]) {
+            Err(Bech32mError::InvalidCharacterLength(expected, found)) => {
+                assert_eq!((expected, found), (expected_length, expected_length - 1))
+            }
+            result => panic!("Expected an invalid length error, found {:?}", result),
+        }
+
+        // Ensure an object string with trailing data is rejected before it is decoded.
+        let string = <Testnet2 as NetworkCrypto>::RecordViewKey::default().to_string();
+        let expected_length = string.len();
+        let data = vec![0u8; <Testnet2 as NetworkCrypto>::RecordViewKey::size_in_bytes() + 1];
+        let string = encode_bech32m(&<Testnet2 as NetworkCrypto>::RecordViewKey::prefix(), &data);
+
+        match <Testnet2 as NetworkCrypto>::RecordViewKey::from_str(&string) {
+            Err(Bech32mError::InvalidCharacterLength(expected, found)) => {
+                assert_eq!((expected, found), (expected_length, string.len()))
+            }
+            result => panic!("Expected an invalid length error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_invalid_prefix() {
+        let string = <Testnet2 as NetworkCrypto>::BlockHash::default().to_string();
+        let string = format!("zz{}", &string[2..]);
+
+        match <Testnet2 as NetworkCrypto>::BlockHash::from_str(&string) {
+            Err(Bech32mError::InvalidPrefix(expected, found)) => {
+                assert_eq!(expected, <Testnet2 as NetworkCrypto>::BlockHash::prefix());
+                assert_eq!(found, "zz");
+            }
+            result => panic!("Expected an invalid prefix error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_invalid_character() {
+        let string = <Testnet2 as NetworkCrypto>::BlockHash::default().to_string();
+        // The character 'b' is not part of the bech32 alphabet.
+        let string = format!("{}b{}", &string[..10], &string[11..]);
+
+        match <Testnet2 as NetworkCrypto>::BlockHash::from_str(&string) {
+            Err(Bech32mError::InvalidCharacter(character, index)) => assert_eq!((character, index), ('b', 10)),
+            result => panic!("Expected an invalid character error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_invalid_checksum_locates_typo() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..10 {
+            let string = <Testnet2 as NetworkCrypto>::BlockHash::rand(rng).to_string();
+            for index in [3, 20, string.len() - 1] {
+                match <Testnet2 as NetworkCrypto>::BlockHash::from_str(&replace(&string, index)) {
+                    Err(Bech32mError::InvalidChecksumAt(typos)) => assert_eq!(typos, vec![index]),
+                    result => panic!("Expected an invalid checksum error, found {:?}", result),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_checksum_of_long_string() {
+        let rng = &mut thread_rng();
+
+        // Ensure typos are not located in a string with more data characters than the search is capped at.
+        let data: Vec<u8> = (0..MAXIMUM_TYPO_SEARCH_LENGTH).map(|_| rng.gen()).collect();
+        let string = encode_bech32m("ab", &data);
+        match decode_bech32m(&replace(&string, 3), "ab", None, false) {
+            Err(Bech32mError::InvalidChecksum) => (),
+            result => panic!("Expected an invalid checksum error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let rng = &mut thread_rng();
+
+        for length in 1..64 {
+            let data: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+            let string = encode_bech32m("ab", &data);
+
+            // Ensure the encoding matches the reference implementation.
+            let expected = bech32::encode("ab", data.to_base32(), bech32::Variant::Bech32m).unwrap();
+            assert_eq!(expected, string);
+
+            assert_eq!(data, decode_bech32m(&string, "ab", None, false).unwrap());
+            assert_eq!(data, decode_bech32m(&string.to_uppercase(), "ab", None, false).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let string = <Testnet2 as NetworkCrypto>::BlockHash::default().to_string();
+        let string = format!("{}{}", &string[..10], string[10..].to_uppercase());
+
+        match <Testnet2 as NetworkCrypto>::BlockHash::from_str(&string) {
+            Err(Bech32mError::MixedCase) => (),
+            result => panic!("Expected a mixed case error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_bech32_variant() {
+        let expected = <Testnet2 as NetworkCrypto>::BlockHash::rand(&mut thread_rng());
+        let data = expected.to_bytes_le().unwrap();
+        let string = bech32::encode(
+            &<Testnet2 as NetworkCrypto>::BlockHash::prefix(),
+            data.to_base32(),
+            bech32::Variant::Bech32,
+        )
+        .unwrap();
+
+        // Ensure a bech32 string is rejected by a network that only accepts bech32m.
+        assert!(!Testnet2::ACCEPTS_BECH32);
+        match <Testnet2 as NetworkCrypto>::BlockHash::from_str(&string) {
+            Err(Bech32mError::InvalidVariant) => (),
+            result => panic!("Expected an invalid variant error, found {:?}", result),
+        }
+
+        // Ensure the existing networks keep rejecting bech32 strings.
+        assert!(!Testnet1::ACCEPTS_BECH32);
+        let testnet1_string = bech32::encode(
+            &<Testnet1 as NetworkCrypto>::BlockHash::prefix(),
+            data.to_base32(),
+            bech32::Variant::Bech32,
+        )
+        .unwrap();
+        match <Testnet1 as NetworkCrypto>::BlockHash::from_str(&testnet1_string) {
+            Err(Bech32mError::InvalidVariant) => (),
+            result => panic!("Expected an invalid variant error, found {:?}", result),
+        }
+
+        // Ensure a bech32 string is accepted if the variant is accepted for compatibility.
+        let prefix = <Testnet2 as NetworkCrypto>::BlockHash::prefix();
+        assert_eq!(data, decode_bech32m(&string, &prefix, None, true).unwrap());
+    }
+}
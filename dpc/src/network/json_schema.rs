@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::{anyhow, Result};
+use std::fmt::Write;
+
+/// The version of the JSON schema that human-readable objects are serialized with.
+pub const JSON_SCHEMA_VERSION: u16 = 1;
+/// The versions of the JSON schema that human-readable objects can be deserialized from.
+pub const SUPPORTED_JSON_SCHEMA_VERSIONS: [u16; 1] = [1];
+/// The name of the field that stores the JSON schema version of a human-readable object.
+pub const JSON_SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// A field of a human-readable object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct JsonField {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The description of the field.
+    pub description: &'static str,
+}
+
+///
+/// The JSON schema of a human-readable object.
+///
+/// An object is serialized as a JSON object with the schema version field,
+/// followed by the fields of its schema, in order.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct JsonSchema {
+    /// The name of the object.
+    pub name: &'static str,
+    /// The fields of the object, in serialization order, excluding the schema version field.
+    pub fields: &'static [JsonField],
+}
+
+impl JsonSchema {
+    /// Returns the names of the fields of the object, in serialization order.
+    pub fn field_names(&self) -> Vec<&'static str> {
+        std::iter::once(JSON_SCHEMA_VERSION_FIELD)
+            .chain(self.fields.iter().map(|field| field.name))
+            .collect()
+    }
+
+    /// Returns the schema version of the given JSON object, if it is supported.
+    /// Objects serialized before the schema was versioned are read as version 1.
+    pub fn read_version(&self, object: &serde_json::Value) -> Result<u16> {
+        let version = match object.get(JSON_SCHEMA_VERSION_FIELD) {
+            None => 1,
+            Some(version) => serde_json::from_value::<u16>(version.clone())
+                .map_err(|_| anyhow!("Invalid {} schema version {}", self.name, version))?,
+        };
+
+        match SUPPORTED_JSON_SCHEMA_VERSIONS.contains(&version) {
+            true => Ok(version),
+            false => Err(anyhow!("Unsupported {} schema version {}", self.name, version)),
+        }
+    }
+
+    /// Returns the documentation of the schema, in Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        writeln!(markdown, "### {} (schema version {})\n", self.name, JSON_SCHEMA_VERSION).unwrap();
+        writeln!(markdown, "| Field | Description |").unwrap();
+        writeln!(markdown, "|-------|-------------|").unwrap();
+        writeln!(
+            markdown,
+            "| `{}` | The version of the JSON schema of the object. |",
+            JSON_SCHEMA_VERSION_FIELD
+        )
+        .unwrap();
+        for field in self.fields {
+            writeln!(markdown, "| `{}` | {} |", field.name, field.description).unwrap();
+        }
+        markdown
+    }
+}
+
+/// The JSON schema of a block.
+pub const BLOCK_JSON_SCHEMA: JsonSchema = JsonSchema {
+    name: "Block",
+    fields: &[
+        JsonField {
+            name: "block_hash",
+            description: "The hash of the block.",
+        },
+        JsonField {
+            name: "previous_block_hash",
+            description: "The hash of the previous block.",
+        },
+        JsonField {
+            name: "header",
+            description: "The block header.",
+        },
+        JsonField {
+            name: "transactions",
+            description: "The transactions of the block.",
+        },
+    ],
+};
+
+/// The JSON schema of a transaction.
+pub const TRANSACTION_JSON_SCHEMA: JsonSchema = JsonSchema {
+    name: "Transaction",
+    fields: &[
+        JsonField {
+            name: "transaction_id",
+            description: "The ID of the transaction.",
+        },
+        JsonField {
+            name: "inner_circuit_id",
+            description: "The ID of the inner circuit used to execute the transaction.",
+        },
+        JsonField {
+            name: "ledger_root",
+            description: "The ledger root used to prove the input records exist.",
+        },
+        JsonField {
+            name: "transitions",
+            description: "The state transitions of the transaction.",
+        },
+        JsonField {
+            name: "data",
+            description: "The public data attached to the transaction, in hex.",
+        },
+    ],
+};
+
+/// The JSON schema of a transition.
+pub const TRANSITION_JSON_SCHEMA: JsonSchema = JsonSchema {
+    name: "Transition",
+    fields: &[
+        JsonField {
+            name: "transition_id",
+            description: "The ID of the transition.",
+        },
+        JsonField {
+            name: "serial_numbers",
+            description: "The serial numbers of the input records.",
+        },
+        JsonField {
+            name: "commitments",
+            description: "The commitments of the output records.",
+        },
+        JsonField {
+            name: "ciphertexts",
+            description: "The ciphertexts of the output records.",
+        },
+        JsonField {
+            name: "value_balance",
+            description: "The difference between the input and output record values.",
+        },
+        JsonField {
+            name: "events",
+            description: "The events emitted by the transition.",
+        },
+        JsonField {
+            name: "proof",
+            description: "The zero-knowledge proof of the transition.",
+        },
+    ],
+};
+
+/// The JSON schema of a record.
+pub const RECORD_JSON_SCHEMA: JsonSchema = JsonSchema {
+    name: "Record",
+    fields: &[
+        JsonField {
+            name: "owner",
+            description: "The address of the record owner.",
+        },
+        JsonField {
+            name: "value",
+            description: "The value of the record.",
+        },
+        JsonField {
+            name: "payload",
+            description: "The payload of the record.",
+        },
+        JsonField {
+            name: "program_id",
+            description: "The ID of the program of the record.",
+        },
+        JsonField {
+            name: "locktime",
+            description: "The minimum block height or timestamp before the record may be consumed, if any.",
+        },
+        JsonField {
+            name: "randomizer",
+            description: "The ciphertext randomizer of the record.",
+        },
+        JsonField {
+            name: "record_view_key",
+            description: "The view key of the record.",
+        },
+        JsonField {
+            name: "commitment",
+            description: "The commitment of the record.",
+        },
+    ],
+};
+
+/// The JSON schemas of the human-readable objects.
+pub const JSON_SCHEMAS: [JsonSchema; 4] = [
+    BLOCK_JSON_SCHEMA,
+    TRANSACTION_JSON_SCHEMA,
+    TRANSITION_JSON_SCHEMA,
+    RECORD_JSON_SCHEMA,
+];
+
+/// Returns the documentation of the JSON schemas of the human-readable objects, in Markdown.
+pub fn json_schema_docs() -> String {
+    JSON_SCHEMAS
+        .iter()
+        .map(|schema| schema.to_markdown())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Asserts the given JSON string serializes the fields of the given schema, in order.
+#[cfg(test)]
+pub(crate) fn assert_json_field_order(schema: &JsonSchema, json: &str) {
+    let positions = schema
+        .field_names()
+        .iter()
+        .map(|field| json.find(&format!("\"{}\":", field)).expect("Missing field in JSON"))
+        .collect::<Vec<_>>();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_version() {
+        let legacy = serde_json::json!({ "block_hash": "ab1" });
+        assert_eq!(1, BLOCK_JSON_SCHEMA.read_version(&legacy).unwrap());
+
+        let current = serde_json::json!({ "schema_version": JSON_SCHEMA_VERSION, "block_hash": "ab1" });
+        assert_eq!(JSON_SCHEMA_VERSION, BLOCK_JSON_SCHEMA.read_version(&current).unwrap());
+
+        let unsupported = serde_json::json!({ "schema_version": JSON_SCHEMA_VERSION + 1 });
+        assert!(BLOCK_JSON_SCHEMA.read_version(&unsupported).is_err());
+
+        let invalid = serde_json::json!({ "schema_version": "one" });
+        assert!(BLOCK_JSON_SCHEMA.read_version(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_json_schema_docs() {
+        let docs = json_schema_docs();
+        for schema in JSON_SCHEMAS.iter() {
+            assert!(docs.contains(&format!("### {} ", schema.name)));
+            for field in schema.field_names() {
+                assert!(docs.contains(&format!("| `{}` |", field)));
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_parameters::ParameterError;
+use snarkvm_utilities::FromBytes;
+
+///
+/// Reads a SNARK key from the given parameter bytes, which are checked against
+/// their expected size and checksum when loaded.
+///
+/// Returns an error if the bytes failed to load, do not encode a valid key, or contain trailing bytes.
+///
+pub(crate) fn read_snark_key<K: FromBytes>(
+    bytes: Result<Vec<u8>, ParameterError>,
+    message: &str,
+) -> Result<K, ParameterError> {
+    let bytes = bytes?;
+
+    let mut reader = &bytes[..];
+    let key =
+        K::read_le(&mut reader).map_err(|error| ParameterError::InvalidKey(message.to_string(), error.to_string()))?;
+
+    match reader.is_empty() {
+        true => Ok(key),
+        false => Err(ParameterError::TrailingBytes(message.to_string(), reader.len())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::ToBytes;
+
+    #[test]
+    fn test_read_snark_key() {
+        let bytes = 5u64.to_bytes_le().unwrap();
+        assert_eq!(5u64, read_snark_key::<u64>(Ok(bytes.clone()), "key").unwrap());
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            read_snark_key::<u64>(Ok(trailing), "key"),
+            Err(ParameterError::TrailingBytes(_, 1))
+        ));
+
+        assert!(matches!(
+            read_snark_key::<u64>(Ok(bytes[..4].to_vec()), "key"),
+            Err(ParameterError::InvalidKey(..))
+        ));
+        assert!(matches!(
+            read_snark_key::<u64>(Err(ParameterError::SizeMismatch(8, 4)), "key"),
+            Err(ParameterError::SizeMismatch(8, 4))
+        ));
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Bech32Locator, Bech32mError};
+use crate::{decode_bech32m, encode_bech32m, Bech32Locator, Bech32mError, CHECKSUM_STRING_LENGTH};
 use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField};
 use snarkvm_utilities::{
     fmt,
@@ -30,7 +30,6 @@ use snarkvm_utilities::{
 };
 
 use anyhow::Result;
-use bech32::{self, FromBase32, ToBase32};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
@@ -39,9 +38,11 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Borrow;
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
-pub struct AleoLocator<F: PrimeField + ToConstraintField<F>, const PREFIX: u16>(F);
+pub struct AleoLocator<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool>(F);
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Bech32Locator<F> for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> Bech32Locator<F>
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn prefix() -> String {
         String::from_utf8(PREFIX.to_le_bytes().to_vec()).expect("Failed to convert prefix to string")
@@ -58,14 +59,18 @@ impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Bech32Locator<F> f
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> From<F> for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> From<F>
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn from(data: F) -> Self {
         Self(data)
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> FromBytes for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> FromBytes
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     /// Reads data into a buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -73,7 +78,9 @@ impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> FromBytes for Aleo
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> ToBytes for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> ToBytes
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     /// Writes the data to a buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -81,54 +88,42 @@ impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> ToBytes for AleoLo
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> FromStr for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> FromStr
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     type Err = Bech32mError;
 
     /// Reads in a bech32m string.
     #[inline]
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        const CHECKSUM_STRING_LENGTH: usize = 6;
-        if string.len() != 3 + Self::data_string_length() + CHECKSUM_STRING_LENGTH {
-            return Err(Bech32mError::InvalidCharacterLength(string.len()));
-        }
-
-        let (hrp, data, variant) = bech32::decode(string)?;
-        if hrp.as_bytes() != PREFIX.to_le_bytes() {
-            return Err(Bech32mError::InvalidPrefix(hrp));
-        };
-        if data.is_empty() {
-            return Err(Bech32mError::InvalidByteLength(0));
-        }
-        if variant != bech32::Variant::Bech32m {
-            return Err(Bech32mError::InvalidVariant);
-        }
-
-        let buffer = Vec::from_base32(&data)?;
+        let expected_length = Self::prefix().len() + 1 + Self::data_string_length() + CHECKSUM_STRING_LENGTH;
+        let buffer = decode_bech32m(string, &Self::prefix(), Some(expected_length), ACCEPTS_BECH32)?;
         Ok(Self::read_le(&buffer[..])?)
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> fmt::Display for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> fmt::Display
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        bech32::encode_to_fmt(
-            f,
-            &Self::prefix(),
-            self.0.to_bytes_le().expect("Failed to write data as bytes").to_base32(),
-            bech32::Variant::Bech32m,
-        )
-        .expect("Failed to encode in bech32m")
+        let data = self.0.to_bytes_le().expect("Failed to write data as bytes");
+        write!(f, "{}", encode_bech32m(&Self::prefix(), &data))
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> fmt::Debug for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> fmt::Debug
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "AleoLocator {{ hrp: {:?}, data: {:?} }}", &Self::prefix(), self.0)
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Serialize for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> Serialize
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
@@ -138,7 +133,9 @@ impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Serialize for Aleo
     }
 }
 
-impl<'de, F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Deserialize<'de> for AleoLocator<F, PREFIX> {
+impl<'de, F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> Deserialize<'de>
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
@@ -150,14 +147,18 @@ impl<'de, F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Deserialize<'
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> ToConstraintField<F> for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> ToConstraintField<F>
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
         self.0.to_field_elements()
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Deref for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> Deref
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     type Target = F;
 
     #[inline]
@@ -166,7 +167,9 @@ impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Deref for AleoLoca
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Borrow<F> for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> Borrow<F>
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn borrow(&self) -> &F {
         &self.0
@@ -174,16 +177,20 @@ impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Borrow<F> for Aleo
 }
 
 #[allow(clippy::from_over_into)]
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Into<Vec<F>> for AleoLocator<F, PREFIX> {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool> Into<Vec<F>>
+    for AleoLocator<F, PREFIX, ACCEPTS_BECH32>
+{
     #[inline]
     fn into(self) -> Vec<F> {
         vec![self.0]
     }
 }
 
-impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16> Distribution<AleoLocator<F, PREFIX>> for Standard {
+impl<F: PrimeField + ToConstraintField<F>, const PREFIX: u16, const ACCEPTS_BECH32: bool>
+    Distribution<AleoLocator<F, PREFIX, ACCEPTS_BECH32>> for Standard
+{
     #[inline]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AleoLocator<F, PREFIX> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AleoLocator<F, PREFIX, ACCEPTS_BECH32> {
         AleoLocator::<F, PREFIX>(UniformRand::rand(rng))
     }
 }
@@ -18,22 +18,45 @@ macro_rules! dpc_setup {
     ($network: ident, $fn_name: ident, $type_name: ident, $setup_msg: expr) => {
         #[inline]
         fn $fn_name() -> &'static Self::$type_name {
-            static PARAMETER: OnceCell<<$network as Network>::$type_name> = OnceCell::new();
+            static PARAMETER: OnceCell<<$network as NetworkCrypto>::$type_name> = OnceCell::new();
             PARAMETER.get_or_init(|| Self::$type_name::setup($setup_msg))
         }
     };
 }
 
+#[rustfmt::skip]
+macro_rules! dpc_empty_hash {
+    ($network: ident, $fn_name: ident, $crh_type: ident, $parameters_fn: ident) => {
+        #[inline]
+        fn $fn_name() -> &'static <Self::$crh_type as snarkvm_algorithms::traits::CRH>::Output {
+            static EMPTY_HASH: OnceCell<<<$network as NetworkCrypto>::$crh_type as snarkvm_algorithms::traits::CRH>::Output> = OnceCell::new();
+            EMPTY_HASH.get_or_init(|| {
+                snarkvm_algorithms::traits::MerkleParameters::hash_empty(Self::$parameters_fn()).expect("Failed to compute the empty hash")
+            })
+        }
+    };
+}
+
 #[rustfmt::skip]
 macro_rules! dpc_snark_setup {
+    ($network: ident, $fn_name: ident, $try_fn_name: ident, $snark_type: ident, $key_type: ident, $parameter: ident, $message: expr) => {
+        #[inline]
+        fn $fn_name() -> &'static <Self::$snark_type as SNARK>::$key_type {
+            Self::$try_fn_name().unwrap_or_else(|error| panic!("{}", error))
+        }
+
+        #[inline]
+        fn $try_fn_name() -> Result<&'static <Self::$snark_type as SNARK>::$key_type, snarkvm_parameters::ParameterError> {
+            static PARAMETER: OnceCell<<<$network as NetworkParameters>::$snark_type as SNARK>::$key_type> = OnceCell::new();
+            PARAMETER.get_or_try_init(|| $crate::network::read_snark_key($parameter::load_bytes(), $message))
+        }
+    };
     ($network: ident, $fn_name: ident, $snark_type: ident, $key_type: ident, $parameter: ident, $message: expr) => {
         #[inline]
         fn $fn_name() -> &'static <Self::$snark_type as SNARK>::$key_type {
-            static PARAMETER: OnceCell<<<$network as Network>::$snark_type as SNARK>::$key_type> = OnceCell::new();
+            static PARAMETER: OnceCell<<<$network as NetworkParameters>::$snark_type as SNARK>::$key_type> = OnceCell::new();
             PARAMETER.get_or_init(|| {
-                <Self::$snark_type as SNARK>::$key_type::read_le(
-                    $parameter::load_bytes().expect(&format!("Failed to load parameter bytes for {}", $message)).as_slice()
-                ).expect(&format!("Failed to read {} from bytes", $message))
+                $crate::network::read_snark_key($parameter::load_bytes(), $message).unwrap_or_else(|error| panic!("{}", error))
             })
         }
     };
@@ -14,6 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod any;
+pub use any::*;
+
+pub mod bech32m;
+pub use bech32m::*;
+
+pub mod json_schema;
+pub use json_schema::*;
+
+mod keys;
+pub(crate) use keys::*;
+
 pub mod locator;
 pub use locator::*;
 
@@ -23,8 +35,98 @@ pub mod macros;
 pub mod object;
 pub use object::*;
 
+pub mod self_check;
+pub use self_check::*;
+
+pub mod test_vectors;
+pub use test_vectors::*;
+
 #[cfg(feature = "testnet1")]
 pub mod testnet1;
 
 #[cfg(feature = "testnet2")]
 pub mod testnet2;
+
+#[cfg(feature = "dev")]
+pub mod testnet2_dev;
+
+#[cfg(feature = "testnet2")]
+pub mod testnet2_mini;
+
+pub mod upgrade;
+pub use upgrade::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::Network;
+    use snarkvm_algorithms::traits::{MerkleParameters, CRH, MERKLE_EMPTY_LEAF_SIZE_IN_BYTES};
+    use snarkvm_fields::PrimeField;
+    use snarkvm_gadgets::{
+        integers::uint::UInt8,
+        traits::{algorithms::CRHGadget, alloc::AllocGadget, eq::EqGadget},
+    };
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    /// Ensures the given empty hash matches the empty leaf hash computed natively and in the circuit.
+    fn check_empty_hash<P: MerkleParameters, F: PrimeField, G: CRHGadget<P::H, F>>(
+        parameters: &P,
+        empty_hash: &<P::H as CRH>::Output,
+    ) {
+        assert_eq!(&parameters.hash_empty().unwrap(), empty_hash);
+
+        let mut cs = TestConstraintSystem::<F>::new();
+        let crh = G::alloc_constant(cs.ns(|| "Declare the CRH parameters"), || Ok(parameters.crh())).unwrap();
+        let candidate_empty_hash = crh
+            .check_evaluation_gadget(
+                cs.ns(|| "Compute the empty hash"),
+                UInt8::constant_vec(&[0u8; MERKLE_EMPTY_LEAF_SIZE_IN_BYTES]),
+            )
+            .unwrap();
+        let expected_empty_hash =
+            G::OutputGadget::alloc(cs.ns(|| "Declare the expected empty hash"), || Ok(empty_hash.clone())).unwrap();
+        candidate_empty_hash
+            .enforce_equal(cs.ns(|| "Check the empty hash"), &expected_empty_hash)
+            .unwrap();
+        assert!(cs.is_satisfied());
+    }
+
+    fn check_empty_hashes<N: Network>() {
+        check_empty_hash::<_, _, N::BlockHeaderRootCRHGadget>(
+            N::block_header_root_parameters(),
+            N::block_header_root_empty_hash(),
+        );
+        check_empty_hash::<_, _, N::LedgerRootCRHGadget>(N::ledger_root_parameters(), N::ledger_root_empty_hash());
+        check_empty_hash::<_, _, N::ProgramIDCRHGadget>(N::program_id_parameters(), N::program_id_empty_hash());
+        check_empty_hash::<_, _, N::TransactionsRootCRHGadget>(
+            N::transactions_root_parameters(),
+            N::transactions_root_empty_hash(),
+        );
+        check_empty_hash::<_, _, N::TransactionIDCRHGadget>(
+            N::transaction_id_parameters(),
+            N::transaction_id_empty_hash(),
+        );
+        check_empty_hash::<_, _, N::TransitionIDCRHGadget>(
+            N::transition_id_parameters(),
+            N::transition_id_empty_hash(),
+        );
+    }
+
+    #[cfg(feature = "testnet1")]
+    #[test]
+    fn test_testnet1_empty_hashes() {
+        check_empty_hashes::<crate::testnet1::Testnet1>();
+    }
+
+    #[cfg(feature = "testnet2")]
+    #[test]
+    fn test_testnet2_empty_hashes() {
+        check_empty_hashes::<crate::testnet2::Testnet2>();
+        check_empty_hashes::<crate::testnet2_mini::Testnet2Mini>();
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn test_testnet2_dev_empty_hashes() {
+        check_empty_hashes::<crate::testnet2_dev::Testnet2Dev>();
+    }
+}
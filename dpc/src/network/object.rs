@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Bech32Object, Bech32mError};
+use crate::{decode_bech32m, encode_bech32m, Bech32Object, Bech32mError, CHECKSUM_STRING_LENGTH};
 use snarkvm_utilities::{
     fmt,
     io::{Read, Result as IoResult, Write},
@@ -28,7 +28,6 @@ use snarkvm_utilities::{
 };
 
 use anyhow::Result;
-use bech32::{self, FromBase32, ToBase32};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::{borrow::Borrow, fmt::Debug, hash::Hash};
 
@@ -37,13 +36,15 @@ pub struct AleoObject<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
+    const ACCEPTS_BECH32: bool,
 >(T);
 
 impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Bech32Object<T> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Bech32Object<T> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn prefix() -> String {
@@ -60,7 +61,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> From<T> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> From<T> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn from(data: T) -> Self {
@@ -72,7 +74,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> FromBytes for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> FromBytes for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     /// Reads data into a buffer.
     #[inline]
@@ -85,7 +88,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> ToBytes for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> ToBytes for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     /// Writes the data to a buffer.
     #[inline]
@@ -98,25 +102,17 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> FromStr for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> FromStr for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     type Err = Bech32mError;
 
     /// Reads in a bech32m string.
     #[inline]
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (hrp, data, variant) = bech32::decode(string)?;
-        if hrp != Self::prefix() {
-            return Err(Bech32mError::InvalidPrefix(hrp));
-        };
-        if data.is_empty() {
-            return Err(Bech32mError::InvalidByteLength(0));
-        }
-        if variant != bech32::Variant::Bech32m {
-            return Err(Bech32mError::InvalidVariant);
-        }
-
-        let buffer = Vec::from_base32(&data)?;
+        let data_string_length = ((DATA_SIZE_IN_BYTES * 8) + 4) / 5;
+        let expected_length = Self::prefix().len() + 1 + data_string_length + CHECKSUM_STRING_LENGTH;
+        let buffer = decode_bech32m(string, &Self::prefix(), Some(expected_length), ACCEPTS_BECH32)?;
         Ok(Self::read_le(&buffer[..])?)
     }
 }
@@ -125,17 +121,13 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> fmt::Display for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> fmt::Display for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        bech32::encode_to_fmt(
-            f,
-            &Self::prefix(),
-            self.0.to_bytes_le().expect("Failed to write data as bytes").to_base32(),
-            bech32::Variant::Bech32m,
-        )
-        .expect("Failed to encode in bech32m")
+        let data = self.0.to_bytes_le().expect("Failed to write data as bytes");
+        write!(f, "{}", encode_bech32m(&Self::prefix(), &data))
     }
 }
 
@@ -143,7 +135,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Debug for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Debug for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -155,7 +148,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Serialize for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Serialize for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -171,7 +165,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Deserialize<'de> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Deserialize<'de> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -186,7 +181,8 @@ impl<
     T: Default + Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Default for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Default for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     fn default() -> Self {
         Self(T::default())
@@ -197,7 +193,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Deref for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Deref for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     type Target = T;
 
@@ -211,7 +208,8 @@ impl<
     T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send,
     const PREFIX: u32,
     const DATA_SIZE_IN_BYTES: usize,
-> Borrow<T> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES>
+    const ACCEPTS_BECH32: bool,
+> Borrow<T> for AleoObject<T, PREFIX, DATA_SIZE_IN_BYTES, ACCEPTS_BECH32>
 {
     #[inline]
     fn borrow(&self) -> &T {
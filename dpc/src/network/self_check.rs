@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, Program, ProgramPublicVariables, SynthesizedCircuit};
+use snarkvm_algorithms::traits::{CRH, SNARK};
+use snarkvm_utilities::{ToBytes, ToMinimalBits, UniformRand};
+
+use anyhow::{anyhow, ensure, Result};
+use rand::thread_rng;
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+///
+/// The outcome of a self-check of the parameters of a network.
+///
+/// Each check runs a minimal end-to-end flow over the loaded parameters, such as proving and verifying
+/// a noop program execution, so that a node with missing or mismatched parameters fails at startup,
+/// rather than on the first transaction or block it processes.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfCheckReport {
+    network_name: &'static str,
+    checks: Vec<(&'static str, Result<(), String>)>,
+}
+
+impl SelfCheckReport {
+    /// Runs every self-check for the given network.
+    ///
+    /// This loads the inner and outer circuit proving keys, and proves a noop program execution.
+    pub fn run<N: Network>() -> Self {
+        let checks = vec![
+            ("inner circuit keys", Self::check(Self::check_inner_circuit_keys::<N>)),
+            ("inner circuit ID", Self::check(Self::check_inner_circuit_id::<N>)),
+            ("outer circuit keys", Self::check(Self::check_outer_circuit_keys::<N>)),
+            ("noop program", Self::check(Self::check_noop_program::<N>)),
+            ("noop execution", Self::check(Self::check_noop_execution::<N>)),
+            ("genesis block", Self::check(Self::check_genesis_block::<N>)),
+        ];
+
+        Self {
+            network_name: N::NETWORK_NAME,
+            checks,
+        }
+    }
+
+    /// Returns `true` if every check passed.
+    pub fn is_ok(&self) -> bool {
+        self.checks.iter().all(|(_, result)| result.is_ok())
+    }
+
+    /// Returns the name and result of each check, in the order they were run.
+    pub fn checks(&self) -> &[(&'static str, Result<(), String>)] {
+        &self.checks
+    }
+
+    /// Returns the name and error of each failed check.
+    pub fn failures(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.checks.iter().filter_map(|(name, result)| match result {
+            Ok(()) => None,
+            Err(error) => Some((*name, error.as_str())),
+        })
+    }
+
+    /// Returns `Ok` if every check passed, or an error listing the failed checks.
+    pub fn ensure_ok(&self) -> Result<()> {
+        match self.is_ok() {
+            true => Ok(()),
+            false => Err(anyhow!("{}", self)),
+        }
+    }
+
+    /// Runs the given check, reporting a panic, such as from a parameter that fails to load, as a failure.
+    fn check(check: fn() -> Result<()>) -> Result<(), String> {
+        match panic::catch_unwind(AssertUnwindSafe(check)) {
+            Ok(result) => result.map_err(|error| error.to_string()),
+            Err(payload) => match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+                (Some(message), _) => Err(format!("panicked: {}", message)),
+                (_, Some(message)) => Err(format!("panicked: {}", message)),
+                _ => Err("panicked".to_string()),
+            },
+        }
+    }
+
+    /// Ensures the inner circuit verifying key corresponds to the inner circuit proving key.
+    fn check_inner_circuit_keys<N: Network>() -> Result<()> {
+        let proving_key = N::try_inner_proving_key()?;
+        let candidate = <N::InnerSNARK as SNARK>::VerifyingKey::from(proving_key.clone());
+        ensure!(
+            candidate.to_bytes_le()? == N::inner_verifying_key().to_bytes_le()?,
+            "The inner circuit verifying key does not correspond to the inner circuit proving key"
+        );
+        Ok(())
+    }
+
+    /// Ensures the inner circuit ID corresponds to the inner circuit verifying key.
+    fn check_inner_circuit_id<N: Network>() -> Result<()> {
+        let candidate: N::InnerCircuitID = N::inner_circuit_id_crh()
+            .hash_bits(&N::inner_verifying_key().to_minimal_bits_v1())?
+            .into();
+        ensure!(
+            &candidate == N::inner_circuit_id(),
+            "The inner circuit ID does not correspond to the inner circuit verifying key"
+        );
+        Ok(())
+    }
+
+    /// Ensures the outer circuit verifying key corresponds to the outer circuit proving key.
+    fn check_outer_circuit_keys<N: Network>() -> Result<()> {
+        let proving_key = N::try_outer_proving_key()?;
+        let candidate = <N::OuterSNARK as SNARK>::VerifyingKey::from(proving_key.clone());
+        ensure!(
+            candidate.to_bytes_le()? == N::outer_verifying_key().to_bytes_le()?,
+            "The outer circuit verifying key does not correspond to the outer circuit proving key"
+        );
+        Ok(())
+    }
+
+    /// Ensures the noop function ID and program path correspond to the noop program.
+    fn check_noop_program<N: Network>() -> Result<()> {
+        ensure!(
+            &N::function_id(N::noop_circuit_verifying_key())? == N::noop_function_id(),
+            "The noop function ID does not correspond to the noop circuit verifying key"
+        );
+        ensure!(
+            &N::noop_program().program_id() == N::noop_program_id(),
+            "The noop program ID does not correspond to the noop program"
+        );
+        ensure!(
+            Program::<N>::verify_program_path(N::noop_program_id(), N::noop_function_id(), N::noop_program_path()),
+            "The noop program path does not prove the noop function is in the noop program"
+        );
+        Ok(())
+    }
+
+    /// Ensures a noop execution proves and verifies with the noop circuit keys.
+    fn check_noop_execution<N: Network>() -> Result<()> {
+        let rng = &mut thread_rng();
+        let payload_commitments = vec![Default::default(); N::NUM_PAYLOAD_COMMITMENTS];
+        let public = ProgramPublicVariables::<N>::new(N::TransitionID::rand(rng), payload_commitments);
+        let proof = <N::ProgramSNARK as SNARK>::prove(
+            N::noop_circuit_proving_key(),
+            &SynthesizedCircuit::Noop(public.clone()),
+            rng,
+        )?;
+        ensure!(
+            <N::ProgramSNARK as SNARK>::verify(N::noop_circuit_verifying_key(), &public, &proof)?,
+            "The noop execution proof does not verify"
+        );
+        Ok(())
+    }
+
+    /// Ensures the genesis block, including its proof of succinct work, is valid.
+    fn check_genesis_block<N: Network>() -> Result<()> {
+        let genesis_block = N::genesis_block();
+        ensure!(
+            N::posw().verify_from_block_header(genesis_block.header()),
+            "The proof of succinct work of the genesis block does not verify"
+        );
+        ensure!(genesis_block.is_valid(), "The genesis block is invalid");
+        Ok(())
+    }
+}
+
+impl fmt::Display for SelfCheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Self-check of {}:", self.network_name)?;
+        for (name, result) in &self.checks {
+            match result {
+                Ok(()) => writeln!(f, "  {}: ok", name)?,
+                Err(error) => writeln!(f, "  {}: FAILED - {}", name, error)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    #[test]
+    fn test_self_check() {
+        let report = Testnet2::self_check();
+        assert!(report.is_ok(), "{}", report);
+        assert!(report.ensure_ok().is_ok());
+        assert_eq!(0, report.failures().count());
+        assert_eq!(6, report.checks().len());
+    }
+
+    #[test]
+    fn test_self_check_reports_failures() {
+        assert_eq!(Ok(()), SelfCheckReport::check(|| Ok(())));
+        assert_eq!(
+            Err("invalid".to_string()),
+            SelfCheckReport::check(|| Err(anyhow!("invalid")))
+        );
+        assert!(SelfCheckReport::check(|| panic!("missing parameters"))
+            .unwrap_err()
+            .contains("missing parameters"));
+
+        let report = SelfCheckReport {
+            network_name: Testnet2::NETWORK_NAME,
+            checks: vec![("passing", Ok(())), ("failing", Err("invalid".to_string()))],
+        };
+        assert!(!report.is_ok());
+        assert!(report.ensure_ok().is_err());
+        assert_eq!(vec![("failing", "invalid")], report.failures().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Account, AccountScheme, AleoAmount, ComputeKey, Network, Payload, Record, Transition};
+use snarkvm_utilities::ToBytes;
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use serde::{Deserialize, Serialize};
+
+/// The version of the format of the test vectors.
+pub const TEST_VECTORS_VERSION: u16 = 1;
+/// The seed of the test vectors that are published for each network.
+pub const TEST_VECTORS_SEED: u64 = 1231275789;
+/// The number of accounts in the test vectors.
+const NUM_TEST_ACCOUNTS: usize = 2;
+
+/// The derivation of an account from a private key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountTestVector {
+    pub private_key: String,
+    pub view_key: String,
+    pub address: String,
+    /// The address, in little-endian hex.
+    pub address_bytes: String,
+}
+
+/// The encryption of a record to its owner, which decrypts with the view key of the owner.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordTestVector {
+    /// The index of the account that owns the record.
+    pub owner_index: usize,
+    pub owner: String,
+    pub value: i64,
+    /// The payload, in little-endian hex.
+    pub payload: String,
+    pub program_id: String,
+    pub record_view_key: String,
+    pub ciphertext: String,
+    pub commitment: String,
+    /// The serial number, derived from the compute key of the owner.
+    pub serial_number: String,
+}
+
+/// The ID of a transition with the given serial numbers and commitments.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionTestVector {
+    pub serial_numbers: Vec<String>,
+    pub commitments: Vec<String>,
+    pub transition_id: String,
+}
+
+///
+/// A canonical bundle of test vectors of a network, for implementations of Aleo in other languages.
+///
+/// The test vectors are generated from a seeded ChaCha20 RNG, and cover account key derivation,
+/// address encoding, record encryption, and transition IDs. An SDK checks its compatibility with
+/// snarkVM by reproducing each vector from its inputs, such as an address from a private key,
+/// or the contents of a record from its ciphertext and the view key of its owner.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    pub version: u16,
+    pub network_id: u16,
+    pub network_name: String,
+    pub seed: u64,
+    pub accounts: Vec<AccountTestVector>,
+    pub records: Vec<RecordTestVector>,
+    pub transitions: Vec<TransitionTestVector>,
+}
+
+impl TestVectors {
+    /// Returns the test vectors of the given network, generated from the given seed.
+    pub fn generate<N: Network>(seed: u64) -> Result<Self> {
+        let rng = &mut ChaChaRng::seed_from_u64(seed);
+
+        let accounts: Vec<Account<N>> = (0..NUM_TEST_ACCOUNTS).map(|_| Account::new(rng)).collect();
+        let account_vectors = accounts
+            .iter()
+            .map(|account| {
+                Ok(AccountTestVector {
+                    private_key: account.private_key().to_string(),
+                    view_key: account.view_key().to_string(),
+                    address: account.address().to_string(),
+                    address_bytes: hex::encode(account.address().to_bytes_le()?),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Each account owns the input records of one transition, whose output records are owned by the next account.
+        let mut record_vectors = Vec::with_capacity(NUM_TEST_ACCOUNTS * N::NUM_TOTAL_RECORDS);
+        let mut transition_vectors = Vec::with_capacity(NUM_TEST_ACCOUNTS);
+        for (index, account) in accounts.iter().enumerate() {
+            let recipient_index = (index + 1) % NUM_TEST_ACCOUNTS;
+
+            let mut serial_numbers = Vec::with_capacity(N::NUM_INPUT_RECORDS);
+            for _ in 0..N::NUM_INPUT_RECORDS {
+                let record = Self::sample_record(account, rng)?;
+                record_vectors.push(Self::to_record_vector(&record, index, account)?);
+                serial_numbers.push(Self::serial_number(&record, account)?);
+            }
+
+            let mut commitments = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
+            for _ in 0..N::NUM_OUTPUT_RECORDS {
+                let recipient = &accounts[recipient_index];
+                let record = Self::sample_record(recipient, rng)?;
+                record_vectors.push(Self::to_record_vector(&record, recipient_index, recipient)?);
+                commitments.push(record.commitment());
+            }
+
+            transition_vectors.push(TransitionTestVector {
+                serial_numbers: serial_numbers
+                    .iter()
+                    .map(|serial_number| serial_number.to_string())
+                    .collect(),
+                commitments: commitments.iter().map(|commitment| commitment.to_string()).collect(),
+                transition_id: Transition::<N>::compute_transition_id(&serial_numbers, &commitments)?.to_string(),
+            });
+        }
+
+        Ok(Self {
+            version: TEST_VECTORS_VERSION,
+            network_id: N::NETWORK_ID,
+            network_name: N::NETWORK_NAME.to_string(),
+            seed,
+            accounts: account_vectors,
+            records: record_vectors,
+            transitions: transition_vectors,
+        })
+    }
+
+    /// Returns the test vectors from the given JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the test vectors as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Ensures the test vectors are for the given network, and match the vectors that this version of snarkVM generates.
+    pub fn verify<N: Network>(&self) -> Result<()> {
+        ensure!(
+            self.version == TEST_VECTORS_VERSION,
+            "Unsupported test vectors version {}",
+            self.version
+        );
+        ensure!(
+            self.network_id == N::NETWORK_ID,
+            "Test vectors are for network {}, not {}",
+            self.network_id,
+            N::NETWORK_ID
+        );
+
+        let expected = Self::generate::<N>(self.seed)?;
+        ensure!(self.accounts == expected.accounts, "Mismatched account test vectors");
+        ensure!(self.records == expected.records, "Mismatched record test vectors");
+        ensure!(
+            self.transitions == expected.transitions,
+            "Mismatched transition test vectors"
+        );
+        Ok(())
+    }
+
+    /// Returns a record owned by the given account, with a value and payload sampled from the given RNG.
+    fn sample_record<N: Network, R: Rng + CryptoRng>(account: &Account<N>, rng: &mut R) -> Result<Record<N>> {
+        let value = AleoAmount::from_i64(rng.gen_range(0..1_000_000_000));
+        let payload: Vec<u8> = (0..Payload::<N>::size()).map(|_| rng.gen()).collect();
+        Ok(Record::new(
+            account.address(),
+            value,
+            Payload::from(&payload),
+            *N::noop_program_id(),
+            rng,
+        )?)
+    }
+
+    /// Returns the serial number of the given record, which is owned by the given account.
+    fn serial_number<N: Network>(record: &Record<N>, account: &Account<N>) -> Result<N::SerialNumber> {
+        Ok(record.to_serial_number(&ComputeKey::from_private_key(account.private_key()))?)
+    }
+
+    /// Returns the test vector of the given record, after ensuring it decrypts with the view key of its owner.
+    fn to_record_vector<N: Network>(
+        record: &Record<N>,
+        owner_index: usize,
+        owner: &Account<N>,
+    ) -> Result<RecordTestVector> {
+        let decrypted = Record::<N>::from_account_view_key(owner.view_key(), record.ciphertext())?;
+        if &decrypted != record {
+            return Err(anyhow!("Record {} does not decrypt to itself", record.commitment()));
+        }
+
+        Ok(RecordTestVector {
+            owner_index,
+            owner: record.owner().to_string(),
+            value: record.value().as_i64(),
+            payload: hex::encode(record.payload().to_bytes_le()?),
+            program_id: record.program_id().to_string(),
+            record_view_key: record.record_view_key().to_string(),
+            ciphertext: record.ciphertext().to_string(),
+            commitment: record.commitment().to_string(),
+            serial_number: Self::serial_number(record, owner)?.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, testnet2::Testnet2, Address, PrivateKey, ViewKey};
+
+    use std::str::FromStr;
+
+    fn test_vectors<N: Network>() {
+        let test_vectors = TestVectors::generate::<N>(TEST_VECTORS_SEED).unwrap();
+        assert_eq!(N::NETWORK_ID, test_vectors.network_id);
+        assert_eq!(NUM_TEST_ACCOUNTS, test_vectors.accounts.len());
+        assert_eq!(NUM_TEST_ACCOUNTS * N::NUM_TOTAL_RECORDS, test_vectors.records.len());
+        assert_eq!(NUM_TEST_ACCOUNTS, test_vectors.transitions.len());
+
+        // Ensure the test vectors are deterministic, and round-trip through JSON.
+        assert_eq!(test_vectors, TestVectors::generate::<N>(TEST_VECTORS_SEED).unwrap());
+        let candidate = TestVectors::from_json(&test_vectors.to_json().unwrap()).unwrap();
+        assert_eq!(test_vectors, candidate);
+        assert!(candidate.verify::<N>().is_ok());
+
+        // Ensure each account vector reproduces from its private key.
+        for account in &test_vectors.accounts {
+            let private_key = PrivateKey::<N>::from_str(&account.private_key).unwrap();
+            assert_eq!(account.address, Address::from_private_key(&private_key).to_string());
+            assert_eq!(account.view_key, ViewKey::from_private_key(&private_key).to_string());
+        }
+
+        // Ensure modified test vectors fail to verify.
+        let mut invalid = test_vectors.clone();
+        invalid.transitions[0].transition_id = invalid.transitions[1].transition_id.clone();
+        assert!(invalid.verify::<N>().is_err());
+
+        let mut invalid = test_vectors;
+        invalid.version += 1;
+        assert!(invalid.verify::<N>().is_err());
+    }
+
+    #[test]
+    fn test_testnet1_test_vectors() {
+        test_vectors::<Testnet1>();
+    }
+
+    #[test]
+    fn test_testnet2_test_vectors() {
+        test_vectors::<Testnet2>();
+
+        // Ensure test vectors of one network do not verify for another.
+        let test_vectors = TestVectors::generate::<Testnet2>(TEST_VECTORS_SEED).unwrap();
+        assert!(test_vectors.verify::<Testnet1>().is_err());
+    }
+}
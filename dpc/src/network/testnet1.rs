@@ -21,12 +21,23 @@ use crate::{
     AleoObject,
     Block,
     Ciphertext,
+    DifficultyAnchor,
+    DifficultyRetarget,
     InnerPublicVariables,
-    Network,
+    LedgerRootMigration,
+    LedgerRootWindow,
+    NetworkConstants,
+    NetworkCrypto,
+    NetworkParameters,
+    NetworkUpgrade,
     OuterPublicVariables,
+    PoSWMode,
+    PoSWRegistry,
     PoSWScheme,
     Program,
     ProgramPublicVariables,
+    RecordCiphertextFormat,
+    UpgradeSchedule,
 };
 use snarkvm_algorithms::{
     crh::{PedersenCompressedCRH, PoseidonCRH, BHPCRH},
@@ -77,7 +88,7 @@ use std::{cell::RefCell, rc::Rc};
 pub struct Testnet1;
 
 #[rustfmt::skip]
-impl Network for Testnet1 {
+impl NetworkConstants for Testnet1 {
     const NETWORK_ID: u16 = 1u16;
     const NETWORK_NAME: &'static str = "testnet1";
 
@@ -111,6 +122,8 @@ impl Network for Testnet1 {
     const RECORD_VIEW_KEY_PREFIX: u32 = hrp4!("rcvk");
     const SIGNATURE_PREFIX: u32 = hrp4!("sign");
 
+    const ACCEPTS_BECH32: bool = false;
+
     const ADDRESS_SIZE_IN_BYTES: usize = 32;
     const HEADER_SIZE_IN_BYTES: usize = 903;
     const HEADER_PROOF_SIZE_IN_BYTES: usize = 771;
@@ -123,6 +136,10 @@ impl Network for Testnet1 {
     const RECORD_VIEW_KEY_SIZE_IN_BYTES: usize = 32;
     const SIGNATURE_SIZE_IN_BYTES: usize = 128;
 
+    const MAX_BLOCK_SIZE_IN_BYTES: usize = 128 * 1024 * 1024;
+    const MAX_TRANSACTION_DATA_SIZE_IN_BYTES: usize = 16 * 1024;
+    const TRANSACTION_DATA_FEE_PER_BYTE: i64 = 1_000;
+
     const HEADER_TRANSACTIONS_TREE_DEPTH: usize = 15;
     const HEADER_TREE_DEPTH: usize = 2;
     const LEDGER_TREE_DEPTH: usize = 32;
@@ -134,7 +151,25 @@ impl Network for Testnet1 {
     const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
     const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
     const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const COINBASE_MATURITY: u32 = 0;
+
+    const UPGRADE_SCHEDULE: UpgradeSchedule = UpgradeSchedule::new(&[
+        NetworkUpgrade {
+            start_height: 0,
+            posw_mode: PoSWMode::NonHiding,
+            posw_proof_size_in_bytes: Self::HEADER_PROOF_SIZE_IN_BYTES,
+            difficulty_retarget: DifficultyRetarget::Asert,
+            difficulty_anchor: DifficultyAnchor::Height(0),
+            record_ciphertext_format: RecordCiphertextFormat::Standard,
+            ledger_root_migration: LedgerRootMigration::None,
+            transaction_data: false,
+            ledger_root_window: LedgerRootWindow::Unbounded,
+        },
+    ]);
+}
 
+#[rustfmt::skip]
+impl NetworkCrypto for Testnet1 {
     type InnerCurve = Bls12_377;
     type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
     
@@ -149,23 +184,6 @@ impl Network for Testnet1 {
     type ProgramBaseField = <Self::ProgramCurveParameters as ModelParameters>::BaseField;
     type ProgramScalarField = <Self::ProgramCurveParameters as ModelParameters>::ScalarField;
 
-    type InnerSNARK = Groth16<Self::InnerCurve, InnerPublicVariables<Testnet1>>;
-    type InnerSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
-    type InnerProof = AleoObject<<Self::InnerSNARK as SNARK>::Proof, { Self::INNER_PROOF_PREFIX }, { Self::INNER_PROOF_SIZE_IN_BYTES }>;
-
-    type OuterSNARK = Groth16<Self::OuterCurve, OuterPublicVariables<Testnet1>>;
-    type OuterProof = AleoObject<<Self::OuterSNARK as SNARK>::Proof, { Self::OUTER_PROOF_PREFIX }, { Self::OUTER_PROOF_SIZE_IN_BYTES }>;
-
-    type ProgramSNARK = Groth16<Self::InnerCurve, ProgramPublicVariables<Self>>;
-    type ProgramSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
-    type ProgramProvingKey = <Self::ProgramSNARK as SNARK>::ProvingKey;
-    type ProgramVerifyingKey = <Self::ProgramSNARK as SNARK>::VerifyingKey;
-    type ProgramProof = AleoObject<<Self::ProgramSNARK as SNARK>::Proof, { Self::PROGRAM_PROOF_PREFIX }, { Self::PROGRAM_PROOF_SIZE_IN_BYTES }>;
-
-    type PoSWSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirAlgebraicSpongeRng<Self::InnerScalarField, Self::OuterScalarField, PoseidonSponge<Self::OuterScalarField, 6, 1>>, MarlinPoswMode, Vec<Self::InnerScalarField>>;
-    type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }>;
-    type PoSW = PoSW<Self>;
-
     type AccountEncryptionScheme = ECIESPoseidonEncryption<Self::ProgramCurveParameters>;
     type AccountEncryptionGadget = ECIESPoseidonEncryptionGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
 
@@ -175,70 +193,69 @@ impl Network for Testnet1 {
     type AccountSignatureScheme = AleoSignatureScheme<Self::ProgramCurveParameters>;
     type AccountSignatureGadget = AleoSignatureSchemeGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
     type AccountSignaturePublicKey = <Self::AccountSignatureScheme as SignatureScheme>::PublicKey;
-    type AccountSignature = AleoObject<<Self::AccountSignatureScheme as SignatureScheme>::Signature, { Self::SIGNATURE_PREFIX }, { Self::SIGNATURE_SIZE_IN_BYTES }>;
+    type AccountSignature = AleoObject<<Self::AccountSignatureScheme as SignatureScheme>::Signature, { Self::SIGNATURE_PREFIX }, { Self::SIGNATURE_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
 
     type BlockHashCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type BlockHashCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
-    type BlockHash = AleoLocator<<Self::BlockHashCRH as CRH>::Output, { Self::BLOCK_HASH_PREFIX }>;
+    type BlockHash = AleoLocator<<Self::BlockHashCRH as CRH>::Output, { Self::BLOCK_HASH_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type BlockHeaderRootCRH = PedersenCompressedCRH<Self::ProgramProjectiveCurve, 4, 128>;
     type BlockHeaderRootCRHGadget = PedersenCompressedCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 4, 128>;
     type BlockHeaderRootParameters = MaskedMerkleTreeParameters<Self::BlockHeaderRootCRH, { Self::HEADER_TREE_DEPTH }>;
-    type BlockHeaderRoot = AleoLocator<<Self::BlockHeaderRootCRH as CRH>::Output, { Self::HEADER_ROOT_PREFIX }>;
+    type BlockHeaderRoot = AleoLocator<<Self::BlockHeaderRootCRH as CRH>::Output, { Self::HEADER_ROOT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type CommitmentScheme = BHPCRH<Self::ProgramProjectiveCurve, 41, 63>;
     type CommitmentGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 41, 63>;
-    type Commitment = AleoLocator<<Self::CommitmentScheme as CRH>::Output, { Self::COMMITMENT_PREFIX }>;
+    type Commitment = AleoLocator<<Self::CommitmentScheme as CRH>::Output, { Self::COMMITMENT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type FunctionIDCRH = PoseidonCRH<Self::OuterScalarField, 34>;
     type FunctionIDCRHGadget = PoseidonCRHGadget<Self::OuterScalarField, 34>;
-    type FunctionID = AleoLocator<<Self::FunctionIDCRH as CRH>::Output, { Self::FUNCTION_ID_PREFIX }>;
+    type FunctionID = AleoLocator<<Self::FunctionIDCRH as CRH>::Output, { Self::FUNCTION_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type FunctionInputsCRH = PoseidonCRH<Self::InnerScalarField, 128>;
     type FunctionInputsCRHGadget = PoseidonCRHGadget<Self::InnerScalarField, 128>;
-    type FunctionInputsHash = AleoLocator<<Self::FunctionInputsCRH as CRH>::Output, { Self::FUNCTION_INPUTS_HASH_PREFIX }>;
+    type FunctionInputsHash = AleoLocator<<Self::FunctionInputsCRH as CRH>::Output, { Self::FUNCTION_INPUTS_HASH_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type InnerCircuitIDCRH = BHPCRH<EdwardsBW6, 85, 63>;
     type InnerCircuitIDCRHGadget = BHPCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget, 85, 63>;
-    type InnerCircuitID = AleoLocator<<Self::InnerCircuitIDCRH as CRH>::Output, { Self::INNER_CIRCUIT_ID_PREFIX }>;
+    type InnerCircuitID = AleoLocator<<Self::InnerCircuitIDCRH as CRH>::Output, { Self::INNER_CIRCUIT_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type LedgerRootCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type LedgerRootCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
     type LedgerRootParameters = MerkleTreeParameters<Self::LedgerRootCRH, { Self::LEDGER_TREE_DEPTH }>;
-    type LedgerRoot = AleoLocator<<Self::LedgerRootCRH as CRH>::Output, { Self::LEDGER_ROOT_PREFIX }>;
+    type LedgerRoot = AleoLocator<<Self::LedgerRootCRH as CRH>::Output, { Self::LEDGER_ROOT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type PoSWMaskPRF = PoseidonPRF<Self::InnerScalarField, 4, false>;
     type PoSWMaskPRFGadget = PoseidonPRFGadget<Self::InnerScalarField, 4, false>;
-    type PoSWNonce = AleoLocator<Self::InnerScalarField, { Self::HEADER_NONCE_PREFIX }>;
+    type PoSWNonce = AleoLocator<Self::InnerScalarField, { Self::HEADER_NONCE_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type ProgramIDCRH = BHPCRH<EdwardsBW6, 16, 48>;
     type ProgramIDCRHGadget = BHPCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget, 16, 48>;
     type ProgramIDParameters = MerkleTreeParameters<Self::ProgramIDCRH, { Self::PROGRAM_TREE_DEPTH }>;
-    type ProgramID = AleoLocator<<Self::ProgramIDCRH as CRH>::Output, { Self::PROGRAM_ID_PREFIX }>;
+    type ProgramID = AleoLocator<<Self::ProgramIDCRH as CRH>::Output, { Self::PROGRAM_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
-    type RecordCiphertext = AleoObject<Ciphertext<Self>, { Self::RECORD_CIPHERTEXT_PREFIX }, { Self::RECORD_CIPHERTEXT_SIZE_IN_BYTES }>;
-    type RecordRandomizer = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::CiphertextRandomizer, { Self::RECORD_RANDOMIZER_PREFIX }>;
-    type RecordViewKey = AleoObject<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKey, { Self::RECORD_VIEW_KEY_PREFIX }, { Self::RECORD_VIEW_KEY_SIZE_IN_BYTES }>;
-    type RecordViewKeyCommitment = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKeyCommitment, { Self::RECORD_VIEW_KEY_COMMITMENT_PREFIX }>;
+    type RecordRandomizer = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::CiphertextRandomizer, { Self::RECORD_RANDOMIZER_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+    type RecordViewKey = AleoObject<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKey, { Self::RECORD_VIEW_KEY_PREFIX }, { Self::RECORD_VIEW_KEY_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+    type RecordViewKeyCommitment = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKeyCommitment, { Self::RECORD_VIEW_KEY_COMMITMENT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type SerialNumberPRF = PoseidonPRF<Self::InnerScalarField, 4, false>;
     type SerialNumberPRFGadget = PoseidonPRFGadget<Self::InnerScalarField, 4, false>;
-    type SerialNumber = AleoLocator<<Self::SerialNumberPRF as PRF>::Output, { Self::SERIAL_NUMBER_PREFIX }>;
+    type SerialNumber = AleoLocator<<Self::SerialNumberPRF as PRF>::Output, { Self::SERIAL_NUMBER_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type TransactionsRootCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type TransactionsRootCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
     type TransactionsRootParameters = MerkleTreeParameters<Self::TransactionsRootCRH, { Self::HEADER_TRANSACTIONS_TREE_DEPTH }>;
-    type TransactionsRoot = AleoLocator<<Self::TransactionsRootCRH as CRH>::Output, { Self::HEADER_TRANSACTIONS_ROOT_PREFIX }>;
+    type TransactionsRoot = AleoLocator<<Self::TransactionsRootCRH as CRH>::Output, { Self::HEADER_TRANSACTIONS_ROOT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type TransactionIDCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type TransactionIDCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
     type TransactionIDParameters = MerkleTreeParameters<Self::TransactionIDCRH, { Self::TRANSACTION_TREE_DEPTH }>;
-    type TransactionID = AleoLocator<<Self::TransactionIDCRH as CRH>::Output, { Self::TRANSACTION_ID_PREFIX }>;
+    type TransactionID = AleoLocator<<Self::TransactionIDCRH as CRH>::Output, { Self::TRANSACTION_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     type TransitionIDCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type TransitionIDCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
     type TransitionIDParameters = MerkleTreeParameters<Self::TransitionIDCRH, { Self::TRANSITION_TREE_DEPTH }>;
-    type TransitionID = AleoLocator<<Self::TransitionIDCRH as CRH>::Output, { Self::TRANSITION_ID_PREFIX }>;
+    type TransitionID = AleoLocator<<Self::TransitionIDCRH as CRH>::Output, { Self::TRANSITION_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
 
     dpc_setup!{Testnet1, account_encryption_scheme, AccountEncryptionScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
     dpc_setup!{Testnet1, account_signature_scheme, AccountSignatureScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
@@ -253,10 +270,39 @@ impl Network for Testnet1 {
     dpc_setup!{Testnet1, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0"}
     dpc_setup!{Testnet1, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0"}
 
-    dpc_snark_setup!{Testnet1, inner_proving_key, InnerSNARK, ProvingKey, InnerProvingKeyBytes, "inner proving key"}
+    dpc_empty_hash!{Testnet1, block_header_root_empty_hash, BlockHeaderRootCRH, block_header_root_parameters}
+    dpc_empty_hash!{Testnet1, ledger_root_empty_hash, LedgerRootCRH, ledger_root_parameters}
+    dpc_empty_hash!{Testnet1, program_id_empty_hash, ProgramIDCRH, program_id_parameters}
+    dpc_empty_hash!{Testnet1, transactions_root_empty_hash, TransactionsRootCRH, transactions_root_parameters}
+    dpc_empty_hash!{Testnet1, transaction_id_empty_hash, TransactionIDCRH, transaction_id_parameters}
+    dpc_empty_hash!{Testnet1, transition_id_empty_hash, TransitionIDCRH, transition_id_parameters}
+}
+
+#[rustfmt::skip]
+impl NetworkParameters for Testnet1 {
+    type InnerSNARK = Groth16<Self::InnerCurve, InnerPublicVariables<Testnet1>>;
+    type InnerSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
+    type InnerProof = AleoObject<<Self::InnerSNARK as SNARK>::Proof, { Self::INNER_PROOF_PREFIX }, { Self::INNER_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type OuterSNARK = Groth16<Self::OuterCurve, OuterPublicVariables<Testnet1>>;
+    type OuterProof = AleoObject<<Self::OuterSNARK as SNARK>::Proof, { Self::OUTER_PROOF_PREFIX }, { Self::OUTER_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type ProgramSNARK = Groth16<Self::InnerCurve, ProgramPublicVariables<Self>>;
+    type ProgramSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
+    type ProgramProvingKey = <Self::ProgramSNARK as SNARK>::ProvingKey;
+    type ProgramVerifyingKey = <Self::ProgramSNARK as SNARK>::VerifyingKey;
+    type ProgramProof = AleoObject<<Self::ProgramSNARK as SNARK>::Proof, { Self::PROGRAM_PROOF_PREFIX }, { Self::PROGRAM_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type PoSWSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirAlgebraicSpongeRng<Self::InnerScalarField, Self::OuterScalarField, PoseidonSponge<Self::OuterScalarField, 6, 1>>, MarlinPoswMode, Vec<Self::InnerScalarField>>;
+    type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+    type PoSW = PoSW<Self>;
+
+    type RecordCiphertext = AleoObject<Ciphertext<Self>, { Self::RECORD_CIPHERTEXT_PREFIX }, { Self::RECORD_CIPHERTEXT_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    dpc_snark_setup!{Testnet1, inner_proving_key, try_inner_proving_key, InnerSNARK, ProvingKey, InnerProvingKeyBytes, "inner proving key"}
     dpc_snark_setup!{Testnet1, inner_verifying_key, InnerSNARK, VerifyingKey, InnerVerifyingKeyBytes, "inner verifying key"}
 
-    dpc_snark_setup!{Testnet1, outer_proving_key, OuterSNARK, ProvingKey, OuterProvingKeyBytes, "outer proving key"}
+    dpc_snark_setup!{Testnet1, outer_proving_key, try_outer_proving_key, OuterSNARK, ProvingKey, OuterProvingKeyBytes, "outer proving key"}
     dpc_snark_setup!{Testnet1, outer_verifying_key, OuterSNARK, VerifyingKey, OuterVerifyingKeyBytes, "outer verifying key"}
 
     dpc_snark_setup!{Testnet1, noop_circuit_proving_key, ProgramSNARK, ProvingKey, NoopProvingKeyBytes, "noop circuit proving key"}
@@ -266,9 +312,9 @@ impl Network for Testnet1 {
     dpc_snark_setup!{Testnet1, posw_verifying_key, PoSWSNARK, VerifyingKey, PoSWVerifyingKeyBytes, "posw verifying key"}
 
     fn inner_circuit_id() -> &'static Self::InnerCircuitID {
-        static INNER_CIRCUIT_ID: OnceCell<<Testnet1 as Network>::InnerCircuitID> = OnceCell::new();
+        static INNER_CIRCUIT_ID: OnceCell<<Testnet1 as NetworkCrypto>::InnerCircuitID> = OnceCell::new();
         INNER_CIRCUIT_ID.get_or_init(|| Self::inner_circuit_id_crh()
-            .hash_bits(&Self::inner_verifying_key().to_minimal_bits())
+            .hash_bits(&Self::inner_verifying_key().to_minimal_bits_v1())
             .expect("Failed to hash inner circuit verifying key elements").into())
     }
 
@@ -278,25 +324,30 @@ impl Network for Testnet1 {
     }
 
     fn noop_program_id() -> &'static Self::ProgramID {
-        static NOOP_PROGRAM_ID: OnceCell<<Testnet1 as Network>::ProgramID> = OnceCell::new();
+        static NOOP_PROGRAM_ID: OnceCell<<Testnet1 as NetworkCrypto>::ProgramID> = OnceCell::new();
         NOOP_PROGRAM_ID.get_or_init(|| Testnet1::noop_program().program_id())
     }
     
     fn noop_program_path() -> &'static MerklePath<Self::ProgramIDParameters> {
-        static NOOP_PROGRAM_PATH: OnceCell<MerklePath<<Testnet1 as Network>::ProgramIDParameters>> = OnceCell::new();
+        static NOOP_PROGRAM_PATH: OnceCell<MerklePath<<Testnet1 as NetworkCrypto>::ProgramIDParameters>> = OnceCell::new();
         NOOP_PROGRAM_PATH.get_or_init(|| Self::noop_program().to_program_path(Self::noop_function_id()).expect("Failed to fetch the noop program path"))
     }
     
     fn noop_function_id() -> &'static Self::FunctionID {
-        static NOOP_FUNCTION_ID: OnceCell<<Testnet1 as Network>::FunctionID> = OnceCell::new();
+        static NOOP_FUNCTION_ID: OnceCell<<Testnet1 as NetworkCrypto>::FunctionID> = OnceCell::new();
         NOOP_FUNCTION_ID.get_or_init(|| Self::function_id(Self::noop_circuit_verifying_key()).expect("Failed to hash noop circuit verifying key"))
     }
 
     fn posw() -> &'static Self::PoSW {
-        static POSW: OnceCell<<Testnet1 as Network>::PoSW> = OnceCell::new();
+        static POSW: OnceCell<<Testnet1 as NetworkParameters>::PoSW> = OnceCell::new();
         POSW.get_or_init(|| <Self::PoSW as PoSWScheme<Self>>::load(true).expect("Failed to load PoSW"))        
     }
 
+    fn posw_registry() -> &'static PoSWRegistry<'static, Self> {
+        static POSW_REGISTRY: OnceCell<PoSWRegistry<'static, Testnet1>> = OnceCell::new();
+        POSW_REGISTRY.get_or_init(PoSWRegistry::from_network)
+    }
+
     fn genesis_block() -> &'static Block<Self> {
         static BLOCK: OnceCell<Block<Testnet1>> = OnceCell::new();
         BLOCK.get_or_init(|| FromBytes::read_le(&GenesisBlock::load_bytes()[..]).expect("Failed to load genesis block"))
@@ -333,7 +384,7 @@ mod tests {
         assert_eq!(
             Testnet1::inner_circuit_id(),
             &Testnet1::inner_circuit_id_crh()
-                .hash_bits(&Testnet1::inner_verifying_key().to_minimal_bits())
+                .hash_bits(&Testnet1::inner_verifying_key().to_minimal_bits_v1())
                 .expect("Failed to hash inner circuit ID")
                 .into(),
             "The inner circuit ID does not correspond to the inner circuit verifying key"
@@ -355,7 +406,7 @@ mod tests {
         // Verify the PoSW tree depth matches the declared depth.
         assert_eq!(
             Testnet1::HEADER_TREE_DEPTH,
-            <<Testnet1 as Network>::BlockHeaderRootParameters as MerkleParameters>::DEPTH
+            <<Testnet1 as NetworkCrypto>::BlockHeaderRootParameters as MerkleParameters>::DEPTH
         );
     }
 }
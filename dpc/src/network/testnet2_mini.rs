@@ -0,0 +1,493 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT,
+    posw::PoSW,
+    Account,
+    AccountScheme,
+    AleoLocator,
+    AleoObject,
+    Block,
+    Ciphertext,
+    DifficultyAnchor,
+    DifficultyRetarget,
+    Execution,
+    Function,
+    InnerCircuit,
+    InnerPublicVariables,
+    LedgerRootMigration,
+    LedgerRootWindow,
+    NetworkConstants,
+    NetworkCrypto,
+    NetworkParameters,
+    NetworkUpgrade,
+    Noop,
+    NoopPrivateVariables,
+    OuterCircuit,
+    OuterPublicVariables,
+    PoSWMode,
+    PoSWRegistry,
+    PoSWScheme,
+    Program,
+    ProgramPrivateVariables,
+    ProgramPublicVariables,
+    RecordCiphertextFormat,
+    UpgradeSchedule,
+};
+use blake2::Blake2s;
+use snarkvm_algorithms::{
+    crh::{PedersenCompressedCRH, PoseidonCRH, BHPCRH},
+    encryption::ECIESPoseidonEncryption,
+    merkle_tree::{MaskedMerkleTreeParameters, MerklePath, MerkleTreeParameters},
+    prelude::*,
+    prf::PoseidonPRF,
+    signature::AleoSignatureScheme,
+    snark::groth16::Groth16,
+};
+use snarkvm_curves::{
+    bls12_377::Bls12_377,
+    bw6_761::BW6_761,
+    edwards_bls12::{
+        EdwardsAffine as EdwardsBls12Affine,
+        EdwardsParameters,
+        EdwardsProjective as EdwardsBls12Projective,
+    },
+    edwards_bw6::EdwardsProjective as EdwardsBW6,
+    traits::*,
+};
+use snarkvm_gadgets::{
+    algorithms::{
+        crh::{BHPCRHGadget, PedersenCompressedCRHGadget, PoseidonCRHGadget},
+        encryption::ECIESPoseidonEncryptionGadget,
+        prf::PoseidonPRFGadget,
+        signature::AleoSignatureSchemeGadget,
+        snark::Groth16VerifierGadget,
+    },
+    curves::{bls12_377::PairingGadget, edwards_bls12::EdwardsBls12Gadget, edwards_bw6::EdwardsBW6Gadget},
+};
+use snarkvm_marlin::{
+    constraints::{snark::MarlinSNARK, verifier::MarlinVerificationGadget},
+    marlin::{MarlinPoswMode, MarlinTestnet2Mode},
+    FiatShamirAlgebraicSpongeRng,
+    FiatShamirChaChaRng,
+    PoseidonSponge,
+};
+use snarkvm_parameters::testnet2::{
+    NoopProvingKeyBytes,
+    NoopVerifyingKeyBytes,
+    PoSWProvingKeyBytes,
+    PoSWVerifyingKeyBytes,
+    UniversalSRSBytes,
+};
+use snarkvm_polycommit::sonic_pc::{sonic_kzg10::SonicKZG10Gadget, SonicKZG10};
+use snarkvm_utilities::ToMinimalBits;
+
+use once_cell::sync::OnceCell;
+use rand::{thread_rng, CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// A test network with the schemes of `Testnet2` and tiny tree depths, for fast local testing.
+///
+/// The inner and outer circuit parameters, and the genesis block, are generated at runtime,
+/// as they depend on the tree depths. The noop and PoSW parameters are shared with `Testnet2`.
+pub struct Testnet2Mini;
+
+#[rustfmt::skip]
+impl NetworkConstants for Testnet2Mini {
+    const NETWORK_ID: u16 = u16::MAX;
+    const NETWORK_NAME: &'static str = "testnet2_mini";
+
+    const NUM_INPUT_RECORDS: usize = 2;
+    const NUM_OUTPUT_RECORDS: usize = 2;
+    const NUM_TRANSITIONS: u8 = u8::pow(2, Self::TRANSACTION_TREE_DEPTH as u32);
+    const NUM_EVENTS: u16 = 2;
+    // Enabled on Testnet2Mini only, as its noop, inner, and outer circuit keys are generated at runtime.
+    // The commitments span the input records and the first output record.
+    const NUM_PAYLOAD_COMMITMENTS: usize = Self::NUM_INPUT_RECORDS + 1;
+
+    const BLOCK_HASH_PREFIX: u16 = hrp2!("ab");
+    const LEDGER_ROOT_PREFIX: u16 = hrp2!("al");
+    const PROGRAM_ID_PREFIX: u16 = hrp2!("ap");
+    const TRANSITION_ID_PREFIX: u16 = hrp2!("as");
+    const TRANSACTION_ID_PREFIX: u16 = hrp2!("at");
+
+    const COMMITMENT_PREFIX: u16 = hrp2!("cm");
+    const FUNCTION_INPUTS_HASH_PREFIX: u16 = hrp2!("fi");
+    const FUNCTION_ID_PREFIX: u16 = hrp2!("fn");
+    const HEADER_NONCE_PREFIX: u16 = hrp2!("hn");
+    const HEADER_ROOT_PREFIX: u16 = hrp2!("hr");
+    const HEADER_TRANSACTIONS_ROOT_PREFIX: u16 = hrp2!("ht");
+    const INNER_CIRCUIT_ID_PREFIX: u16 = hrp2!("ic");
+    const RECORD_RANDOMIZER_PREFIX: u16 = hrp2!("rr");
+    const RECORD_VIEW_KEY_COMMITMENT_PREFIX: u16 = hrp2!("rc");
+    const SERIAL_NUMBER_PREFIX: u16 = hrp2!("sn");
+
+    const HEADER_PROOF_PREFIX: u32 = hrp4!("hzkp");
+    const INNER_PROOF_PREFIX: u32 = hrp4!("izkp");
+    const OUTER_PROOF_PREFIX: u32 = hrp4!("ozkp");
+    const PROGRAM_PROOF_PREFIX: u32 = hrp4!("pzkp");
+    const RECORD_CIPHERTEXT_PREFIX: u32 = hrp4!("recd");
+    const RECORD_VIEW_KEY_PREFIX: u32 = hrp4!("rcvk");
+    const SIGNATURE_PREFIX: u32 = hrp4!("sign");
+
+    const ACCEPTS_BECH32: bool = false;
+
+    const ADDRESS_SIZE_IN_BYTES: usize = 32;
+    const HEADER_SIZE_IN_BYTES: usize = 903;
+    const HEADER_PROOF_SIZE_IN_BYTES: usize = 771;
+    const INNER_PROOF_SIZE_IN_BYTES: usize = 193;
+    const OUTER_PROOF_SIZE_IN_BYTES: usize = 289;
+    const PROGRAM_PROOF_SIZE_IN_BYTES: usize = 916;
+    // Includes the 12 bytes of the record locktime.
+    const RECORD_SIZE_IN_BYTES: usize = 292;
+    const RECORD_CIPHERTEXT_SIZE_IN_BYTES: usize = 320;
+    const RECORD_PAYLOAD_SIZE_IN_BYTES: usize = 128;
+    const RECORD_VIEW_KEY_SIZE_IN_BYTES: usize = 32;
+    const SIGNATURE_SIZE_IN_BYTES: usize = 128;
+
+    const MAX_BLOCK_SIZE_IN_BYTES: usize = 128 * 1024 * 1024;
+    const MAX_TRANSACTION_DATA_SIZE_IN_BYTES: usize = 16 * 1024;
+    const TRANSACTION_DATA_FEE_PER_BYTE: i64 = 1_000;
+
+    const HEADER_TRANSACTIONS_TREE_DEPTH: usize = 4;
+    const HEADER_TREE_DEPTH: usize = 2;
+    const LEDGER_TREE_DEPTH: usize = 4;
+    const PROGRAM_TREE_DEPTH: usize = 2;
+    const TRANSITION_TREE_DEPTH: usize = 2;
+    const TRANSACTION_TREE_DEPTH: usize = 1;
+
+    const ALEO_BLOCK_TIME_IN_SECS: i64 = 20i64;
+    const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
+    const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
+    const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const COINBASE_MATURITY: u32 = 0;
+    // Enabled on Testnet2Mini only, as its inner and outer circuit keys are generated at runtime.
+    const RECORD_LOCKTIMES_ENABLED: bool = true;
+    // Enabled on Testnet2Mini only, as its inner and outer circuit keys are generated at runtime.
+    const PROGRAM_FEES_ENABLED: bool = true;
+
+    const UPGRADE_SCHEDULE: UpgradeSchedule = UpgradeSchedule::new(&[
+        NetworkUpgrade {
+            start_height: 0,
+            posw_mode: PoSWMode::NonHiding,
+            posw_proof_size_in_bytes: Self::HEADER_PROOF_SIZE_IN_BYTES,
+            difficulty_retarget: DifficultyRetarget::Asert,
+            difficulty_anchor: DifficultyAnchor::Height(0),
+            record_ciphertext_format: RecordCiphertextFormat::Standard,
+            ledger_root_migration: LedgerRootMigration::None,
+            transaction_data: true,
+            ledger_root_window: LedgerRootWindow::Blocks(4096),
+        },
+    ]);
+}
+
+#[rustfmt::skip]
+impl NetworkCrypto for Testnet2Mini {
+    type InnerCurve = Bls12_377;
+    type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
+
+    type OuterCurve = BW6_761;
+    type OuterBaseField = <Self::OuterCurve as PairingEngine>::Fq;
+    type OuterScalarField = <Self::OuterCurve as PairingEngine>::Fr;
+
+    type ProgramAffineCurve = EdwardsBls12Affine;
+    type ProgramAffineCurveGadget = EdwardsBls12Gadget;
+    type ProgramProjectiveCurve = EdwardsBls12Projective;
+    type ProgramCurveParameters = EdwardsParameters;
+    type ProgramBaseField = <Self::ProgramCurveParameters as ModelParameters>::BaseField;
+    type ProgramScalarField = <Self::ProgramCurveParameters as ModelParameters>::ScalarField;
+
+    type AccountEncryptionScheme = ECIESPoseidonEncryption<Self::ProgramCurveParameters>;
+    type AccountEncryptionGadget = ECIESPoseidonEncryptionGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
+
+    type AccountSeedPRF = PoseidonPRF<Self::ProgramScalarField, 4, false>;
+    type AccountSeed = <Self::AccountSeedPRF as PRF>::Seed;
+
+    type AccountSignatureScheme = AleoSignatureScheme<Self::ProgramCurveParameters>;
+    type AccountSignatureGadget = AleoSignatureSchemeGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
+    type AccountSignaturePublicKey = <Self::AccountSignatureScheme as SignatureScheme>::PublicKey;
+    type AccountSignature = AleoObject<<Self::AccountSignatureScheme as SignatureScheme>::Signature, { Self::SIGNATURE_PREFIX }, { Self::SIGNATURE_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type BlockHashCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type BlockHashCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type BlockHash = AleoLocator<<Self::BlockHashCRH as CRH>::Output, { Self::BLOCK_HASH_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type BlockHeaderRootCRH = PedersenCompressedCRH<Self::ProgramProjectiveCurve, 4, 128>;
+    type BlockHeaderRootCRHGadget = PedersenCompressedCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 4, 128>;
+    type BlockHeaderRootParameters = MaskedMerkleTreeParameters<Self::BlockHeaderRootCRH, { Self::HEADER_TREE_DEPTH }>;
+    type BlockHeaderRoot = AleoLocator<<Self::BlockHeaderRootCRH as CRH>::Output, { Self::HEADER_ROOT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type CommitmentScheme = BHPCRH<Self::ProgramProjectiveCurve, 41, 63>;
+    type CommitmentGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 41, 63>;
+    type Commitment = AleoLocator<<Self::CommitmentScheme as CRH>::Output, { Self::COMMITMENT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type FunctionIDCRH = PoseidonCRH<Self::OuterScalarField, 34>;
+    type FunctionIDCRHGadget = PoseidonCRHGadget<Self::OuterScalarField, 34>;
+    type FunctionID = AleoLocator<<Self::FunctionIDCRH as CRH>::Output, { Self::FUNCTION_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type FunctionInputsCRH = PoseidonCRH<Self::InnerScalarField, 128>;
+    type FunctionInputsCRHGadget = PoseidonCRHGadget<Self::InnerScalarField, 128>;
+    type FunctionInputsHash = AleoLocator<<Self::FunctionInputsCRH as CRH>::Output, { Self::FUNCTION_INPUTS_HASH_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type InnerCircuitIDCRH = BHPCRH<EdwardsBW6, 85, 63>;
+    type InnerCircuitIDCRHGadget = BHPCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget, 85, 63>;
+    type InnerCircuitID = AleoLocator<<Self::InnerCircuitIDCRH as CRH>::Output, { Self::INNER_CIRCUIT_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type LedgerRootCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type LedgerRootCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type LedgerRootParameters = MerkleTreeParameters<Self::LedgerRootCRH, { Self::LEDGER_TREE_DEPTH }>;
+    type LedgerRoot = AleoLocator<<Self::LedgerRootCRH as CRH>::Output, { Self::LEDGER_ROOT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type PoSWMaskPRF = PoseidonPRF<Self::InnerScalarField, 4, false>;
+    type PoSWMaskPRFGadget = PoseidonPRFGadget<Self::InnerScalarField, 4, false>;
+    type PoSWNonce = AleoLocator<Self::InnerScalarField, { Self::HEADER_NONCE_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type ProgramIDCRH = BHPCRH<EdwardsBW6, 16, 48>;
+    type ProgramIDCRHGadget = BHPCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget, 16, 48>;
+    type ProgramIDParameters = MerkleTreeParameters<Self::ProgramIDCRH, { Self::PROGRAM_TREE_DEPTH }>;
+    type ProgramID = AleoLocator<<Self::ProgramIDCRH as CRH>::Output, { Self::PROGRAM_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type RecordRandomizer = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::CiphertextRandomizer, { Self::RECORD_RANDOMIZER_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+    type RecordViewKey = AleoObject<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKey, { Self::RECORD_VIEW_KEY_PREFIX }, { Self::RECORD_VIEW_KEY_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+    type RecordViewKeyCommitment = AleoLocator<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKeyCommitment, { Self::RECORD_VIEW_KEY_COMMITMENT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type SerialNumberPRF = PoseidonPRF<Self::InnerScalarField, 4, false>;
+    type SerialNumberPRFGadget = PoseidonPRFGadget<Self::InnerScalarField, 4, false>;
+    type SerialNumber = AleoLocator<<Self::SerialNumberPRF as PRF>::Output, { Self::SERIAL_NUMBER_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type TransactionsRootCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type TransactionsRootCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type TransactionsRootParameters = MerkleTreeParameters<Self::TransactionsRootCRH, { Self::HEADER_TRANSACTIONS_TREE_DEPTH }>;
+    type TransactionsRoot = AleoLocator<<Self::TransactionsRootCRH as CRH>::Output, { Self::HEADER_TRANSACTIONS_ROOT_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type TransactionIDCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type TransactionIDCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type TransactionIDParameters = MerkleTreeParameters<Self::TransactionIDCRH, { Self::TRANSACTION_TREE_DEPTH }>;
+    type TransactionID = AleoLocator<<Self::TransactionIDCRH as CRH>::Output, { Self::TRANSACTION_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    type TransitionIDCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
+    type TransitionIDCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
+    type TransitionIDParameters = MerkleTreeParameters<Self::TransitionIDCRH, { Self::TRANSITION_TREE_DEPTH }>;
+    type TransitionID = AleoLocator<<Self::TransitionIDCRH as CRH>::Output, { Self::TRANSITION_ID_PREFIX }, { Self::ACCEPTS_BECH32 }>;
+
+    dpc_setup!{Testnet2Mini, account_encryption_scheme, AccountEncryptionScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
+    dpc_setup!{Testnet2Mini, account_signature_scheme, AccountSignatureScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
+    dpc_setup!{Testnet2Mini, block_hash_crh, BlockHashCRH, "AleoBlockHashCRH0"}
+    dpc_setup!{Testnet2Mini, block_header_root_parameters, BlockHeaderRootParameters, "AleoBlockHeaderRootCRH0"}
+    dpc_setup!{Testnet2Mini, commitment_scheme, CommitmentScheme, "AleoCommitmentScheme0"}
+    dpc_setup!{Testnet2Mini, function_id_crh, FunctionIDCRH, "AleoFunctionIDCRH0"}
+    dpc_setup!{Testnet2Mini, inner_circuit_id_crh, InnerCircuitIDCRH, "AleoInnerCircuitIDCRH0"}
+    dpc_setup!{Testnet2Mini, ledger_root_parameters, LedgerRootParameters, "AleoLedgerRootCRH0"}
+    dpc_setup!{Testnet2Mini, program_id_parameters, ProgramIDParameters, "AleoProgramIDCRH0"}
+    dpc_setup!{Testnet2Mini, transactions_root_parameters, TransactionsRootParameters, "AleoTransactionsRootCRH0"}
+    dpc_setup!{Testnet2Mini, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0"}
+    dpc_setup!{Testnet2Mini, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0"}
+
+    dpc_empty_hash!{Testnet2Mini, block_header_root_empty_hash, BlockHeaderRootCRH, block_header_root_parameters}
+    dpc_empty_hash!{Testnet2Mini, ledger_root_empty_hash, LedgerRootCRH, ledger_root_parameters}
+    dpc_empty_hash!{Testnet2Mini, program_id_empty_hash, ProgramIDCRH, program_id_parameters}
+    dpc_empty_hash!{Testnet2Mini, transactions_root_empty_hash, TransactionsRootCRH, transactions_root_parameters}
+    dpc_empty_hash!{Testnet2Mini, transaction_id_empty_hash, TransactionIDCRH, transaction_id_parameters}
+    dpc_empty_hash!{Testnet2Mini, transition_id_empty_hash, TransitionIDCRH, transition_id_parameters}
+}
+
+#[rustfmt::skip]
+impl NetworkParameters for Testnet2Mini {
+    type InnerSNARK = Groth16<Self::InnerCurve, InnerPublicVariables<Testnet2Mini>>;
+    type InnerSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, PairingGadget>;
+    type InnerProof = AleoObject<<Self::InnerSNARK as SNARK>::Proof, { Self::INNER_PROOF_PREFIX }, { Self::INNER_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type OuterSNARK = Groth16<Self::OuterCurve, OuterPublicVariables<Testnet2Mini>>;
+    type OuterProof = AleoObject<<Self::OuterSNARK as SNARK>::Proof, { Self::OUTER_PROOF_PREFIX }, { Self::OUTER_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type ProgramSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirAlgebraicSpongeRng<Self::InnerScalarField, Self::OuterScalarField, PoseidonSponge<Self::OuterScalarField, 6, 1>>, MarlinTestnet2Mode, ProgramPublicVariables<Self>>;
+    type ProgramSNARKGadget = MarlinVerificationGadget<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, SonicKZG10Gadget<Self::InnerCurve, Self::OuterCurve, PairingGadget>, MarlinTestnet2Mode>;
+    type ProgramProvingKey = <Self::ProgramSNARK as SNARK>::ProvingKey;
+    type ProgramVerifyingKey = <Self::ProgramSNARK as SNARK>::VerifyingKey;
+    type ProgramProof = AleoObject<<Self::ProgramSNARK as SNARK>::Proof, { Self::PROGRAM_PROOF_PREFIX }, { Self::PROGRAM_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    type PoSWSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirChaChaRng<Self::InnerScalarField, Self::OuterScalarField, Blake2s>, MarlinPoswMode, Vec<Self::InnerScalarField>>;
+    type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+    type PoSW = PoSW<Self>;
+
+    type RecordCiphertext = AleoObject<Ciphertext<Self>, { Self::RECORD_CIPHERTEXT_PREFIX }, { Self::RECORD_CIPHERTEXT_SIZE_IN_BYTES }, { Self::ACCEPTS_BECH32 }>;
+
+    fn inner_proving_key() -> &'static <Self::InnerSNARK as SNARK>::ProvingKey {
+        &Self::inner_circuit_keys().0
+    }
+
+    fn inner_verifying_key() -> &'static <Self::InnerSNARK as SNARK>::VerifyingKey {
+        &Self::inner_circuit_keys().1
+    }
+
+    fn outer_proving_key() -> &'static <Self::OuterSNARK as SNARK>::ProvingKey {
+        &Self::outer_circuit_keys().0
+    }
+
+    fn outer_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::VerifyingKey {
+        &Self::outer_circuit_keys().1
+    }
+
+    dpc_snark_setup!{Testnet2Mini, noop_circuit_proving_key, ProgramSNARK, ProvingKey, NoopProvingKeyBytes, "noop circuit proving key"}
+    dpc_snark_setup!{Testnet2Mini, noop_circuit_verifying_key, ProgramSNARK, VerifyingKey, NoopVerifyingKeyBytes, "noop circuit verifying key"}
+
+    dpc_snark_setup!{Testnet2Mini, posw_proving_key, PoSWSNARK, ProvingKey, PoSWProvingKeyBytes, "posw proving key"}
+    dpc_snark_setup!{Testnet2Mini, posw_verifying_key, PoSWSNARK, VerifyingKey, PoSWVerifyingKeyBytes, "posw verifying key"}
+
+    fn inner_circuit_id() -> &'static Self::InnerCircuitID {
+        static INNER_CIRCUIT_ID: OnceCell<<Testnet2Mini as NetworkCrypto>::InnerCircuitID> = OnceCell::new();
+        INNER_CIRCUIT_ID.get_or_init(|| Self::inner_circuit_id_crh()
+            .hash_bits(&Self::inner_verifying_key().to_minimal_bits_v1())
+            .expect("Failed to hash inner circuit verifying key elements").into())
+    }
+
+    fn noop_program() -> &'static Program<Self> {
+        static NOOP_PROGRAM: OnceCell<Program<Testnet2Mini>> = OnceCell::new();
+        NOOP_PROGRAM.get_or_init(|| Program::<Testnet2Mini>::new_noop().expect("Failed to fetch the noop program"))
+    }
+
+    fn noop_program_id() -> &'static Self::ProgramID {
+        static NOOP_PROGRAM_ID: OnceCell<<Testnet2Mini as NetworkCrypto>::ProgramID> = OnceCell::new();
+        NOOP_PROGRAM_ID.get_or_init(|| Testnet2Mini::noop_program().program_id())
+    }
+
+    fn noop_program_path() -> &'static MerklePath<Self::ProgramIDParameters> {
+        static NOOP_PROGRAM_PATH: OnceCell<MerklePath<<Testnet2Mini as NetworkCrypto>::ProgramIDParameters>> = OnceCell::new();
+        NOOP_PROGRAM_PATH.get_or_init(|| Self::noop_program().to_program_path(Self::noop_function_id()).expect("Failed to fetch the noop program path"))
+    }
+
+    fn noop_function_id() -> &'static Self::FunctionID {
+        static NOOP_FUNCTION_ID: OnceCell<<Testnet2Mini as NetworkCrypto>::FunctionID> = OnceCell::new();
+        NOOP_FUNCTION_ID.get_or_init(|| Self::function_id(Self::noop_circuit_verifying_key()).expect("Failed to hash noop circuit verifying key"))
+    }
+
+    fn posw() -> &'static Self::PoSW {
+        static POSW: OnceCell<<Testnet2Mini as NetworkParameters>::PoSW> = OnceCell::new();
+        POSW.get_or_init(|| <Self::PoSW as PoSWScheme<Self>>::load(true).expect("Failed to load PoSW"))
+    }
+
+    fn posw_registry() -> &'static PoSWRegistry<'static, Self> {
+        static POSW_REGISTRY: OnceCell<PoSWRegistry<'static, Testnet2Mini>> = OnceCell::new();
+        POSW_REGISTRY.get_or_init(PoSWRegistry::from_network)
+    }
+
+    fn genesis_block() -> &'static Block<Self> {
+        static BLOCK: OnceCell<Block<Testnet2Mini>> = OnceCell::new();
+        BLOCK.get_or_init(|| {
+            let recipient = Account::<Testnet2Mini>::new(&mut thread_rng()).address();
+            Block::new_genesis(recipient, &mut thread_rng()).expect("Failed to generate the genesis block")
+        })
+    }
+
+    /// Returns the program SRS for Aleo applications.
+    fn program_srs<R: Rng + CryptoRng>(_rng: &mut R) -> Rc<RefCell<SRS<R, <Self::ProgramSNARK as SNARK>::UniversalSetupParameters>>> {
+        static UNIVERSAL_SRS: OnceCell<<<Testnet2Mini as NetworkParameters>::ProgramSNARK as SNARK>::UniversalSetupParameters> = OnceCell::new();
+        let universal_srs = UNIVERSAL_SRS.get_or_init(|| <Self::ProgramSNARK as SNARK>::UniversalSetupParameters::from_bytes_le(
+            &UniversalSRSBytes::load_bytes().expect("Failed to load universal SRS bytes"),
+        ).unwrap());
+        Rc::new(RefCell::new(SRS::<_, _>::Universal(universal_srs)))
+    }
+}
+
+type InnerCircuitKeys = (
+    <<Testnet2Mini as NetworkParameters>::InnerSNARK as SNARK>::ProvingKey,
+    <<Testnet2Mini as NetworkParameters>::InnerSNARK as SNARK>::VerifyingKey,
+);
+type OuterCircuitKeys = (
+    <<Testnet2Mini as NetworkParameters>::OuterSNARK as SNARK>::ProvingKey,
+    <<Testnet2Mini as NetworkParameters>::OuterSNARK as SNARK>::VerifyingKey,
+);
+
+impl Testnet2Mini {
+    /// Returns the inner circuit keys, running the circuit setup on first use.
+    fn inner_circuit_keys() -> &'static InnerCircuitKeys {
+        static INNER_CIRCUIT_KEYS: OnceCell<InnerCircuitKeys> = OnceCell::new();
+        INNER_CIRCUIT_KEYS.get_or_init(|| {
+            <Self as NetworkParameters>::InnerSNARK::setup(
+                &InnerCircuit::<Self>::blank(),
+                &mut SRS::CircuitSpecific(&mut thread_rng()),
+            )
+            .expect("Failed to run the inner circuit setup")
+        })
+    }
+
+    /// Returns the outer circuit keys, running the circuit setup on first use.
+    fn outer_circuit_keys() -> &'static OuterCircuitKeys {
+        static OUTER_CIRCUIT_KEYS: OnceCell<OuterCircuitKeys> = OnceCell::new();
+        OUTER_CIRCUIT_KEYS.get_or_init(|| {
+            let rng = &mut thread_rng();
+
+            let (inner_proving_key, inner_verifying_key) = Self::inner_circuit_keys();
+            let inner_proof =
+                <Self as NetworkParameters>::InnerSNARK::prove(inner_proving_key, &InnerCircuit::<Self>::blank(), rng)
+                    .expect("Failed to prove the blank inner circuit");
+
+            let noop_proof = Noop::<Self>::new()
+                .execute(
+                    ProgramPublicVariables::blank(),
+                    &NoopPrivateVariables::<Self>::new_blank().expect("Failed to construct noop private variables"),
+                )
+                .expect("Failed to execute the noop program");
+
+            let execution = Execution {
+                program_id: *Self::noop_program_id(),
+                program_path: Self::noop_program_path().clone(),
+                verifying_key: Self::noop_circuit_verifying_key().clone(),
+                proof: noop_proof,
+                payload_commitments: vec![Default::default(); Self::NUM_PAYLOAD_COMMITMENTS],
+            };
+
+            <Self as NetworkParameters>::OuterSNARK::setup(
+                &OuterCircuit::<Self>::blank(inner_verifying_key.clone(), inner_proof.into(), execution),
+                &mut SRS::CircuitSpecific(rng),
+            )
+            .expect("Failed to run the outer circuit setup")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_name_sanity_check() {
+        assert_eq!(Testnet2Mini::NETWORK_NAME, "testnet2_mini");
+    }
+
+    #[test]
+    fn test_tree_depth_sanity_check() {
+        // Verify the Merkle tree parameters match the declared depths.
+        assert_eq!(
+            Testnet2Mini::LEDGER_TREE_DEPTH,
+            <<Testnet2Mini as NetworkCrypto>::LedgerRootParameters as MerkleParameters>::DEPTH
+        );
+        assert_eq!(
+            Testnet2Mini::TRANSACTION_TREE_DEPTH,
+            <<Testnet2Mini as NetworkCrypto>::TransactionIDParameters as MerkleParameters>::DEPTH
+        );
+        assert_eq!(Testnet2Mini::NUM_TRANSITIONS, 2);
+
+        // Verify the PoSW tree depth matches Testnet2, as the PoSW parameters are shared.
+        assert_eq!(
+            Testnet2Mini::HEADER_TREE_DEPTH,
+            <crate::testnet2::Testnet2 as NetworkConstants>::HEADER_TREE_DEPTH
+        );
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+use snarkvm_algorithms::{SNARKProtocolID, SNARK};
+
+use std::fmt;
+
+/// The version of a PoSW proof, which determines the proof system that it is verified with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PoSWVersion {
+    /// Proofs of the deprecated hiding Marlin mode, used by testnet2 blocks up to the V12 upgrade.
+    V1 = 1,
+    /// Proofs of the network PoSW SNARK.
+    V2 = 2,
+}
+
+impl PoSWVersion {
+    /// Returns the PoSW version with the given byte representation, if it exists.
+    pub fn from_u8(version: u8) -> Option<Self> {
+        match version {
+            1 => Some(Self::V1),
+            2 => Some(Self::V2),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if proofs of this version use the deprecated hiding Marlin mode.
+    pub fn is_deprecated(&self) -> bool {
+        *self == Self::V1
+    }
+
+    /// Returns the protocol ID of the proof system of PoSW proofs of this version.
+    pub fn protocol_id<N: Network>(&self) -> SNARKProtocolID {
+        match self {
+            Self::V1 => <crate::testnet2::DeprecatedPoSWSNARK<N> as SNARK>::PROTOCOL_ID,
+            Self::V2 => <N::PoSWSNARK as SNARK>::PROTOCOL_ID,
+        }
+    }
+}
+
+impl fmt::Display for PoSWVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}", *self as u8)
+    }
+}
+
+/// The PoSW proof mode enforced for blocks in a network upgrade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PoSWMode {
+    /// Proofs are produced with the deprecated hiding Marlin mode.
+    Hiding,
+    /// Proofs are produced with the network PoSW SNARK.
+    NonHiding,
+}
+
+impl PoSWMode {
+    /// Returns the version of the PoSW proofs that are produced in this mode.
+    pub fn version(&self) -> PoSWVersion {
+        match self {
+            Self::Hiding => PoSWVersion::V1,
+            Self::NonHiding => PoSWVersion::V2,
+        }
+    }
+
+    /// Returns the protocol ID of the proof system that PoSW proofs are produced with in this mode.
+    pub fn protocol_id<N: Network>(&self) -> SNARKProtocolID {
+        self.version().protocol_id::<N>()
+    }
+}
+
+/// The difficulty retargeting algorithm used for blocks in a network upgrade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DifficultyRetarget {
+    /// The Bitcoin retargeting algorithm, computed from the previous block in floating-point arithmetic.
+    Bitcoin,
+    /// The Bitcoin retargeting algorithm, computed from the previous block in exact integer arithmetic.
+    BitcoinExact,
+    /// The ASERT retargeting algorithm, computed from a fixed anchor block.
+    Asert,
+}
+
+/// The block header used to anchor the difficulty computation of a block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DifficultyAnchor {
+    /// The block immediately preceding the block being computed.
+    PreviousBlock,
+    /// The block at the given height.
+    Height(u32),
+}
+
+/// The wire format of record ciphertexts accepted in a network upgrade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RecordCiphertextFormat {
+    /// Record ciphertexts are encoded with each field element padded to whole bytes.
+    Standard,
+    /// Record ciphertexts are encoded in either the standard format, or with the bits of the field elements packed.
+    Packed,
+}
+
+/// The migration of the ledger tree to a larger depth in a network upgrade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LedgerRootMigration {
+    /// Only the roots of the ledger tree at its network depth are accepted.
+    None,
+    /// The roots of the ledger tree are accepted both at its network depth, and migrated to the given depth.
+    Transition { depth: usize },
+}
+
+impl LedgerRootMigration {
+    /// Returns the depth that ledger roots are migrated to, if a transition is active.
+    pub fn depth(&self) -> Option<usize> {
+        match self {
+            Self::None => None,
+            Self::Transition { depth } => Some(*depth),
+        }
+    }
+}
+
+/// The ledger roots that a new transaction may reference in a network upgrade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LedgerRootWindow {
+    /// Any ledger root of the canon chain may be referenced.
+    Unbounded,
+    /// Only the ledger roots of the given number of latest blocks, and the root of the empty ledger, may be referenced.
+    Blocks(u32),
+}
+
+/// A set of consensus rules that activates at a given block height.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NetworkUpgrade {
+    /// The first block height (inclusive) at which these rules apply.
+    pub start_height: u32,
+    /// The PoSW proof mode.
+    pub posw_mode: PoSWMode,
+    /// The size of a PoSW proof in bytes.
+    pub posw_proof_size_in_bytes: usize,
+    /// The difficulty retargeting algorithm.
+    pub difficulty_retarget: DifficultyRetarget,
+    /// The anchor block for the difficulty retargeting algorithm.
+    pub difficulty_anchor: DifficultyAnchor,
+    /// The wire format of record ciphertexts.
+    pub record_ciphertext_format: RecordCiphertextFormat,
+    /// The migration of the ledger tree to a larger depth.
+    pub ledger_root_migration: LedgerRootMigration,
+    /// `true` if transactions may carry public data, which changes their byte format and transaction ID.
+    pub transaction_data: bool,
+    /// The ledger roots that a new transaction may reference, which bounds how long a prepared transaction stays valid.
+    pub ledger_root_window: LedgerRootWindow,
+}
+
+/// An ordered list of network upgrades, mapping block heights to consensus rules.
+///
+/// The upgrades must be sorted by `start_height`, and the first upgrade must start at height 0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeSchedule {
+    upgrades: &'static [NetworkUpgrade],
+}
+
+impl UpgradeSchedule {
+    /// Initializes a new upgrade schedule from the given list of upgrades.
+    pub const fn new(upgrades: &'static [NetworkUpgrade]) -> Self {
+        Self { upgrades }
+    }
+
+    /// Returns the consensus rules that apply to the given block height.
+    pub fn get(&self, block_height: u32) -> &NetworkUpgrade {
+        self.upgrades
+            .iter()
+            .rev()
+            .find(|upgrade| upgrade.start_height <= block_height)
+            .expect("The upgrade schedule must contain an upgrade starting at height 0")
+    }
+
+    /// Returns the list of upgrades in this schedule.
+    pub fn upgrades(&self) -> &'static [NetworkUpgrade] {
+        self.upgrades
+    }
+
+    /// Returns `true` if the schedule is non-empty, starts at height 0, and is strictly increasing.
+    pub fn is_valid(&self) -> bool {
+        match self.upgrades.first() {
+            Some(first) if first.start_height == 0 => self
+                .upgrades
+                .windows(2)
+                .all(|pair| pair[0].start_height < pair[1].start_height),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, testnet2::Testnet2, NetworkConstants};
+
+    const SCHEDULE: UpgradeSchedule = UpgradeSchedule::new(&[
+        NetworkUpgrade {
+            start_height: 0,
+            posw_mode: PoSWMode::Hiding,
+            posw_proof_size_in_bytes: 771,
+            difficulty_retarget: DifficultyRetarget::Bitcoin,
+            difficulty_anchor: DifficultyAnchor::PreviousBlock,
+            record_ciphertext_format: RecordCiphertextFormat::Standard,
+            ledger_root_migration: LedgerRootMigration::None,
+            transaction_data: false,
+            ledger_root_window: LedgerRootWindow::Unbounded,
+        },
+        NetworkUpgrade {
+            start_height: 10,
+            posw_mode: PoSWMode::NonHiding,
+            posw_proof_size_in_bytes: 771,
+            difficulty_retarget: DifficultyRetarget::Asert,
+            difficulty_anchor: DifficultyAnchor::Height(9),
+            record_ciphertext_format: RecordCiphertextFormat::Packed,
+            ledger_root_migration: LedgerRootMigration::Transition { depth: 40 },
+            transaction_data: true,
+            ledger_root_window: LedgerRootWindow::Blocks(4096),
+        },
+    ]);
+
+    #[test]
+    fn test_upgrade_schedule_get() {
+        assert!(SCHEDULE.is_valid());
+        assert_eq!(SCHEDULE.get(0).posw_mode, PoSWMode::Hiding);
+        assert_eq!(SCHEDULE.get(9).posw_mode, PoSWMode::Hiding);
+        assert_eq!(SCHEDULE.get(10).posw_mode, PoSWMode::NonHiding);
+        assert_eq!(SCHEDULE.get(u32::MAX).difficulty_anchor, DifficultyAnchor::Height(9));
+        assert_eq!(
+            SCHEDULE.get(9).record_ciphertext_format,
+            RecordCiphertextFormat::Standard
+        );
+        assert_eq!(
+            SCHEDULE.get(10).record_ciphertext_format,
+            RecordCiphertextFormat::Packed
+        );
+        assert_eq!(SCHEDULE.get(9).ledger_root_migration.depth(), None);
+        assert_eq!(SCHEDULE.get(10).ledger_root_migration.depth(), Some(40));
+        assert!(!SCHEDULE.get(9).transaction_data);
+        assert!(SCHEDULE.get(10).transaction_data);
+        assert_eq!(SCHEDULE.get(9).ledger_root_window, LedgerRootWindow::Unbounded);
+        assert_eq!(SCHEDULE.get(10).ledger_root_window, LedgerRootWindow::Blocks(4096));
+    }
+
+    #[test]
+    fn test_posw_version() {
+        assert_eq!(PoSWVersion::V1, PoSWMode::Hiding.version());
+        assert_eq!(PoSWVersion::V2, PoSWMode::NonHiding.version());
+        assert!(PoSWVersion::V1.is_deprecated());
+        assert!(!PoSWVersion::V2.is_deprecated());
+
+        for version in [PoSWVersion::V1, PoSWVersion::V2].iter() {
+            assert_eq!(Some(*version), PoSWVersion::from_u8(*version as u8));
+        }
+        assert_eq!(None, PoSWVersion::from_u8(0));
+        assert_eq!(None, PoSWVersion::from_u8(3));
+        assert_eq!("v2", PoSWVersion::V2.to_string());
+    }
+
+    #[test]
+    fn test_upgrade_schedule_is_valid() {
+        assert!(!UpgradeSchedule::new(&[]).is_valid());
+        assert!(!UpgradeSchedule::new(&SCHEDULE.upgrades()[1..]).is_valid());
+        assert!(Testnet1::UPGRADE_SCHEDULE.is_valid());
+        assert!(Testnet2::UPGRADE_SCHEDULE.is_valid());
+    }
+}
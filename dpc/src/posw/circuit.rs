@@ -27,8 +27,12 @@ use snarkvm_gadgets::{
     ToBytesGadget,
 };
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+#[cfg(any(test, feature = "dev"))]
+use snarkvm_utilities::UniformRand;
 
 use anyhow::Result;
+#[cfg(any(test, feature = "dev"))]
+use rand::{CryptoRng, Rng};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PoSWCircuit<N: Network> {
@@ -51,14 +55,10 @@ impl<N: Network> PoSWCircuit<N> {
 
     /// Creates a blank PoSW circuit for setup.
     pub fn blank() -> Result<Self> {
-        let empty_hash = N::block_header_root_parameters()
-            .hash_empty()
-            .map_err(|_| SynthesisError::Unsatisfiable)?;
-
         Ok(Self {
             block_header_root: Default::default(),
             nonce: Default::default(),
-            hashed_leaves: vec![empty_hash; usize::pow(2, N::HEADER_TREE_DEPTH as u32)],
+            hashed_leaves: vec![*N::block_header_root_empty_hash(); usize::pow(2, N::HEADER_TREE_DEPTH as u32)],
         })
     }
 
@@ -79,6 +79,20 @@ impl<N: Network> PoSWCircuit<N> {
     }
 }
 
+#[cfg(any(test, feature = "dev"))]
+impl<N: Network> PoSWCircuit<N> {
+    ///
+    /// Creates a PoSW circuit for `BlockTemplate::new_test(difficulty_target)`, with a nonce sampled from the given RNG.
+    ///
+    /// The circuit is deterministic for a seeded RNG. To test a PoSW proof just above or below a difficulty target,
+    /// prove the circuit once, and verify the proof against `PoSWProof::to_lowest_satisfied_target`
+    /// and `PoSWProof::to_highest_unsatisfied_target`, instead of mining a proof for a given target.
+    ///
+    pub fn new_test<R: Rng + CryptoRng>(difficulty_target: u64, rng: &mut R) -> Result<Self> {
+        Self::new(&BlockTemplate::new_test(difficulty_target)?, UniformRand::rand(rng))
+    }
+}
+
 impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for PoSWCircuit<N> {
     fn generate_constraints<CS: ConstraintSystem<N::InnerScalarField>>(
         &self,
@@ -158,6 +172,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for PoSWCircuit<N> {
 mod test {
     use super::*;
     use crate::{testnet1::Testnet1, testnet2::Testnet2, PoSWProof};
+    use snarkvm_fields::One;
     use snarkvm_marlin::marlin::MarlinTestnet1Mode;
     use snarkvm_r1cs::TestConstraintSystem;
     use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
@@ -185,6 +200,41 @@ mod test {
         assert_eq!(26909, num_constraints);
     }
 
+    /// Returns the block template of the genesis block.
+    fn genesis_block_template<N: Network>() -> BlockTemplate<N> {
+        let block = N::genesis_block();
+        BlockTemplate::new(
+            block.previous_block_hash(),
+            block.height(),
+            block.timestamp(),
+            block.difficulty_target(),
+            block.cumulative_weight(),
+            block.previous_ledger_root(),
+            block.transactions().clone(),
+            block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+        )
+    }
+
+    fn posw_mutations_test<N: Network>() {
+        let mut cs = TestConstraintSystem::<N::InnerScalarField>::new();
+
+        // Synthesize an assigned PoSW circuit.
+        let nonce = UniformRand::rand(&mut thread_rng());
+        PoSWCircuit::<N>::new(&genesis_block_template(), nonce)
+            .unwrap()
+            .generate_constraints(&mut cs.ns(|| "PoSW circuit"))
+            .unwrap();
+        assert!(cs.is_satisfied());
+
+        // Check that the constraint system rejects a mutation of each public variable.
+        let statistics = cs.mutate_assignments(256);
+        assert!(
+            statistics.accepted_public_mutations.is_empty(),
+            "PoSW circuit accepted mutations of public variables: {:?}",
+            statistics.accepted_public_mutations
+        );
+    }
+
     fn posw_proof_test<N: Network, R: Rng + CryptoRng>(rng: &mut R) {
         // Generate the proving and verifying key.
         let (proving_key, verifying_key) = {
@@ -192,9 +242,9 @@ mod test {
                 20000, 20000, 200000,
             )
             .unwrap();
-            let universal_srs = <<N as Network>::PoSWSNARK as SNARK>::universal_setup(&max_degree, rng).unwrap();
+            let universal_srs = <N::PoSWSNARK as SNARK>::universal_setup(&max_degree, rng).unwrap();
 
-            <<N as Network>::PoSWSNARK as SNARK>::setup::<_, R>(
+            <N::PoSWSNARK as SNARK>::setup::<_, R>(
                 &PoSWCircuit::<N>::blank().unwrap(),
                 &mut SRS::<R, _>::Universal(&universal_srs),
             )
@@ -204,26 +254,13 @@ mod test {
         // Sample a random nonce.
         let nonce = UniformRand::rand(rng);
 
-        // Construct the block template.
-        let block = N::genesis_block();
-        let block_template = BlockTemplate::new(
-            block.previous_block_hash(),
-            block.height(),
-            block.timestamp(),
-            block.difficulty_target(),
-            block.cumulative_weight(),
-            block.previous_ledger_root(),
-            block.transactions().clone(),
-            block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
-        );
-
         // Construct an assigned circuit.
-        let assigned_circuit = PoSWCircuit::<N>::new(&block_template, nonce).unwrap();
+        let assigned_circuit = PoSWCircuit::<N>::new(&genesis_block_template(), nonce).unwrap();
 
         // Compute the proof.
         let proof = {
             let timer = Instant::now();
-            let proof = <<N as Network>::PoSWSNARK as SNARK>::prove(&proving_key, &assigned_circuit, rng).unwrap();
+            let proof = <N::PoSWSNARK as SNARK>::prove(&proving_key, &assigned_circuit, rng).unwrap();
             println!("\nPosW elapsed time: {} ms\n", (Instant::now() - timer).as_millis());
             proof
         };
@@ -238,7 +275,14 @@ mod test {
             *assigned_circuit.nonce,
         ];
         assert_eq!(2, inputs.len());
-        assert!(<<N as Network>::PoSWSNARK as SNARK>::verify(&verifying_key, &inputs, &proof).unwrap());
+        assert!(<N::PoSWSNARK as SNARK>::verify(&verifying_key, &inputs, &proof).unwrap());
+
+        // Verify the proof is invalid on each mutated public input.
+        for i in 0..inputs.len() {
+            let mut mutated_inputs = inputs.clone();
+            mutated_inputs[i] += N::InnerScalarField::one();
+            assert!(!<N::PoSWSNARK as SNARK>::verify(&verifying_key, &mutated_inputs, &proof).unwrap());
+        }
     }
 
     #[test]
@@ -247,6 +291,12 @@ mod test {
         posw_constraints_test::<Testnet2>();
     }
 
+    #[test]
+    fn test_posw_mutations() {
+        posw_mutations_test::<Testnet1>();
+        posw_mutations_test::<Testnet2>();
+    }
+
     #[test]
     fn test_posw_proof() {
         posw_proof_test::<Testnet1, ThreadRng>(&mut thread_rng());
@@ -17,33 +17,30 @@
 //! Generic PoSW Miner and Verifier, compatible with any implementer of the SNARK trait.
 
 use crate::{
-    posw::PoSWCircuit,
+    posw::{PoSWCircuit, VersionedPoSW},
     BlockHeader,
-    BlockHeaderMetadata,
     BlockTemplate,
     Network,
     PoSWError,
     PoSWProof,
     PoSWScheme,
+    PoSWVersion,
+    ProofOfSuccinctWork,
 };
 use snarkvm_algorithms::{traits::SNARK, SRS};
 use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
-use chrono::Utc;
 use core::sync::atomic::AtomicBool;
 use rand::{CryptoRng, Rng};
 
-use std::time::Instant;
-use metrics::histogram;
-
 /// A Proof of Succinct Work miner and verifier.
 #[derive(Clone)]
 pub struct PoSW<N: Network> {
     /// The proving key. If not provided, PoSW will work in verify-only mode
     /// and the `mine` function will panic.
-    proving_key: Option<<<N as Network>::PoSWSNARK as SNARK>::ProvingKey>,
+    proving_key: Option<<N::PoSWSNARK as SNARK>::ProvingKey>,
     /// The verifying key.
-    verifying_key: <<N as Network>::PoSWSNARK as SNARK>::VerifyingKey,
+    verifying_key: <N::PoSWSNARK as SNARK>::VerifyingKey,
 }
 
 impl<N: Network> PoSWScheme<N> for PoSW<N> {
@@ -51,10 +48,10 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
     /// Initializes a new instance of PoSW using the given SRS.
     ///
     fn setup<R: Rng + CryptoRng>(
-        srs: &mut SRS<R, <<N as Network>::PoSWSNARK as SNARK>::UniversalSetupParameters>,
+        srs: &mut SRS<R, <N::PoSWSNARK as SNARK>::UniversalSetupParameters>,
     ) -> Result<Self, PoSWError> {
         let (proving_key, verifying_key) =
-            <<N as Network>::PoSWSNARK as SNARK>::setup::<_, R>(&PoSWCircuit::<N>::blank()?, srs)?;
+            <N::PoSWSNARK as SNARK>::setup::<_, R>(&PoSWCircuit::<N>::blank()?, srs)?;
 
         Ok(Self {
             proving_key: Some(proving_key),
@@ -90,7 +87,8 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
     }
 
     ///
-    /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target.
+    /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target,
+    /// with the PoSW version scheduled for the block height.
     ///
     fn mine<R: Rng + CryptoRng>(
         &self,
@@ -98,49 +96,8 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<BlockHeader<N>, PoSWError> {
-        const MAXIMUM_MINING_DURATION: i64 = 600; // 600 seconds = 10 minutes.
-
-        // Instantiate the circuit.
-        let mut circuit = PoSWCircuit::<N>::new(block_template, UniformRand::rand(rng))?;
-
-        let mut iteration = 1;
-        loop {
-            // Every 100 iterations, check that the miner is still within the allowed mining duration.
-            if iteration % 100 == 0
-                && Utc::now().timestamp() >= block_template.block_timestamp() + MAXIMUM_MINING_DURATION
-            {
-                return Err(PoSWError::Message(
-                    "Failed mine block in the allowed mining duration".to_string(),
-                ));
-            }
-
-            // Run one iteration of PoSW.
-            let prove_start = Instant::now();
-            let proof = self.prove_once_unchecked(&mut circuit, block_template, terminator, rng)?;
-            let duration = prove_start.elapsed();
-            histogram!("snarkvm_posw_duration", duration);
-            trace!("Prove time: {:?}, height: {}, timestamp: {}, difficulty: {}, weight: {}", duration, block_template.block_height(), block_template.block_timestamp(), block_template.difficulty_target(), block_template.cumulative_weight());
-
-            // Check if the updated block header is valid.
-            if self.verify(
-                block_template.block_height(),
-                block_template.difficulty_target(),
-                &circuit.to_public_inputs(),
-                &proof,
-            ) {
-                // Construct a block header.
-                return Ok(BlockHeader::from(
-                    block_template.previous_ledger_root(),
-                    block_template.transactions().transactions_root(),
-                    BlockHeaderMetadata::new(block_template),
-                    circuit.nonce(),
-                    proof,
-                )?);
-            }
-
-            // Increment the iteration by one.
-            iteration += 1;
-        }
+        let posw_mode = N::UPGRADE_SCHEDULE.get(block_template.block_height()).posw_mode;
+        VersionedPoSW::<N, Self>::new(posw_mode.version(), self).mine(block_template, terminator, rng)
     }
 
     ///
@@ -154,32 +111,53 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<PoSWProof<N>, PoSWError> {
+        // Mine blocks with the PoSW version scheduled for the block height.
+        let posw_mode = N::UPGRADE_SCHEDULE.get(block_template.block_height()).posw_mode;
+        self.prove_once_unchecked_with_version(posw_mode.version(), circuit, terminator, rng)
+    }
+
+    ///
+    /// Given the PoSW circuit, compute a PoSW proof of the given version.
+    /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
+    ///
+    fn prove_once_unchecked_with_version<R: Rng + CryptoRng>(
+        &self,
+        version: PoSWVersion,
+        circuit: &mut PoSWCircuit<N>,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<PoSWProof<N>, PoSWError> {
+        let _span = debug_span!("PoSW::prove").entered();
         let pk = self.proving_key.as_ref().expect("tried to mine without a PK set up");
 
         // Sample a random nonce.
         circuit.set_nonce(UniformRand::rand(rng));
 
-        // TODO (raychu86): TEMPORARY - Remove this after testnet2 period.
-        // Mine blocks with the deprecated PoSW mode for blocks behind `V12_UPGRADE_BLOCK_HEIGHT`.
-        if <N as Network>::NETWORK_ID == 2 && block_template.block_height() <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT
-        {
-            let pk = <crate::testnet2::DeprecatedPoSWSNARK<N> as SNARK>::ProvingKey::from_bytes_le(&pk.to_bytes_le()?)?;
-            // Construct a PoSW proof.
-            Ok(PoSWProof::<N>::new_hiding(
-                <crate::testnet2::DeprecatedPoSWSNARK<N> as SNARK>::prove_with_terminator(
-                    &pk, circuit, terminator, rng,
-                )?
-                .into(),
-            ))
-        } else {
-            // Construct a PoSW proof.
-            Ok(PoSWProof::<N>::new(
-                <<N as Network>::PoSWSNARK as SNARK>::prove_with_terminator(pk, circuit, terminator, rng)?.into(),
-            ))
+        match version {
+            PoSWVersion::V1 => {
+                let pk =
+                    <crate::testnet2::DeprecatedPoSWSNARK<N> as SNARK>::ProvingKey::from_bytes_le(&pk.to_bytes_le()?)?;
+                // Construct a PoSW proof.
+                Ok(PoSWProof::<N>::new_hiding(
+                    <crate::testnet2::DeprecatedPoSWSNARK<N> as SNARK>::prove_with_terminator(
+                        &pk, circuit, terminator, rng,
+                    )?
+                    .into(),
+                ))
+            }
+            PoSWVersion::V2 => {
+                // Construct a PoSW proof.
+                Ok(PoSWProof::<N>::new(
+                    <N::PoSWSNARK as SNARK>::prove_with_terminator(pk, circuit, terminator, rng)?.into(),
+                ))
+            }
         }
     }
 
+    ///
     /// Verifies the Proof of Succinct Work against the nonce, root, and difficulty target.
+    /// The proof is verified with the proof system of its PoSW version.
+    ///
     fn verify_from_block_header(&self, block_header: &BlockHeader<N>) -> bool {
         self.verify(
             block_header.height(),
@@ -197,42 +175,38 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         inputs: &[N::InnerScalarField],
         proof: &PoSWProof<N>,
     ) -> bool {
+        let _span = debug_span!("PoSW::verify").entered();
+
         // Ensure the difficulty target is met.
-        match proof.to_proof_difficulty() {
-            Ok(proof_difficulty) => {
-                if proof_difficulty > difficulty_target {
-                    #[cfg(debug_assertions)]
-                    eprintln!(
-                        "PoSW difficulty target is not met. Expected {}, found {}",
-                        difficulty_target, proof_difficulty
-                    );
-                    return false;
-                }
-            }
-            Err(error) => {
-                eprintln!("Failed to convert PoSW proof to bytes: {}", error);
-                return false;
-            }
-        };
+        if !proof.satisfies_target(difficulty_target) {
+            return false;
+        }
 
-        // TODO (raychu86): TEMPORARY - Remove this after testnet2 period.
-        // Verify blocks with the deprecated PoSW mode for blocks behind `V12_UPGRADE_BLOCK_HEIGHT`.
-        if <N as Network>::NETWORK_ID == 2 && block_height <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-            // Ensure the proof type is hiding.
-            if !proof.is_hiding() {
+        // Ensure the proof version matches the PoSW mode scheduled for the block height.
+        let expected_version = N::UPGRADE_SCHEDULE.get(block_height).posw_mode.version();
+        if proof.version() != expected_version {
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "PoSW proof for block {} should be {}, found {}",
+                block_height,
+                expected_version,
+                proof.version()
+            );
+            return false;
+        }
+
+        // Ensure the proof size matches the PoSW proof size scheduled for the block height.
+        let expected_size = N::UPGRADE_SCHEDULE.get(block_height).posw_proof_size_in_bytes;
+        match proof.to_bytes_le() {
+            Ok(bytes) if bytes.len() == expected_size => (),
+            _ => {
                 #[cfg(debug_assertions)]
-                eprintln!("[deprecated] PoSW proof for block {} should be hiding", block_height);
+                eprintln!("PoSW proof for block {} should be {} bytes", block_height, expected_size);
                 return false;
             }
         }
-        // Ensure the proof type is not hiding.
-        else if proof.is_hiding() {
-            #[cfg(debug_assertions)]
-            eprintln!("PoSW proof for block {} should not be hiding", block_height);
-            return false;
-        }
 
-        // Ensure the proof is valid under the deprecated PoSW parameters.
+        // Ensure the proof is valid under the proof system of its version.
         if !proof.verify(&self.verifying_key, inputs) {
             return false;
         }
@@ -245,14 +219,34 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
 mod tests {
     use core::sync::atomic::AtomicBool;
 
-    use crate::{testnet2::Testnet2, BlockTemplate, Network, PoSWScheme};
-    use snarkvm_utilities::ToBytes;
-
-    use rand::thread_rng;
+    use crate::{
+        posw::PoSWCircuit,
+        testnet2::Testnet2,
+        BlockTemplate,
+        NetworkConstants,
+        NetworkParameters,
+        PoSWMode,
+        PoSWScheme,
+    };
+    use snarkvm_algorithms::{SNARKProtocolID, SNARK};
+    use snarkvm_parameters::testnet2::PoSWProvingKeyBytes;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use rand::{thread_rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
 
     #[test]
     fn test_load() {
-        let _params = <<Testnet2 as Network>::PoSW as PoSWScheme<Testnet2>>::load(true).unwrap();
+        let _params = <<Testnet2 as NetworkParameters>::PoSW as PoSWScheme<Testnet2>>::load(true).unwrap();
+    }
+
+    #[test]
+    fn test_load_posw_proving_key() {
+        // Ensure the saved PoSW proving key deserializes, and serializes back to the same bytes.
+        let proving_key_bytes = PoSWProvingKeyBytes::load_bytes().unwrap();
+        let proving_key =
+            <<Testnet2 as NetworkParameters>::PoSWSNARK as SNARK>::ProvingKey::read_le(&proving_key_bytes[..]).unwrap();
+        assert_eq!(proving_key_bytes, proving_key.to_bytes_le().unwrap());
     }
 
     #[test]
@@ -280,5 +274,53 @@ mod tests {
             Testnet2::HEADER_PROOF_SIZE_IN_BYTES
         ); // NOTE: Marlin proofs use compressed serialization
         assert!(Testnet2::posw().verify_from_block_header(&block_header));
+
+        // Ensure the proof is tagged with the proof system of the PoSW mode of the block.
+        let posw_mode = Testnet2::UPGRADE_SCHEDULE.get(block_header.height()).posw_mode;
+        assert_eq!(posw_mode.protocol_id::<Testnet2>(), block_header.proof().protocol_id());
+        assert_eq!(posw_mode.version(), block_header.posw_version());
+    }
+
+    #[test]
+    fn test_posw_mode_protocol_id() {
+        assert_eq!(
+            SNARKProtocolID::MarlinHiding,
+            PoSWMode::Hiding.protocol_id::<Testnet2>()
+        );
+        assert_eq!(SNARKProtocolID::Marlin, PoSWMode::NonHiding.protocol_id::<Testnet2>());
+    }
+
+    #[test]
+    fn test_posw_difficulty_target_boundaries() {
+        let block_template = BlockTemplate::<Testnet2>::new_test(u64::MAX).unwrap();
+        assert_eq!(block_template, BlockTemplate::new_test(u64::MAX).unwrap());
+        assert_eq!(7, block_template.with_difficulty_target(7).difficulty_target());
+
+        // Ensure the test circuit is deterministic for a seeded RNG.
+        let mut circuit = PoSWCircuit::<Testnet2>::new_test(u64::MAX, &mut ChaChaRng::seed_from_u64(1)).unwrap();
+        assert_eq!(
+            circuit,
+            PoSWCircuit::new_test(u64::MAX, &mut ChaChaRng::seed_from_u64(1)).unwrap()
+        );
+
+        let posw = Testnet2::posw();
+        let proof = posw
+            .prove_once_unchecked(
+                &mut circuit,
+                &block_template,
+                &AtomicBool::new(false),
+                &mut thread_rng(),
+            )
+            .unwrap();
+        let inputs = circuit.to_public_inputs();
+        let block_height = block_template.block_height();
+
+        // Ensure the proof is valid for the target it just satisfies, and invalid for the target just below it.
+        let satisfied_target = proof.to_lowest_satisfied_target().unwrap();
+        assert!(posw.verify(block_height, satisfied_target, &inputs, &proof));
+        if let Some(unsatisfied_target) = proof.to_highest_unsatisfied_target().unwrap() {
+            assert_eq!(satisfied_target - 1, unsatisfied_target);
+            assert!(!posw.verify(block_height, unsatisfied_target, &inputs, &proof));
+        }
     }
 }
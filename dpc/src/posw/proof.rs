@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Network, PoSWError};
-use snarkvm_algorithms::{crh::sha256d_to_u64, SNARK};
+use crate::{Network, PoSWError, PoSWVersion};
+use snarkvm_algorithms::{crh::sha256d_to_u64, SNARKProtocolID, SNARK};
 use snarkvm_utilities::{
     fmt,
     io::{Read, Result as IoResult, Write},
@@ -61,6 +61,108 @@ impl<N: Network> PoSWProof<N> {
         }
     }
 
+    ///
+    /// Returns the version of the PoSW proof.
+    ///
+    pub fn version(&self) -> PoSWVersion {
+        match self {
+            Self::NonHiding(..) => PoSWVersion::V2,
+            Self::Hiding(..) => PoSWVersion::V1,
+        }
+    }
+
+    ///
+    /// Returns the protocol ID of the proof system of the PoSW proof.
+    ///
+    pub fn protocol_id(&self) -> SNARKProtocolID {
+        self.version().protocol_id::<N>()
+    }
+
+    ///
+    /// Reads a PoSW proof of the given version.
+    ///
+    /// Unlike `read_le`, which infers the version of the proof from its encoding,
+    /// this fails if the proof is not of the given version.
+    ///
+    pub fn read_le_with_version<R: Read>(version: PoSWVersion, mut reader: R) -> IoResult<Self> {
+        let mut buffer = vec![0u8; N::HEADER_PROOF_SIZE_IN_BYTES];
+        reader.read_exact(&mut buffer)?;
+        Self::from_padded_bytes_le(version, &buffer)
+    }
+
+    ///
+    /// Reads a PoSW proof of the size scheduled for the given block height.
+    ///
+    /// Unlike `read_le`, which reads `N::HEADER_PROOF_SIZE_IN_BYTES` bytes,
+    /// this fails if the proof is not encoded in exactly `posw_proof_size_in_bytes` bytes.
+    ///
+    pub fn read_le_for_height<R: Read>(block_height: u32, mut reader: R) -> IoResult<Self> {
+        let mut buffer = vec![0u8; N::UPGRADE_SCHEDULE.get(block_height).posw_proof_size_in_bytes];
+        reader.read_exact(&mut buffer)?;
+        Self::from_padded_bytes_le(Self::padded_version(&buffer), &buffer)
+    }
+
+    ///
+    /// Returns the version of the given padded PoSW proof bytes.
+    ///
+    /// Non-hiding proofs are padded with zeros to the size of hiding proofs.
+    ///
+    fn padded_version(buffer: &[u8]) -> PoSWVersion {
+        match buffer.get(691..) {
+            Some(padding) if padding.iter().all(|byte| *byte == 0u8) => PoSWVersion::V2,
+            _ => PoSWVersion::V1,
+        }
+    }
+
+    ///
+    /// Returns the PoSW proof of the given version from its padded bytes.
+    ///
+    /// This fails if the proof does not consume every byte that is not padding.
+    ///
+    fn from_padded_bytes_le(version: PoSWVersion, buffer: &[u8]) -> IoResult<Self> {
+        match version {
+            PoSWVersion::V1 => {
+                let mut reader = buffer;
+                if let Ok(proof) = crate::testnet2::DeprecatedPoSWProof::<N>::read_le(&mut reader) {
+                    if reader.is_empty() {
+                        return Ok(Self::Hiding(proof));
+                    }
+                }
+            }
+            PoSWVersion::V2 => {
+                if buffer.len() >= 691 && buffer[691..].iter().all(|byte| *byte == 0u8) {
+                    if let Ok(proof) = N::PoSWProof::read_le(&buffer[..691]) {
+                        return Ok(Self::NonHiding(proof));
+                    }
+                }
+            }
+        }
+
+        Err(PoSWError::Message(format!("Failed to deserialize a {} PoSW proof", version)).into())
+    }
+
+    ///
+    /// Returns the PoSW proof bytes, prefixed with the byte of its version.
+    ///
+    /// This encoding identifies the version of an archived proof without the block height or the network upgrades.
+    ///
+    pub fn to_versioned_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![self.version() as u8];
+        self.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    ///
+    /// Returns the PoSW proof from bytes that are prefixed with the byte of its version.
+    ///
+    pub fn from_versioned_bytes_le(bytes: &[u8]) -> Result<Self> {
+        let (version, proof) = bytes
+            .split_first()
+            .ok_or_else(|| anyhow!("Missing the version of the PoSW proof"))?;
+        let version = PoSWVersion::from_u8(*version).ok_or_else(|| anyhow!("Invalid PoSW version {}", version))?;
+        Ok(Self::read_le_with_version(version, proof)?)
+    }
+
     ///
     /// Returns the proof difficulty, determined by double-hashing the proof bytes to a u64.
     ///
@@ -68,18 +170,57 @@ impl<N: Network> PoSWProof<N> {
         Ok(sha256d_to_u64(&self.to_bytes_le()?))
     }
 
+    ///
+    /// Returns `true` if the proof difficulty satisfies the given difficulty target.
+    ///
+    /// This check only hashes the proof bytes, and may be used to discard
+    /// low-difficulty proofs before performing the SNARK verification.
+    ///
+    pub fn satisfies_target(&self, difficulty_target: u64) -> bool {
+        match self.to_proof_difficulty() {
+            Ok(proof_difficulty) => {
+                if proof_difficulty > difficulty_target {
+                    #[cfg(debug_assertions)]
+                    eprintln!(
+                        "PoSW difficulty target is not met. Expected {}, found {}",
+                        difficulty_target, proof_difficulty
+                    );
+                    return false;
+                }
+                true
+            }
+            Err(error) => {
+                eprintln!("Failed to convert PoSW proof to bytes: {}", error);
+                false
+            }
+        }
+    }
+
+    ///
+    /// Returns `true` if the PoSW proof satisfies the given difficulty target and is valid.
+    /// The difficulty target is checked first, to avoid verifying low-difficulty proofs.
+    ///
+    pub fn verify_with_target(
+        &self,
+        verifying_key: &<N::PoSWSNARK as SNARK>::VerifyingKey,
+        inputs: &[N::InnerScalarField],
+        difficulty_target: u64,
+    ) -> bool {
+        self.satisfies_target(difficulty_target) && self.verify(verifying_key, inputs)
+    }
+
     ///
     /// Returns `true` if the PoSW proof is valid.
     ///
     pub fn verify(
         &self,
-        verifying_key: &<<N as Network>::PoSWSNARK as SNARK>::VerifyingKey,
+        verifying_key: &<N::PoSWSNARK as SNARK>::VerifyingKey,
         inputs: &[N::InnerScalarField],
     ) -> bool {
         match self {
             Self::NonHiding(proof) => {
                 // Ensure the proof is valid.
-                if !<<N as Network>::PoSWSNARK as SNARK>::verify(verifying_key, &inputs.to_vec(), proof).unwrap() {
+                if !<N::PoSWSNARK as SNARK>::verify(verifying_key, &inputs.to_vec(), proof).unwrap() {
                     #[cfg(debug_assertions)]
                     eprintln!("PoSW proof verification failed");
                     return false;
@@ -117,21 +258,30 @@ impl<N: Network> PoSWProof<N> {
     }
 }
 
+#[cfg(any(test, feature = "dev"))]
+impl<N: Network> PoSWProof<N> {
+    ///
+    /// Returns the lowest difficulty target that the proof satisfies, which is its proof difficulty.
+    ///
+    pub fn to_lowest_satisfied_target(&self) -> Result<u64> {
+        self.to_proof_difficulty()
+    }
+
+    ///
+    /// Returns the highest difficulty target that the proof does not satisfy,
+    /// or `None` if the proof satisfies every difficulty target.
+    ///
+    pub fn to_highest_unsatisfied_target(&self) -> Result<Option<u64>> {
+        Ok(self.to_proof_difficulty()?.checked_sub(1))
+    }
+}
+
 impl<N: Network> FromBytes for PoSWProof<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let mut buffer = vec![0u8; N::HEADER_PROOF_SIZE_IN_BYTES];
         reader.read_exact(&mut buffer)?;
-
-        if buffer[691..N::HEADER_PROOF_SIZE_IN_BYTES] == [0u8; 80] {
-            if let Ok(proof) = N::PoSWProof::read_le(&buffer[..691]) {
-                return Ok(Self::NonHiding(proof));
-            }
-        } else if let Ok(proof) = crate::testnet2::DeprecatedPoSWProof::<N>::read_le(&buffer[..]) {
-            return Ok(Self::Hiding(proof));
-        }
-
-        Err(PoSWError::Message("Failed to deserialize PoSW proof with FromBytes".to_string()).into())
+        Self::from_padded_bytes_le(Self::padded_version(&buffer), &buffer)
     }
 }
 
@@ -233,6 +383,7 @@ mod tests {
             ); // NOTE: Marlin proofs use compressed serialization
             assert!(Testnet2::posw().verify_from_block_header(&latest_block_header));
             assert!(latest_proof.is_hiding());
+            assert_eq!(PoSWVersion::V1, latest_block_header.posw_version());
         }
 
         // Check block 1.
@@ -265,9 +416,70 @@ mod tests {
             ); // NOTE: Marlin proofs use compressed serialization
             assert!(Testnet2::posw().verify_from_block_header(&latest_block_header));
             assert!(!latest_proof.is_hiding());
+            assert_eq!(PoSWVersion::V2, latest_block_header.posw_version());
         }
     }
 
+    #[test]
+    fn test_versioned_bytes() {
+        let proof = Testnet2::genesis_block().header().proof();
+        assert_eq!(PoSWVersion::V1, proof.version());
+
+        // Ensure the proof round-trips through its versioned bytes.
+        let versioned_bytes = proof.to_versioned_bytes_le().unwrap();
+        assert_eq!(PoSWVersion::V1 as u8, versioned_bytes[0]);
+        assert_eq!(proof.to_bytes_le().unwrap(), versioned_bytes[1..]);
+        assert_eq!(
+            *proof,
+            PoSWProof::<Testnet2>::from_versioned_bytes_le(&versioned_bytes).unwrap()
+        );
+
+        // Ensure the proof is not read as a proof of a different version.
+        assert!(PoSWProof::<Testnet2>::read_le_with_version(PoSWVersion::V2, &versioned_bytes[1..]).is_err());
+
+        let mut invalid_bytes = versioned_bytes.clone();
+        invalid_bytes[0] = PoSWVersion::V2 as u8;
+        assert!(PoSWProof::<Testnet2>::from_versioned_bytes_le(&invalid_bytes).is_err());
+        invalid_bytes[0] = 0;
+        assert!(PoSWProof::<Testnet2>::from_versioned_bytes_le(&invalid_bytes).is_err());
+        assert!(PoSWProof::<Testnet2>::from_versioned_bytes_le(&[]).is_err());
+    }
+
+    #[test]
+    fn test_read_le_for_height() {
+        let proof = Testnet2::genesis_block().header().proof();
+        let bytes = proof.to_bytes_le().unwrap();
+        assert_eq!(Testnet2::UPGRADE_SCHEDULE.get(0).posw_proof_size_in_bytes, bytes.len());
+
+        // Ensure the proof round-trips at the size scheduled for its block height.
+        assert_eq!(
+            *proof,
+            PoSWProof::<Testnet2>::read_le_for_height(0, &bytes[..]).unwrap()
+        );
+
+        // Ensure a proof that is shorter than the scheduled size is rejected.
+        assert!(PoSWProof::<Testnet2>::read_le_for_height(0, &bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_satisfies_target() {
+        let block_header = Testnet2::genesis_block().header();
+        let proof = block_header.proof();
+        let proof_difficulty = proof.to_proof_difficulty().unwrap();
+        let verifying_key = Testnet2::posw().verifying_key();
+        let inputs = [*block_header.to_header_root().unwrap(), *block_header.nonce()];
+
+        // Ensure the difficulty check passes for targets at or above the proof difficulty.
+        assert!(proof.satisfies_target(block_header.difficulty_target()));
+        assert!(proof.satisfies_target(proof_difficulty));
+        assert!(proof.satisfies_target(u64::MAX));
+        assert!(proof.verify_with_target(verifying_key, &inputs, block_header.difficulty_target()));
+
+        // Ensure the difficulty check fails for targets below the proof difficulty.
+        assert!(!proof.satisfies_target(proof_difficulty - 1));
+        assert!(!proof.verify_with_target(verifying_key, &inputs, proof_difficulty - 1));
+    }
+
     #[test]
     fn test_genesis_proof() {
         use snarkvm_parameters::Genesis;
@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    BlockHeader,
+    BlockTemplate,
+    Network,
+    NetworkParameters,
+    PoSWCircuit,
+    PoSWError,
+    PoSWProof,
+    PoSWRng,
+    PoSWScheme,
+    PoSWVersion,
+    ProofOfSuccinctWork,
+};
+use snarkvm_utilities::UniformRand;
+
+use std::{collections::HashMap, marker::PhantomData, sync::atomic::AtomicBool, time::Instant};
+
+///
+/// A PoSW scheme, by default of the network, as an algorithm that produces and verifies proofs
+/// of the given PoSW version.
+///
+pub struct VersionedPoSW<'a, N: Network, P: PoSWScheme<N> = <N as NetworkParameters>::PoSW> {
+    /// The version of the PoSW proofs.
+    version: PoSWVersion,
+    /// The PoSW scheme.
+    posw: &'a P,
+    _network: PhantomData<N>,
+}
+
+impl<'a, N: Network, P: PoSWScheme<N>> VersionedPoSW<'a, N, P> {
+    /// Initializes a new instance of the given PoSW scheme, for proofs of the given PoSW version.
+    pub fn new(version: PoSWVersion, posw: &'a P) -> Self {
+        Self {
+            version,
+            posw,
+            _network: PhantomData,
+        }
+    }
+}
+
+impl<'a, N: Network, P: PoSWScheme<N>> ProofOfSuccinctWork<N> for VersionedPoSW<'a, N, P> {
+    fn version(&self) -> PoSWVersion {
+        self.version
+    }
+
+    fn prove_once_unchecked(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        mut rng: &mut dyn PoSWRng,
+    ) -> Result<(N::PoSWNonce, PoSWProof<N>), PoSWError> {
+        // Instantiate the circuit, whose nonce is sampled by the prover.
+        let mut circuit = PoSWCircuit::<N>::new(block_template, UniformRand::rand(&mut rng))?;
+        let proof = self
+            .posw
+            .prove_once_unchecked_with_version(self.version, &mut circuit, terminator, &mut rng)?;
+        Ok((circuit.nonce(), proof))
+    }
+
+    fn verify_proof(&self, inputs: &[N::InnerScalarField], proof: &PoSWProof<N>) -> bool {
+        proof.verify(self.posw.verifying_key(), inputs)
+    }
+}
+
+///
+/// A registry of PoSW algorithms, keyed by the version of the proofs they produce.
+///
+/// Blocks are mined and verified with the algorithm registered for the PoSW version
+/// that is scheduled at their height in the network upgrades.
+///
+pub struct PoSWRegistry<'a, N: Network> {
+    algorithms: HashMap<PoSWVersion, Box<dyn ProofOfSuccinctWork<N> + 'a>>,
+}
+
+impl<'a, N: Network> PoSWRegistry<'a, N> {
+    /// Initializes a new instance of an empty registry.
+    pub fn new() -> Self {
+        Self {
+            algorithms: HashMap::new(),
+        }
+    }
+
+    /// Registers the given algorithm for its PoSW version, and returns the algorithm it replaces, if any.
+    pub fn register(
+        &mut self,
+        algorithm: Box<dyn ProofOfSuccinctWork<N> + 'a>,
+    ) -> Option<Box<dyn ProofOfSuccinctWork<N> + 'a>> {
+        self.algorithms.insert(algorithm.version(), algorithm)
+    }
+
+    /// Returns the algorithm registered for the given PoSW version, if it exists.
+    pub fn get(&self, version: PoSWVersion) -> Option<&(dyn ProofOfSuccinctWork<N> + 'a)> {
+        self.algorithms.get(&version).map(|algorithm| algorithm.as_ref())
+    }
+
+    /// Returns the algorithm registered for the PoSW version scheduled at the given block height.
+    pub fn get_for_height(&self, block_height: u32) -> Result<&(dyn ProofOfSuccinctWork<N> + 'a), PoSWError> {
+        let version = N::UPGRADE_SCHEDULE.get(block_height).posw_mode.version();
+        self.get(version).ok_or(PoSWError::UnregisteredVersion(version))
+    }
+
+    /// Returns the PoSW versions in the registry, in increasing order.
+    pub fn versions(&self) -> Vec<PoSWVersion> {
+        let mut versions = self.algorithms.keys().copied().collect::<Vec<_>>();
+        versions.sort_by_key(|version| *version as u8);
+        versions
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// with the algorithm of the PoSW version scheduled at the block height.
+    ///
+    pub fn mine(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        rng: &mut dyn PoSWRng,
+    ) -> Result<BlockHeader<N>, PoSWError> {
+        self.get_for_height(block_template.block_height())?
+            .mine(block_template, terminator, rng)
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// with the algorithm of the PoSW version scheduled at the block height,
+    /// or return `PoSWError::Timeout` once the deadline passes.
+    ///
+    pub fn mine_until(
+        &self,
+        deadline: Instant,
+        block_template: &BlockTemplate<N>,
+        rng: &mut dyn PoSWRng,
+    ) -> Result<BlockHeader<N>, PoSWError> {
+        self.get_for_height(block_template.block_height())?
+            .mine_until(deadline, block_template, rng)
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof for a random nonce, and return the nonce and the proof,
+    /// with the algorithm of the PoSW version scheduled at the block height.
+    /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
+    ///
+    pub fn prove_once_unchecked(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        rng: &mut dyn PoSWRng,
+    ) -> Result<(N::PoSWNonce, PoSWProof<N>), PoSWError> {
+        self.get_for_height(block_template.block_height())?
+            .prove_once_unchecked(block_template, terminator, rng)
+    }
+
+    ///
+    /// Verifies the Proof of Succinct Work against the nonce, root, and difficulty target of the block header,
+    /// with the algorithm of the PoSW version scheduled at the block height.
+    ///
+    pub fn verify_from_block_header(&self, block_header: &BlockHeader<N>) -> bool {
+        match self.get_for_height(block_header.height()) {
+            Ok(algorithm) => algorithm.verify_from_block_header(block_header),
+            Err(error) => {
+                eprintln!("{}", error);
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> PoSWRegistry<'static, N> {
+    /// Returns a registry with the PoSW scheme of the network, for each PoSW version in its upgrade schedule.
+    pub fn from_network() -> Self {
+        let mut registry = Self::new();
+        for upgrade in N::UPGRADE_SCHEDULE.upgrades() {
+            let version = upgrade.posw_mode.version();
+            if registry.get(version).is_none() {
+                registry.register(Box::new(VersionedPoSW::<N>::new(version, N::posw())));
+            }
+        }
+        registry
+    }
+}
+
+impl<'a, N: Network> Default for PoSWRegistry<'a, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, NetworkConstants, NetworkParameters};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_registry_from_network() {
+        let registry = PoSWRegistry::<Testnet2>::from_network();
+
+        // Ensure every PoSW version in the upgrade schedule is registered once.
+        let mut expected_versions = Testnet2::UPGRADE_SCHEDULE
+            .upgrades()
+            .iter()
+            .map(|upgrade| upgrade.posw_mode.version())
+            .collect::<Vec<_>>();
+        expected_versions.dedup();
+        assert_eq!(expected_versions, registry.versions());
+
+        for version in registry.versions() {
+            assert_eq!(version, registry.get(version).unwrap().version());
+        }
+
+        // Ensure the genesis block verifies with the algorithm of its scheduled version.
+        let genesis_header = Testnet2::genesis_block().header();
+        assert!(registry.verify_from_block_header(genesis_header));
+        assert_eq!(
+            genesis_header.posw_version(),
+            registry.get_for_height(genesis_header.height()).unwrap().version()
+        );
+
+        // Ensure the proof encoding of each algorithm only accepts proofs of its version.
+        let algorithm = registry.get_for_height(genesis_header.height()).unwrap();
+        let proof_bytes = algorithm.proof_to_bytes_le(genesis_header.proof()).unwrap();
+        assert_eq!(
+            *genesis_header.proof(),
+            algorithm.proof_from_bytes_le(&proof_bytes).unwrap()
+        );
+        for version in registry.versions() {
+            if version != algorithm.version() {
+                assert!(registry
+                    .get(version)
+                    .unwrap()
+                    .proof_from_bytes_le(&proof_bytes)
+                    .is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_unregistered_version() {
+        let registry = PoSWRegistry::<Testnet2>::new();
+        assert!(registry.versions().is_empty());
+
+        let genesis_header = Testnet2::genesis_block().header();
+        assert!(matches!(
+            registry.get_for_height(genesis_header.height()),
+            Err(PoSWError::UnregisteredVersion(_))
+        ));
+        assert!(!registry.verify_from_block_header(genesis_header));
+
+        let block_template = BlockTemplate::<Testnet2>::new_test(u64::MAX).unwrap();
+        assert!(matches!(
+            registry.mine(&block_template, &AtomicBool::new(false), &mut thread_rng()),
+            Err(PoSWError::UnregisteredVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_versioned_posw_mine() {
+        let block_template = BlockTemplate::<Testnet2>::new_test(u64::MAX).unwrap();
+        let version = Testnet2::UPGRADE_SCHEDULE
+            .get(block_template.block_height())
+            .posw_mode
+            .version();
+        let algorithm = VersionedPoSW::<Testnet2>::new(version, Testnet2::posw());
+
+        // Ensure the mined block header verifies with both the algorithm and the network PoSW.
+        let block_header = algorithm
+            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .unwrap();
+        assert_eq!(version, block_header.posw_version());
+        assert!(algorithm.verify_from_block_header(&block_header));
+        assert!(Testnet2::posw().verify_from_block_header(&block_header));
+
+        // Ensure the proof difficulty is the same for the algorithm and the proof.
+        assert_eq!(
+            block_header.proof().to_proof_difficulty().unwrap(),
+            algorithm.to_proof_difficulty(block_header.proof()).unwrap()
+        );
+
+        // Ensure the proof does not satisfy a difficulty target below its proof difficulty.
+        let proof_difficulty = algorithm.to_proof_difficulty(block_header.proof()).unwrap();
+        if let Some(unsatisfied_target) = proof_difficulty.checked_sub(1) {
+            let inputs = [*block_header.to_header_root().unwrap(), *block_header.nonce()];
+            assert!(algorithm.verify(proof_difficulty, &inputs, block_header.proof()));
+            assert!(!algorithm.verify(unsatisfied_target, &inputs, block_header.proof()));
+        }
+    }
+}
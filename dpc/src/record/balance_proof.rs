@@ -0,0 +1,384 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Address,
+    AleoAmount,
+    BalanceCircuit,
+    BalancePrivateVariables,
+    BalancePublicVariables,
+    ComputeKey,
+    LedgerProof,
+    Network,
+    PrivateKey,
+    Record,
+};
+use snarkvm_algorithms::{snark::groth16::Groth16, traits::SNARK, SRS};
+use snarkvm_utilities::{has_duplicates, to_bytes_le, FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::io::{Read, Result as IoResult, Write};
+
+/// The SNARK for balance proofs.
+pub type BalanceSNARK<N> = Groth16<<N as Network>::InnerCurve, BalancePublicVariables<N>>;
+
+///
+/// A proof that a set of records, owned by one account, are in the ledger with the given ledger root,
+/// and have a total value of at least the given threshold, without revealing the records.
+///
+/// The proof reveals the serial numbers of the records, which are unlinkable to the record commitments.
+/// A verifier must check that the serial numbers are not in the ledger, to ensure the records are unspent.
+///
+/// Note: A serial number is revealed again when its record is spent, so anyone holding the balance proof
+/// learns which later transaction spends each of the records. The balance proof should only be shared
+/// with parties that may link the records to their spends, such as the auditor of an exchange.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct BalanceProof<N: Network> {
+    ledger_root: N::LedgerRoot,
+    threshold: AleoAmount,
+    serial_numbers: Vec<N::SerialNumber>,
+    proof: <BalanceSNARK<N> as SNARK>::Proof,
+}
+
+impl<N: Network> BalanceProof<N> {
+    /// Returns the proving and verifying keys for balance proofs over the given number of records.
+    pub fn setup<R: Rng + CryptoRng>(
+        num_records: usize,
+        rng: &mut R,
+    ) -> Result<(
+        <BalanceSNARK<N> as SNARK>::ProvingKey,
+        <BalanceSNARK<N> as SNARK>::VerifyingKey,
+    )> {
+        if num_records == 0 {
+            return Err(anyhow!("A balance proof requires at least one record"));
+        }
+        Ok(BalanceSNARK::<N>::setup(
+            &BalanceCircuit::<N>::blank(num_records),
+            &mut SRS::CircuitSpecific(rng),
+        )?)
+    }
+
+    /// Returns a proof that the given records of the private key have a total value of at least the given threshold.
+    /// The ledger proofs must be for the given records, in order, with respect to the same ledger root.
+    pub fn prove<R: Rng + CryptoRng>(
+        proving_key: &<BalanceSNARK<N> as SNARK>::ProvingKey,
+        private_key: &PrivateKey<N>,
+        records: &[Record<N>],
+        ledger_proofs: &[LedgerProof<N>],
+        threshold: AleoAmount,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure there is a ledger proof for each record.
+        if records.is_empty() || records.len() != ledger_proofs.len() {
+            return Err(anyhow!("A balance proof requires one ledger proof for each record"));
+        }
+
+        // Ensure the records are distinct.
+        if has_duplicates(records.iter().map(|record| record.commitment())) {
+            return Err(anyhow!("A balance proof may not contain duplicate records"));
+        }
+
+        // Ensure the records are owned by the private key, and are not dummy records.
+        let address = Address::from_private_key(private_key);
+        if records
+            .iter()
+            .any(|record| record.owner() != address || record.is_dummy())
+        {
+            return Err(anyhow!("Records must be non-dummy records owned by the private key"));
+        }
+
+        // Ensure the ledger proofs are for the records, with respect to the same ledger root.
+        let ledger_root = ledger_proofs[0].ledger_root();
+        for (record, ledger_proof) in records.iter().zip(ledger_proofs) {
+            if ledger_proof.commitment() != record.commitment() || ledger_proof.ledger_root() != ledger_root {
+                return Err(anyhow!("Ledger proof does not match record {}", record.commitment()));
+            }
+        }
+
+        // Ensure the records are worth at least the threshold.
+        let total_value = records
+            .iter()
+            .try_fold(0i64, |total, record| total.checked_add(record.value().as_i64()))
+            .ok_or_else(|| anyhow!("Total value of the records overflows"))?;
+        if total_value < threshold.as_i64() {
+            return Err(anyhow!(
+                "Records are worth {}, which is less than {}",
+                total_value,
+                threshold
+            ));
+        }
+
+        // Sign the record commitments, and compute the serial numbers of the records.
+        let commitments: Vec<_> = records.iter().map(|record| record.commitment()).collect();
+        let signature = private_key.sign(&to_bytes_le![commitments]?, rng)?;
+        let compute_key = ComputeKey::from_private_key(private_key);
+        let serial_numbers = records
+            .iter()
+            .map(|record| Ok(record.to_serial_number(&compute_key)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        let public = BalancePublicVariables::new(ledger_root, threshold, serial_numbers.clone());
+        let private = BalancePrivateVariables::new(records.to_vec(), ledger_proofs.to_vec(), signature);
+        let proof = BalanceSNARK::<N>::prove(proving_key, &BalanceCircuit::new(public, private), rng)?;
+
+        Ok(Self {
+            ledger_root,
+            threshold,
+            serial_numbers,
+            proof,
+        })
+    }
+
+    /// Returns the ledger root that the records are proven to be in.
+    pub fn ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_root
+    }
+
+    /// Returns the minimum total value of the records.
+    pub fn threshold(&self) -> AleoAmount {
+        self.threshold
+    }
+
+    /// Returns the serial numbers of the records.
+    pub fn serial_numbers(&self) -> &Vec<N::SerialNumber> {
+        &self.serial_numbers
+    }
+
+    /// Returns `true` if the balance proof is valid for the given verifying key.
+    /// This does not check that the records are unspent, which requires the ledger.
+    pub fn verify(&self, verifying_key: &<BalanceSNARK<N> as SNARK>::VerifyingKey) -> bool {
+        // Ensure the records are distinct.
+        if has_duplicates(&self.serial_numbers) {
+            eprintln!("Balance proof contains duplicate serial numbers");
+            return false;
+        }
+
+        let public = BalancePublicVariables::new(self.ledger_root, self.threshold, self.serial_numbers.clone());
+        match BalanceSNARK::<N>::verify(verifying_key, &public, &self.proof) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify the balance proof: {}", error);
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for BalanceProof<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let ledger_root = FromBytes::read_le(&mut reader)?;
+        let threshold = FromBytes::read_le(&mut reader)?;
+
+        let num_serial_numbers: u16 = FromBytes::read_le(&mut reader)?;
+        let mut serial_numbers = Vec::with_capacity(num_serial_numbers as usize);
+        for _ in 0..num_serial_numbers {
+            serial_numbers.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let proof = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            ledger_root,
+            threshold,
+            serial_numbers,
+            proof,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for BalanceProof<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.ledger_root.write_le(&mut writer)?;
+        self.threshold.write_le(&mut writer)?;
+        (self.serial_numbers.len() as u16).write_le(&mut writer)?;
+        self.serial_numbers.write_le(&mut writer)?;
+        self.proof.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme, Ledger};
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_balance_proof() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        // Mine two blocks to the account.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let records = (0..2)
+            .map(|_| {
+                ledger
+                    .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let ledger_proofs = records
+            .iter()
+            .map(|record| ledger.to_ledger_proof(record.commitment()).unwrap())
+            .collect::<Vec<_>>();
+        let total_value = records[0].value().add(records[1].value());
+
+        // Ensure a balance proof for the total value verifies.
+        let (proving_key, verifying_key) = BalanceProof::<Testnet2>::setup(2, rng).unwrap();
+        let balance_proof = BalanceProof::prove(
+            &proving_key,
+            account.private_key(),
+            &records,
+            &ledger_proofs,
+            total_value,
+            rng,
+        )
+        .unwrap();
+        assert!(balance_proof.verify(&verifying_key));
+        assert!(ledger.verify_balance_proof(&balance_proof, &verifying_key));
+
+        // Ensure the balance proof round-trips through bytes.
+        let balance_proof_bytes = balance_proof.to_bytes_le().unwrap();
+        let candidate_balance_proof = BalanceProof::<Testnet2>::read_le(&balance_proof_bytes[..]).unwrap();
+        assert_eq!(balance_proof_bytes, candidate_balance_proof.to_bytes_le().unwrap());
+        assert!(candidate_balance_proof.verify(&verifying_key));
+
+        // Ensure the balance proof does not verify for a different threshold.
+        let mut invalid_balance_proof = balance_proof.clone();
+        invalid_balance_proof.threshold = total_value.add(AleoAmount::from_i64(1));
+        assert!(!invalid_balance_proof.verify(&verifying_key));
+
+        // Ensure the balance proof does not verify with a duplicate serial number.
+        let mut invalid_balance_proof = balance_proof.clone();
+        invalid_balance_proof.serial_numbers[1] = invalid_balance_proof.serial_numbers[0];
+        assert!(!invalid_balance_proof.verify(&verifying_key));
+        assert!(!ledger.verify_balance_proof(&invalid_balance_proof, &verifying_key));
+
+        // Ensure a balance proof cannot be created with a duplicate record.
+        assert!(BalanceProof::prove(
+            &proving_key,
+            account.private_key(),
+            &[records[0].clone(), records[0].clone()],
+            &[ledger_proofs[0].clone(), ledger_proofs[0].clone()],
+            records[0].value(),
+            rng,
+        )
+        .is_err());
+
+        // Ensure a balance proof cannot be created for more than the total value.
+        assert!(BalanceProof::prove(
+            &proving_key,
+            account.private_key(),
+            &records,
+            &ledger_proofs,
+            total_value.add(AleoAmount::from_i64(1)),
+            rng,
+        )
+        .is_err());
+
+        // Ensure a balance proof cannot be created by a different account.
+        let stranger = Account::<Testnet2>::new(rng);
+        assert!(BalanceProof::prove(
+            &proving_key,
+            stranger.private_key(),
+            &records,
+            &ledger_proofs,
+            total_value,
+            rng,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_balance_circuit_threshold() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let record = ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+        let serial_number = record
+            .to_serial_number(&ComputeKey::from_private_key(account.private_key()))
+            .unwrap();
+        let signature = account
+            .private_key()
+            .sign(&record.commitment().to_bytes_le().unwrap(), rng)
+            .unwrap();
+
+        let is_satisfied = |threshold: AleoAmount| {
+            let public = BalancePublicVariables::new(ledger_proof.ledger_root(), threshold, vec![serial_number]);
+            let private =
+                BalancePrivateVariables::new(vec![record.clone()], vec![ledger_proof.clone()], signature.clone());
+
+            let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+            BalanceCircuit::new(public, private)
+                .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+                .unwrap();
+            cs.is_satisfied()
+        };
+
+        // Ensure the circuit is satisfied if and only if the record value is at least the threshold.
+        assert!(is_satisfied(AleoAmount::ZERO));
+        assert!(is_satisfied(record.value()));
+        assert!(!is_satisfied(record.value().add(AleoAmount::from_i64(1))));
+    }
+
+    #[test]
+    fn test_balance_circuit_duplicate_records() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let record = ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng)
+            .unwrap();
+        let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+        let serial_number = record
+            .to_serial_number(&ComputeKey::from_private_key(account.private_key()))
+            .unwrap();
+        let signature = account
+            .private_key()
+            .sign(&to_bytes_le![record.commitment(), record.commitment()].unwrap(), rng)
+            .unwrap();
+
+        // Ensure the circuit is not satisfied if the same record is counted twice.
+        let public = BalancePublicVariables::new(ledger_proof.ledger_root(), record.value(), vec![
+            serial_number,
+            serial_number,
+        ]);
+        let private = BalancePrivateVariables::new(
+            vec![record.clone(), record],
+            vec![ledger_proof.clone(), ledger_proof],
+            signature,
+        );
+
+        let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+        BalanceCircuit::new(public, private)
+            .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+            .unwrap();
+        assert!(!cs.is_satisfied());
+        assert!(cs.which_is_unsatisfied().unwrap().contains("distinct"));
+    }
+}
@@ -14,18 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Bech32Locator, Network, RecordError, ViewKey};
+use crate::{Bech32Locator, Network, RecordCiphertextFormat, RecordError, ViewKey};
 use snarkvm_algorithms::traits::{EncryptionScheme, CRH};
+use snarkvm_fields::{FieldParameters, PrimeField};
 use snarkvm_utilities::{
     io::{Cursor, Result as IoResult},
     to_bytes_le,
+    FromBits,
     FromBytes,
     Read,
+    ToBits,
     ToBytes,
     Write,
 };
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 #[derive(Derivative)]
 #[derivative(
@@ -94,11 +97,113 @@ impl<N: Network> Ciphertext<N> {
         &self.record_view_key_commitment
     }
 
+    /// Returns the encrypted record bytes.
+    pub fn record_bytes(&self) -> &[u8] {
+        &self.record_bytes
+    }
+
     /// Returns the plaintext corresponding to the record ciphertext.
     pub fn to_plaintext(&self, record_view_key: &N::RecordViewKey) -> Result<Vec<u8>, RecordError> {
         // Decrypt the record ciphertext.
         Ok(N::account_encryption_scheme().decrypt(record_view_key, &self.record_bytes)?)
     }
+
+    /// Returns the size of a record ciphertext in the packed wire format, in bytes.
+    pub fn packed_size_in_bytes() -> usize {
+        (Self::num_field_elements() * Self::field_element_size_in_bits() + 7) / 8
+    }
+
+    ///
+    /// Returns the record ciphertext in the packed wire format.
+    ///
+    /// A record ciphertext consists of field elements, namely the ciphertext randomizer,
+    /// the record view key commitment, and the encrypted record. The packed wire format
+    /// concatenates the bits of the field elements, instead of padding each one to whole bytes.
+    ///
+    pub fn to_packed_bytes_le(&self) -> Result<Vec<u8>> {
+        let bytes = self.to_bytes_le()?;
+
+        let mut bits = Vec::with_capacity(Self::packed_size_in_bytes() * 8);
+        for element_bytes in bytes.chunks(Self::field_element_size_in_bytes()) {
+            bits.extend(N::InnerScalarField::read_le(element_bytes)?.to_bits_le());
+        }
+
+        Ok(bits
+            .chunks(8)
+            .map(|byte_bits| byte_bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+            .collect())
+    }
+
+    /// Returns the record ciphertext from the given bytes in the packed wire format.
+    pub fn from_packed_bytes_le(packed_bytes: &[u8]) -> Result<Self> {
+        if packed_bytes.len() != Self::packed_size_in_bytes() {
+            return Err(anyhow!(
+                "Invalid packed record ciphertext size. Expected {}, found {}",
+                Self::packed_size_in_bytes(),
+                packed_bytes.len()
+            ));
+        }
+
+        let bits: Vec<bool> = packed_bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect();
+        let (element_bits, padding_bits) =
+            bits.split_at(Self::num_field_elements() * Self::field_element_size_in_bits());
+        if padding_bits.iter().any(|bit| *bit) {
+            return Err(anyhow!("Invalid padding in packed record ciphertext"));
+        }
+
+        let mut bytes = Vec::with_capacity(N::RECORD_CIPHERTEXT_SIZE_IN_BYTES);
+        for bits in element_bits.chunks(Self::field_element_size_in_bits()) {
+            let repr = <N::InnerScalarField as PrimeField>::BigInteger::from_bits_le(bits);
+            let element = N::InnerScalarField::from_repr(repr)
+                .ok_or_else(|| anyhow!("Invalid field element in packed record ciphertext"))?;
+            element.write_le(&mut bytes)?;
+        }
+
+        Ok(Self::read_le(&bytes[..])?)
+    }
+
+    /// Returns the record ciphertext in the wire format of the network upgrade at the given block height.
+    pub fn to_wire_bytes_le(&self, block_height: u32) -> Result<Vec<u8>> {
+        match N::UPGRADE_SCHEDULE.get(block_height).record_ciphertext_format {
+            RecordCiphertextFormat::Standard => Ok(self.to_bytes_le()?),
+            RecordCiphertextFormat::Packed => self.to_packed_bytes_le(),
+        }
+    }
+
+    /// Returns the record ciphertext from the given bytes, if they are in a wire format
+    /// accepted by the network upgrade at the given block height.
+    pub fn from_wire_bytes_le(bytes: &[u8], block_height: u32) -> Result<Self> {
+        let format = N::UPGRADE_SCHEDULE.get(block_height).record_ciphertext_format;
+        match bytes.len() {
+            size if size == N::RECORD_CIPHERTEXT_SIZE_IN_BYTES => Ok(Self::read_le(bytes)?),
+            size if size == Self::packed_size_in_bytes() && format == RecordCiphertextFormat::Packed => {
+                Self::from_packed_bytes_le(bytes)
+            }
+            size => Err(anyhow!(
+                "Record ciphertext of {} bytes is not accepted at block {}",
+                size,
+                block_height
+            )),
+        }
+    }
+
+    /// Returns the number of field elements in a record ciphertext.
+    fn num_field_elements() -> usize {
+        N::RECORD_CIPHERTEXT_SIZE_IN_BYTES / Self::field_element_size_in_bytes()
+    }
+
+    /// Returns the size of a field element of a record ciphertext in the standard format, in bytes.
+    fn field_element_size_in_bytes() -> usize {
+        N::RecordRandomizer::data_size_in_bytes()
+    }
+
+    /// Returns the size of a field element of a record ciphertext in the packed format, in bits.
+    fn field_element_size_in_bits() -> usize {
+        <N::InnerScalarField as PrimeField>::Parameters::MODULUS_BITS as usize
+    }
 }
 
 impl<N: Network> FromBytes for Ciphertext<N> {
@@ -138,6 +243,38 @@ impl<N: Network> ToBytes for Ciphertext<N> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    #[test]
+    fn test_packed_wire_format() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+
+        for ciphertext in transaction.ciphertexts() {
+            let ciphertext: &Ciphertext<Testnet2> = ciphertext;
+            let packed_bytes = ciphertext.to_packed_bytes_le().unwrap();
+            assert_eq!(285, Ciphertext::<Testnet2>::packed_size_in_bytes());
+            assert_eq!(Ciphertext::<Testnet2>::packed_size_in_bytes(), packed_bytes.len());
+            assert_eq!(*ciphertext, Ciphertext::from_packed_bytes_le(&packed_bytes).unwrap());
+
+            // Ensure the padding bits are checked.
+            let mut invalid_bytes = packed_bytes.clone();
+            *invalid_bytes.last_mut().unwrap() |= 0x80;
+            assert!(Ciphertext::<Testnet2>::from_packed_bytes_le(&invalid_bytes).is_err());
+            assert!(Ciphertext::<Testnet2>::from_packed_bytes_le(&packed_bytes[1..]).is_err());
+
+            // Ensure the standard wire format is accepted, and the packed wire format is not,
+            // as Testnet2 does not activate the packed wire format.
+            let wire_bytes = ciphertext.to_wire_bytes_le(0).unwrap();
+            assert_eq!(ciphertext.to_bytes_le().unwrap(), wire_bytes);
+            assert_eq!(*ciphertext, Ciphertext::from_wire_bytes_le(&wire_bytes, 0).unwrap());
+            assert!(Ciphertext::<Testnet2>::from_wire_bytes_le(&packed_bytes, u32::MAX).is_err());
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// The earliest point at which a record may be consumed, as a minimum block height or block timestamp.
+///
+/// In a record, a locktime is encoded as a minimum block height and a minimum timestamp,
+/// one of which is zero. A record without a locktime encodes both as zero.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Locktime {
+    /// The record may be consumed in a block at or above the given height.
+    BlockHeight(u32),
+    /// The record may be consumed in a block at or after the given timestamp.
+    Timestamp(i64),
+}
+
+impl Locktime {
+    /// Returns `true` if a record with this locktime may be consumed by a transition with the given cutoff.
+    pub fn is_unlocked(&self, cutoff: &LocktimeCutoff) -> bool {
+        match self {
+            Self::BlockHeight(block_height) => *block_height <= cutoff.block_height,
+            Self::Timestamp(timestamp) => *timestamp <= cutoff.timestamp,
+        }
+    }
+
+    /// Returns the minimum block height and timestamp that encode the given locktime in a record.
+    pub(crate) fn to_bounds(locktime: Option<Self>) -> LocktimeCutoff {
+        match locktime {
+            None => LocktimeCutoff::default(),
+            Some(Self::BlockHeight(block_height)) => LocktimeCutoff::new(block_height, 0),
+            Some(Self::Timestamp(timestamp)) => LocktimeCutoff::new(0, timestamp),
+        }
+    }
+
+    /// Returns the locktime encoded by the given minimum block height and timestamp of a record.
+    pub(crate) fn from_bounds(bounds: LocktimeCutoff) -> Result<Option<Self>> {
+        match (bounds.block_height, bounds.timestamp) {
+            (0, 0) => Ok(None),
+            (block_height, 0) => Ok(Some(Self::BlockHeight(block_height))),
+            (0, timestamp) if timestamp > 0 => Ok(Some(Self::Timestamp(timestamp))),
+            (block_height, timestamp) => Err(anyhow!(
+                "Invalid record locktime with block height {} and timestamp {}",
+                block_height,
+                timestamp
+            )),
+        }
+    }
+}
+
+///
+/// The block height and timestamp that the locktimes of the input records of a transition are checked against.
+///
+/// A transition with a cutoff may only be included in a block at or above the cutoff height,
+/// with a timestamp at or after the cutoff timestamp.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LocktimeCutoff {
+    block_height: u32,
+    timestamp: i64,
+}
+
+impl LocktimeCutoff {
+    /// Initializes a new cutoff at the given block height and timestamp.
+    pub fn new(block_height: u32, timestamp: i64) -> Self {
+        Self {
+            block_height,
+            timestamp,
+        }
+    }
+
+    /// Returns the block height of the cutoff.
+    pub fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the timestamp of the cutoff.
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns `true` if a block with the given height and timestamp may include a transition with this cutoff.
+    pub fn is_reached(&self, block_height: u32, timestamp: i64) -> bool {
+        self.block_height <= block_height && self.timestamp <= timestamp
+    }
+}
+
+impl FromBytes for LocktimeCutoff {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let block_height = FromBytes::read_le(&mut reader)?;
+        let timestamp = FromBytes::read_le(&mut reader)?;
+        Ok(Self {
+            block_height,
+            timestamp,
+        })
+    }
+}
+
+impl ToBytes for LocktimeCutoff {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.block_height.write_le(&mut writer)?;
+        self.timestamp.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_unlocked() {
+        let cutoff = LocktimeCutoff::new(10, 1_000);
+
+        assert!(Locktime::BlockHeight(9).is_unlocked(&cutoff));
+        assert!(Locktime::BlockHeight(10).is_unlocked(&cutoff));
+        assert!(!Locktime::BlockHeight(11).is_unlocked(&cutoff));
+
+        assert!(Locktime::Timestamp(1_000).is_unlocked(&cutoff));
+        assert!(!Locktime::Timestamp(1_001).is_unlocked(&cutoff));
+
+        assert!(cutoff.is_reached(10, 1_000));
+        assert!(!cutoff.is_reached(9, 1_000));
+        assert!(!cutoff.is_reached(10, 999));
+    }
+
+    #[test]
+    fn test_bounds() {
+        for locktime in [
+            None,
+            Some(Locktime::BlockHeight(7)),
+            Some(Locktime::Timestamp(1_600_000_000)),
+        ] {
+            let bounds = Locktime::to_bounds(locktime);
+            assert_eq!(locktime, Locktime::from_bounds(bounds).unwrap());
+
+            let bounds_bytes = bounds.to_bytes_le().unwrap();
+            assert_eq!(12, bounds_bytes.len());
+            assert_eq!(bounds, LocktimeCutoff::read_le(&bounds_bytes[..]).unwrap());
+        }
+
+        // Ensure bounds with both a block height and a timestamp, or a negative timestamp, are rejected.
+        assert!(Locktime::from_bounds(LocktimeCutoff::new(7, 1)).is_err());
+        assert!(Locktime::from_bounds(LocktimeCutoff::new(0, -1)).is_err());
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let locktime = Locktime::BlockHeight(7);
+        let locktime_string = serde_json::to_string(&locktime).unwrap();
+        assert_eq!("{\"block_height\":7}", locktime_string);
+        assert_eq!(locktime, serde_json::from_str(&locktime_string).unwrap());
+
+        let locktime = Some(Locktime::Timestamp(1_600_000_000));
+        assert_eq!(
+            locktime,
+            serde_json::from_str(&serde_json::to_string(&locktime).unwrap()).unwrap()
+        );
+    }
+}
@@ -14,20 +14,35 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod balance_proof;
+pub use balance_proof::*;
+
 pub mod ciphertext;
 pub use ciphertext::*;
 
 pub mod decryption_key;
 pub use decryption_key::*;
 
+pub mod locktime;
+pub use locktime::*;
+
+pub mod non_ownership_proof;
+pub use non_ownership_proof::*;
+
 pub mod payload;
 pub use payload::*;
 
+pub mod payment;
+pub use payment::*;
+
 pub mod record;
 pub use record::*;
 
 pub mod record_proof;
 pub use record_proof::*;
 
+pub mod reencryption;
+pub use reencryption::*;
+
 #[cfg(test)]
 mod tests;
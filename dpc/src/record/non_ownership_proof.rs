@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Address,
+    Network,
+    NonOwnershipCircuit,
+    NonOwnershipPrivateVariables,
+    NonOwnershipPublicVariables,
+    ViewKey,
+};
+use snarkvm_algorithms::{snark::groth16::Groth16, traits::SNARK, SRS};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::{
+    io::{Read, Result as IoResult, Write},
+    ops::Deref,
+};
+
+/// The SNARK for non-ownership proofs.
+pub type NonOwnershipSNARK<N> = Groth16<<N as Network>::InnerCurve, NonOwnershipPublicVariables<N>>;
+
+///
+/// A proof that the account with the given address does not own the record ciphertext
+/// with the given commitment, without revealing the account view key.
+///
+/// The proof shows the record view key derived by the account view key does not match the ciphertext,
+/// so the account cannot decrypt the record.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct NonOwnershipProof<N: Network> {
+    address: Address<N>,
+    commitment: N::Commitment,
+    proof: <NonOwnershipSNARK<N> as SNARK>::Proof,
+}
+
+impl<N: Network> NonOwnershipProof<N> {
+    /// Returns the proving and verifying keys for non-ownership proofs.
+    pub fn setup<R: Rng + CryptoRng>(
+        rng: &mut R,
+    ) -> Result<(
+        <NonOwnershipSNARK<N> as SNARK>::ProvingKey,
+        <NonOwnershipSNARK<N> as SNARK>::VerifyingKey,
+    )> {
+        Ok(NonOwnershipSNARK::<N>::setup(
+            &NonOwnershipCircuit::<N>::blank(),
+            &mut SRS::CircuitSpecific(rng),
+        )?)
+    }
+
+    /// Returns a proof that the account of the given view key does not own the given record ciphertext.
+    pub fn prove<R: Rng + CryptoRng>(
+        proving_key: &<NonOwnershipSNARK<N> as SNARK>::ProvingKey,
+        account_view_key: &ViewKey<N>,
+        ciphertext: &N::RecordCiphertext,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the record ciphertext is not owned by the account view key.
+        if ciphertext.deref().is_owner(account_view_key) {
+            return Err(anyhow!(
+                "Record {} is owned by the account view key",
+                ciphertext.deref().commitment()
+            ));
+        }
+
+        let address = Address::from_view_key(account_view_key);
+        let public = NonOwnershipPublicVariables::new(address, ciphertext);
+        let private = NonOwnershipPrivateVariables::new(account_view_key.clone());
+        let proof = NonOwnershipSNARK::<N>::prove(proving_key, &NonOwnershipCircuit::new(public, private), rng)?;
+
+        Ok(Self {
+            address,
+            commitment: ciphertext.deref().commitment(),
+            proof,
+        })
+    }
+
+    /// Returns the address of the account that does not own the record.
+    pub fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the commitment of the record.
+    pub fn commitment(&self) -> N::Commitment {
+        self.commitment
+    }
+
+    /// Returns `true` if the non-ownership proof is valid for the given verifying key and record ciphertext.
+    pub fn verify(
+        &self,
+        verifying_key: &<NonOwnershipSNARK<N> as SNARK>::VerifyingKey,
+        ciphertext: &N::RecordCiphertext,
+    ) -> bool {
+        // Ensure the proof is for the given record ciphertext.
+        if ciphertext.deref().commitment() != self.commitment {
+            eprintln!("Non-ownership proof is for a different record ciphertext");
+            return false;
+        }
+
+        let public = NonOwnershipPublicVariables::new(self.address, ciphertext);
+        match NonOwnershipSNARK::<N>::verify(verifying_key, &public, &self.proof) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify the non-ownership proof: {}", error);
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for NonOwnershipProof<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let address = FromBytes::read_le(&mut reader)?;
+        let commitment = FromBytes::read_le(&mut reader)?;
+        let proof = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            address,
+            commitment,
+            proof,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for NonOwnershipProof<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.address.write_le(&mut writer)?;
+        self.commitment.write_le(&mut writer)?;
+        self.proof.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme, AleoAmount, Payload, Record};
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_non_ownership_proof() {
+        let rng = &mut thread_rng();
+
+        let owner = Account::<Testnet2>::new(rng);
+        let record = Record::new(
+            owner.address(),
+            AleoAmount::from_i64(1234),
+            Payload::from(&[7u8; 32]),
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap();
+        let ciphertext = record.ciphertext();
+
+        // Ensure a non-ownership proof by a different account verifies.
+        let stranger = Account::<Testnet2>::new(rng);
+        let (proving_key, verifying_key) = NonOwnershipProof::<Testnet2>::setup(rng).unwrap();
+        let non_ownership_proof = NonOwnershipProof::prove(&proving_key, stranger.view_key(), ciphertext, rng).unwrap();
+        assert_eq!(stranger.address(), non_ownership_proof.address());
+        assert_eq!(record.commitment(), non_ownership_proof.commitment());
+        assert!(non_ownership_proof.verify(&verifying_key, ciphertext));
+
+        // Ensure the non-ownership proof round-trips through bytes.
+        let non_ownership_proof_bytes = non_ownership_proof.to_bytes_le().unwrap();
+        let candidate_non_ownership_proof =
+            NonOwnershipProof::<Testnet2>::read_le(&non_ownership_proof_bytes[..]).unwrap();
+        assert_eq!(
+            non_ownership_proof_bytes,
+            candidate_non_ownership_proof.to_bytes_le().unwrap()
+        );
+        assert!(candidate_non_ownership_proof.verify(&verifying_key, ciphertext));
+
+        // Ensure the non-ownership proof does not verify for a different address.
+        let mut invalid_non_ownership_proof = non_ownership_proof.clone();
+        invalid_non_ownership_proof.address = owner.address();
+        assert!(!invalid_non_ownership_proof.verify(&verifying_key, ciphertext));
+
+        // Ensure the non-ownership proof does not verify for a different record ciphertext.
+        let other_record = Record::new(
+            stranger.address(),
+            AleoAmount::from_i64(1234),
+            Payload::from(&[7u8; 32]),
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap();
+        assert!(!non_ownership_proof.verify(&verifying_key, other_record.ciphertext()));
+
+        // Ensure a non-ownership proof cannot be created by the record owner.
+        assert!(NonOwnershipProof::prove(&proving_key, owner.view_key(), ciphertext, rng).is_err());
+    }
+
+    #[test]
+    fn test_non_ownership_circuit() {
+        let rng = &mut thread_rng();
+
+        let owner = Account::<Testnet2>::new(rng);
+        let stranger = Account::<Testnet2>::new(rng);
+        let record = Record::new(
+            owner.address(),
+            AleoAmount::from_i64(1234),
+            Payload::from(&[7u8; 32]),
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap();
+
+        let is_satisfied = |address: Address<Testnet2>, account_view_key: &ViewKey<Testnet2>| {
+            let public = NonOwnershipPublicVariables::new(address, record.ciphertext());
+            let private = NonOwnershipPrivateVariables::new(account_view_key.clone());
+
+            let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+            NonOwnershipCircuit::new(public, private)
+                .generate_constraints(&mut cs.ns(|| "Non-ownership circuit"))
+                .unwrap();
+            cs.is_satisfied()
+        };
+
+        // Ensure the circuit is satisfied if and only if the view key derives the address and does not own the record.
+        assert!(is_satisfied(stranger.address(), stranger.view_key()));
+        assert!(!is_satisfied(owner.address(), owner.view_key()));
+        assert!(!is_satisfied(owner.address(), stranger.view_key()));
+    }
+}
@@ -114,7 +114,7 @@ impl<N: Network> Default for Payload<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testnet2::Testnet2;
+    use crate::{testnet2::Testnet2, NetworkConstants};
     use snarkvm_utilities::UniformRand;
 
     use rand::thread_rng;
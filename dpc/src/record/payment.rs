@@ -0,0 +1,267 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, AleoAmount, EncryptionRandomness, Locktime, Network, Payload, Record, RecordError};
+use snarkvm_algorithms::traits::EncryptionScheme;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::anyhow;
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// An opening of an output record commitment, which allows the sender of a record
+/// to prove the owner and value of the record to a third party, without the
+/// cooperation of the recipient.
+///
+/// The opening consists of the record plaintext and the encryption randomness that was
+/// sampled by the sender, from which the ciphertext randomizer and record view key are re-derived.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct PaymentOpening<N: Network> {
+    owner: Address<N>,
+    value: AleoAmount,
+    payload: Payload<N>,
+    program_id: N::ProgramID,
+    locktime: Option<Locktime>,
+    encryption_randomness: EncryptionRandomness<N>,
+}
+
+impl<N: Network> PaymentOpening<N> {
+    /// Returns a new payment opening for the given record and its encryption randomness.
+    pub fn new(record: &Record<N>, encryption_randomness: EncryptionRandomness<N>) -> Result<Self, RecordError> {
+        let opening = Self {
+            owner: record.owner(),
+            value: record.value(),
+            payload: record.payload().clone(),
+            program_id: record.program_id(),
+            locktime: record.locktime(),
+            encryption_randomness,
+        };
+
+        // Ensure the encryption randomness corresponds to the record.
+        match opening.to_record()? == *record {
+            true => Ok(opening),
+            false => Err(anyhow!("Encryption randomness does not correspond to the given record").into()),
+        }
+    }
+
+    /// Returns the record owner.
+    pub fn owner(&self) -> Address<N> {
+        self.owner
+    }
+
+    /// Returns the record value.
+    pub fn value(&self) -> AleoAmount {
+        self.value
+    }
+
+    /// Returns the record payload.
+    pub fn payload(&self) -> &Payload<N> {
+        &self.payload
+    }
+
+    /// Returns the program ID of the record.
+    pub fn program_id(&self) -> N::ProgramID {
+        self.program_id
+    }
+
+    /// Returns the locktime of the record, if it has one.
+    pub fn locktime(&self) -> Option<Locktime> {
+        self.locktime
+    }
+
+    /// Returns the encryption randomness of the record.
+    pub fn encryption_randomness(&self) -> EncryptionRandomness<N> {
+        self.encryption_randomness
+    }
+
+    /// Returns the record, by re-deriving its ciphertext randomizer and record view key.
+    pub fn to_record(&self) -> Result<Record<N>, RecordError> {
+        let (randomizer, record_view_key) = N::account_encryption_scheme()
+            .generate_asymmetric_key_from_randomness(&*self.owner, self.encryption_randomness);
+        Record::from_with_locktime(
+            self.owner,
+            self.value,
+            self.payload.clone(),
+            self.program_id,
+            self.locktime,
+            randomizer.into(),
+            record_view_key.into(),
+        )
+    }
+
+    /// Returns `true` if the opening is for the given commitment, and pays the expected amount to the expected address.
+    pub fn verify(&self, commitment: N::Commitment, expected_address: Address<N>, expected_amount: AleoAmount) -> bool {
+        // Ensure the owner and value match the expected payment.
+        if self.owner != expected_address {
+            eprintln!("Payment opening owner does not match the expected address");
+            return false;
+        }
+        if self.value != expected_amount {
+            eprintln!("Payment opening value does not match the expected amount");
+            return false;
+        }
+
+        // Ensure the re-derived record commitment matches the given commitment.
+        match self.to_record() {
+            Ok(record) if record.commitment() == commitment => true,
+            Ok(_) => {
+                eprintln!("Payment opening does not match the given commitment");
+                false
+            }
+            Err(error) => {
+                eprintln!("Failed to re-derive the record from the payment opening: {}", error);
+                false
+            }
+        }
+    }
+}
+
+/// Returns `true` if the given opening proves a payment of the expected amount to the expected address,
+/// in the output record with the given commitment.
+pub fn verify_payment<N: Network>(
+    commitment: N::Commitment,
+    opening: &PaymentOpening<N>,
+    expected_address: Address<N>,
+    expected_amount: AleoAmount,
+) -> bool {
+    opening.verify(commitment, expected_address, expected_amount)
+}
+
+impl<N: Network> FromBytes for PaymentOpening<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let owner = FromBytes::read_le(&mut reader)?;
+        let value = FromBytes::read_le(&mut reader)?;
+        let payload = FromBytes::read_le(&mut reader)?;
+        let program_id = FromBytes::read_le(&mut reader)?;
+        let locktime = match N::RECORD_LOCKTIMES_ENABLED {
+            true => Locktime::from_bounds(FromBytes::read_le(&mut reader)?).map_err(RecordError::from)?,
+            false => None,
+        };
+        let encryption_randomness = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            owner,
+            value,
+            payload,
+            program_id,
+            locktime,
+            encryption_randomness,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for PaymentOpening<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.owner.write_le(&mut writer)?;
+        self.value.write_le(&mut writer)?;
+        self.payload.write_le(&mut writer)?;
+        self.program_id.write_le(&mut writer)?;
+        if N::RECORD_LOCKTIMES_ENABLED {
+            Locktime::to_bounds(self.locktime).write_le(&mut writer)?;
+        }
+        self.encryption_randomness.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, testnet2_mini::Testnet2Mini, Account, AccountScheme, Output};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_verify_payment() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+        let amount = AleoAmount::from_i64(1234);
+
+        // Craft an output record, and export its opening.
+        let output = Output::<Testnet2>::new(recipient.address(), amount, Payload::default(), None).unwrap();
+        let (record, encryption_randomness) = output.to_record(rng).unwrap();
+        let opening = PaymentOpening::new(&record, encryption_randomness).unwrap();
+
+        // Ensure the opening verifies against the record commitment.
+        assert!(verify_payment(
+            record.commitment(),
+            &opening,
+            recipient.address(),
+            amount
+        ));
+
+        // Ensure the opening does not verify for a different address, amount, or commitment.
+        let other = Account::<Testnet2>::new(rng);
+        assert!(!verify_payment(record.commitment(), &opening, other.address(), amount));
+        assert!(!verify_payment(
+            record.commitment(),
+            &opening,
+            recipient.address(),
+            AleoAmount::from_i64(1)
+        ));
+        let other_record = Record::new_noop(recipient.address(), rng).unwrap();
+        assert!(!verify_payment(
+            other_record.commitment(),
+            &opening,
+            recipient.address(),
+            amount
+        ));
+
+        // Ensure the opening round-trips through bytes.
+        let opening_bytes = opening.to_bytes_le().unwrap();
+        assert_eq!(opening, PaymentOpening::read_le(&opening_bytes[..]).unwrap());
+
+        // Ensure an opening cannot be created with the wrong encryption randomness.
+        let (_, wrong_randomness) = output.to_record(rng).unwrap();
+        assert!(PaymentOpening::new(&record, wrong_randomness).is_err());
+    }
+
+    #[test]
+    fn test_verify_payment_with_locktime() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2Mini>::new(rng);
+        let amount = AleoAmount::from_i64(1234);
+
+        // Craft a locked output record, and export its opening.
+        let output = Output::<Testnet2Mini>::new(recipient.address(), amount, Payload::default(), None)
+            .unwrap()
+            .with_locktime(Locktime::BlockHeight(10));
+        let (record, encryption_randomness) = output.to_record(rng).unwrap();
+        let opening = PaymentOpening::new(&record, encryption_randomness).unwrap();
+        assert_eq!(Some(Locktime::BlockHeight(10)), opening.locktime());
+
+        // Ensure the opening re-derives the locked record, and verifies against its commitment.
+        assert_eq!(record, opening.to_record().unwrap());
+        assert!(verify_payment(
+            record.commitment(),
+            &opening,
+            recipient.address(),
+            amount
+        ));
+
+        // Ensure the opening round-trips through bytes, including its locktime.
+        let opening_bytes = opening.to_bytes_le().unwrap();
+        assert_eq!(opening, PaymentOpening::read_le(&opening_bytes[..]).unwrap());
+    }
+}
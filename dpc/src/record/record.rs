@@ -21,12 +21,18 @@ use crate::{
     Ciphertext,
     ComputeKey,
     DecryptionKey,
+    Locktime,
+    LocktimeCutoff,
     Network,
     Payload,
+    PrivateKey,
     RecordError,
     ViewKey,
+    JSON_SCHEMA_VERSION,
+    JSON_SCHEMA_VERSION_FIELD,
+    RECORD_JSON_SCHEMA,
 };
-use snarkvm_algorithms::traits::{EncryptionScheme, PRF};
+use snarkvm_algorithms::traits::{EncryptionScheme, CRH, PRF};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{to_bytes_le, FromBits, FromBytes, FromBytesDeserializer, ToBits, ToBytes, ToBytesSerializer};
 
@@ -52,6 +58,7 @@ pub struct Record<N: Network> {
     value: AleoAmount,
     payload: Payload<N>,
     program_id: N::ProgramID,
+    locktime: Option<Locktime>,
     record_view_key: N::RecordViewKey,
     ciphertext: N::RecordCiphertext,
 }
@@ -68,6 +75,12 @@ impl<N: Network> Record<N> {
         )
     }
 
+    /// Returns a new dummy record to pad the input records of the given caller.
+    /// The record is owned by the caller, as the inner circuit requires of every input record.
+    pub fn new_dummy<R: Rng + CryptoRng>(caller: &PrivateKey<N>, rng: &mut R) -> Result<Self, RecordError> {
+        Self::new_noop(Address::from_private_key(caller), rng)
+    }
+
     /// Returns a new record.
     pub fn new<R: Rng + CryptoRng>(
         owner: Address<N>,
@@ -75,15 +88,28 @@ impl<N: Network> Record<N> {
         payload: Payload<N>,
         program_id: N::ProgramID,
         rng: &mut R,
+    ) -> Result<Self, RecordError> {
+        Self::new_with_locktime(owner, value, payload, program_id, None, rng)
+    }
+
+    /// Returns a new record, which may not be consumed before the given locktime.
+    pub fn new_with_locktime<R: Rng + CryptoRng>(
+        owner: Address<N>,
+        value: AleoAmount,
+        payload: Payload<N>,
+        program_id: N::ProgramID,
+        locktime: Option<Locktime>,
+        rng: &mut R,
     ) -> Result<Self, RecordError> {
         // Generate the ciphertext parameters.
         let (_randomness, randomizer, record_view_key) =
             N::account_encryption_scheme().generate_asymmetric_key(&*owner, rng);
-        Self::from(
+        Self::from_with_locktime(
             owner,
             value,
             payload,
             program_id,
+            locktime,
             randomizer.into(),
             record_view_key.into(),
         )
@@ -98,8 +124,26 @@ impl<N: Network> Record<N> {
         randomizer: N::RecordRandomizer,
         record_view_key: N::RecordViewKey,
     ) -> Result<Self, RecordError> {
+        Self::from_with_locktime(owner, value, payload, program_id, None, randomizer, record_view_key)
+    }
+
+    /// Returns a record from the given inputs, which may not be consumed before the given locktime.
+    pub fn from_with_locktime(
+        owner: Address<N>,
+        value: AleoAmount,
+        payload: Payload<N>,
+        program_id: N::ProgramID,
+        locktime: Option<Locktime>,
+        randomizer: N::RecordRandomizer,
+        record_view_key: N::RecordViewKey,
+    ) -> Result<Self, RecordError> {
+        // Ensure the network supports record locktimes, if one is given.
+        if locktime.is_some() && !N::RECORD_LOCKTIMES_ENABLED {
+            return Err(RecordError::LocktimesDisabled);
+        }
+
         // Encode the record contents into plaintext bytes.
-        let plaintext = Self::encode_plaintext(owner, value, &payload, program_id)?;
+        let plaintext = Self::encode_plaintext(owner, value, &payload, program_id, locktime)?;
 
         // Encrypt the record bytes.
         let ciphertext = Ciphertext::<N>::from(
@@ -115,6 +159,7 @@ impl<N: Network> Record<N> {
             value,
             payload,
             program_id,
+            locktime,
             record_view_key,
             ciphertext: ciphertext.into(),
         })
@@ -145,7 +190,7 @@ impl<N: Network> Record<N> {
 
         // Decrypt the record ciphertext.
         let plaintext = ciphertext.deref().to_plaintext(&record_view_key)?;
-        let (owner, value, payload, program_id) = Self::decode_plaintext(&plaintext)?;
+        let (owner, value, payload, program_id, locktime) = Self::decode_plaintext(&plaintext)?;
 
         // Ensure the record owner matches.
         let expected_owner = Address::from_view_key(account_view_key);
@@ -155,6 +200,7 @@ impl<N: Network> Record<N> {
                 value,
                 payload,
                 program_id,
+                locktime,
                 record_view_key,
                 ciphertext: ciphertext.clone(),
             }),
@@ -169,13 +215,14 @@ impl<N: Network> Record<N> {
     ) -> Result<Self, RecordError> {
         // Decrypt the record ciphertext.
         let plaintext = ciphertext.deref().to_plaintext(record_view_key)?;
-        let (owner, value, payload, program_id) = Self::decode_plaintext(&plaintext)?;
+        let (owner, value, payload, program_id, locktime) = Self::decode_plaintext(&plaintext)?;
 
         Ok(Self {
             owner,
             value,
             payload,
             program_id,
+            locktime,
             record_view_key: record_view_key.clone(),
             ciphertext: ciphertext.clone(),
         })
@@ -186,6 +233,37 @@ impl<N: Network> Record<N> {
         self.value.is_zero() && self.payload.is_empty() && self.program_id == *N::noop_program_id()
     }
 
+    ///
+    /// Returns `true` if the record satisfies the checks of the inner circuit, namely that
+    /// the ciphertext is the encryption of the owner, dummy flag, value, payload, and program ID
+    /// of the record, under its randomizer and record view key.
+    ///
+    /// The derivation of the randomizer and record view key from the owner requires the
+    /// encryption randomness, which is not part of the record, and is not checked here.
+    ///
+    pub fn is_well_formed(&self) -> bool {
+        // Ensure the ciphertext matches the record contents.
+        match Self::from_with_locktime(
+            self.owner,
+            self.value,
+            self.payload.clone(),
+            self.program_id,
+            self.locktime,
+            self.randomizer(),
+            self.record_view_key.clone(),
+        ) {
+            Ok(expected) if expected.ciphertext == self.ciphertext => true,
+            Ok(_) => {
+                eprintln!("Record ciphertext does not match the record contents");
+                false
+            }
+            Err(error) => {
+                eprintln!("Failed to encrypt the record contents: {}", error);
+                false
+            }
+        }
+    }
+
     /// Returns the record owner.
     pub fn owner(&self) -> Address<N> {
         self.owner
@@ -206,6 +284,20 @@ impl<N: Network> Record<N> {
         self.program_id
     }
 
+    /// Returns the locktime of this record, if it has one.
+    pub fn locktime(&self) -> Option<Locktime> {
+        self.locktime
+    }
+
+    /// Returns `true` if this record may be consumed by a transition with the given locktime cutoff.
+    /// Wallets should only select records that are unlocked at the cutoff of the transition they build.
+    pub fn is_unlocked(&self, cutoff: &LocktimeCutoff) -> bool {
+        match &self.locktime {
+            Some(locktime) => locktime.is_unlocked(cutoff),
+            None => true,
+        }
+    }
+
     /// Returns the randomizer used for the ciphertext.
     pub fn randomizer(&self) -> N::RecordRandomizer {
         self.ciphertext.deref().randomizer()
@@ -226,6 +318,16 @@ impl<N: Network> Record<N> {
         &self.ciphertext
     }
 
+    /// Returns the commitment to the payload of this record, under the randomizer of the record.
+    ///
+    /// A program proof may expose this commitment as a public input, which the inner circuit
+    /// checks against the payload of the record, to bind the program statement to the record data.
+    pub fn to_payload_commitment(&self) -> Result<N::Commitment, RecordError> {
+        Ok(N::commitment_scheme()
+            .hash(&to_bytes_le![self.randomizer(), self.payload]?)?
+            .into())
+    }
+
     /// Returns the serial number of the record, given the compute key corresponding to the record owner.
     pub fn to_serial_number(&self, compute_key: &ComputeKey<N>) -> Result<N::SerialNumber, RecordError> {
         // Check that the compute key corresponds with the owner of the record.
@@ -233,6 +335,16 @@ impl<N: Network> Record<N> {
             return Err(RecordError::IncorrectComputeKey);
         }
 
+        Self::compute_serial_number(compute_key, self.commitment())
+    }
+
+    /// Returns the serial number of the record with the given commitment, given the compute key of the record owner.
+    ///
+    /// This does not check that the compute key corresponds with the owner of the record.
+    pub fn compute_serial_number(
+        compute_key: &ComputeKey<N>,
+        commitment: N::Commitment,
+    ) -> Result<N::SerialNumber, RecordError> {
         // Compute the serial number.
         // First, convert the program scalar field element to bytes,
         // and interpret these bytes as a program base field element
@@ -240,24 +352,38 @@ impl<N: Network> Record<N> {
         // scalar field is always smaller than base field, so the bytes always fit without
         // wraparound.
         let seed = N::InnerScalarField::from_repr(FromBits::from_bits_le(&compute_key.sk_prf().to_bits_le())).unwrap();
-        let input = self.commitment();
-        let serial_number = N::SerialNumberPRF::evaluate(&seed, &input.into())?.into();
+        let serial_number = N::SerialNumberPRF::evaluate(&seed, &commitment.into())?.into();
 
         Ok(serial_number)
     }
 
+    /// Returns the size of the record plaintext in bytes.
+    pub fn plaintext_size_in_bytes() -> usize {
+        let locktime_size_in_bytes = match N::RECORD_LOCKTIMES_ENABLED {
+            true => 4 + 8,
+            false => 0,
+        };
+        N::ADDRESS_SIZE_IN_BYTES
+            + 1
+            + 8
+            + N::RECORD_PAYLOAD_SIZE_IN_BYTES
+            + N::ProgramID::data_size_in_bytes()
+            + locktime_size_in_bytes
+    }
+
     /// Encode the record contents into plaintext bytes.
     fn encode_plaintext(
         owner: Address<N>,
         value: AleoAmount,
         payload: &Payload<N>,
         program_id: N::ProgramID,
+        locktime: Option<Locktime>,
     ) -> Result<Vec<u8>, RecordError> {
         // Determine if the record is a dummy.
         let is_dummy = value.is_zero() && payload.is_empty() && program_id == *N::noop_program_id();
 
         // Total = 32 + 1 + 8 + 128 + 48 = 217 bytes
-        let plaintext = to_bytes_le![
+        let mut plaintext = to_bytes_le![
             owner,      // 256 bits = 32 bytes
             is_dummy,   // 1 bit = 1 byte
             value,      // 64 bits = 8 bytes
@@ -265,6 +391,11 @@ impl<N: Network> Record<N> {
             program_id  // 384 bits = 48 bytes
         ]?;
 
+        // If the network supports record locktimes, append the locktime bounds (12 bytes).
+        if N::RECORD_LOCKTIMES_ENABLED {
+            Locktime::to_bounds(locktime).write_le(&mut plaintext)?;
+        }
+
         // Ensure the record bytes are within the permitted size.
         match plaintext.len() <= u16::MAX as usize {
             true => Ok(plaintext),
@@ -273,11 +404,11 @@ impl<N: Network> Record<N> {
     }
 
     /// Decode the plaintext bytes into the record contents.
-    fn decode_plaintext(plaintext: &[u8]) -> Result<(Address<N>, AleoAmount, Payload<N>, N::ProgramID), RecordError> {
-        assert_eq!(
-            1 + N::ADDRESS_SIZE_IN_BYTES + 8 + N::RECORD_PAYLOAD_SIZE_IN_BYTES + N::ProgramID::data_size_in_bytes(),
-            plaintext.len()
-        );
+    #[allow(clippy::type_complexity)]
+    fn decode_plaintext(
+        plaintext: &[u8],
+    ) -> Result<(Address<N>, AleoAmount, Payload<N>, N::ProgramID, Option<Locktime>), RecordError> {
+        assert_eq!(Self::plaintext_size_in_bytes(), plaintext.len());
 
         // Decode the plaintext bytes.
         let mut cursor = Cursor::new(plaintext);
@@ -286,11 +417,15 @@ impl<N: Network> Record<N> {
         let value = AleoAmount::read_le(&mut cursor)?;
         let payload = Payload::read_le(&mut cursor)?;
         let program_id = N::ProgramID::read_le(&mut cursor)?;
+        let locktime = match N::RECORD_LOCKTIMES_ENABLED {
+            true => Locktime::from_bounds(LocktimeCutoff::read_le(&mut cursor)?)?,
+            false => None,
+        };
 
         // Ensure the dummy flag in the record is correct.
         let expected_dummy = value.is_zero() && payload.is_empty() && program_id == *N::noop_program_id();
         match is_dummy == expected_dummy as u8 {
-            true => Ok((owner, value, payload, program_id)),
+            true => Ok((owner, value, payload, program_id, locktime)),
             false => Err(anyhow!("Decoded incorrect is_dummy flag in record plaintext bytes").into()),
         }
     }
@@ -303,6 +438,9 @@ impl<N: Network> ToBytes for Record<N> {
         self.value.write_le(&mut writer)?;
         self.payload.write_le(&mut writer)?;
         self.program_id.write_le(&mut writer)?;
+        if N::RECORD_LOCKTIMES_ENABLED {
+            Locktime::to_bounds(self.locktime).write_le(&mut writer)?;
+        }
         self.randomizer().write_le(&mut writer)?;
         self.record_view_key.write_le(&mut writer)
     }
@@ -315,14 +453,19 @@ impl<N: Network> FromBytes for Record<N> {
         let value: AleoAmount = FromBytes::read_le(&mut reader)?;
         let payload: Payload<N> = FromBytes::read_le(&mut reader)?;
         let program_id: N::ProgramID = FromBytes::read_le(&mut reader)?;
+        let locktime = match N::RECORD_LOCKTIMES_ENABLED {
+            true => Locktime::from_bounds(FromBytes::read_le(&mut reader)?).map_err(RecordError::from)?,
+            false => None,
+        };
         let randomizer: N::RecordRandomizer = FromBytes::read_le(&mut reader)?;
         let record_view_key: N::RecordViewKey = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self::from(
+        Ok(Self::from_with_locktime(
             owner,
             value,
             payload,
             program_id,
+            locktime,
             randomizer,
             record_view_key,
         )?)
@@ -351,11 +494,13 @@ impl<N: Network> Serialize for Record<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut record = serializer.serialize_struct("Record", 7)?;
+                let mut record = serializer.serialize_struct("Record", 9)?;
+                record.serialize_field(JSON_SCHEMA_VERSION_FIELD, &JSON_SCHEMA_VERSION)?;
                 record.serialize_field("owner", &self.owner)?;
                 record.serialize_field("value", &self.value)?;
                 record.serialize_field("payload", &self.payload)?;
                 record.serialize_field("program_id", &self.program_id)?;
+                record.serialize_field("locktime", &self.locktime)?;
                 record.serialize_field("randomizer", &self.randomizer())?;
                 record.serialize_field("record_view_key", &self.record_view_key)?;
                 record.serialize_field("commitment", &self.commitment())?;
@@ -371,15 +516,17 @@ impl<'de, N: Network> Deserialize<'de> for Record<N> {
         match deserializer.is_human_readable() {
             true => {
                 let record = serde_json::Value::deserialize(deserializer)?;
+                RECORD_JSON_SCHEMA.read_version(&record).map_err(de::Error::custom)?;
                 let commitment: N::Commitment =
                     serde_json::from_value(record["commitment"].clone()).map_err(de::Error::custom)?;
 
-                // Recover the record.
-                let record = Self::from(
+                // Recover the record. Records serialized before locktimes were introduced have no locktime field.
+                let record = Self::from_with_locktime(
                     serde_json::from_value(record["owner"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(record["value"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(record["payload"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(record["program_id"].clone()).map_err(de::Error::custom)?,
+                    serde_json::from_value(record["locktime"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(record["randomizer"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(record["record_view_key"].clone()).map_err(de::Error::custom)?,
                 )
@@ -417,10 +564,121 @@ impl<N: Network> Default for Record<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet2::Testnet2, Address, PrivateKey};
+    use crate::{
+        assert_json_field_order,
+        testnet2::Testnet2,
+        testnet2_mini::Testnet2Mini,
+        Address,
+        NetworkConstants,
+        NetworkParameters,
+        PrivateKey,
+    };
 
     use rand::thread_rng;
 
+    #[test]
+    fn test_new_dummy() {
+        let rng = &mut thread_rng();
+
+        let caller = PrivateKey::<Testnet2>::new(rng);
+        let record = Record::new_dummy(&caller, rng).unwrap();
+        assert!(record.is_dummy());
+        assert!(record.is_well_formed());
+        assert_eq!(caller.to_address(), record.owner());
+
+        let address: Address<Testnet2> = PrivateKey::new(rng).into();
+        let program_id = *Testnet2::noop_program_id();
+        let mut record = Record::new(address, AleoAmount(1), Default::default(), program_id, rng).unwrap();
+        assert!(!record.is_dummy());
+        assert!(record.is_well_formed());
+
+        // Ensure a record whose contents do not match its ciphertext is rejected.
+        record.value = AleoAmount::ZERO;
+        assert!(record.is_dummy());
+        assert!(!record.is_well_formed());
+    }
+
+    #[test]
+    fn test_locktime_disabled() {
+        let rng = &mut thread_rng();
+        let address: Address<Testnet2> = PrivateKey::new(rng).into();
+        let program_id = *Testnet2::noop_program_id();
+
+        // Ensure a record without a locktime is always unlocked.
+        let record = Record::new(address, AleoAmount(1), Default::default(), program_id, rng).unwrap();
+        assert_eq!(None, record.locktime());
+        assert!(record.is_unlocked(&LocktimeCutoff::default()));
+
+        // Ensure a locktime is rejected on a network without record locktimes.
+        assert!(!Testnet2::RECORD_LOCKTIMES_ENABLED);
+        assert!(matches!(
+            Record::new_with_locktime(
+                address,
+                AleoAmount(1),
+                Default::default(),
+                program_id,
+                Some(Locktime::BlockHeight(10)),
+                rng
+            ),
+            Err(RecordError::LocktimesDisabled)
+        ));
+    }
+
+    #[test]
+    fn test_locktime_enabled() {
+        let rng = &mut thread_rng();
+        let address: Address<Testnet2Mini> = PrivateKey::new(rng).into();
+        let program_id = *Testnet2Mini::noop_program_id();
+        assert!(Testnet2Mini::RECORD_LOCKTIMES_ENABLED);
+
+        for locktime in [None, Some(Locktime::BlockHeight(10)), Some(Locktime::Timestamp(1_000))] {
+            let record =
+                Record::new_with_locktime(address, AleoAmount(1), Default::default(), program_id, locktime, rng)
+                    .unwrap();
+            assert_eq!(locktime, record.locktime());
+            assert!(record.is_well_formed());
+
+            // Ensure the locktime is encrypted into the record ciphertext.
+            let record_bytes = record.to_bytes_le().unwrap();
+            assert_eq!(Testnet2Mini::RECORD_SIZE_IN_BYTES, record_bytes.len());
+            assert_eq!(record, Record::read_le(&record_bytes[..]).unwrap());
+            assert_eq!(
+                record,
+                Record::from_record_view_key(record.record_view_key(), record.ciphertext()).unwrap()
+            );
+        }
+
+        // Ensure a locked record is only unlocked at or after its locktime.
+        let record = Record::new_with_locktime(
+            address,
+            AleoAmount(1),
+            Default::default(),
+            program_id,
+            Some(Locktime::BlockHeight(10)),
+            rng,
+        )
+        .unwrap();
+        assert!(!record.is_unlocked(&LocktimeCutoff::new(9, i64::MAX)));
+        assert!(record.is_unlocked(&LocktimeCutoff::new(10, 0)));
+    }
+
+    #[test]
+    fn test_payload_commitment() {
+        let rng = &mut thread_rng();
+        let address: Address<Testnet2> = PrivateKey::new(rng).into();
+        let program_id = *Testnet2::noop_program_id();
+
+        let payload = Payload::<Testnet2>::from(&[1u8; 32]);
+        let record = Record::new(address, AleoAmount(1), payload.clone(), program_id, rng).unwrap();
+        let payload_commitment = record.to_payload_commitment().unwrap();
+        assert_eq!(payload_commitment, record.to_payload_commitment().unwrap());
+        assert_ne!(record.commitment(), payload_commitment);
+
+        // Ensure the payload commitment is hiding, as the randomizer differs between records.
+        let other_record = Record::new(address, AleoAmount(2), payload, program_id, rng).unwrap();
+        assert_ne!(payload_commitment, other_record.to_payload_commitment().unwrap());
+    }
+
     #[test]
     fn test_serde_json_noop() {
         let rng = &mut thread_rng();
@@ -433,6 +691,7 @@ mod tests {
         let expected_string = expected_record.to_string();
         let candidate_string = serde_json::to_string(&expected_record).unwrap();
         assert_eq!(expected_string, candidate_string);
+        assert_json_field_order(&RECORD_JSON_SCHEMA, &candidate_string);
 
         // Deserialize
         assert_eq!(expected_record, Record::from_str(&candidate_string).unwrap());
@@ -460,6 +719,7 @@ mod tests {
         let expected_string = expected_record.to_string();
         let candidate_string = serde_json::to_string(&expected_record).unwrap();
         assert_eq!(expected_string, candidate_string);
+        assert_json_field_order(&RECORD_JSON_SCHEMA, &candidate_string);
 
         // Deserialize
         assert_eq!(expected_record, Record::from_str(&candidate_string).unwrap());
@@ -180,7 +180,7 @@ impl<N: Network> FromBytes for RecordProof<N> {
             transactions_inclusion_proof,
             local_proof,
         )
-        .expect("Failed to deserialize a record inclusion proof"))
+        .map_err(RecordError::from)?)
     }
 }
 
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, Network, Record, RecordError, ViewKey};
+use snarkvm_algorithms::traits::EncryptionScheme;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::anyhow;
+use rand::{CryptoRng, Rng};
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// A re-encryption of a record ciphertext to a new account view key, which lets a wallet move
+/// its records to a new key for backup or migration, without spending them. This is an off-chain
+/// convenience, and the record is still owned and spent by the original account.
+///
+/// The re-encryption carries the original ciphertext, along with its record view key encrypted to
+/// the address of the new view key. The record view key is checked against the commitment to it
+/// in the original ciphertext, so the re-encryption only decrypts to the record with the original commitment.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ReencryptedRecord<N: Network> {
+    ciphertext: N::RecordCiphertext,
+    randomizer: N::RecordRandomizer,
+    record_view_key_commitment: N::RecordViewKeyCommitment,
+    encrypted_record_view_key: Vec<u8>,
+}
+
+impl<N: Network> ReencryptedRecord<N> {
+    /// Returns a re-encryption of the given record ciphertext, which is owned by the given account view key,
+    /// to the address of the new view key.
+    pub fn new<R: Rng + CryptoRng>(
+        account_view_key: &ViewKey<N>,
+        ciphertext: &N::RecordCiphertext,
+        new_address: Address<N>,
+        rng: &mut R,
+    ) -> Result<Self, RecordError> {
+        // Ensure the ciphertext is owned by the account view key.
+        let record = Record::from_account_view_key(account_view_key, ciphertext)?;
+
+        // Encrypt the record view key to the new address.
+        let (_randomness, randomizer, symmetric_key) =
+            N::account_encryption_scheme().generate_asymmetric_key(&*new_address, rng);
+        let encrypted_record_view_key =
+            N::account_encryption_scheme().encrypt(&symmetric_key, &record.record_view_key().to_bytes_le()?)?;
+
+        Ok(Self {
+            ciphertext: ciphertext.clone(),
+            randomizer: randomizer.into(),
+            record_view_key_commitment: N::account_encryption_scheme()
+                .generate_symmetric_key_commitment(&symmetric_key)
+                .into(),
+            encrypted_record_view_key,
+        })
+    }
+
+    /// Returns the original record ciphertext.
+    pub fn ciphertext(&self) -> &N::RecordCiphertext {
+        &self.ciphertext
+    }
+
+    /// Returns the commitment of the original record ciphertext.
+    pub fn commitment(&self) -> N::Commitment {
+        self.ciphertext.commitment()
+    }
+
+    /// Returns `true` if the record view key is encrypted to the given account view key.
+    pub fn is_owner(&self, account_view_key: &ViewKey<N>) -> bool {
+        self.to_symmetric_key(account_view_key).is_some()
+    }
+
+    /// Returns the record, by decrypting its record view key with the given account view key.
+    pub fn to_record(&self, account_view_key: &ViewKey<N>) -> Result<Record<N>, RecordError> {
+        let symmetric_key = self
+            .to_symmetric_key(account_view_key)
+            .ok_or_else(|| anyhow!("The record view key is not encrypted to the given account view key"))?;
+
+        // Decrypt the record view key.
+        let record_view_key_bytes =
+            N::account_encryption_scheme().decrypt(&symmetric_key, &self.encrypted_record_view_key)?;
+        let record_view_key = N::RecordViewKey::read_le(&record_view_key_bytes[..])?;
+
+        // Ensure the record view key opens the original ciphertext.
+        let record_view_key_commitment =
+            N::account_encryption_scheme().generate_symmetric_key_commitment(&record_view_key);
+        if **self.ciphertext.record_view_key_commitment() != record_view_key_commitment {
+            return Err(anyhow!("The record view key does not correspond to the original ciphertext").into());
+        }
+
+        Record::from_record_view_key(&record_view_key, &self.ciphertext)
+    }
+
+    /// Returns the symmetric key for the encrypted record view key, if it is encrypted to the given account view key.
+    fn to_symmetric_key(
+        &self,
+        account_view_key: &ViewKey<N>,
+    ) -> Option<<N::AccountEncryptionScheme as EncryptionScheme>::SymmetricKey> {
+        let symmetric_key =
+            N::account_encryption_scheme().generate_symmetric_key(account_view_key, *self.randomizer)?;
+        let record_view_key_commitment =
+            N::account_encryption_scheme().generate_symmetric_key_commitment(&symmetric_key);
+        match *self.record_view_key_commitment == record_view_key_commitment {
+            true => Some(symmetric_key),
+            false => None,
+        }
+    }
+}
+
+impl<N: Network> FromBytes for ReencryptedRecord<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let ciphertext = FromBytes::read_le(&mut reader)?;
+        let randomizer = FromBytes::read_le(&mut reader)?;
+        let record_view_key_commitment = FromBytes::read_le(&mut reader)?;
+
+        let num_bytes: u16 = FromBytes::read_le(&mut reader)?;
+        let mut encrypted_record_view_key = vec![0u8; num_bytes as usize];
+        reader.read_exact(&mut encrypted_record_view_key)?;
+
+        Ok(Self {
+            ciphertext,
+            randomizer,
+            record_view_key_commitment,
+            encrypted_record_view_key,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for ReencryptedRecord<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.ciphertext.write_le(&mut writer)?;
+        self.randomizer.write_le(&mut writer)?;
+        self.record_view_key_commitment.write_le(&mut writer)?;
+        (self.encrypted_record_view_key.len() as u16).write_le(&mut writer)?;
+        self.encrypted_record_view_key.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme, AleoAmount, Payload};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_reencrypted_record() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let new_account = Account::<Testnet2>::new(rng);
+
+        let record = Record::new(
+            account.address(),
+            AleoAmount::from_i64(1234),
+            Payload::default(),
+            *Testnet2::noop_program_id(),
+            rng,
+        )
+        .unwrap();
+
+        // Re-encrypt the record to the new view key.
+        let reencrypted =
+            ReencryptedRecord::new(account.view_key(), record.ciphertext(), new_account.address(), rng).unwrap();
+        assert_eq!(record.commitment(), reencrypted.commitment());
+        assert!(reencrypted.is_owner(new_account.view_key()));
+        assert!(!reencrypted.is_owner(account.view_key()));
+
+        // Ensure the new view key recovers the original record, which is still owned by the original account.
+        let recovered = reencrypted.to_record(new_account.view_key()).unwrap();
+        assert_eq!(record, recovered);
+        assert_eq!(account.address(), recovered.owner());
+        assert!(reencrypted.to_record(account.view_key()).is_err());
+
+        // Ensure a record that is not owned by the view key cannot be re-encrypted.
+        assert!(
+            ReencryptedRecord::new(new_account.view_key(), record.ciphertext(), new_account.address(), rng).is_err()
+        );
+
+        // Ensure the re-encryption round trips through bytes.
+        let bytes = reencrypted.to_bytes_le().unwrap();
+        assert_eq!(reencrypted, ReencryptedRecord::read_le(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_reencrypted_record_with_mismatched_record_view_key() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let new_account = Account::<Testnet2>::new(rng);
+
+        let record = Record::new_noop(account.address(), rng).unwrap();
+        let other_record = Record::new_noop(account.address(), rng).unwrap();
+
+        // Ensure the record view key of one record does not open the ciphertext of another.
+        let mut reencrypted =
+            ReencryptedRecord::new(account.view_key(), record.ciphertext(), new_account.address(), rng).unwrap();
+        reencrypted.ciphertext = other_record.ciphertext().clone();
+        assert!(reencrypted.to_record(new_account.view_key()).is_err());
+    }
+}
@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{testnet2::*, Account, AccountScheme, AleoAmount, Network, Payload, Record, ViewKey};
+use crate::{
+    testnet2::*,
+    Account,
+    AccountScheme,
+    AleoAmount,
+    NetworkConstants,
+    NetworkParameters,
+    Payload,
+    Record,
+    ViewKey,
+};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use rand::{Rng, SeedableRng};
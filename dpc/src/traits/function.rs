@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FunctionType, Network, ProgramPublicVariables};
+use crate::{FunctionType, Network, ProgramFee, ProgramPublicVariables, UpgradePolicy};
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError, ToConstraintField};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -22,12 +22,25 @@ use anyhow::Result;
 use std::fmt::Debug;
 
 pub trait Function<N: Network>: Send + Sync {
-    /// Returns the function ID.
+    /// Returns the function ID, which is derived with `N::function_id_with_program_fee`
+    /// from the verifying key and the program fee of the function.
     fn function_id(&self) -> N::FunctionID;
 
     /// Returns the circuit type.
     fn function_type(&self) -> FunctionType;
 
+    /// Returns the fee paid to the program owner on each execution of the function, if any.
+    /// The function ID commits to the program fee, so a function with a program fee
+    /// may only be executed in a network with program fees enabled.
+    fn program_fee(&self) -> Option<ProgramFee<N>> {
+        None
+    }
+
+    /// Returns the policy that determines whether the function may be upgraded, which is immutable by default.
+    fn upgrade_policy(&self) -> UpgradePolicy<N> {
+        UpgradePolicy::Immutable
+    }
+
     /// Executes the function, returning an proof.
     fn execute(
         &self,
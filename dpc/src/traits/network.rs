@@ -15,13 +15,20 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    AleoAmount,
+    Bech32mError,
     Block,
     Ciphertext,
     InnerPublicVariables,
     OuterPublicVariables,
+    PoSWRegistry,
     PoSWScheme,
     Program,
+    ProgramFee,
     ProgramPublicVariables,
+    SelfCheckReport,
+    UpgradeSchedule,
+    VMError,
 };
 use snarkvm_algorithms::{crypto_hash::PoseidonDefaultParametersField, merkle_tree::MerklePath, prelude::*};
 use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve, TwistedEdwardsParameters};
@@ -33,6 +40,7 @@ use snarkvm_gadgets::{
     MaskedCRHGadget,
     SNARKVerifierGadget,
 };
+use snarkvm_parameters::ParameterError;
 use snarkvm_utilities::{
     fmt::{Debug, Display},
     hash::Hash,
@@ -59,7 +67,7 @@ pub trait Bech32Locator<F: Field>:
     + Default
     + Debug
     + Display
-    + FromStr
+    + FromStr<Err = Bech32mError>
     + ToBytes
     + FromBytes
     + Serialize
@@ -82,6 +90,7 @@ pub trait Bech32Object<T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq +
     + Clone
     + Debug
     + Display
+    + FromStr<Err = Bech32mError>
     + ToBytes
     + FromBytes
     + PartialEq
@@ -95,8 +104,9 @@ pub trait Bech32Object<T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq +
     fn size_in_bytes() -> usize;
 }
 
+/// The consensus constants of a network.
 #[rustfmt::skip]
-pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync {
+pub trait NetworkConstants: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync {
     const NETWORK_ID: u16;
     const NETWORK_NAME: &'static str;
 
@@ -105,6 +115,17 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     const NUM_TOTAL_RECORDS: usize = Self::NUM_INPUT_RECORDS + Self::NUM_OUTPUT_RECORDS;
     const NUM_TRANSITIONS: u8;
     const NUM_EVENTS: u16;
+    /// The number of record payloads that are committed to in the public inputs of a program proof.
+    /// The commitments bind the payloads of the first records of a transition, inputs before outputs.
+    const NUM_PAYLOAD_COMMITMENTS: usize = 0;
+    /// If `true`, records may declare a locktime, which the inner circuit checks against the locktime
+    /// cutoff of the transition. This adds 12 bytes to the record plaintext, and requires
+    /// the record ciphertext size of the network to account for them.
+    const RECORD_LOCKTIMES_ENABLED: bool = false;
+    /// If `true`, functions may declare a program fee, which the function ID commits to,
+    /// and which the inner circuit checks is paid by an output record of the transition.
+    /// This adds the program fee to the public inputs of the inner and outer circuits.
+    const PROGRAM_FEES_ENABLED: bool = false;
 
     const BLOCK_HASH_PREFIX: u16;
     const LEDGER_ROOT_PREFIX: u16;
@@ -131,6 +152,9 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     const RECORD_VIEW_KEY_PREFIX: u32;
     const SIGNATURE_PREFIX: u32;
 
+    /// If `true`, strings with a bech32 checksum are accepted in addition to those with a bech32m checksum.
+    const ACCEPTS_BECH32: bool;
+
     const ADDRESS_SIZE_IN_BYTES: usize;
     const HEADER_SIZE_IN_BYTES: usize;
     const HEADER_PROOF_SIZE_IN_BYTES: usize;
@@ -143,6 +167,26 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     const RECORD_VIEW_KEY_SIZE_IN_BYTES: usize;
     const SIGNATURE_SIZE_IN_BYTES: usize;
 
+    const MAX_BLOCK_SIZE_IN_BYTES: usize;
+    const MAX_TRANSACTIONS_PER_BLOCK: usize = 1 << Self::HEADER_TRANSACTIONS_TREE_DEPTH;
+    const MAX_TRANSITIONS_PER_TRANSACTION: usize = Self::NUM_TRANSITIONS as usize;
+    /// The maximum size of the public data attached to a transaction.
+    const MAX_TRANSACTION_DATA_SIZE_IN_BYTES: usize;
+    /// The fee for each byte of public data attached to a transaction, in the smallest denomination of Aleo credits.
+    const TRANSACTION_DATA_FEE_PER_BYTE: i64;
+
+    /// The maximum size of a serialized event, which is a custom event with the largest payload.
+    const MAX_EVENT_SIZE_IN_BYTES: usize = 1 + 2 + u16::MAX as usize;
+    /// The maximum size of a serialized transition, where each ID and serial number is bounded by 64 bytes.
+    const MAX_TRANSITION_SIZE_IN_BYTES: usize = 64 * (1 + Self::NUM_INPUT_RECORDS)
+        + Self::NUM_OUTPUT_RECORDS * Self::RECORD_CIPHERTEXT_SIZE_IN_BYTES
+        + 8 + 2 + Self::NUM_EVENTS as usize * Self::MAX_EVENT_SIZE_IN_BYTES
+        + Self::OUTER_PROOF_SIZE_IN_BYTES;
+    /// The maximum size of a serialized transaction, where each ID is bounded by 64 bytes.
+    const MAX_TRANSACTION_SIZE_IN_BYTES: usize = 64 * 2 + 2
+        + Self::MAX_TRANSITIONS_PER_TRANSACTION * Self::MAX_TRANSITION_SIZE_IN_BYTES
+        + 2 + Self::MAX_TRANSACTION_DATA_SIZE_IN_BYTES;
+
     const HEADER_TRANSACTIONS_TREE_DEPTH: usize;
     const HEADER_TREE_DEPTH: usize;
     const LEDGER_TREE_DEPTH: usize;
@@ -159,6 +203,108 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     /// The maximum number of blocks that a fork can be.
     const ALEO_MAXIMUM_FORK_DEPTH: u32;
 
+    ///
+    /// The number of blocks that must follow the block of a coinbase record before the record may be spent.
+    ///
+    /// Serial numbers do not reveal the records they spend, so maturity is enforced on the ledger root
+    /// that a transaction references. As a result, a nonzero maturity delays the spending of *every* record,
+    /// and not only coinbase records, by this many blocks.
+    ///
+    const COINBASE_MATURITY: u32;
+
+    /// The schedule of consensus rules, indexed by block height.
+    const UPGRADE_SCHEDULE: UpgradeSchedule;
+
+    ///
+    /// Returns the block reward for the given block height.
+    ///
+    /// The genesis block rewards the starting supply. After the genesis block, the reward is 100 credits,
+    /// and halves every 3 years of blocks, at most 2 times. A network may override this for its own emission schedule.
+    ///
+    fn block_reward(height: u32) -> AleoAmount {
+        match height == 0 {
+            true => {
+                // Output the starting supply as the genesis block reward.
+                AleoAmount::from_i64(Self::ALEO_STARTING_SUPPLY_IN_CREDITS * AleoAmount::ONE_CREDIT.0)
+            }
+            false => {
+                // The block reward halves at most 2 times - minimum is 25 ALEO.
+                // The reward will halve at blocks `4,730,400` and `9,460,800`.
+                // Blocks 1 to 4,730,400         - 100 CREDITS
+                // Blocks 4,730,401 to 9,460,800 - 50 CREDITS
+                // Blocks 9,460,801+             - 25 CREDITS
+                let initial_reward = 100i64 * AleoAmount::ONE_CREDIT.0;
+                let num_halves = Self::block_reward_halvings(height);
+                let reward = initial_reward / (2_u64.pow(num_halves)) as i64;
+
+                AleoAmount::from_i64(reward)
+            }
+        }
+    }
+
+    ///
+    /// Returns the number of blocks between two halvings of the block reward,
+    /// which is 4,730,400 blocks (approximately 3 years) for a 20 second block time.
+    ///
+    fn block_reward_halving_interval() -> u32 {
+        let expected_blocks_per_hour: u32 = 3600 / (Self::ALEO_BLOCK_TIME_IN_SECS as u32);
+        let num_years = 3;
+        num_years * 365 * 24 * expected_blocks_per_hour
+    }
+
+    ///
+    /// Returns the number of times the block reward has halved at the given block height.
+    ///
+    fn block_reward_halvings(height: u32) -> u32 {
+        u32::min(height.saturating_sub(1) / Self::block_reward_halving_interval(), 2)
+    }
+
+    ///
+    /// Returns the block height of the given halving of the block reward,
+    /// which is the last block height before the reward halves.
+    ///
+    fn block_reward_halving_height(halving: u32) -> u32 {
+        halving.saturating_mul(Self::block_reward_halving_interval())
+    }
+
+    ///
+    /// Returns the supply scheduled for issuance up to and including the given block height,
+    /// which is the sum of the block rewards from the genesis block to the given block.
+    ///
+    /// The sum is computed for each halving interval, so a network that overrides `block_reward`
+    /// with rewards that change within a halving interval must also override this method.
+    ///
+    fn scheduled_supply_at(height: u32) -> AleoAmount {
+        let mut supply = Self::block_reward(0);
+
+        let mut halving = 0;
+        loop {
+            // Compute the range of block heights with the reward of this halving interval.
+            let start = Self::block_reward_halving_height(halving).saturating_add(1);
+            let end = match Self::block_reward_halvings(start) == Self::block_reward_halvings(u32::MAX) {
+                true => height,
+                false => u32::min(height, Self::block_reward_halving_height(halving + 1)),
+            };
+            if end < start {
+                break;
+            }
+
+            let num_blocks = (end - start) as i64 + 1;
+            supply = supply.add(AleoAmount::from_i64(num_blocks * Self::block_reward(start).0));
+
+            if end == height {
+                break;
+            }
+            halving += 1;
+        }
+
+        supply
+    }
+}
+
+/// The cryptographic schemes of a network, and their setup parameters.
+#[rustfmt::skip]
+pub trait NetworkCrypto: NetworkConstants {
     /// Inner curve type declarations.
     type InnerCurve: PairingEngine<Fr = Self::InnerScalarField, Fq = Self::OuterScalarField>;
     type InnerScalarField: PrimeField + PoseidonDefaultParametersField;
@@ -176,27 +322,6 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type ProgramBaseField: PrimeField;
     type ProgramScalarField: PrimeField;
 
-    /// SNARK for inner circuit proof generation.
-    type InnerSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = InnerPublicVariables<Self>>;
-    type InnerSNARKGadget: SNARKVerifierGadget<Self::InnerSNARK>;
-    type InnerProof: Bech32Object<<Self::InnerSNARK as SNARK>::Proof>;
-
-    /// SNARK for proof-verification checks.
-    type OuterSNARK: SNARK<ScalarField = Self::OuterScalarField, BaseField = Self::OuterBaseField, VerifierInput = OuterPublicVariables<Self>>;
-    type OuterProof: Bech32Object<<Self::OuterSNARK as SNARK>::Proof>;
-
-    /// SNARK for Aleo program functions.
-    type ProgramSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = ProgramPublicVariables<Self>, ProvingKey = Self::ProgramProvingKey, VerifyingKey = Self::ProgramVerifyingKey, UniversalSetupConfig = usize>;
-    type ProgramSNARKGadget: SNARKVerifierGadget<Self::ProgramSNARK>;
-    type ProgramProvingKey: Clone + ToBytes + FromBytes + Send + Sync;
-    type ProgramVerifyingKey: ToConstraintField<Self::OuterScalarField> + Clone + ToBytes + FromBytes + ToMinimalBits + Send + Sync;
-    type ProgramProof: Bech32Object<<Self::ProgramSNARK as SNARK>::Proof>;
-
-    /// SNARK for PoSW.
-    type PoSWSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = Vec<Self::InnerScalarField>, UniversalSetupConfig = usize>;
-    type PoSWProof: Bech32Object<<Self::PoSWSNARK as SNARK>::Proof>;
-    type PoSW: PoSWScheme<Self>;
-
     /// Encryption scheme for accounts. Invoked only over `Self::InnerScalarField`.
     type AccountEncryptionScheme: EncryptionScheme<PrivateKey = Self::ProgramScalarField, PublicKey = Self::ProgramAffineCurve, CiphertextRandomizer = Self::ProgramBaseField, SymmetricKeyCommitment = Self::ProgramBaseField>;
     type AccountEncryptionGadget: EncryptionGadget<Self::AccountEncryptionScheme, Self::InnerScalarField>;
@@ -205,7 +330,8 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type AccountSeedPRF: PRF<Input = Vec<Self::ProgramScalarField>, Seed = Self::AccountSeed, Output = Self::ProgramScalarField>;
     type AccountSeed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug + UniformRand;
 
-    /// Signature scheme for transaction authorizations. Invoked only over `Self::InnerScalarField`.
+    /// Signature scheme for transaction authorizations, such as `AleoSignatureScheme` or the hash-based
+    /// `WinternitzAccountSignatureScheme`. Invoked only over `Self::InnerScalarField`.
     type AccountSignatureScheme: SignatureScheme<PrivateKey = (Self::ProgramScalarField, Self::ProgramScalarField), PublicKey = Self::ProgramAffineCurve>
         + SignatureSchemeOperations<AffineCurve = Self::ProgramAffineCurve, BaseField = Self::ProgramBaseField, ScalarField = Self::ProgramScalarField, Signature = <Self::AccountSignatureScheme as SignatureScheme>::Signature>;
     type AccountSignatureGadget: SignatureGadget<Self::AccountSignatureScheme, Self::InnerScalarField>;
@@ -261,7 +387,6 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type ProgramID: Bech32Locator<<Self::ProgramIDCRH as CRH>::Output>;
 
     /// Encryption scheme for records. Invoked only over `Self::InnerScalarField`.
-    type RecordCiphertext: Bech32Object<Ciphertext<Self>> + Hash;
     type RecordRandomizer: Bech32Locator<<Self::AccountEncryptionScheme as EncryptionScheme>::CiphertextRandomizer>;
     type RecordViewKey: Bech32Object<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKey> + Default;
     type RecordViewKeyCommitment: Bech32Locator<<Self::AccountEncryptionScheme as EncryptionScheme>::SymmetricKeyCommitment>;
@@ -307,6 +432,43 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     fn transaction_id_parameters() -> &'static Self::TransactionIDParameters;
     fn transition_id_parameters() -> &'static Self::TransitionIDParameters;
 
+    /// Returns the canonical hash of an empty leaf in each Merkle tree of the network.
+    /// These are the padding leaves of the trees, and must match the hashes computed in the circuits.
+    fn block_header_root_empty_hash() -> &'static <Self::BlockHeaderRootCRH as CRH>::Output;
+    fn ledger_root_empty_hash() -> &'static <Self::LedgerRootCRH as CRH>::Output;
+    fn program_id_empty_hash() -> &'static <Self::ProgramIDCRH as CRH>::Output;
+    fn transactions_root_empty_hash() -> &'static <Self::TransactionsRootCRH as CRH>::Output;
+    fn transaction_id_empty_hash() -> &'static <Self::TransactionIDCRH as CRH>::Output;
+    fn transition_id_empty_hash() -> &'static <Self::TransitionIDCRH as CRH>::Output;
+}
+
+/// The SNARKs of a network, and their proving and verifying parameters.
+#[rustfmt::skip]
+pub trait NetworkParameters: NetworkCrypto {
+    /// SNARK for inner circuit proof generation.
+    type InnerSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = InnerPublicVariables<Self>>;
+    type InnerSNARKGadget: SNARKVerifierGadget<Self::InnerSNARK>;
+    type InnerProof: Bech32Object<<Self::InnerSNARK as SNARK>::Proof>;
+
+    /// SNARK for proof-verification checks.
+    type OuterSNARK: SNARK<ScalarField = Self::OuterScalarField, BaseField = Self::OuterBaseField, VerifierInput = OuterPublicVariables<Self>>;
+    type OuterProof: Bech32Object<<Self::OuterSNARK as SNARK>::Proof>;
+
+    /// SNARK for Aleo program functions.
+    type ProgramSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = ProgramPublicVariables<Self>, ProvingKey = Self::ProgramProvingKey, VerifyingKey = Self::ProgramVerifyingKey, UniversalSetupConfig = usize>;
+    type ProgramSNARKGadget: SNARKVerifierGadget<Self::ProgramSNARK>;
+    type ProgramProvingKey: Clone + ToBytes + FromBytes + Send + Sync;
+    type ProgramVerifyingKey: ToConstraintField<Self::OuterScalarField> + Clone + ToBytes + FromBytes + ToMinimalBits + Send + Sync;
+    type ProgramProof: Bech32Object<<Self::ProgramSNARK as SNARK>::Proof>;
+
+    /// SNARK for PoSW.
+    type PoSWSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = Vec<Self::InnerScalarField>, UniversalSetupConfig = usize>;
+    type PoSWProof: Bech32Object<<Self::PoSWSNARK as SNARK>::Proof>;
+    type PoSW: PoSWScheme<Self>;
+
+    /// Ciphertext for records.
+    type RecordCiphertext: Bech32Object<Ciphertext<Self>> + Hash;
+
     fn inner_circuit_id() -> &'static Self::InnerCircuitID;
     fn inner_proving_key() -> &'static <Self::InnerSNARK as SNARK>::ProvingKey;
     fn inner_verifying_key() -> &'static <Self::InnerSNARK as SNARK>::VerifyingKey;
@@ -321,17 +483,49 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     fn outer_proving_key() -> &'static <Self::OuterSNARK as SNARK>::ProvingKey;
     fn outer_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::VerifyingKey;
 
+    /// Returns the inner circuit proving key, loading it on first use, or an error if it is invalid.
+    fn try_inner_proving_key() -> Result<&'static <Self::InnerSNARK as SNARK>::ProvingKey, ParameterError> {
+        Ok(Self::inner_proving_key())
+    }
+
+    /// Returns the outer circuit proving key, loading it on first use, or an error if it is invalid.
+    fn try_outer_proving_key() -> Result<&'static <Self::OuterSNARK as SNARK>::ProvingKey, ParameterError> {
+        Ok(Self::outer_proving_key())
+    }
+
     fn posw_proving_key() -> &'static <Self::PoSWSNARK as SNARK>::ProvingKey;
     fn posw_verifying_key() -> &'static <Self::PoSWSNARK as SNARK>::VerifyingKey;
     fn posw() -> &'static Self::PoSW;
+    /// Returns the PoSW algorithms of the network, for each PoSW version in its upgrade schedule.
+    fn posw_registry() -> &'static PoSWRegistry<'static, Self>;
 
     fn genesis_block() -> &'static Block<Self>;
 
-    /// Returns the function ID given a program function verifying key.
+    /// Returns the function ID given a program function verifying key,
+    /// from version 1 of its minimal bit encoding.
     fn function_id(
         verifying_key: &<Self::ProgramSNARK as SNARK>::VerifyingKey,
     ) -> Result<Self::FunctionID> {
-        Ok(Self::function_id_crh().hash_bits(&verifying_key.to_minimal_bits())?.into())
+        Self::function_id_with_program_fee(verifying_key, None)
+    }
+
+    /// Returns the function ID given a program function verifying key and the program fee of the function.
+    /// If program fees are enabled, the function ID commits to the program fee, which is empty if there is none.
+    fn function_id_with_program_fee(
+        verifying_key: &<Self::ProgramSNARK as SNARK>::VerifyingKey,
+        program_fee: Option<&ProgramFee<Self>>,
+    ) -> Result<Self::FunctionID> {
+        let mut bits = verifying_key.to_minimal_bits_v1();
+        match (Self::PROGRAM_FEES_ENABLED, program_fee) {
+            (true, program_fee) => bits.extend(
+                ProgramFee::to_input_bytes(program_fee)?
+                    .into_iter()
+                    .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)),
+            ),
+            (false, Some(_)) => return Err(VMError::ProgramFeesDisabled.into()),
+            (false, None) => (),
+        }
+        Ok(Self::function_id_crh().hash_bits(&bits)?.into())
     }
 
     /// Returns the program SRS for Aleo applications.
@@ -339,3 +533,13 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
         rng: &mut R,
     ) -> Rc<RefCell<SRS<R, <Self::ProgramSNARK as SNARK>::UniversalSetupParameters>>>;
 }
+
+/// A complete network, composed of its constants, cryptographic schemes, and parameters.
+pub trait Network: NetworkConstants + NetworkCrypto + NetworkParameters {
+    /// Runs a minimal end-to-end check of the loaded parameters of the network, for use at startup.
+    fn self_check() -> SelfCheckReport {
+        SelfCheckReport::run::<Self>()
+    }
+}
+
+impl<N: NetworkConstants + NetworkCrypto + NetworkParameters> Network for N {}
@@ -14,18 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use core::sync::atomic::AtomicBool;
+use core::sync::atomic::{AtomicBool, Ordering};
 
-use crate::{BlockHeader, BlockTemplate, Network, PoSWCircuit, PoSWError, PoSWProof};
-use snarkvm_algorithms::{traits::SNARK, SRS};
+use crate::{BlockHeader, BlockTemplate, Network, PoSWCircuit, PoSWError, PoSWProof, PoSWVersion};
+use snarkvm_algorithms::{traits::SNARK, SNARKError, SRS};
 
 use anyhow::Result;
-use rand::{CryptoRng, Rng};
+use chrono::Utc;
+use metrics::histogram;
+use rand::{CryptoRng, Rng, RngCore};
+use std::{
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Instant,
+};
+
+/// The maximum duration of mining a block, in seconds since the timestamp of its block template.
+pub const MAXIMUM_MINING_DURATION: i64 = 600; // 600 seconds = 10 minutes.
 
 pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
     /// Sets up an instance of PoSW using an SRS.
     fn setup<R: Rng + CryptoRng>(
-        srs: &mut SRS<R, <<N as Network>::PoSWSNARK as SNARK>::UniversalSetupParameters>,
+        srs: &mut SRS<R, <N::PoSWSNARK as SNARK>::UniversalSetupParameters>,
     ) -> Result<Self, PoSWError>;
 
     /// Loads an instance of PoSW using stored parameters.
@@ -58,10 +71,24 @@ pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
         rng: &mut R,
     ) -> Result<PoSWProof<N>, PoSWError>;
 
+    ///
+    /// Given the PoSW circuit, compute a PoSW proof of the given version.
+    /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
+    ///
+    fn prove_once_unchecked_with_version<R: Rng + CryptoRng>(
+        &self,
+        version: PoSWVersion,
+        circuit: &mut PoSWCircuit<N>,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<PoSWProof<N>, PoSWError>;
+
     /// Verifies the Proof of Succinct Work against the nonce, root, and difficulty target.
+    /// The proof is verified with the proof system of its PoSW version.
     fn verify_from_block_header(&self, block_header: &BlockHeader<N>) -> bool;
 
     /// Verifies the Proof of Succinct Work against the nonce, root, and difficulty target.
+    /// The proof must be of the PoSW version scheduled for the block height.
     fn verify(
         &self,
         block_height: u32,
@@ -70,3 +97,192 @@ pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
         proof: &PoSWProof<N>,
     ) -> bool;
 }
+
+/// A cryptographically secure random number generator, which may be used as a trait object.
+pub trait PoSWRng: RngCore + CryptoRng {}
+
+impl<R: RngCore + CryptoRng> PoSWRng for R {}
+
+///
+/// An object-safe Proof of Succinct Work algorithm, which produces and verifies proofs of one PoSW version.
+///
+/// Unlike `PoSWScheme`, this interface does not expose the PoSW circuit or the keys of its SNARK,
+/// so algorithms with different circuits or proof systems may be registered in a `PoSWRegistry`,
+/// and benchmarked through the same interface. The proof difficulty and the proof encoding
+/// are provided methods, so they are the same for every algorithm unless overridden.
+///
+pub trait ProofOfSuccinctWork<N: Network>: Send + Sync {
+    /// Returns the version of the PoSW proofs that are produced and verified by this algorithm.
+    fn version(&self) -> PoSWVersion;
+
+    ///
+    /// Given the block template, compute a PoSW proof for a random nonce, and return the nonce and the proof.
+    /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
+    ///
+    fn prove_once_unchecked(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        rng: &mut dyn PoSWRng,
+    ) -> Result<(N::PoSWNonce, PoSWProof<N>), PoSWError>;
+
+    /// Returns `true` if the PoSW proof is valid for the given inputs, without checking the difficulty target.
+    fn verify_proof(&self, inputs: &[N::InnerScalarField], proof: &PoSWProof<N>) -> bool;
+
+    /// Returns the proof difficulty, which is compared against the difficulty target of a block.
+    fn to_proof_difficulty(&self, proof: &PoSWProof<N>) -> Result<u64> {
+        proof.to_proof_difficulty()
+    }
+
+    /// Returns the PoSW proof bytes, prefixed with the byte of its version.
+    fn proof_to_bytes_le(&self, proof: &PoSWProof<N>) -> Result<Vec<u8>> {
+        proof.to_versioned_bytes_le()
+    }
+
+    /// Returns the PoSW proof from bytes that are prefixed with the byte of its version,
+    /// or an error if the proof is not of the version of this algorithm.
+    fn proof_from_bytes_le(&self, bytes: &[u8]) -> Result<PoSWProof<N>> {
+        let proof = PoSWProof::from_versioned_bytes_le(bytes)?;
+        match proof.version() == self.version() {
+            true => Ok(proof),
+            false => Err(PoSWError::Message(format!(
+                "Expected a {} PoSW proof, found {}",
+                self.version(),
+                proof.version()
+            ))
+            .into()),
+        }
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target.
+    /// This runs until a proof is found, the terminator is set, or `MAXIMUM_MINING_DURATION` has passed
+    /// since the timestamp of the block template.
+    ///
+    fn mine(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        rng: &mut dyn PoSWRng,
+    ) -> Result<BlockHeader<N>, PoSWError> {
+        let mut iteration = 1;
+        loop {
+            // Every 100 iterations, check that the miner is still within the allowed mining duration.
+            if iteration % 100 == 0
+                && Utc::now().timestamp() >= block_template.block_timestamp() + MAXIMUM_MINING_DURATION
+            {
+                return Err(PoSWError::Message(
+                    "Failed mine block in the allowed mining duration".to_string(),
+                ));
+            }
+
+            // Run one iteration of PoSW.
+            let prove_start = Instant::now();
+            let (nonce, proof) = self.prove_once_unchecked(block_template, terminator, rng)?;
+            let duration = prove_start.elapsed();
+            histogram!("snarkvm_posw_duration", duration);
+            trace!("Prove time: {:?}, height: {}, timestamp: {}, difficulty: {}, weight: {}", duration, block_template.block_height(), block_template.block_timestamp(), block_template.difficulty_target(), block_template.cumulative_weight());
+
+            // Check if the block header is valid.
+            let block_header = BlockHeader::from_template_unchecked(block_template, nonce, proof);
+            if self.verify_from_block_header(&block_header) {
+                return Ok(block_header);
+            }
+
+            // Increment the iteration by one.
+            iteration += 1;
+        }
+    }
+
+    ///
+    /// Given the block template, compute a PoSW proof and nonce such that they are under the difficulty target,
+    /// or return `PoSWError::Timeout` once the deadline passes.
+    ///
+    /// The deadline sets the terminator of the prover, which is checked after each MSM and round
+    /// of the prover, so the prover may run briefly past the deadline before it stops.
+    ///
+    fn mine_until(
+        &self,
+        deadline: Instant,
+        block_template: &BlockTemplate<N>,
+        rng: &mut dyn PoSWRng,
+    ) -> Result<BlockHeader<N>, PoSWError> {
+        if Instant::now() >= deadline {
+            return Err(PoSWError::Timeout);
+        }
+
+        // Set the terminator at the deadline, unless mining finishes first and drops the sender.
+        let terminator = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel::<()>();
+        let watchdog = {
+            let terminator = terminator.clone();
+            thread::spawn(move || {
+                if let Err(RecvTimeoutError::Timeout) =
+                    receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                {
+                    terminator.store(true, Ordering::SeqCst);
+                }
+            })
+        };
+
+        let result = self.mine(block_template, &terminator, rng);
+
+        drop(sender);
+        watchdog
+            .join()
+            .map_err(|_| PoSWError::Message("The PoSW deadline watchdog panicked".to_string()))?;
+
+        match result {
+            Err(PoSWError::SNARKError(SNARKError::Terminated)) if terminator.load(Ordering::SeqCst) => {
+                Err(PoSWError::Timeout)
+            }
+            result => result,
+        }
+    }
+
+    /// Verifies the Proof of Succinct Work against the inputs and difficulty target.
+    /// The proof must be of the version of this algorithm.
+    fn verify(&self, difficulty_target: u64, inputs: &[N::InnerScalarField], proof: &PoSWProof<N>) -> bool {
+        // Ensure the proof is of the version of this algorithm.
+        if proof.version() != self.version() {
+            #[cfg(debug_assertions)]
+            eprintln!("PoSW proof should be {}, found {}", self.version(), proof.version());
+            return false;
+        }
+
+        // Ensure the difficulty target is met.
+        match self.to_proof_difficulty(proof) {
+            Ok(proof_difficulty) if proof_difficulty <= difficulty_target => {}
+            Ok(proof_difficulty) => {
+                #[cfg(debug_assertions)]
+                eprintln!(
+                    "PoSW difficulty target is not met. Expected {}, found {}",
+                    difficulty_target, proof_difficulty
+                );
+                return false;
+            }
+            Err(error) => {
+                eprintln!("Failed to compute the PoSW proof difficulty: {}", error);
+                return false;
+            }
+        }
+
+        // Ensure the proof is valid.
+        self.verify_proof(inputs, proof)
+    }
+
+    /// Verifies the Proof of Succinct Work against the nonce, root, and difficulty target of the block header.
+    fn verify_from_block_header(&self, block_header: &BlockHeader<N>) -> bool {
+        match block_header.to_header_root() {
+            Ok(header_root) => self.verify(
+                block_header.difficulty_target(),
+                &[*header_root, *block_header.nonce()],
+                block_header.proof(),
+            ),
+            Err(error) => {
+                eprintln!("Failed to compute the block header root: {}", error);
+                false
+            }
+        }
+    }
+}
@@ -112,7 +112,7 @@ impl<N: Network> FromBytes for LocalProof<N> {
             transition_inclusion_proof,
             commitment,
         )
-        .expect("Failed to deserialize a local inclusion proof"))
+        .map_err(VMError::from)?)
     }
 }
 
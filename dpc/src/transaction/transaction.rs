@@ -24,9 +24,14 @@ use crate::{
     LocalProof,
     Network,
     Request,
+    SizeBreakdown,
     Transition,
     Transitions,
+    VMError,
     VirtualMachine,
+    JSON_SCHEMA_VERSION,
+    JSON_SCHEMA_VERSION_FIELD,
+    TRANSACTION_JSON_SCHEMA,
 };
 use snarkvm_utilities::{
     has_duplicates,
@@ -42,6 +47,7 @@ use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
+    convert::TryFrom,
     fmt,
     hash::{Hash, Hasher},
     str::FromStr,
@@ -63,6 +69,8 @@ pub struct Transaction<N: Network> {
     ledger_root: N::LedgerRoot,
     /// The state transition.
     transitions: Vec<Transition<N>>,
+    /// The public data attached to the transaction.
+    data: Vec<u8>,
 }
 
 impl<N: Network> Transaction<N> {
@@ -93,13 +101,27 @@ impl<N: Network> Transaction<N> {
         ledger_root: N::LedgerRoot,
         transitions: Vec<Transition<N>>,
     ) -> Result<Self> {
-        let transaction_id = Self::compute_transaction_id(&transitions)?;
+        Self::from_with_data(inner_circuit_id, ledger_root, transitions, Vec::new())
+    }
+
+    /// Initializes an instance of `Transaction` from the given inputs, with the given public data attached.
+    /// The data is committed to in the transaction ID, and requires a fee of `N::TRANSACTION_DATA_FEE_PER_BYTE`
+    /// for each byte, which is paid from the value balance of the transaction.
+    #[inline]
+    pub fn from_with_data(
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        transitions: Vec<Transition<N>>,
+        data: Vec<u8>,
+    ) -> Result<Self> {
+        let transaction_id = Self::compute_transaction_id(&transitions, &data)?;
 
         let transaction = Self {
             transaction_id,
             inner_circuit_id,
             ledger_root,
             transitions,
+            data,
         };
 
         match transaction.is_valid() {
@@ -113,56 +135,94 @@ impl<N: Network> Transaction<N> {
     /// correct ciphertext IDs, and a valid proof.
     #[inline]
     pub fn is_valid(&self) -> bool {
-        // Ensure the number of transitions is between 1 and N::NUM_TRANSITIONS.
+        // Returns `false` if the transaction is malformed.
+        let local_transitions_roots = match self.to_local_transitions_roots() {
+            Some(local_transitions_roots) => local_transitions_roots,
+            None => return false,
+        };
+
+        // Returns `false` if any transition is invalid.
+        for (transition, local_transitions_root) in self.transitions.iter().zip_eq(local_transitions_roots) {
+            if !transition.verify(self.inner_circuit_id, self.ledger_root, local_transitions_root) {
+                eprintln!("Transaction contains an invalid transition");
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the local transitions root that each transition is proven against,
+    /// if the transaction is well-formed, meaning it contains the correct network ID,
+    /// unique serial numbers, unique commitments, correct ciphertext IDs, and a
+    /// correct transaction ID. The transition proofs are not verified.
+    pub(crate) fn to_local_transitions_roots(&self) -> Option<Vec<N::TransactionID>> {
+        // Ensure the number of transitions is between 1 and N::MAX_TRANSITIONS_PER_TRANSACTION.
         let num_transitions = self.transitions.len();
-        if num_transitions < 1 || num_transitions > N::NUM_TRANSITIONS as usize {
+        if num_transitions < 1 || num_transitions > N::MAX_TRANSITIONS_PER_TRANSACTION {
             eprintln!("Transaction contains invalid number of transitions");
-            return false;
+            return None;
+        }
+
+        // Ensure the public data does not exceed `N::MAX_TRANSACTION_DATA_SIZE_IN_BYTES` or the
+        // size that its length prefix encodes, and there is a leaf for it in the local transitions tree.
+        if self.data.len() > N::MAX_TRANSACTION_DATA_SIZE_IN_BYTES
+            || self.data.len() > u16::MAX as usize
+            || (!self.data.is_empty() && num_transitions >= N::MAX_TRANSITIONS_PER_TRANSACTION)
+        {
+            eprintln!("Transaction contains invalid public data");
+            return None;
+        }
+
+        // Ensure the value balance pays the fee for the public data.
+        if !self.data.is_empty() && self.value_balance().as_i64() < self.data_fee().as_i64() {
+            eprintln!("Transaction does not pay the fee for its public data");
+            return None;
         }
 
         // Ensure the number of events is less than `N::NUM_EVENTS`.
         if self.events().count() > num_transitions * N::NUM_EVENTS as usize {
             eprintln!("Transaction contains an invalid number of events");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of serial numbers in the transaction is incorrect.
+        // Returns `None` if the number of serial numbers in the transaction is incorrect.
         if self.serial_numbers().count() != num_transitions * N::NUM_INPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate serial numbers in the transaction.
+        // Returns `None` if there are duplicate serial numbers in the transaction.
         if has_duplicates(self.serial_numbers()) {
             eprintln!("Transaction contains duplicate serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of commitments in the transaction is incorrect.
+        // Returns `None` if the number of commitments in the transaction is incorrect.
         if self.commitments().count() != num_transitions * N::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of commitments");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate commitments numbers in the transaction.
+        // Returns `None` if there are duplicate commitments numbers in the transaction.
         if has_duplicates(self.commitments()) {
             eprintln!("Transaction contains duplicate commitments");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of record ciphertexts in the transaction is incorrect.
+        // Returns `None` if the number of record ciphertexts in the transaction is incorrect.
         if self.ciphertexts().count() != num_transitions * N::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of record ciphertexts");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate ciphertexts in the transition.
+        // Returns `None` if there are duplicate ciphertexts in the transition.
         if has_duplicates(self.ciphertexts()) {
             eprintln!("Transaction contains duplicate ciphertexts");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the transaction is not a coinbase, and has a transition with a negative value balance.
+        // Returns `None` if the transaction is not a coinbase, and has a transition with a negative value balance.
         if self.transitions.len() > 1
             && self
                 .transitions
@@ -170,7 +230,23 @@ impl<N: Network> Transaction<N> {
                 .any(|transition| transition.value_balance().is_negative())
         {
             eprintln!("Transaction contains a transition with a negative value balance");
-            return false;
+            return None;
+        }
+
+        // Returns `None` if a transition emits a program fee event that does not reveal its output record,
+        // or if the network does not support program fees.
+        for transition in &self.transitions {
+            match transition.to_program_fee() {
+                Ok(Some(_)) if !N::PROGRAM_FEES_ENABLED => {
+                    eprintln!("Transaction contains a program fee, but program fees are not enabled");
+                    return None;
+                }
+                Ok(_) => (),
+                Err(error) => {
+                    eprintln!("Transaction contains an invalid program fee: {}", error);
+                    return None;
+                }
+            }
         }
 
         // Initialize a local transitions tree.
@@ -178,38 +254,43 @@ impl<N: Network> Transaction<N> {
             Ok(transitions) => transitions,
             Err(error) => {
                 eprintln!("Transaction failed to initialize a local transitions tree: {}", error);
-                return false;
+                return None;
             }
         };
 
-        // Returns `false` if any transition is invalid.
+        // Compute the local transitions root of each transition.
+        let mut local_transitions_roots = Vec::with_capacity(num_transitions);
         for transition in &self.transitions {
-            // Returns `false` if the transition is invalid.
-            if !transition.verify(self.inner_circuit_id, self.ledger_root, transitions.root()) {
-                eprintln!("Transaction contains an invalid transition");
-                return false;
-            }
+            local_transitions_roots.push(transitions.root());
 
             // Update the local transitions tree.
             if let Err(error) = transitions.add(transition) {
                 eprintln!("Transaction failed to update local transitions tree: {}", error);
-                return false;
+                return None;
             }
         }
 
-        // Returns `false` if the size of the local transitions tree does not match the number of transitions.
+        // Returns `None` if the size of the local transitions tree does not match the number of transitions.
         if transitions.len() != num_transitions {
             eprintln!("Transaction contains invalid local transitions tree state");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the final transitions root does not match the transaction ID.
+        // Update the local transitions tree with the public data.
+        if !self.data.is_empty() {
+            if let Err(error) = transitions.add_data(&self.data) {
+                eprintln!("Transaction failed to add its public data: {}", error);
+                return None;
+            }
+        }
+
+        // Returns `None` if the final transitions root does not match the transaction ID.
         if transitions.root() != self.transaction_id {
             eprintln!("Transaction contains an invalid transaction ID");
-            return false;
+            return None;
         }
 
-        true
+        Some(local_transitions_roots)
     }
 
     /// Returns `true` if the given transition ID exists.
@@ -295,6 +376,34 @@ impl<N: Network> Transaction<N> {
         &self.transitions
     }
 
+    /// Returns the public data attached to the transaction.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the fee for the public data attached to the transaction.
+    #[inline]
+    pub fn data_fee(&self) -> AleoAmount {
+        AleoAmount::from_i64(self.data.len() as i64 * N::TRANSACTION_DATA_FEE_PER_BYTE)
+    }
+
+    /// Returns `true` if the transaction carries no public data, or if the network upgrade
+    /// at the given block height accepts transactions with public data.
+    #[inline]
+    pub fn is_data_accepted_at(&self, block_height: u32) -> bool {
+        self.data.is_empty() || N::UPGRADE_SCHEDULE.get(block_height).transaction_data
+    }
+
+    /// Returns `true` if a block with the given height and timestamp reaches
+    /// the locktime cutoff of every transition in the transaction.
+    #[inline]
+    pub fn is_unlocked_at(&self, block_height: u32, timestamp: i64) -> bool {
+        self.transitions
+            .iter()
+            .all(|transition| transition.locktime_cutoff().is_reached(block_height, timestamp))
+    }
+
     /// Returns records from the transaction belonging to the given account view key.
     #[inline]
     pub fn to_decrypted_records<'a>(
@@ -312,6 +421,24 @@ impl<N: Network> Transaction<N> {
         self.transitions.iter().flat_map(Transition::to_records)
     }
 
+    /// Returns the size of the serialized transaction in bytes.
+    #[inline]
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        Ok(self.to_bytes_le()?.len())
+    }
+
+    /// Returns the number of bytes used by each component of the serialized transaction.
+    pub fn size_breakdown(&self) -> Result<SizeBreakdown> {
+        let mut breakdown = self
+            .transitions
+            .iter()
+            .map(Transition::size_breakdown)
+            .sum::<Result<SizeBreakdown>>()?;
+        breakdown.data = self.data.len();
+        breakdown.other += self.size_in_bytes()? - breakdown.total();
+        Ok(breakdown)
+    }
+
     /// Returns the local proof for a given commitment.
     #[inline]
     pub fn to_local_proof(&self, record_commitment: N::Commitment) -> Result<LocalProof<N>> {
@@ -319,35 +446,75 @@ impl<N: Network> Transaction<N> {
         let mut transitions_tree = Transitions::<N>::new()?;
         // Add all given transition IDs to the tree.
         transitions_tree.add_all(self.transitions())?;
+        // Add the public data to the tree.
+        if !self.data.is_empty() {
+            transitions_tree.add_data(&self.data)?;
+        }
         // Return the local proof for the transitions tree.
         transitions_tree.to_local_proof(record_commitment)
     }
 
-    /// Transaction ID := MerkleTree(transition IDs)
+    /// Transaction ID := MerkleTree(transition IDs || CRH(data))
+    ///
+    /// The leaf for the public data is only added if the data is not empty.
     #[inline]
-    pub(crate) fn compute_transaction_id(transitions: &[Transition<N>]) -> Result<N::TransactionID> {
+    pub(crate) fn compute_transaction_id(transitions: &[Transition<N>], data: &[u8]) -> Result<N::TransactionID> {
         // Initialize a transitions tree.
         let mut transitions_tree = Transitions::<N>::new()?;
         // Add all given transition IDs to the tree.
         transitions_tree.add_all(transitions)?;
+        // Add the public data to the tree.
+        if !data.is_empty() {
+            transitions_tree.add_data(data)?;
+        }
         // Return the root of the transitions tree.
         Ok(transitions_tree.root())
     }
 }
 
+/// The flag in the number of transitions that indicates the transaction bytes contain public data.
+/// Transactions without public data are encoded as before the data was introduced.
+const TRANSACTION_DATA_FLAG: u16 = 1 << 15;
+
 impl<N: Network> FromBytes for Transaction<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let inner_circuit_id = FromBytes::read_le(&mut reader)?;
         let ledger_root = FromBytes::read_le(&mut reader)?;
 
-        let num_transitions: u16 = FromBytes::read_le(&mut reader)?;
+        let num_transitions_and_flag: u16 = FromBytes::read_le(&mut reader)?;
+        let has_data = num_transitions_and_flag & TRANSACTION_DATA_FLAG != 0;
+        let num_transitions = num_transitions_and_flag & !TRANSACTION_DATA_FLAG;
+        if num_transitions as usize > N::MAX_TRANSITIONS_PER_TRANSACTION {
+            return Err(VMError::Message(format!(
+                "Transaction contains {} transitions, exceeding the limit",
+                num_transitions
+            ))
+            .into());
+        }
         let mut transitions = Vec::with_capacity(num_transitions as usize);
         for _ in 0..num_transitions {
             transitions.push(FromBytes::read_le(&mut reader)?);
         }
 
-        Ok(Self::from(inner_circuit_id, ledger_root, transitions).expect("Failed to deserialize a transaction"))
+        let data = match has_data {
+            true => {
+                let data_size: u16 = FromBytes::read_le(&mut reader)?;
+                if data_size as usize > N::MAX_TRANSACTION_DATA_SIZE_IN_BYTES {
+                    return Err(VMError::Message(format!(
+                        "Transaction contains {} bytes of public data, exceeding the limit",
+                        data_size
+                    ))
+                    .into());
+                }
+                let mut data = vec![0u8; data_size as usize];
+                reader.read_exact(&mut data)?;
+                data
+            }
+            false => Vec::new(),
+        };
+
+        Ok(Self::from_with_data(inner_circuit_id, ledger_root, transitions, data).map_err(VMError::from)?)
     }
 }
 
@@ -356,8 +523,26 @@ impl<N: Network> ToBytes for Transaction<N> {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.inner_circuit_id.write_le(&mut writer)?;
         self.ledger_root.write_le(&mut writer)?;
-        (self.transitions.len() as u16).write_le(&mut writer)?;
-        self.transitions.write_le(&mut writer)
+        match self.data.is_empty() {
+            true => (self.transitions.len() as u16).write_le(&mut writer)?,
+            false => (self.transitions.len() as u16 | TRANSACTION_DATA_FLAG).write_le(&mut writer)?,
+        }
+        self.transitions.write_le(&mut writer)?;
+        if !self.data.is_empty() {
+            let data_size = match u16::try_from(self.data.len()) {
+                Ok(data_size) => data_size,
+                Err(_) => {
+                    return Err(VMError::Message(format!(
+                        "Transaction contains {} bytes of public data, exceeding the limit",
+                        self.data.len()
+                    ))
+                    .into());
+                }
+            };
+            data_size.write_le(&mut writer)?;
+            writer.write_all(&self.data)?;
+        }
+        Ok(())
     }
 }
 
@@ -383,11 +568,13 @@ impl<N: Network> Serialize for Transaction<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transaction = serializer.serialize_struct("Transaction", 5)?;
+                let mut transaction = serializer.serialize_struct("Transaction", 6)?;
+                transaction.serialize_field(JSON_SCHEMA_VERSION_FIELD, &JSON_SCHEMA_VERSION)?;
                 transaction.serialize_field("transaction_id", &self.transaction_id)?;
                 transaction.serialize_field("inner_circuit_id", &self.inner_circuit_id)?;
                 transaction.serialize_field("ledger_root", &self.ledger_root)?;
                 transaction.serialize_field("transitions", &self.transitions)?;
+                transaction.serialize_field("data", &hex::encode(&self.data))?;
                 transaction.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -400,14 +587,27 @@ impl<'de, N: Network> Deserialize<'de> for Transaction<N> {
         match deserializer.is_human_readable() {
             true => {
                 let transaction = serde_json::Value::deserialize(deserializer)?;
+                TRANSACTION_JSON_SCHEMA
+                    .read_version(&transaction)
+                    .map_err(de::Error::custom)?;
                 let transaction_id =
                     N::TransactionID::deserialize(transaction["transaction_id"].clone()).map_err(de::Error::custom)?;
 
+                // Recover the public data. Transactions serialized before the data was introduced have none.
+                let data = match transaction.get("data") {
+                    Some(data) => {
+                        let data: String = serde_json::from_value(data.clone()).map_err(de::Error::custom)?;
+                        hex::decode(data).map_err(de::Error::custom)?
+                    }
+                    None => Vec::new(),
+                };
+
                 // Recover the transaction.
-                let transaction = Self::from(
+                let transaction = Self::from_with_data(
                     serde_json::from_value(transaction["inner_circuit_id"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(transaction["ledger_root"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(transaction["transitions"].clone()).map_err(de::Error::custom)?,
+                    data,
                 )
                 .map_err(de::Error::custom)?;
 
@@ -422,7 +622,11 @@ impl<'de, N: Network> Deserialize<'de> for Transaction<N> {
                     .map_err(de::Error::custom),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transaction"),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "transaction",
+                N::MAX_TRANSACTION_SIZE_IN_BYTES,
+            ),
         }
     }
 }
@@ -437,7 +641,16 @@ impl<N: Network> Hash for Transaction<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet2::Testnet2, Account, AccountScheme};
+    use crate::{
+        assert_json_field_order,
+        testnet2::Testnet2,
+        Account,
+        AccountScheme,
+        Bech32Locator,
+        NetworkConstants,
+        NetworkCrypto,
+        NetworkParameters,
+    };
 
     use rand::thread_rng;
 
@@ -494,8 +707,9 @@ mod tests {
         // Serialize
         let expected_string = expected_transaction.to_string();
         let candidate_string = serde_json::to_string(&expected_transaction).unwrap();
-        assert_eq!(2347, candidate_string.len(), "Update me if serialization has changed");
+        assert_eq!(2395, candidate_string.len(), "Update me if serialization has changed");
         assert_eq!(expected_string, candidate_string);
+        assert_json_field_order(&TRANSACTION_JSON_SCHEMA, &candidate_string);
 
         // Deserialize
         assert_eq!(expected_transaction, Transaction::from_str(&candidate_string).unwrap());
@@ -515,6 +729,7 @@ mod tests {
         let expected_bytes = expected_transaction.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_transaction).unwrap();
         assert_eq!(1121, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(expected_bytes.len(), expected_transaction.size_in_bytes().unwrap());
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -525,4 +740,74 @@ mod tests {
             bincode::deserialize(&candidate_bytes[..]).unwrap()
         );
     }
+
+    #[test]
+    fn test_transaction_read_le_malformed() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let expected_bytes = transaction.to_bytes_le().unwrap();
+
+        // Ensure truncated bytes fail to deserialize.
+        assert!(Transaction::<Testnet2>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+
+        // Ensure a number of transitions exceeding the limit fails to deserialize.
+        let num_transitions_offset = <Testnet2 as NetworkCrypto>::InnerCircuitID::data_size_in_bytes()
+            + <Testnet2 as NetworkCrypto>::LedgerRoot::data_size_in_bytes();
+        let mut candidate_bytes = expected_bytes;
+        candidate_bytes[num_transitions_offset..num_transitions_offset + 2].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert!(Transaction::<Testnet2>::read_le(&candidate_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_transaction_bincode_size_limit() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let mut transaction_bytes = bincode::serialize(&transaction).unwrap();
+
+        // Ensure the transaction is within the size limit.
+        assert!(transaction_bytes.len() <= Testnet2::MAX_TRANSACTION_SIZE_IN_BYTES);
+
+        // Ensure a byte array exceeding the size limit fails to deserialize.
+        let oversized_bytes = bincode::serialize(&vec![0u8; Testnet2::MAX_TRANSACTION_SIZE_IN_BYTES + 1]).unwrap();
+        assert!(bincode::deserialize::<Transaction<Testnet2>>(&oversized_bytes[..]).is_err());
+
+        // Ensure a length encoding exceeding the size limit fails to deserialize.
+        transaction_bytes[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(bincode::deserialize::<Transaction<Testnet2>>(&transaction_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_transaction_data() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        assert!(transaction.data().is_empty());
+        assert_eq!(AleoAmount::ZERO, transaction.data_fee());
+
+        // Ensure the public data is committed to in the transaction ID.
+        let data = vec![7u8; 100];
+        let transaction_id = Transaction::compute_transaction_id(transaction.transitions(), &data).unwrap();
+        assert_ne!(transaction.transaction_id(), transaction_id);
+        assert_ne!(
+            transaction_id,
+            Transaction::compute_transaction_id(transaction.transitions(), &data[1..]).unwrap()
+        );
+
+        // Ensure transactions without public data are accepted at every block height,
+        // and public data is only accepted once the network upgrade activates it.
+        assert!(transaction.is_data_accepted_at(0));
+        assert!(transaction.is_data_accepted_at(u32::MAX));
+        assert!(!Testnet2::UPGRADE_SCHEDULE.get(u32::MAX).transaction_data);
+
+        // Ensure a coinbase transaction cannot pay the fee for public data.
+        assert!(Transaction::<Testnet2>::from_with_data(
+            transaction.inner_circuit_id(),
+            transaction.ledger_root(),
+            transaction.transitions().clone(),
+            data,
+        )
+        .is_err());
+
+        // Ensure a transaction serialized without the public data deserializes with none.
+        let mut transaction_json = serde_json::to_value(&transaction).unwrap();
+        transaction_json.as_object_mut().unwrap().remove("data");
+        let candidate: Transaction<Testnet2> = serde_json::from_value(transaction_json).unwrap();
+        assert_eq!(transaction, candidate);
+    }
 }
@@ -29,6 +29,8 @@ pub(crate) struct Transitions<N: Network> {
     tree: Arc<MerkleTree<N::TransactionIDParameters>>,
     transitions: HashMap<N::TransitionID, (u8, Transition<N>)>,
     current_index: u8,
+    /// The leaf of the public data of the transaction, if it has been added to the tree.
+    data_leaf: Option<N::TransitionID>,
 }
 
 impl<N: Network> Transitions<N> {
@@ -41,11 +43,17 @@ impl<N: Network> Transitions<N> {
             )?),
             transitions: Default::default(),
             current_index: 0,
+            data_leaf: None,
         })
     }
 
     /// Adds the given transition to the tree, returning its index in the tree.
     pub(crate) fn add(&mut self, transition: &Transition<N>) -> Result<u8> {
+        // Ensure the public data has not been added to the tree.
+        if self.data_leaf.is_some() {
+            return Err(anyhow!("The transitions tree already contains the public data"));
+        }
+
         // Ensure the transition does not already exist in the tree.
         let transition_id = transition.transition_id();
         if self.contains_transition(&transition_id) {
@@ -69,6 +77,11 @@ impl<N: Network> Transitions<N> {
 
     /// Adds all given transitions to the tree, returning the start and ending index in the tree.
     pub(crate) fn add_all(&mut self, transitions: &[Transition<N>]) -> Result<(u8, u8)> {
+        // Ensure the public data has not been added to the tree.
+        if self.data_leaf.is_some() {
+            return Err(anyhow!("The transitions tree already contains the public data"));
+        }
+
         // Ensure the current index has not reached the maximum number of transitions permitted in software.
         if self.current_index >= N::NUM_TRANSITIONS
             || self.current_index + transitions.len() as u8 >= N::NUM_TRANSITIONS
@@ -103,6 +116,40 @@ impl<N: Network> Transitions<N> {
         Ok((start_index, end_index))
     }
 
+    /// Adds the commitment of the given public data to the tree, after the transitions.
+    /// As the data is added last, the local transitions root of each transition is unchanged.
+    pub(crate) fn add_data(&mut self, data: &[u8]) -> Result<()> {
+        // Ensure the public data has not been added to the tree.
+        if self.data_leaf.is_some() {
+            return Err(anyhow!("The transitions tree already contains the public data"));
+        }
+
+        // Ensure the current index has not reached the maximum number of leaves permitted in software.
+        if self.current_index >= N::NUM_TRANSITIONS {
+            return Err(anyhow!("The transitions tree has reached its maximum size"));
+        }
+
+        let data_leaf = Self::to_data_leaf(data)?;
+        self.tree = Arc::new(self.tree.rebuild(self.current_index as usize, &[data_leaf])?);
+        self.data_leaf = Some(data_leaf);
+
+        Ok(())
+    }
+
+    /// Returns the leaf that commits to the given public data.
+    ///
+    /// The data is committed by chaining the CRH of the transaction ID over 32-byte chunks of the data,
+    /// starting from the hash of the data length.
+    pub(crate) fn to_data_leaf(data: &[u8]) -> Result<N::TransitionID> {
+        let parameters = N::transaction_id_parameters();
+
+        let mut digest = parameters.hash_leaf(&(data.len() as u32))?;
+        for chunk in data.chunks(32) {
+            digest = parameters.hash_inner_node(&digest, &parameters.crh().hash(chunk)?)?;
+        }
+        Ok(digest.into())
+    }
+
     /// Returns `true` if the given transition exists.
     pub(crate) fn contains_transition(&self, transition_id: &N::TransitionID) -> bool {
         self.transitions.contains_key(transition_id)
@@ -118,7 +165,7 @@ impl<N: Network> Transitions<N> {
         (*self.tree.root()).into()
     }
 
-    /// Returns the size of the local transitions tree.
+    /// Returns the number of transitions in the local transitions tree.
     pub(crate) fn len(&self) -> usize {
         self.current_index as usize
     }
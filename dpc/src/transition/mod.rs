@@ -22,3 +22,9 @@ pub use response::*;
 
 pub mod transition;
 pub use transition::*;
+
+pub mod signature;
+pub use signature::*;
+
+pub mod verifier;
+pub use verifier::*;
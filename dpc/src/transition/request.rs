@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Address, AleoAmount, ComputeKey, FunctionType, LedgerProof, Network, Operation, PrivateKey, Record};
+use crate::{
+    Address,
+    AleoAmount,
+    ComputeKey,
+    FunctionType,
+    LedgerProof,
+    Network,
+    Operation,
+    PrivateKey,
+    Record,
+    VMError,
+};
 use snarkvm_algorithms::SignatureScheme;
 use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
@@ -118,7 +129,7 @@ impl<N: Network> Request<N> {
         // Pad the records with noops if there is less than required.
         let mut records = records;
         while records.len() < N::NUM_INPUT_RECORDS {
-            records.push(Record::new_noop(caller_address, rng)?);
+            records.push(Record::new_dummy(caller, rng)?);
         }
 
         let mut commitments = Vec::with_capacity(N::NUM_INPUT_RECORDS);
@@ -356,8 +367,7 @@ impl<N: Network> FromBytes for Request<N> {
         let signature = FromBytes::read_le(&mut reader)?;
         let is_public = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self::from(records, ledger_proofs, operation, fee, signature, is_public)
-            .expect("Failed to deserialize a request"))
+        Ok(Self::from(records, ledger_proofs, operation, fee, signature, is_public).map_err(VMError::from)?)
     }
 }
 
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, Event, Network, Record};
+use crate::{AleoAmount, Events, Network, NetworkCrypto, PaymentOpening, Record};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use std::{
     fmt,
     io::{Read, Result as IoResult, Write},
@@ -25,7 +25,8 @@ use std::{
 
 // TODO (howardwu): TEMPORARY - Merge this into the Network trait.
 use snarkvm_algorithms::traits::*;
-pub type EncryptionRandomness<N> = <<N as Network>::AccountEncryptionScheme as EncryptionScheme>::ScalarRandomness;
+pub type EncryptionRandomness<N> =
+    <<N as NetworkCrypto>::AccountEncryptionScheme as EncryptionScheme>::ScalarRandomness;
 
 #[derive(Clone, Debug)]
 pub struct Response<N: Network> {
@@ -38,7 +39,7 @@ pub struct Response<N: Network> {
     /// A value balance is the difference between the input and output record values.
     value_balance: AleoAmount,
     /// The events emitted from the execution.
-    events: Vec<Event<N>>,
+    events: Events<N>,
 }
 
 impl<N: Network> Response<N> {
@@ -48,7 +49,7 @@ impl<N: Network> Response<N> {
         records: Vec<Record<N>>,
         encryption_randomness: Vec<EncryptionRandomness<N>>,
         value_balance: AleoAmount,
-        events: Vec<Event<N>>,
+        events: Events<N>,
     ) -> Result<Self> {
         Ok(Self {
             transition_id,
@@ -98,13 +99,26 @@ impl<N: Network> Response<N> {
         &self.encryption_randomness
     }
 
+    /// Returns the payment opening for the output record with the given commitment.
+    pub fn to_payment_opening(&self, commitment: N::Commitment) -> Result<PaymentOpening<N>> {
+        match self
+            .records
+            .iter()
+            .zip(&self.encryption_randomness)
+            .find(|(record, _)| record.commitment() == commitment)
+        {
+            Some((record, encryption_randomness)) => Ok(PaymentOpening::new(record, *encryption_randomness)?),
+            None => Err(anyhow!("Commitment {} is not an output of this response", commitment)),
+        }
+    }
+
     /// Returns the value balance.
     pub fn value_balance(&self) -> AleoAmount {
         self.value_balance
     }
 
     /// Returns a reference to the events.
-    pub fn events(&self) -> &Vec<Event<N>> {
+    pub fn events(&self) -> &Events<N> {
         &self.events
     }
 }
@@ -126,11 +140,7 @@ impl<N: Network> FromBytes for Response<N> {
 
         let value_balance = FromBytes::read_le(&mut reader)?;
 
-        let num_events: u16 = FromBytes::read_le(&mut reader)?;
-        let mut events = Vec::with_capacity(num_events as usize);
-        for _ in 0..num_events {
-            events.push(FromBytes::read_le(&mut reader)?);
-        }
+        let events = FromBytes::read_le(&mut reader)?;
 
         Ok(Self {
             transition_id,
@@ -149,7 +159,6 @@ impl<N: Network> ToBytes for Response<N> {
         self.records.write_le(&mut writer)?;
         self.encryption_randomness.write_le(&mut writer)?;
         self.value_balance.write_le(&mut writer)?;
-        (self.events.len() as u16).write_le(&mut writer)?;
         self.events.write_le(&mut writer)
     }
 }
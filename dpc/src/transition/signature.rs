@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::io::{Read, Result as IoResult, Write};
+
+/// The domain separator for messages signed by a transition signature.
+const TRANSITION_SIGNATURE_DOMAIN: &[u8] = b"AleoTransitionSignature0";
+
+///
+/// A signature on an arbitrary message, proving that the signer spent an input record of a transition.
+///
+/// The signature is bound to the transition ID and the commitment of the spent input record.
+/// Verifying the signature recovers the compute key of the signer, and checks that it derives
+/// a serial number of the transition. Note that this reveals the address of the signer,
+/// and links it to the spent input record, but does not reveal the private key.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct TransitionSignature<N: Network> {
+    /// The ID of the signed transition.
+    transition_id: N::TransitionID,
+    /// The commitment of the input record spent by the signer.
+    commitment: N::Commitment,
+    /// The signature on the transition ID, commitment, and message.
+    signature: N::AccountSignature,
+}
+
+impl<N: Network> TransitionSignature<N> {
+    /// Signs the given message, proving that the given private key spent the given input record of the transition.
+    pub fn sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        record: &Record<N>,
+        transition: &Transition<N>,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the record was spent by the private key in the given transition.
+        let serial_number = record.to_serial_number(&private_key.to_compute_key())?;
+        if !transition.serial_numbers().any(|candidate| *candidate == serial_number) {
+            return Err(anyhow!(
+                "The given record is not an input of transition {}",
+                transition.transition_id()
+            ));
+        }
+
+        let transition_id = transition.transition_id();
+        let commitment = record.commitment();
+        let signature = private_key.sign(&Self::to_signed_message(transition_id, commitment, message)?, rng)?;
+
+        Ok(Self {
+            transition_id,
+            commitment,
+            signature,
+        })
+    }
+
+    /// Returns `true` if the signature is a valid signature on the given message,
+    /// from the owner of an input record of the given transition.
+    pub fn verify(&self, transition: &Transition<N>, message: &[u8]) -> bool {
+        // Ensure the signature is for the given transition.
+        if self.transition_id != transition.transition_id() {
+            eprintln!("Transition signature is for a different transition");
+            return false;
+        }
+
+        // Recover the compute key of the signer.
+        let compute_key = match ComputeKey::<N>::from_signature(&self.signature) {
+            Ok(compute_key) => compute_key,
+            Err(error) => {
+                eprintln!(
+                    "Failed to recover the compute key from the transition signature: {}",
+                    error
+                );
+                return false;
+            }
+        };
+
+        // Ensure the signer spent the input record in the given transition.
+        match Record::<N>::compute_serial_number(&compute_key, self.commitment) {
+            Ok(serial_number) => {
+                if !transition.serial_numbers().any(|candidate| *candidate == serial_number) {
+                    eprintln!("Transition signature signer did not spend a record in the transition");
+                    return false;
+                }
+            }
+            Err(error) => {
+                eprintln!(
+                    "Failed to compute the serial number of the transition signature: {}",
+                    error
+                );
+                return false;
+            }
+        }
+
+        // Ensure the signature is valid for the message.
+        let signed_message = match Self::to_signed_message(self.transition_id, self.commitment, message) {
+            Ok(signed_message) => signed_message,
+            Err(error) => {
+                eprintln!("Failed to construct the transition signature message: {}", error);
+                return false;
+            }
+        };
+        match Address::from_compute_key(&compute_key).verify_signature(&signed_message, &self.signature) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify the transition signature: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns the transition ID.
+    pub fn transition_id(&self) -> N::TransitionID {
+        self.transition_id
+    }
+
+    /// Returns the commitment of the input record spent by the signer.
+    pub fn commitment(&self) -> N::Commitment {
+        self.commitment
+    }
+
+    /// Returns a reference to the signature.
+    pub fn signature(&self) -> &N::AccountSignature {
+        &self.signature
+    }
+
+    /// Returns the address of the signer.
+    pub fn signer(&self) -> Result<Address<N>> {
+        Ok(Address::from_compute_key(&ComputeKey::from_signature(&self.signature)?))
+    }
+
+    /// Returns the bytes signed for the given transition ID, commitment, and message.
+    fn to_signed_message(transition_id: N::TransitionID, commitment: N::Commitment, message: &[u8]) -> Result<Vec<u8>> {
+        let mut signed_message = TRANSITION_SIGNATURE_DOMAIN.to_vec();
+        transition_id.write_le(&mut signed_message)?;
+        commitment.write_le(&mut signed_message)?;
+        signed_message.extend_from_slice(message);
+        Ok(signed_message)
+    }
+}
+
+impl<N: Network> FromBytes for TransitionSignature<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let transition_id = FromBytes::read_le(&mut reader)?;
+        let commitment = FromBytes::read_le(&mut reader)?;
+        let signature = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            transition_id,
+            commitment,
+            signature,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for TransitionSignature<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.transition_id.write_le(&mut writer)?;
+        self.commitment.write_le(&mut writer)?;
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_transition_signature() {
+        let rng = &mut thread_rng();
+
+        // Execute a coinbase request from a known caller, spending its noop records.
+        let caller = PrivateKey::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng).address();
+        let amount = AleoAmount::from_i64(1234);
+        let request = Request::new(
+            &caller,
+            vec![],
+            vec![LedgerProof::default(); Testnet2::NUM_INPUT_RECORDS],
+            Operation::Coinbase(recipient, amount),
+            AleoAmount::ZERO.sub(amount),
+            true,
+            rng,
+        )
+        .unwrap();
+        let (vm, _) = VirtualMachine::<Testnet2>::new(LedgerTree::<Testnet2>::new().unwrap().root())
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap();
+        let transaction = vm.finalize().unwrap();
+        let transition = &transaction.transitions()[0];
+
+        let message = b"Hello, transition!";
+        let record = &request.records()[0];
+        let signature = TransitionSignature::sign(&caller, record, transition, message, rng).unwrap();
+        assert!(signature.verify(transition, message));
+        assert_eq!(Address::from_private_key(&caller), signature.signer().unwrap());
+        assert_eq!(record.commitment(), signature.commitment());
+
+        // Ensure the signature does not verify for a different message.
+        assert!(!signature.verify(transition, b"Hello, world!"));
+
+        // Ensure the signature does not verify for a different transition.
+        let (other, _) = Transaction::<Testnet2>::new_coinbase(recipient, amount, true, rng).unwrap();
+        assert!(!signature.verify(&other.transitions()[0], message));
+
+        // Ensure a private key that does not own the record cannot sign.
+        let stranger = PrivateKey::<Testnet2>::new(rng);
+        assert!(TransitionSignature::sign(&stranger, record, transition, message, rng).is_err());
+
+        // Ensure a record that is not an input of the transition cannot be signed for.
+        let unspent = Record::new_noop(Address::from_private_key(&caller), rng).unwrap();
+        assert!(TransitionSignature::sign(&caller, &unspent, transition, message, rng).is_err());
+
+        // Ensure the signature serializes and deserializes.
+        let bytes = signature.to_bytes_le().unwrap();
+        let recovered = TransitionSignature::<Testnet2>::read_le(&bytes[..]).unwrap();
+        assert_eq!(signature, recovered);
+        assert!(recovered.verify(transition, message));
+    }
+}
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{circuits::*, prelude::*};
+use crate::{circuits::*, prelude::*, JSON_SCHEMA_VERSION, JSON_SCHEMA_VERSION_FIELD, TRANSITION_JSON_SCHEMA};
 use snarkvm_algorithms::{
     merkle_tree::{MerklePath, MerkleTree},
-    traits::SNARK,
+    traits::{Prepare, SNARK},
 };
 use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
@@ -50,7 +50,10 @@ pub struct Transition<N: Network> {
     /// A value balance is the difference between the input and output record values.
     value_balance: AleoAmount,
     /// The events emitted from this transition.
-    events: Vec<Event<N>>,
+    events: Events<N>,
+    /// The cutoff that the locktimes of the input records are checked against.
+    /// This is only serialized if `N::RECORD_LOCKTIMES_ENABLED` is `true`.
+    locktime_cutoff: LocktimeCutoff,
     /// The zero-knowledge proof attesting to the validity of this transition.
     proof: N::OuterProof,
 }
@@ -58,7 +61,12 @@ pub struct Transition<N: Network> {
 impl<N: Network> Transition<N> {
     /// Initializes a new instance of a transition.
     #[inline]
-    pub(crate) fn new(request: &Request<N>, response: &Response<N>, proof: N::OuterProof) -> Result<Self> {
+    pub(crate) fn new(
+        request: &Request<N>,
+        response: &Response<N>,
+        locktime_cutoff: LocktimeCutoff,
+        proof: N::OuterProof,
+    ) -> Result<Self> {
         // Fetch the serial numbers.
         let serial_numbers = request.to_serial_numbers()?;
 
@@ -69,7 +77,15 @@ impl<N: Network> Transition<N> {
         let events = response.events().clone();
 
         // Construct the transition.
-        Self::from(transition_id, serial_numbers, ciphertexts, value_balance, events, proof)
+        Self::from(
+            transition_id,
+            serial_numbers,
+            ciphertexts,
+            value_balance,
+            events,
+            locktime_cutoff,
+            proof,
+        )
     }
 
     /// Constructs an instance of a transition from the given inputs.
@@ -78,9 +94,15 @@ impl<N: Network> Transition<N> {
         serial_numbers: Vec<N::SerialNumber>,
         ciphertexts: Vec<N::RecordCiphertext>,
         value_balance: AleoAmount,
-        events: Vec<Event<N>>,
+        events: Events<N>,
+        locktime_cutoff: LocktimeCutoff,
         proof: N::OuterProof,
     ) -> Result<Self> {
+        // Ensure the locktime cutoff is empty, if the network does not support record locktimes.
+        if !N::RECORD_LOCKTIMES_ENABLED && locktime_cutoff != LocktimeCutoff::default() {
+            return Err(anyhow!("Record locktimes are not enabled on this network"));
+        }
+
         // Compute the commitments.
         let commitments = ciphertexts.iter().map(|c| c.commitment()).collect::<Vec<_>>();
         // Construct the transition.
@@ -91,6 +113,7 @@ impl<N: Network> Transition<N> {
             ciphertexts,
             value_balance,
             events,
+            locktime_cutoff,
             proof,
         };
         // Ensure the transition ID matches.
@@ -112,27 +135,38 @@ impl<N: Network> Transition<N> {
         ledger_root: N::LedgerRoot,
         local_transitions_root: N::TransactionID,
     ) -> bool {
-        // Ensure the number of events is less than `N::NUM_EVENTS`.
-        if self.events.len() > N::NUM_EVENTS as usize {
-            eprintln!("Transition contains an invalid number of events");
-            return false;
-        }
+        self.verify_prepared(
+            &N::outer_verifying_key().prepare(),
+            inner_circuit_id,
+            ledger_root,
+            local_transitions_root,
+        )
+    }
+
+    /// Returns `true` if the transition ID is well-formed and the transition proof is valid,
+    /// using the given prepared outer circuit verifying key.
+    #[inline]
+    pub fn verify_prepared(
+        &self,
+        outer_verifying_key: &<N::OuterSNARK as SNARK>::PreparedVerifyingKey,
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> bool {
+        let _span = debug_span!("Transition::verify").entered();
+
+        // Returns `false` if the program fee event is invalid.
+        let public_variables =
+            match self.to_outer_public_variables(inner_circuit_id, ledger_root, local_transitions_root) {
+                Ok(public_variables) => public_variables,
+                Err(error) => {
+                    eprintln!("Transition contains an invalid program fee: {}", error);
+                    return false;
+                }
+            };
 
         // Returns `false` if the transition proof is invalid.
-        match N::OuterSNARK::verify(
-            N::outer_verifying_key(),
-            &OuterPublicVariables::new(
-                InnerPublicVariables::new(
-                    self.transition_id,
-                    self.value_balance,
-                    ledger_root,
-                    local_transitions_root,
-                    None,
-                ),
-                &inner_circuit_id,
-            ),
-            &self.proof,
-        ) {
+        match N::OuterSNARK::verify_prepared(outer_verifying_key, &public_variables, &self.proof) {
             Ok(is_valid) => match is_valid {
                 true => true,
                 false => {
@@ -147,6 +181,31 @@ impl<N: Network> Transition<N> {
         }
     }
 
+    /// Returns the public inputs of the transition proof.
+    ///
+    /// If program fees are enabled, the program fee is a public input of the transition proof,
+    /// so this returns an error if the program fee event is invalid.
+    pub(crate) fn to_outer_public_variables(
+        &self,
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> Result<OuterPublicVariables<N>> {
+        Ok(OuterPublicVariables::new(
+            InnerPublicVariables::new(
+                self.transition_id,
+                self.value_balance,
+                ledger_root,
+                local_transitions_root,
+                self.locktime_cutoff,
+                self.to_program_fee()?,
+                None,
+                None,
+            ),
+            &inner_circuit_id,
+        ))
+    }
+
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
         self.serial_numbers.contains(serial_number)
@@ -193,12 +252,37 @@ impl<N: Network> Transition<N> {
         self.events.iter()
     }
 
+    /// Returns the cutoff that the locktimes of the input records are checked against.
+    /// A transition may only be included in a block that reaches its cutoff.
+    #[inline]
+    pub fn locktime_cutoff(&self) -> LocktimeCutoff {
+        self.locktime_cutoff
+    }
+
     /// Returns a reference to the transition proof.
     #[inline]
     pub fn proof(&self) -> &N::OuterProof {
         &self.proof
     }
 
+    /// Returns the number of bytes used by each component of the serialized transition.
+    pub fn size_breakdown(&self) -> Result<SizeBreakdown> {
+        let serial_numbers = self.serial_numbers.to_bytes_le()?.len();
+        let ciphertexts = self.ciphertexts.to_bytes_le()?.len();
+        let events = self.events.to_bytes_le()?.len();
+        let transition_proofs = self.proof.to_bytes_le()?.len();
+        let size = self.to_bytes_le()?.len();
+
+        Ok(SizeBreakdown {
+            serial_numbers,
+            ciphertexts,
+            events,
+            transition_proofs,
+            other: size - serial_numbers - ciphertexts - events - transition_proofs,
+            ..Default::default()
+        })
+    }
+
     /// Returns records from the transaction belonging to the given account view key.
     #[inline]
     pub fn to_decrypted_records<'a>(
@@ -227,6 +311,38 @@ impl<N: Network> Transition<N> {
             .filter(|record| !record.is_dummy())
     }
 
+    /// Returns the program fee paid in the transition, if it emits a program fee event.
+    /// Returns an error if the transition emits more than one program fee event,
+    /// or if the event does not reveal a non-dummy output record.
+    #[inline]
+    pub fn to_program_fee(&self) -> Result<Option<ProgramFee<N>>> {
+        let mut program_fees = self.events.iter().filter_map(|event| match event {
+            Event::ProgramFee(i, record_view_key) => Some((*i, record_view_key)),
+            _ => None,
+        });
+
+        let (index, record_view_key) = match program_fees.next() {
+            Some(program_fee) => program_fee,
+            None => return Ok(None),
+        };
+        if program_fees.next().is_some() {
+            return Err(anyhow!(
+                "Transition {} emits more than one program fee",
+                self.transition_id
+            ));
+        }
+
+        let ciphertext = self
+            .ciphertexts
+            .get(index as usize)
+            .ok_or_else(|| anyhow!("Program fee event refers to a missing output record {}", index))?;
+        let record = Record::from_record_view_key(record_view_key, ciphertext)?;
+        match record.is_dummy() {
+            true => Err(anyhow!("Program fee event refers to a dummy output record {}", index)),
+            false => Ok(Some(ProgramFee::new(record.owner(), record.value()))),
+        }
+    }
+
     /// Returns an inclusion proof for the transition tree.
     #[inline]
     pub fn to_transition_inclusion_proof(&self, leaf: impl ToBytes) -> Result<MerklePath<N::TransitionIDParameters>> {
@@ -315,18 +431,25 @@ impl<N: Network> FromBytes for Transition<N> {
 
         let value_balance: AleoAmount = FromBytes::read_le(&mut reader)?;
 
-        let num_events: u16 = FromBytes::read_le(&mut reader)?;
-        let mut events = Vec::with_capacity(num_events as usize);
-        for _ in 0..num_events {
-            events.push(FromBytes::read_le(&mut reader)?);
-        }
+        let events: Events<N> = FromBytes::read_le(&mut reader)?;
+
+        let locktime_cutoff = match N::RECORD_LOCKTIMES_ENABLED {
+            true => FromBytes::read_le(&mut reader)?,
+            false => LocktimeCutoff::default(),
+        };
 
         let proof: N::OuterProof = FromBytes::read_le(&mut reader)?;
 
-        Ok(
-            Self::from(transition_id, serial_numbers, ciphertexts, value_balance, events, proof)
-                .expect("Failed to deserialize a transition from bytes"),
+        Ok(Self::from(
+            transition_id,
+            serial_numbers,
+            ciphertexts,
+            value_balance,
+            events,
+            locktime_cutoff,
+            proof,
         )
+        .map_err(VMError::from)?)
     }
 }
 
@@ -337,8 +460,10 @@ impl<N: Network> ToBytes for Transition<N> {
         self.serial_numbers.write_le(&mut writer)?;
         self.ciphertexts.write_le(&mut writer)?;
         self.value_balance.write_le(&mut writer)?;
-        (self.events.len() as u16).write_le(&mut writer)?;
         self.events.write_le(&mut writer)?;
+        if N::RECORD_LOCKTIMES_ENABLED {
+            self.locktime_cutoff.write_le(&mut writer)?;
+        }
         self.proof.write_le(&mut writer)
     }
 }
@@ -365,13 +490,17 @@ impl<N: Network> Serialize for Transition<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Transition", 7)?;
+                let mut transition = serializer.serialize_struct("Transition", 8)?;
+                transition.serialize_field(JSON_SCHEMA_VERSION_FIELD, &JSON_SCHEMA_VERSION)?;
                 transition.serialize_field("transition_id", &self.transition_id)?;
                 transition.serialize_field("serial_numbers", &self.serial_numbers)?;
                 transition.serialize_field("commitments", &self.commitments)?;
                 transition.serialize_field("ciphertexts", &self.ciphertexts)?;
                 transition.serialize_field("value_balance", &self.value_balance)?;
                 transition.serialize_field("events", &self.events)?;
+                if N::RECORD_LOCKTIMES_ENABLED {
+                    transition.serialize_field("locktime_cutoff", &self.locktime_cutoff)?;
+                }
                 transition.serialize_field("proof", &self.proof)?;
                 transition.end()
             }
@@ -385,6 +514,9 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
         match deserializer.is_human_readable() {
             true => {
                 let transition = serde_json::Value::deserialize(deserializer)?;
+                TRANSITION_JSON_SCHEMA
+                    .read_version(&transition)
+                    .map_err(de::Error::custom)?;
                 // Recover the transition.
                 Self::from(
                     serde_json::from_value(transition["transition_id"].clone()).map_err(de::Error::custom)?,
@@ -392,11 +524,21 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                     serde_json::from_value(transition["ciphertexts"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(transition["value_balance"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(transition["events"].clone()).map_err(de::Error::custom)?,
+                    match N::RECORD_LOCKTIMES_ENABLED {
+                        true => {
+                            serde_json::from_value(transition["locktime_cutoff"].clone()).map_err(de::Error::custom)?
+                        }
+                        false => LocktimeCutoff::default(),
+                    },
                     serde_json::from_value(transition["proof"].clone()).map_err(de::Error::custom)?,
                 )
                 .map_err(de::Error::custom)
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transition"),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "transition",
+                N::MAX_TRANSITION_SIZE_IN_BYTES,
+            ),
         }
     }
 }
@@ -411,7 +553,7 @@ impl<N: Network> Hash for Transition<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testnet2::Testnet2;
+    use crate::{assert_json_field_order, testnet2::Testnet2};
 
     #[test]
     fn test_size() {
@@ -438,8 +580,9 @@ mod tests {
         // Serialize
         let expected_string = expected_transition.to_string();
         let candidate_string = serde_json::to_string(&expected_transition).unwrap();
-        assert_eq!(2074, candidate_string.len(), "Update me if serialization has changed");
+        assert_eq!(2093, candidate_string.len(), "Update me if serialization has changed");
         assert_eq!(expected_string, candidate_string);
+        assert_json_field_order(&TRANSITION_JSON_SCHEMA, &candidate_string);
 
         // Deserialize
         assert_eq!(expected_transition, Transition::from_str(&candidate_string).unwrap());
@@ -462,4 +605,27 @@ mod tests {
         assert_eq!(expected_transition, Transition::read_le(&expected_bytes[..]).unwrap());
         assert_eq!(expected_transition, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_transition_read_le_malformed() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let expected_bytes = transaction.transitions().first().unwrap().to_bytes_le().unwrap();
+
+        // Ensure truncated bytes fail to deserialize.
+        assert!(Transition::<Testnet2>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+
+        // Ensure a mismatching transition ID fails to deserialize.
+        let mut candidate_bytes = expected_bytes.clone();
+        candidate_bytes[0] ^= 1;
+        assert!(Transition::<Testnet2>::read_le(&candidate_bytes[..]).is_err());
+
+        // Ensure a number of events exceeding the limit fails to deserialize.
+        let num_events_offset = <Testnet2 as NetworkCrypto>::TransitionID::data_size_in_bytes()
+            + <Testnet2 as NetworkCrypto>::SerialNumber::data_size_in_bytes() * Testnet2::NUM_INPUT_RECORDS
+            + Testnet2::RECORD_CIPHERTEXT_SIZE_IN_BYTES * Testnet2::NUM_OUTPUT_RECORDS
+            + 8;
+        let mut candidate_bytes = expected_bytes;
+        candidate_bytes[num_events_offset..num_events_offset + 2].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert!(Transition::<Testnet2>::read_le(&candidate_bytes[..]).is_err());
+    }
 }
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, Transition};
+use snarkvm_algorithms::traits::{Prepare, SNARK};
+
+use rayon::prelude::*;
+
+///
+/// Returns `true` if the given transition proof is valid with respect to the given inner circuit ID,
+/// ledger root, and local transitions root.
+///
+/// This does not require the transaction of the transition, or the ledger state,
+/// and does not check that the ledger root exists or that the serial numbers are unspent.
+///
+pub fn verify_transition<N: Network>(
+    transition: &Transition<N>,
+    inner_circuit_id: N::InnerCircuitID,
+    ledger_root: N::LedgerRoot,
+    local_transitions_root: N::TransactionID,
+) -> bool {
+    transition.verify(inner_circuit_id, ledger_root, local_transitions_root)
+}
+
+///
+/// Returns `true` for each given transition proof that is valid with respect to its inner circuit ID,
+/// ledger root, and local transitions root, as defined by `verify_transition`.
+///
+/// The outer circuit verifying key is prepared once, and the proofs are verified in parallel.
+///
+pub fn verify_transitions<N: Network>(
+    transitions: &[(&Transition<N>, N::InnerCircuitID, N::LedgerRoot, N::TransactionID)],
+) -> Vec<bool>
+where
+    <N::OuterSNARK as SNARK>::PreparedVerifyingKey: Sync,
+{
+    let outer_verifying_key = N::outer_verifying_key().prepare();
+    transitions
+        .par_iter()
+        .map(|(transition, inner_circuit_id, ledger_root, local_transitions_root)| {
+            transition.verify_prepared(
+                &outer_verifying_key,
+                *inner_circuit_id,
+                *ledger_root,
+                *local_transitions_root,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    #[test]
+    fn test_verify_transition() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transition = transaction.transitions().first().unwrap();
+        let inner_circuit_id = transaction.inner_circuit_id();
+        let ledger_root = transaction.ledger_root();
+        let local_transitions_root = transaction.to_local_transitions_roots().unwrap()[0];
+
+        assert!(verify_transition(
+            transition,
+            inner_circuit_id,
+            ledger_root,
+            local_transitions_root
+        ));
+
+        // Ensure the transition does not verify against a different ledger root.
+        assert!(!verify_transition(
+            transition,
+            inner_circuit_id,
+            Default::default(),
+            local_transitions_root
+        ));
+    }
+
+    #[test]
+    fn test_verify_transitions() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transition = transaction.transitions().first().unwrap();
+        let inner_circuit_id = transaction.inner_circuit_id();
+        let ledger_root = transaction.ledger_root();
+        let local_transitions_root = transaction.to_local_transitions_roots().unwrap()[0];
+
+        let results = verify_transitions::<Testnet2>(&[
+            (transition, inner_circuit_id, ledger_root, local_transitions_root),
+            (transition, inner_circuit_id, ledger_root, Default::default()),
+            (transition, inner_circuit_id, ledger_root, local_transitions_root),
+        ]);
+        assert_eq!(vec![true, false, true], results);
+
+        assert!(verify_transitions::<Testnet2>(&[]).is_empty());
+    }
+}
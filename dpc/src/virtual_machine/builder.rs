@@ -27,7 +27,9 @@ pub struct ResponseBuilder<N: Network> {
     /// A list of expected outputs for a state transition.
     outputs: Vec<Output<N>>,
     /// A publicly-visible field encoding events from the state transition.
-    events: Vec<Event<N>>,
+    events: Events<N>,
+    /// The program fee, and the index of the output that pays it, if the function has a program fee.
+    program_fee: Option<(ProgramFee<N>, u8)>,
     /// A list of errors accumulated from calling the builder.
     errors: Vec<String>,
 }
@@ -40,7 +42,8 @@ impl<N: Network> ResponseBuilder<N> {
         Self {
             request: OnceCell::new(),
             outputs: Vec::with_capacity(N::NUM_OUTPUT_RECORDS),
-            events: Vec::new(),
+            events: Events::default(),
+            program_fee: None,
             errors: Vec::new(),
         }
     }
@@ -84,14 +87,35 @@ impl<N: Network> ResponseBuilder<N> {
         self
     }
 
+    ///
+    /// Adds an output paying the given program fee to the program owner into the builder.
+    ///
+    pub fn add_program_fee(mut self, program_fee: &ProgramFee<N>) -> Self {
+        if self.program_fee.is_some() {
+            self.errors.push("Builder already set a program fee".into());
+            return self;
+        }
+
+        match Output::new(program_fee.owner(), program_fee.amount(), Default::default(), None) {
+            Ok(output) => {
+                self.program_fee = Some((*program_fee, self.outputs.len() as u8));
+                self.add_output(output)
+            }
+            Err(error) => {
+                self.errors
+                    .push(format!("Builder failed to add the program fee: {}", error));
+                self
+            }
+        }
+    }
+
     ///
     /// Adds the given event into the builder.
     ///
     pub fn add_event(mut self, event: Event<N>) -> Self {
-        match self.events.len() < N::NUM_EVENTS as usize {
-            true => self.events.push(event),
-            false => self.errors.push("Builder exceeded maximum number of events".into()),
-        };
+        if self.events.push(event).is_err() {
+            self.errors.push("Builder exceeded maximum number of events".into());
+        }
         self
     }
 
@@ -139,9 +163,14 @@ impl<N: Network> ResponseBuilder<N> {
             .map(|(i, output)| {
                 let (record, encryption_randomness) = output.to_record(rng)?;
 
+                // Add the program fee event if the output record pays the program fee.
+                if matches!(self.program_fee, Some((_, index)) if index as usize == i) {
+                    events.push(Event::ProgramFee(i as u8, record.record_view_key().clone()))?;
+                }
+
                 // Add the record view key event if the output record is public.
-                if request.is_public() && events.len() < N::NUM_EVENTS as usize {
-                    events.push(Event::RecordViewKey(i as u8, record.record_view_key().clone()))
+                if request.is_public() && !events.is_full() {
+                    events.push(Event::RecordViewKey(i as u8, record.record_view_key().clone()))?;
                 }
 
                 Ok((record, encryption_randomness))
@@ -185,11 +214,15 @@ impl<N: Network> ResponseBuilder<N> {
             value_balance = value_balance.sub(record.value());
         }
 
-        // Ensure the value balance matches the fee from the request.
-        if value_balance != request.fee() {
+        // Ensure the value balance matches the fee from the request, less the program fee paid out of it.
+        let expected_value_balance = match &self.program_fee {
+            Some((program_fee, _)) => request.fee().sub(program_fee.amount()),
+            None => request.fee(),
+        };
+        if value_balance != expected_value_balance {
             return Err(anyhow!(
                 "Value balance does not match fee amount from request. Expected {} from request, found {} from response",
-                request.fee(),
+                expected_value_balance,
                 value_balance
             ));
         }
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{Network, Operation};
-use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
@@ -32,6 +32,8 @@ pub enum Event<N: Network> {
     RecordViewKey(u8, N::RecordViewKey),
     /// Emits the operation performed in a transition.
     Operation(Operation<N>),
+    /// Emits the view key for the output record at the specified index that pays the program fee.
+    ProgramFee(u8, N::RecordViewKey),
 }
 
 impl<N: Network> Event<N> {
@@ -42,6 +44,7 @@ impl<N: Network> Event<N> {
             Self::Custom(..) => 0,
             Self::RecordViewKey(..) => 1,
             Self::Operation(..) => 2,
+            Self::ProgramFee(..) => 3,
         }
     }
 }
@@ -63,7 +66,12 @@ impl<N: Network> FromBytes for Event<N> {
                 Ok(Self::RecordViewKey(index, record_view_key))
             }
             2 => Ok(Self::Operation(FromBytes::read_le(&mut reader)?)),
-            _ => unreachable!("Invalid event ID during deserialization"),
+            3 => {
+                let index: u8 = FromBytes::read_le(&mut reader)?;
+                let record_view_key: N::RecordViewKey = FromBytes::read_le(&mut reader)?;
+                Ok(Self::ProgramFee(index, record_view_key))
+            }
+            _ => Err(error("Invalid event ID during deserialization")),
         }
     }
 }
@@ -82,6 +90,10 @@ impl<N: Network> ToBytes for Event<N> {
                 record_view_key.write_le(&mut writer)
             }
             Self::Operation(operation) => operation.write_le(&mut writer),
+            Self::ProgramFee(index, record_view_key) => {
+                index.write_le(&mut writer)?;
+                record_view_key.write_le(&mut writer)
+            }
         }
     }
 }
@@ -127,6 +139,13 @@ impl<N: Network> Serialize for Event<N> {
                     event.serialize_field("operation", &operation)?;
                     event.end()
                 }
+                Self::ProgramFee(ref index, ref record_view_key) => {
+                    let mut event = serializer.serialize_struct("Event", 3)?;
+                    event.serialize_field("id", &self.id())?;
+                    event.serialize_field("index", &index)?;
+                    event.serialize_field("record_view_key", &record_view_key)?;
+                    event.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -153,10 +172,18 @@ impl<'de, N: Network> Deserialize<'de> for Event<N> {
                     2 => Ok(Self::Operation(
                         serde_json::from_value(event["operation"].clone()).map_err(de::Error::custom)?,
                     )),
+                    3 => Ok(Self::ProgramFee(
+                        serde_json::from_value(event["index"].clone()).map_err(de::Error::custom)?,
+                        serde_json::from_value(event["record_view_key"].clone()).map_err(de::Error::custom)?,
+                    )),
                     _ => unreachable!(format!("Invalid event id {}", event_id)),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "event"),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "event",
+                N::MAX_EVENT_SIZE_IN_BYTES,
+            ),
         }
     }
 }
@@ -196,4 +223,9 @@ mod tests {
         assert_eq!(expected_event, Event::read_le(&expected_bytes[..]).unwrap());
         assert_eq!(expected_event, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_event_read_le_invalid_id() {
+        assert!(Event::<Testnet2>::read_le(&[u8::MAX][..]).is_err());
+    }
 }
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Event, Network, VMError};
+use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use anyhow::{anyhow, Result};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    io::{Read, Result as IoResult, Write},
+    ops::Deref,
+};
+
+/// A list of events, containing at most `N::NUM_EVENTS` events.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Events<N: Network>(Vec<Event<N>>);
+
+impl<N: Network> Events<N> {
+    /// Initializes a new list of events, ensuring it contains at most `N::NUM_EVENTS` events.
+    #[inline]
+    pub fn new(events: Vec<Event<N>>) -> Result<Self> {
+        match events.len() <= N::NUM_EVENTS as usize {
+            true => Ok(Self(events)),
+            false => Err(anyhow!(
+                "Found {} events, exceeding the limit of {}",
+                events.len(),
+                N::NUM_EVENTS
+            )),
+        }
+    }
+
+    /// Appends the given event, if the list has not reached `N::NUM_EVENTS` events.
+    #[inline]
+    pub fn push(&mut self, event: Event<N>) -> Result<()> {
+        match self.0.len() < N::NUM_EVENTS as usize {
+            true => {
+                self.0.push(event);
+                Ok(())
+            }
+            false => Err(anyhow!("The list of events has reached its maximum size")),
+        }
+    }
+
+    /// Returns `true` if the list has reached `N::NUM_EVENTS` events.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.0.len() >= N::NUM_EVENTS as usize
+    }
+}
+
+impl<N: Network> Default for Events<N> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<N: Network> Deref for Events<N> {
+    type Target = [Event<N>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a, N: Network> IntoIterator for &'a Events<N> {
+    type IntoIter = std::slice::Iter<'a, Event<N>>;
+    type Item = &'a Event<N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<N: Network> FromBytes for Events<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_events: u16 = FromBytes::read_le(&mut reader)?;
+        if num_events > N::NUM_EVENTS {
+            return Err(VMError::Message(format!("Found {} events, exceeding the limit", num_events)).into());
+        }
+
+        let mut events = Vec::with_capacity(num_events as usize);
+        for _ in 0..num_events {
+            events.push(FromBytes::read_le(&mut reader)?);
+        }
+        Ok(Self(events))
+    }
+}
+
+impl<N: Network> ToBytes for Events<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.0.len() as u16).write_le(&mut writer)?;
+        self.0.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for Events<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => self.0.serialize(serializer),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Events<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => Self::new(Vec::<Event<N>>::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding_bounded(
+                deserializer,
+                "events",
+                2 + N::NUM_EVENTS as usize * N::MAX_EVENT_SIZE_IN_BYTES,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, NetworkConstants, Operation};
+
+    #[test]
+    fn test_events_new() {
+        let events = vec![Event::<Testnet2>::Operation(Operation::Noop); Testnet2::NUM_EVENTS as usize];
+        assert!(Events::<Testnet2>::new(events.clone()).is_ok());
+        assert!(Events::<Testnet2>::new([events, vec![Event::Custom(vec![])]].concat()).is_err());
+    }
+
+    #[test]
+    fn test_events_push() {
+        let mut events = Events::<Testnet2>::default();
+        for _ in 0..Testnet2::NUM_EVENTS {
+            assert!(!events.is_full());
+            events.push(Event::Operation(Operation::Noop)).unwrap();
+        }
+        assert!(events.is_full());
+        assert!(events.push(Event::Operation(Operation::Noop)).is_err());
+        assert_eq!(Testnet2::NUM_EVENTS as usize, events.len());
+    }
+
+    #[test]
+    fn test_events_read_le() {
+        let expected_events = Events::<Testnet2>::new(vec![Event::Operation(Operation::Noop)]).unwrap();
+        let expected_bytes = expected_events.to_bytes_le().unwrap();
+        assert_eq!(expected_events, Events::read_le(&expected_bytes[..]).unwrap());
+
+        // Ensure a number of events exceeding the limit fails to deserialize.
+        let mut candidate_bytes = (Testnet2::NUM_EVENTS + 1).to_bytes_le().unwrap();
+        for _ in 0..=Testnet2::NUM_EVENTS {
+            candidate_bytes.extend_from_slice(&Event::<Testnet2>::Operation(Operation::Noop).to_bytes_le().unwrap());
+        }
+        assert!(Events::<Testnet2>::read_le(&candidate_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_events_serde_json() {
+        let expected_events = Events::<Testnet2>::new(vec![Event::Operation(Operation::Noop)]).unwrap();
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected_events).unwrap();
+        assert_eq!(candidate_string, serde_json::to_string(&*expected_events).unwrap());
+
+        // Deserialize
+        assert_eq!(expected_events, serde_json::from_str(&candidate_string).unwrap());
+
+        // Ensure a number of events exceeding the limit fails to deserialize.
+        let events = vec![Event::<Testnet2>::Operation(Operation::Noop); Testnet2::NUM_EVENTS as usize + 1];
+        let candidate_string = serde_json::to_string(&events).unwrap();
+        assert!(serde_json::from_str::<Events<Testnet2>>(&candidate_string).is_err());
+    }
+}
@@ -14,13 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Network;
+use crate::{Network, VMError};
 use snarkvm_algorithms::merkle_tree::MerklePath;
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use std::io::{Read, Result as IoResult, Write};
 
-/// Program ID, program path, verifying key, and proof.
+/// Program ID, program path, verifying key, proof, and the payload commitments exposed by the proof.
 #[derive(Derivative)]
 #[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
 pub struct Execution<N: Network> {
@@ -29,6 +29,7 @@ pub struct Execution<N: Network> {
     #[derivative(Debug = "ignore")]
     pub verifying_key: N::ProgramVerifyingKey,
     pub proof: N::ProgramProof,
+    pub payload_commitments: Vec<N::Commitment>,
 }
 
 impl<N: Network> FromBytes for Execution<N> {
@@ -39,11 +40,26 @@ impl<N: Network> FromBytes for Execution<N> {
         let verifying_key = FromBytes::read_le(&mut reader)?;
         let proof = FromBytes::read_le(&mut reader)?;
 
+        let num_payload_commitments: u16 = FromBytes::read_le(&mut reader)?;
+        if num_payload_commitments as usize > N::NUM_PAYLOAD_COMMITMENTS {
+            return Err(VMError::Message(format!(
+                "Found {} payload commitments, exceeding the limit",
+                num_payload_commitments
+            ))
+            .into());
+        }
+
+        let mut payload_commitments = Vec::with_capacity(num_payload_commitments as usize);
+        for _ in 0..num_payload_commitments {
+            payload_commitments.push(FromBytes::read_le(&mut reader)?);
+        }
+
         Ok(Self {
             program_id,
             program_path,
             verifying_key,
             proof,
+            payload_commitments,
         })
     }
 }
@@ -54,6 +70,8 @@ impl<N: Network> ToBytes for Execution<N> {
         self.program_id.write_le(&mut writer)?;
         self.program_path.write_le(&mut writer)?;
         self.verifying_key.write_le(&mut writer)?;
-        self.proof.write_le(&mut writer)
+        self.proof.write_le(&mut writer)?;
+        (self.payload_commitments.len() as u16).write_le(&mut writer)?;
+        self.payload_commitments.write_le(&mut writer)
     }
 }
@@ -23,6 +23,9 @@ pub use builder::*;
 pub mod event;
 pub use event::*;
 
+pub mod events;
+pub use events::*;
+
 pub mod execution;
 pub use execution::*;
 
@@ -44,8 +47,17 @@ pub use output::*;
 pub mod program;
 pub use program::*;
 
+pub mod program_fee;
+pub use program_fee::*;
+
 pub mod program_public_variables;
 pub use program_public_variables::*;
 
+pub mod prover;
+pub use prover::*;
+
+pub mod upgrade_policy;
+pub use upgrade_policy::*;
+
 pub mod virtual_machine;
 pub use virtual_machine::*;
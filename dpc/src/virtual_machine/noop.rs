@@ -53,7 +53,7 @@ impl<N: Network> Function<N> for Noop<N> {
         cs: &mut CS,
         public: &ProgramPublicVariables<N>,
     ) -> Result<(), SynthesisError> {
-        SynthesizedCircuit::Noop(*public).generate_constraints(cs)?;
+        SynthesizedCircuit::Noop(public.clone()).generate_constraints(cs)?;
         Ok(())
     }
 
@@ -65,7 +65,7 @@ impl<N: Network> Function<N> for Noop<N> {
     ) -> Result<N::ProgramProof> {
         let proof = <N::ProgramSNARK as SNARK>::prove(
             N::noop_circuit_proving_key(),
-            &SynthesizedCircuit::Noop(public),
+            &SynthesizedCircuit::Noop(public.clone()),
             &mut rand::thread_rng(),
         )?
         .into();
@@ -112,6 +112,13 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for SynthesizedCircu
                     || Ok(public.transition_id),
                 )?;
 
+                for (i, payload_commitment) in public.payload_commitments.iter().enumerate() {
+                    let _payload_commitment = <N::CommitmentGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(
+                        cs.ns(|| format!("Alloc the payload commitment {}", i)),
+                        || Ok(*payload_commitment),
+                    )?;
+                }
+
                 Ok(())
             } // Self::Blank(logic) => {
               //     let synthesizer = Self::Assigned(logic.clone(), Default::default());
@@ -32,6 +32,8 @@ pub struct Output<N: Network> {
     payload: Payload<N>,
     /// The program that was run.
     program_id: N::ProgramID,
+    /// The locktime of the output record.
+    locktime: Option<Locktime>,
 }
 
 impl<N: Network> Output<N> {
@@ -61,9 +63,16 @@ impl<N: Network> Output<N> {
             value,
             payload,
             program_id,
+            locktime: None,
         })
     }
 
+    /// Returns the output with the given locktime, before which the output record may not be consumed.
+    pub fn with_locktime(mut self, locktime: Locktime) -> Self {
+        self.locktime = Some(locktime);
+        self
+    }
+
     /// Returns `true` if the program ID is the noop program.
     pub fn is_noop(&self) -> bool {
         self.program_id == *N::noop_program_id()
@@ -74,11 +83,12 @@ impl<N: Network> Output<N> {
         // Generate the ciphertext parameters.
         let (randomness, randomizer, record_view_key) =
             N::account_encryption_scheme().generate_asymmetric_key(&*self.address, rng);
-        let record = Record::from(
+        let record = Record::from_with_locktime(
             self.address,
             self.value,
             self.payload.clone(),
             self.program_id,
+            self.locktime,
             randomizer.into(),
             record_view_key.into(),
         )?;
@@ -104,6 +114,11 @@ impl<N: Network> Output<N> {
     pub fn program_id(&self) -> N::ProgramID {
         self.program_id
     }
+
+    /// Returns the locktime of the output record, if it has one.
+    pub fn locktime(&self) -> Option<Locktime> {
+        self.locktime
+    }
 }
 
 // #[cfg(test)]
@@ -19,7 +19,7 @@ use snarkvm_algorithms::{
     merkle_tree::{MerklePath, MerkleTree},
     prelude::*,
 };
-use snarkvm_utilities::has_duplicates;
+use snarkvm_utilities::{has_duplicates, to_bytes_le, ToBytes};
 
 use anyhow::{anyhow, Result};
 use std::{collections::HashMap, sync::Arc};
@@ -89,6 +89,17 @@ impl<N: Network> Program<N> {
         }
     }
 
+    /// Returns the function indices and function IDs in the program, ordered by function index.
+    pub fn functions(&self) -> Vec<(u8, N::FunctionID)> {
+        let mut functions: Vec<_> = self
+            .functions
+            .iter()
+            .map(|(function_id, (index, _))| (*index, *function_id))
+            .collect();
+        functions.sort_by_key(|(index, _)| *index);
+        functions
+    }
+
     /// Returns the program path (the Merkle path for a given function ID).
     pub fn to_program_path(&self, function_id: &N::FunctionID) -> Result<MerklePath<N::ProgramIDParameters>> {
         match self.get_function_index(function_id) {
@@ -96,6 +107,142 @@ impl<N: Network> Program<N> {
             _ => Err(MerkleError::MissingLeaf(format!("{}", function_id)).into()),
         }
     }
+
+    /// Returns `true` if the given program path proves the function ID is in the program with the given program ID.
+    ///
+    /// The path is checked against the network program ID parameters, ignoring the parameters in the given path.
+    pub fn verify_program_path(
+        program_id: &N::ProgramID,
+        function_id: &N::FunctionID,
+        program_path: &MerklePath<N::ProgramIDParameters>,
+    ) -> bool {
+        let program_path = MerklePath::<N::ProgramIDParameters> {
+            parameters: Arc::new(N::program_id_parameters().clone()),
+            path: program_path.path.clone(),
+            leaf_index: program_path.leaf_index,
+        };
+
+        match program_path.verify(program_id, function_id) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify program path: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns the upgrade policy of the function with the given function ID, if it exists.
+    pub fn upgrade_policy(&self, function_id: &N::FunctionID) -> Result<UpgradePolicy<N>> {
+        Ok(self.to_function(function_id)?.upgrade_policy())
+    }
+
+    /// Returns `true` if none of the functions in the program may be upgraded.
+    pub fn is_immutable(&self) -> bool {
+        self.functions
+            .values()
+            .all(|(_, function)| function.upgrade_policy().is_immutable())
+    }
+
+    ///
+    /// Returns the commitment to the upgrade policies of the functions in the program.
+    ///
+    /// If any function may be upgraded, the commitment is the last leaf of the program tree,
+    /// after the function IDs, so the program ID commits to the upgrade policy of each function.
+    ///
+    pub fn to_upgrade_policies_commitment(&self) -> Result<N::FunctionID> {
+        let mut bytes = Vec::new();
+        for (index, function_id) in self.functions() {
+            index.write_le(&mut bytes)?;
+            function_id.write_le(&mut bytes)?;
+            self.upgrade_policy(&function_id)?.write_le(&mut bytes)?;
+        }
+        Ok(N::function_id_crh().hash(&bytes)?.into())
+    }
+
+    ///
+    /// Returns the message that the program owner signs to upgrade the given function
+    /// to the given upgraded function, for an upgrade announced at the given announcement height.
+    ///
+    pub fn to_upgrade_message(
+        &self,
+        function_id: &N::FunctionID,
+        upgraded_function_id: &N::FunctionID,
+        announcement_height: u32,
+    ) -> Result<Vec<u8>> {
+        Ok(to_bytes_le![
+            self.program_id(),
+            function_id,
+            upgraded_function_id,
+            announcement_height
+        ]?)
+    }
+
+    ///
+    /// Returns the program with the given function replaced by the given upgraded function,
+    /// which has a new program ID, if the upgrade policy of the function permits the upgrade
+    /// at the given block height, for an upgrade announced at the given announcement height.
+    /// The given signature must be a signature of the upgrade message by the program owner.
+    ///
+    pub fn upgrade(
+        &self,
+        function_id: &N::FunctionID,
+        function: Arc<dyn Function<N>>,
+        signature: &N::AccountSignature,
+        announcement_height: u32,
+        block_height: u32,
+    ) -> Result<Self> {
+        let message = self.to_upgrade_message(function_id, &function.function_id(), announcement_height)?;
+        self.upgrade_policy(function_id)?
+            .check_upgrade(&message, signature, announcement_height, block_height)?;
+
+        // Replace the function at its function index, so the order of the functions is preserved.
+        let functions = self
+            .functions()
+            .into_iter()
+            .map(|(_, id)| match id == *function_id {
+                true => function.clone(),
+                false => self.functions[&id].1.clone(),
+            })
+            .collect();
+        Self::new(functions)
+    }
+
+    ///
+    /// Returns `Ok(())` if the given upgraded program only replaces functions of the given previous program,
+    /// as permitted by their upgrade policies for the given heights, as defined by `Program::upgrade`.
+    /// The given signatures are the signatures of the upgrade messages of the replaced functions, in order.
+    ///
+    pub fn verify_upgrade(
+        previous: &Self,
+        upgraded: &Self,
+        signatures: &[N::AccountSignature],
+        announcement_height: u32,
+        block_height: u32,
+    ) -> Result<()> {
+        let previous_functions = previous.functions();
+        let upgraded_functions = upgraded.functions();
+        if previous_functions.len() != upgraded_functions.len() {
+            return Err(VMError::UpgradeNotPermitted("the number of functions in the program changed".into()).into());
+        }
+
+        let replaced_functions = previous_functions
+            .iter()
+            .zip(upgraded_functions.iter())
+            .filter(|((_, previous_id), (_, upgraded_id))| previous_id != upgraded_id)
+            .collect::<Vec<_>>();
+        if replaced_functions.len() != signatures.len() {
+            return Err(VMError::UpgradeNotPermitted("each replaced function requires one signature".into()).into());
+        }
+
+        for (((_, previous_id), (_, upgraded_id)), signature) in replaced_functions.into_iter().zip(signatures) {
+            let message = previous.to_upgrade_message(previous_id, upgraded_id, announcement_height)?;
+            previous
+                .upgrade_policy(previous_id)?
+                .check_upgrade(&message, signature, announcement_height, block_height)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<N: Network> Program<N> {
@@ -115,6 +262,7 @@ impl<N: Network> Program<N> {
             .insert(function.function_id(), (self.last_function_index, function));
 
         self.last_function_index += 1;
+        self.commit_upgrade_policies()?;
         Ok(self.last_function_index - 1)
     }
 
@@ -154,9 +302,20 @@ impl<N: Network> Program<N> {
         self.last_function_index += num_functions as u8;
         let end_index = self.last_function_index - 1;
 
+        self.commit_upgrade_policies()?;
         Ok((start_index, end_index))
     }
 
+    /// Adds the commitment to the upgrade policies as the last leaf of the tree, if any function may be upgraded.
+    /// The commitment is overwritten by the next function added to the tree, and then added again.
+    fn commit_upgrade_policies(&mut self) -> Result<()> {
+        if !self.is_immutable() {
+            let commitment = self.to_upgrade_policies_commitment()?;
+            self.tree = self.tree.rebuild(self.last_function_index as usize, &[commitment])?;
+        }
+        Ok(())
+    }
+
     /// Returns the function given the function index, if it exists.
     pub fn find_function_by_index(&self, function_index: u8) -> Option<&Arc<dyn Function<N>>> {
         self.functions
@@ -172,3 +331,170 @@ impl<N: Network> Program<N> {
         self.functions.get(function_id).map(|(index, _)| *index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+    use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+
+    use rand::{thread_rng, CryptoRng, Rng};
+
+    /// A noop function with the given function ID and upgrade policy.
+    struct UpgradableNoop(<Testnet2 as Network>::FunctionID, UpgradePolicy<Testnet2>);
+
+    impl UpgradableNoop {
+        fn new<R: Rng + CryptoRng>(policy: UpgradePolicy<Testnet2>, rng: &mut R) -> Arc<dyn Function<Testnet2>> {
+            Arc::new(Self(UniformRand::rand(rng), policy))
+        }
+    }
+
+    impl Function<Testnet2> for UpgradableNoop {
+        fn function_id(&self) -> <Testnet2 as Network>::FunctionID {
+            self.0
+        }
+
+        fn function_type(&self) -> FunctionType {
+            FunctionType::Noop
+        }
+
+        fn upgrade_policy(&self) -> UpgradePolicy<Testnet2> {
+            self.1
+        }
+
+        fn execute(
+            &self,
+            public: ProgramPublicVariables<Testnet2>,
+            private: &dyn ProgramPrivateVariables<Testnet2>,
+        ) -> Result<<Testnet2 as Network>::ProgramProof> {
+            Noop::<Testnet2>::new().execute(public, private)
+        }
+
+        fn verify(
+            &self,
+            public: &ProgramPublicVariables<Testnet2>,
+            proof: &<Testnet2 as Network>::ProgramProof,
+        ) -> bool {
+            Noop::<Testnet2>::new().verify(public, proof)
+        }
+
+        fn synthesize<CS: ConstraintSystem<<Testnet2 as Network>::InnerScalarField>>(
+            &self,
+            cs: &mut CS,
+            public: &ProgramPublicVariables<Testnet2>,
+        ) -> Result<(), SynthesisError> {
+            Noop::<Testnet2>::new().synthesize(cs, public)
+        }
+    }
+
+    #[test]
+    fn test_program_path() {
+        let program = Program::<Testnet2>::new_noop().unwrap();
+        let program_id = program.program_id();
+        let function_id = *Testnet2::noop_function_id();
+
+        assert_eq!(vec![(0, function_id)], program.functions());
+
+        let verify = Program::<Testnet2>::verify_program_path;
+        let program_path = program.to_program_path(&function_id).unwrap();
+        assert!(verify(&program_id, &function_id, &program_path));
+
+        // Ensure the program path does not verify for a different function ID or program ID.
+        let other_id = Default::default();
+        assert!(!verify(&program_id, &other_id, &program_path));
+        assert!(!verify(&Default::default(), &function_id, &program_path));
+
+        // Ensure the program path serializes and deserializes.
+        let bytes = program_path.to_bytes_le().unwrap();
+        let recovered = MerklePath::<<Testnet2 as Network>::ProgramIDParameters>::read_le(&bytes[..]).unwrap();
+        assert_eq!(bytes, recovered.to_bytes_le().unwrap());
+        assert!(verify(&program_id, &function_id, &recovered));
+
+        let string = serde_json::to_string(&program_path).unwrap();
+        let recovered: MerklePath<<Testnet2 as Network>::ProgramIDParameters> = serde_json::from_str(&string).unwrap();
+        assert_eq!(bytes, recovered.to_bytes_le().unwrap());
+        assert!(verify(&program_id, &function_id, &recovered));
+    }
+
+    #[test]
+    fn test_program_upgrade() {
+        let rng = &mut thread_rng();
+        let owner = Account::<Testnet2>::new(rng);
+        let other = Account::<Testnet2>::new(rng);
+
+        let immutable = UpgradableNoop::new(UpgradePolicy::Immutable, rng);
+        let timelocked_policy = UpgradePolicy::Timelocked {
+            owner: owner.address(),
+            delay_in_blocks: 100,
+        };
+        let timelocked = UpgradableNoop::new(timelocked_policy, rng);
+        let program = Program::<Testnet2>::new(vec![immutable.clone(), timelocked.clone()]).unwrap();
+        assert!(!program.is_immutable());
+        assert!(program.upgrade_policy(&immutable.function_id()).unwrap().is_immutable());
+        assert_eq!(
+            Some(owner.address()),
+            program.upgrade_policy(&timelocked.function_id()).unwrap().owner()
+        );
+
+        // Ensure the program ID commits to the upgrade policies of its functions.
+        let mutable = UpgradableNoop::new(UpgradePolicy::OwnerUpgradable { owner: owner.address() }, rng);
+        let same_function: Arc<dyn Function<Testnet2>> =
+            Arc::new(UpgradableNoop(timelocked.function_id(), UpgradePolicy::Immutable));
+        let immutable_program = Program::<Testnet2>::new(vec![immutable.clone(), same_function]).unwrap();
+        assert_ne!(program.program_id(), immutable_program.program_id());
+
+        // Ensure the commitment is the last leaf of the program tree, as functions are added.
+        let mut incremental = Program::<Testnet2>::new(vec![immutable.clone(), timelocked.clone()]).unwrap();
+        incremental.add(mutable.clone()).unwrap();
+        let expected = Program::<Testnet2>::new(vec![immutable.clone(), timelocked.clone(), mutable]).unwrap();
+        assert_eq!(expected.program_id(), incremental.program_id());
+        let program_path = program.to_program_path(&timelocked.function_id()).unwrap();
+        assert!(Program::verify_program_path(&program.program_id(), &timelocked.function_id(), &program_path));
+
+        // Ensure an immutable function may not be upgraded.
+        let function = UpgradableNoop::new(UpgradePolicy::Immutable, rng);
+        let sign = |account: &Account<Testnet2>, function_id, announcement_height| {
+            let message = program
+                .to_upgrade_message(&function_id, &function.function_id(), announcement_height)
+                .unwrap();
+            account.private_key().sign(&message, &mut thread_rng()).unwrap()
+        };
+        let signature = sign(&owner, immutable.function_id(), 0);
+        assert!(program
+            .upgrade(&immutable.function_id(), function.clone(), &signature, 0, 100)
+            .is_err());
+
+        // Ensure a timelocked function may only be upgraded by the owner, once its delay has passed.
+        let signature = sign(&owner, timelocked.function_id(), 0);
+        assert!(program
+            .upgrade(&timelocked.function_id(), function.clone(), &signature, 0, 99)
+            .is_err());
+        assert!(program
+            .upgrade(&timelocked.function_id(), function.clone(), &signature, 1, 101)
+            .is_err());
+        let other_signature = sign(&other, timelocked.function_id(), 0);
+        assert!(program
+            .upgrade(&timelocked.function_id(), function.clone(), &other_signature, 0, 100)
+            .is_err());
+
+        let upgraded = program
+            .upgrade(&timelocked.function_id(), function.clone(), &signature, 0, 100)
+            .unwrap();
+        assert_ne!(program.program_id(), upgraded.program_id());
+        assert_eq!(
+            vec![(0, immutable.function_id()), (1, function.function_id())],
+            upgraded.functions()
+        );
+        assert!(upgraded.is_immutable());
+
+        // Ensure the upgrade verifies against the upgrade policies of the previous program.
+        let verify = Program::<Testnet2>::verify_upgrade;
+        assert!(verify(&program, &upgraded, &[signature.clone()], 0, 100).is_ok());
+        assert!(verify(&program, &upgraded, &[signature.clone()], 0, 99).is_err());
+        assert!(verify(&program, &upgraded, &[other_signature], 0, 100).is_err());
+        assert!(verify(&program, &upgraded, &[], 0, 100).is_err());
+        assert!(verify(&upgraded, &program, &[signature.clone()], 0, 100).is_err());
+        assert!(verify(&program, &Program::new_noop().unwrap(), &[signature], 0, 100).is_err());
+    }
+}
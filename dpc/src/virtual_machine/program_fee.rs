@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, AleoAmount, Network};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::Result;
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// A fee paid to the owner of a program on each execution of a function, as declared by the function.
+///
+/// The fee is paid out of the fee of the request, in an output record owned by the program owner.
+/// The record view key of the output record is emitted in a `ProgramFee` event,
+/// so that anyone may check the fee was paid.
+///
+/// If the network enables program fees, the function ID commits to the program fee, and the program fee
+/// revealed by the event is a public input of the transition proof. The outer circuit checks it against
+/// the function, and the inner circuit checks it is paid by an output record, so the event is required.
+///
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "N: Network"),
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ProgramFee<N: Network> {
+    owner: Address<N>,
+    amount: AleoAmount,
+}
+
+impl<N: Network> ProgramFee<N> {
+    /// Initializes a new program fee of the given amount, payable to the given program owner.
+    pub fn new(owner: Address<N>, amount: AleoAmount) -> Self {
+        Self { owner, amount }
+    }
+
+    /// Returns the address of the program owner.
+    pub fn owner(&self) -> Address<N> {
+        self.owner
+    }
+
+    /// Returns the amount of the program fee.
+    pub fn amount(&self) -> AleoAmount {
+        self.amount
+    }
+
+    /// Returns the given program fee as a public input of the transition proof,
+    /// which is zero if there is no program fee.
+    pub(crate) fn to_input_bytes(program_fee: Option<&Self>) -> Result<Vec<u8>> {
+        match program_fee {
+            Some(program_fee) => Ok(program_fee.to_bytes_le()?),
+            None => Ok(vec![0u8; N::ADDRESS_SIZE_IN_BYTES + 8]),
+        }
+    }
+}
+
+impl<N: Network> FromBytes for ProgramFee<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let owner = FromBytes::read_le(&mut reader)?;
+        let amount = FromBytes::read_le(&mut reader)?;
+        Ok(Self { owner, amount })
+    }
+}
+
+impl<N: Network> ToBytes for ProgramFee<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.owner.write_le(&mut writer)?;
+        self.amount.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme, Event, Output, Record, Request, ResponseBuilder};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_program_fee_output() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+        let program_owner = Account::<Testnet2>::new(rng);
+
+        let amount = AleoAmount::from_i64(100);
+        let program_fee = ProgramFee::<Testnet2>::new(program_owner.address(), AleoAmount::from_i64(10));
+
+        // Ensure the program fee round-trips through bytes.
+        let program_fee_bytes = program_fee.to_bytes_le().unwrap();
+        assert_eq!(program_fee, ProgramFee::read_le(&program_fee_bytes[..]).unwrap());
+
+        // Pay the program fee out of the fee of the request.
+        let request = Request::new_coinbase(recipient.address(), amount, false, rng).unwrap();
+        let response = ResponseBuilder::new()
+            .add_request(request)
+            .add_output(Output::new(recipient.address(), amount, Default::default(), None).unwrap())
+            .add_program_fee(&program_fee)
+            .build(rng)
+            .unwrap();
+        assert_eq!(
+            AleoAmount::ZERO.sub(amount).sub(program_fee.amount()),
+            response.value_balance()
+        );
+
+        // Ensure the program fee event reveals the output record paying the program fee.
+        let ciphertexts = response.ciphertexts();
+        let record = response
+            .events()
+            .iter()
+            .find_map(|event| match event {
+                Event::ProgramFee(index, record_view_key) => {
+                    Some(Record::from_record_view_key(record_view_key, &ciphertexts[*index as usize]).unwrap())
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(program_fee.owner(), record.owner());
+        assert_eq!(program_fee.amount(), record.value());
+    }
+}
@@ -19,24 +19,29 @@ use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 
 #[derive(Derivative)]
 #[derivative(
-    Copy(bound = "N: Network"),
     Clone(bound = "N: Network"),
     Debug(bound = "N: Network"),
     Default(bound = "N: Network")
 )]
 pub struct ProgramPublicVariables<N: Network> {
     pub transition_id: N::TransitionID,
+    /// The commitments to the payloads of the first `N::NUM_PAYLOAD_COMMITMENTS` records of the transition.
+    pub payload_commitments: Vec<N::Commitment>,
 }
 
 impl<N: Network> ProgramPublicVariables<N> {
     pub fn blank() -> Self {
         Self {
             transition_id: Default::default(),
+            payload_commitments: vec![Default::default(); N::NUM_PAYLOAD_COMMITMENTS],
         }
     }
 
-    pub fn new(transition_id: N::TransitionID) -> Self {
-        Self { transition_id }
+    pub fn new(transition_id: N::TransitionID, payload_commitments: Vec<N::Commitment>) -> Self {
+        Self {
+            transition_id,
+            payload_commitments,
+        }
     }
 }
 
@@ -45,6 +50,9 @@ impl<N: Network> ToConstraintField<N::InnerScalarField> for ProgramPublicVariabl
     fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
         let mut v = ToConstraintField::<N::InnerScalarField>::to_field_elements(&[0u8][..])?;
         v.extend_from_slice(&self.transition_id.to_field_elements()?);
+        for payload_commitment in &self.payload_commitments {
+            v.extend_from_slice(&payload_commitment.to_field_elements()?);
+        }
         Ok(v)
     }
 }
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{InnerCircuit, Network, OuterCircuit, SynthesizedCircuit};
+use snarkvm_algorithms::SNARK;
+
+use anyhow::Result;
+use rand::rngs::StdRng;
+
+///
+/// A handle to the prover of the transitions in the virtual machine.
+///
+/// The virtual machine computes the program, inner, and outer proofs of each transition
+/// through this handle. An integrator can implement this trait to offload proving,
+/// for example to a GPU or to a remote proving service, and pass it to `VirtualMachine::new_with_prover`.
+///
+pub trait TransitionProver<N: Network>: Send + Sync {
+    /// Returns the name of the prover.
+    fn name(&self) -> &str;
+
+    /// Returns a proof of the given program circuit.
+    fn prove_program(
+        &self,
+        proving_key: &N::ProgramProvingKey,
+        circuit: &SynthesizedCircuit<N>,
+        rng: &mut StdRng,
+    ) -> Result<<N::ProgramSNARK as SNARK>::Proof> {
+        Ok(N::ProgramSNARK::prove(proving_key, circuit, rng)?)
+    }
+
+    /// Returns a proof of the given inner circuit.
+    fn prove_inner(
+        &self,
+        proving_key: &<N::InnerSNARK as SNARK>::ProvingKey,
+        circuit: &InnerCircuit<N>,
+        rng: &mut StdRng,
+    ) -> Result<<N::InnerSNARK as SNARK>::Proof> {
+        Ok(N::InnerSNARK::prove(proving_key, circuit, rng)?)
+    }
+
+    /// Returns a proof of the given outer circuit.
+    fn prove_outer(
+        &self,
+        proving_key: &<N::OuterSNARK as SNARK>::ProvingKey,
+        circuit: &OuterCircuit<N>,
+        rng: &mut StdRng,
+    ) -> Result<<N::OuterSNARK as SNARK>::Proof> {
+        Ok(N::OuterSNARK::prove(proving_key, circuit, rng)?)
+    }
+}
+
+/// The default prover, which computes the proofs on the CPU of the virtual machine.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CpuProver;
+
+impl<N: Network> TransitionProver<N> for CpuProver {
+    fn name(&self) -> &str {
+        "cpu"
+    }
+}
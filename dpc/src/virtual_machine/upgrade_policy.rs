@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, Network, VMError};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::io::{Error, ErrorKind, Read, Result as IoResult, Write};
+
+///
+/// The policy that determines whether the code of a program function may be upgraded, as declared by the function.
+///
+/// An upgrade replaces the function in its program, which derives a new program ID.
+/// Records of the previous program ID are unaffected, so a user may check the policy of a function
+/// before spending records with it, to learn whether the function code may change.
+/// The program ID commits to the policies of its functions, and an upgrade must be signed by the program owner.
+///
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "N: Network"),
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub enum UpgradePolicy<N: Network> {
+    /// The function may never be upgraded.
+    Immutable,
+    /// The function may be upgraded by the program owner at any block height.
+    OwnerUpgradable { owner: Address<N> },
+    /// The function may be upgraded by the program owner, once the given number of blocks
+    /// have passed since the upgrade was announced.
+    Timelocked { owner: Address<N>, delay_in_blocks: u32 },
+}
+
+impl<N: Network> UpgradePolicy<N> {
+    /// Returns `true` if the function may never be upgraded.
+    pub fn is_immutable(&self) -> bool {
+        matches!(self, Self::Immutable)
+    }
+
+    /// Returns the address of the program owner, if the function may be upgraded.
+    pub fn owner(&self) -> Option<Address<N>> {
+        match self {
+            Self::Immutable => None,
+            Self::OwnerUpgradable { owner } | Self::Timelocked { owner, .. } => Some(*owner),
+        }
+    }
+
+    ///
+    /// Returns `Ok(())` if the given signature of the upgrade message is from the program owner,
+    /// and the function may be upgraded at the given block height, for an upgrade that was announced
+    /// at the given announcement height.
+    ///
+    pub fn check_upgrade(
+        &self,
+        message: &[u8],
+        signature: &N::AccountSignature,
+        announcement_height: u32,
+        block_height: u32,
+    ) -> Result<(), VMError> {
+        let (owner, delay_in_blocks) = match self {
+            Self::Immutable => return Err(VMError::UpgradeNotPermitted("the function is immutable".into())),
+            Self::OwnerUpgradable { owner } => (owner, 0),
+            Self::Timelocked { owner, delay_in_blocks } => (owner, *delay_in_blocks),
+        };
+
+        if !matches!(owner.verify_signature(message, signature), Ok(true)) {
+            return Err(VMError::UpgradeNotPermitted(format!(
+                "the upgrade is not signed by the program owner {}",
+                owner
+            )));
+        }
+
+        if block_height < announcement_height.saturating_add(delay_in_blocks) {
+            return Err(VMError::UpgradeNotPermitted(format!(
+                "the upgrade announced at block {} is timelocked for {} blocks",
+                announcement_height, delay_in_blocks
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Default for UpgradePolicy<N> {
+    fn default() -> Self {
+        Self::Immutable
+    }
+}
+
+impl<N: Network> FromBytes for UpgradePolicy<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant: u8 = FromBytes::read_le(&mut reader)?;
+        match variant {
+            0 => Ok(Self::Immutable),
+            1 => Ok(Self::OwnerUpgradable {
+                owner: FromBytes::read_le(&mut reader)?,
+            }),
+            2 => {
+                let owner = FromBytes::read_le(&mut reader)?;
+                let delay_in_blocks = FromBytes::read_le(&mut reader)?;
+                Ok(Self::Timelocked { owner, delay_in_blocks })
+            }
+            _ => Err(Error::new(ErrorKind::Other, "Invalid upgrade policy")),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for UpgradePolicy<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Immutable => 0u8.write_le(&mut writer),
+            Self::OwnerUpgradable { owner } => {
+                1u8.write_le(&mut writer)?;
+                owner.write_le(&mut writer)
+            }
+            Self::Timelocked { owner, delay_in_blocks } => {
+                2u8.write_le(&mut writer)?;
+                owner.write_le(&mut writer)?;
+                delay_in_blocks.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_check_upgrade() {
+        let rng = &mut thread_rng();
+        let owner_account = Account::<Testnet2>::new(rng);
+        let owner = owner_account.address();
+
+        let message = b"upgrade";
+        let signature = owner_account.private_key().sign(message, rng).unwrap();
+        let other_signature = Account::<Testnet2>::new(rng).private_key().sign(message, rng).unwrap();
+
+        // Ensure an immutable function may not be upgraded.
+        let policy = UpgradePolicy::<Testnet2>::Immutable;
+        assert!(policy.is_immutable());
+        assert_eq!(None, policy.owner());
+        assert!(policy.check_upgrade(message, &signature, 0, u32::MAX).is_err());
+
+        // Ensure an owner-upgradable function may only be upgraded with a signature of the program owner.
+        let policy = UpgradePolicy::<Testnet2>::OwnerUpgradable { owner };
+        assert_eq!(Some(owner), policy.owner());
+        assert!(policy.check_upgrade(message, &signature, 10, 10).is_ok());
+        assert!(policy.check_upgrade(message, &other_signature, 10, 10).is_err());
+        assert!(policy.check_upgrade(b"other upgrade", &signature, 10, 10).is_err());
+
+        // Ensure a timelocked function may only be upgraded once its delay has passed.
+        let policy = UpgradePolicy::<Testnet2>::Timelocked {
+            owner,
+            delay_in_blocks: 100,
+        };
+        assert!(!policy.is_immutable());
+        assert!(policy.check_upgrade(message, &signature, 10, 109).is_err());
+        assert!(policy.check_upgrade(message, &signature, 10, 110).is_ok());
+        assert!(policy.check_upgrade(message, &other_signature, 10, 110).is_err());
+
+        // Ensure each policy round-trips through bytes.
+        for policy in [
+            UpgradePolicy::Immutable,
+            UpgradePolicy::OwnerUpgradable { owner },
+            policy,
+        ] {
+            let bytes = policy.to_bytes_le().unwrap();
+            assert_eq!(policy, UpgradePolicy::read_le(&bytes[..]).unwrap());
+        }
+        assert!(UpgradePolicy::<Testnet2>::read_le(&[3u8][..]).is_err());
+    }
+}
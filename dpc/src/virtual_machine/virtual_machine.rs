@@ -18,7 +18,7 @@ use crate::{circuits::*, prelude::*};
 use snarkvm_algorithms::{merkle_tree::MerklePath, prelude::*};
 
 use anyhow::{anyhow, Result};
-use rand::{CryptoRng, Rng};
+use rand::{rngs::StdRng, CryptoRng, Rng, SeedableRng};
 use std::sync::Arc;
 
 #[derive(Derivative)]
@@ -26,22 +26,54 @@ use std::sync::Arc;
 pub struct VirtualMachine<N: Network> {
     /// The root of the ledger tree used to prove inclusion of ledger-consumed records.
     ledger_root: N::LedgerRoot,
+    /// The cutoff that the locktimes of the input records are checked against.
+    locktime_cutoff: LocktimeCutoff,
     /// The local transitions tree.
     local_transitions: Transitions<N>,
     /// The current list of transitions.
     transitions: Vec<Transition<N>>,
+    /// The prover of the transitions.
+    #[derivative(Debug = "ignore")]
+    prover: Arc<dyn TransitionProver<N>>,
 }
 
 impl<N: Network> VirtualMachine<N> {
     /// Initializes a new instance of the virtual machine, with the given request.
     pub fn new(ledger_root: N::LedgerRoot) -> Result<Self> {
+        Self::new_with_prover(ledger_root, Arc::new(CpuProver))
+    }
+
+    /// Initializes a new instance of the virtual machine, which proves transitions with the given prover.
+    pub fn new_with_prover(ledger_root: N::LedgerRoot, prover: Arc<dyn TransitionProver<N>>) -> Result<Self> {
         Ok(Self {
             ledger_root,
+            locktime_cutoff: Default::default(),
             local_transitions: Transitions::new()?,
             transitions: Default::default(),
+            prover,
         })
     }
 
+    /// Returns the virtual machine with the given locktime cutoff, which must be reached by the block
+    /// that includes the transaction. Input records with a locktime after the cutoff may not be consumed.
+    pub fn with_locktime_cutoff(mut self, locktime_cutoff: LocktimeCutoff) -> Result<Self> {
+        if !N::RECORD_LOCKTIMES_ENABLED && locktime_cutoff != LocktimeCutoff::default() {
+            return Err(RecordError::LocktimesDisabled.into());
+        }
+        self.locktime_cutoff = locktime_cutoff;
+        Ok(self)
+    }
+
+    /// Returns the locktime cutoff of the transitions.
+    pub fn locktime_cutoff(&self) -> LocktimeCutoff {
+        self.locktime_cutoff
+    }
+
+    /// Returns the prover of the transitions.
+    pub fn prover(&self) -> &Arc<dyn TransitionProver<N>> {
+        &self.prover
+    }
+
     /// Returns the local proof for a given commitment.
     pub fn to_local_proof(&self, commitment: N::Commitment) -> Result<LocalProof<N>> {
         self.local_transitions.to_local_proof(commitment)
@@ -54,11 +86,16 @@ impl<N: Network> VirtualMachine<N> {
 
     /// Executes the request, returning a transaction.
     pub fn execute<R: Rng + CryptoRng>(mut self, request: &Request<N>, rng: &mut R) -> Result<(Self, Response<N>)> {
+        let _span = debug_span!("VirtualMachine::execute", prover = self.prover.name()).entered();
+
         // Ensure the request is valid.
         if !request.is_valid() {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
 
+        // Ensure the input records are unlocked at the locktime cutoff.
+        self.ensure_unlocked(request)?;
+
         // Compute the operation.
         let operation = request.operation().clone();
         let response = match operation {
@@ -71,6 +108,7 @@ impl<N: Network> VirtualMachine<N> {
                 &function_id,
                 &function_type,
                 &function_inputs,
+                None,   // program_fee
                 vec![], // custom_events
                 rng,
             )?,
@@ -79,16 +117,29 @@ impl<N: Network> VirtualMachine<N> {
         let program_id = request.to_program_id()?;
         let transition_id = response.transition_id();
         let value_balance = response.value_balance();
+        let payload_commitments = Self::to_payload_commitments(request, &response)?;
+
+        // Initialize the randomness of the prover.
+        let prover_rng = &mut StdRng::from_rng(&mut *rng)?;
 
         // Compute the noop execution, for now.
+        let noop_public = ProgramPublicVariables::new(transition_id, payload_commitments.clone());
+        let noop_proof = self
+            .prover
+            .prove_program(
+                N::noop_circuit_proving_key(),
+                &SynthesizedCircuit::Noop(noop_public.clone()),
+                prover_rng,
+            )?
+            .into();
+        assert!(Noop::<N>::new().verify(&noop_public, &noop_proof));
+
         let execution = Execution {
             program_id: *N::noop_program_id(),
             program_path: N::noop_program_path().clone(),
             verifying_key: N::noop_circuit_verifying_key().clone(),
-            proof: Noop::<N>::new().execute(
-                ProgramPublicVariables::new(transition_id),
-                &NoopPrivateVariables::<N>::new_blank()?,
-            )?,
+            proof: noop_proof,
+            payload_commitments,
         };
 
         // Compute the inner circuit proof, and verify that the inner proof passes.
@@ -97,11 +148,16 @@ impl<N: Network> VirtualMachine<N> {
             value_balance,
             self.ledger_root,
             self.local_transitions.root(),
+            self.locktime_cutoff,
+            None,
             Some(program_id),
+            Some(payload_commitments.clone()),
         );
         let inner_private = InnerPrivateVariables::new(request, &response)?;
-        let inner_circuit = InnerCircuit::<N>::new(inner_public, inner_private);
-        let inner_proof = N::InnerSNARK::prove(N::inner_proving_key(), &inner_circuit, rng)?;
+        let inner_circuit = InnerCircuit::<N>::new(inner_public.clone(), inner_private);
+        let inner_proof = self
+            .prover
+            .prove_inner(N::try_inner_proving_key()?, &inner_circuit, prover_rng)?;
 
         assert!(N::InnerSNARK::verify(
             N::inner_verifying_key(),
@@ -113,7 +169,9 @@ impl<N: Network> VirtualMachine<N> {
         let outer_public = OuterPublicVariables::new(inner_public, N::inner_circuit_id());
         let outer_private = OuterPrivateVariables::new(N::inner_verifying_key().clone(), inner_proof.into(), execution);
         let outer_circuit = OuterCircuit::<N>::new(outer_public.clone(), outer_private);
-        let outer_proof = N::OuterSNARK::prove(N::outer_proving_key(), &outer_circuit, rng)?;
+        let outer_proof = self
+            .prover
+            .prove_outer(N::try_outer_proving_key()?, &outer_circuit, prover_rng)?;
 
         assert!(N::OuterSNARK::verify(
             N::outer_verifying_key(),
@@ -122,7 +180,7 @@ impl<N: Network> VirtualMachine<N> {
         )?);
 
         // Construct the transition.
-        let transition = Transition::<N>::new(request, &response, outer_proof.into())?;
+        let transition = Transition::<N>::new(request, &response, self.locktime_cutoff, outer_proof.into())?;
 
         // Update the state of the virtual machine.
         self.local_transitions.add(&transition)?;
@@ -194,6 +252,7 @@ impl<N: Network> VirtualMachine<N> {
         function_id: &N::FunctionID,
         _function_type: &FunctionType,
         function_inputs: &FunctionInputs<N>,
+        program_fee: Option<ProgramFee<N>>,
         custom_events: Vec<Vec<u8>>,
         rng: &mut R,
     ) -> Result<Response<N>> {
@@ -211,6 +270,13 @@ impl<N: Network> VirtualMachine<N> {
             return Err(anyhow!("Caller in instruction does not match request caller"));
         }
 
+        // Ensure the fee from the request pays the program fee, if the function has one.
+        if let Some(program_fee) = &program_fee {
+            if request.fee() < program_fee.amount() {
+                return Err(VMError::ProgramFeeInsufficient.into());
+            }
+        }
+
         // Compute the starting balance of the caller.
         let starting_balance = request.to_balance().sub(request.fee());
         if starting_balance.is_negative() {
@@ -242,6 +308,11 @@ impl<N: Network> VirtualMachine<N> {
             )?)
         }
 
+        // Add the program fee output, which is paid to the program owner.
+        if let Some(program_fee) = &program_fee {
+            response_builder = response_builder.add_program_fee(program_fee);
+        }
+
         // Add custom events to the response.
         for event in custom_events {
             response_builder = response_builder.add_event(Event::Custom(event));
@@ -260,19 +331,30 @@ impl<N: Network> VirtualMachine<N> {
     pub fn execute_program<R: Rng + CryptoRng>(
         mut self,
         request: &Request<N>,
-        program_id: <N as Network>::ProgramID,
+        program_id: N::ProgramID,
         function: &Arc<dyn Function<N>>,
-        function_path: &MerklePath<<N as Network>::ProgramIDParameters>,
-        function_verifying_key: <<N as Network>::ProgramSNARK as SNARK>::VerifyingKey,
+        function_path: &MerklePath<N::ProgramIDParameters>,
+        function_verifying_key: <N::ProgramSNARK as SNARK>::VerifyingKey,
         private_variables: &dyn ProgramPrivateVariables<N>,
         custom_events: Vec<Vec<u8>>,
         rng: &mut R,
     ) -> Result<(Self, Response<N>)> {
+        let _span = debug_span!("VirtualMachine::execute_program", prover = self.prover.name()).entered();
+
         // Ensure the request is valid.
         if !request.is_valid() {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
 
+        // Ensure the input records are unlocked at the locktime cutoff.
+        self.ensure_unlocked(request)?;
+
+        // Ensure the network supports the program fee of the function, if it has one.
+        let program_fee = function.program_fee();
+        if program_fee.is_some() && !N::PROGRAM_FEES_ENABLED {
+            return Err(VMError::ProgramFeesDisabled.into());
+        }
+
         // Compute the operation.
         let operation = request.operation().clone();
         let response = match operation {
@@ -282,6 +364,7 @@ impl<N: Network> VirtualMachine<N> {
                 &function_id,
                 &function_type,
                 &function_inputs,
+                program_fee,
                 custom_events,
                 rng,
             )?,
@@ -290,6 +373,10 @@ impl<N: Network> VirtualMachine<N> {
 
         let transition_id = response.transition_id();
         let value_balance = response.value_balance();
+        let payload_commitments = Self::to_payload_commitments(request, &response)?;
+
+        // Initialize the randomness of the prover.
+        let prover_rng = &mut StdRng::from_rng(&mut *rng)?;
 
         // Compute the inner circuit proof, and verify that the inner proof passes.
         let inner_public = InnerPublicVariables::new(
@@ -297,11 +384,16 @@ impl<N: Network> VirtualMachine<N> {
             value_balance,
             self.ledger_root,
             self.local_transitions.root(),
+            self.locktime_cutoff,
+            program_fee,
             Some(program_id),
+            Some(payload_commitments.clone()),
         );
         let inner_private = InnerPrivateVariables::new(request, &response)?;
-        let inner_circuit = InnerCircuit::<N>::new(inner_public, inner_private);
-        let inner_proof = N::InnerSNARK::prove(N::inner_proving_key(), &inner_circuit, rng)?;
+        let inner_circuit = InnerCircuit::<N>::new(inner_public.clone(), inner_private);
+        let inner_proof = self
+            .prover
+            .prove_inner(N::try_inner_proving_key()?, &inner_circuit, prover_rng)?;
 
         assert!(N::InnerSNARK::verify(
             N::inner_verifying_key(),
@@ -310,8 +402,8 @@ impl<N: Network> VirtualMachine<N> {
         )?);
 
         // Compute the execution.
-        let proof = function.execute(ProgramPublicVariables::new(transition_id), private_variables)?;
-        let public_variables = ProgramPublicVariables::new(transition_id);
+        let public_variables = ProgramPublicVariables::new(transition_id, payload_commitments.clone());
+        let proof = function.execute(public_variables.clone(), private_variables)?;
 
         assert!(function.verify(&public_variables, &proof));
         assert!(function_path.verify(&program_id, &function.function_id())?);
@@ -321,13 +413,16 @@ impl<N: Network> VirtualMachine<N> {
             program_path: function_path.clone(),
             verifying_key: function_verifying_key,
             proof,
+            payload_commitments,
         };
 
         // Construct the outer circuit public and private variables.
         let outer_public = OuterPublicVariables::new(inner_public, N::inner_circuit_id());
         let outer_private = OuterPrivateVariables::new(N::inner_verifying_key().clone(), inner_proof.into(), execution);
         let outer_circuit = OuterCircuit::<N>::new(outer_public.clone(), outer_private);
-        let outer_proof = N::OuterSNARK::prove(N::outer_proving_key(), &outer_circuit, rng)?;
+        let outer_proof = self
+            .prover
+            .prove_outer(N::try_outer_proving_key()?, &outer_circuit, prover_rng)?;
 
         assert!(N::OuterSNARK::verify(
             N::outer_verifying_key(),
@@ -336,7 +431,7 @@ impl<N: Network> VirtualMachine<N> {
         )?);
 
         // Construct the transition.
-        let transition = Transition::<N>::new(request, &response, outer_proof.into())?;
+        let transition = Transition::<N>::new(request, &response, self.locktime_cutoff, outer_proof.into())?;
 
         // Update the state of the virtual machine.
         self.local_transitions.add(&transition)?;
@@ -344,4 +439,28 @@ impl<N: Network> VirtualMachine<N> {
 
         Ok((self, response))
     }
+
+    /// Returns an error if an input record of the request is locked at the locktime cutoff.
+    fn ensure_unlocked(&self, request: &Request<N>) -> Result<()> {
+        match request
+            .records()
+            .iter()
+            .find(|record| !record.is_unlocked(&self.locktime_cutoff))
+        {
+            Some(record) => Err(VMError::RecordLocked(record.commitment().to_string()).into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the payload commitments of the first `N::NUM_PAYLOAD_COMMITMENTS` records of the transition,
+    /// where the input records precede the output records.
+    fn to_payload_commitments(request: &Request<N>, response: &Response<N>) -> Result<Vec<N::Commitment>> {
+        request
+            .records()
+            .iter()
+            .chain(response.records().iter())
+            .take(N::NUM_PAYLOAD_COMMITMENTS)
+            .map(|record| Ok(record.to_payload_commitment()?))
+            .collect()
+    }
 }
@@ -19,20 +19,47 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use snarkvm_algorithms::{SNARKError, SNARK, SRS};
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_dpc::{testnet2::Testnet2, BlockTemplate, Network, PoSWError, PoSWScheme};
+use snarkvm_dpc::{
+    testnet2_mini::Testnet2Mini,
+    BlockTemplate,
+    LedgerTree,
+    LedgerTreeScheme,
+    NetworkConstants,
+    NetworkCrypto,
+    NetworkParameters,
+    PoSWError,
+    PoSWScheme,
+};
 use snarkvm_marlin::marlin::{CircuitProvingKey, MarlinPoswMode, MarlinTestnet1Mode};
+use snarkvm_utilities::UniformRand;
 
 use rand::{rngs::ThreadRng, thread_rng};
 
+#[test]
+fn test_ledger_tree_is_bounded() {
+    let rng = &mut thread_rng();
+
+    // Fill the ledger tree to capacity.
+    let capacity = 1 << Testnet2Mini::LEDGER_TREE_DEPTH;
+    let block_hashes = (0..capacity)
+        .map(|_| <Testnet2Mini as NetworkCrypto>::BlockHash::rand(rng))
+        .collect::<Vec<_>>();
+    let mut ledger_tree = LedgerTree::<Testnet2Mini>::new().unwrap();
+    ledger_tree.add_all(&block_hashes).unwrap();
+
+    // Ensure the ledger tree rejects an additional block hash.
+    assert!(ledger_tree.add(&UniformRand::rand(rng)).is_err());
+}
+
 #[test]
 fn test_posw_terminate() {
     // Construct the block template.
-    let block = Testnet2::genesis_block();
+    let block = Testnet2Mini::genesis_block();
     let block_template = BlockTemplate::new(
         block.previous_block_hash(),
         block.height(),
@@ -50,11 +77,37 @@ fn test_posw_terminate() {
         std::thread::sleep(Duration::from_secs(1));
         thread_terminator.store(true, Ordering::SeqCst);
     });
-    let result = Testnet2::posw().mine(&block_template, &AtomicBool::new(true), &mut thread_rng());
+    let result = Testnet2Mini::posw().mine(&block_template, &AtomicBool::new(true), &mut thread_rng());
 
     assert!(matches!(result, Err(PoSWError::SNARKError(SNARKError::Terminated))));
 }
 
+#[test]
+fn test_posw_mine_until() {
+    // Construct a block template with a difficulty target that no proof satisfies.
+    let block = Testnet2Mini::genesis_block();
+    let block_template = BlockTemplate::new(
+        block.previous_block_hash(),
+        block.height(),
+        block.timestamp(),
+        0,
+        block.cumulative_weight(),
+        block.previous_ledger_root(),
+        block.transactions().clone(),
+        block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+    );
+
+    // Ensure mining stops with a timeout once the deadline passes.
+    let deadline = Instant::now() + Duration::from_secs(1);
+    let result = Testnet2Mini::posw_registry().mine_until(deadline, &block_template, &mut thread_rng());
+    assert!(matches!(result, Err(PoSWError::Timeout)));
+    assert!(Instant::now() >= deadline);
+
+    // Ensure mining does not start after the deadline.
+    let result = Testnet2Mini::posw_registry().mine_until(Instant::now(), &block_template, &mut thread_rng());
+    assert!(matches!(result, Err(PoSWError::Timeout)));
+}
+
 /// TODO (howardwu): Update this when testnet2 is live.
 #[ignore]
 #[test]
@@ -77,7 +130,7 @@ fn test_posw_verify_testnet1() {
     // let proof = {
     //     let bytes = hex::decode(POSW_PROOF).unwrap();
     //     assert_eq!(bytes.len(), 972); // NOTE: Marlin proofs use compressed serialization
-    //     <<Testnet1 as Network>::PoSWSNARK as SNARK>::Proof::read_le(&bytes[..]).unwrap()
+    //     <<Testnet1 as NetworkParameters>::PoSWSNARK as SNARK>::Proof::read_le(&bytes[..]).unwrap()
     // };
     //
     // let posw = PoswMarlin::<Testnet1>::load(true).unwrap();
@@ -86,19 +139,19 @@ fn test_posw_verify_testnet1() {
 
 #[test]
 fn test_posw_setup_vs_load_weak_sanity_check() {
-    let generated_posw: <Testnet2 as Network>::PoSW = {
+    let generated_posw: <Testnet2Mini as NetworkParameters>::PoSW = {
         // Load the PoSW Marlin parameters.
         let rng = &mut thread_rng();
         // Run the universal setup.
         let max_degree = snarkvm_marlin::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(40000, 40000, 60000).unwrap();
-        let universal_srs = <Testnet2 as Network>::PoSWSNARK::universal_setup(&max_degree, rng).unwrap();
+        let universal_srs = <Testnet2Mini as NetworkParameters>::PoSWSNARK::universal_setup(&max_degree, rng).unwrap();
         // Run the circuit setup.
-        <<Testnet2 as Network>::PoSW as PoSWScheme<Testnet2>>::setup::<ThreadRng>(&mut SRS::<ThreadRng, _>::Universal(
-            &universal_srs,
-        ))
+        <<Testnet2Mini as NetworkParameters>::PoSW as PoSWScheme<Testnet2Mini>>::setup::<ThreadRng>(
+            &mut SRS::<ThreadRng, _>::Universal(&universal_srs),
+        )
         .unwrap()
     };
-    let loaded_posw = Testnet2::posw().clone();
+    let loaded_posw = Testnet2Mini::posw().clone();
 
     let generated_proving_key: &CircuitProvingKey<Fr, _, _, MarlinPoswMode> =
         generated_posw.proving_key().as_ref().unwrap();
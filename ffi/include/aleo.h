@@ -0,0 +1,137 @@
+#ifndef ALEO_H
+#define ALEO_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * An account, with its private key, view key, and address.
+ */
+typedef struct AleoAccount AleoAccount;
+
+/**
+ * An account address.
+ */
+typedef struct AleoAddress AleoAddress;
+
+/**
+ * A decrypted record.
+ */
+typedef struct AleoRecord AleoRecord;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns the address of the given account.
+ */
+char *aleo_account_address(const AleoAccount *account);
+
+/**
+ * Frees the given account.
+ */
+void aleo_account_free(AleoAccount *account);
+
+/**
+ * Returns the account of the given private key.
+ */
+AleoAccount *aleo_account_from_private_key(const char *private_key);
+
+/**
+ * Returns a new account, sampled from the randomness of the operating system.
+ */
+AleoAccount *aleo_account_new(void);
+
+/**
+ * Returns the private key of the given account, which must be freed with `aleo_secret_string_free`.
+ */
+char *aleo_account_private_key(const AleoAccount *account);
+
+/**
+ * Returns the view key of the given account, which must be freed with `aleo_secret_string_free`.
+ */
+char *aleo_account_view_key(const AleoAccount *account);
+
+/**
+ * Frees the given address.
+ */
+void aleo_address_free(AleoAddress *address);
+
+/**
+ * Returns the address in the given string.
+ */
+AleoAddress *aleo_address_from_str(const char *address);
+
+/**
+ * Returns the given address as a string.
+ */
+char *aleo_address_to_string(const AleoAddress *address);
+
+/**
+ *
+ * Returns the message of the last error on this thread, or null if the last call succeeded.
+ *
+ * The message is owned by this library, and remains valid until the next call to this library on this thread.
+ *
+ */
+const char *aleo_last_error(void);
+
+/**
+ * Returns the record in the given ciphertext, if it is owned by the given view key.
+ */
+AleoRecord *aleo_record_decrypt(const char *view_key, const char *ciphertext);
+
+/**
+ * Frees the given record.
+ */
+void aleo_record_free(AleoRecord *record);
+
+/**
+ * Returns the owner of the given record.
+ */
+char *aleo_record_owner(const AleoRecord *record);
+
+/**
+ * Returns the given record as a JSON string.
+ */
+char *aleo_record_to_string(const AleoRecord *record);
+
+/**
+ * Returns the value of the given record, or `-1` if the record is null.
+ */
+int64_t aleo_record_value(const AleoRecord *record);
+
+/**
+ * Frees a secret string returned by this library, such as a private key, after overwriting it with zeros.
+ */
+void aleo_secret_string_free(char *string);
+
+/**
+ *
+ * Frees a string returned by this library.
+ *
+ * Secret strings, such as private keys and view keys, must be freed with `aleo_secret_string_free` instead.
+ *
+ */
+void aleo_string_free(char *string);
+
+/**
+ *
+ * Returns `1` if the given JSON transaction is valid, `0` if it is invalid, and `-1` if it cannot be parsed.
+ *
+ * This checks the transition proofs of the transaction, and does not check the transaction against
+ * the ledger, such as whether its ledger root exists or its serial numbers are unspent.
+ *
+ */
+int32_t aleo_transaction_verify(const char *transaction);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* ALEO_H */
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{free_handle, guard, to_c_string, to_handle, to_ref, to_secret_c_string, to_str, CurrentNetwork};
+use snarkvm_dpc::{Account, AccountScheme, PrivateKey};
+
+use std::{os::raw::c_char, ptr, str::FromStr};
+
+/// An account, with its private key, view key, and address.
+pub struct AleoAccount(pub(crate) Account<CurrentNetwork>);
+
+/// Returns a new account, sampled from the randomness of the operating system.
+#[no_mangle]
+pub extern "C" fn aleo_account_new() -> *mut AleoAccount {
+    guard(ptr::null_mut(), || {
+        Ok(to_handle(AleoAccount(Account::new(&mut rand::thread_rng()))))
+    })
+}
+
+/// Returns the account of the given private key.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_account_from_private_key(private_key: *const c_char) -> *mut AleoAccount {
+    guard(ptr::null_mut(), || {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(to_str(private_key)?)?;
+        Ok(to_handle(AleoAccount(Account::from(private_key))))
+    })
+}
+
+/// Returns the private key of the given account, which must be freed with `aleo_secret_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_account_private_key(account: *const AleoAccount) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        to_secret_c_string(to_ref(account)?.0.private_key().to_string())
+    })
+}
+
+/// Returns the view key of the given account, which must be freed with `aleo_secret_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_account_view_key(account: *const AleoAccount) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        to_secret_c_string(to_ref(account)?.0.view_key().to_string())
+    })
+}
+
+/// Returns the address of the given account.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_account_address(account: *const AleoAccount) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        to_c_string(to_ref(account)?.0.address().to_string())
+    })
+}
+
+/// Frees the given account.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_account_free(account: *mut AleoAccount) {
+    free_handle(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aleo_secret_string_free, aleo_string_free};
+
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn test_account_from_private_key() {
+        unsafe {
+            let account = aleo_account_new();
+            assert!(!account.is_null());
+
+            let private_key = aleo_account_private_key(account);
+            let address = aleo_account_address(account);
+
+            // Ensure the account of the private key has the same address.
+            let candidate = aleo_account_from_private_key(private_key);
+            let candidate_address = aleo_account_address(candidate);
+            assert_eq!(CStr::from_ptr(address), CStr::from_ptr(candidate_address));
+
+            aleo_secret_string_free(private_key);
+            aleo_string_free(address);
+            aleo_string_free(candidate_address);
+            aleo_account_free(account);
+            aleo_account_free(candidate);
+        }
+    }
+
+    #[test]
+    fn test_account_invalid_private_key() {
+        unsafe {
+            let private_key = CString::new("invalid").unwrap();
+            assert!(aleo_account_from_private_key(private_key.as_ptr()).is_null());
+            assert!(!crate::aleo_last_error().is_null());
+
+            assert!(aleo_account_from_private_key(ptr::null()).is_null());
+            assert!(aleo_account_address(ptr::null()).is_null());
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{free_handle, guard, to_c_string, to_handle, to_ref, to_str, CurrentNetwork};
+use snarkvm_dpc::Address;
+
+use std::{os::raw::c_char, ptr, str::FromStr};
+
+/// An account address.
+pub struct AleoAddress(pub(crate) Address<CurrentNetwork>);
+
+/// Returns the address in the given string.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_address_from_str(address: *const c_char) -> *mut AleoAddress {
+    guard(ptr::null_mut(), || {
+        Ok(to_handle(AleoAddress(Address::from_str(to_str(address)?)?)))
+    })
+}
+
+/// Returns the given address as a string.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_address_to_string(address: *const AleoAddress) -> *mut c_char {
+    guard(ptr::null_mut(), || to_c_string(to_ref(address)?.0.to_string()))
+}
+
+/// Frees the given address.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_address_free(address: *mut AleoAddress) {
+    free_handle(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aleo_account_address, aleo_account_free, aleo_account_new, aleo_string_free};
+
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn test_address_from_str() {
+        unsafe {
+            let account = aleo_account_new();
+            let expected = aleo_account_address(account);
+
+            let address = aleo_address_from_str(expected);
+            assert!(!address.is_null());
+            let candidate = aleo_address_to_string(address);
+            assert_eq!(CStr::from_ptr(expected), CStr::from_ptr(candidate));
+
+            aleo_string_free(expected);
+            aleo_string_free(candidate);
+            aleo_address_free(address);
+            aleo_account_free(account);
+
+            let invalid = CString::new("aleo1invalid").unwrap();
+            assert!(aleo_address_from_str(invalid.as_ptr()).is_null());
+        }
+    }
+}
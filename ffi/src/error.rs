@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::{anyhow, Result};
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString},
+    fmt::Display,
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+thread_local! {
+    /// The message of the last error on this thread.
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+/// Records the given error as the last error on this thread.
+fn set_last_error(error: impl Display) {
+    // An interior NUL is the only reason this may fail, in which case the message is truncated there.
+    let message = error.to_string();
+    let message = CString::new(message.split('\0').next().unwrap_or_default()).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+///
+/// Returns the message of the last error on this thread, or null if the last call succeeded.
+///
+/// The message is owned by this library, and remains valid until the next call to this library on this thread.
+///
+#[no_mangle]
+pub extern "C" fn aleo_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+///
+/// Frees a string returned by this library.
+///
+/// Secret strings, such as private keys and view keys, must be freed with `aleo_secret_string_free` instead.
+///
+#[no_mangle]
+pub unsafe extern "C" fn aleo_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Frees a secret string returned by this library, such as a private key, after overwriting it with zeros.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_secret_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        CString::from_raw(string).into_bytes_with_nul().zeroize();
+    }
+}
+
+///
+/// Returns the result of the given function, or the given default if the function fails or panics.
+/// The last error on this thread is cleared, or set to the error of the function.
+///
+/// Panics must not unwind into the caller, as unwinding across the C ABI is undefined behavior.
+///
+pub(crate) fn guard<T>(default: T, function: impl FnOnce() -> Result<T>) -> T {
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = None);
+    match catch_unwind(AssertUnwindSafe(function)) {
+        Ok(Ok(output)) => output,
+        Ok(Err(error)) => {
+            set_last_error(error);
+            default
+        }
+        Err(_) => {
+            set_last_error("snarkVM panicked");
+            default
+        }
+    }
+}
+
+/// Returns the given C string as a string slice.
+pub(crate) unsafe fn to_str<'a>(string: *const c_char) -> Result<&'a str> {
+    if string.is_null() {
+        return Err(anyhow!("Expected a string, found a null pointer"));
+    }
+    Ok(CStr::from_ptr(string).to_str()?)
+}
+
+/// Returns a reference to the value behind the given handle.
+pub(crate) unsafe fn to_ref<'a, T>(handle: *const T) -> Result<&'a T> {
+    handle
+        .as_ref()
+        .ok_or_else(|| anyhow!("Expected a handle, found a null pointer"))
+}
+
+/// Returns the given string as a C string, which is owned by the caller.
+pub(crate) fn to_c_string(string: impl Into<Vec<u8>>) -> Result<*mut c_char> {
+    Ok(CString::new(string)?.into_raw())
+}
+
+/// Returns the given secret as a C string, which is owned by the caller and freed with `aleo_secret_string_free`.
+pub(crate) fn to_secret_c_string(secret: String) -> Result<*mut c_char> {
+    let secret = Zeroizing::new(secret);
+    // Reserve the NUL terminator up front, so a reallocation does not leave a copy of the secret behind.
+    let mut bytes = Vec::with_capacity(secret.len() + 1);
+    bytes.extend_from_slice(secret.as_bytes());
+    to_c_string(bytes)
+}
+
+/// Returns the given value as a handle, which is owned by the caller.
+pub(crate) fn to_handle<T>(value: T) -> *mut T {
+    Box::into_raw(Box::new(value))
+}
+
+/// Frees the value behind the given handle.
+pub(crate) unsafe fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard() {
+        assert_eq!(1, guard(0, || Ok(1)));
+        assert!(aleo_last_error().is_null());
+
+        assert_eq!(0, guard(0, || Err(anyhow!("failure"))));
+        let message = unsafe { CStr::from_ptr(aleo_last_error()) };
+        assert_eq!("failure", message.to_str().unwrap());
+
+        // Ensure a panic is caught, and recorded as the last error.
+        assert_eq!(0, guard(0, || panic!("failure")));
+        assert!(!aleo_last_error().is_null());
+
+        // Ensure a successful call clears the last error.
+        assert_eq!(1, guard(0, || Ok(1)));
+        assert!(aleo_last_error().is_null());
+    }
+
+    #[test]
+    fn test_secret_string_free() {
+        let secret = to_secret_c_string("secret".to_string()).unwrap();
+        assert_eq!("secret", unsafe { CStr::from_ptr(secret) }.to_str().unwrap());
+
+        unsafe {
+            aleo_secret_string_free(secret);
+            aleo_secret_string_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! C bindings for the wallet operations of snarkVM.
+//!
+//! The functions take and return opaque handles and NUL-terminated strings. See the README for
+//! the ownership rules of the handles and strings, and for how errors are reported.
+
+#![allow(clippy::missing_safety_doc)]
+
+pub mod account;
+pub use account::*;
+
+pub mod address;
+pub use address::*;
+
+pub mod error;
+pub use error::*;
+
+pub mod record;
+pub use record::*;
+
+pub mod transaction;
+pub use transaction::*;
+
+/// The network of the wallet operations.
+pub type CurrentNetwork = snarkvm_dpc::testnet2::Testnet2;
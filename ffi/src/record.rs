@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{free_handle, guard, to_c_string, to_handle, to_ref, to_str, CurrentNetwork};
+use snarkvm_dpc::{Network, Record, ViewKey};
+
+use std::{os::raw::c_char, ptr, str::FromStr};
+
+/// A decrypted record.
+pub struct AleoRecord(pub(crate) Record<CurrentNetwork>);
+
+/// Returns the record in the given ciphertext, if it is owned by the given view key.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_record_decrypt(view_key: *const c_char, ciphertext: *const c_char) -> *mut AleoRecord {
+    guard(ptr::null_mut(), || {
+        let view_key = ViewKey::<CurrentNetwork>::from_str(to_str(view_key)?)?;
+        let ciphertext = <CurrentNetwork as Network>::RecordCiphertext::from_str(to_str(ciphertext)?)?;
+        Ok(to_handle(AleoRecord(Record::from_account_view_key(
+            &view_key,
+            &ciphertext,
+        )?)))
+    })
+}
+
+/// Returns the owner of the given record.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_record_owner(record: *const AleoRecord) -> *mut c_char {
+    guard(ptr::null_mut(), || to_c_string(to_ref(record)?.0.owner().to_string()))
+}
+
+/// Returns the value of the given record, or `-1` if the record is null.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_record_value(record: *const AleoRecord) -> i64 {
+    guard(-1, || Ok(to_ref(record)?.0.value().as_i64()))
+}
+
+/// Returns the given record as a JSON string.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_record_to_string(record: *const AleoRecord) -> *mut c_char {
+    guard(ptr::null_mut(), || to_c_string(to_ref(record)?.0.to_string()))
+}
+
+/// Frees the given record.
+#[no_mangle]
+pub unsafe extern "C" fn aleo_record_free(record: *mut AleoRecord) {
+    free_handle(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aleo_account_free,
+        aleo_account_new,
+        aleo_account_view_key,
+        aleo_secret_string_free,
+        aleo_string_free,
+    };
+    use snarkvm_dpc::AccountScheme;
+
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn test_record_decrypt() {
+        unsafe {
+            let account = aleo_account_new();
+            let view_key = aleo_account_view_key(account);
+            let expected = Record::new_noop((*account).0.address(), &mut rand::thread_rng()).unwrap();
+            let ciphertext = CString::new(expected.ciphertext().to_string()).unwrap();
+
+            let record = aleo_record_decrypt(view_key, ciphertext.as_ptr());
+            assert!(!record.is_null());
+            assert_eq!(0, aleo_record_value(record));
+
+            let owner = aleo_record_owner(record);
+            assert_eq!(expected.owner().to_string(), CStr::from_ptr(owner).to_str().unwrap());
+
+            // Ensure the record cannot be decrypted by another account.
+            let stranger = aleo_account_new();
+            let stranger_view_key = aleo_account_view_key(stranger);
+            assert!(aleo_record_decrypt(stranger_view_key, ciphertext.as_ptr()).is_null());
+
+            aleo_string_free(owner);
+            aleo_secret_string_free(view_key);
+            aleo_secret_string_free(stranger_view_key);
+            aleo_record_free(record);
+            aleo_account_free(account);
+            aleo_account_free(stranger);
+        }
+    }
+}
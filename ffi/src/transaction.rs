@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{guard, to_str, CurrentNetwork};
+use snarkvm_dpc::Transaction;
+
+use std::{os::raw::c_char, str::FromStr};
+
+///
+/// Returns `1` if the given JSON transaction is valid, `0` if it is invalid, and `-1` if it cannot be parsed.
+///
+/// This checks the transition proofs of the transaction, and does not check the transaction against
+/// the ledger, such as whether its ledger root exists or its serial numbers are unspent.
+///
+#[no_mangle]
+pub unsafe extern "C" fn aleo_transaction_verify(transaction: *const c_char) -> i32 {
+    guard(-1, || {
+        let transaction = Transaction::<CurrentNetwork>::from_str(to_str(transaction)?)?;
+        Ok(transaction.is_valid() as i32)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_dpc::Network;
+
+    use std::ffi::CString;
+
+    #[test]
+    fn test_transaction_verify() {
+        let transaction = CurrentNetwork::genesis_block().to_coinbase_transaction().unwrap();
+        let transaction = CString::new(transaction.to_string()).unwrap();
+        assert_eq!(1, unsafe { aleo_transaction_verify(transaction.as_ptr()) });
+
+        let invalid = CString::new("{}").unwrap();
+        assert_eq!(-1, unsafe { aleo_transaction_verify(invalid.as_ptr()) });
+    }
+}
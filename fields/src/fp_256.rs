@@ -80,6 +80,15 @@ impl<P: Fp256Parameters> Fp256<P> {
         }
     }
 
+    /// Reduces the element, given the carry out of the backing representation.
+    /// The carry may only be set if the modulus uses all 256 bits, as the secp256k1 moduli do.
+    #[inline]
+    fn reduce_with_carry(&mut self, carry: bool) {
+        if carry || !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
     #[inline]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -132,7 +141,7 @@ impl<P: Fp256Parameters> Fp256<P> {
         (self.0).0[1] = r5;
         (self.0).0[2] = r6;
         (self.0).0[3] = r7;
-        self.reduce();
+        self.reduce_with_carry(carry != 0);
     }
 }
 
@@ -173,10 +182,11 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn double_in_place(&mut self) {
-        // This cannot exceed the backing capacity.
+        // This only exceeds the backing capacity if the modulus uses all 256 bits.
+        let carry = (self.0).0[3] >> 63 == 1;
         self.0.mul2();
         // However, it may need to be reduced.
-        self.reduce();
+        self.reduce_with_carry(carry);
     }
 
     #[inline]
@@ -252,8 +262,9 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if b.0.is_even() {
                         b.0.div2();
                     } else {
-                        b.0.add_nocarry(&P::MODULUS);
+                        let carry = b.0.add_nocarry(&P::MODULUS);
                         b.0.div2();
+                        (b.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -263,8 +274,9 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if c.0.is_even() {
                         c.0.div2();
                     } else {
-                        c.0.add_nocarry(&P::MODULUS);
+                        let carry = c.0.add_nocarry(&P::MODULUS);
                         c.0.div2();
+                        (c.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -573,11 +585,11 @@ impl<'a, P: Fp256Parameters> Div<&'a Fp256<P>> for Fp256<P> {
 impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
-        self.0.add_nocarry(&other.0);
+        // This only exceeds the backing capacity if the modulus uses all 256 bits.
+        let carry = self.0.add_nocarry(&other.0);
         // However, it may need to be reduced
 
-        self.reduce();
+        self.reduce_with_carry(carry);
     }
 }
 
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![no_main]
+
+use snarkvm_dpc::{testnet2::Testnet2, Address, NetworkCrypto, NetworkParameters, PrivateKey, ViewKey};
+
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        // Account types.
+        let _ = PrivateKey::<Testnet2>::from_str(string);
+        let _ = ViewKey::<Testnet2>::from_str(string);
+        let _ = Address::<Testnet2>::from_str(string);
+
+        // Locators.
+        let _ = <Testnet2 as NetworkCrypto>::BlockHash::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::Commitment::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::LedgerRoot::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::SerialNumber::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::TransactionID::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::TransitionID::from_str(string);
+
+        // Objects.
+        let _ = <Testnet2 as NetworkParameters>::RecordCiphertext::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::RecordViewKey::from_str(string);
+        let _ = <Testnet2 as NetworkCrypto>::AccountSignature::from_str(string);
+        let _ = <Testnet2 as NetworkParameters>::PoSWProof::from_str(string);
+    }
+});
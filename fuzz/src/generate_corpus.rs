@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates seed corpora for the fuzz targets from the genesis block fixtures.
+//!
+//! Run with `cargo run --bin generate_corpus` from the `fuzz` directory.
+
+use snarkvm_dpc::{testnet2::Testnet2, NetworkParameters};
+use snarkvm_utilities::ToBytes;
+
+use anyhow::Result;
+use std::{fs, path::Path};
+
+fn write_seed(target: &str, name: &str, bytes: &[u8]) -> Result<()> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus").join(target);
+    fs::create_dir_all(&directory)?;
+    fs::write(directory.join(name), bytes)?;
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let block = Testnet2::genesis_block();
+    write_seed("block", "genesis", &block.to_bytes_le()?)?;
+
+    write_seed("bech32", "block_hash", block.hash().to_string().as_bytes())?;
+    write_seed(
+        "bech32",
+        "ledger_root",
+        block.previous_ledger_root().to_string().as_bytes(),
+    )?;
+
+    for (i, transaction) in block.transactions().iter().enumerate() {
+        write_seed("transaction", &format!("genesis_{}", i), &transaction.to_bytes_le()?)?;
+        write_seed(
+            "bech32",
+            &format!("transaction_id_{}", i),
+            transaction.transaction_id().to_string().as_bytes(),
+        )?;
+
+        for (j, record) in transaction.to_records().enumerate() {
+            write_seed("record", &format!("genesis_{}_{}", i, j), &record.to_bytes_le()?)?;
+            write_seed(
+                "bech32",
+                &format!("address_{}_{}", i, j),
+                record.owner().to_string().as_bytes(),
+            )?;
+            write_seed(
+                "bech32",
+                &format!("commitment_{}_{}", i, j),
+                record.commitment().to_string().as_bytes(),
+            )?;
+            write_seed(
+                "bech32",
+                &format!("ciphertext_{}_{}", i, j),
+                record.ciphertext().to_string().as_bytes(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+use snarkvm_algorithms::{signature::ECDSASignatureScheme, traits::SignatureScheme};
+use snarkvm_gadgets::{algorithms::signature::ECDSASignatureSchemeGadget, prelude::*};
+use snarkvm_r1cs::{ConstraintSystem, Fr, TestConstraintSystem};
+
+type ECDSAGadget = ECDSASignatureSchemeGadget<Fr>;
+
+/// Synthesizes the verification of an ECDSA signature, and returns the number of constraints.
+fn ecdsa_verification(rng: &mut XorShiftRng) -> usize {
+    let message = [7u8; 32];
+
+    let signature_scheme = ECDSASignatureScheme::setup("ecdsa_verification_bench");
+    let private_key = signature_scheme.generate_private_key(rng);
+    let public_key = signature_scheme.generate_public_key(&private_key);
+    let signature = signature_scheme.sign(&private_key, &message, rng).unwrap();
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let signature_scheme_gadget =
+        ECDSAGadget::alloc_constant(cs.ns(|| "signature_scheme"), || Ok(signature_scheme)).unwrap();
+    let public_key_gadget = <ECDSAGadget as SignatureGadget<ECDSASignatureScheme, Fr>>::PublicKeyGadget::alloc(
+        cs.ns(|| "public_key"),
+        || Ok(public_key),
+    )
+    .unwrap();
+    let message_gadget = UInt8::alloc_vec(cs.ns(|| "message"), &message).unwrap();
+    let signature_gadget = <ECDSAGadget as SignatureGadget<ECDSASignatureScheme, Fr>>::SignatureGadget::alloc(
+        cs.ns(|| "signature"),
+        || Ok(signature),
+    )
+    .unwrap();
+
+    let verification = signature_scheme_gadget
+        .verify(
+            cs.ns(|| "verify"),
+            &public_key_gadget,
+            &message_gadget,
+            &signature_gadget,
+        )
+        .unwrap();
+    assert_eq!(Some(true), verification.get_value());
+
+    cs.num_constraints()
+}
+
+fn bench_ecdsa_verification(c: &mut Criterion) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // The constraint count is the main cost of the gadget, as every field operation is emulated.
+    println!("ecdsa::verification uses {} constraints", ecdsa_verification(&mut rng));
+
+    let mut group = c.benchmark_group("ecdsa");
+    group.sample_size(10);
+    group.bench_function("verification", |b| b.iter(|| ecdsa_verification(&mut rng)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdsa_verification);
+criterion_main!(benches);
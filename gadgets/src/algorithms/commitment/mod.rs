@@ -23,5 +23,8 @@ pub use pedersen::*;
 pub mod pedersen_compressed;
 pub use pedersen_compressed::*;
 
+pub mod pedersen_homomorphic;
+pub use pedersen_homomorphic::*;
+
 #[cfg(test)]
 pub mod tests;
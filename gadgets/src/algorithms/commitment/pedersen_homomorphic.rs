@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::commitment::PedersenRandomnessGadget,
+    integers::uint::UInt8,
+    traits::{algorithms::CommitmentGadget, alloc::AllocGadget, curves::CurveGadget, integers::Integer},
+};
+use snarkvm_algorithms::commitment::PedersenHomomorphicCommitment;
+use snarkvm_curves::ProjectiveCurve;
+use snarkvm_fields::{Field, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+#[derive(Clone)]
+pub struct PedersenHomomorphicCommitmentGadget<G: ProjectiveCurve, F: Field, GG: CurveGadget<G, F>> {
+    pub(crate) pedersen: PedersenHomomorphicCommitment<G>,
+    _group_gadget: PhantomData<GG>,
+    _field: PhantomData<F>,
+}
+
+impl<G: ProjectiveCurve, F: PrimeField, GG: CurveGadget<G, F>> PedersenHomomorphicCommitmentGadget<G, F, GG> {
+    /// Returns a commitment to the same value, with randomness increased by the given randomness.
+    pub fn rerandomize<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        commitment: &GG,
+        randomness: &PedersenRandomnessGadget<G>,
+    ) -> Result<GG, SynthesisError> {
+        let mut result = commitment.clone();
+        let rand_bits = randomness.0.iter().flat_map(|byte| byte.to_bits_le());
+        result.scalar_multiplication(cs.ns(|| "randomizer"), rand_bits.zip(&self.pedersen.random_base))?;
+        Ok(result)
+    }
+}
+
+impl<G: ProjectiveCurve, F: PrimeField, GG: CurveGadget<G, F>> AllocGadget<PedersenHomomorphicCommitment<G>, F>
+    for PedersenHomomorphicCommitmentGadget<G, F, GG>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PedersenHomomorphicCommitment<G>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            pedersen: value_gen()?.borrow().clone(),
+            _group_gadget: PhantomData,
+            _field: PhantomData,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PedersenHomomorphicCommitment<G>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PedersenHomomorphicCommitment<G>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+}
+
+impl<G: ProjectiveCurve, F: PrimeField, GG: CurveGadget<G, F>> CommitmentGadget<PedersenHomomorphicCommitment<G>, F>
+    for PedersenHomomorphicCommitmentGadget<G, F, GG>
+{
+    type OutputGadget = GG;
+    type RandomnessGadget = PedersenRandomnessGadget<G>;
+
+    fn randomness_from_bytes<CS: ConstraintSystem<F>>(
+        _cs: CS,
+        bytes: &[UInt8],
+    ) -> Result<Self::RandomnessGadget, SynthesisError> {
+        Ok(PedersenRandomnessGadget(bytes.to_vec(), PhantomData))
+    }
+
+    fn check_commitment_gadget<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        input: &[UInt8],
+        randomness: &Self::RandomnessGadget,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        assert!((input.len() * 8) <= self.pedersen.value_base.len());
+
+        // Compute g^v
+        let mut result = GG::zero(cs.ns(|| "zero"))?;
+        let input_bits = input.iter().flat_map(|byte| byte.to_bits_le());
+        result.scalar_multiplication(cs.ns(|| "value"), input_bits.zip(&self.pedersen.value_base))?;
+
+        // Compute h^r
+        let rand_bits = randomness.0.iter().flat_map(|byte| byte.to_bits_le());
+        result.scalar_multiplication(cs.ns(|| "randomizer"), rand_bits.zip(&self.pedersen.random_base))?;
+
+        Ok(result)
+    }
+}
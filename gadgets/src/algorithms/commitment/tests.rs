@@ -21,10 +21,10 @@ use crate::{
     traits::{algorithms::CommitmentGadget, alloc::AllocGadget, FieldGadget},
 };
 use snarkvm_algorithms::{
-    commitment::{BHPCommitment, PedersenCommitment, PedersenCompressedCommitment},
+    commitment::{BHPCommitment, PedersenCommitment, PedersenCompressedCommitment, PedersenHomomorphicCommitment},
     CommitmentScheme,
 };
-use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fq};
+use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fq, Fr};
 use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
 use snarkvm_utilities::rand::UniformRand;
 
@@ -104,3 +104,47 @@ fn pedersen_compressed_commitment_gadget_test() {
         assert_eq!(native_output, gadget_output.get_value().unwrap());
     }
 }
+
+#[test]
+fn pedersen_homomorphic_commitment_gadget_test() {
+    type TestCommitment = PedersenHomomorphicCommitment<EdwardsProjective>;
+    type TestCommitmentGadget = PedersenHomomorphicCommitmentGadget<EdwardsProjective, Fq, EdwardsBls12Gadget>;
+
+    let rng = &mut thread_rng();
+    let commitment_scheme = TestCommitment::setup("homomorphic_commitment_gadget_test");
+
+    // Compute the native commitment to a 64-bit value, and its rerandomization.
+    let value: u64 = rng.gen();
+    let randomness = Fr::rand(rng);
+    let delta = Fr::rand(rng);
+    let native_output = commitment_scheme.commit(&value.to_le_bytes(), &randomness).unwrap();
+    let native_rerandomized = commitment_scheme.rerandomize(&native_output, &delta);
+
+    // Compute the gadget commitment, and its rerandomization.
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &value.to_le_bytes()).unwrap();
+    let randomness_gadget = <TestCommitmentGadget as CommitmentGadget<TestCommitment, Fq>>::RandomnessGadget::alloc(
+        cs.ns(|| "randomness"),
+        || Ok(&randomness),
+    )
+    .unwrap();
+    let delta_gadget = <TestCommitmentGadget as CommitmentGadget<TestCommitment, Fq>>::RandomnessGadget::alloc(
+        cs.ns(|| "delta"),
+        || Ok(&delta),
+    )
+    .unwrap();
+    let commitment_gadget =
+        TestCommitmentGadget::alloc_constant(cs.ns(|| "parameters"), || Ok(&commitment_scheme)).unwrap();
+    let gadget_output = commitment_gadget
+        .check_commitment_gadget(cs.ns(|| "commitment"), &input_bytes, &randomness_gadget)
+        .unwrap();
+    let gadget_rerandomized = commitment_gadget
+        .rerandomize(cs.ns(|| "rerandomize"), &gadget_output, &delta_gadget)
+        .unwrap();
+    assert!(cs.is_satisfied());
+
+    assert_eq!(native_output.x, gadget_output.x.get_value().unwrap());
+    assert_eq!(native_output.y, gadget_output.y.get_value().unwrap());
+    assert_eq!(native_rerandomized.x, gadget_rerandomized.x.get_value().unwrap());
+    assert_eq!(native_rerandomized.y, gadget_rerandomized.y.get_value().unwrap());
+}
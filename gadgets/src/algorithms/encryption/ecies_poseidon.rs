@@ -558,6 +558,71 @@ fn symmetric_encryption<F: PoseidonDefaultParametersField>(
     Ok(ciphertext)
 }
 
+/// On input the symmetric key and the ciphertext, outputs
+/// the plaintext of the given size.
+fn symmetric_decryption<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>(
+    mut cs: impl ConstraintSystem<F>,
+    encryption: &ECIESPoseidonEncryption<TE>,
+    symmetric_key: &FpGadget<F>,
+    ciphertext: &[UInt8],
+    plaintext_size_in_bytes: usize,
+) -> Result<Vec<UInt8>, SynthesisError> {
+    // Decrypt the ciphertext natively, to witness the plaintext.
+    // If the ciphertext does not decrypt to a plaintext of the given size,
+    // a zero plaintext is witnessed, and the constraints below are unsatisfied.
+    let ciphertext_bytes = ciphertext.iter().map(|byte| byte.value).collect::<Option<Vec<u8>>>();
+    let plaintext = match (symmetric_key.get_value(), ciphertext_bytes) {
+        (Some(symmetric_key), Some(ciphertext)) => encryption
+            .decrypt(&symmetric_key, &ciphertext)
+            .ok()
+            .filter(|plaintext| plaintext.len() == plaintext_size_in_bytes),
+        _ => None,
+    }
+    .unwrap_or_else(|| vec![0u8; plaintext_size_in_bytes]);
+    let plaintext = UInt8::alloc_vec(cs.ns(|| "allocate the plaintext"), &plaintext)?;
+
+    // Re-encrypt the plaintext, and check that it matches the given ciphertext.
+    let candidate_ciphertext = symmetric_encryption(cs.ns(|| "enc with symmetric key"), symmetric_key, &plaintext)?;
+    if candidate_ciphertext.len() != ciphertext.len() {
+        return Err(anyhow!(
+            "The ciphertext is {} bytes, expected {} bytes for a {} byte plaintext",
+            ciphertext.len(),
+            candidate_ciphertext.len(),
+            plaintext_size_in_bytes
+        )
+        .into());
+    }
+    for (i, (candidate, expected)) in candidate_ciphertext.iter().zip_eq(ciphertext).enumerate() {
+        candidate.enforce_equal(cs.ns(|| format!("check ciphertext byte {}", i)), expected)?;
+    }
+
+    Ok(plaintext)
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
+    ECIESPoseidonEncryptionGadget<TE, F>
+{
+    /// On input the ciphertext randomizer and the private key, outputs
+    /// the symmetric key.
+    fn symmetric_key_from_ciphertext_randomizer<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        ciphertext_randomizer: &ECIESPoseidonCiphertextRandomizerGadget<TE, F>,
+        private_key: &ECIESPoseidonEncryptionPrivateKeyGadget<TE, F>,
+    ) -> Result<FpGadget<F>, SynthesisError> {
+        let zero: TEAffineGadget<TE, F> =
+            <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "affine zero")).unwrap();
+
+        let private_key_bits = private_key.0.iter().flat_map(|b| b.to_bits_le()).collect::<Vec<_>>();
+        Ok(<TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::mul_bits(
+            &ciphertext_randomizer.0,
+            cs.ns(|| "compute the symmetric key"),
+            &zero,
+            private_key_bits.iter().copied(),
+        )?
+        .x)
+    }
+}
+
 impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
     EncryptionGadget<ECIESPoseidonEncryption<TE>, F> for ECIESPoseidonEncryptionGadget<TE, F>
 {
@@ -667,20 +732,70 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaul
         private_key: &Self::PrivateKeyGadget,
         message: &[UInt8],
     ) -> Result<Vec<UInt8>, SynthesisError> {
-        let zero: TEAffineGadget<TE, F> =
-            <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "affine zero")).unwrap();
-
         // Compute the symmetric key.
-        let private_key_bits = private_key.0.iter().flat_map(|b| b.to_bits_le()).collect::<Vec<_>>();
-        let symmetric_key = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::mul_bits(
-            &ciphertext_randomizer.0,
-            cs.ns(|| "compute the symmetric key"),
-            &zero,
-            private_key_bits.iter().copied(),
-        )?
-        .x;
+        let symmetric_key = Self::symmetric_key_from_ciphertext_randomizer(
+            cs.ns(|| "symmetric key"),
+            ciphertext_randomizer,
+            private_key,
+        )?;
 
         let ciphertext = symmetric_encryption(cs.ns(|| "enc with symmetric key"), &symmetric_key, message)?;
         Ok(ciphertext)
     }
+
+    /// Assumes symmetric key is committed before hand.
+    /// Otherwise, this allows the decrypter to open the ciphertext to any
+    /// plaintext.
+    ///
+    /// # Returns
+    /// The plaintext of the given size, decrypted by this key.
+    fn check_decryption_from_symmetric_key<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        symmetric_key: &Self::SymmetricKeyGadget,
+        ciphertext: &[UInt8],
+        plaintext_size_in_bytes: usize,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        symmetric_decryption(
+            cs,
+            &self.encryption,
+            &symmetric_key.0,
+            ciphertext,
+            plaintext_size_in_bytes,
+        )
+    }
+
+    fn check_decryption_from_ciphertext_randomizer<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        ciphertext_randomizer: &Self::CiphertextRandomizer,
+        private_key: &Self::PrivateKeyGadget,
+        ciphertext: &[UInt8],
+        plaintext_size_in_bytes: usize,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        // Compute the symmetric key.
+        let symmetric_key = Self::symmetric_key_from_ciphertext_randomizer(
+            cs.ns(|| "symmetric key"),
+            ciphertext_randomizer,
+            private_key,
+        )?;
+
+        symmetric_decryption(
+            cs.ns(|| "dec with symmetric key"),
+            &self.encryption,
+            &symmetric_key,
+            ciphertext,
+            plaintext_size_in_bytes,
+        )
+    }
+
+    fn check_symmetric_key_from_ciphertext_randomizer<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        ciphertext_randomizer: &Self::CiphertextRandomizer,
+        private_key: &Self::PrivateKeyGadget,
+    ) -> Result<Self::SymmetricKeyGadget, SynthesisError> {
+        let symmetric_key = Self::symmetric_key_from_ciphertext_randomizer(cs, ciphertext_randomizer, private_key)?;
+        Ok(ECIESPoseidonEncryptionSymmetricKeyGadget(symmetric_key, PhantomData))
+    }
 }
@@ -20,11 +20,13 @@ mod ecies_poseidon {
         AllocGadget,
         EncryptionGadget,
         EqGadget,
+        ToBytesGadget,
         UInt8,
     };
     use snarkvm_algorithms::{encryption::ECIESPoseidonEncryption, EncryptionScheme};
     use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsParameters};
     use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+    use snarkvm_utilities::ToBytes;
 
     use rand::SeedableRng;
     use rand_chacha::ChaChaRng;
@@ -232,4 +234,169 @@ mod ecies_poseidon {
         }
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn test_ecies_poseidon_decryption_from_ciphertext_randomizer_equivalence() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("test_encryption_gadget");
+
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+        let (_randomness, ciphertext_randomizer, symmetric_key) =
+            encryption_scheme.generate_asymmetric_key(&public_key, rng);
+
+        let message = (0..32).map(|_| rand::random::<u8>()).collect::<Vec<u8>>();
+        let ciphertext = encryption_scheme.encrypt(&symmetric_key, &message).unwrap();
+
+        // Alloc parameters, private key, ciphertext randomizer, and ciphertext
+        let encryption =
+            TestEncryptionSchemeGadget::alloc_constant(&mut cs.ns(|| "parameters_gadget"), || Ok(&encryption_scheme))
+                .unwrap();
+        let private_key_gadget =
+            <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::PrivateKeyGadget::alloc(
+                &mut cs.ns(|| "private_key_gadget"),
+                || Ok(&private_key),
+            )
+            .unwrap();
+        let ciphertext_randomizer_gadget =
+            <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::CiphertextRandomizer::alloc(
+                &mut cs.ns(|| "ciphertext_randomizer_gadget"),
+                || Ok(&ciphertext_randomizer),
+            )
+            .unwrap();
+        let ciphertext_gadget = UInt8::alloc_vec(&mut cs.ns(|| "ciphertext_gadget"), &ciphertext).unwrap();
+
+        // Expected plaintext gadget
+        let expected_message_gadget = UInt8::alloc_vec(&mut cs.ns(|| "plaintext_gadget"), &message).unwrap();
+
+        println!("number of constraints for inputs: {}", cs.num_constraints());
+
+        let message_gadget = encryption
+            .check_decryption_from_ciphertext_randomizer(
+                &mut cs.ns(|| "plaintext_gadget_evaluation"),
+                &ciphertext_randomizer_gadget,
+                &private_key_gadget,
+                &ciphertext_gadget,
+                message.len(),
+            )
+            .unwrap();
+
+        expected_message_gadget
+            .enforce_equal(
+                cs.ns(|| "Check that declared and computed plaintexts are equal"),
+                &message_gadget,
+            )
+            .unwrap();
+
+        println!("number of constraints total: {}", cs.num_constraints());
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+
+        // Ensure a different private key does not decrypt the ciphertext.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let encryption =
+            TestEncryptionSchemeGadget::alloc_constant(&mut cs.ns(|| "parameters_gadget"), || Ok(&encryption_scheme))
+                .unwrap();
+        let private_key_gadget =
+            <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::PrivateKeyGadget::alloc(
+                &mut cs.ns(|| "private_key_gadget"),
+                || Ok(&encryption_scheme.generate_private_key(rng)),
+            )
+            .unwrap();
+        let ciphertext_randomizer_gadget =
+            <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::CiphertextRandomizer::alloc(
+                &mut cs.ns(|| "ciphertext_randomizer_gadget"),
+                || Ok(&ciphertext_randomizer),
+            )
+            .unwrap();
+        let ciphertext_gadget = UInt8::alloc_vec(&mut cs.ns(|| "ciphertext_gadget"), &ciphertext).unwrap();
+
+        encryption
+            .check_decryption_from_ciphertext_randomizer(
+                &mut cs.ns(|| "plaintext_gadget_evaluation"),
+                &ciphertext_randomizer_gadget,
+                &private_key_gadget,
+                &ciphertext_gadget,
+                message.len(),
+            )
+            .unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_ecies_poseidon_symmetric_key_from_ciphertext_randomizer_equivalence() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("test_encryption_gadget");
+
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+        let (_randomness, ciphertext_randomizer, symmetric_key) =
+            encryption_scheme.generate_asymmetric_key(&public_key, rng);
+        let symmetric_key_commitment = encryption_scheme.generate_symmetric_key_commitment(&symmetric_key);
+
+        // Returns `true` if the given private key derives the expected symmetric key commitment in-circuit.
+        let is_satisfied = |private_key: &<TestEncryptionScheme as EncryptionScheme>::PrivateKey| {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            // Alloc parameters, private key, and ciphertext randomizer
+            let encryption = TestEncryptionSchemeGadget::alloc_constant(&mut cs.ns(|| "parameters_gadget"), || {
+                Ok(&encryption_scheme)
+            })
+            .unwrap();
+            let private_key_gadget =
+                <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::PrivateKeyGadget::alloc(
+                    &mut cs.ns(|| "private_key_gadget"),
+                    || Ok(private_key),
+                )
+                .unwrap();
+            let ciphertext_randomizer_gadget =
+                <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::CiphertextRandomizer::alloc(
+                    &mut cs.ns(|| "ciphertext_randomizer_gadget"),
+                    || Ok(&ciphertext_randomizer),
+                )
+                .unwrap();
+
+            // Expected symmetric key commitment gadget
+            let expected_commitment_gadget = UInt8::alloc_vec(
+                &mut cs.ns(|| "symmetric_key_commitment_gadget"),
+                &symmetric_key_commitment.to_bytes_le().unwrap(),
+            )
+            .unwrap();
+
+            let symmetric_key_gadget = encryption
+                .check_symmetric_key_from_ciphertext_randomizer(
+                    &mut cs.ns(|| "symmetric_key_gadget_evaluation"),
+                    &ciphertext_randomizer_gadget,
+                    &private_key_gadget,
+                )
+                .unwrap();
+            let commitment_gadget = encryption
+                .check_symmetric_key_commitment(
+                    &mut cs.ns(|| "symmetric_key_commitment_gadget_evaluation"),
+                    &symmetric_key_gadget,
+                )
+                .unwrap();
+
+            expected_commitment_gadget
+                .enforce_equal(
+                    cs.ns(|| "Check that declared and computed commitments are equal"),
+                    &commitment_gadget.to_bytes(cs.ns(|| "commitment_to_bytes")).unwrap(),
+                )
+                .unwrap();
+
+            cs.is_satisfied()
+        };
+
+        assert!(is_satisfied(&private_key));
+
+        // Ensure a different private key does not derive the symmetric key.
+        assert!(!is_satisfied(&encryption_scheme.generate_private_key(rng)));
+    }
 }
@@ -15,9 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    algorithms::crypto_hash::PoseidonCryptoHashGadget,
+    algorithms::crypto_hash::{PoseidonCryptoHashGadget, PoseidonSpongeGadget},
     traits::alloc::AllocGadget,
+    AlgebraicSpongeVar,
     CryptoHashGadget,
+    FieldGadget,
     FpGadget,
     PRFGadget,
 };
@@ -25,7 +27,7 @@ use snarkvm_algorithms::{crypto_hash::PoseidonDefaultParametersField, prf::Posei
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 pub struct PoseidonPRFGadget<
     F: PrimeField + PoseidonDefaultParametersField,
@@ -33,6 +35,77 @@ pub struct PoseidonPRFGadget<
     const OPTIMIZED_FOR_WEIGHTS: bool,
 >(PhantomData<F>);
 
+impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool>
+    PoseidonPRFGadget<F, RATE, OPTIMIZED_FOR_WEIGHTS>
+{
+    /// Returns `num_outputs` field elements, matching `PoseidonPRF::evaluate_many`.
+    pub fn check_evaluation_many_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        seed: &FpGadget<F>,
+        input: &[FpGadget<F>],
+        num_outputs: usize,
+    ) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        // Construct the input length as a field element.
+        let input_length = {
+            let mut buffer = input.len().to_le_bytes().to_vec();
+            buffer.resize((F::size_in_bits() + 7) / 8, 0u8);
+            F::from_bytes_le(&buffer)?
+        };
+
+        // Allocate the input length as a constant, as it is fixed by the circuit.
+        let input_length_gadget =
+            FpGadget::<F>::alloc_constant(cs.ns(|| "Allocate input length"), || Ok(&input_length))?;
+
+        // Construct the preimage.
+        let mut preimage = vec![seed.clone()];
+        preimage.push(input_length_gadget);
+        preimage.extend_from_slice(input);
+
+        // Evaluate the preimage.
+        let parameters = F::get_default_poseidon_parameters::<RATE>(OPTIMIZED_FOR_WEIGHTS)
+            .ok_or(SynthesisError::AssignmentMissing)?;
+        let mut sponge =
+            PoseidonSpongeGadget::<F, RATE, 1>::with_parameters(cs.ns(|| "Initialize sponge"), &Arc::new(parameters));
+        sponge.absorb(cs.ns(|| "Absorb preimage"), preimage.iter())?;
+        sponge.squeeze_field_elements(cs.ns(|| "Squeeze outputs"), num_outputs)
+    }
+
+    /// Returns the ciphertext of the given plaintext, matching `PoseidonPRF::encrypt`.
+    pub fn check_encryption_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        key: &FpGadget<F>,
+        nonce: &[FpGadget<F>],
+        plaintext: &[FpGadget<F>],
+    ) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let keystream = Self::check_evaluation_many_gadget(cs.ns(|| "Compute keystream"), key, nonce, plaintext.len())?;
+
+        plaintext
+            .iter()
+            .zip(keystream.iter())
+            .enumerate()
+            .map(|(i, (p, k))| p.add(cs.ns(|| format!("Encrypt element {}", i)), k))
+            .collect()
+    }
+
+    /// Returns the plaintext of the given ciphertext, matching `PoseidonPRF::decrypt`.
+    pub fn check_decryption_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        key: &FpGadget<F>,
+        nonce: &[FpGadget<F>],
+        ciphertext: &[FpGadget<F>],
+    ) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let keystream =
+            Self::check_evaluation_many_gadget(cs.ns(|| "Compute keystream"), key, nonce, ciphertext.len())?;
+
+        ciphertext
+            .iter()
+            .zip(keystream.iter())
+            .enumerate()
+            .map(|(i, (c, k))| c.sub(cs.ns(|| format!("Decrypt element {}", i)), k))
+            .collect()
+    }
+}
+
 impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool>
     PRFGadget<PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS>, F> for PoseidonPRFGadget<F, RATE, OPTIMIZED_FOR_WEIGHTS>
 {
@@ -72,7 +145,8 @@ impl<F: PrimeField + PoseidonDefaultParametersField, const RATE: usize, const OP
 mod tests {
     use crate::{
         algorithms::prf::*,
-        traits::{algorithms::PRFGadget, alloc::AllocGadget, eq::EqGadget},
+        traits::{algorithms::PRFGadget, alloc::AllocGadget, eq::EqGadget, fields::FieldGadget},
+        FpGadget,
     };
     use snarkvm_algorithms::{prf::PoseidonPRF, traits::PRF};
     use snarkvm_curves::bls12_377::Fr;
@@ -118,4 +192,62 @@ mod tests {
         }
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn test_prf_encryption() {
+        let mut rng = ChaChaRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let key: Fr = rng.gen();
+        let nonce: Vec<Fr> = vec![rng.gen(), rng.gen()];
+        let plaintext: Vec<Fr> = (0..12).map(|_| rng.gen()).collect();
+
+        // Ensure the native encryption round-trips, and extends the PRF output.
+        let ciphertext = PoseidonPRF::<Fr, 4, false>::encrypt(&key, &nonce, &plaintext).unwrap();
+        assert_eq!(
+            plaintext,
+            PoseidonPRF::<Fr, 4, false>::decrypt(&key, &nonce, &ciphertext).unwrap()
+        );
+        assert_eq!(
+            PoseidonPRF::<Fr, 4, false>::evaluate(&key, &nonce).unwrap(),
+            PoseidonPRF::<Fr, 4, false>::evaluate_many(&key, &nonce, 12).unwrap()[0]
+        );
+
+        let key_gadget = FpGadget::alloc(&mut cs.ns(|| "key"), || Ok(key)).unwrap();
+        let nonce_gadget = Vec::<FpGadget<Fr>>::alloc(&mut cs.ns(|| "nonce"), || Ok(nonce)).unwrap();
+        let plaintext_gadget =
+            Vec::<FpGadget<Fr>>::alloc(&mut cs.ns(|| "plaintext"), || Ok(plaintext.clone())).unwrap();
+
+        // Ensure the in-circuit encryption matches the native encryption.
+        let candidate_ciphertext = PoseidonPRFGadget::<Fr, 4, false>::check_encryption_gadget(
+            &mut cs.ns(|| "encrypt"),
+            &key_gadget,
+            &nonce_gadget,
+            &plaintext_gadget,
+        )
+        .unwrap();
+        assert_eq!(
+            ciphertext,
+            candidate_ciphertext
+                .iter()
+                .map(|c| c.get_value().unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        // Ensure the in-circuit decryption recovers the plaintext.
+        let candidate_plaintext = PoseidonPRFGadget::<Fr, 4, false>::check_decryption_gadget(
+            &mut cs.ns(|| "decrypt"),
+            &key_gadget,
+            &nonce_gadget,
+            &candidate_ciphertext,
+        )
+        .unwrap();
+        for (i, (expected, candidate)) in plaintext_gadget.iter().zip(&candidate_plaintext).enumerate() {
+            candidate
+                .enforce_equal(&mut cs.ns(|| format!("enforce equal {}", i)), expected)
+                .unwrap();
+        }
+
+        assert!(cs.is_satisfied());
+    }
 }
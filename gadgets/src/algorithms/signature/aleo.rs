@@ -45,7 +45,7 @@ use anyhow::Result;
 use itertools::Itertools;
 use std::{borrow::Borrow, marker::PhantomData};
 
-type TEAffineGadget<TE, F> = crate::curves::templates::twisted_edwards::AffineGadget<TE, F, FpGadget<F>>;
+pub(crate) type TEAffineGadget<TE, F> = crate::curves::templates::twisted_edwards::AffineGadget<TE, F, FpGadget<F>>;
 
 #[derive(Derivative)]
 #[derivative(
@@ -55,7 +55,7 @@ type TEAffineGadget<TE, F> = crate::curves::templates::twisted_edwards::AffineGa
     Debug(bound = "TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField")
 )]
 pub struct AleoSignaturePublicKeyGadget<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField>(
-    TEAffineGadget<TE, F>,
+    pub(crate) TEAffineGadget<TE, F>,
 );
 
 impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField> AllocGadget<TEAffine<TE>, F>
@@ -364,6 +364,49 @@ pub struct AleoSignatureSchemeGadget<
     pub(crate) _engine: PhantomData<F>,
 }
 
+impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
+    AleoSignatureSchemeGadget<TE, F>
+{
+    /// Returns G^scalar, for the given little-endian bits of the scalar.
+    pub(crate) fn g_scalar_multiply<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        scalar_bits: &[Boolean],
+    ) -> Result<TEAffineGadget<TE, F>, SynthesisError> {
+        let mut g_scalar = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "affine zero"))?;
+        for (i, (base, bit)) in self.signature.parameters().iter().zip_eq(scalar_bits).enumerate() {
+            let added = g_scalar.add_constant(cs.ns(|| format!("add_g_scalar_{}", i)), base)?;
+
+            g_scalar = TEAffineGadget::<TE, F>::conditionally_select(
+                cs.ns(|| format!("cond_select_g_scalar_{}", i)),
+                bit,
+                &added,
+                &g_scalar,
+            )?;
+        }
+        Ok(g_scalar)
+    }
+
+    /// Returns the little-endian bits of the hash of the given input into the scalar field,
+    /// padded to MODULUS_BITS as in `AleoSignatureScheme::hash_to_scalar_field`.
+    pub(crate) fn hash_to_scalar_field<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        input: &[FpGadget<F>],
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let output = PoseidonCryptoHashGadget::<F, 4, false>::check_evaluation_gadget(cs.ns(|| "Poseidon"), input)?;
+
+        // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
+        let mut bits = output.to_bits_le_strict(cs.ns(|| "Convert the output into bits"))?;
+        bits.resize(
+            <TE::ScalarField as PrimeField>::Parameters::CAPACITY as usize,
+            Boolean::Constant(false),
+        );
+        bits.push(Boolean::Constant(false)); // Append one 0 bit to match MODULUS_BITS size.
+        Ok(bits)
+    }
+}
+
 impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
     AllocGadget<AleoSignatureScheme<TE>, F> for AleoSignatureSchemeGadget<TE, F>
 {
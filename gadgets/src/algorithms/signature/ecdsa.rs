@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    nonnative::{NonNativeAffineVar, NonNativeFieldVar},
+    traits::{
+        algorithms::SignatureGadget,
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        fields::FieldGadget,
+        integers::Integer,
+        select::CondSelectGadget,
+    },
+    ToBitsLEGadget,
+};
+use snarkvm_algorithms::signature::{ECDSASignature, ECDSASignatureScheme, ECDSA_MESSAGE_HASH_SIZE};
+use snarkvm_curves::{
+    secp256k1::{Fr, Secp256k1Affine, Secp256k1Parameters},
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use anyhow::anyhow;
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// A secp256k1 point in affine coordinates, whose coordinates are non-native field elements.
+pub type Secp256k1PointGadget<F> = NonNativeAffineVar<Secp256k1Parameters, F>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ECDSASignatureGadget<F: PrimeField> {
+    pub(crate) r: NonNativeFieldVar<Fr, F>,
+    pub(crate) s: NonNativeFieldVar<Fr, F>,
+}
+
+impl<F: PrimeField> AllocGadget<ECDSASignature, F> for ECDSASignatureGadget<F> {
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<ECDSASignature>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let signature = *value_gen()?.borrow();
+        Ok(Self {
+            r: NonNativeFieldVar::Constant(signature.r()),
+            s: NonNativeFieldVar::Constant(signature.s()),
+        })
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<ECDSASignature>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let (r, s) = match value_gen() {
+            Ok(signature) => {
+                let signature = *signature.borrow();
+                (Ok(signature.r()), Ok(signature.s()))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        Ok(Self {
+            r: NonNativeFieldVar::alloc(cs.ns(|| "alloc_r"), || r)?,
+            s: NonNativeFieldVar::alloc(cs.ns(|| "alloc_s"), || s)?,
+        })
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<ECDSASignature>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let (r, s) = match value_gen() {
+            Ok(signature) => {
+                let signature = *signature.borrow();
+                (Ok(signature.r()), Ok(signature.s()))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        Ok(Self {
+            r: NonNativeFieldVar::alloc_input(cs.ns(|| "alloc_input_r"), || r)?,
+            s: NonNativeFieldVar::alloc_input(cs.ns(|| "alloc_input_s"), || s)?,
+        })
+    }
+}
+
+impl<F: PrimeField> ConditionalEqGadget<F> for ECDSASignatureGadget<F> {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.r
+            .conditional_enforce_equal(cs.ns(|| "r_conditional_enforce_equal"), &other.r, condition)?;
+        self.s
+            .conditional_enforce_equal(cs.ns(|| "s_conditional_enforce_equal"), &other.s, condition)
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
+
+impl<F: PrimeField> EqGadget<F> for ECDSASignatureGadget<F> {
+    fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let r_is_eq = self.r.is_eq(cs.ns(|| "r_is_eq"), &other.r)?;
+        let s_is_eq = self.s.is_eq(cs.ns(|| "s_is_eq"), &other.s)?;
+        Boolean::and(cs.ns(|| "is_eq"), &r_is_eq, &s_is_eq)
+    }
+}
+
+impl<F: PrimeField> ToBytesGadget<F> for ECDSASignatureGadget<F> {
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = self.r.to_bytes(cs.ns(|| "r_to_bytes"))?;
+        result.extend(self.s.to_bytes(cs.ns(|| "s_to_bytes"))?);
+        Ok(result)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = self.r.to_bytes_strict(cs.ns(|| "r_to_bytes_strict"))?;
+        result.extend(self.s.to_bytes_strict(cs.ns(|| "s_to_bytes_strict"))?);
+        Ok(result)
+    }
+}
+
+///
+/// A gadget for verifying ECDSA signatures over secp256k1, whose fields are emulated with non-native arithmetic.
+///
+/// The verifier computes `(z / s) * G + (r / s) * Q` with incomplete additions, so each scalar
+/// multiplication starts from an offset point of unknown discrete logarithm, which is subtracted at the end.
+/// A signature whose verification reaches the point at infinity leaves the circuit unsatisfiable,
+/// whereas the native verifier rejects it. A signature with a zero `s` is inverted as one instead,
+/// and its verification returns false.
+///
+#[derive(Clone, Debug)]
+pub struct ECDSASignatureSchemeGadget<F: PrimeField> {
+    pub(crate) generator: Secp256k1Affine,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> AllocGadget<ECDSASignatureScheme, F> for ECDSASignatureSchemeGadget<F> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<ECDSASignatureScheme>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let signature_scheme = value_gen()?;
+        Ok(Self {
+            generator: *signature_scheme.borrow().parameters(),
+            _field: PhantomData,
+        })
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<ECDSASignatureScheme>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<ECDSASignatureScheme>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+}
+
+impl<F: PrimeField> SignatureGadget<ECDSASignatureScheme, F> for ECDSASignatureSchemeGadget<F> {
+    type ComputeKeyGadget = Vec<Boolean>;
+    type PublicKeyGadget = Secp256k1PointGadget<F>;
+    type SignatureGadget = ECDSASignatureGadget<F>;
+
+    ///
+    /// Returns an error, as ECDSA signatures do not have a compute key
+    /// from which the account serial numbers may be derived.
+    ///
+    fn compute_key<CS: ConstraintSystem<F>>(
+        &self,
+        _cs: CS,
+        _signature: &Self::SignatureGadget,
+    ) -> Result<Self::ComputeKeyGadget, SynthesisError> {
+        Err(anyhow!("ECDSA signatures do not have a compute key").into())
+    }
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        if message.len() != ECDSA_MESSAGE_HASH_SIZE {
+            return Err(anyhow!(
+                "ECDSA signs a {}-byte message hash, found {} bytes",
+                ECDSA_MESSAGE_HASH_SIZE,
+                message.len()
+            )
+            .into());
+        }
+
+        // Read the message hash in big-endian, as in `ECDSASignatureScheme::message_hash_to_scalar`.
+        let message_bits: Vec<Boolean> = message.iter().rev().flat_map(|byte| byte.to_bits_le()).collect();
+        let message_hash = Secp256k1PointGadget::bits_to_scalar(cs.ns(|| "message_hash_to_scalar"), &message_bits)?;
+
+        // Compute the scalars `z / s` and `r / s`, substituting one for a zero `s` so the inverse exists.
+        let s_is_zero = signature
+            .s
+            .is_eq(cs.ns(|| "check_s_is_zero"), &NonNativeFieldVar::Constant(Fr::zero()))?;
+        let s = NonNativeFieldVar::conditionally_select(
+            cs.ns(|| "select_s"),
+            &s_is_zero,
+            &NonNativeFieldVar::Constant(Fr::one()),
+            &signature.s,
+        )?;
+        let s_inverse = s.inverse(cs.ns(|| "s_inverse"))?;
+        let u1 = message_hash.mul(cs.ns(|| "u1"), &s_inverse)?;
+        let u2 = signature.r.mul(cs.ns(|| "u2"), &s_inverse)?;
+        let u1_bits = u1.to_bits_le_strict(cs.ns(|| "u1_to_bits"))?;
+        let u2_bits = u2.to_bits_le_strict(cs.ns(|| "u2_to_bits"))?;
+
+        // Compute `u1 * G + u2 * Q`, and remove the offsets of the scalar multiplications.
+        let (first_offset, second_offset) = Secp256k1PointGadget::<F>::offset_points();
+        let first =
+            Secp256k1PointGadget::fixed_base_mul(cs.ns(|| "fixed_base_mul"), &first_offset, &self.generator, &u1_bits)?;
+        let second = public_key.variable_base_mul(cs.ns(|| "variable_base_mul"), &second_offset, &u2_bits)?;
+        let sum = first.add(cs.ns(|| "add_products"), &second)?;
+        let negated_offsets = -(first_offset.into_projective() + &second_offset.into_projective()).into_affine();
+        let point = sum.add(
+            cs.ns(|| "remove_offsets"),
+            &Secp256k1PointGadget::constant(&negated_offsets),
+        )?;
+
+        // Check that the x-coordinate of the point, reduced modulo the order of the curve, is the nonzero `r`,
+        // and that `s` is nonzero.
+        let x_bits = point.x.to_bits_le_strict(cs.ns(|| "x_to_bits"))?;
+        let x_scalar = Secp256k1PointGadget::bits_to_scalar(cs.ns(|| "x_to_scalar"), &x_bits)?;
+        let r_is_valid = x_scalar.is_eq(cs.ns(|| "check_r"), &signature.r)?;
+        let r_is_zero = signature
+            .r
+            .is_eq(cs.ns(|| "check_r_is_zero"), &NonNativeFieldVar::Constant(Fr::zero()))?;
+        let r_is_valid = Boolean::and(cs.ns(|| "check_r_is_nonzero"), &r_is_valid, &r_is_zero.not())?;
+        Boolean::and(cs.ns(|| "check_signature"), &r_is_valid, &s_is_zero.not())
+    }
+}
@@ -17,5 +17,24 @@
 pub mod aleo;
 pub use aleo::*;
 
+#[cfg(feature = "nonnative")]
+pub mod ecdsa;
+#[cfg(feature = "nonnative")]
+pub use ecdsa::*;
+
+#[cfg(feature = "nonnative")]
+pub mod schnorr;
+#[cfg(feature = "nonnative")]
+pub use schnorr::*;
+
+pub mod winternitz;
+pub use winternitz::*;
+
+pub mod winternitz_account;
+pub use winternitz_account::*;
+
+pub mod winternitz_tree;
+pub use winternitz_tree::*;
+
 #[cfg(test)]
 pub mod tests;
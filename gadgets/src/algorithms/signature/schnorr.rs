@@ -0,0 +1,320 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    nonnative::{NonNativeAffineVar, NonNativeFieldVar},
+    traits::{
+        algorithms::{CRHGadget, SignatureGadget},
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+    },
+    ToBitsLEGadget,
+};
+use snarkvm_algorithms::{
+    signature::{SchnorrSignature, SchnorrSignatureScheme},
+    CRH,
+};
+use snarkvm_curves::{templates::short_weierstrass_jacobian::Affine, ShortWeierstrassParameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use anyhow::anyhow;
+use std::{borrow::Borrow, marker::PhantomData};
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField"),
+    Debug(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField"),
+    PartialEq(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField"),
+    Eq(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField")
+)]
+pub struct SchnorrSignatureGadget<P: ShortWeierstrassParameters, F: PrimeField>
+where
+    P::BaseField: PrimeField,
+{
+    pub(crate) r: NonNativeAffineVar<P, F>,
+    pub(crate) s: NonNativeFieldVar<P::ScalarField, F>,
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> AllocGadget<SchnorrSignature<P>, F> for SchnorrSignatureGadget<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SchnorrSignature<P>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let signature = *value_gen()?.borrow();
+        Ok(Self {
+            r: NonNativeAffineVar::alloc_constant(cs.ns(|| "alloc_constant_r"), || Ok(signature.r()))?,
+            s: NonNativeFieldVar::Constant(signature.s()),
+        })
+    }
+
+    ///
+    /// Allocates the signature, and enforces that its nonce point is on the curve.
+    ///
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<SchnorrSignature<P>>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let (r, s) = match value_gen() {
+            Ok(signature) => {
+                let signature = *signature.borrow();
+                (Ok(signature.r()), Ok(signature.s()))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        Ok(Self {
+            r: NonNativeAffineVar::alloc(cs.ns(|| "alloc_r"), || r)?,
+            s: NonNativeFieldVar::alloc(cs.ns(|| "alloc_s"), || s)?,
+        })
+    }
+
+    ///
+    /// Allocates the signature as a public input, and enforces that its nonce point is on the curve.
+    ///
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SchnorrSignature<P>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let (r, s) = match value_gen() {
+            Ok(signature) => {
+                let signature = *signature.borrow();
+                (Ok(signature.r()), Ok(signature.s()))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        Ok(Self {
+            r: NonNativeAffineVar::alloc_input(cs.ns(|| "alloc_input_r"), || r)?,
+            s: NonNativeFieldVar::alloc_input(cs.ns(|| "alloc_input_s"), || s)?,
+        })
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> ConditionalEqGadget<F> for SchnorrSignatureGadget<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.r
+            .conditional_enforce_equal(cs.ns(|| "r_conditional_enforce_equal"), &other.r, condition)?;
+        self.s
+            .conditional_enforce_equal(cs.ns(|| "s_conditional_enforce_equal"), &other.s, condition)
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> EqGadget<F> for SchnorrSignatureGadget<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let r_is_eq = self.r.is_eq(cs.ns(|| "r_is_eq"), &other.r)?;
+        let s_is_eq = self.s.is_eq(cs.ns(|| "s_is_eq"), &other.s)?;
+        Boolean::and(cs.ns(|| "is_eq"), &r_is_eq, &s_is_eq)
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> ToBytesGadget<F> for SchnorrSignatureGadget<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = self.r.to_bytes(cs.ns(|| "r_to_bytes"))?;
+        result.extend(self.s.to_bytes(cs.ns(|| "s_to_bytes"))?);
+        Ok(result)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = self.r.to_bytes_strict(cs.ns(|| "r_to_bytes_strict"))?;
+        result.extend(self.s.to_bytes_strict(cs.ns(|| "s_to_bytes_strict"))?);
+        Ok(result)
+    }
+}
+
+///
+/// A gadget for verifying Schnorr signatures over a short Weierstrass curve,
+/// whose fields are emulated with non-native arithmetic.
+///
+/// The verifier checks `s * G = R + e * Q` with incomplete additions, so both sides start from
+/// the same offset point of unknown discrete logarithm. A signature whose verification reaches
+/// the point at infinity leaves the circuit unsatisfiable, whereas the native verifier rejects it.
+///
+pub struct SchnorrSignatureSchemeGadget<P: ShortWeierstrassParameters, H: CRH, HG: CRHGadget<H, F>, F: PrimeField>
+where
+    P::BaseField: PrimeField,
+{
+    pub(crate) generator: Affine<P>,
+    pub(crate) crh: HG,
+    _scheme: PhantomData<(H, F)>,
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH, HG: CRHGadget<H, F>, F: PrimeField>
+    SchnorrSignatureSchemeGadget<P, H, HG, F>
+where
+    P::BaseField: PrimeField,
+{
+    /// Returns the bytes of the given coordinate, as in `SchnorrSignatureScheme::coordinate_to_bytes`.
+    fn coordinate_to_bytes<CS: ConstraintSystem<F>>(
+        cs: CS,
+        coordinate: &NonNativeFieldVar<P::BaseField, F>,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut bytes = coordinate.to_bytes_strict(cs)?;
+        bytes.truncate((P::BaseField::size_in_bits() + 7) / 8);
+        Ok(bytes)
+    }
+
+    /// Returns the challenge for the given nonce point, public key, and message,
+    /// as in `SchnorrSignatureScheme::challenge`.
+    fn challenge<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        r: &NonNativeAffineVar<P, F>,
+        public_key: &NonNativeAffineVar<P, F>,
+        message: &[UInt8],
+    ) -> Result<NonNativeFieldVar<P::ScalarField, F>, SynthesisError> {
+        let mut preimage = Vec::with_capacity(message.len());
+        for (i, point) in [r, public_key].iter().enumerate() {
+            let x_bytes = Self::coordinate_to_bytes(cs.ns(|| format!("x_to_bytes_{}", i)), point.x())?;
+            let y_bytes = Self::coordinate_to_bytes(cs.ns(|| format!("y_to_bytes_{}", i)), point.y())?;
+            preimage.extend(x_bytes);
+            preimage.extend(y_bytes);
+        }
+        preimage.extend_from_slice(message);
+
+        let challenge = self.crh.check_evaluation_gadget(cs.ns(|| "hash"), preimage)?;
+        let challenge_bytes = challenge.to_bytes_strict(cs.ns(|| "challenge_to_bytes"))?;
+        let challenge_bits = challenge_bytes.to_bits_le(cs.ns(|| "challenge_to_bits"))?;
+        NonNativeAffineVar::<P, F>::bits_to_scalar(cs.ns(|| "challenge_to_scalar"), &challenge_bits)
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH, HG: CRHGadget<H, F>, F: PrimeField>
+    AllocGadget<SchnorrSignatureScheme<P, H>, F> for SchnorrSignatureSchemeGadget<P, H, HG, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SchnorrSignatureScheme<P, H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let signature_scheme = value_gen()?;
+        Ok(Self {
+            generator: *signature_scheme.borrow().generator(),
+            crh: HG::alloc_constant(cs.ns(|| "alloc_constant_crh"), || {
+                Ok(signature_scheme.borrow().crh().clone())
+            })?,
+            _scheme: PhantomData,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SchnorrSignatureScheme<P, H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SchnorrSignatureScheme<P, H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+}
+
+impl<P: ShortWeierstrassParameters, H: CRH, HG: CRHGadget<H, F>, F: PrimeField>
+    SignatureGadget<SchnorrSignatureScheme<P, H>, F> for SchnorrSignatureSchemeGadget<P, H, HG, F>
+where
+    P::BaseField: PrimeField,
+{
+    type ComputeKeyGadget = Vec<Boolean>;
+    type PublicKeyGadget = NonNativeAffineVar<P, F>;
+    type SignatureGadget = SchnorrSignatureGadget<P, F>;
+
+    ///
+    /// Returns an error, as Schnorr signatures over external curves do not have a compute key
+    /// from which the account serial numbers may be derived.
+    ///
+    fn compute_key<CS: ConstraintSystem<F>>(
+        &self,
+        _cs: CS,
+        _signature: &Self::SignatureGadget,
+    ) -> Result<Self::ComputeKeyGadget, SynthesisError> {
+        Err(anyhow!("Schnorr signatures over external curves do not have a compute key").into())
+    }
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        let challenge = self.challenge(cs.ns(|| "challenge"), &signature.r, public_key, message)?;
+        let challenge_bits = challenge.to_bits_le_strict(cs.ns(|| "challenge_scalar_to_bits"))?;
+        let s_bits = signature.s.to_bits_le_strict(cs.ns(|| "s_to_bits"))?;
+
+        // Compute `offset + s * G` and `offset + e * Q + R`, which are equal if and only if `s * G = R + e * Q`.
+        let (offset, _) = NonNativeAffineVar::<P, F>::offset_points();
+        let left = NonNativeAffineVar::fixed_base_mul(cs.ns(|| "fixed_base_mul"), &offset, &self.generator, &s_bits)?;
+        let right = public_key
+            .variable_base_mul(cs.ns(|| "variable_base_mul"), &offset, &challenge_bits)?
+            .add(cs.ns(|| "add_r"), &signature.r)?;
+
+        left.is_eq(cs.ns(|| "check_signature"), &right)
+    }
+}
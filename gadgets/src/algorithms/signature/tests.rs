@@ -152,3 +152,568 @@ mod aleo {
         assert!(cs.is_satisfied());
     }
 }
+
+#[cfg(feature = "nonnative")]
+mod ecdsa {
+    use crate::{
+        algorithms::signature::ECDSASignatureSchemeGadget,
+        integers::uint::UInt8,
+        traits::{algorithms::SignatureGadget, alloc::AllocGadget, eq::EqGadget},
+        Boolean,
+    };
+    use snarkvm_algorithms::{signature::ECDSASignatureScheme, traits::SignatureScheme};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    type TestSignatureScheme = ECDSASignatureScheme;
+    type TestSignatureSchemeGadget = ECDSASignatureSchemeGadget<Fr>;
+
+    fn check_signature_verification(message: &[u8], candidate_message: &[u8], expected: bool) {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let signature_scheme = TestSignatureScheme::setup("ecdsa_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+        assert_eq!(
+            expected,
+            signature_scheme
+                .verify(&public_key, candidate_message, &signature)
+                .unwrap()
+        );
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), candidate_message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_signature_verification() {
+        let message = [7u8; 32];
+        check_signature_verification(&message, &message, true);
+    }
+
+    #[test]
+    fn failed_test_signature_verification() {
+        check_signature_verification(&[7u8; 32], &[8u8; 32], false);
+    }
+
+    #[test]
+    fn test_zero_s_signature_verification() {
+        use snarkvm_algorithms::signature::ECDSASignature;
+        use snarkvm_fields::Zero;
+
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+        let message = [7u8; 32];
+
+        let signature_scheme = TestSignatureScheme::setup("ecdsa_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, &message, rng).unwrap();
+        let signature = ECDSASignature::new(signature.r(), snarkvm_curves::secp256k1::Fr::zero());
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), &message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(false))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_public_key_not_on_curve() {
+        use snarkvm_curves::secp256k1::{Fq, Secp256k1Affine};
+        use snarkvm_fields::One;
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+            cs.ns(|| "alloc_public_key"),
+            || Ok(Secp256k1Affine::new(Fq::one(), Fq::one(), false)),
+        )
+        .unwrap();
+        assert!(!cs.is_satisfied());
+    }
+}
+
+#[cfg(feature = "nonnative")]
+mod schnorr {
+    use crate::{
+        algorithms::{crh::PoseidonCRHGadget, signature::SchnorrSignatureSchemeGadget},
+        integers::uint::UInt8,
+        traits::{algorithms::SignatureGadget, alloc::AllocGadget, eq::EqGadget},
+        Boolean,
+    };
+    use snarkvm_algorithms::{
+        crh::PoseidonCRH,
+        signature::{SchnorrSignature, SchnorrSignatureScheme},
+        traits::SignatureScheme,
+    };
+    use snarkvm_curves::{bls12_377::Fr, secp256k1::Secp256k1Parameters};
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    type TestCRH = PoseidonCRH<Fr, 8>;
+    type TestSignatureScheme = SchnorrSignatureScheme<Secp256k1Parameters, TestCRH>;
+    type TestSignatureSchemeGadget =
+        SchnorrSignatureSchemeGadget<Secp256k1Parameters, TestCRH, PoseidonCRHGadget<Fr, 8>, Fr>;
+
+    fn check_signature_verification(
+        message: &[u8],
+        candidate_message: &[u8],
+        signature: Option<SchnorrSignature<Secp256k1Parameters>>,
+        expected: bool,
+    ) {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let signature_scheme = TestSignatureScheme::setup("schnorr_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = match signature {
+            Some(signature) => signature,
+            None => signature_scheme.sign(&private_key, message, rng).unwrap(),
+        };
+        assert_eq!(
+            expected,
+            signature_scheme
+                .verify(&public_key, candidate_message, &signature)
+                .unwrap()
+        );
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), candidate_message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_signature_verification() {
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+        check_signature_verification(message, message, None, true);
+    }
+
+    #[test]
+    fn failed_test_signature_verification() {
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+        check_signature_verification(message, b"Bad message", None, false);
+    }
+
+    #[test]
+    fn failed_test_signature_of_other_key() {
+        let rng = &mut ChaChaRng::seed_from_u64(7u64);
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+
+        let signature_scheme = TestSignatureScheme::setup("schnorr_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+        check_signature_verification(message, message, Some(signature), false);
+    }
+}
+
+mod winternitz {
+    use crate::{
+        algorithms::{crh::PoseidonCRHGadget, signature::WinternitzSignatureSchemeGadget},
+        integers::uint::UInt8,
+        traits::{algorithms::SignatureGadget, alloc::AllocGadget, eq::EqGadget},
+        Boolean,
+    };
+    use snarkvm_algorithms::{crh::PoseidonCRH, signature::WinternitzSignatureScheme, traits::SignatureScheme};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    type TestCRH = PoseidonCRH<Fr, 3>;
+    type TestSignatureScheme = WinternitzSignatureScheme<TestCRH>;
+    type TestSignatureSchemeGadget = WinternitzSignatureSchemeGadget<TestCRH, PoseidonCRHGadget<Fr, 3>, Fr>;
+
+    fn check_signature_verification(message: &[u8], candidate_message: &[u8], expected: bool) {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let signature_scheme = TestSignatureScheme::setup("winternitz_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+        assert_eq!(
+            expected,
+            signature_scheme
+                .verify(&public_key, candidate_message, &signature)
+                .unwrap()
+        );
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), candidate_message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_signature_verification() {
+        let message = "Hi, I am a Winternitz signature!".as_bytes();
+        check_signature_verification(message, message, true);
+    }
+
+    #[test]
+    fn failed_test_signature_verification() {
+        let message = "Hi, I am a Winternitz signature!".as_bytes();
+        check_signature_verification(message, "Bad Message, of the same length.".as_bytes(), false);
+    }
+}
+
+mod winternitz_tree {
+    use crate::{
+        algorithms::{crh::PoseidonCRHGadget, signature::WinternitzTreeSignatureSchemeGadget},
+        integers::uint::UInt8,
+        traits::{algorithms::SignatureGadget, alloc::AllocGadget, eq::EqGadget},
+        Boolean,
+    };
+    use snarkvm_algorithms::{crh::PoseidonCRH, signature::WinternitzTreeSignatureScheme, traits::SignatureScheme};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    type TestCRH = PoseidonCRH<Fr, 3>;
+    type TestSignatureScheme = WinternitzTreeSignatureScheme<TestCRH, 1>;
+    type TestSignatureSchemeGadget = WinternitzTreeSignatureSchemeGadget<TestCRH, PoseidonCRHGadget<Fr, 3>, Fr, 1>;
+
+    fn check_signature_verification(message: &[u8], candidate_message: &[u8], expected: bool) {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let signature_scheme = TestSignatureScheme::setup("winternitz_tree_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+        assert_eq!(
+            expected,
+            signature_scheme
+                .verify(&public_key, candidate_message, &signature)
+                .unwrap()
+        );
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), candidate_message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_signature_verification() {
+        let message = "Hi, I am a Winternitz tree signature!".as_bytes();
+        check_signature_verification(message, message, true);
+    }
+
+    #[test]
+    fn failed_test_signature_verification() {
+        let message = "Hi, I am a Winternitz tree signature!".as_bytes();
+        check_signature_verification(message, "Bad Message, of the very same length.".as_bytes(), false);
+    }
+}
+
+mod winternitz_account {
+    use crate::{
+        algorithms::{crh::PoseidonCRHGadget, signature::WinternitzAccountSignatureSchemeGadget},
+        integers::uint::UInt8,
+        traits::{algorithms::SignatureGadget, alloc::AllocGadget, eq::EqGadget},
+        Boolean,
+    };
+    use snarkvm_algorithms::{
+        crh::PoseidonCRH,
+        signature::WinternitzAccountSignatureScheme,
+        traits::{SignatureScheme, SignatureSchemeOperations},
+    };
+    use snarkvm_curves::{
+        bls12_377::Fr,
+        edwards_bls12::{EdwardsParameters, Fr as EdwardsFr},
+    };
+    use snarkvm_fields::{FieldParameters, PrimeField};
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+    use snarkvm_utilities::ToBits;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    type TestCRH = PoseidonCRH<Fr, 3>;
+    type TestSignatureScheme = WinternitzAccountSignatureScheme<EdwardsParameters, TestCRH, 1>;
+    type TestSignatureSchemeGadget =
+        WinternitzAccountSignatureSchemeGadget<EdwardsParameters, TestCRH, PoseidonCRHGadget<Fr, 3>, Fr, 1>;
+
+    fn check_signature_verification(message: &[u8], candidate_message: &[u8], expected: bool) {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let signature_scheme = TestSignatureScheme::setup("winternitz_account_signature_verification_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+        assert_eq!(
+            expected,
+            signature_scheme
+                .verify(&public_key, candidate_message, &signature)
+                .unwrap()
+        );
+
+        // Compute the expected sk_prf.
+        let pk_sig = signature_scheme.pk_sig(&signature).unwrap();
+        let pr_sig = signature_scheme.pr_sig(&signature).unwrap();
+        let sk_prf = signature_scheme.hash_to_scalar_field(&[pk_sig.x, pr_sig.x]);
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), candidate_message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        // Ensure the compute key in the circuit matches the native compute key.
+        let compute_key_gadget = signature_scheme_gadget
+            .compute_key(cs.ns(|| "compute_key"), &signature_gadget)
+            .unwrap();
+        let mut expected_sk_prf_bits = sk_prf.to_bits_le();
+        expected_sk_prf_bits.truncate(<EdwardsFr as PrimeField>::Parameters::CAPACITY as usize);
+        assert_eq!(
+            expected_sk_prf_bits,
+            compute_key_gadget
+                .sk_prf_bits
+                .iter()
+                .map(|bit| bit.get_value().unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_signature_verification() {
+        let message = "Hi, I am a Winternitz account signature!".as_bytes();
+        check_signature_verification(message, message, true);
+    }
+
+    #[test]
+    fn failed_test_signature_verification() {
+        let message = "Hi, I am a Winternitz account signature!".as_bytes();
+        check_signature_verification(message, "Bad Message, of precisely the same size.".as_bytes(), false);
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{CRHGadget, SignatureGadget},
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        fields::FieldGadget,
+        integers::Integer,
+        select::CondSelectGadget,
+    },
+    FpGadget,
+    ToBitsLEGadget,
+};
+use snarkvm_algorithms::{
+    signature::{
+        WinternitzSignature,
+        WinternitzSignatureScheme,
+        WINTERNITZ_CHAIN_DOMAIN,
+        WINTERNITZ_CHECKSUM_DIGITS,
+        WINTERNITZ_DIGIT_BITS,
+        WINTERNITZ_MESSAGE_DIGITS,
+        WINTERNITZ_MESSAGE_DOMAIN,
+        WINTERNITZ_NUM_CHAINS,
+        WINTERNITZ_PUBLIC_KEY_DOMAIN,
+    },
+    CRH,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use std::{borrow::Borrow, marker::PhantomData};
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    PartialEq(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    Eq(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    Debug(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField")
+)]
+pub struct WinternitzSignatureGadget<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> {
+    pub(crate) chain_values: Vec<HG::OutputGadget>,
+    _scheme: PhantomData<(H, F)>,
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> AllocGadget<WinternitzSignature<H>, F>
+    for WinternitzSignatureGadget<H, HG, F>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzSignature<H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let chain_values = value
+            .borrow()
+            .chain_values()
+            .iter()
+            .enumerate()
+            .map(|(i, chain_value)| {
+                HG::OutputGadget::alloc_constant(cs.ns(|| format!("alloc_constant_chain_value_{}", i)), || {
+                    Ok(*chain_value)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            chain_values,
+            _scheme: PhantomData,
+        })
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<WinternitzSignature<H>>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let chain_values = value
+            .borrow()
+            .chain_values()
+            .iter()
+            .enumerate()
+            .map(|(i, chain_value)| {
+                HG::OutputGadget::alloc(cs.ns(|| format!("alloc_chain_value_{}", i)), || Ok(*chain_value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            chain_values,
+            _scheme: PhantomData,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzSignature<H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let chain_values = value
+            .borrow()
+            .chain_values()
+            .iter()
+            .enumerate()
+            .map(|(i, chain_value)| {
+                HG::OutputGadget::alloc_input(cs.ns(|| format!("alloc_input_chain_value_{}", i)), || Ok(*chain_value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            chain_values,
+            _scheme: PhantomData,
+        })
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> ConditionalEqGadget<F> for WinternitzSignatureGadget<H, HG, F> {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.chain_values.conditional_enforce_equal(
+            &mut cs.ns(|| "chain_values_conditional_enforce_equal"),
+            &other.chain_values,
+            condition,
+        )
+    }
+
+    fn cost() -> usize {
+        <HG::OutputGadget as ConditionalEqGadget<F>>::cost() * WINTERNITZ_NUM_CHAINS
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> EqGadget<F> for WinternitzSignatureGadget<H, HG, F> {
+    fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        self.chain_values
+            .is_eq(&mut cs.ns(|| "chain_values_is_eq"), &other.chain_values)
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> ToBytesGadget<F> for WinternitzSignatureGadget<H, HG, F> {
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = Vec::new();
+        for (i, chain_value) in self.chain_values.iter().enumerate() {
+            result.extend(chain_value.to_bytes(&mut cs.ns(|| format!("chain_value_to_bytes_{}", i)))?);
+        }
+        Ok(result)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = Vec::new();
+        for (i, chain_value) in self.chain_values.iter().enumerate() {
+            result.extend(chain_value.to_bytes_strict(&mut cs.ns(|| format!("chain_value_to_bytes_strict_{}", i)))?);
+        }
+        Ok(result)
+    }
+}
+
+pub struct WinternitzSignatureSchemeGadget<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> {
+    pub(crate) crh: HG,
+    _scheme: PhantomData<(H, F)>,
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> WinternitzSignatureSchemeGadget<H, HG, F> {
+    /// Returns the hash of the given digest, domain separator, and input.
+    fn compress<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        digest: &HG::OutputGadget,
+        domain: u8,
+        input: &[UInt8],
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        let mut preimage = digest.to_bytes_strict(cs.ns(|| "digest_to_bytes"))?;
+        preimage.push(UInt8::constant(domain));
+        preimage.extend_from_slice(input);
+        self.crh.check_evaluation_gadget(cs.ns(|| "hash"), preimage)
+    }
+
+    /// Returns the value of the hash chain with the given index, one step after the given value.
+    fn chain_step<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        index: usize,
+        value: &HG::OutputGadget,
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        let mut preimage = value.to_bytes_strict(cs.ns(|| "value_to_bytes"))?;
+        preimage.extend(UInt8::constant_vec(&[WINTERNITZ_CHAIN_DOMAIN, index as u8]));
+        self.crh.check_evaluation_gadget(cs.ns(|| "hash"), preimage)
+    }
+
+    /// Returns the bits of the digits to sign for the given message, as in `WinternitzSignatureScheme`.
+    fn to_digits<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        message: &[UInt8],
+    ) -> Result<Vec<Vec<Boolean>>, SynthesisError> {
+        // Hash the message length, followed by the message in chunks of 32 bytes.
+        let default_digest =
+            HG::OutputGadget::alloc_constant(cs.ns(|| "default_digest"), || Ok(<H::Output as Default>::default()))?;
+        let mut digest = self.compress(
+            cs.ns(|| "hash_message_length"),
+            &default_digest,
+            WINTERNITZ_MESSAGE_DOMAIN,
+            &UInt8::constant_vec(&(message.len() as u32).to_le_bytes()),
+        )?;
+        for (i, chunk) in message.chunks(32).enumerate() {
+            digest = self.compress(
+                cs.ns(|| format!("hash_message_chunk_{}", i)),
+                &digest,
+                WINTERNITZ_MESSAGE_DOMAIN,
+                chunk,
+            )?;
+        }
+
+        let digest_bytes = digest.to_bytes_strict(cs.ns(|| "digest_to_bytes"))?;
+        if digest_bytes.len() * 8 < WINTERNITZ_MESSAGE_DIGITS * WINTERNITZ_DIGIT_BITS {
+            return Err(anyhow!("The hash output is too small for a Winternitz signature").into());
+        }
+
+        let digest_bits = digest_bytes.to_bits_le(cs.ns(|| "digest_to_bits"))?;
+        let mut digits: Vec<Vec<Boolean>> = digest_bits[..WINTERNITZ_MESSAGE_DIGITS * WINTERNITZ_DIGIT_BITS]
+            .chunks(WINTERNITZ_DIGIT_BITS)
+            .map(|digit| digit.to_vec())
+            .collect();
+
+        // Compute the checksum as the sum of the complements of the digits,
+        // where the complement of a digit is its bitwise negation.
+        let mut checksum = FpGadget::<F>::zero(cs.ns(|| "checksum_zero"))?;
+        for (i, digit) in digits.iter().enumerate() {
+            for (j, bit) in digit.iter().enumerate() {
+                let complement = FpGadget::<F>::from_boolean(cs.ns(|| format!("complement_{}_{}", i, j)), bit.not())?;
+                let complement = complement
+                    .mul_by_constant(cs.ns(|| format!("scale_complement_{}_{}", i, j)), &F::from(1u128 << j))?;
+                checksum.add_in_place(cs.ns(|| format!("add_complement_{}_{}", i, j)), &complement)?;
+            }
+        }
+
+        let checksum_bits = checksum.to_bits_le_strict(cs.ns(|| "checksum_to_bits"))?;
+        digits.extend(
+            checksum_bits[..WINTERNITZ_CHECKSUM_DIGITS * WINTERNITZ_DIGIT_BITS]
+                .chunks(WINTERNITZ_DIGIT_BITS)
+                .map(|digit| digit.to_vec()),
+        );
+
+        Ok(digits)
+    }
+
+    /// Returns the public key under which the given signature verifies for the given message.
+    pub(crate) fn recover_public_key<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        message: &[UInt8],
+        signature: &WinternitzSignatureGadget<H, HG, F>,
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        let digits = self.to_digits(cs.ns(|| "message_to_digits"), message)?;
+
+        // Complete each hash chain from the position of its digit. The number of remaining steps
+        // is the complement of the digit, so a block of 2^j steps is taken for each unset bit j.
+        let mut chain_ends = Vec::with_capacity(WINTERNITZ_NUM_CHAINS);
+        for (i, (chain_value, digit)) in signature.chain_values.iter().zip_eq(digits).enumerate() {
+            let mut chain_value = chain_value.clone();
+            for (j, bit) in digit.iter().enumerate() {
+                let mut advanced = chain_value.clone();
+                for k in 0..(1 << j) {
+                    advanced =
+                        self.chain_step(cs.ns(|| format!("chain_{}_block_{}_step_{}", i, j, k)), i, &advanced)?;
+                }
+                chain_value = HG::OutputGadget::conditionally_select(
+                    cs.ns(|| format!("chain_{}_select_block_{}", i, j)),
+                    bit,
+                    &chain_value,
+                    &advanced,
+                )?;
+            }
+            chain_ends.push(chain_value);
+        }
+
+        // Compute the candidate public key from the ends of the hash chains.
+        let mut candidate_public_key =
+            HG::OutputGadget::alloc_constant(cs.ns(|| "default_public_key"), || Ok(<H::Output as Default>::default()))?;
+        for (i, chain_end) in chain_ends.iter().enumerate() {
+            let chain_end_bytes = chain_end.to_bytes_strict(cs.ns(|| format!("chain_end_to_bytes_{}", i)))?;
+            candidate_public_key = self.compress(
+                cs.ns(|| format!("compress_chain_end_{}", i)),
+                &candidate_public_key,
+                WINTERNITZ_PUBLIC_KEY_DOMAIN,
+                &chain_end_bytes,
+            )?;
+        }
+
+        Ok(candidate_public_key)
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> AllocGadget<WinternitzSignatureScheme<H>, F>
+    for WinternitzSignatureSchemeGadget<H, HG, F>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzSignatureScheme<H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let signature_scheme = value_gen()?;
+        Ok(Self {
+            crh: HG::alloc_constant(cs.ns(|| "alloc_constant_crh"), || {
+                Ok(signature_scheme.borrow().crh().clone())
+            })?,
+            _scheme: PhantomData,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzSignatureScheme<H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzSignatureScheme<H>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField> SignatureGadget<WinternitzSignatureScheme<H>, F>
+    for WinternitzSignatureSchemeGadget<H, HG, F>
+{
+    type ComputeKeyGadget = Vec<Boolean>;
+    type PublicKeyGadget = HG::OutputGadget;
+    type SignatureGadget = WinternitzSignatureGadget<H, HG, F>;
+
+    ///
+    /// Returns an error, as hash-based signatures do not have a compute key
+    /// from which the account serial numbers may be derived.
+    ///
+    fn compute_key<CS: ConstraintSystem<F>>(
+        &self,
+        _cs: CS,
+        _signature: &Self::SignatureGadget,
+    ) -> Result<Self::ComputeKeyGadget, SynthesisError> {
+        Err(anyhow!("Winternitz signatures do not have a compute key").into())
+    }
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        let candidate_public_key = self.recover_public_key(cs.ns(|| "recover_public_key"), message, signature)?;
+        public_key.is_eq(cs.ns(|| "Check public key"), &candidate_public_key)
+    }
+}
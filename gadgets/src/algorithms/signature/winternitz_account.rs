@@ -0,0 +1,405 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::{
+        crypto_hash::PoseidonCryptoHashGadget,
+        signature::{
+            AleoComputeKeyGadget,
+            AleoSignaturePublicKeyGadget,
+            AleoSignatureSchemeGadget,
+            TEAffineGadget,
+            WinternitzTreeSignatureGadget,
+            WinternitzTreeSignatureSchemeGadget,
+        },
+    },
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{CRHGadget, SignatureGadget},
+        alloc::AllocGadget,
+        curves::GroupGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+    },
+    CryptoHashGadget,
+    ToBitsLEGadget,
+    ToConstraintFieldGadget,
+};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonDefaultParametersField,
+    signature::{WinternitzAccountSignature, WinternitzAccountSignatureScheme},
+    CRH,
+};
+use snarkvm_curves::{templates::twisted_edwards_extended::Affine as TEAffine, TwistedEdwardsParameters};
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::borrow::Borrow;
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    Eq(bound = "TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    Debug(bound = "TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField")
+)]
+pub struct WinternitzAccountSignatureGadget<
+    TE: TwistedEdwardsParameters<BaseField = F>,
+    H: CRH,
+    HG: CRHGadget<H, F>,
+    F: PrimeField,
+    const DEPTH: usize,
+> {
+    pub(crate) tree_signature: WinternitzTreeSignatureGadget<H, HG, F, DEPTH>,
+    pub(crate) tree_root: HG::OutputGadget,
+    pub(crate) root_randomizer: TEAffineGadget<TE, F>,
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    AllocGadget<WinternitzAccountSignature<TE, H, DEPTH>, F> for WinternitzAccountSignatureGadget<TE, H, HG, F, DEPTH>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzAccountSignature<TE, H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature = value.borrow();
+
+        let tree_signature = WinternitzTreeSignatureGadget::<H, HG, F, DEPTH>::alloc_constant(
+            cs.ns(|| "alloc_constant_tree_signature"),
+            || Ok(signature.tree_signature()),
+        )?;
+        let tree_root =
+            HG::OutputGadget::alloc_constant(cs.ns(|| "alloc_constant_tree_root"), || Ok(*signature.tree_root()))?;
+        let root_randomizer =
+            TEAffineGadget::<TE, F>::alloc_constant(cs.ns(|| "alloc_constant_root_randomizer"), || {
+                Ok(signature.root_randomizer()?)
+            })?;
+
+        Ok(Self {
+            tree_signature,
+            tree_root,
+            root_randomizer,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzAccountSignature<TE, H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature = value.borrow();
+
+        let tree_signature =
+            WinternitzTreeSignatureGadget::<H, HG, F, DEPTH>::alloc(cs.ns(|| "alloc_tree_signature"), || {
+                Ok(signature.tree_signature())
+            })?;
+        let tree_root = HG::OutputGadget::alloc(cs.ns(|| "alloc_tree_root"), || Ok(*signature.tree_root()))?;
+        let root_randomizer =
+            TEAffineGadget::<TE, F>::alloc(cs.ns(|| "alloc_root_randomizer"), || Ok(signature.root_randomizer()?))?;
+
+        Ok(Self {
+            tree_signature,
+            tree_root,
+            root_randomizer,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzAccountSignature<TE, H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature = value.borrow();
+
+        let tree_signature = WinternitzTreeSignatureGadget::<H, HG, F, DEPTH>::alloc_input(
+            cs.ns(|| "alloc_input_tree_signature"),
+            || Ok(signature.tree_signature()),
+        )?;
+        let tree_root =
+            HG::OutputGadget::alloc_input(cs.ns(|| "alloc_input_tree_root"), || Ok(*signature.tree_root()))?;
+        let root_randomizer = TEAffineGadget::<TE, F>::alloc_input(cs.ns(|| "alloc_input_root_randomizer"), || {
+            Ok(signature.root_randomizer()?)
+        })?;
+
+        Ok(Self {
+            tree_signature,
+            tree_root,
+            root_randomizer,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    ConditionalEqGadget<F> for WinternitzAccountSignatureGadget<TE, H, HG, F, DEPTH>
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.tree_signature.conditional_enforce_equal(
+            &mut cs.ns(|| "tree_signature_conditional_enforce_equal"),
+            &other.tree_signature,
+            condition,
+        )?;
+        self.tree_root.conditional_enforce_equal(
+            &mut cs.ns(|| "tree_root_conditional_enforce_equal"),
+            &other.tree_root,
+            condition,
+        )?;
+        self.root_randomizer.conditional_enforce_equal(
+            &mut cs.ns(|| "root_randomizer_conditional_enforce_equal"),
+            &other.root_randomizer,
+            condition,
+        )?;
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        <WinternitzTreeSignatureGadget<H, HG, F, DEPTH> as ConditionalEqGadget<F>>::cost()
+            + <HG::OutputGadget as ConditionalEqGadget<F>>::cost()
+            + <TEAffineGadget<TE, F> as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    EqGadget<F> for WinternitzAccountSignatureGadget<TE, H, HG, F, DEPTH>
+{
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    ToBytesGadget<F> for WinternitzAccountSignatureGadget<TE, H, HG, F, DEPTH>
+{
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = Vec::new();
+        result.extend(self.tree_signature.to_bytes(&mut cs.ns(|| "tree_signature_to_bytes"))?);
+        result.extend(self.tree_root.to_bytes(&mut cs.ns(|| "tree_root_to_bytes"))?);
+        result.extend(
+            self.root_randomizer
+                .to_bytes(&mut cs.ns(|| "root_randomizer_to_bytes"))?,
+        );
+        Ok(result)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = Vec::new();
+        result.extend(
+            self.tree_signature
+                .to_bytes_strict(&mut cs.ns(|| "tree_signature_to_bytes_strict"))?,
+        );
+        result.extend(
+            self.tree_root
+                .to_bytes_strict(&mut cs.ns(|| "tree_root_to_bytes_strict"))?,
+        );
+        result.extend(
+            self.root_randomizer
+                .to_bytes_strict(&mut cs.ns(|| "root_randomizer_to_bytes_strict"))?,
+        );
+        Ok(result)
+    }
+}
+
+pub struct WinternitzAccountSignatureSchemeGadget<
+    TE: TwistedEdwardsParameters<BaseField = F>,
+    H: CRH,
+    HG: CRHGadget<H, F>,
+    F: PrimeField + PoseidonDefaultParametersField,
+    const DEPTH: usize,
+> {
+    pub(crate) account: AleoSignatureSchemeGadget<TE, F>,
+    pub(crate) tree: WinternitzTreeSignatureSchemeGadget<H, HG, F, DEPTH>,
+}
+
+impl<
+    TE: TwistedEdwardsParameters<BaseField = F>,
+    H: CRH,
+    HG: CRHGadget<H, F>,
+    F: PrimeField + PoseidonDefaultParametersField,
+    const DEPTH: usize,
+> WinternitzAccountSignatureSchemeGadget<TE, H, HG, F, DEPTH>
+{
+    /// Returns the signature root public key pk_sig := G^Hash(tree_root).
+    fn pk_sig<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        tree_root: &HG::OutputGadget,
+    ) -> Result<TEAffineGadget<TE, F>, SynthesisError> {
+        let tree_root_bytes = tree_root.to_bytes_strict(cs.ns(|| "tree_root_to_bytes"))?;
+        let tree_root_field_elements =
+            tree_root_bytes.to_constraint_field(cs.ns(|| "convert tree_root into field elements"))?;
+        let pk_sig_bits = self
+            .account
+            .hash_to_scalar_field(cs.ns(|| "Hash tree_root"), &tree_root_field_elements)?;
+        self.account.g_scalar_multiply(cs.ns(|| "Compute pk_sig"), &pk_sig_bits)
+    }
+}
+
+impl<
+    TE: TwistedEdwardsParameters<BaseField = F>,
+    H: CRH,
+    HG: CRHGadget<H, F>,
+    F: PrimeField + PoseidonDefaultParametersField,
+    const DEPTH: usize,
+> AllocGadget<WinternitzAccountSignatureScheme<TE, H, DEPTH>, F>
+    for WinternitzAccountSignatureSchemeGadget<TE, H, HG, F, DEPTH>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzAccountSignatureScheme<TE, H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature_scheme = value.borrow();
+        Ok(Self {
+            account: AleoSignatureSchemeGadget::alloc_constant(cs.ns(|| "alloc_constant_account"), || {
+                Ok(signature_scheme.account_scheme().clone())
+            })?,
+            tree: WinternitzTreeSignatureSchemeGadget::alloc_constant(cs.ns(|| "alloc_constant_tree"), || {
+                Ok(signature_scheme.tree_scheme().clone())
+            })?,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzAccountSignatureScheme<TE, H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzAccountSignatureScheme<TE, H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+}
+
+impl<
+    TE: TwistedEdwardsParameters<BaseField = F>,
+    H: CRH,
+    HG: CRHGadget<H, F>,
+    F: PrimeField + PoseidonDefaultParametersField,
+    const DEPTH: usize,
+> SignatureGadget<WinternitzAccountSignatureScheme<TE, H, DEPTH>, F>
+    for WinternitzAccountSignatureSchemeGadget<TE, H, HG, F, DEPTH>
+{
+    type ComputeKeyGadget = AleoComputeKeyGadget;
+    type PublicKeyGadget = AleoSignaturePublicKeyGadget<TE, F>;
+    type SignatureGadget = WinternitzAccountSignatureGadget<TE, H, HG, F, DEPTH>;
+
+    fn compute_key<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        signature: &Self::SignatureGadget,
+    ) -> Result<Self::ComputeKeyGadget, SynthesisError> {
+        let pk_sig = self.pk_sig(cs.ns(|| "Compute pk_sig"), &signature.tree_root)?;
+
+        let output = PoseidonCryptoHashGadget::<F, 4, false>::check_evaluation_gadget(
+            &mut cs.ns(|| "Hash pk_sig and root_randomizer"),
+            &[pk_sig.to_x_coordinate(), signature.root_randomizer.to_x_coordinate()],
+        )?;
+
+        // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
+        let mut sk_prf_bits = output.to_bits_le_strict(&mut cs.ns(|| "Output hash to bytes"))?;
+        sk_prf_bits.resize(
+            <TE::ScalarField as PrimeField>::Parameters::CAPACITY as usize,
+            Boolean::Constant(false),
+        );
+        Ok(AleoComputeKeyGadget { sk_prf_bits })
+    }
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        // Compute pk_sig := G^Hash(tree_root).
+        let pk_sig = self.pk_sig(cs.ns(|| "Compute pk_sig"), &signature.tree_root)?;
+
+        // Extract G^r_sig.
+        let g_r_sig = &signature.root_randomizer;
+
+        // Compute the candidate public key as (pk_sig G^r_sig G^sk_prf).
+        let candidate_public_key = {
+            // Compute sk_prf := RO(pk_sig || G^r_sig).
+            let sk_prf = self.account.hash_to_scalar_field(
+                cs.ns(|| "Poseidon(pk_sig || G^r_sig)"),
+                &[pk_sig.x.clone(), g_r_sig.x.clone()],
+            )?;
+
+            // Compute G^sk_prf.
+            let g_sk_prf = self.account.g_scalar_multiply(cs.ns(|| "Compute G^sk_prf"), &sk_prf)?;
+
+            // Compute pk_sig G^r_sig.
+            let pk_sig_g_r_sig = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::add(
+                &pk_sig,
+                cs.ns(|| "pk_sig G^r_sig"),
+                g_r_sig,
+            )?;
+
+            // Compute pk_sig G^r_sig G^sk_prf.
+            <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::add(
+                &pk_sig_g_r_sig,
+                cs.ns(|| "pk_sig G^r_sig G^sk_prf"),
+                &g_sk_prf,
+            )?
+        };
+
+        // Check the public key equals.
+        let public_key_equals = public_key
+            .0
+            .is_eq(cs.ns(|| "Check public key"), &candidate_public_key)?;
+
+        // Check the signature of the message under the tree of one-time keys.
+        let tree_signature_verifies = self.tree.verify(
+            cs.ns(|| "Verify tree signature"),
+            &signature.tree_root,
+            message,
+            &signature.tree_signature,
+        )?;
+
+        Boolean::and(cs.ns(|| "a ^ b"), &public_key_equals, &tree_signature_verifies)
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::signature::{WinternitzSignatureGadget, WinternitzSignatureSchemeGadget},
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{CRHGadget, SignatureGadget},
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        integers::Integer,
+        select::CondSelectGadget,
+    },
+};
+use snarkvm_algorithms::{
+    signature::{WinternitzTreeSignature, WinternitzTreeSignatureScheme},
+    CRH,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use std::borrow::Borrow;
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    PartialEq(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    Eq(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField"),
+    Debug(bound = "H: CRH, HG: CRHGadget<H, F>, F: PrimeField")
+)]
+pub struct WinternitzTreeSignatureGadget<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize> {
+    /// The bits of the leaf index, from the bit of the leaf up to the bit of the child of the root.
+    pub(crate) leaf_index_bits: Vec<Boolean>,
+    pub(crate) message_signature: WinternitzSignatureGadget<H, HG, F>,
+    pub(crate) path: Vec<(WinternitzSignatureGadget<H, HG, F>, HG::OutputGadget)>,
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize> AllocGadget<WinternitzTreeSignature<H, DEPTH>, F>
+    for WinternitzTreeSignatureGadget<H, HG, F, DEPTH>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzTreeSignature<H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature = value.borrow();
+
+        let leaf_index_bits = (0..DEPTH)
+            .map(|i| Boolean::constant((signature.leaf_index() >> i) & 1 == 1))
+            .collect();
+        let message_signature = WinternitzSignatureGadget::<H, HG, F>::alloc_constant(
+            cs.ns(|| "alloc_constant_message_signature"),
+            || Ok(signature.message_signature()),
+        )?;
+        let path = signature
+            .path()
+            .iter()
+            .enumerate()
+            .map(|(i, (node_signature, sibling_public_key))| {
+                Ok((
+                    WinternitzSignatureGadget::<H, HG, F>::alloc_constant(
+                        cs.ns(|| format!("alloc_constant_node_signature_{}", i)),
+                        || Ok(node_signature),
+                    )?,
+                    HG::OutputGadget::alloc_constant(
+                        cs.ns(|| format!("alloc_constant_sibling_public_key_{}", i)),
+                        || Ok(*sibling_public_key),
+                    )?,
+                ))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        Ok(Self {
+            leaf_index_bits,
+            message_signature,
+            path,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzTreeSignature<H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature = value.borrow();
+
+        let leaf_index_bits = (0..DEPTH)
+            .map(|i| {
+                Boolean::alloc(cs.ns(|| format!("alloc_leaf_index_bit_{}", i)), || {
+                    Ok((signature.leaf_index() >> i) & 1 == 1)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let message_signature =
+            WinternitzSignatureGadget::<H, HG, F>::alloc(cs.ns(|| "alloc_message_signature"), || {
+                Ok(signature.message_signature())
+            })?;
+        let path = signature
+            .path()
+            .iter()
+            .enumerate()
+            .map(|(i, (node_signature, sibling_public_key))| {
+                Ok((
+                    WinternitzSignatureGadget::<H, HG, F>::alloc(
+                        cs.ns(|| format!("alloc_node_signature_{}", i)),
+                        || Ok(node_signature),
+                    )?,
+                    HG::OutputGadget::alloc(cs.ns(|| format!("alloc_sibling_public_key_{}", i)), || {
+                        Ok(*sibling_public_key)
+                    })?,
+                ))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        Ok(Self {
+            leaf_index_bits,
+            message_signature,
+            path,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzTreeSignature<H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen()?;
+        let signature = value.borrow();
+
+        let leaf_index_bits = (0..DEPTH)
+            .map(|i| {
+                Boolean::alloc_input(cs.ns(|| format!("alloc_input_leaf_index_bit_{}", i)), || {
+                    Ok((signature.leaf_index() >> i) & 1 == 1)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let message_signature =
+            WinternitzSignatureGadget::<H, HG, F>::alloc_input(cs.ns(|| "alloc_input_message_signature"), || {
+                Ok(signature.message_signature())
+            })?;
+        let path = signature
+            .path()
+            .iter()
+            .enumerate()
+            .map(|(i, (node_signature, sibling_public_key))| {
+                Ok((
+                    WinternitzSignatureGadget::<H, HG, F>::alloc_input(
+                        cs.ns(|| format!("alloc_input_node_signature_{}", i)),
+                        || Ok(node_signature),
+                    )?,
+                    HG::OutputGadget::alloc_input(cs.ns(|| format!("alloc_input_sibling_public_key_{}", i)), || {
+                        Ok(*sibling_public_key)
+                    })?,
+                ))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        Ok(Self {
+            leaf_index_bits,
+            message_signature,
+            path,
+        })
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize> ConditionalEqGadget<F>
+    for WinternitzTreeSignatureGadget<H, HG, F, DEPTH>
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.leaf_index_bits.conditional_enforce_equal(
+            &mut cs.ns(|| "leaf_index_bits_conditional_enforce_equal"),
+            &other.leaf_index_bits,
+            condition,
+        )?;
+        self.message_signature.conditional_enforce_equal(
+            &mut cs.ns(|| "message_signature_conditional_enforce_equal"),
+            &other.message_signature,
+            condition,
+        )?;
+        for (i, ((node_signature, sibling_public_key), (other_node_signature, other_sibling_public_key))) in
+            self.path.iter().zip_eq(&other.path).enumerate()
+        {
+            node_signature.conditional_enforce_equal(
+                &mut cs.ns(|| format!("node_signature_conditional_enforce_equal_{}", i)),
+                other_node_signature,
+                condition,
+            )?;
+            sibling_public_key.conditional_enforce_equal(
+                &mut cs.ns(|| format!("sibling_public_key_conditional_enforce_equal_{}", i)),
+                other_sibling_public_key,
+                condition,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        <Boolean as ConditionalEqGadget<F>>::cost() * DEPTH
+            + <WinternitzSignatureGadget<H, HG, F> as ConditionalEqGadget<F>>::cost() * (DEPTH + 1)
+            + <HG::OutputGadget as ConditionalEqGadget<F>>::cost() * DEPTH
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize> EqGadget<F>
+    for WinternitzTreeSignatureGadget<H, HG, F, DEPTH>
+{
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize> ToBytesGadget<F>
+    for WinternitzTreeSignatureGadget<H, HG, F, DEPTH>
+{
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        // Encode the leaf index as a little-endian u64, as in `WinternitzTreeSignature`.
+        let mut leaf_index_bits = self.leaf_index_bits.clone();
+        leaf_index_bits.resize(64, Boolean::constant(false));
+        let mut result: Vec<UInt8> = leaf_index_bits.chunks(8).map(UInt8::from_bits_le).collect();
+
+        result.extend(
+            self.message_signature
+                .to_bytes(&mut cs.ns(|| "message_signature_to_bytes"))?,
+        );
+        for (i, (node_signature, sibling_public_key)) in self.path.iter().enumerate() {
+            result.extend(node_signature.to_bytes(&mut cs.ns(|| format!("node_signature_to_bytes_{}", i)))?);
+            result.extend(sibling_public_key.to_bytes(&mut cs.ns(|| format!("sibling_public_key_to_bytes_{}", i)))?);
+        }
+        Ok(result)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        // Encode the leaf index as a little-endian u64, as in `WinternitzTreeSignature`.
+        let mut leaf_index_bits = self.leaf_index_bits.clone();
+        leaf_index_bits.resize(64, Boolean::constant(false));
+        let mut result: Vec<UInt8> = leaf_index_bits.chunks(8).map(UInt8::from_bits_le).collect();
+
+        result.extend(
+            self.message_signature
+                .to_bytes_strict(&mut cs.ns(|| "message_signature_to_bytes_strict"))?,
+        );
+        for (i, (node_signature, sibling_public_key)) in self.path.iter().enumerate() {
+            result.extend(
+                node_signature.to_bytes_strict(&mut cs.ns(|| format!("node_signature_to_bytes_strict_{}", i)))?,
+            );
+            result.extend(
+                sibling_public_key
+                    .to_bytes_strict(&mut cs.ns(|| format!("sibling_public_key_to_bytes_strict_{}", i)))?,
+            );
+        }
+        Ok(result)
+    }
+}
+
+pub struct WinternitzTreeSignatureSchemeGadget<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize> {
+    pub(crate) one_time: WinternitzSignatureSchemeGadget<H, HG, F>,
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    WinternitzTreeSignatureSchemeGadget<H, HG, F, DEPTH>
+{
+    /// Returns the public key of the root under which the given signature verifies for the given message.
+    pub(crate) fn recover_public_key<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        message: &[UInt8],
+        signature: &WinternitzTreeSignatureGadget<H, HG, F, DEPTH>,
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        // Recover the public key of the leaf.
+        let mut candidate_public_key = self.one_time.recover_public_key(
+            cs.ns(|| "recover_leaf_public_key"),
+            message,
+            &signature.message_signature,
+        )?;
+
+        // Walk up from the leaf to the root, recovering the public key of each node.
+        for (i, (is_right, (node_signature, sibling_public_key))) in
+            signature.leaf_index_bits.iter().zip_eq(&signature.path).enumerate()
+        {
+            let left = HG::OutputGadget::conditionally_select(
+                cs.ns(|| format!("select_left_child_{}", i)),
+                is_right,
+                sibling_public_key,
+                &candidate_public_key,
+            )?;
+            let right = HG::OutputGadget::conditionally_select(
+                cs.ns(|| format!("select_right_child_{}", i)),
+                is_right,
+                &candidate_public_key,
+                sibling_public_key,
+            )?;
+
+            let mut children_message = left.to_bytes_strict(cs.ns(|| format!("left_child_to_bytes_{}", i)))?;
+            children_message.extend(right.to_bytes_strict(cs.ns(|| format!("right_child_to_bytes_{}", i)))?);
+
+            candidate_public_key = self.one_time.recover_public_key(
+                cs.ns(|| format!("recover_node_public_key_{}", i)),
+                &children_message,
+                node_signature,
+            )?;
+        }
+
+        Ok(candidate_public_key)
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    AllocGadget<WinternitzTreeSignatureScheme<H, DEPTH>, F> for WinternitzTreeSignatureSchemeGadget<H, HG, F, DEPTH>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzTreeSignatureScheme<H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let signature_scheme = value_gen()?;
+        Ok(Self {
+            one_time: WinternitzSignatureSchemeGadget::alloc_constant(cs.ns(|| "alloc_constant_one_time"), || {
+                Ok(signature_scheme.borrow().one_time_scheme().clone())
+            })?,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzTreeSignatureScheme<H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<WinternitzTreeSignatureScheme<H, DEPTH>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        unimplemented!()
+    }
+}
+
+impl<H: CRH, HG: CRHGadget<H, F>, F: PrimeField, const DEPTH: usize>
+    SignatureGadget<WinternitzTreeSignatureScheme<H, DEPTH>, F>
+    for WinternitzTreeSignatureSchemeGadget<H, HG, F, DEPTH>
+{
+    type ComputeKeyGadget = Vec<Boolean>;
+    type PublicKeyGadget = HG::OutputGadget;
+    type SignatureGadget = WinternitzTreeSignatureGadget<H, HG, F, DEPTH>;
+
+    ///
+    /// Returns an error, as hash-based signatures do not have a compute key
+    /// from which the account serial numbers may be derived.
+    ///
+    fn compute_key<CS: ConstraintSystem<F>>(
+        &self,
+        _cs: CS,
+        _signature: &Self::SignatureGadget,
+    ) -> Result<Self::ComputeKeyGadget, SynthesisError> {
+        Err(anyhow!("Winternitz tree signatures do not have a compute key").into())
+    }
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        let candidate_public_key = self.recover_public_key(cs.ns(|| "recover_public_key"), message, signature)?;
+        public_key.is_eq(cs.ns(|| "Check public key"), &candidate_public_key)
+    }
+}
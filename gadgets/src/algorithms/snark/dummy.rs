@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use snarkvm_algorithms::{
+    snark::dummy::{DummyProof, DummySNARK},
+    SNARK,
+};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    traits::{algorithms::snark::SNARKVerifierGadget, alloc::AllocGadget, eq::EqGadget},
+    AllocBytesGadget,
+    Boolean,
+    UInt8,
+};
+use snarkvm_utilities::FromBytes;
+
+/// The gadget of a dummy proof, holding its validity flag.
+#[derive(Clone)]
+pub struct DummyProofGadget {
+    pub is_valid: Boolean,
+}
+
+impl<F: Field> AllocGadget<DummyProof, F> for DummyProofGadget {
+    #[inline]
+    fn alloc<FN, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<DummyProof>,
+    {
+        let is_valid = Boolean::alloc(cs.ns(|| "is_valid"), || Ok(value_gen()?.borrow().is_valid()))?;
+        Ok(Self { is_valid })
+    }
+
+    #[inline]
+    fn alloc_input<FN, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<DummyProof>,
+    {
+        let is_valid = Boolean::alloc_input(cs.ns(|| "is_valid"), || Ok(value_gen()?.borrow().is_valid()))?;
+        Ok(Self { is_valid })
+    }
+}
+
+impl<F: Field> AllocBytesGadget<Vec<u8>, F> for DummyProofGadget {
+    #[inline]
+    fn alloc_bytes<FN, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Vec<u8>>,
+    {
+        value_gen().and_then(|proof_bytes| {
+            let proof: DummyProof = FromBytes::read_le(&proof_bytes.borrow()[..])?;
+
+            Self::alloc(cs.ns(|| "alloc_bytes"), || Ok(proof))
+        })
+    }
+
+    #[inline]
+    fn alloc_input_bytes<FN, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Vec<u8>>,
+    {
+        value_gen().and_then(|proof_bytes| {
+            let proof: DummyProof = FromBytes::read_le(&proof_bytes.borrow()[..])?;
+
+            Self::alloc_input(cs.ns(|| "alloc_input_bytes"), || Ok(proof))
+        })
+    }
+}
+
+/// The verifier gadget of `DummySNARK<S>`, which reuses the key and input gadgets of `G`,
+/// and only enforces the validity flag of the proof.
+pub struct DummySNARKVerifierGadget<S: SNARK, G: SNARKVerifierGadget<S>> {
+    _phantom: PhantomData<(S, G)>,
+}
+
+impl<S: SNARK, G: SNARKVerifierGadget<S>> SNARKVerifierGadget<DummySNARK<S>> for DummySNARKVerifierGadget<S, G> {
+    type InputGadget = G::InputGadget;
+    type PreparedVerificationKeyGadget = G::PreparedVerificationKeyGadget;
+    type ProofGadget = DummyProofGadget;
+    type VerificationKeyGadget = G::VerificationKeyGadget;
+
+    fn input_gadget_from_bytes<CS: ConstraintSystem<S::BaseField>>(
+        cs: CS,
+        bytes: &[UInt8],
+    ) -> Result<Self::InputGadget, SynthesisError> {
+        G::input_gadget_from_bytes(cs, bytes)
+    }
+
+    fn prepare_constant_verification_key<CS: ConstraintSystem<S::BaseField>>(
+        cs: CS,
+        verification_key: &S::VerifyingKey,
+    ) -> Result<Self::PreparedVerificationKeyGadget, SynthesisError> {
+        G::prepare_constant_verification_key(cs, verification_key)
+    }
+
+    fn prepared_check_verify<CS: ConstraintSystem<S::BaseField>>(
+        mut cs: CS,
+        _prepared_verification_key: &Self::PreparedVerificationKeyGadget,
+        _input: &Self::InputGadget,
+        proof: &Self::ProofGadget,
+    ) -> Result<(), SynthesisError> {
+        proof
+            .is_valid
+            .enforce_equal(cs.ns(|| "is_valid"), &Boolean::constant(true))
+    }
+}
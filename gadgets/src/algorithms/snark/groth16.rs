@@ -16,7 +16,7 @@
 
 use std::{borrow::Borrow, marker::PhantomData};
 
-use snarkvm_algorithms::snark::groth16::{Groth16, Proof, VerifyingKey};
+use snarkvm_algorithms::snark::groth16::{prepare_verifying_key, Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
 use snarkvm_curves::traits::{AffineCurve, PairingEngine};
 use snarkvm_fields::{FieldParameters, PrimeField, ToConstraintField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
@@ -102,6 +102,79 @@ pub struct PreparedVerifyingKeyGadget<PairingE: PairingEngine, P: PairingGadget<
     pub gamma_abc_g1: Vec<P::G1Gadget>,
 }
 
+impl<PairingE, P> AllocGadget<PreparedVerifyingKey<PairingE>, PairingE::Fq> for PreparedVerifyingKeyGadget<PairingE, P>
+where
+    PairingE: PairingEngine,
+    P: PairingGadget<PairingE, PairingE::Fq>,
+{
+    /// Allocates a constant prepared verifying key. The pairing `e(alpha_g1, beta_g2)` and
+    /// the line coefficients of `-gamma_g2` and `-delta_g2` are precomputed natively,
+    /// which removes the cost of preparing the verifying key in the circuit.
+    #[inline]
+    fn alloc_constant<FN, T, CS: ConstraintSystem<PairingE::Fq>>(
+        mut cs: CS,
+        value_gen: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PreparedVerifyingKey<PairingE>>,
+    {
+        value_gen().and_then(|pvk| {
+            let pvk = pvk.borrow();
+
+            let alpha_g1_beta_g2 =
+                P::GTGadget::alloc_constant(cs.ns(|| "alpha_g1_beta_g2"), || Ok(pvk.alpha_g1_beta_g2))?;
+            let gamma_g2_neg_pc =
+                P::G2PreparedGadget::alloc_constant(cs.ns(|| "gamma_g2_neg_pc"), || Ok(&pvk.gamma_g2_neg_pc))?;
+            let delta_g2_neg_pc =
+                P::G2PreparedGadget::alloc_constant(cs.ns(|| "delta_g2_neg_pc"), || Ok(&pvk.delta_g2_neg_pc))?;
+
+            let gamma_abc_g1 = pvk
+                .vk
+                .gamma_abc_g1
+                .iter()
+                .enumerate()
+                .map(|(i, gamma_abc_i)| {
+                    P::G1Gadget::alloc_constant(cs.ns(|| format!("gamma_abc_{}", i)), || {
+                        Ok(gamma_abc_i.into_projective())
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Self {
+                alpha_g1_beta_g2,
+                gamma_g2_neg_pc,
+                delta_g2_neg_pc,
+                gamma_abc_g1,
+            })
+        })
+    }
+
+    #[inline]
+    fn alloc<FN, T, CS: ConstraintSystem<PairingE::Fq>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PreparedVerifyingKey<PairingE>>,
+    {
+        let vk = VerifyingKeyGadget::<PairingE, P>::alloc(cs.ns(|| "alloc_vk"), || {
+            value_gen().map(|pvk| pvk.borrow().vk.clone())
+        })?;
+        vk.prepare(cs.ns(|| "prepare_vk"))
+    }
+
+    #[inline]
+    fn alloc_input<FN, T, CS: ConstraintSystem<PairingE::Fq>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PreparedVerifyingKey<PairingE>>,
+    {
+        let vk = VerifyingKeyGadget::<PairingE, P>::alloc_input(cs.ns(|| "alloc_vk"), || {
+            value_gen().map(|pvk| pvk.borrow().vk.clone())
+        })?;
+        vk.prepare(cs.ns(|| "prepare_vk"))
+    }
+}
+
 pub struct Groth16VerifierGadget<PairingE, P>
 where
     PairingE: PairingEngine,
@@ -134,6 +207,15 @@ where
         Ok(BooleanInputGadget::new(bits))
     }
 
+    /// Prepares the constant verification key natively, which removes the constraints
+    /// of computing `e(alpha_g1, beta_g2)` and the line coefficients of `-gamma_g2` and `-delta_g2`.
+    fn prepare_constant_verification_key<CS: ConstraintSystem<PairingE::Fq>>(
+        cs: CS,
+        verification_key: &VerifyingKey<PairingE>,
+    ) -> Result<Self::PreparedVerificationKeyGadget, SynthesisError> {
+        PreparedVerifyingKeyGadget::alloc_constant(cs, || Ok(prepare_verifying_key(verification_key.clone())))
+    }
+
     fn prepared_check_verify<CS: ConstraintSystem<PairingE::Fq>>(
         mut cs: CS,
         pvk: &Self::PreparedVerificationKeyGadget,
@@ -231,6 +313,48 @@ where
     PairingE: PairingEngine,
     P: PairingGadget<PairingE, PairingE::Fq>,
 {
+    #[inline]
+    fn alloc_constant<FN, T, CS: ConstraintSystem<PairingE::Fq>>(
+        mut cs: CS,
+        value_gen: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<VerifyingKey<PairingE>>,
+    {
+        value_gen().and_then(|vk| {
+            let VerifyingKey {
+                alpha_g1,
+                beta_g2,
+                gamma_g2,
+                delta_g2,
+                gamma_abc_g1,
+            } = vk.borrow();
+            let alpha_g1 = P::G1Gadget::alloc_constant(cs.ns(|| "alpha_g1"), || Ok(alpha_g1.into_projective()))?;
+            let beta_g2 = P::G2Gadget::alloc_constant(cs.ns(|| "beta_g2"), || Ok(beta_g2.into_projective()))?;
+            let gamma_g2 = P::G2Gadget::alloc_constant(cs.ns(|| "gamma_g2"), || Ok(gamma_g2.into_projective()))?;
+            let delta_g2 = P::G2Gadget::alloc_constant(cs.ns(|| "delta_g2"), || Ok(delta_g2.into_projective()))?;
+
+            let gamma_abc_g1 = gamma_abc_g1
+                .iter()
+                .enumerate()
+                .map(|(i, gamma_abc_i)| {
+                    P::G1Gadget::alloc_constant(cs.ns(|| format!("gamma_abc_{}", i)), || {
+                        Ok(gamma_abc_i.into_projective())
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Self {
+                alpha_g1,
+                beta_g2,
+                gamma_g2,
+                delta_g2,
+                gamma_abc_g1,
+            })
+        })
+    }
+
     #[inline]
     fn alloc<FN, T, CS: ConstraintSystem<PairingE::Fq>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
     where
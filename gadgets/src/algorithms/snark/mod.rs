@@ -14,6 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "dev")]
+pub mod dummy;
+#[cfg(feature = "dev")]
+pub use dummy::*;
+
 pub mod groth16;
 pub use groth16::*;
 
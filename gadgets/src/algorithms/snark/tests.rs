@@ -37,6 +37,7 @@ mod groth16 {
     type TestVerifierGadget = Groth16VerifierGadget<Bls12_377, Bls12_377PairingGadget>;
     type TestProofGadget = ProofGadget<Bls12_377, Bls12_377PairingGadget>;
     type TestVkGadget = VerifyingKeyGadget<Bls12_377, Bls12_377PairingGadget>;
+    type TestPreparedVkGadget = PreparedVerifyingKeyGadget<Bls12_377, Bls12_377PairingGadget>;
 
     struct Bench<F: Field> {
         inputs: Vec<Option<F>>,
@@ -281,4 +282,84 @@ mod groth16 {
             assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
         }
     }
+
+    #[test]
+    fn groth16_verifier_constant_vk_test() {
+        let num_inputs = 100;
+        let num_constraints = num_inputs;
+        let rng = &mut test_rng();
+        let mut inputs: Vec<Option<Fr>> = Vec::with_capacity(num_inputs);
+        for _ in 0..num_inputs {
+            inputs.push(Some(rng.gen()));
+        }
+        let params = {
+            let c = Bench::<Fr> {
+                inputs: vec![None; num_inputs],
+                num_constraints,
+            };
+
+            generate_random_parameters(&c, rng).unwrap()
+        };
+
+        let proof = {
+            let c = Bench {
+                inputs: inputs.clone(),
+                num_constraints,
+            };
+            create_random_proof(&c, &params, rng).unwrap()
+        };
+        let inputs = inputs.into_iter().map(|input| input.unwrap()).collect::<Vec<_>>();
+        let pvk = prepare_verifying_key(params.vk.clone());
+
+        // Verify the proof against a verifying key that is prepared in the circuit.
+        let num_allocated_constraints = {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let input_gadgets =
+                BooleanInputGadget::<Fr, Fq>::alloc_input(cs.ns(|| "input"), || Ok(inputs.clone())).unwrap();
+            let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+            let num_constraints_before = cs.num_constraints();
+
+            let pvk_gadget = TestPreparedVkGadget::alloc(cs.ns(|| "Pvk"), || Ok(&pvk)).unwrap();
+            <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem>>::prepared_check_verify(
+                cs.ns(|| "Verify"),
+                &pvk_gadget,
+                &input_gadgets,
+                &proof_gadget,
+            )
+            .unwrap();
+            assert!(cs.is_satisfied());
+
+            cs.num_constraints() - num_constraints_before
+        };
+
+        // Verify the proof against a constant verifying key, with precomputed pairing lines.
+        let num_constant_constraints = {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let input_gadgets =
+                BooleanInputGadget::<Fr, Fq>::alloc_input(cs.ns(|| "input"), || Ok(inputs.clone())).unwrap();
+            let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+            let num_constraints_before = cs.num_constraints();
+
+            let pvk_gadget =
+                <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem>>::prepare_constant_verification_key(
+                    cs.ns(|| "Pvk"),
+                    &params.vk,
+                )
+                .unwrap();
+            <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem>>::prepared_check_verify(
+                cs.ns(|| "Verify"),
+                &pvk_gadget,
+                &input_gadgets,
+                &proof_gadget,
+            )
+            .unwrap();
+            assert!(cs.is_satisfied());
+
+            cs.num_constraints() - num_constraints_before
+        };
+
+        println!("allocated_vk_constraints : {:?}", num_allocated_constraints);
+        println!("constant_vk_constraints : {:?}", num_constant_constraints);
+        assert!(num_constant_constraints < num_allocated_constraints);
+    }
 }
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::traits::fields::FieldGadget;
+
+///
+/// Returns the inverses of the given elements, using Montgomery's trick.
+///
+/// This performs a single in-circuit inversion and `3 * (n - 1)` multiplications,
+/// in place of `n` inversions. It is cheaper whenever an inversion costs more than
+/// three multiplications, as is the case for nonnative field gadgets, where every
+/// inversion allocates and range-checks a new witness. For native field gadgets,
+/// an inversion costs a single constraint, and individual inversions are cheaper.
+///
+/// As with `FieldGadget::inverse`, the constraints are unsatisfiable if any element is zero.
+///
+pub fn batch_inversion<NativeF: Field, F: Field, FG: FieldGadget<NativeF, F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    elements: &[FG],
+) -> Result<Vec<FG>, SynthesisError> {
+    if elements.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Compute the prefix products `a_0 * ... * a_i`.
+    let mut prefix_products = Vec::with_capacity(elements.len());
+    prefix_products.push(elements[0].clone());
+    for (i, element) in elements.iter().enumerate().skip(1) {
+        let product = prefix_products[i - 1].mul(cs.ns(|| format!("prefix_product_{}", i)), element)?;
+        prefix_products.push(product);
+    }
+
+    // Invert the product of all elements.
+    let mut inverse = prefix_products[elements.len() - 1].inverse(cs.ns(|| "inverse"))?;
+
+    // Peel off the inverse of each element, from last to first.
+    let mut inverses = Vec::with_capacity(elements.len());
+    for i in (1..elements.len()).rev() {
+        inverses.push(inverse.mul(cs.ns(|| format!("inverse_{}", i)), &prefix_products[i - 1])?);
+        inverse = inverse.mul(cs.ns(|| format!("remove_{}", i)), &elements[i])?;
+    }
+    inverses.push(inverse);
+    inverses.reverse();
+
+    Ok(inverses)
+}
@@ -82,6 +82,42 @@ impl<F: PrimeField> From<AllocatedFp<F>> for FpGadget<F> {
 }
 
 impl<F: PrimeField> AllocatedFp<F> {
+    /// Enforces that `self` is less than `2^num_bits`, and outputs its `num_bits`-bit
+    /// representation in *little-endian* form. The caller must ensure `num_bits` is
+    /// less than the modulus bit length.
+    pub(crate) fn assert_bits_le<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        // If the value does not fit in `num_bits` bits, the truncated bits do not satisfy the packing constraint.
+        let bit_values = match self.value {
+            Some(value) => BitIteratorLE::new(value.to_repr()).take(num_bits).map(Some).collect(),
+            None => vec![None; num_bits],
+        };
+
+        let bits: Vec<_> = bit_values
+            .into_iter()
+            .enumerate()
+            .map(|(i, b)| Boolean::alloc(cs.ns(|| format!("bit {}", i)), || b.get()))
+            .collect::<Result<_, _>>()?;
+
+        let mut lc = LinearCombination::zero();
+        let mut coeff = F::one();
+
+        for bit in bits.iter() {
+            lc = &lc + bit.lc(CS::one(), F::one()) * coeff;
+
+            coeff.double_in_place();
+        }
+
+        lc = &self.variable.clone().neg() + lc;
+
+        cs.enforce(|| "packing_constraint", |lc| lc, |lc| lc, |_| lc);
+
+        Ok(bits)
+    }
+
     /// Constructs `Self` from a `Boolean`:
     ///     if `cond` is false, this outputs `zero`, else it outputs `one`.
     pub fn from_boolean<CS: ConstraintSystem<F>>(mut cs: CS, cond: Boolean) -> Result<Self, SynthesisError> {
@@ -790,6 +826,32 @@ impl<F: PrimeField> FpGadget<F> {
             Ok(Self::Variable(AllocatedFp::from_boolean(cs, other)?))
         }
     }
+
+    /// Enforces that `self` is less than `2^num_bits`, and outputs its `num_bits`-bit
+    /// representation in *little-endian* form.
+    ///
+    /// This uses `num_bits + 1` constraints, and requires `num_bits` to be less than the modulus bit length.
+    pub fn assert_bits_le<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        // Ensure the bit decomposition is unique, by requiring `2^num_bits` to be less than the modulus.
+        if num_bits >= F::Parameters::MODULUS_BITS as usize {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        match self {
+            Self::Constant(c) => {
+                let bits = BitIteratorLE::new(c.to_repr()).collect::<Vec<_>>();
+                match bits[num_bits..].iter().any(|bit| *bit) {
+                    true => Err(SynthesisError::Unsatisfiable),
+                    false => Ok(bits[..num_bits].iter().copied().map(Boolean::constant).collect()),
+                }
+            }
+            Self::Variable(v) => v.assert_bits_le(cs, num_bits),
+        }
+    }
 }
 
 impl<F: PrimeField> FieldGadget<F, F> for FpGadget<F> {
@@ -1356,3 +1418,51 @@ impl<F: PrimeField> ToConstraintFieldGadget<F> for FpGadget<F> {
         Ok(vec![self.clone()])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_r1cs::TestConstraintSystem;
+
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_assert_bits_le() {
+        let rng = &mut thread_rng();
+
+        for i in 0..100 {
+            let value: u64 = rng.gen();
+
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let gadget = FpGadget::alloc(cs.ns(|| format!("alloc {}", i)), || Ok(Fr::from(value))).unwrap();
+            let bits = gadget.assert_bits_le(cs.ns(|| "assert_bits_le"), 64).unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(65, cs.num_constraints());
+
+            let expected = (0..64).map(|j| (value >> j) & 1 == 1).collect::<Vec<_>>();
+            assert_eq!(
+                expected,
+                bits.iter().map(|bit| bit.get_value().unwrap()).collect::<Vec<_>>()
+            );
+        }
+
+        // Ensure a value of 2^64 does not satisfy a 64-bit range check.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let value = Fr::from(u64::MAX as u128 + 1);
+        let gadget = FpGadget::alloc(cs.ns(|| "alloc"), || Ok(value)).unwrap();
+        gadget.assert_bits_le(cs.ns(|| "assert_bits_le"), 64).unwrap();
+        assert!(!cs.is_satisfied());
+
+        // Ensure constants are checked without constraints.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let bits = FpGadget::Constant(Fr::from(5u64))
+            .assert_bits_le(cs.ns(|| "constant"), 64)
+            .unwrap();
+        assert_eq!(64, bits.len());
+        assert_eq!(0, cs.num_constraints());
+        assert!(FpGadget::Constant(value)
+            .assert_bits_le(cs.ns(|| "overflow"), 64)
+            .is_err());
+    }
+}
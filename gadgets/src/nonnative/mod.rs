@@ -29,6 +29,9 @@
 //!     result of multiplication, which is hidden from the `FieldVar` interface
 //!     and is left for advanced users who want better performance.
 //!
+//! It also provides `NonNativeAffineVar`, a point of a short Weierstrass curve
+//! whose coordinates are `NonNativeFieldVar`s, for verifying signatures over external curves.
+//!
 mod allocated_nonnative_field_var;
 pub use allocated_nonnative_field_var::*;
 
@@ -44,6 +47,9 @@ pub use nonnative_field_input_var::*;
 mod nonnative_field_mul_result_var;
 pub use nonnative_field_mul_result_var::*;
 
+mod nonnative_affine_var;
+pub use nonnative_affine_var::*;
+
 /// example parameters of non-native field gadget
 ///
 /// Sample parameters for non-native field gadgets
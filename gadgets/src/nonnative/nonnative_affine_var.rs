@@ -0,0 +1,345 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    nonnative::NonNativeFieldVar,
+    traits::{
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        fields::FieldGadget,
+        select::CondSelectGadget,
+    },
+};
+use snarkvm_curves::{
+    templates::short_weierstrass_jacobian::Affine,
+    AffineCurve,
+    Group,
+    ProjectiveCurve,
+    ShortWeierstrassParameters,
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+///
+/// A point of a short Weierstrass curve in affine coordinates, whose coordinates are non-native field elements.
+///
+/// The point at infinity is not representable, and the additions of points are incomplete:
+/// adding two points with the same x-coordinate leaves the circuit unsatisfiable.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField"),
+    Debug(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField"),
+    PartialEq(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField"),
+    Eq(bound = "P: ShortWeierstrassParameters, P::BaseField: PrimeField, F: PrimeField")
+)]
+pub struct NonNativeAffineVar<P: ShortWeierstrassParameters, F: PrimeField>
+where
+    P::BaseField: PrimeField,
+{
+    pub(crate) x: NonNativeFieldVar<P::BaseField, F>,
+    pub(crate) y: NonNativeFieldVar<P::BaseField, F>,
+    _parameters: PhantomData<P>,
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> NonNativeAffineVar<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    /// Returns the point with the given coordinates.
+    fn new(x: NonNativeFieldVar<P::BaseField, F>, y: NonNativeFieldVar<P::BaseField, F>) -> Self {
+        Self {
+            x,
+            y,
+            _parameters: PhantomData,
+        }
+    }
+
+    /// Returns the given point as a constant.
+    pub(crate) fn constant(point: &Affine<P>) -> Self {
+        Self::new(
+            NonNativeFieldVar::Constant(point.x),
+            NonNativeFieldVar::Constant(point.y),
+        )
+    }
+
+    /// Returns the x-coordinate of the point.
+    pub fn x(&self) -> &NonNativeFieldVar<P::BaseField, F> {
+        &self.x
+    }
+
+    /// Returns the y-coordinate of the point.
+    pub fn y(&self) -> &NonNativeFieldVar<P::BaseField, F> {
+        &self.y
+    }
+
+    /// Returns the sum of the given points, from the slope `(y2 - y1) / (x2 - x1)`.
+    pub(crate) fn add<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let dx = other.x.sub(cs.ns(|| "dx"), &self.x)?;
+        let dy = other.y.sub(cs.ns(|| "dy"), &self.y)?;
+        // The inverse enforces that `dx` is nonzero, which excludes the points that the slope does not add.
+        let dx_inverse = dx.inverse(cs.ns(|| "dx_inverse"))?;
+        let lambda = dy.mul(cs.ns(|| "lambda"), &dx_inverse)?;
+        self.add_with_slope(cs.ns(|| "add_with_slope"), other, &lambda)
+    }
+
+    /// Returns the sum of the given point with itself, from the slope `(3 * x^2 + a) / (2 * y)`.
+    pub(crate) fn double<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let x_squared = self.x.mul(cs.ns(|| "x_squared"), &self.x)?;
+        let mut numerator = x_squared.mul_by_constant(cs.ns(|| "numerator"), &P::BaseField::from(3u64))?;
+        if !P::COEFF_A.is_zero() {
+            numerator = numerator.add_constant(cs.ns(|| "numerator_plus_a"), &P::COEFF_A)?;
+        }
+        let denominator = self.y.add(cs.ns(|| "denominator"), &self.y)?;
+        // The inverse enforces that `y` is nonzero, which excludes the points of order two.
+        let denominator_inverse = denominator.inverse(cs.ns(|| "denominator_inverse"))?;
+        let lambda = numerator.mul(cs.ns(|| "lambda"), &denominator_inverse)?;
+        self.add_with_slope(cs.ns(|| "add_with_slope"), self, &lambda)
+    }
+
+    /// Returns the sum of the given points, from the slope of the line through them.
+    fn add_with_slope<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        lambda: &NonNativeFieldVar<P::BaseField, F>,
+    ) -> Result<Self, SynthesisError> {
+        let lambda_squared = lambda.mul(cs.ns(|| "lambda_squared"), lambda)?;
+        let x = lambda_squared
+            .sub(cs.ns(|| "x_minus_x1"), &self.x)?
+            .sub(cs.ns(|| "x_minus_x2"), &other.x)?;
+        let y = self
+            .x
+            .sub(cs.ns(|| "x1_minus_x"), &x)?
+            .mul(cs.ns(|| "mul_lambda"), lambda)?
+            .sub(cs.ns(|| "y_minus_y1"), &self.y)?;
+        Ok(Self::new(x, y))
+    }
+
+    /// Returns `first` if the given bit is set, and `second` otherwise.
+    pub(crate) fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        bit: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self::new(
+            NonNativeFieldVar::conditionally_select(cs.ns(|| "select_x"), bit, &first.x, &second.x)?,
+            NonNativeFieldVar::conditionally_select(cs.ns(|| "select_y"), bit, &first.y, &second.y)?,
+        ))
+    }
+
+    /// Enforces that the point is on the curve `y^2 = x^3 + a * x + b`.
+    fn enforce_on_curve<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<(), SynthesisError> {
+        let y_squared = self.y.mul(cs.ns(|| "y_squared"), &self.y)?;
+        let x_squared = self.x.mul(cs.ns(|| "x_squared"), &self.x)?;
+        let mut right = x_squared.mul(cs.ns(|| "x_cubed"), &self.x)?;
+        if !P::COEFF_A.is_zero() {
+            let a_x = self.x.mul_by_constant(cs.ns(|| "a_x"), &P::COEFF_A)?;
+            right = right.add(cs.ns(|| "x_cubed_plus_a_x"), &a_x)?;
+        }
+        let right = right.add_constant(cs.ns(|| "plus_b"), &P::COEFF_B)?;
+        y_squared.enforce_equal(cs.ns(|| "enforce_equal"), &right)
+    }
+
+    /// Returns the first two points with a small x-coordinate, which are used as the offsets of scalar multiplications.
+    /// The offsets have no known discrete logarithm, so the incomplete additions of a scalar multiplication
+    /// from an offset only fail for adversarial inputs.
+    pub(crate) fn offset_points() -> (Affine<P>, Affine<P>) {
+        let mut points = (1u64..).filter_map(|x| Affine::<P>::from_x_coordinate(P::BaseField::from(x), false));
+        let first = points.next().unwrap();
+        let second = points.next().unwrap();
+        (first, second)
+    }
+
+    /// Returns the scalar with the given little-endian bits, reduced modulo the order of the curve.
+    pub(crate) fn bits_to_scalar<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        bits: &[Boolean],
+    ) -> Result<NonNativeFieldVar<P::ScalarField, F>, SynthesisError> {
+        let zero = NonNativeFieldVar::Constant(P::ScalarField::zero());
+        let mut scalar = zero.clone();
+        let mut power = P::ScalarField::one();
+        for (i, bit) in bits.iter().enumerate() {
+            let term = NonNativeFieldVar::conditionally_select(
+                cs.ns(|| format!("select_term_{}", i)),
+                bit,
+                &NonNativeFieldVar::Constant(power),
+                &zero,
+            )?;
+            scalar = scalar.add(cs.ns(|| format!("add_term_{}", i)), &term)?;
+            power.double_in_place();
+        }
+        Ok(scalar)
+    }
+
+    /// Returns `offset + scalar * base` for a constant base, from a table of the constant points `2^i * base`.
+    pub(crate) fn fixed_base_mul<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        offset: &Affine<P>,
+        base: &Affine<P>,
+        scalar_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let mut result = Self::constant(offset);
+        let mut base = base.into_projective();
+        for (i, bit) in scalar_bits.iter().enumerate() {
+            let sum = result.add(
+                cs.ns(|| format!("add_base_{}", i)),
+                &Self::constant(&base.into_affine()),
+            )?;
+            result = Self::conditionally_select(cs.ns(|| format!("select_{}", i)), bit, &sum, &result)?;
+            base.double_in_place();
+        }
+        Ok(result)
+    }
+
+    /// Returns `offset + scalar * self`, by doubling the point for each bit of the scalar.
+    pub(crate) fn variable_base_mul<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        offset: &Affine<P>,
+        scalar_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let mut result = Self::constant(offset);
+        let mut base = self.clone();
+        for (i, bit) in scalar_bits.iter().enumerate() {
+            let sum = result.add(cs.ns(|| format!("add_base_{}", i)), &base)?;
+            result = Self::conditionally_select(cs.ns(|| format!("select_{}", i)), bit, &sum, &result)?;
+            if i + 1 < scalar_bits.len() {
+                base = base.double(cs.ns(|| format!("double_base_{}", i)))?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> AllocGadget<Affine<P>, F> for NonNativeAffineVar<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Affine<P>>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self::constant(value_gen()?.borrow()))
+    }
+
+    ///
+    /// Allocates the point, and enforces that it is on the curve.
+    ///
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Affine<P>>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let (x, y) = match value_gen() {
+            Ok(point) => {
+                let point = *point.borrow();
+                (Ok(point.x), Ok(point.y))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let x = NonNativeFieldVar::alloc(cs.ns(|| "alloc_x"), || x)?;
+        let y = NonNativeFieldVar::alloc(cs.ns(|| "alloc_y"), || y)?;
+
+        let point = Self::new(x, y);
+        point.enforce_on_curve(cs.ns(|| "enforce_on_curve"))?;
+        Ok(point)
+    }
+
+    ///
+    /// Allocates the point as a public input, and enforces that it is on the curve.
+    ///
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Affine<P>>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let (x, y) = match value_gen() {
+            Ok(point) => {
+                let point = *point.borrow();
+                (Ok(point.x), Ok(point.y))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let x = NonNativeFieldVar::alloc_input(cs.ns(|| "alloc_input_x"), || x)?;
+        let y = NonNativeFieldVar::alloc_input(cs.ns(|| "alloc_input_y"), || y)?;
+
+        let point = Self::new(x, y);
+        point.enforce_on_curve(cs.ns(|| "enforce_on_curve"))?;
+        Ok(point)
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> ConditionalEqGadget<F> for NonNativeAffineVar<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.x
+            .conditional_enforce_equal(cs.ns(|| "x_conditional_enforce_equal"), &other.x, condition)?;
+        self.y
+            .conditional_enforce_equal(cs.ns(|| "y_conditional_enforce_equal"), &other.y, condition)
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> EqGadget<F> for NonNativeAffineVar<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let x_is_eq = self.x.is_eq(cs.ns(|| "x_is_eq"), &other.x)?;
+        let y_is_eq = self.y.is_eq(cs.ns(|| "y_is_eq"), &other.y)?;
+        Boolean::and(cs.ns(|| "is_eq"), &x_is_eq, &y_is_eq)
+    }
+}
+
+impl<P: ShortWeierstrassParameters, F: PrimeField> ToBytesGadget<F> for NonNativeAffineVar<P, F>
+where
+    P::BaseField: PrimeField,
+{
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = self.x.to_bytes(cs.ns(|| "x_to_bytes"))?;
+        result.extend(self.y.to_bytes(cs.ns(|| "y_to_bytes"))?);
+        Ok(result)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut result = self.x.to_bytes_strict(cs.ns(|| "x_to_bytes_strict"))?;
+        result.extend(self.y.to_bytes_strict(cs.ns(|| "y_to_bytes_strict"))?);
+        Ok(result)
+    }
+}
@@ -88,11 +88,11 @@ impl<TargetField: PrimeField, BaseField: PrimeField> NonNativeFieldVar<TargetFie
     pub fn is_eq<CS: ConstraintSystem<BaseField>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
         let mut constant = true;
 
-        if let Self::Constant(_) = self {
+        if let Self::Var(_) = self {
             constant = false;
         }
 
-        if let Self::Constant(_) = other {
+        if let Self::Var(_) = other {
             constant = false;
         }
 
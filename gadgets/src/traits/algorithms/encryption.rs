@@ -96,4 +96,38 @@ pub trait EncryptionGadget<E: EncryptionScheme, F: PrimeField>: AllocGadget<E, F
         private_key: &Self::PrivateKeyGadget,
         message: &[UInt8],
     ) -> Result<Vec<UInt8>, SynthesisError>;
+
+    /// Assumes symmetric key is committed before hand.
+    /// Otherwise, this allows the decrypter to open the ciphertext to any
+    /// plaintext.
+    ///
+    /// # Returns
+    /// The plaintext of the given size, decrypted by this key.
+    fn check_decryption_from_symmetric_key<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        symmetric_key: &Self::SymmetricKeyGadget,
+        ciphertext: &[UInt8],
+        plaintext_size_in_bytes: usize,
+    ) -> Result<Vec<UInt8>, SynthesisError>;
+
+    /// # Returns
+    /// The plaintext of the given size, decrypted by the private key.
+    fn check_decryption_from_ciphertext_randomizer<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        ciphertext_randomizer: &Self::CiphertextRandomizer,
+        private_key: &Self::PrivateKeyGadget,
+        ciphertext: &[UInt8],
+        plaintext_size_in_bytes: usize,
+    ) -> Result<Vec<UInt8>, SynthesisError>;
+
+    /// # Returns
+    /// The symmetric key that the private key derives from the ciphertext randomizer.
+    fn check_symmetric_key_from_ciphertext_randomizer<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        ciphertext_randomizer: &Self::CiphertextRandomizer,
+        private_key: &Self::PrivateKeyGadget,
+    ) -> Result<Self::SymmetricKeyGadget, SynthesisError>;
 }
@@ -70,6 +70,19 @@ pub trait SNARKVerifierGadget<S: SNARK> {
         )
     }
 
+    /// Returns the prepared gadget of a constant verification key.
+    ///
+    /// By default, the verification key is allocated as a constant and prepared in the circuit.
+    /// Proof systems that can prepare the verification key natively override this method.
+    fn prepare_constant_verification_key<CS: ConstraintSystem<S::BaseField>>(
+        mut cs: CS,
+        verification_key: &S::VerifyingKey,
+    ) -> Result<Self::PreparedVerificationKeyGadget, SynthesisError> {
+        let verification_key =
+            Self::VerificationKeyGadget::alloc_constant(cs.ns(|| "alloc_constant"), || Ok(verification_key))?;
+        verification_key.prepare(cs.ns(|| "prepare"))
+    }
+
     fn prepared_check_verify<CS: ConstraintSystem<S::BaseField>>(
         cs: CS,
         prepared_verification_key: &Self::PreparedVerificationKeyGadget,
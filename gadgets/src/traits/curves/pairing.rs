@@ -77,4 +77,13 @@ pub trait PairingGadget<E: PairingEngine, F: PrimeField = <E as PairingEngine>::
 
     fn prepare_g2<CS: ConstraintSystem<F>>(cs: CS, q: Self::G2Gadget)
     -> Result<Self::G2PreparedGadget, SynthesisError>;
+
+    /// Prepares a constant G2 element. The line coefficients are precomputed natively,
+    /// so unlike `prepare_g2`, this incurs no constraints.
+    fn prepare_g2_constant<CS: ConstraintSystem<F>>(
+        cs: CS,
+        q: &E::G2Affine,
+    ) -> Result<Self::G2PreparedGadget, SynthesisError> {
+        Self::G2PreparedGadget::alloc_constant(cs, || Ok(q.prepare()))
+    }
 }
@@ -39,6 +39,7 @@ pub trait SumGadget<F: PrimeField>: Clone {
 }
 
 pub trait ToMinimalBitsGadget<F: PrimeField>: Clone {
+    /// Returns `self` as a minimal boolean array, in version 1 of the encoding of `ToMinimalBits`.
     fn to_minimal_bits<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<Boolean>, SynthesisError>;
 }
 
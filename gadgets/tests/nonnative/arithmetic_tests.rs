@@ -20,6 +20,8 @@ use rand_xorshift::XorShiftRng;
 use snarkvm_curves::{bls12_377::Bls12_377, traits::PairingEngine};
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
+    bits::Boolean,
+    fields::batch_inversion,
     nonnative::NonNativeFieldVar,
     traits::{alloc::AllocGadget, eq::EqGadget, fields::FieldGadget},
 };
@@ -106,6 +108,38 @@ fn equality_test<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintS
         .unwrap();
 }
 
+fn is_eq_test<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintSystem<BaseField>, R: RngCore>(
+    mut cs: CS,
+    rng: &mut R,
+) {
+    let a_native = TargetField::rand(rng);
+    let a = NonNativeFieldVar::<TargetField, BaseField>::alloc(cs.ns(|| "alloc_a"), || Ok(a_native)).unwrap();
+
+    let b_native = TargetField::rand(rng);
+    let b = NonNativeFieldVar::<TargetField, BaseField>::alloc(cs.ns(|| "alloc_b"), || Ok(b_native)).unwrap();
+
+    let a_constant = NonNativeFieldVar::<TargetField, BaseField>::Constant(a_native);
+    let b_constant = NonNativeFieldVar::<TargetField, BaseField>::Constant(b_native);
+
+    // Ensure the comparison of variables is a variable.
+    let a_is_eq_a = a.is_eq(cs.ns(|| "a_is_eq_a"), &a).unwrap();
+    let a_is_eq_b = a.is_eq(cs.ns(|| "a_is_eq_b"), &b).unwrap();
+    assert!(!matches!(a_is_eq_a, Boolean::Constant(_)) && a_is_eq_a.get_value().unwrap());
+    assert!(!matches!(a_is_eq_b, Boolean::Constant(_)) && !a_is_eq_b.get_value().unwrap());
+
+    // Ensure the comparison of a variable and a constant is a variable.
+    let a_is_eq_a_constant = a.is_eq(cs.ns(|| "a_is_eq_a_constant"), &a_constant).unwrap();
+    let b_constant_is_eq_a = b_constant.is_eq(cs.ns(|| "b_constant_is_eq_a"), &a).unwrap();
+    assert!(!matches!(a_is_eq_a_constant, Boolean::Constant(_)) && a_is_eq_a_constant.get_value().unwrap());
+    assert!(!matches!(b_constant_is_eq_a, Boolean::Constant(_)) && !b_constant_is_eq_a.get_value().unwrap());
+
+    // Ensure the comparison of constants is a constant.
+    let num_constraints = cs.num_constraints();
+    let a_constant_is_eq_b_constant = a_constant.is_eq(cs.ns(|| "a_constant_is_eq_b"), &b_constant).unwrap();
+    assert!(matches!(a_constant_is_eq_b_constant, Boolean::Constant(false)));
+    assert_eq!(num_constraints, cs.num_constraints());
+}
+
 fn edge_cases_test<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintSystem<BaseField>, R: RngCore>(
     mut cs: CS,
     rng: &mut R,
@@ -499,6 +533,47 @@ fn inverse_stress_test<TargetField: PrimeField, BaseField: PrimeField, CS: Const
     }
 }
 
+fn batch_inversion_test<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintSystem<BaseField>, R: RngCore>(
+    mut cs: CS,
+    rng: &mut R,
+) {
+    let mut nums_native = Vec::with_capacity(TEST_COUNT);
+    let mut nums = Vec::with_capacity(TEST_COUNT);
+    for i in 0..TEST_COUNT {
+        let num_native = TargetField::rand(rng);
+        if num_native == TargetField::zero() {
+            continue;
+        }
+        nums.push(
+            NonNativeFieldVar::<TargetField, BaseField>::alloc(cs.ns(|| format!("num_{}", i)), || Ok(num_native))
+                .unwrap(),
+        );
+        nums_native.push(num_native);
+    }
+
+    let nums_inverse = batch_inversion(cs.ns(|| "batch_inversion"), &nums).unwrap();
+    assert_eq!(nums.len(), nums_inverse.len());
+
+    for (num_native, num_inverse) in nums_native.iter().zip(&nums_inverse) {
+        assert!(num_inverse.value().unwrap().eq(&num_native.inverse().unwrap()));
+    }
+}
+
+#[test]
+fn batch_inversion_is_satisfied() {
+    type TargetField = <Bls12_377 as PairingEngine>::Fq;
+    type BaseField = <Bls12_377 as PairingEngine>::Fr;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for i in 0..NUM_REPETITIONS {
+        let mut cs = TestConstraintSystem::<BaseField>::new();
+        batch_inversion_test::<TargetField, BaseField, _, _>(cs.ns(|| format!("test_{}", i)), &mut rng);
+        assert!(cs.num_constraints() > 0);
+        assert!(cs.is_satisfied(), "unsatisfied constraint: {:?}", cs.which_is_unsatisfied());
+    }
+}
+
 macro_rules! nonnative_test_individual {
     ($test_method:ident, $test_name:ident, $test_target_field:ty, $test_base_field:ty) => {
         paste::item! {
@@ -527,6 +602,7 @@ macro_rules! nonnative_test {
             $test_base_field
         );
         nonnative_test_individual!(equality_test, $test_name, $test_target_field, $test_base_field);
+        nonnative_test_individual!(is_eq_test, $test_name, $test_target_field, $test_base_field);
         nonnative_test_individual!(edge_cases_test, $test_name, $test_target_field, $test_base_field);
         nonnative_test_individual!(
             distribution_law_test,
@@ -588,6 +664,12 @@ macro_rules! nonnative_test {
             $test_target_field,
             $test_base_field
         );
+        nonnative_test_individual!(
+            batch_inversion_test,
+            $test_name,
+            $test_target_field,
+            $test_base_field
+        );
     };
 }
 
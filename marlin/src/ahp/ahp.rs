@@ -14,8 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "verifier")]
+use crate::ahp::verifier;
 use crate::{
-    ahp::{matrices, prover::ProverConstraintSystem, verifier, AHPError, CircuitInfo},
+    ahp::{matrices, AHPError, AHPPolynomial, CircuitInfo},
     marlin::MarlinMode,
     String,
     ToString,
@@ -25,6 +27,8 @@ use snarkvm_algorithms::{cfg_iter_mut, fft::EvaluationDomain};
 use snarkvm_fields::{batch_inversion, Field, PrimeField};
 use snarkvm_r1cs::errors::SynthesisError;
 
+#[cfg(feature = "verifier")]
+use snarkvm_polycommit::LinearCombinationBuilder;
 use snarkvm_polycommit::{LCTerm, LabeledPolynomial, LinearCombination};
 
 use core::{borrow::Borrow, marker::PhantomData};
@@ -43,39 +47,42 @@ pub struct AHPForR1CS<F: Field, MM: MarlinMode> {
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// The labels for the polynomials output by the AHP indexer.
     #[rustfmt::skip]
-    pub const INDEXER_POLYNOMIALS: [&'static str; 6] = [
+    pub const INDEXER_POLYNOMIALS: [AHPPolynomial; 6] = [
         // Polynomials for M
-        "row", "col", "a_val", "b_val", "c_val", "row_col",
+        AHPPolynomial::Row, AHPPolynomial::Col, AHPPolynomial::AVal, AHPPolynomial::BVal, AHPPolynomial::CVal,
+        AHPPolynomial::RowCol,
     ];
     /// The labels for the polynomials output and vanishing polynomials by the AHP indexer.
     #[rustfmt::skip]
-    pub const INDEXER_POLYNOMIALS_WITH_VANISHING: [&'static str; 8] = [
+    pub const INDEXER_POLYNOMIALS_WITH_VANISHING: [AHPPolynomial; 8] = [
         // Polynomials for M
-        "row", "col", "a_val", "b_val", "c_val", "row_col",
+        AHPPolynomial::Row, AHPPolynomial::Col, AHPPolynomial::AVal, AHPPolynomial::BVal, AHPPolynomial::CVal,
+        AHPPolynomial::RowCol,
         // Vanishing polynomials
-        "vanishing_poly_h", "vanishing_poly_k"
+        AHPPolynomial::VanishingPolyH, AHPPolynomial::VanishingPolyK,
     ];
     /// The linear combinations that are statically known to evaluate to zero.
     #[rustfmt::skip]
     pub const LC_WITH_ZERO_EVAL: [&'static str; 2] = ["inner_sumcheck", "outer_sumcheck"];
     /// The labels for the polynomials output by the AHP prover.
     #[rustfmt::skip]
-    pub const PROVER_POLYNOMIALS_WITHOUT_ZK: [&'static str; 8] = [
+    pub const PROVER_POLYNOMIALS_WITHOUT_ZK: [AHPPolynomial; 8] = [
         // First sumcheck
-        "w", "z_a", "z_b", "t", "g_1", "h_1",
+        AHPPolynomial::W, AHPPolynomial::ZA, AHPPolynomial::ZB, AHPPolynomial::T, AHPPolynomial::G1, AHPPolynomial::H1,
         // Second sumcheck
-        "g_2", "h_2",
+        AHPPolynomial::G2, AHPPolynomial::H2,
     ];
     /// The labels for the polynomials output by the AHP prover.
     #[rustfmt::skip]
-    pub const PROVER_POLYNOMIALS_WITH_ZK: [&'static str; 9] = [
+    pub const PROVER_POLYNOMIALS_WITH_ZK: [AHPPolynomial; 9] = [
         // First sumcheck
-        "w", "z_a", "z_b", "mask_poly", "t", "g_1", "h_1",
+        AHPPolynomial::W, AHPPolynomial::ZA, AHPPolynomial::ZB, AHPPolynomial::MaskPoly, AHPPolynomial::T,
+        AHPPolynomial::G1, AHPPolynomial::H1,
         // Second sumcheck
-        "g_2", "h_2",
+        AHPPolynomial::G2, AHPPolynomial::H2,
     ];
 
-    pub(crate) fn indexer_polynomials() -> impl Iterator<Item = &'static str> {
+    pub(crate) fn indexer_polynomials() -> impl Iterator<Item = AHPPolynomial> {
         if MM::RECURSION {
             Self::INDEXER_POLYNOMIALS_WITH_VANISHING.as_ref().iter().copied()
         } else {
@@ -83,7 +90,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         }
     }
 
-    pub(crate) fn prover_polynomials() -> impl Iterator<Item = &'static str> {
+    pub(crate) fn prover_polynomials() -> impl Iterator<Item = AHPPolynomial> {
         if MM::ZK {
             Self::PROVER_POLYNOMIALS_WITH_ZK.as_ref().iter().copied()
         } else {
@@ -94,7 +101,22 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     pub(crate) fn polynomial_labels() -> impl Iterator<Item = String> {
         Self::indexer_polynomials()
             .chain(Self::prover_polynomials())
-            .map(|s| s.to_string())
+            .map(|polynomial| polynomial.to_string())
+    }
+
+    /// Formats the public input according to the requirements of the constraint system.
+    #[cfg(feature = "verifier")]
+    pub(crate) fn format_public_input(public_input: &[F]) -> Vec<F> {
+        let mut input = vec![F::one()];
+        input.extend_from_slice(public_input);
+        input
+    }
+
+    /// Takes in a previously formatted public input and removes the formatting
+    /// imposed by the constraint system.
+    #[cfg(feature = "verifier")]
+    pub(crate) fn unformat_public_input(input: &[F]) -> Vec<F> {
+        input[1..].to_vec()
     }
 
     /// Check that the (formatted) public input is of the form 2^n for some integer n.
@@ -149,9 +171,49 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         degree_bounds
     }
 
+    /// Output the number of oracles sent by the prover in the first round.
+    pub fn prover_num_first_round_oracles() -> usize {
+        if MM::ZK { 4 } else { 3 }
+    }
+
+    /// Output the degree bounds of oracles in the first round.
+    pub fn prover_first_round_degree_bounds(_info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
+        if MM::ZK {
+            vec![None; 4].into_iter()
+        } else {
+            vec![None; 3].into_iter()
+        }
+    }
+
+    /// Output the number of oracles sent by the prover in the second round.
+    pub fn prover_num_second_round_oracles() -> usize {
+        3
+    }
+
+    /// Output the degree bounds of oracles in the second round.
+    pub fn prover_second_round_degree_bounds(info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
+        let h_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap();
+
+        vec![None, Some(h_domain_size - 2), None].into_iter()
+    }
+
+    /// Output the number of oracles sent by the prover in the third round.
+    pub fn prover_num_third_round_oracles() -> usize {
+        2
+    }
+
+    /// Output the degree bounds of oracles in the third round.
+    pub fn prover_third_round_degree_bounds(info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
+        let num_non_zero = info.num_non_zero;
+        let k_size = EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).unwrap();
+
+        vec![Some(k_size - 2), None].into_iter()
+    }
+
     /// Construct the linear combinations that are checked by the AHP.
     /// Public input should be unformatted.
     #[allow(non_snake_case)]
+    #[cfg(feature = "verifier")]
     pub fn construct_linear_combinations<E: EvaluationsProvider<F>>(
         public_input: &[F],
         evals: &E,
@@ -161,7 +223,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let domain_k = state.domain_k;
         let k_size = domain_k.size_as_field_element;
 
-        let public_input = ProverConstraintSystem::format_public_input(public_input);
+        let public_input = Self::format_public_input(public_input);
         if !Self::formatted_public_input_is_admissible(&public_input) {
             return Err(AHPError::InvalidPublicInputLength);
         }
@@ -179,9 +241,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let mut linear_combinations = Vec::with_capacity(9);
 
         // Outer sumchecK:
-        let z_b = LinearCombination::new("z_b", vec![(F::one(), "z_b")]);
-        let g_1 = LinearCombination::new("g_1", vec![(F::one(), "g_1")]);
-        let t = LinearCombination::new("t", vec![(F::one(), "t")]);
+        let z_b = LinearCombination::new(AHPPolynomial::ZB, vec![(F::one(), AHPPolynomial::ZB)]);
+        let g_1 = LinearCombination::new(AHPPolynomial::G1, vec![(F::one(), AHPPolynomial::G1)]);
+        let t = LinearCombination::new(AHPPolynomial::T, vec![(F::one(), AHPPolynomial::T)]);
 
         let r_alpha_at_beta = domain_h.eval_unnormalized_bivariate_lagrange_poly(alpha, beta);
         let v_H_at_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
@@ -199,19 +261,20 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .map(|(l, x)| l * x)
             .fold(F::zero(), |x, y| x + y);
 
-        #[rustfmt::skip]
+        // The constant terms are merged into a single term, following `z_a`.
         let outer_sumcheck = {
-            let mut lc_terms = vec![];
+            let mut builder = LinearCombinationBuilder::with_capacity("outer_sumcheck", 5);
             if MM::ZK {
-                lc_terms.push((F::one(), "mask_poly".into()));
+                builder.add_term(F::one(), AHPPolynomial::MaskPoly);
             }
-            lc_terms.push((r_alpha_at_beta * (eta_a + (eta_c * z_b_at_beta)), "z_a".into()));
-            lc_terms.push((r_alpha_at_beta * eta_b * z_b_at_beta, LCTerm::One));
-            lc_terms.push((-t_at_beta * v_X_at_beta, "w".into()));
-            lc_terms.push((-t_at_beta * x_at_beta, LCTerm::One));
-            lc_terms.push((-v_H_at_beta, "h_1".into()));
-            lc_terms.push((-beta * g_1_at_beta, LCTerm::One));
-            LinearCombination::new("outer_sumcheck", lc_terms)
+            builder
+                .add_term(r_alpha_at_beta * (eta_a + (eta_c * z_b_at_beta)), AHPPolynomial::ZA)
+                .add_term(r_alpha_at_beta * eta_b * z_b_at_beta, LCTerm::One)
+                .add_term(-t_at_beta * v_X_at_beta, AHPPolynomial::W)
+                .add_term(-t_at_beta * x_at_beta, LCTerm::One)
+                .add_term(-v_H_at_beta, AHPPolynomial::H1)
+                .add_term(-beta * g_1_at_beta, LCTerm::One);
+            builder.build()
         };
         debug_assert!(evals.get_lc_eval(&outer_sumcheck, beta)?.is_zero());
 
@@ -222,39 +285,45 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         //  Inner sumcheck:
         let beta_alpha = beta * alpha;
-        let g_2 = LinearCombination::new("g_2", vec![(F::one(), "g_2")]);
+        let g_2 = LinearCombination::new(AHPPolynomial::G2, vec![(F::one(), AHPPolynomial::G2)]);
 
         let g_2_at_gamma = evals.get_lc_eval(&g_2, gamma)?;
 
         let v_K_at_gamma = domain_k.evaluate_vanishing_polynomial(gamma);
 
-        let mut a = LinearCombination::new("a_poly", vec![(eta_a, "a_val"), (eta_b, "b_val"), (eta_c, "c_val")]);
-        a *= v_H_at_alpha * v_H_at_beta;
+        let a = LinearCombination::new("a_poly", vec![
+            (eta_a, AHPPolynomial::AVal),
+            (eta_b, AHPPolynomial::BVal),
+            (eta_c, AHPPolynomial::CVal),
+        ]);
 
-        let mut b = LinearCombination::new("denom", vec![
+        let b = LinearCombination::new("denom", vec![
             (beta_alpha, LCTerm::One),
-            (-alpha, "row".into()),
-            (-beta, "col".into()),
-            (F::one(), "row_col".into()),
+            (-alpha, AHPPolynomial::Row.into()),
+            (-beta, AHPPolynomial::Col.into()),
+            (F::one(), AHPPolynomial::RowCol.into()),
         ]);
-        b *= gamma * g_2_at_gamma + (t_at_beta / k_size);
 
-        let mut inner_sumcheck = a;
-        inner_sumcheck -= &b;
-        inner_sumcheck -= &LinearCombination::new("h_2", vec![(v_K_at_gamma, "h_2")]);
-        inner_sumcheck.label = "inner_sumcheck".into();
+        let inner_sumcheck = {
+            let mut builder = LinearCombinationBuilder::with_capacity("inner_sumcheck", 8);
+            builder
+                .add_linear_combination(v_H_at_alpha * v_H_at_beta, &a)
+                .add_linear_combination(-(gamma * g_2_at_gamma + (t_at_beta / k_size)), &b)
+                .add_term(-v_K_at_gamma, AHPPolynomial::H2);
+            builder.build()
+        };
         debug_assert!(evals.get_lc_eval(&inner_sumcheck, gamma)?.is_zero());
 
         linear_combinations.push(g_2);
         linear_combinations.push(inner_sumcheck);
 
         if MM::RECURSION {
-            let vanishing_poly_h_alpha =
-                LinearCombination::new("vanishing_poly_h_alpha", vec![(F::one(), "vanishing_poly_h")]);
-            let vanishing_poly_h_beta =
-                LinearCombination::new("vanishing_poly_h_beta", vec![(F::one(), "vanishing_poly_h")]);
-            let vanishing_poly_k_gamma =
-                LinearCombination::new("vanishing_poly_k_gamma", vec![(F::one(), "vanishing_poly_k")]);
+            let vanishing_poly_h = vec![(F::one(), AHPPolynomial::VanishingPolyH)];
+            let vanishing_poly_k = vec![(F::one(), AHPPolynomial::VanishingPolyK)];
+
+            let vanishing_poly_h_alpha = LinearCombination::new("vanishing_poly_h_alpha", vanishing_poly_h.clone());
+            let vanishing_poly_h_beta = LinearCombination::new("vanishing_poly_h_beta", vanishing_poly_h);
+            let vanishing_poly_k_gamma = LinearCombination::new("vanishing_poly_k_gamma", vanishing_poly_k);
 
             linear_combinations.push(vanishing_poly_h_alpha);
             linear_combinations.push(vanishing_poly_h_beta);
@@ -401,6 +470,24 @@ mod tests {
         assert_eq!(sum, first + last);
     }
 
+    #[test]
+    fn test_summation_batch_evaluate() {
+        let rng = &mut test_rng();
+        let size = 1 << 4;
+        let domain = EvaluationDomain::<Fr>::new(1 << 4).unwrap();
+        let size_as_fe = domain.size_as_field_element;
+        let poly = DensePolynomial::rand(size, rng);
+
+        let elements = domain.elements().collect::<Vec<_>>();
+        let evals = poly.batch_evaluate(&elements);
+        assert_eq!(evals, elements.iter().map(|e| poly.evaluate(*e)).collect::<Vec<_>>());
+
+        let sum = evals.iter().fold(Fr::zero(), |sum, eval| sum + eval);
+        let first = poly.coeffs[0] * size_as_fe;
+        let last = *poly.coeffs.last().unwrap() * size_as_fe;
+        assert_eq!(sum, first + last);
+    }
+
     #[test]
     fn test_alternator_polynomial() {
         use snarkvm_algorithms::fft::Evaluations;
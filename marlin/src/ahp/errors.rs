@@ -31,6 +31,8 @@ pub enum AHPError {
     MissingEval(String),
     /// Currently we only support square constraint matrices.
     NonSquareMatrix,
+    /// The fixed assignments cannot be baked into the index.
+    InvalidSpecialization(String),
 }
 
 impl From<crate::fiat_shamir::FiatShamirError> for AHPError {
@@ -16,7 +16,15 @@
 
 use core::marker::PhantomData;
 
-use crate::{ahp::matrices::MatrixArithmetization, marlin::MarlinMode, CircuitInfo, Matrix};
+use crate::{
+    ahp::matrices::MatrixArithmetization,
+    marlin::MarlinMode,
+    CircuitInfo,
+    Matrix,
+    Vec,
+};
+#[cfg(feature = "prover")]
+use crate::ahp::AHPError;
 use snarkvm_fields::PrimeField;
 use snarkvm_polycommit::LabeledPolynomial;
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
@@ -69,3 +77,47 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
         .into_iter()
     }
 }
+
+/// Describes how a specialized index is derived from the index of its circuit,
+/// so that the prover may map an assignment of the circuit to an assignment of the specialized index.
+/// It is stored alongside the specialized index, so that the serialization of `Circuit` is unchanged.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "F: PrimeField"))]
+#[derive(CanonicalSerialize, CanonicalDeserialize, Debug)]
+pub struct CircuitSpecialization<F: PrimeField> {
+    /// The number of variables of the circuit, after padding.
+    pub num_variables: usize,
+    /// The number of constraints of the circuit, after padding.
+    pub num_constraints: usize,
+    /// The fixed private variables of the circuit, as pairs of their index and their value.
+    pub fixed_assignments: Vec<(usize, F)>,
+    /// The indices of the private variables of the circuit that remain in the specialized index, in order.
+    pub private_variables: Vec<usize>,
+    /// The number of unconstrained private variables that pad the specialized matrices to be square.
+    pub num_padding_variables: usize,
+}
+
+impl<F: PrimeField> CircuitSpecialization<F> {
+    /// Returns the private variables of the specialized index for the given private variables of the circuit,
+    /// or an error if they do not match the fixed assignments.
+    #[cfg(feature = "prover")]
+    pub(crate) fn specialize_assignment(&self, private_variables: &[F]) -> Result<Vec<F>, AHPError> {
+        for (index, value) in &self.fixed_assignments {
+            if private_variables.get(*index) != Some(value) {
+                return Err(AHPError::InstanceDoesNotMatchIndex);
+            }
+        }
+
+        let mut specialized_variables = Vec::with_capacity(self.private_variables.len() + self.num_padding_variables);
+        for index in &self.private_variables {
+            let variable = private_variables
+                .get(*index)
+                .ok_or(AHPError::InstanceDoesNotMatchIndex)?;
+            specialized_variables.push(*variable);
+        }
+        // The padding variables are assigned one, as in `make_matrices_square`.
+        specialized_variables.resize(specialized_variables.len() + self.num_padding_variables, F::one());
+
+        Ok(specialized_variables)
+    }
+}
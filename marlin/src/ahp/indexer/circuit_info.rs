@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ahp::AHPForR1CS, marlin::MarlinMode, BTreeSet, Matrix, Vec};
+use crate::{ahp::AHPForR1CS, marlin::MarlinMode};
+#[cfg(feature = "prover")]
+use crate::{BTreeSet, Matrix, Vec};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{errors::SerializationError, serialize::*, ToBytes};
 
@@ -39,6 +41,7 @@ pub struct CircuitInfo<F> {
     pub f: PhantomData<F>,
 }
 
+#[cfg(feature = "prover")]
 pub(crate) fn sum_matrices<F: PrimeField>(a: &Matrix<F>, b: &Matrix<F>, c: &Matrix<F>) -> Vec<Vec<usize>> {
     a.iter()
         .zip(b)
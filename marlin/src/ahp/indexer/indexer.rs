@@ -16,12 +16,15 @@
 
 use crate::{
     ahp::{
-        indexer::{Circuit, CircuitInfo, IndexerConstraintSystem},
+        indexer::{Circuit, CircuitInfo, CircuitSpecialization, IndexerConstraintSystem, Matrix},
         matrices::arithmetize_matrix,
         AHPError,
         AHPForR1CS,
     },
     marlin::MarlinMode,
+    BTreeMap,
+    BTreeSet,
+    Vec,
 };
 use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_fields::PrimeField;
@@ -38,6 +41,13 @@ use snarkvm_utilities::println;
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Generate the index for this constraint system.
     pub fn index<C: ConstraintSynthesizer<F>>(c: &C) -> Result<Circuit<F, MM>, AHPError> {
+        Ok(Self::index_with_num_public_variables(c)?.0)
+    }
+
+    /// Generate the index for this constraint system, and return it with its number of padded public variables.
+    fn index_with_num_public_variables<C: ConstraintSynthesizer<F>>(
+        c: &C,
+    ) -> Result<(Circuit<F, MM>, usize), AHPError> {
         let index_time = start_timer!(|| "AHP::Index");
 
         let constraint_time = start_timer!(|| "Generating constraints");
@@ -53,14 +63,169 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let b = ics.b_matrix();
         let c = ics.c_matrix();
 
-        let joint_matrix = sum_matrices(&a, &b, &c);
-
         // balance_matrices(&mut a, &mut b);
         end_timer!(padding_time);
 
-        let num_padded_public_variables = ics.num_public_variables();
+        let num_public_variables = ics.num_public_variables();
         let num_private_variables = ics.num_private_variables();
-        let num_constraints = ics.num_constraints();
+        let circuit = Self::index_matrices(a, b, c, num_public_variables, num_private_variables)?;
+
+        end_timer!(index_time);
+        Ok((circuit, num_public_variables))
+    }
+
+    ///
+    /// Generate a specialized index, in which the given private variables of the circuit are fixed.
+    ///
+    /// The fixed variables are folded into the constant column of the matrices, the constraints that
+    /// only involve constants are checked and removed, and the private variables that no longer appear
+    /// in any constraint are removed. The specialized index is no larger than the given index,
+    /// and proves the circuit for the assignments that match the fixed values.
+    ///
+    /// The fixed assignments map the index of a private variable, in the order of allocation, to its value.
+    /// Returns the specialized index, and the specialization that maps assignments of the circuit to it.
+    ///
+    pub fn specialize<C: ConstraintSynthesizer<F>>(
+        c: &C,
+        fixed_assignments: &BTreeMap<usize, F>,
+    ) -> Result<(Circuit<F, MM>, CircuitSpecialization<F>), AHPError> {
+        let specialize_time = start_timer!(|| "AHP::Specialize");
+
+        let (index, num_public_variables) = Self::index_with_num_public_variables(c)?;
+        let num_private_variables = index.index_info.num_variables - num_public_variables;
+        if let Some(variable) = fixed_assignments
+            .keys()
+            .find(|variable| **variable >= num_private_variables)
+        {
+            return Err(AHPError::InvalidSpecialization(format!(
+                "private variable {} does not exist",
+                variable
+            )));
+        }
+
+        // Fold the fixed variables, and the constant one, into a single constant term of the row.
+        let substitute = |row: &[(F, usize)]| {
+            let mut constant = F::zero();
+            let mut terms = Vec::with_capacity(row.len());
+            for &(coefficient, column) in row {
+                let fixed_value = column
+                    .checked_sub(num_public_variables)
+                    .and_then(|variable| fixed_assignments.get(&variable));
+                match fixed_value {
+                    Some(value) => constant += coefficient * value,
+                    None if column == 0 => constant += coefficient,
+                    None => terms.push((coefficient, column)),
+                }
+            }
+            (constant, terms)
+        };
+        let with_constant = |constant: F, mut terms: Vec<(F, usize)>| {
+            if !constant.is_zero() {
+                terms.insert(0, (constant, 0));
+            }
+            terms
+        };
+
+        let substitution_time = start_timer!(|| "Substituting the fixed assignments");
+        let mut rows = Vec::with_capacity(index.index_info.num_constraints);
+        for (i, ((row_a, row_b), row_c)) in index.a.iter().zip(&index.b).zip(&index.c).enumerate() {
+            let (a_constant, a_terms) = substitute(row_a);
+            let (b_constant, b_terms) = substitute(row_b);
+            let (c_constant, c_terms) = substitute(row_c);
+
+            if a_terms.is_empty() && b_terms.is_empty() && c_terms.is_empty() {
+                if a_constant * b_constant != c_constant {
+                    return Err(AHPError::InvalidSpecialization(format!(
+                        "the fixed assignments do not satisfy constraint {}",
+                        i
+                    )));
+                }
+                continue;
+            }
+
+            rows.push((
+                with_constant(a_constant, a_terms),
+                with_constant(b_constant, b_terms),
+                with_constant(c_constant, c_terms),
+            ));
+        }
+        end_timer!(substitution_time);
+
+        // Keep the private variables that remain in a constraint, and renumber their columns in order.
+        let private_columns = rows
+            .iter()
+            .flat_map(|(row_a, row_b, row_c)| row_a.iter().chain(row_b).chain(row_c))
+            .map(|(_, column)| *column)
+            .filter(|column| *column >= num_public_variables)
+            .collect::<BTreeSet<_>>();
+        let columns = private_columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| (*column, num_public_variables + i))
+            .collect::<BTreeMap<_, _>>();
+        let renumber = |row: Vec<(F, usize)>| -> Vec<(F, usize)> {
+            row.into_iter()
+                .map(|(coefficient, column)| (coefficient, *columns.get(&column).unwrap_or(&column)))
+                .collect()
+        };
+
+        let mut a = Vec::with_capacity(rows.len());
+        let mut b = Vec::with_capacity(rows.len());
+        let mut c = Vec::with_capacity(rows.len());
+        for (row_a, row_b, row_c) in rows {
+            a.push(renumber(row_a));
+            b.push(renumber(row_b));
+            c.push(renumber(row_c));
+        }
+
+        // Pad the matrices to be square, as in `make_matrices_square`.
+        let num_variables = num_public_variables + private_columns.len();
+        let mut num_padding_variables = 0;
+        if num_variables > a.len() {
+            a.resize(num_variables, Vec::new());
+            b.resize(num_variables, Vec::new());
+            c.resize(num_variables, Vec::new());
+        } else {
+            num_padding_variables = a.len() - num_variables;
+        }
+
+        let specialization = CircuitSpecialization {
+            num_variables: index.index_info.num_variables,
+            num_constraints: index.index_info.num_constraints,
+            fixed_assignments: fixed_assignments
+                .iter()
+                .map(|(variable, value)| (*variable, *value))
+                .collect(),
+            private_variables: private_columns
+                .iter()
+                .map(|column| column - num_public_variables)
+                .collect(),
+            num_padding_variables,
+        };
+
+        let circuit = Self::index_matrices(
+            a,
+            b,
+            c,
+            num_public_variables,
+            private_columns.len() + num_padding_variables,
+        )?;
+
+        end_timer!(specialize_time);
+        Ok((circuit, specialization))
+    }
+
+    /// Generate the index for the given square matrices.
+    fn index_matrices(
+        a: Matrix<F>,
+        b: Matrix<F>,
+        c: Matrix<F>,
+        num_padded_public_variables: usize,
+        num_private_variables: usize,
+    ) -> Result<Circuit<F, MM>, AHPError> {
+        let joint_matrix = sum_matrices(&a, &b, &c);
+
+        let num_constraints = a.len();
         let num_non_zero = num_non_zero(&joint_matrix);
         let num_variables = num_padded_public_variables + num_private_variables;
 
@@ -99,7 +264,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let joint_arith = arithmetize_matrix(&joint_matrix, &a, &b, &c, domain_k, domain_h, x_domain);
         end_timer!(joint_arithmetization_time);
 
-        end_timer!(index_time);
         Ok(Circuit {
             index_info,
 
@@ -24,14 +24,18 @@ pub(crate) use circuit::*;
 mod circuit_info;
 pub(crate) use circuit_info::*;
 
+#[cfg(feature = "prover")]
 mod constraint_system;
+#[cfg(feature = "prover")]
 pub(crate) use constraint_system::*;
 
+#[cfg(feature = "prover")]
 mod indexer;
 
 /// Represents a matrix.
 pub(crate) type Matrix<F> = Vec<Vec<(F, usize)>>;
 
+#[cfg(feature = "prover")]
 pub(crate) fn num_non_zero(joint_matrix: &[Vec<usize>]) -> usize {
     joint_matrix.iter().map(|row| row.len()).sum()
 }
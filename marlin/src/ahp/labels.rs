@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::String;
+use snarkvm_polycommit::{LCTerm, PolynomialLabelSet};
+
+use core::fmt;
+
+/// The polynomials of the AHP, which label the polynomial commitments of a Marlin proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AHPPolynomial {
+    // Indexer polynomials for M
+    Row,
+    Col,
+    AVal,
+    BVal,
+    CVal,
+    RowCol,
+    // Vanishing polynomials
+    VanishingPolyH,
+    VanishingPolyK,
+    // First round
+    W,
+    ZA,
+    ZB,
+    MaskPoly,
+    // First sumcheck
+    T,
+    G1,
+    H1,
+    // Second sumcheck
+    G2,
+    H2,
+}
+
+impl AHPPolynomial {
+    /// Returns the polynomial label.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Row => "row",
+            Self::Col => "col",
+            Self::AVal => "a_val",
+            Self::BVal => "b_val",
+            Self::CVal => "c_val",
+            Self::RowCol => "row_col",
+            Self::VanishingPolyH => "vanishing_poly_h",
+            Self::VanishingPolyK => "vanishing_poly_k",
+            Self::W => "w",
+            Self::ZA => "z_a",
+            Self::ZB => "z_b",
+            Self::MaskPoly => "mask_poly",
+            Self::T => "t",
+            Self::G1 => "g_1",
+            Self::H1 => "h_1",
+            Self::G2 => "g_2",
+            Self::H2 => "h_2",
+        }
+    }
+}
+
+impl PolynomialLabelSet for AHPPolynomial {
+    #[rustfmt::skip]
+    const ALL: &'static [Self] = &[
+        Self::Row, Self::Col, Self::AVal, Self::BVal, Self::CVal, Self::RowCol,
+        Self::VanishingPolyH, Self::VanishingPolyK,
+        Self::W, Self::ZA, Self::ZB, Self::MaskPoly,
+        Self::T, Self::G1, Self::H1,
+        Self::G2, Self::H2,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        self.label()
+    }
+}
+
+impl From<AHPPolynomial> for String {
+    fn from(polynomial: AHPPolynomial) -> Self {
+        polynomial.label().into()
+    }
+}
+
+impl From<AHPPolynomial> for LCTerm {
+    fn from(polynomial: AHPPolynomial) -> Self {
+        LCTerm::PolyLabel(polynomial.label().into())
+    }
+}
+
+impl fmt::Display for AHPPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ahp::AHPForR1CS, marlin::MarlinRecursiveMode};
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_labels_are_unique() {
+        for (i, polynomial) in AHPPolynomial::ALL.iter().enumerate() {
+            assert_eq!(Some(*polynomial), AHPPolynomial::from_label(polynomial.label()));
+            assert!(AHPPolynomial::ALL[i + 1..]
+                .iter()
+                .all(|other| other.label() != polynomial.label()));
+        }
+        assert_eq!(None, AHPPolynomial::from_label("g1"));
+    }
+
+    #[test]
+    fn test_ahp_polynomials_are_labeled() {
+        type AHP = AHPForR1CS<Fr, MarlinRecursiveMode>;
+
+        // Ensure the indexer and prover polynomials of the AHP are exactly the labeled polynomials.
+        let mut polynomials: Vec<_> = AHP::INDEXER_POLYNOMIALS_WITH_VANISHING
+            .iter()
+            .chain(AHP::PROVER_POLYNOMIALS_WITH_ZK.iter())
+            .copied()
+            .collect();
+        polynomials.sort();
+        let mut expected = AHPPolynomial::ALL.to_vec();
+        expected.sort();
+        assert_eq!(expected, polynomials);
+    }
+}
@@ -16,26 +16,30 @@
 
 #![allow(non_snake_case)]
 
+#[cfg(feature = "prover")]
 use crate::{
-    ahp::{indexer::Matrix, UnnormalizedBivariateLagrangePoly},
+    ahp::{indexer::Matrix, AHPPolynomial, UnnormalizedBivariateLagrangePoly},
     BTreeMap,
 };
-use snarkvm_algorithms::{
-    cfg_iter_mut,
-    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
-};
-use snarkvm_fields::{batch_inversion, Field, PrimeField};
+use snarkvm_algorithms::fft::Evaluations as EvaluationsOnDomain;
+#[cfg(feature = "prover")]
+use snarkvm_algorithms::{cfg_iter_mut, fft::EvaluationDomain};
+use snarkvm_fields::PrimeField;
+#[cfg(feature = "prover")]
+use snarkvm_fields::{batch_inversion, Field};
 use snarkvm_polycommit::LabeledPolynomial;
+#[cfg(feature = "prover")]
 use snarkvm_r1cs::{ConstraintSystem, Index as VarIndex};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
 use derivative::Derivative;
 
-#[cfg(feature = "parallel")]
+#[cfg(all(feature = "prover", feature = "parallel"))]
 use rayon::prelude::*;
 
 // This function converts a matrix output by Zexe's constraint infrastructure
 // to the one used in this crate.
+#[cfg(feature = "prover")]
 pub(crate) fn to_matrix_helper<F: Field>(matrix: &[Vec<(F, VarIndex)>], num_input_variables: usize) -> Matrix<F> {
     let mut new_matrix = Vec::with_capacity(matrix.len());
     for row in matrix {
@@ -53,11 +57,13 @@ pub(crate) fn to_matrix_helper<F: Field>(matrix: &[Vec<(F, VarIndex)>], num_inpu
 }
 
 /// This must *always* be in sync with `make_matrices_square`.
+#[cfg(feature = "prover")]
 pub(crate) fn padded_matrix_dim(num_formatted_variables: usize, num_constraints: usize) -> usize {
     core::cmp::max(num_formatted_variables, num_constraints)
 }
 
 /// Pads the public variables up to the closest power of two.
+#[cfg(feature = "prover")]
 pub(crate) fn pad_input_for_indexer_and_prover<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut CS) {
     let num_public_variables = cs.num_public_variables();
 
@@ -73,6 +79,7 @@ pub(crate) fn pad_input_for_indexer_and_prover<F: PrimeField, CS: ConstraintSyst
     }
 }
 
+#[cfg(feature = "prover")]
 pub(crate) fn make_matrices_square<F: Field, CS: ConstraintSystem<F>>(cs: &mut CS, num_formatted_variables: usize) {
     let num_constraints = cs.num_constraints();
     let matrix_padding = ((num_formatted_variables as isize) - (num_constraints as isize)).abs();
@@ -136,6 +143,7 @@ pub struct MatrixArithmetization<F: PrimeField> {
 }
 
 // TODO for debugging: add test that checks result of arithmetize_matrix(M).
+#[cfg(feature = "prover")]
 pub(crate) fn arithmetize_matrix<F: PrimeField>(
     joint_matrix: &[Vec<usize>],
     a: &Matrix<F>,
@@ -147,7 +155,7 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
 ) -> MatrixArithmetization<F> {
     let matrix_time = start_timer!(|| "Computing row, col, and val LDEs");
 
-    let elems: Vec<_> = output_domain.elements().collect();
+    let elems: Vec<_> = output_domain.par_elements().collect();
 
     let lde_evals_time = start_timer!(|| "Computing row, col and val evals");
     // Recall that we are computing the arithmetization of M^*,
@@ -174,8 +182,9 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
         .collect::<BTreeMap<(usize, usize), F>>();
 
     let eq_poly_vals_time = start_timer!(|| "Precomputing eq_poly_vals");
-    let eq_poly_vals: BTreeMap<F, F> = output_domain
-        .elements()
+    let eq_poly_vals: BTreeMap<F, F> = elems
+        .iter()
+        .copied()
         .zip(output_domain.batch_eval_unnormalized_bivariate_lagrange_poly_with_same_inputs())
         .collect();
     end_timer!(eq_poly_vals_time);
@@ -260,17 +269,17 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
     };
 
     MatrixArithmetization {
-        row: LabeledPolynomial::new("row".into(), row, None, None),
-        col: LabeledPolynomial::new("col".into(), col, None, None),
-        val_a: LabeledPolynomial::new("a_val".into(), val_a, None, None),
-        val_b: LabeledPolynomial::new("b_val".into(), val_b, None, None),
-        val_c: LabeledPolynomial::new("c_val".into(), val_c, None, None),
-        row_col: LabeledPolynomial::new("row_col".into(), row_col, None, None),
+        row: LabeledPolynomial::new(AHPPolynomial::Row.into(), row, None, None),
+        col: LabeledPolynomial::new(AHPPolynomial::Col.into(), col, None, None),
+        val_a: LabeledPolynomial::new(AHPPolynomial::AVal.into(), val_a, None, None),
+        val_b: LabeledPolynomial::new(AHPPolynomial::BVal.into(), val_b, None, None),
+        val_c: LabeledPolynomial::new(AHPPolynomial::CVal.into(), val_c, None, None),
+        row_col: LabeledPolynomial::new(AHPPolynomial::RowCol.into(), row_col, None, None),
         evals_on_K,
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod tests {
     use super::*;
     use crate::num_non_zero;
@@ -22,6 +22,10 @@ pub use ahp::*;
 pub mod errors;
 pub use errors::*;
 
+/// The labels of the polynomials of the AHP.
+pub mod labels;
+pub use labels::*;
+
 /// Describes data structures and the algorithms used by the AHP indexer.
 pub mod indexer;
 pub(crate) use indexer::*;
@@ -32,4 +36,5 @@ pub(crate) mod matrices;
 pub mod prover;
 
 /// Describes data structures and the algorithms used by the AHP verifier.
+#[cfg(feature = "verifier")]
 pub mod verifier;
@@ -39,20 +39,6 @@ impl<F: Field> ProverConstraintSystem<F> {
         }
     }
 
-    /// Formats the public input according to the requirements of the constraint
-    /// system
-    pub(crate) fn format_public_input(public_input: &[F]) -> Vec<F> {
-        let mut input = vec![F::one()];
-        input.extend_from_slice(public_input);
-        input
-    }
-
-    /// Takes in a previously formatted public input and removes the formatting
-    /// imposed by the constraint system.
-    pub(crate) fn unformat_public_input(input: &[F]) -> Vec<F> {
-        input[1..].to_vec()
-    }
-
     pub(crate) fn make_matrices_square(&mut self) {
         let num_variables = self.num_public_variables + self.num_private_variables;
         make_matrices_square(self, num_variables);
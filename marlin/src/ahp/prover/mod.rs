@@ -16,12 +16,16 @@
 
 #![allow(non_snake_case)]
 
+#[cfg(feature = "prover")]
 mod constraint_system;
+#[cfg(feature = "prover")]
 pub(crate) use constraint_system::*;
 
 mod message;
 pub(crate) use message::*;
 
+#[cfg(feature = "prover")]
 mod prover;
 
+#[cfg(feature = "prover")]
 mod state;
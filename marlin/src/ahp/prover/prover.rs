@@ -16,23 +16,23 @@
 
 use crate::{
     ahp::{
-        indexer::{Circuit, CircuitInfo, Matrix},
+        indexer::{Circuit, CircuitSpecialization, Matrix},
         prover::ProverConstraintSystem,
         verifier::{VerifierFirstMessage, VerifierSecondMessage},
         AHPError,
         AHPForR1CS,
+        AHPPolynomial,
         UnnormalizedBivariateLagrangePoly,
     },
     marlin::MarlinMode,
     prover::{state::ProverState, ProverMessage},
-    ToString,
     Vec,
 };
 use snarkvm_algorithms::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain, GeneralEvaluationDomain},
 };
 use snarkvm_fields::{batch_inversion, Field, PrimeField};
 use snarkvm_r1cs::errors::SynthesisError;
@@ -109,6 +109,15 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     pub fn prover_init<'a, C: ConstraintSynthesizer<F>>(
         index: &'a Circuit<F, MM>,
         circuit: &C,
+    ) -> Result<ProverState<'a, F, MM>, AHPError> {
+        Self::prover_init_with_specialization(index, None, circuit)
+    }
+
+    /// Initialize the AHP prover for the given index, which is derived from the circuit by the given specialization.
+    pub fn prover_init_with_specialization<'a, C: ConstraintSynthesizer<F>>(
+        index: &'a Circuit<F, MM>,
+        specialization: Option<&CircuitSpecialization<F>>,
+        circuit: &C,
     ) -> Result<ProverState<'a, F, MM>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
@@ -126,10 +135,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let ProverConstraintSystem {
             public_variables: padded_public_variables,
-            private_variables,
-            num_constraints,
+            mut private_variables,
+            mut num_constraints,
             num_public_variables,
-            num_private_variables,
+            mut num_private_variables,
             ..
         } = pcs;
 
@@ -137,6 +146,19 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         assert!(padded_public_variables[0].is_one());
         assert_eq!(private_variables.len(), num_private_variables);
 
+        // Map the assignment of the circuit to the assignment of the specialized index.
+        if let Some(specialization) = specialization {
+            if specialization.num_constraints != num_constraints
+                || specialization.num_variables != (num_public_variables + num_private_variables)
+            {
+                return Err(AHPError::InstanceDoesNotMatchIndex);
+            }
+
+            private_variables = specialization.specialize_assignment(&private_variables)?;
+            num_private_variables = private_variables.len();
+            num_constraints = index.index_info.num_constraints;
+        }
+
         if cfg!(debug_assertions) {
             println!(
                 "Number of padded public variables in Prover::Init: {}",
@@ -214,6 +236,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     }
 
     /// Output the first round message and the next state.
+    ///
+    /// The private variables and the evaluations of `z_A` and `z_B` are consumed by this round,
+    /// and their buffers are reused for the witness and the interpolated polynomials.
+    /// At most 3 vectors of size |H| are live at once, in addition to the polynomials kept in the state.
     #[allow(clippy::type_complexity)]
     pub fn prover_first_round<'a, R: RngCore>(
         mut state: ProverState<'a, F, MM>,
@@ -234,13 +260,11 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let ratio = domain_h.size() / domain_x.size();
 
-        let mut w_extended = state.private_variables.clone();
-        w_extended.extend(vec![
-            F::zero();
-            domain_h.size() - domain_x.size() - state.private_variables.len()
-        ]);
-
         let w_poly_time = start_timer!(|| "Computing w polynomial");
+        // The private variables are not needed after this round, so they are padded in place.
+        let mut w_extended = core::mem::take(&mut state.private_variables);
+        w_extended.resize(domain_h.size() - domain_x.size(), F::zero());
+
         let w_poly_evals = cfg_into_iter!(0..domain_h.size())
             .map(|k| {
                 if k % ratio == 0 {
@@ -250,6 +274,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 }
             })
             .collect();
+        drop(w_extended);
+        drop(x_evals);
 
         let w_poly = &EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, domain_h).interpolate()
             + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
@@ -258,7 +284,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         end_timer!(w_poly_time);
 
         let z_a_poly_time = start_timer!(|| "Computing z_A polynomial");
-        let z_a = state.z_a.clone().unwrap();
+        let z_a = state.z_a.take().unwrap();
         let mut z_a_poly = EvaluationsOnDomain::from_vec_and_domain(z_a, domain_h).interpolate();
         if MM::ZK {
             z_a_poly += &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
@@ -266,7 +292,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         end_timer!(z_a_poly_time);
 
         let z_b_poly_time = start_timer!(|| "Computing z_B polynomial");
-        let z_b = state.z_b.clone().unwrap();
+        let z_b = state.z_b.take().unwrap();
         let mut z_b_poly = EvaluationsOnDomain::from_vec_and_domain(z_b, domain_h).interpolate();
         if MM::ZK {
             z_b_poly += &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
@@ -294,12 +320,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let hiding_bound = if MM::ZK { Some(1) } else { None };
 
-        let w = LabeledPolynomial::new("w".to_string(), w_poly, None, hiding_bound);
-        let z_a = LabeledPolynomial::new("z_a".to_string(), z_a_poly, None, hiding_bound);
-        let z_b = LabeledPolynomial::new("z_b".to_string(), z_b_poly, None, hiding_bound);
+        let w = LabeledPolynomial::new(AHPPolynomial::W.into(), w_poly, None, hiding_bound);
+        let z_a = LabeledPolynomial::new(AHPPolynomial::ZA.into(), z_a_poly, None, hiding_bound);
+        let z_b = LabeledPolynomial::new(AHPPolynomial::ZB.into(), z_b_poly, None, hiding_bound);
 
         let mask_poly =
-            mask_poly.map(|mask_poly| LabeledPolynomial::new("mask_poly".to_string(), mask_poly, None, None));
+            mask_poly.map(|mask_poly| LabeledPolynomial::new(AHPPolynomial::MaskPoly.into(), mask_poly, None, None));
 
         let oracles = ProverFirstOracles {
             w: w.clone(),
@@ -335,21 +361,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         EvaluationsOnDomain::from_vec_and_domain(t_evals_on_h, domain_h).interpolate()
     }
 
-    /// Output the number of oracles sent by the prover in the first round.
-    pub fn prover_num_first_round_oracles() -> usize {
-        if MM::ZK { 4 } else { 3 }
-    }
-
-    /// Output the degree bounds of oracles in the first round.
-    pub fn prover_first_round_degree_bounds(_info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
-        if MM::ZK {
-            vec![None; 4].into_iter()
-        } else {
-            vec![None; 3].into_iter()
-        }
-    }
-
     /// Output the second round message and the next state.
+    ///
+    /// The polynomials `w`, `z_A`, and `z_B` are consumed by this round. The product for `q_1` is
+    /// computed over a multiplication domain M of size about 3|H|, with at most 3 vectors of size |M| live at once.
     pub fn prover_second_round<'a, R: RngCore>(
         verifier_message: &VerifierFirstMessage<F>,
         mut state: ProverState<'a, F, MM>,
@@ -371,7 +386,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         } = *verifier_message;
 
         let summed_z_m_poly_time = start_timer!(|| "Compute z_m poly");
-        let (z_a_poly, z_b_poly) = state.mz_polys.as_ref().unwrap();
+        let (z_a_poly, z_b_poly) = state.mz_polys.take().unwrap();
         let z_c_poly = z_a_poly.polynomial() * z_b_poly.polynomial();
 
         let mut summed_z_m_coeffs = z_c_poly.coeffs;
@@ -383,6 +398,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .zip(&z_a_poly.polynomial().coeffs)
             .zip(&z_b_poly.polynomial().coeffs)
             .for_each(|((c, a), b)| *c += &(eta_a * a + (eta_b * b)));
+        drop(z_a_poly);
+        drop(z_b_poly);
 
         let summed_z_m = Polynomial::from_coefficients_vec(summed_z_m_coeffs);
         end_timer!(summed_z_m_poly_time);
@@ -407,13 +424,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let z_poly_time = start_timer!(|| "Compute z poly");
 
-        let domain_x = EvaluationDomain::new(state.padded_public_variables.len())
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
+        let domain_x = state.domain_x;
         let x_poly =
             EvaluationsOnDomain::from_vec_and_domain(state.padded_public_variables.clone(), domain_x).interpolate();
-        let w_poly = state.w_poly.as_ref().unwrap();
+        let w_poly = state.w_poly.take().unwrap();
         let mut z_poly = w_poly.polynomial().mul_by_vanishing_poly(domain_x);
+        drop(w_poly);
         cfg_iter_mut!(z_poly.coeffs)
             .zip(&x_poly.coeffs)
             .for_each(|(z, x)| *z += x);
@@ -431,22 +447,36 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         .iter()
         .max()
         .unwrap();
+        // The products have up to three times the size of `domain_h`, so a mixed-radix domain is used
+        // when a radix-2 domain would exceed the two-adicity of the field.
         let mul_domain =
-            EvaluationDomain::new(mul_domain_size).expect("field is not smooth enough to construct domain");
-        let mut r_alpha_evals = r_alpha_poly.evaluate_over_domain_by_ref(mul_domain);
-        let summed_z_m_evals = summed_z_m.evaluate_over_domain_by_ref(mul_domain);
-        let z_poly_evals = z_poly.evaluate_over_domain_by_ref(mul_domain);
-        let t_poly_m_evals = t_poly.evaluate_over_domain_by_ref(mul_domain);
-
-        cfg_iter_mut!(r_alpha_evals.evaluations)
-            .zip(&summed_z_m_evals.evaluations)
-            .zip(&z_poly_evals.evaluations)
-            .zip(&t_poly_m_evals.evaluations)
-            .for_each(|(((a, b), &c), d)| {
-                *a *= b;
-                *a -= &(c * d);
-            });
-        let mut rhs = r_alpha_evals.interpolate();
+            GeneralEvaluationDomain::new(mul_domain_size).expect("field is not smooth enough to construct domain");
+
+        // Compute r_alpha * summed_z_m - z * t, evaluating one operand at a time and reusing the coefficient buffers.
+        let mut r_alpha_evals = r_alpha_poly.coeffs;
+        mul_domain.fft_in_place(&mut r_alpha_evals);
+        let mut summed_z_m_evals = summed_z_m.coeffs;
+        mul_domain.fft_in_place(&mut summed_z_m_evals);
+        cfg_iter_mut!(r_alpha_evals)
+            .zip(&summed_z_m_evals)
+            .for_each(|(a, b)| *a *= b);
+        drop(summed_z_m_evals);
+
+        let mut z_poly_evals = z_poly.coeffs;
+        mul_domain.fft_in_place(&mut z_poly_evals);
+        let t_poly_m_evals = mul_domain.fft(&t_poly.coeffs);
+        cfg_iter_mut!(z_poly_evals)
+            .zip(&t_poly_m_evals)
+            .for_each(|(c, d)| *c *= d);
+        drop(t_poly_m_evals);
+
+        cfg_iter_mut!(r_alpha_evals)
+            .zip(&z_poly_evals)
+            .for_each(|(a, c)| *a -= c);
+        drop(z_poly_evals);
+
+        mul_domain.ifft_in_place(&mut r_alpha_evals);
+        let mut rhs = Polynomial::from_coefficients_vec(r_alpha_evals);
         rhs += mask_poly.map_or(&Polynomial::zero(), |p| p.polynomial());
         let q_1 = rhs;
         end_timer!(q_1_time);
@@ -463,31 +493,21 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let hiding_bound = if MM::ZK { Some(1) } else { None };
         let oracles = ProverSecondOracles {
-            t: LabeledPolynomial::new("t".into(), t_poly, None, None),
-            g_1: LabeledPolynomial::new("g_1".into(), g_1, Some(domain_h.size() - 2), hiding_bound),
-            h_1: LabeledPolynomial::new("h_1".into(), h_1, None, None),
+            t: LabeledPolynomial::new(AHPPolynomial::T.into(), t_poly, None, None),
+            g_1: LabeledPolynomial::new(AHPPolynomial::G1.into(), g_1, Some(domain_h.size() - 2), hiding_bound),
+            h_1: LabeledPolynomial::new(AHPPolynomial::H1.into(), h_1, None, None),
         };
 
-        state.w_poly = None;
         state.verifier_first_message = Some(*verifier_message);
         end_timer!(round_time);
 
         (msg, oracles, state)
     }
 
-    /// Output the number of oracles sent by the prover in the second round.
-    pub fn prover_num_second_round_oracles() -> usize {
-        3
-    }
-
-    /// Output the degree bounds of oracles in the second round.
-    pub fn prover_second_round_degree_bounds(info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
-        let h_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap();
-
-        vec![None, Some(h_domain_size - 2), None].into_iter()
-    }
-
     /// Output the third round message and the next state.
+    ///
+    /// The polynomials are computed one at a time, with at most 3 vectors of size |K|,
+    /// and the product `b * f` of size 2|K|, live at once.
     pub fn prover_third_round<'a, R: RngCore>(
         verifier_message: &VerifierSecondMessage<F>,
         prover_state: ProverState<'a, F, MM>,
@@ -523,42 +543,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let joint_arith = &index.joint_arith;
 
-        let a_poly_time = start_timer!(|| "Computing a poly");
-        let a_poly = {
-            let a = joint_arith.val_a.coeffs();
-            let b = joint_arith.val_b.coeffs();
-            let c = joint_arith.val_c.coeffs();
-            let coeffs: Vec<F> = cfg_iter!(a)
-                .zip(b)
-                .zip(c)
-                .map(|((a, b), c)| {
-                    eta_a_times_v_H_alpha_v_H_beta * a
-                        + eta_b_times_v_H_alpha_v_H_beta * b
-                        + eta_c_times_v_H_alpha_v_H_beta * c
-                })
-                .collect();
-            DensePolynomial::from_coefficients_vec(coeffs)
-        };
-        end_timer!(a_poly_time);
-
         let (row_on_K, col_on_K, row_col_on_K) = (
             &joint_arith.evals_on_K.row,
             &joint_arith.evals_on_K.col,
             &joint_arith.evals_on_K.row_col,
         );
 
-        let b_poly_time = start_timer!(|| "Computing b poly");
-        let alpha_beta = alpha * beta;
-        let b_poly = {
-            let evals: Vec<F> = cfg_iter!(row_on_K.evaluations)
-                .zip(&col_on_K.evaluations)
-                .zip(&row_col_on_K.evaluations)
-                .map(|((r, c), r_c)| alpha_beta - alpha * r - beta * c + r_c)
-                .collect();
-            EvaluationsOnDomain::from_vec_and_domain(evals, domain_k).interpolate()
-        };
-        end_timer!(b_poly_time);
-
         let f_evals_time = start_timer!(|| "Computing f evals on K");
         let mut inverses: Vec<_> = cfg_into_iter!(0..domain_k.size())
             .map(|i| (beta - row_on_K[i]) * (alpha - col_on_K[i]))
@@ -578,20 +568,53 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                         + eta_c_times_v_H_alpha_v_H_beta * val_c_on_K[i])
             })
             .collect();
+        drop(inverses);
         end_timer!(f_evals_time);
 
         let f_poly_time = start_timer!(|| "Computing f poly");
         let f = EvaluationsOnDomain::from_vec_and_domain(f_evals_on_K, domain_k).interpolate();
         end_timer!(f_poly_time);
 
+        let b_poly_time = start_timer!(|| "Computing b poly");
+        let alpha_beta = alpha * beta;
+        let b_poly = {
+            let evals: Vec<F> = cfg_iter!(row_on_K.evaluations)
+                .zip(&col_on_K.evaluations)
+                .zip(&row_col_on_K.evaluations)
+                .map(|((r, c), r_c)| alpha_beta - alpha * r - beta * c + r_c)
+                .collect();
+            EvaluationsOnDomain::from_vec_and_domain(evals, domain_k).interpolate()
+        };
+        end_timer!(b_poly_time);
+
+        // Compute `b * f` before `a`, so that `b` can be dropped first.
+        let b_f = &b_poly * &f;
+        drop(b_poly);
+
+        let a_poly_time = start_timer!(|| "Computing a poly");
+        let a_poly = {
+            let a = joint_arith.val_a.coeffs();
+            let b = joint_arith.val_b.coeffs();
+            let c = joint_arith.val_c.coeffs();
+            let coeffs: Vec<F> = cfg_iter!(a)
+                .zip(b)
+                .zip(c)
+                .map(|((a, b), c)| {
+                    eta_a_times_v_H_alpha_v_H_beta * a
+                        + eta_b_times_v_H_alpha_v_H_beta * b
+                        + eta_c_times_v_H_alpha_v_H_beta * c
+                })
+                .collect();
+            DensePolynomial::from_coefficients_vec(coeffs)
+        };
+        end_timer!(a_poly_time);
+
         let h_2_poly_time = start_timer!(|| "Computing sumcheck h poly");
-        let h_2 = (&a_poly - &(&b_poly * &f))
-            .divide_by_vanishing_poly(domain_k)
-            .unwrap()
-            .0;
-        end_timer!(h_2_poly_time);
+        let h_2 = (&a_poly - &b_f).divide_by_vanishing_poly(domain_k).unwrap().0;
         drop(a_poly);
-        drop(b_poly);
+        drop(b_f);
+        end_timer!(h_2_poly_time);
+
         let g_2 = DensePolynomial::from_coefficients_slice(&f.coeffs[1..]);
         drop(f);
 
@@ -601,24 +624,11 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         assert!(g_2.degree() <= domain_k.size() - 2);
 
         let oracles = ProverThirdOracles {
-            g_2: LabeledPolynomial::new("g_2".to_string(), g_2, Some(domain_k.size() - 2), None),
-            h_2: LabeledPolynomial::new("h_2".to_string(), h_2, None, None),
+            g_2: LabeledPolynomial::new(AHPPolynomial::G2.into(), g_2, Some(domain_k.size() - 2), None),
+            h_2: LabeledPolynomial::new(AHPPolynomial::H2.into(), h_2, None, None),
         };
         end_timer!(round_time);
 
         Ok((msg, oracles))
     }
-
-    /// Output the number of oracles sent by the prover in the third round.
-    pub fn prover_num_third_round_oracles() -> usize {
-        2
-    }
-
-    /// Output the degree bounds of oracles in the third round.
-    pub fn prover_third_round_degree_bounds(info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
-        let num_non_zero = info.num_non_zero;
-        let k_size = EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).unwrap();
-
-        vec![Some(k_size - 2), None].into_iter()
-    }
 }
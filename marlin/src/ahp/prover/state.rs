@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    ahp::{indexer::Circuit, prover::ProverConstraintSystem, verifier::VerifierFirstMessage},
+    ahp::{indexer::Circuit, verifier::VerifierFirstMessage, AHPForR1CS},
     marlin::MarlinMode,
     Vec,
 };
@@ -26,15 +26,18 @@ use snarkvm_polycommit::LabeledPolynomial;
 /// State for the AHP prover.
 pub struct ProverState<'a, F: PrimeField, MM: MarlinMode> {
     pub(super) padded_public_variables: Vec<F>,
+    /// The private variables, consumed in the first round
     pub(super) private_variables: Vec<F>,
-    /// Az
+    /// Az, consumed in the first round
     pub(super) z_a: Option<Vec<F>>,
-    /// Bz
+    /// Bz, consumed in the first round
     pub(super) z_b: Option<Vec<F>>,
     /// query bound b
     pub(super) zk_bound: usize,
 
+    /// The LDE of `w`, consumed in the second round
     pub(super) w_poly: Option<LabeledPolynomial<F>>,
+    /// The LDEs of `Az` and `Bz`, consumed in the second round
     pub(super) mz_polys: Option<(LabeledPolynomial<F>, LabeledPolynomial<F>)>,
 
     pub(super) index: &'a Circuit<F, MM>,
@@ -58,7 +61,7 @@ pub struct ProverState<'a, F: PrimeField, MM: MarlinMode> {
 impl<'a, F: PrimeField, MM: MarlinMode> ProverState<'a, F, MM> {
     /// Get the public input.
     pub fn public_input(&self) -> Vec<F> {
-        ProverConstraintSystem::unformat_public_input(&self.padded_public_variables)
+        AHPForR1CS::<F, MM>::unformat_public_input(&self.padded_public_variables)
     }
 
     /// Get the padded public input.
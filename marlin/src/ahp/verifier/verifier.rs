@@ -22,6 +22,7 @@ use crate::{
         verifier::{VerifierFirstMessage, VerifierSecondMessage, VerifierState},
         AHPError,
         AHPForR1CS,
+        AHPPolynomial,
     },
     marlin::MarlinMode,
     traits::FiatShamirRng,
@@ -143,9 +144,9 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
         //  LinearCombination::new("z_b", vec![(F::one(), z_b)])
         //  LinearCombination::new("g_1", vec![(F::one(), g_1)], rhs::new(g_1_at_beta))
         //  LinearCombination::new("t", vec![(F::one(), t)])
-        query_set.insert(("g_1".into(), ("beta".into(), beta)));
-        query_set.insert(("z_b".into(), ("beta".into(), beta)));
-        query_set.insert(("t".into(), ("beta".into(), beta)));
+        query_set.insert((AHPPolynomial::G1.into(), ("beta".into(), beta)));
+        query_set.insert((AHPPolynomial::ZB.into(), ("beta".into(), beta)));
+        query_set.insert((AHPPolynomial::T.into(), ("beta".into(), beta)));
         query_set.insert(("outer_sumcheck".into(), ("beta".into(), beta)));
 
         // For the second linear combination
@@ -188,7 +189,7 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
         // // This LC is the only one that is evaluated:
         // let inner_sumcheck = a_poly_lc - (b_lc * (gamma * g_2_at_gamma + (t_at_beta / &k_size))) - h_lc
         // main_lc.set_label("inner_sumcheck");
-        query_set.insert(("g_2".into(), ("gamma".into(), gamma)));
+        query_set.insert((AHPPolynomial::G2.into(), ("gamma".into(), gamma)));
         query_set.insert(("inner_sumcheck".into(), ("gamma".into(), gamma)));
 
         if with_vanishing {
@@ -432,14 +432,19 @@ impl<
                 LinearCombinationCoeffVar::Var(r_alpha_at_beta.mul(cs.ns(|| "r_alpha_mul_eta_a"), &eta_a_add_above)?),
                 "z_a".into(),
             ));
-            terms.push((
-                LinearCombinationCoeffVar::Var(
-                    r_alpha_at_beta
-                        .mul(cs.ns(|| "r_alpha_at_beta_mul_eta_b"), &eta_b)?
-                        .mul(cs.ns(|| "r_alpha_at_beta_mul_eta_b_mul_z_b_at_beta"), z_b_at_beta)?,
-                ),
-                LCTerm::One,
-            ));
+            // The constant terms are merged into a single term, following `z_a`, as in the native verifier.
+            let constant_term = r_alpha_at_beta
+                .mul(cs.ns(|| "r_alpha_at_beta_mul_eta_b"), &eta_b)?
+                .mul(cs.ns(|| "r_alpha_at_beta_mul_eta_b_mul_z_b_at_beta"), z_b_at_beta)?
+                .sub(
+                    cs.ns(|| "sub_t_f"),
+                    &t_at_beta.mul(cs.ns(|| "t_at_beta_mul_f_x_at_beta"), &f_x_at_beta)?,
+                )?
+                .sub(
+                    cs.ns(|| "sub_beta_g1"),
+                    &beta.mul(cs.ns(|| "beta_mul_g_1_at_beta"), g_1_at_beta)?,
+                )?;
+            terms.push((LinearCombinationCoeffVar::Var(constant_term), LCTerm::One));
             terms.push((
                 LinearCombinationCoeffVar::Var(
                     t_at_beta
@@ -448,24 +453,10 @@ impl<
                 ),
                 "w".into(),
             ));
-            terms.push((
-                LinearCombinationCoeffVar::Var(
-                    t_at_beta
-                        .mul(cs.ns(|| "t_at_beta_mul_f_x_at_beta"), &f_x_at_beta)?
-                        .negate(cs.ns(|| "negate_t_f"))?,
-                ),
-                LCTerm::One,
-            ));
             terms.push((
                 LinearCombinationCoeffVar::Var(v_h_at_beta.negate(cs.ns(|| "negate_v_h"))?),
                 "h_1".into(),
             ));
-            terms.push((
-                LinearCombinationCoeffVar::Var(
-                    (beta.mul(cs.ns(|| "beta_mul_g_1_at_beta"), g_1_at_beta))?.negate(cs.ns(|| "negate_beta_g1"))?,
-                ),
-                LCTerm::One,
-            ));
             terms
         };
         let outer_sumcheck_lc_gadget = LinearCombinationVar::<TargetField, BaseField> {
@@ -860,7 +851,7 @@ impl<
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 #[allow(clippy::upper_case_acronyms)]
 mod test {
     use core::ops::MulAssign;
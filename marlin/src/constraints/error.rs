@@ -49,6 +49,9 @@ impl From<MarlinError> for MarlinConstraintsError {
                 AHPError::NonSquareMatrix => Self {
                     error_msg: String::from("non-sqaure matrix"),
                 },
+                AHPError::InvalidSpecialization(str) => Self {
+                    error_msg: String::from("invalid specialization: ") + &*str,
+                },
             },
             MarlinError::R1CSError(err) => Self {
                 error_msg: err.to_string(),
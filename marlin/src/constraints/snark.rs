@@ -30,10 +30,15 @@ use crate::{
     PhantomData,
     PolynomialCommitment,
 };
+use blake2::{Blake2s, Digest};
 use core::sync::atomic::AtomicBool;
-use snarkvm_algorithms::{crypto_hash::PoseidonDefaultParametersField, SNARKError, SNARK, SRS};
+use snarkvm_algorithms::{crypto_hash::PoseidonDefaultParametersField, SNARKError, SNARKProtocolID, SNARK, SRS};
 use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+/// The size in bytes of the integrity hash prepended to a serialized Marlin index.
+pub const INDEX_CHECKSUM_SIZE_IN_BYTES: usize = 32;
 
 /// The Marlin proof system.
 #[derive(Clone, Debug)]
@@ -48,6 +53,66 @@ pub struct MarlinSNARK<
     _phantom: PhantomData<(F, FSF, PC, FS, MC, V)>,
 }
 
+impl<TargetField, BaseField, PC, FS, MM, V> MarlinSNARK<TargetField, BaseField, PC, FS, MM, V>
+where
+    TargetField: PrimeField,
+    BaseField: PrimeField + PoseidonDefaultParametersField,
+    PC: PolynomialCommitment<TargetField, BaseField>,
+    FS: FiatShamirRng<TargetField, BaseField>,
+    MM: MarlinMode,
+    V: ToConstraintField<TargetField> + Clone,
+{
+    ///
+    /// Returns the serialized index (the indexed circuit and its commitments) of the given proving key,
+    /// prefixed with a BLAKE2s integrity hash, so that it may be cached and restored with `load_index`.
+    ///
+    pub fn index_to_bytes(
+        proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+    ) -> Result<Vec<u8>, SNARKError> {
+        let index = proving_key.to_bytes_le()?;
+
+        let mut bytes = Vec::with_capacity(INDEX_CHECKSUM_SIZE_IN_BYTES + index.len());
+        bytes.extend_from_slice(&Blake2s::digest(&index));
+        bytes.extend_from_slice(&index);
+        Ok(bytes)
+    }
+
+    ///
+    /// Returns the proving key and verifying key from a serialized index produced by `index_to_bytes`,
+    /// skipping the AHP indexer and the polynomial commitments to the indexed polynomials.
+    ///
+    /// Returns an error if the integrity hash does not match the serialized index.
+    ///
+    pub fn load_index(
+        bytes: &[u8],
+    ) -> Result<
+        (
+            CircuitProvingKey<TargetField, BaseField, PC, MM>,
+            CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        ),
+        SNARKError,
+    > {
+        if bytes.len() < INDEX_CHECKSUM_SIZE_IN_BYTES {
+            return Err(SNARKError::Message(format!(
+                "Marlin index is {} bytes, expected at least {} bytes",
+                bytes.len(),
+                INDEX_CHECKSUM_SIZE_IN_BYTES
+            )));
+        }
+
+        let (checksum, index) = bytes.split_at(INDEX_CHECKSUM_SIZE_IN_BYTES);
+        if checksum != Blake2s::digest(index).as_slice() {
+            return Err(SNARKError::Message(
+                "Marlin index failed the integrity check".to_string(),
+            ));
+        }
+
+        let proving_key = CircuitProvingKey::<TargetField, BaseField, PC, MM>::from_bytes_le(index)?;
+        let verifying_key = proving_key.circuit_verifying_key.clone();
+        Ok((proving_key, verifying_key))
+    }
+}
+
 impl<TargetField, BaseField, PC, FS, MM, V> SNARK for MarlinSNARK<TargetField, BaseField, PC, FS, MM, V>
 where
     TargetField: PrimeField,
@@ -57,6 +122,11 @@ where
     MM: MarlinMode,
     V: ToConstraintField<TargetField> + Clone,
 {
+    const PROTOCOL_ID: SNARKProtocolID = match MM::ZK {
+        true => SNARKProtocolID::MarlinHiding,
+        false => SNARKProtocolID::Marlin,
+    };
+
     type BaseField = BaseField;
     type PreparedVerifyingKey = PreparedCircuitVerifyingKey<TargetField, BaseField, PC, MM>;
     type Proof = Proof<TargetField, BaseField, PC>;
@@ -82,13 +152,24 @@ where
         circuit: &C,
         srs: &mut SRS<R, Self::UniversalSetupParameters>,
     ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        let (pk, vk) = match srs {
-            SRS::CircuitSpecific(rng) => {
-                MarlinCore::<TargetField, BaseField, PC, FS, MM>::circuit_specific_setup(circuit, rng)?
-            }
-            SRS::Universal(srs) => MarlinCore::<TargetField, BaseField, PC, FS, MM>::circuit_setup(srs, circuit)?,
-        };
-        Ok((pk, vk))
+        #[cfg(feature = "prover")]
+        {
+            let (pk, vk) = match srs {
+                SRS::CircuitSpecific(rng) => {
+                    MarlinCore::<TargetField, BaseField, PC, FS, MM>::circuit_specific_setup(circuit, rng)?
+                }
+                SRS::Universal(srs) => MarlinCore::<TargetField, BaseField, PC, FS, MM>::circuit_setup(srs, circuit)?,
+            };
+            Ok((pk, vk))
+        }
+
+        #[cfg(not(feature = "prover"))]
+        {
+            let _ = (circuit, srs);
+            Err(SNARKError::Message(
+                "Marlin setup requires the `prover` feature".to_string(),
+            ))
+        }
     }
 
     fn prove_with_terminator<C: ConstraintSynthesizer<TargetField>, R: Rng + CryptoRng>(
@@ -97,11 +178,22 @@ where
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError> {
-        match MarlinCore::<TargetField, BaseField, PC, FS, MM>::prove_with_terminator(
-            parameters, circuit, terminator, rng,
-        ) {
-            Ok(res) => Ok(res),
-            Err(e) => Err(SNARKError::from(e)),
+        #[cfg(feature = "prover")]
+        {
+            match MarlinCore::<TargetField, BaseField, PC, FS, MM>::prove_with_terminator(
+                parameters, circuit, terminator, rng,
+            ) {
+                Ok(res) => Ok(res),
+                Err(e) => Err(SNARKError::from(e)),
+            }
+        }
+
+        #[cfg(not(feature = "prover"))]
+        {
+            let _ = (parameters, circuit, terminator, rng);
+            Err(SNARKError::Message(
+                "Marlin proving requires the `prover` feature".to_string(),
+            ))
         }
     }
 
@@ -121,7 +213,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 pub mod test {
     use core::ops::MulAssign;
 
@@ -275,6 +367,43 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn marlin_load_index_test() {
+        let mut rng = test_rng();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+
+        let circ = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+
+        let (pk, vk) = TestSNARK::setup(&circ, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+
+        // Restore the index from bytes, and check it produces the same keys.
+        let index = TestSNARK::index_to_bytes(&pk).unwrap();
+        let (candidate_pk, candidate_vk) = TestSNARK::load_index(&index).unwrap();
+        assert_eq!(pk.to_bytes_le().unwrap(), candidate_pk.to_bytes_le().unwrap());
+        assert_eq!(vk.to_bytes_le().unwrap(), candidate_vk.to_bytes_le().unwrap());
+
+        // Check the restored index produces verifiable proofs.
+        let proof = TestSNARK::prove(&candidate_pk, &circ, &mut rng).unwrap();
+        assert!(TestSNARK::verify(&vk, &vec![c], &proof).unwrap());
+
+        // Check a corrupted or truncated index is rejected.
+        let mut corrupted = index.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 1;
+        assert!(TestSNARK::load_index(&corrupted).is_err());
+        assert!(TestSNARK::load_index(&index[..INDEX_CHECKSUM_SIZE_IN_BYTES - 1]).is_err());
+        assert!(TestSNARK::load_index(&index[..index.len() - 1]).is_err());
+    }
+
     #[test]
     #[cfg(debug_assertions)]
     fn marlin_verifier_num_constraints_test() {
@@ -363,7 +492,7 @@ pub mod test {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 #[allow(clippy::upper_case_acronyms)]
 pub mod multiple_input_tests {
     use core::ops::MulAssign;
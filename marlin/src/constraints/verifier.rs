@@ -313,7 +313,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 #[allow(clippy::upper_case_acronyms)]
 mod test {
     use core::ops::MulAssign;
@@ -447,7 +447,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod test {
     use core::ops::MulAssign;
 
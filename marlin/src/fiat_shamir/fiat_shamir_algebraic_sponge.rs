@@ -53,6 +53,8 @@ where
     S: DefaultCapacityAlgebraicSponge<BaseField, 6>,
 {
     type Parameters = S::Parameters;
+    /// The state of the algebraic sponge.
+    type Snapshot = S;
 
     fn sample_params() -> Self::Parameters {
         S::sample_parameters()
@@ -72,6 +74,14 @@ where
         }
     }
 
+    fn snapshot(&self) -> Self::Snapshot {
+        self.s.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        self.s = snapshot.clone();
+    }
+
     fn absorb_nonnative_field_elements(&mut self, elems: &[TargetField], ty: OptimizationType) {
         Self::push_elements_to_sponge(&mut self.s, elems, ty);
     }
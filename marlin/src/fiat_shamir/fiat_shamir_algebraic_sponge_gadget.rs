@@ -314,6 +314,9 @@ impl<
 > FiatShamirRngVar<TargetField, BaseField, FiatShamirAlgebraicSpongeRng<TargetField, BaseField, PS>>
     for FiatShamirAlgebraicSpongeRngVar<TargetField, BaseField, PS, S>
 {
+    /// The state of the algebraic sponge gadget.
+    type Snapshot = S;
+
     fn new<CS: ConstraintSystem<BaseField>>(cs: CS) -> Self {
         Self {
             s: S::with_parameters(cs, &PS::sample_parameters()),
@@ -335,6 +338,14 @@ impl<
         }
     }
 
+    fn snapshot(&self) -> Self::Snapshot {
+        self.s.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        self.s = snapshot.clone();
+    }
+
     fn absorb_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
         &mut self,
         cs: CS,
@@ -594,6 +605,55 @@ mod tests {
         assert!(cs.is_satisfied());
     }
 
+    #[test]
+    fn test_snapshot_restore() {
+        let rng = &mut ChaChaRng::seed_from_u64(123456789u64);
+        let absorbed_rand_field_elems: Vec<_> = (0..NUM_ABSORBED_RAND_FIELD_ELEMS).map(|_| Fq::rand(rng)).collect();
+
+        // fs_rng in the plaintext world
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_native_field_elements(&absorbed_rand_field_elems);
+        let snapshot = fs_rng.snapshot();
+        let expected = fs_rng.squeeze_native_field_elements(NUM_SQUEEZED_FIELD_ELEMS).unwrap();
+
+        fs_rng.absorb_bytes(&[1u8; SIZE_ABSORBED_BYTE_ELEM]);
+        fs_rng.restore(&snapshot);
+        assert_eq!(
+            expected,
+            fs_rng.squeeze_native_field_elements(NUM_SQUEEZED_FIELD_ELEMS).unwrap()
+        );
+
+        // fs_rng in the constraint world
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let mut fs_rng_gadget = FSGadget::new(cs.ns(|| "new"));
+
+        let absorbed_rand_field_elems_gadgets = absorbed_rand_field_elems
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| FpGadget::alloc(cs.ns(|| format!("alloc_native_field_var_{}", i)), || Ok(elem)).unwrap())
+            .collect::<Vec<_>>();
+        fs_rng_gadget
+            .absorb_native_field_elements(cs.ns(|| "absorb_native_fe"), &absorbed_rand_field_elems_gadgets)
+            .unwrap();
+        let snapshot_gadget = fs_rng_gadget.snapshot();
+
+        let absorbed_rand_byte_elems_gadgets = vec![UInt8::constant(1u8); SIZE_ABSORBED_BYTE_ELEM];
+        fs_rng_gadget
+            .absorb_bytes(cs.ns(|| "absorb_bytes"), &absorbed_rand_byte_elems_gadgets)
+            .unwrap();
+
+        // Ensure the restored gadget squeezes the same elements as the plaintext RNG.
+        fs_rng_gadget.restore(&snapshot_gadget);
+        let squeezed_gadgets = fs_rng_gadget
+            .squeeze_native_field_elements(cs.ns(|| "squeeze_native_fe"), NUM_SQUEEZED_FIELD_ELEMS)
+            .unwrap();
+        for (left, right) in expected.iter().zip(squeezed_gadgets.iter()) {
+            assert_eq!(*left, right.get_value().unwrap());
+        }
+
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn test_compress_gadgets_weight_optimized() {
         let mut rng = ChaChaRng::seed_from_u64(123456789u64);
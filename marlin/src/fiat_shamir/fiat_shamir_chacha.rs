@@ -78,6 +78,8 @@ impl<TargetField: PrimeField, BaseField: PrimeField, D: Digest + Clone + Debug>
     for FiatShamirChaChaRng<TargetField, BaseField, D>
 {
     type Parameters = ();
+    /// The ChaCha RNG, including its position in the stream, and the seed.
+    type Snapshot = (Option<ChaChaRng>, Option<Vec<u8>>);
 
     fn sample_params() -> Self::Parameters {}
 
@@ -93,6 +95,16 @@ impl<TargetField: PrimeField, BaseField: PrimeField, D: Digest + Clone + Debug>
         Self::new()
     }
 
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.r.clone(), self.seed.clone())
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        let (r, seed) = snapshot.clone();
+        self.r = r;
+        self.seed = seed;
+    }
+
     fn absorb_nonnative_field_elements(&mut self, elems: &[TargetField], _: OptimizationType) {
         let mut bytes = Vec::new();
         for elem in elems {
@@ -223,4 +235,33 @@ mod tests {
         let _squeezed_short_fields_elems =
             fs_rng.squeeze_128_bits_nonnative_field_elements(NUM_SQUEEZED_SHORT_FIELD_ELEMS);
     }
+
+    #[test]
+    fn test_chacharng_snapshot_restore() {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+        let absorbed_rand_field_elems: Vec<_> =
+            (0..NUM_ABSORBED_RAND_FIELD_ELEMS).map(|_| Fr::rand(&mut rng)).collect();
+
+        let mut fs_rng = FiatShamirChaChaRng::<Fr, Fq, Blake2s>::new();
+        fs_rng.absorb_nonnative_field_elements(&absorbed_rand_field_elems, OptimizationType::Weight);
+        let snapshot = fs_rng.snapshot();
+
+        let expected = fs_rng
+            .squeeze_nonnative_field_elements(NUM_SQUEEZED_FIELD_ELEMS, OptimizationType::Weight)
+            .unwrap();
+
+        // Advance the transcript, then ensure restoring the snapshot replays the same challenges.
+        fs_rng.absorb_bytes(&[1u8; SIZE_ABSORBED_BYTE_ELEM]);
+        let _ = fs_rng.squeeze_128_bits_nonnative_field_elements(NUM_SQUEEZED_SHORT_FIELD_ELEMS);
+
+        fs_rng.restore(&snapshot);
+        let candidate = fs_rng
+            .squeeze_nonnative_field_elements(NUM_SQUEEZED_FIELD_ELEMS, OptimizationType::Weight)
+            .unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure restoring an uninitialized snapshot uninitializes the RNG.
+        fs_rng.restore(&FiatShamirChaChaRng::<Fr, Fq, Blake2s>::new().snapshot());
+        assert!(fs_rng.squeeze_native_field_elements(1).is_err());
+    }
 }
@@ -28,6 +28,9 @@ use rand_core::RngCore;
 pub trait FiatShamirRng<TargetField: PrimeField, BaseField: PrimeField>: Clone + Debug + RngCore {
     type Parameters;
 
+    /// The transcript state of the RNG, from which the RNG can be restored.
+    type Snapshot: Clone + Debug;
+
     /// Samples parameters.
     fn sample_params() -> Self::Parameters;
 
@@ -37,6 +40,13 @@ pub trait FiatShamirRng<TargetField: PrimeField, BaseField: PrimeField>: Clone +
     /// Initializes an RNG with the provided parameters.
     fn with_parameters(params: &Self::Parameters) -> Self;
 
+    /// Returns a snapshot of the transcript state, such as after the first rounds of a proof.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restores the transcript state from the given snapshot.
+    /// Everything absorbed or squeezed after the snapshot was taken is discarded.
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
     /// Takes in field elements.
     fn absorb_nonnative_field_elements(&mut self, elements: &[TargetField], ty: OptimizationType);
 
@@ -28,12 +28,22 @@ use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 pub trait FiatShamirRngVar<TargetField: PrimeField, BaseField: PrimeField, PFS: FiatShamirRng<TargetField, BaseField>>:
     Clone
 {
+    /// The transcript state of the RNG, from which the RNG can be restored.
+    type Snapshot: Clone;
+
     /// Create a new RNG.
     fn new<CS: ConstraintSystem<BaseField>>(cs: CS) -> Self;
 
     /// Instantiate from a plaintext fs_rng.
     fn constant<CS: ConstraintSystem<BaseField>>(cs: CS, pfs: &PFS) -> Self;
 
+    /// Returns a snapshot of the transcript state.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restores the transcript state from the given snapshot.
+    /// Everything absorbed or squeezed after the snapshot was taken is discarded.
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
     /// Take in field elements.
     fn absorb_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
         &mut self,
@@ -23,6 +23,14 @@
 //! is the same as the number of constraints (i.e., where the constraint
 //! matrices are square). Furthermore, Marlin only supports instances where the
 //! public inputs are of size one less than a power of 2 (i.e., 2^n - 1).
+//!
+//! # Features
+//!
+//! The AHP verifier, Marlin verification, and the verification gadgets are compiled
+//! only with the `verifier` feature. The `prover` feature additionally compiles the
+//! indexer, the AHP prover, the prover backends, and the Marlin setup and proving
+//! algorithms; it implies `verifier` and is enabled by default. A verify-only build
+//! may depend on this crate with `default-features = false, features = ["std", "verifier"]`.
 #![forbid(unsafe_code)]
 #![allow(clippy::module_inception)]
 #![allow(clippy::type_complexity)]
@@ -30,6 +38,9 @@
 #[macro_use]
 extern crate snarkvm_profiler;
 
+#[macro_use]
+extern crate tracing;
+
 #[cfg(not(feature = "std"))]
 #[macro_use]
 extern crate alloc;
@@ -75,6 +86,7 @@ pub mod ahp;
 pub use ahp::*;
 
 /// Implements the Marlin verification gadget.
+#[cfg(feature = "verifier")]
 pub mod constraints;
 
 /// Implements the base Marlin zkSNARK proof system.
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Vec;
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::{Error as PCError, LabeledCommitment, LabeledPolynomial, PolynomialCommitment};
+
+use core::sync::atomic::AtomicBool;
+use rand_core::RngCore;
+
+///
+/// A backend for the polynomial commitments of the Marlin prover.
+///
+/// The commitments of the three prover rounds are the bulk of the MSMs of a Marlin proof.
+/// An integrator can implement this trait to offload them, for example to a GPU or to a remote
+/// proving service over IPC, and pass the backend to `MarlinSNARK::prove_with_backend`.
+///
+pub trait ProverBackend<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>>: Send + Sync {
+    /// Returns the name of the backend.
+    fn name(&self) -> &str;
+
+    /// Commits to the given polynomials of a prover round, as `PolynomialCommitment::commit_with_terminator` does.
+    #[allow(clippy::type_complexity)]
+    fn commit(
+        &self,
+        committer_key: &PC::CommitterKey,
+        polynomials: &[&LabeledPolynomial<F>],
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<PC::Commitment>>, Vec<PC::Randomness>), PCError> {
+        PC::commit_with_terminator(committer_key, polynomials.iter().copied(), terminator, rng)
+    }
+}
+
+/// The default backend, which computes the commitments on the CPU of the prover.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CpuBackend;
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ProverBackend<F, CF, PC> for CpuBackend {
+    fn name(&self) -> &str {
+        "cpu"
+    }
+}
@@ -56,10 +56,13 @@ impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinM
     }
 }
 
+/// The version 1 encoding of a verifying key is the size of the constraint domain and the size of
+/// the non-zero domain, each as 64 little-endian bits, followed by the encodings of the circuit commitments.
+/// The verifier key of the polynomial commitment scheme is not encoded.
 impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> ToMinimalBits
     for CircuitVerifyingKey<F, CF, PC, MM>
 {
-    fn to_minimal_bits(&self) -> Vec<bool> {
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
         let domain_h = EvaluationDomain::<F>::new(self.circuit_info.num_constraints)
             .ok_or(SynthesisError::PolynomialDegreeTooLarge)
             .unwrap();
@@ -84,7 +87,7 @@ impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinM
             .flat_map(|&byte| (0..8).map(move |i| (byte >> i) & 1u8 == 1u8))
             .collect::<Vec<bool>>();
 
-        let circuit_commitments_bits = self.circuit_commitments.to_minimal_bits();
+        let circuit_commitments_bits = self.circuit_commitments.to_minimal_bits_v1();
 
         [domain_h_size_bits, domain_k_size_bits, circuit_commitments_bits].concat()
     }
@@ -0,0 +1,327 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encodes Marlin proofs, verifying keys, and public inputs in a layout that
+//! is consumable by an EVM verifier contract.
+//!
+//! The layout follows the conventions of the BLS12 precompiles in EIP-2537:
+//! - a base field element is written big-endian, left-padded to 64 bytes,
+//! - a scalar field element is written big-endian, left-padded to 32 bytes,
+//! - a G1 point is written as `x || y` (128 bytes), and the point at infinity is all zeros,
+//! - a G2 point is written as `x.c0 || x.c1 || y.c0 || y.c1` (256 bytes),
+//! - a length or a degree is written as a 32-byte big-endian word.
+//!
+//! # Note
+//!
+//! EIP-2537 only provides precompiles for BLS12-381, so proofs over `Bls12_381` may be checked
+//! directly. There are no precompiles for BLS12-377 (EIP-2539 is only a proposal), so proofs
+//! over `Bls12_377` may be encoded, but a contract must implement the curve arithmetic itself.
+//! The base field of BW6-761 does not fit in 64 bytes, and is rejected by this encoder.
+
+use crate::{
+    marlin::{CircuitVerifyingKey, MarlinMode, Proof},
+    IoResult,
+    Vec,
+};
+use snarkvm_curves::{AffineCurve, PairingEngine};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_polycommit::sonic_pc::SonicKZG10;
+use snarkvm_utilities::{error, ToBytes};
+
+/// The size in bytes of an EVM word.
+pub const EVM_WORD_SIZE: usize = 32;
+/// The size in bytes of an encoded base field element.
+pub const EVM_BASE_FIELD_SIZE: usize = 2 * EVM_WORD_SIZE;
+/// The size in bytes of an encoded G1 point.
+pub const EVM_G1_SIZE: usize = 2 * EVM_BASE_FIELD_SIZE;
+/// The size in bytes of an encoded G2 point.
+pub const EVM_G2_SIZE: usize = 4 * EVM_BASE_FIELD_SIZE;
+
+impl<E: PairingEngine> Proof<E::Fr, E::Fq, SonicKZG10<E>> {
+    /// Returns the EVM encoding of the proof.
+    ///
+    /// The layout is the commitments of each round (each prefixed by its length), the AHP evaluations,
+    /// the prover messages (each prefixed by its length), and the evaluation proofs of the polynomial
+    /// commitment, where each proof is `w || is_hiding || random_v`.
+    pub fn to_evm_bytes(&self) -> IoResult<Vec<u8>> {
+        let mut output = Vec::new();
+
+        write_length(&mut output, self.commitments.len());
+        for round in &self.commitments {
+            write_length(&mut output, round.len());
+            for commitment in round {
+                write_g1::<E>(&mut output, &commitment.0)?;
+            }
+        }
+
+        write_scalars(&mut output, &self.evaluations)?;
+
+        write_length(&mut output, self.prover_messages.len());
+        for message in &self.prover_messages {
+            write_scalars(&mut output, &message.field_elements)?;
+        }
+
+        write_length(&mut output, self.pc_proof.proof.len());
+        for proof in &self.pc_proof.proof {
+            write_g1::<E>(&mut output, &proof.w)?;
+            write_length(&mut output, proof.random_v.is_some() as usize);
+            write_scalar(&mut output, &proof.random_v.unwrap_or_else(E::Fr::zero))?;
+        }
+        write_scalars(&mut output, self.pc_proof.evaluations.as_deref().unwrap_or(&[]))?;
+
+        Ok(output)
+    }
+}
+
+impl<E: PairingEngine, MM: MarlinMode> CircuitVerifyingKey<E::Fr, E::Fq, SonicKZG10<E>, MM> {
+    /// Returns the EVM encoding of the verifying key.
+    ///
+    /// The layout is the circuit sizes, the circuit commitments (prefixed by their length),
+    /// the KZG10 verifier key `g || gamma_g || h || beta_h`, the supported and maximum degrees,
+    /// and the degree bounds (prefixed by their length), where each bound is `degree || neg_power_of_h`.
+    pub fn to_evm_bytes(&self) -> IoResult<Vec<u8>> {
+        let mut output = Vec::new();
+
+        write_length(&mut output, self.circuit_info.num_variables);
+        write_length(&mut output, self.circuit_info.num_constraints);
+        write_length(&mut output, self.circuit_info.num_non_zero);
+
+        write_length(&mut output, self.circuit_commitments.len());
+        for commitment in &self.circuit_commitments {
+            write_g1::<E>(&mut output, &commitment.0)?;
+        }
+
+        let vk = &self.verifier_key.vk;
+        write_g1::<E>(&mut output, &vk.g)?;
+        write_g1::<E>(&mut output, &vk.gamma_g)?;
+        write_g2::<E>(&mut output, &vk.h)?;
+        write_g2::<E>(&mut output, &vk.beta_h)?;
+
+        write_length(&mut output, self.verifier_key.supported_degree);
+        write_length(&mut output, self.verifier_key.max_degree);
+
+        let degree_bounds = self
+            .verifier_key
+            .degree_bounds_and_neg_powers_of_h
+            .as_deref()
+            .unwrap_or(&[]);
+        write_length(&mut output, degree_bounds.len());
+        for (degree_bound, neg_power_of_h) in degree_bounds {
+            write_length(&mut output, *degree_bound);
+            write_g2::<E>(&mut output, neg_power_of_h)?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// Returns the EVM encoding of the given public inputs, prefixed by their length.
+pub fn public_inputs_to_evm_bytes<F: PrimeField>(public_inputs: &[F]) -> IoResult<Vec<u8>> {
+    let mut output = Vec::new();
+    write_scalars(&mut output, public_inputs)?;
+    Ok(output)
+}
+
+/// Writes the given length as a 32-byte big-endian word.
+fn write_length(output: &mut Vec<u8>, length: usize) {
+    output.extend_from_slice(&[0u8; EVM_WORD_SIZE - 8]);
+    output.extend_from_slice(&(length as u64).to_be_bytes());
+}
+
+/// Writes the given big-endian bytes, left-padded to the given size.
+fn write_padded(output: &mut Vec<u8>, bytes_be: &[u8], size: usize) -> IoResult<()> {
+    // Strip the leading zeros, which may exceed the padded size for the limb representation.
+    let first_nonzero = bytes_be.iter().position(|byte| *byte != 0).unwrap_or(bytes_be.len());
+    let bytes_be = &bytes_be[first_nonzero..];
+    if bytes_be.len() > size {
+        return Err(error("field element does not fit in the EVM encoding"));
+    }
+
+    output.extend(core::iter::repeat(0u8).take(size - bytes_be.len()));
+    output.extend_from_slice(bytes_be);
+    Ok(())
+}
+
+/// Returns the big-endian bytes of the given prime field element.
+fn to_bytes_be<F: PrimeField>(element: &F) -> Vec<u8> {
+    element
+        .to_repr()
+        .as_ref()
+        .iter()
+        .rev()
+        .flat_map(|limb| limb.to_be_bytes().to_vec())
+        .collect()
+}
+
+/// Writes the given scalar field element in a 32-byte word.
+fn write_scalar<F: PrimeField>(output: &mut Vec<u8>, scalar: &F) -> IoResult<()> {
+    write_padded(output, &to_bytes_be(scalar), EVM_WORD_SIZE)
+}
+
+/// Writes the given scalar field elements, prefixed by their length.
+fn write_scalars<F: PrimeField>(output: &mut Vec<u8>, scalars: &[F]) -> IoResult<()> {
+    write_length(output, scalars.len());
+    for scalar in scalars {
+        write_scalar(output, scalar)?;
+    }
+    Ok(())
+}
+
+/// Writes the given G1 point as `x || y`, or as zeros if it is the point at infinity.
+fn write_g1<E: PairingEngine>(output: &mut Vec<u8>, point: &E::G1Affine) -> IoResult<()> {
+    if point.is_zero() {
+        output.extend_from_slice(&[0u8; EVM_G1_SIZE]);
+        return Ok(());
+    }
+    write_padded(output, &to_bytes_be(&point.to_x_coordinate()), EVM_BASE_FIELD_SIZE)?;
+    write_padded(output, &to_bytes_be(&point.to_y_coordinate()), EVM_BASE_FIELD_SIZE)
+}
+
+/// Writes the given G2 point as `x.c0 || x.c1 || y.c0 || y.c1`, or as zeros if it is the point at infinity.
+fn write_g2<E: PairingEngine>(output: &mut Vec<u8>, point: &E::G2Affine) -> IoResult<()> {
+    if point.is_zero() {
+        output.extend_from_slice(&[0u8; EVM_G2_SIZE]);
+        return Ok(());
+    }
+
+    // The extension field is serialized as its little-endian base field coefficients.
+    let base_field_size = E::Fq::zero().to_bytes_le()?.len();
+    for coordinate in [point.to_x_coordinate(), point.to_y_coordinate()].iter() {
+        let coordinate_bytes = coordinate.to_bytes_le()?;
+        if coordinate_bytes.len() != 2 * base_field_size {
+            return Err(error("G2 coordinate is not a quadratic extension of the base field"));
+        }
+        for coefficient in coordinate_bytes.chunks(base_field_size) {
+            let coefficient_be: Vec<u8> = coefficient.iter().rev().copied().collect();
+            write_padded(output, &coefficient_be, EVM_BASE_FIELD_SIZE)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fiat_shamir::FiatShamirChaChaRng,
+        marlin::{tests::Circuit, MarlinSNARK, MarlinTestnet1Mode},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr, G1Affine};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    use blake2::Blake2s;
+    use core::ops::MulAssign;
+
+    type MarlinInst =
+        MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+
+    /// The EVM encoding of the BLS12-377 G1 generator.
+    const BLS12_377_G1_GENERATOR: &str = concat!(
+        "000000000000000000000000000000000094a2b6015983b3a15ca8611c20543d",
+        "4c522d7aa91157f7698d3d8b829c73431c32d6aa1d3824180f1ff930106fdb66",
+        "000000000000000000000000000000000006285f49795e690d15f4c3519b90e8",
+        "4eb9c79b3e8594c8f62f1db67e503fcc9f690c4bf418104f7c297f574a174455",
+    );
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_g1_test_vectors() {
+        let mut output = Vec::new();
+        write_g1::<Bls12_377>(&mut output, &G1Affine::prime_subgroup_generator()).unwrap();
+        assert_eq!(from_hex(BLS12_377_G1_GENERATOR), output);
+
+        let mut output = Vec::new();
+        write_g1::<Bls12_377>(&mut output, &G1Affine::zero()).unwrap();
+        assert_eq!(vec![0u8; EVM_G1_SIZE], output);
+    }
+
+    #[test]
+    fn test_public_inputs_test_vectors() {
+        let expected = from_hex(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "00000000000000000000000000000000000000000000000000000000000000ff",
+        ));
+        let output = public_inputs_to_evm_bytes(&[Fr::from(1u64), Fr::from(255u64)]).unwrap();
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_proof_and_verifying_key_encoding() {
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+
+        // Ensure the proof encoding has the expected size.
+        let num_commitments: usize = proof.commitments.iter().map(|round| round.len()).sum();
+        let num_field_elements: usize = proof.prover_messages.iter().map(|m| m.field_elements.len()).sum();
+        let num_pc_evaluations = proof
+            .pc_proof
+            .evaluations
+            .as_ref()
+            .map_or(0, |evaluations| evaluations.len());
+        let expected_size = EVM_WORD_SIZE * (1 + proof.commitments.len())
+            + EVM_G1_SIZE * num_commitments
+            + EVM_WORD_SIZE * (1 + proof.evaluations.len())
+            + EVM_WORD_SIZE * (1 + proof.prover_messages.len() + num_field_elements)
+            + EVM_WORD_SIZE
+            + (EVM_G1_SIZE + 2 * EVM_WORD_SIZE) * proof.pc_proof.proof.len()
+            + EVM_WORD_SIZE * (1 + num_pc_evaluations);
+        assert_eq!(expected_size, proof.to_evm_bytes().unwrap().len());
+
+        // Ensure the verifying key encoding begins with the circuit sizes and the generator of G1.
+        let vk_bytes = index_vk.to_evm_bytes().unwrap();
+        let circuit_info = &index_vk.circuit_info;
+        let mut expected_prefix = Vec::new();
+        write_length(&mut expected_prefix, circuit_info.num_variables);
+        write_length(&mut expected_prefix, circuit_info.num_constraints);
+        write_length(&mut expected_prefix, circuit_info.num_non_zero);
+        write_length(&mut expected_prefix, index_vk.circuit_commitments.len());
+        assert_eq!(expected_prefix[..], vk_bytes[..4 * EVM_WORD_SIZE]);
+
+        let g_offset = 4 * EVM_WORD_SIZE + EVM_G1_SIZE * index_vk.circuit_commitments.len();
+        let mut expected_g = Vec::new();
+        write_g1::<Bls12_377>(&mut expected_g, &index_vk.verifier_key.vk.g).unwrap();
+        assert_eq!(expected_g[..], vk_bytes[g_offset..g_offset + EVM_G1_SIZE]);
+
+        // Ensure the public inputs are encoded as scalars.
+        assert_eq!(3 * EVM_WORD_SIZE, public_inputs_to_evm_bytes(&[c, d]).unwrap().len());
+    }
+}
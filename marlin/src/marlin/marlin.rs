@@ -15,37 +15,61 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
     fiat_shamir::traits::FiatShamirRng,
-    marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinError, MarlinMode, Proof, UniversalSRS},
-    prover::ProverConstraintSystem,
+    marlin::{MarlinError, MarlinMode, UniversalSRS},
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::PolynomialCommitment;
+
+#[cfg(feature = "verifier")]
+use crate::{
+    ahp::AHPForR1CS,
+    marlin::{CircuitVerifyingKey, PreparedCircuitVerifyingKey, Proof},
     String,
-    ToString,
     Vec,
 };
+#[cfg(feature = "verifier")]
 use snarkvm_algorithms::fft::EvaluationDomain;
-use snarkvm_fields::PrimeField;
+#[cfg(feature = "verifier")]
 use snarkvm_gadgets::nonnative::params::OptimizationType;
-use snarkvm_polycommit::{
-    Evaluations,
-    LabeledCommitment,
-    LabeledPolynomial,
-    PCProof,
-    PCRandomness,
-    PCUniversalParams,
-    PolynomialCommitment,
-};
-use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
+#[cfg(feature = "verifier")]
+use snarkvm_polycommit::{Evaluations, LabeledCommitment, PCProof};
+#[cfg(feature = "verifier")]
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
-#[cfg(not(feature = "std"))]
-use snarkvm_utilities::println;
-
-use crate::marlin::PreparedCircuitVerifyingKey;
+#[cfg(feature = "prover")]
+use crate::{
+    ahp::{
+        indexer::{Circuit, CircuitSpecialization},
+        AHPError,
+        AHPPolynomial,
+        EvaluationsProvider,
+    },
+    marlin::{
+        CircuitProvingKey,
+        CpuBackend,
+        ProverBackend,
+        ProverStage,
+        SpecializedCircuitProvingKey,
+        YieldGranularity,
+    },
+    BTreeMap,
+    ToString,
+};
+#[cfg(feature = "prover")]
+use snarkvm_polycommit::{LabeledPolynomial, PCRandomness, PCUniversalParams};
+#[cfg(feature = "prover")]
+use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
+#[cfg(feature = "prover")]
 use core::{
-    marker::PhantomData,
+    future::Future,
     sync::atomic::{AtomicBool, Ordering},
 };
+
+#[cfg(all(feature = "verifier", not(feature = "std")))]
+use snarkvm_utilities::println;
+
+use core::marker::PhantomData;
 use rand_core::RngCore;
 
 /// The Marlin proof system.
@@ -90,6 +114,7 @@ impl<
 
     /// Generate the index-specific (i.e., circuit-specific) prover and verifier
     /// keys. This is a trusted setup.
+    #[cfg(feature = "prover")]
     #[allow(clippy::type_complexity)]
     pub fn circuit_specific_setup<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
         c: &C,
@@ -123,13 +148,13 @@ impl<
 
             vanishing_polys = vec![
                 LabeledPolynomial::new(
-                    "vanishing_poly_h".to_string(),
+                    AHPPolynomial::VanishingPolyH.into(),
                     domain_h.vanishing_polynomial().into(),
                     None,
                     None,
                 ),
                 LabeledPolynomial::new(
-                    "vanishing_poly_k".to_string(),
+                    AHPPolynomial::VanishingPolyK.into(),
                     domain_k.vanishing_polynomial().into(),
                     None,
                     None,
@@ -167,6 +192,7 @@ impl<
 
     /// Generates the circuit proving and verifying keys.
     /// This is a deterministic algorithm that anyone can rerun.
+    #[cfg(feature = "prover")]
     #[allow(clippy::type_complexity)]
     pub fn circuit_setup<C: ConstraintSynthesizer<TargetField>>(
         universal_srs: &UniversalSRS<TargetField, BaseField, PC>,
@@ -182,6 +208,64 @@ impl<
 
         // TODO: Add check that c is in the correct mode.
         let index = AHPForR1CS::<_, MM>::index(circuit)?;
+        let keys = Self::index_setup(universal_srs, index)?;
+
+        end_timer!(index_time);
+
+        Ok(keys)
+    }
+
+    ///
+    /// Generates the proving and verifying keys of a specialized index, in which the given
+    /// private variables of the circuit are fixed. This is a deterministic algorithm that anyone can rerun.
+    ///
+    /// The fixed assignments map the index of a private variable, in the order of allocation, to its value.
+    /// The specialized index is no larger than the index of the circuit, so proofs of the circuit
+    /// for the fixed values are faster to compute. The prover is given the full circuit as before,
+    /// with [`prove_specialized`], and fails if its assignment does not match the fixed values.
+    ///
+    #[cfg(feature = "prover")]
+    #[allow(clippy::type_complexity)]
+    pub fn specialize<C: ConstraintSynthesizer<TargetField>>(
+        universal_srs: &UniversalSRS<TargetField, BaseField, PC>,
+        circuit: &C,
+        fixed_assignments: &BTreeMap<usize, TargetField>,
+    ) -> Result<
+        (
+            SpecializedCircuitProvingKey<TargetField, BaseField, PC, MM>,
+            CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        ),
+        MarlinError,
+    > {
+        let specialize_time = start_timer!(|| "Marlin::Specialize");
+
+        let (index, specialization) = AHPForR1CS::<_, MM>::specialize(circuit, fixed_assignments)?;
+        let (circuit_proving_key, circuit_verifying_key) = Self::index_setup(universal_srs, index)?;
+
+        end_timer!(specialize_time);
+
+        Ok((
+            SpecializedCircuitProvingKey {
+                circuit_proving_key,
+                specialization,
+            },
+            circuit_verifying_key,
+        ))
+    }
+
+    /// Generates the proving and verifying keys for the given index.
+    #[cfg(feature = "prover")]
+    #[allow(clippy::type_complexity)]
+    fn index_setup(
+        universal_srs: &UniversalSRS<TargetField, BaseField, PC>,
+        index: Circuit<TargetField, MM>,
+    ) -> Result<
+        (
+            CircuitProvingKey<TargetField, BaseField, PC, MM>,
+            CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
+        ),
+        MarlinError,
+    > {
         if universal_srs.max_degree() < index.max_degree() {
             return Err(MarlinError::IndexTooLarge(
                 universal_srs.max_degree(),
@@ -209,13 +293,13 @@ impl<
 
             vanishing_polynomials = vec![
                 LabeledPolynomial::new(
-                    "vanishing_poly_h".to_string(),
+                    AHPPolynomial::VanishingPolyH.into(),
                     domain_h.vanishing_polynomial().into(),
                     None,
                     None,
                 ),
                 LabeledPolynomial::new(
-                    "vanishing_poly_k".to_string(),
+                    AHPPolynomial::VanishingPolyK.into(),
                     domain_k.vanishing_polynomial().into(),
                     None,
                     None,
@@ -246,12 +330,11 @@ impl<
             committer_key,
         };
 
-        end_timer!(index_time);
-
         Ok((circuit_proving_key, circuit_verifying_key))
     }
 
     /// Create a zkSNARK asserting that the constraint system is satisfied.
+    #[cfg(feature = "prover")]
     pub fn prove<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
         circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
         circuit: &C,
@@ -261,12 +344,114 @@ impl<
     }
 
     /// Same as [`prove`] with an added termination flag, [`terminator`].
+    #[cfg(feature = "prover")]
     pub fn prove_with_terminator<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
         circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
         circuit: &C,
         terminator: &AtomicBool,
         zk_rng: &mut R,
     ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        Self::prove_with_backend(circuit_proving_key, circuit, &CpuBackend, terminator, zk_rng)
+    }
+
+    /// Same as [`prove_with_terminator`], with the commitments of the prover rounds computed by the given backend.
+    #[cfg(feature = "prover")]
+    pub fn prove_with_backend<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuit: &C,
+        backend: &dyn ProverBackend<TargetField, BaseField, PC>,
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        crate::marlin::block_on(Self::prove_with_yields(
+            circuit_proving_key,
+            None,
+            circuit,
+            backend,
+            terminator,
+            zk_rng,
+            None,
+            |_| core::future::ready(()),
+        ))
+    }
+
+    ///
+    /// Same as [`prove_with_terminator`], as a future that yields to the executor at the given granularity.
+    ///
+    /// At each yield point, `yield_fn` is called with the stage of the prover, and its future is awaited,
+    /// which lets the caller report progress, or set the terminator to cancel the proof. The prover checks
+    /// the terminator after each yield. In a browser, the future of `yield_fn` should resolve in a later task
+    /// of the event loop, such as a `setTimeout` promise, so that the page is rendered in between.
+    ///
+    #[cfg(all(feature = "prover", any(target_arch = "wasm32", test)))]
+    pub async fn prove_async<C, R, Y, F>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuit: &C,
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+        granularity: YieldGranularity,
+        yield_fn: Y,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError>
+    where
+        C: ConstraintSynthesizer<TargetField>,
+        R: RngCore,
+        Y: FnMut(ProverStage) -> F,
+        F: Future<Output = ()>,
+    {
+        Self::prove_with_yields(
+            circuit_proving_key,
+            None,
+            circuit,
+            &CpuBackend,
+            terminator,
+            zk_rng,
+            Some(granularity),
+            yield_fn,
+        )
+        .await
+    }
+
+    /// Create a zkSNARK asserting that the constraint system is satisfied, with a specialized index of the circuit.
+    #[cfg(feature = "prover")]
+    pub fn prove_specialized<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        specialized_proving_key: &SpecializedCircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuit: &C,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        crate::marlin::block_on(Self::prove_with_yields(
+            &specialized_proving_key.circuit_proving_key,
+            Some(&specialized_proving_key.specialization),
+            circuit,
+            &CpuBackend,
+            &AtomicBool::new(false),
+            zk_rng,
+            None,
+            |_| core::future::ready(()),
+        ))
+    }
+
+    /// The Marlin prover, which awaits `yield_fn` at the yield points of the given granularity, if any.
+    #[cfg(feature = "prover")]
+    #[allow(clippy::too_many_arguments)]
+    async fn prove_with_yields<C, R, Y, F>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        specialization: Option<&CircuitSpecialization<TargetField>>,
+        circuit: &C,
+        backend: &dyn ProverBackend<TargetField, BaseField, PC>,
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+        granularity: Option<YieldGranularity>,
+        mut yield_fn: Y,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError>
+    where
+        C: ConstraintSynthesizer<TargetField>,
+        R: RngCore,
+        Y: FnMut(ProverStage) -> F,
+        F: Future<Output = ()>,
+    {
+        use crate::marlin::checkpoint;
+
+        let _span = debug_span!("Marlin::prove", backend = backend.name()).entered();
         let prover_time = start_timer!(|| "Marlin::Prover");
         // TODO: Add check that c is in the correct mode.
 
@@ -274,7 +459,11 @@ impl<
             return Err(MarlinError::Terminated);
         }
 
-        let prover_init_state = AHPForR1CS::<_, MM>::prover_init(&circuit_proving_key.circuit, circuit)?;
+        let prover_init_state = AHPForR1CS::<_, MM>::prover_init_with_specialization(
+            &circuit_proving_key.circuit,
+            specialization,
+            circuit,
+        )?;
         let public_input = prover_init_state.public_input();
         let padded_public_input = prover_init_state.padded_public_input();
 
@@ -295,6 +484,8 @@ impl<
             );
         }
 
+        checkpoint(granularity, ProverStage::Initialization, true, &mut yield_fn).await;
+
         // --------------------------------------------------------------------
         // First round
 
@@ -305,14 +496,16 @@ impl<
         let (prover_first_message, prover_first_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_first_round(prover_init_state, zk_rng)?;
 
+        checkpoint(granularity, ProverStage::FirstRound, false, &mut yield_fn).await;
+
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = PC::commit_with_terminator(
+        let (first_commitments, first_commitment_randomnesses) = backend.commit(
             &circuit_proving_key.committer_key,
-            prover_first_oracles.iter(),
+            &prover_first_oracles.iter().collect::<Vec<_>>(),
             terminator,
             Some(zk_rng),
         )?;
@@ -327,6 +520,8 @@ impl<
             fs_rng.absorb_bytes(&to_bytes_le![first_commitments, prover_first_message].unwrap());
         }
 
+        checkpoint(granularity, ProverStage::FirstRound, true, &mut yield_fn).await;
+
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
@@ -347,10 +542,16 @@ impl<
         let (prover_second_message, prover_second_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
 
+        checkpoint(granularity, ProverStage::SecondRound, false, &mut yield_fn).await;
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = PC::commit_with_terminator(
+        let (second_commitments, second_commitment_randomnesses) = backend.commit(
             &circuit_proving_key.committer_key,
-            prover_second_oracles.iter(),
+            &prover_second_oracles.iter().collect::<Vec<_>>(),
             terminator,
             Some(zk_rng),
         )?;
@@ -365,6 +566,8 @@ impl<
             fs_rng.absorb_bytes(&to_bytes_le![second_commitments, prover_second_message].unwrap());
         }
 
+        checkpoint(granularity, ProverStage::SecondRound, true, &mut yield_fn).await;
+
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
@@ -383,10 +586,16 @@ impl<
         let (prover_third_message, prover_third_oracles) =
             AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
 
+        checkpoint(granularity, ProverStage::ThirdRound, false, &mut yield_fn).await;
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(MarlinError::Terminated);
+        }
+
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = PC::commit_with_terminator(
+        let (third_commitments, third_commitment_randomnesses) = backend.commit(
             &circuit_proving_key.committer_key,
-            prover_third_oracles.iter(),
+            &prover_third_oracles.iter().collect::<Vec<_>>(),
             terminator,
             Some(zk_rng),
         )?;
@@ -404,6 +613,8 @@ impl<
         let verifier_state = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut fs_rng)?;
         // --------------------------------------------------------------------
 
+        checkpoint(granularity, ProverStage::ThirdRound, true, &mut yield_fn).await;
+
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
@@ -416,13 +627,13 @@ impl<
 
             vec![
                 LabeledPolynomial::new(
-                    "vanishing_poly_h".to_string(),
+                    AHPPolynomial::VanishingPolyH.into(),
                     domain_h.vanishing_polynomial().into(),
                     None,
                     None,
                 ),
                 LabeledPolynomial::new(
-                    "vanishing_poly_k".to_string(),
+                    AHPPolynomial::VanishingPolyK.into(),
                     domain_k.vanishing_polynomial().into(),
                     None,
                     None,
@@ -467,7 +678,7 @@ impl<
             .iter()
             .cloned()
             .zip(indexer_polynomials)
-            .map(|(c, l)| LabeledCommitment::new(l.to_string(), c, None))
+            .map(|(c, l)| LabeledCommitment::new(l.into(), c, None))
             .chain(first_commitments.into_iter())
             .chain(second_commitments.into_iter())
             .chain(third_commitments.into_iter())
@@ -514,6 +725,8 @@ impl<
         let evaluations = evaluations_unsorted.iter().map(|x| x.1).collect::<Vec<TargetField>>();
         end_timer!(eval_time);
 
+        checkpoint(granularity, ProverStage::Evaluation, true, &mut yield_fn).await;
+
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
@@ -573,6 +786,7 @@ impl<
 
     /// Verify that a proof for the constraint system defined by `C` asserts that
     /// all constraints are satisfied.
+    #[cfg(feature = "verifier")]
     pub fn verify(
         circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
         public_input: &[TargetField],
@@ -583,12 +797,14 @@ impl<
 
     /// Verify that a proof for the constraint system defined by `C` asserts that
     /// all constraints are satisfied.
+    #[cfg(feature = "verifier")]
     pub fn verify_with_fs_parameters(
         circuit_verifying_key: &CircuitVerifyingKey<TargetField, BaseField, PC, MM>,
         fs_parameters: &FS::Parameters,
         public_input: &[TargetField],
         proof: &Proof<TargetField, BaseField, PC>,
     ) -> Result<bool, MarlinError> {
+        let _span = debug_span!("Marlin::verify").entered();
         let verifier_time = start_timer!(|| "Marlin::Verify");
         let first_commitments = &proof.commitments[0];
         let second_commitments = &proof.commitments[1];
@@ -621,7 +837,7 @@ impl<
             assert!(new_input.first().unwrap().is_one());
             new_input
         };
-        let public_input = ProverConstraintSystem::unformat_public_input(&padded_public_input);
+        let public_input = AHPForR1CS::<TargetField, MM>::unformat_public_input(&padded_public_input);
 
         if cfg!(debug_assertions) {
             println!("Number of padded public variables: {}", padded_public_input.len());
@@ -788,6 +1004,7 @@ impl<
 
     /// Verify that a proof for the constraint system defined by `C` asserts that
     /// all constraints are satisfied using the prepared verifying key.
+    #[cfg(feature = "verifier")]
     pub fn prepared_verify(
         prepared_vk: &PreparedCircuitVerifyingKey<TargetField, BaseField, PC, MM>,
         public_input: &[TargetField],
@@ -798,6 +1015,7 @@ impl<
 
     /// Verify that a proof for the constraint system defined by `C` asserts that
     /// all constraints are satisfied using the prepared verifying key.
+    #[cfg(feature = "verifier")]
     pub fn prepared_verify_with_fs_parameters(
         prepared_vk: &PreparedCircuitVerifyingKey<TargetField, BaseField, PC, MM>,
         fs_parameters: &FS::Parameters,
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+/// The backend for the commitments of the Marlin prover.
+#[cfg(feature = "prover")]
+mod backend;
+#[cfg(feature = "prover")]
+pub use backend::*;
+
 /// The Marlin circuit proving key.
 mod circuit_proving_key;
 pub use circuit_proving_key::*;
@@ -22,6 +28,10 @@ pub use circuit_proving_key::*;
 mod circuit_verifying_key;
 pub use circuit_verifying_key::*;
 
+/// An EVM-friendly encoding of Marlin proofs and verifying keys.
+mod evm;
+pub use evm::*;
+
 /// Errors.
 mod errors;
 pub use errors::*;
@@ -42,9 +52,19 @@ pub use prepared_circuit_verifying_key::*;
 mod proof;
 pub use proof::*;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 pub mod tests;
 
+/// The Marlin proving key for a specialized index of a circuit.
+mod specialized_circuit_proving_key;
+pub use specialized_circuit_proving_key::*;
+
 /// The Marlin universal SRS.
 mod universal_srs;
 pub use universal_srs::*;
+
+/// The yield points of the asynchronous Marlin prover.
+#[cfg(feature = "prover")]
+mod yielding;
+#[cfg(feature = "prover")]
+pub use yielding::*;
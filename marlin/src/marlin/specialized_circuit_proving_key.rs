@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ahp::indexer::CircuitSpecialization, marlin::CircuitProvingKey};
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::PolynomialCommitment;
+use snarkvm_utilities::{serialize::*, FromBytes, ToBytes};
+
+use crate::{IoResult, Read, Write};
+use derivative::Derivative;
+
+use super::MarlinMode;
+
+/// Proving key for a specialized index of a circuit, in which some private variables are fixed.
+/// The proving key of the specialized index is serialized as any other, followed by its specialization.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+#[derive(Debug)]
+pub struct SpecializedCircuitProvingKey<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> {
+    /// The proving key of the specialized index.
+    pub circuit_proving_key: CircuitProvingKey<F, CF, PC, MM>,
+    /// The specialization, which maps an assignment of the circuit to an assignment of the specialized index.
+    pub specialization: CircuitSpecialization<F>,
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> ToBytes
+    for SpecializedCircuitProvingKey<F, CF, PC, MM>
+{
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.circuit_proving_key.write_le(&mut writer)?;
+        CanonicalSerialize::serialize(&self.specialization, &mut writer)?;
+        Ok(())
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> FromBytes
+    for SpecializedCircuitProvingKey<F, CF, PC, MM>
+{
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let circuit_proving_key = FromBytes::read_le(&mut reader)?;
+        let specialization = CanonicalDeserialize::deserialize(&mut reader)?;
+
+        Ok(Self {
+            circuit_proving_key,
+            specialization,
+        })
+    }
+}
@@ -75,9 +75,18 @@ mod marlin {
         fiat_shamir::FiatShamirChaChaRng,
         marlin::{MarlinPoswMode, MarlinSNARK, MarlinTestnet1Mode},
     };
-    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_algorithms::fft::EvaluationDomain;
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fq, Fr},
+        bls12_381::{Bls12_381, Fq as Bls12_381Fq, Fr as Bls12_381Fr},
+    };
     use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        FromBytes,
+        ToBytes,
+        ToMinimalBits,
+    };
 
     use blake2::Blake2s;
     use core::ops::MulAssign;
@@ -90,19 +99,31 @@ mod marlin {
 
     type MarlinSonicPoswInst = MarlinSNARK<Fr, Fq, MultiPCSonic, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinPoswMode>;
 
+    type MultiPCSonicBls12_381 = SonicKZG10<Bls12_381>;
+    type MarlinSonicBls12_381Inst = MarlinSNARK<
+        Bls12_381Fr,
+        Bls12_381Fq,
+        MultiPCSonicBls12_381,
+        FiatShamirChaChaRng<Bls12_381Fr, Bls12_381Fq, Blake2s>,
+        MarlinTestnet1Mode,
+    >;
+
     macro_rules! impl_marlin_test {
         ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt) => {
+            impl_marlin_test!($test_struct, $marlin_inst, $marlin_mode, Fr);
+        };
+        ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt, $fr: ident) => {
             struct $test_struct {}
             impl $test_struct {
                 pub(crate) fn test_circuit(num_constraints: usize, num_variables: usize) {
                     let rng = &mut test_rng();
 
-                    let max_degree = crate::ahp::AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let max_degree = crate::ahp::AHPForR1CS::<$fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
                     let universal_srs = $marlin_inst::universal_setup(max_degree, rng).unwrap();
 
                     for _ in 0..100 {
-                        let a = Fr::rand(rng);
-                        let b = Fr::rand(rng);
+                        let a = $fr::rand(rng);
+                        let b = $fr::rand(rng);
                         let mut c = a;
                         c.mul_assign(&b);
                         let mut d = c;
@@ -134,6 +155,12 @@ mod marlin {
     impl_marlin_test!(MarlinPCTest, MarlinInst, MarlinTestnet1Mode);
     impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinTestnet1Mode);
     impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinPoswMode);
+    impl_marlin_test!(
+        SonicPCBls12_381Test,
+        MarlinSonicBls12_381Inst,
+        MarlinTestnet1Mode,
+        Bls12_381Fr
+    );
 
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
@@ -143,6 +170,7 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
+        SonicPCBls12_381Test::test_circuit(num_constraints, num_variables);
     }
 
     #[test]
@@ -153,6 +181,7 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
+        SonicPCBls12_381Test::test_circuit(num_constraints, num_variables);
     }
 
     #[test]
@@ -163,6 +192,7 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
+        SonicPCBls12_381Test::test_circuit(num_constraints, num_variables);
     }
 
     #[test]
@@ -173,6 +203,7 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
+        SonicPCBls12_381Test::test_circuit(num_constraints, num_variables);
     }
 
     #[test]
@@ -183,6 +214,254 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
+        SonicPCBls12_381Test::test_circuit(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_and_verify_with_backend() {
+        use crate::marlin::{CpuBackend, ProverBackend};
+        use snarkvm_polycommit::{Error as PCError, LabeledCommitment, LabeledPolynomial, PolynomialCommitment};
+
+        use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        use rand_core::RngCore;
+
+        /// A backend that counts the polynomials it commits to.
+        #[derive(Default)]
+        struct CountingBackend {
+            num_polynomials: AtomicUsize,
+        }
+
+        impl ProverBackend<Fr, Fq, MultiPCSonic> for CountingBackend {
+            fn name(&self) -> &str {
+                "counting"
+            }
+
+            fn commit(
+                &self,
+                committer_key: &<MultiPCSonic as PolynomialCommitment<Fr, Fq>>::CommitterKey,
+                polynomials: &[&LabeledPolynomial<Fr>],
+                terminator: &AtomicBool,
+                rng: Option<&mut dyn RngCore>,
+            ) -> Result<
+                (
+                    Vec<LabeledCommitment<<MultiPCSonic as PolynomialCommitment<Fr, Fq>>::Commitment>>,
+                    Vec<<MultiPCSonic as PolynomialCommitment<Fr, Fq>>::Randomness>,
+                ),
+                PCError,
+            > {
+                self.num_polynomials.fetch_add(polynomials.len(), Ordering::SeqCst);
+                ProverBackend::<Fr, Fq, MultiPCSonic>::commit(&CpuBackend, committer_key, polynomials, terminator, rng)
+            }
+        }
+
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let backend = CountingBackend::default();
+        let proof =
+            MarlinSonicInst::prove_with_backend(&index_pk, &circuit, &backend, &AtomicBool::new(false), rng).unwrap();
+        assert!(MarlinSonicInst::verify(&index_vk, &[c, d], &proof).unwrap());
+
+        // Ensure every prover round committed through the backend.
+        let num_round_polynomials = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::PROVER_POLYNOMIALS_WITH_ZK.len();
+        assert_eq!(num_round_polynomials, backend.num_polynomials.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn prove_and_verify_async() {
+        use crate::marlin::{block_on, yield_now, MarlinError, ProverStage, YieldGranularity};
+
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure the prover yields at the end of each stage, and the proof verifies.
+        let mut stages = vec![];
+        let terminator = AtomicBool::new(false);
+        let proof = block_on(MarlinSonicInst::prove_async(
+            &index_pk,
+            &circuit,
+            &terminator,
+            rng,
+            YieldGranularity::Round,
+            |stage| {
+                stages.push(stage);
+                yield_now(stage)
+            },
+        ))
+        .unwrap();
+        assert!(MarlinSonicInst::verify(&index_vk, &[c, d], &proof).unwrap());
+        assert_eq!(
+            vec![
+                ProverStage::Initialization,
+                ProverStage::FirstRound,
+                ProverStage::SecondRound,
+                ProverStage::ThirdRound,
+                ProverStage::Evaluation
+            ],
+            stages
+        );
+
+        // Ensure the prover also yields within each prover round.
+        let mut num_yields = 0;
+        block_on(MarlinSonicInst::prove_async(
+            &index_pk,
+            &circuit,
+            &terminator,
+            rng,
+            YieldGranularity::Step,
+            |stage| {
+                num_yields += 1;
+                yield_now(stage)
+            },
+        ))
+        .unwrap();
+        assert_eq!(8, num_yields);
+
+        // Ensure the prover is terminated if the terminator is set while it yields.
+        let result = block_on(MarlinSonicInst::prove_async(
+            &index_pk,
+            &circuit,
+            &terminator,
+            rng,
+            YieldGranularity::Round,
+            |stage| {
+                if stage == ProverStage::FirstRound {
+                    terminator.store(true, Ordering::Relaxed);
+                }
+                yield_now(stage)
+            },
+        ));
+        assert!(matches!(result, Err(MarlinError::Terminated)));
+    }
+
+    #[test]
+    fn circuit_verifying_key_to_minimal_bits_v1() {
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree, rng).unwrap();
+
+        let circuit = Circuit {
+            a: Some(Fr::rand(rng)),
+            b: Some(Fr::rand(rng)),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (_, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure the encoding is the size of each domain as 64 little-endian bits, followed by the commitments.
+        let domain_size_bits = |size: usize| {
+            let size = EvaluationDomain::<Fr>::new(size).unwrap().size() as u64;
+            (0..64).map(move |i| (size >> i) & 1 == 1)
+        };
+        let expected_bits: Vec<bool> = domain_size_bits(index_vk.circuit_info.num_constraints)
+            .chain(domain_size_bits(index_vk.circuit_info.num_non_zero))
+            .chain(index_vk.iter().flat_map(|commitment| commitment.to_minimal_bits_v1()))
+            .collect();
+        assert_eq!(expected_bits, index_vk.to_minimal_bits_v1());
+        assert_eq!(expected_bits, index_vk.to_minimal_bits());
+    }
+
+    #[test]
+    fn prove_and_verify_with_specialized_index() {
+        use crate::{ahp::AHPError, marlin::MarlinError, BTreeMap};
+
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, _) = MarlinSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Fix the first private variable, which is `a`.
+        let mut fixed_assignments = BTreeMap::new();
+        fixed_assignments.insert(0, a);
+        let (specialized_pk, specialized_vk) =
+            MarlinSonicInst::specialize(&universal_srs, &circuit, &fixed_assignments).unwrap();
+
+        // Ensure the fixed and unconstrained variables are removed from the index.
+        let index_info = &index_pk.circuit.index_info;
+        let specialized_info = &specialized_pk.circuit_proving_key.circuit.index_info;
+        assert!(specialized_info.num_variables < index_info.num_variables);
+        assert!(specialized_info.num_constraints <= index_info.num_constraints);
+
+        // Ensure the specialized index proves the circuit for the fixed value of `a`.
+        let proof = MarlinSonicInst::prove_specialized(&specialized_pk, &circuit, rng).unwrap();
+        assert!(MarlinSonicInst::verify(&specialized_vk, &[c, d], &proof).unwrap());
+        assert!(!MarlinSonicInst::verify(&specialized_vk, &[a, a], &proof).unwrap());
+
+        // Ensure the specialized proving key round-trips, and is not accepted without its specialization.
+        let specialized_pk_bytes = specialized_pk.to_bytes_le().unwrap();
+        let candidate_pk = FromBytes::read_le(&specialized_pk_bytes[..]).unwrap();
+        let proof = MarlinSonicInst::prove_specialized(&candidate_pk, &circuit, rng).unwrap();
+        assert!(MarlinSonicInst::verify(&specialized_vk, &[c, d], &proof).unwrap());
+        assert!(MarlinSonicInst::prove(&specialized_pk.circuit_proving_key, &circuit, rng).is_err());
+
+        // Ensure the specialized index does not prove the circuit for another value of `a`.
+        let other_circuit = Circuit {
+            a: Some(b),
+            b: Some(a),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let result = MarlinSonicInst::prove_specialized(&specialized_pk, &other_circuit, rng);
+        assert!(matches!(
+            result,
+            Err(MarlinError::AHPError(AHPError::InstanceDoesNotMatchIndex))
+        ));
+
+        // Ensure a nonexistent variable cannot be fixed.
+        fixed_assignments.insert(index_info.num_variables, a);
+        assert!(MarlinSonicInst::specialize(&universal_srs, &circuit, &fixed_assignments).is_err());
     }
 }
 
@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, sync::Arc, task::Wake};
+#[cfg(feature = "std")]
+use std::{
+    sync::Arc,
+    task::Wake,
+    thread::{self, Thread},
+};
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+/// The stage of the Marlin prover at a yield point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProverStage {
+    /// The prover has synthesized the circuit and absorbed the public input.
+    Initialization,
+    /// The prover is in, or has finished, the first round.
+    FirstRound,
+    /// The prover is in, or has finished, the second round.
+    SecondRound,
+    /// The prover is in, or has finished, the third round.
+    ThirdRound,
+    /// The prover has evaluated the linear combinations, and has yet to open the commitments.
+    Evaluation,
+}
+
+/// The points at which the asynchronous Marlin prover yields to the executor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum YieldGranularity {
+    /// Yield once at the end of each stage of the prover.
+    Round,
+    /// Yield at the end of each stage, and between the computation and the commitment of each prover round.
+    Step,
+}
+
+impl YieldGranularity {
+    /// Returns `true` if the prover yields at a point of this granularity, where `is_round_end`
+    /// is `true` at the end of a stage, and `false` at a point within a stage.
+    pub fn yields_at(&self, is_round_end: bool) -> bool {
+        match self {
+            Self::Round => is_round_end,
+            Self::Step => true,
+        }
+    }
+}
+
+///
+/// A future that returns control to the executor once, before it completes.
+///
+/// This lets other tasks of the executor run, such as a task that sets the terminator of the prover.
+/// In a browser, a `wasm-bindgen-futures` executor resumes the prover in a microtask, before the page
+/// is rendered; to let the page render progress, yield on a timer (such as a `setTimeout` promise) instead.
+///
+#[derive(Debug, Default)]
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        match self.yielded {
+            true => Poll::Ready(()),
+            false => {
+                self.yielded = true;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Returns a future that returns control to the executor once, for any stage of the prover.
+pub fn yield_now(_stage: ProverStage) -> YieldNow {
+    YieldNow::default()
+}
+
+/// Calls and awaits `yield_fn` for the given stage, if the prover yields at this point with the given granularity.
+pub(crate) async fn checkpoint<Y: FnMut(ProverStage) -> F, F: Future<Output = ()>>(
+    granularity: Option<YieldGranularity>,
+    stage: ProverStage,
+    is_round_end: bool,
+    yield_fn: &mut Y,
+) {
+    if let Some(granularity) = granularity {
+        if granularity.yields_at(is_round_end) {
+            yield_fn(stage).await;
+        }
+    }
+}
+
+/// A waker that unparks the thread running `block_on`.
+#[cfg(feature = "std")]
+struct ThreadWaker(Thread);
+
+#[cfg(feature = "std")]
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+///
+/// Polls the given future on the current thread until it completes,
+/// parking the thread between polls until the future is woken.
+///
+/// This is used to run the asynchronous prover synchronously, without yields,
+/// in which case the future completes on its first poll.
+///
+#[cfg(feature = "std")]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            // A wake-up that arrives before the thread is parked is not lost,
+            // as it makes the next call to `park` return immediately.
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// A waker that does nothing, as there is no thread to park without `std`.
+#[cfg(not(feature = "std"))]
+struct NoopWaker;
+
+#[cfg(not(feature = "std"))]
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+///
+/// Polls the given future on the current thread until it completes.
+///
+/// Without `std`, the thread cannot be parked, so the future is polled again after a spin-loop hint.
+///
+#[cfg(not(feature = "std"))]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => core::hint::spin_loop(),
+        }
+    }
+}
@@ -78,9 +78,9 @@ pub fn universal_setup<N: Network>() -> Result<()> {
     const UNIVERSAL_SRS: &str = "universal.srs";
 
     let max_degree =
-        AHPForR1CS::<<N as Network>::InnerScalarField, MarlinTestnet1Mode>::max_degree(2000000, 4000000, 8000000)
+        AHPForR1CS::<N::InnerScalarField, MarlinTestnet1Mode>::max_degree(2000000, 4000000, 8000000)
             .unwrap();
-    let universal_srs = <<N as Network>::ProgramSNARK as SNARK>::universal_setup(&max_degree, &mut thread_rng())?;
+    let universal_srs = <N::ProgramSNARK as SNARK>::universal_setup(&max_degree, &mut thread_rng())?;
     let universal_srs = universal_srs.to_bytes_le()?;
 
     let universal_checksum = checksum(&universal_srs);
@@ -103,11 +103,11 @@ pub fn noop_setup<N: Network>() -> Result<()> {
     const NOOP_VERIFYING_KEY: &str = "noop.verifying";
 
     let (proving_key, verifying_key) = <N::ProgramSNARK as SNARK>::setup(
-        &SynthesizedCircuit::<N>::Noop(Default::default()),
+        &SynthesizedCircuit::<N>::Noop(ProgramPublicVariables::blank()),
         &mut *N::program_srs(&mut thread_rng()).borrow_mut(),
     )?;
 
-    let noop_function_id = hex::encode(<N as Network>::function_id(&verifying_key)?.to_bytes_le()?);
+    let noop_function_id = hex::encode(N::function_id(&verifying_key)?.to_bytes_le()?);
     let noop_proving_key = proving_key.to_bytes_le()?;
     let noop_verifying_key = verifying_key.to_bytes_le()?;
 
@@ -140,7 +140,7 @@ pub fn inner_setup<N: Network>() -> Result<()> {
 
     let inner_circuit_id = hex::encode(
         N::inner_circuit_id_crh()
-            .hash_bits(&inner_verifying_key.to_minimal_bits())?
+            .hash_bits(&inner_verifying_key.to_minimal_bits_v1())?
             .to_bytes_le()?,
     );
     let inner_proving_key = inner_proving_key.to_bytes_le()?;
@@ -204,6 +204,7 @@ pub fn outer_setup<N: Network>() -> Result<()> {
                 ProgramPublicVariables::blank(),
                 &NoopPrivateVariables::<N>::new_blank().unwrap(),
             )?,
+            payload_commitments: vec![Default::default(); N::NUM_PAYLOAD_COMMITMENTS],
         }),
         &mut SRS::CircuitSpecific(&mut thread_rng()),
     )?;
@@ -235,8 +236,8 @@ pub fn posw_setup<N: Network>() -> Result<()> {
 
     // TODO: decide the size of the universal setup
     let max_degree =
-        AHPForR1CS::<<N as Network>::InnerScalarField, MarlinTestnet1Mode>::max_degree(40000, 40000, 60000).unwrap();
-    let universal_srs = <<N as Network>::PoSWSNARK as SNARK>::universal_setup(&max_degree, &mut thread_rng())?;
+        AHPForR1CS::<N::InnerScalarField, MarlinTestnet1Mode>::max_degree(40000, 40000, 60000).unwrap();
+    let universal_srs = <N::PoSWSNARK as SNARK>::universal_setup(&max_degree, &mut thread_rng())?;
     let srs_bytes = universal_srs.to_bytes_le()?;
     println!("srs\n\tsize - {}", srs_bytes.len());
 
@@ -24,6 +24,9 @@ pub enum ParameterError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("Failed to read {} from bytes: {}", _0, _1)]
+    InvalidKey(String, String),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -32,6 +35,9 @@ pub enum ParameterError {
 
     #[error("Expected size of {}, found size of {}", _0, _1)]
     SizeMismatch(usize, usize),
+
+    #[error("Found {} trailing bytes after reading {}", _1, _0)]
+    TrailingBytes(String, usize),
 }
 
 #[cfg(not(feature = "wasm"))]
@@ -30,6 +30,9 @@ pub mod macros;
 pub mod errors;
 pub use errors::*;
 
+pub mod srs;
+pub use srs::*;
+
 pub mod testnet1;
 
 pub mod testnet2;
@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ParameterError;
+use snarkvm_algorithms::crh::sha256::sha256;
+
+use std::{convert::TryInto, io::Write};
+#[cfg(not(feature = "wasm"))]
+use std::path::Path;
+
+/// The magic bytes at the start of an indexed SRS.
+pub const INDEXED_SRS_MAGIC: [u8; 4] = *b"ASRS";
+/// The version of the indexed SRS format.
+pub const INDEXED_SRS_VERSION: u16 = 1;
+/// The number of powers of G in each section of an indexed SRS.
+pub const INDEXED_SRS_POWERS_PER_SECTION: usize = 1 << 16;
+
+/// The size of the header of an indexed SRS, which is followed by the section index.
+const HEADER_SIZE: usize = 4 + 2 + 4 + 8 + 4;
+/// The size of an entry of the section index, which is the offset, length, and checksum of the section.
+const SECTION_ENTRY_SIZE: usize = 8 + 8 + 32;
+
+/// A contiguous, checksummed range of bytes in an indexed SRS.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Section {
+    offset: usize,
+    length: usize,
+    checksum: [u8; 32],
+}
+
+///
+/// A universal SRS in an indexed format, whose sections are each checked against their checksum.
+///
+/// The universal SRS is serialized as the number of powers of G, followed by the powers of G,
+/// followed by the remaining parameters. The indexed format splits the powers of G into sections of
+/// `INDEXED_SRS_POWERS_PER_SECTION` powers, and the remaining parameters into a final section,
+/// and stores the offset, length, and SHA-256 checksum of each section in an index after the header.
+/// The sections are checked against their checksums when the universal SRS bytes are loaded.
+///
+/// The format is:
+/// `magic (4 bytes) || version (u16) || element size (u32) || number of powers (u64) || number of sections (u32)`
+/// `|| (offset (u64) || length (u64) || checksum (32 bytes)) for each section || sections`
+///
+pub struct IndexedSRS {
+    bytes: Vec<u8>,
+    num_powers: usize,
+    sections: Vec<Section>,
+}
+
+impl IndexedSRS {
+    ///
+    /// Writes the given universal SRS bytes in the indexed format,
+    /// where `element_size` is the size in bytes of a serialized power of G.
+    ///
+    pub fn write_indexed<W: Write>(srs_bytes: &[u8], element_size: usize, mut writer: W) -> Result<(), ParameterError> {
+        if element_size == 0 {
+            return Err(ParameterError::Message(
+                "The SRS element size must be nonzero".to_string(),
+            ));
+        }
+
+        let num_powers = read_u32(srs_bytes, 0)? as usize;
+        let powers_end = num_powers
+            .checked_mul(element_size)
+            .and_then(|length| length.checked_add(4))
+            .filter(|powers_end| *powers_end <= srs_bytes.len())
+            .ok_or_else(|| ParameterError::Message(format!("The SRS is missing some of its {} powers", num_powers)))?;
+
+        let sections = srs_bytes[4..powers_end]
+            .chunks(INDEXED_SRS_POWERS_PER_SECTION * element_size)
+            .chain(std::iter::once(&srs_bytes[powers_end..]))
+            .collect::<Vec<_>>();
+
+        // Write the header.
+        writer.write_all(&INDEXED_SRS_MAGIC)?;
+        writer.write_all(&INDEXED_SRS_VERSION.to_le_bytes())?;
+        writer.write_all(&(element_size as u32).to_le_bytes())?;
+        writer.write_all(&(num_powers as u64).to_le_bytes())?;
+        writer.write_all(&(sections.len() as u32).to_le_bytes())?;
+
+        // Write the section index.
+        let mut offset = HEADER_SIZE + sections.len() * SECTION_ENTRY_SIZE;
+        for section in &sections {
+            writer.write_all(&(offset as u64).to_le_bytes())?;
+            writer.write_all(&(section.len() as u64).to_le_bytes())?;
+            writer.write_all(&sha256(section))?;
+            offset += section.len();
+        }
+
+        // Write the sections.
+        for section in sections {
+            writer.write_all(section)?;
+        }
+        Ok(writer.flush()?)
+    }
+
+    /// Returns the indexed SRS in the file at the given path.
+    #[cfg(not(feature = "wasm"))]
+    pub fn open(path: &Path) -> Result<Self, ParameterError> {
+        Self::from_bytes(std::fs::read(path)?)
+    }
+
+    /// Returns the number of powers of G in the SRS.
+    pub fn num_powers(&self) -> usize {
+        self.num_powers
+    }
+
+    /// Returns the maximum degree supported by the SRS.
+    pub fn max_degree(&self) -> usize {
+        self.num_powers - 1
+    }
+
+    /// Returns the universal SRS bytes, if every section matches its checksum.
+    pub fn load_bytes(&self) -> Result<Vec<u8>, ParameterError> {
+        let length = self.sections.iter().map(|section| section.length).sum::<usize>();
+
+        let mut bytes = Vec::with_capacity(4 + length);
+        bytes.extend_from_slice(&(self.num_powers as u32).to_le_bytes());
+        for section in &self.sections {
+            bytes.extend_from_slice(self.load_section(section)?);
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the bytes of the given section, if they match the checksum of the section.
+    fn load_section(&self, section: &Section) -> Result<&[u8], ParameterError> {
+        let bytes = &self.bytes[section.offset..section.offset + section.length];
+
+        let candidate_checksum = sha256(bytes);
+        if section.checksum != candidate_checksum {
+            return checksum_error!(hex::encode(section.checksum), hex::encode(candidate_checksum));
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the indexed SRS with the given bytes, if its header and section index are well-formed.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ParameterError> {
+        if bytes.len() < HEADER_SIZE || bytes[0..4] != INDEXED_SRS_MAGIC {
+            return Err(ParameterError::Message("Invalid indexed SRS header".to_string()));
+        }
+        let version = u16::from_le_bytes(bytes[4..6].try_into().unwrap());
+        if version != INDEXED_SRS_VERSION {
+            return Err(ParameterError::Message(format!(
+                "Unsupported indexed SRS version {}",
+                version
+            )));
+        }
+        let element_size = read_u32(&bytes, 6)? as usize;
+        let num_powers = read_u64(&bytes, 10)? as usize;
+        let num_sections = read_u32(&bytes, 18)? as usize;
+
+        // Ensure the number of powers fits the `u32` that prefixes the powers of G in the universal SRS bytes.
+        if element_size == 0 || num_powers == 0 || num_powers > u32::MAX as usize {
+            return Err(ParameterError::Message("Invalid indexed SRS header".to_string()));
+        }
+
+        // Ensure the sections are the expected number of sections of powers of G, followed by the parameters section.
+        let num_power_sections = (num_powers + INDEXED_SRS_POWERS_PER_SECTION - 1) / INDEXED_SRS_POWERS_PER_SECTION;
+        if num_sections != num_power_sections + 1 {
+            return Err(ParameterError::Message("Invalid indexed SRS header".to_string()));
+        }
+
+        let mut sections = Vec::with_capacity(num_sections);
+        for i in 0..num_sections {
+            let entry = HEADER_SIZE + i * SECTION_ENTRY_SIZE;
+            let offset = read_u64(&bytes, entry)? as usize;
+            let length = read_u64(&bytes, entry + 8)? as usize;
+            let checksum = bytes
+                .get(entry + 16..entry + SECTION_ENTRY_SIZE)
+                .ok_or_else(|| ParameterError::Message("Invalid indexed SRS section index".to_string()))?
+                .try_into()
+                .unwrap();
+
+            // Ensure the section is within the bytes.
+            if offset.checked_add(length).map_or(true, |end| end > bytes.len()) {
+                return Err(ParameterError::SizeMismatch(offset.saturating_add(length), bytes.len()));
+            }
+
+            // Ensure each section of powers of G has the expected number of powers.
+            if i < num_power_sections {
+                let expected_length =
+                    INDEXED_SRS_POWERS_PER_SECTION.min(num_powers - i * INDEXED_SRS_POWERS_PER_SECTION) * element_size;
+                if length != expected_length {
+                    return Err(ParameterError::SizeMismatch(expected_length, length));
+                }
+            }
+
+            sections.push(Section {
+                offset,
+                length,
+                checksum,
+            });
+        }
+
+        Ok(Self {
+            bytes,
+            num_powers,
+            sections,
+        })
+    }
+}
+
+/// Returns the little-endian `u32` at the given offset of the given bytes.
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, ParameterError> {
+    match bytes.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_le_bytes(bytes.try_into().unwrap())),
+        None => Err(ParameterError::SizeMismatch(offset + 4, bytes.len())),
+    }
+}
+
+/// Returns the little-endian `u64` at the given offset of the given bytes.
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, ParameterError> {
+    match bytes.get(offset..offset + 8) {
+        Some(bytes) => Ok(u64::from_le_bytes(bytes.try_into().unwrap())),
+        None => Err(ParameterError::SizeMismatch(offset + 8, bytes.len())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_polycommit::kzg10::{KZG10DegreeBoundsConfig, UniversalParams, KZG10};
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use rand::thread_rng;
+
+    /// The size in bytes of a serialized BLS12-377 G1 affine point.
+    const ELEMENT_SIZE: usize = 97;
+
+    /// Returns synthetic SRS bytes with the given number of powers of the given element size.
+    fn sample_srs_bytes(num_powers: usize, element_size: usize) -> Vec<u8> {
+        let mut bytes = (num_powers as u32).to_le_bytes().to_vec();
+        bytes.extend((0..num_powers * element_size).map(|i| (i % 251) as u8));
+        bytes.extend_from_slice(b"remaining parameters");
+        bytes
+    }
+
+    fn to_indexed(srs_bytes: &[u8], element_size: usize) -> Vec<u8> {
+        let mut indexed = vec![];
+        IndexedSRS::write_indexed(srs_bytes, element_size, &mut indexed).unwrap();
+        indexed
+    }
+
+    #[test]
+    fn test_load_bytes() {
+        let element_size = 3;
+        let num_powers = 2 * INDEXED_SRS_POWERS_PER_SECTION + 5;
+        let srs_bytes = sample_srs_bytes(num_powers, element_size);
+
+        let srs = IndexedSRS::from_bytes(to_indexed(&srs_bytes, element_size)).unwrap();
+        assert_eq!(num_powers, srs.num_powers());
+        assert_eq!(num_powers - 1, srs.max_degree());
+        assert_eq!(srs_bytes, srs.load_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_checksum_per_section() {
+        let element_size = 3;
+        let num_powers = INDEXED_SRS_POWERS_PER_SECTION + 1;
+        let srs_bytes = sample_srs_bytes(num_powers, element_size);
+        let mut indexed = to_indexed(&srs_bytes, element_size);
+
+        // Corrupt the last power of G, which is in the second section.
+        let last_power = HEADER_SIZE + 3 * SECTION_ENTRY_SIZE + INDEXED_SRS_POWERS_PER_SECTION * element_size;
+        indexed[last_power] ^= 1;
+
+        let srs = IndexedSRS::from_bytes(indexed).unwrap();
+        assert!(matches!(srs.load_bytes(), Err(ParameterError::ChecksumMismatch(..))));
+    }
+
+    #[test]
+    fn test_invalid_indexed_srs() {
+        let srs_bytes = sample_srs_bytes(10, 3);
+        let indexed = to_indexed(&srs_bytes, 3);
+
+        let mut invalid_magic = indexed.clone();
+        invalid_magic[0] = 0;
+        assert!(IndexedSRS::from_bytes(invalid_magic).is_err());
+
+        assert!(IndexedSRS::from_bytes(indexed[..indexed.len() - 1].to_vec()).is_err());
+        assert!(IndexedSRS::from_bytes(indexed[..HEADER_SIZE].to_vec()).is_err());
+
+        // Ensure SRS bytes with fewer powers than their length prefix are rejected.
+        assert!(IndexedSRS::write_indexed(&srs_bytes[..20], 3, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_kzg10_universal_params() {
+        let max_degree = 32;
+        let pp =
+            KZG10::<Bls12_377>::setup(max_degree, &KZG10DegreeBoundsConfig::MARLIN, true, &mut thread_rng()).unwrap();
+        let srs_bytes = pp.to_bytes_le().unwrap();
+
+        let srs = IndexedSRS::from_bytes(to_indexed(&srs_bytes, ELEMENT_SIZE)).unwrap();
+        assert_eq!(max_degree, srs.max_degree());
+
+        let candidate = UniversalParams::<Bls12_377>::read_le(&srs.load_bytes().unwrap()[..]).unwrap();
+        assert_eq!(pp.powers_of_g, candidate.powers_of_g);
+        assert_eq!(pp.powers_of_gamma_g, candidate.powers_of_gamma_g);
+        assert_eq!(pp.inverse_neg_powers_of_h, candidate.inverse_neg_powers_of_h);
+    }
+}
@@ -17,11 +17,53 @@
 pub mod genesis;
 pub use genesis::*;
 
+#[cfg(not(feature = "wasm"))]
+use crate::{IndexedSRS, ParameterError};
+
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/aleo.parameters";
 
 // Universal SRS
 impl_remote!(UniversalSRSBytes, REMOTE_URL, "./resources/", "universal", "srs");
 
+/// The size in bytes of a power of G in the universal SRS, which is a serialized BLS12-377 G1 affine point.
+#[cfg(not(feature = "wasm"))]
+const UNIVERSAL_SRS_ELEMENT_SIZE: usize = 97;
+
+#[cfg(not(feature = "wasm"))]
+impl UniversalSRSBytes {
+    ///
+    /// Returns the indexed universal SRS from the local parameters directory.
+    ///
+    /// If the indexed universal SRS does not exist, it is created from the universal SRS bytes.
+    ///
+    pub fn load_indexed() -> Result<IndexedSRS, ParameterError> {
+        const METADATA: &str = include_str!("./resources/universal.metadata");
+
+        let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+        let checksum = metadata["srs_checksum"].as_str().expect("Failed to parse checksum");
+
+        // Construct the versioned filename, so that an index of a previous universal SRS is not used.
+        let mut file_path = aleo_std::aleo_dir();
+        file_path.push("./resources/");
+        file_path.push(format!("universal.srs.{}.indexed", &checksum[..7]));
+
+        if !file_path.exists() {
+            let srs_bytes = Self::load_bytes()?;
+
+            // Write the index to a temporary file, which is renamed once it is complete.
+            if let Some(directory_path) = file_path.parent() {
+                std::fs::create_dir_all(directory_path)?;
+            }
+            let temporary_path = file_path.with_extension("indexed.tmp");
+            let file = std::fs::File::create(&temporary_path)?;
+            IndexedSRS::write_indexed(&srs_bytes, UNIVERSAL_SRS_ELEMENT_SIZE, std::io::BufWriter::new(file))?;
+            std::fs::rename(&temporary_path, &file_path)?;
+        }
+
+        IndexedSRS::open(&file_path)
+    }
+}
+
 // Noop Circuit
 impl_local!(NoopProvingKeyBytes, "./resources/", "noop", "proving");
 impl_local!(NoopVerifyingKeyBytes, "./resources/", "noop", "verifying");
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+use snarkvm_polycommit::{sonic_pc::SonicKZG10, LabeledPolynomial, Polynomial, PolynomialCommitment};
+use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+use criterion::{BenchmarkId, Criterion};
+
+type PC = SonicKZG10<Bls12_377>;
+
+/// Degrees to benchmark on, as powers of two.
+const MIN_LOG_DEGREE: usize = 10;
+const MAX_LOG_DEGREE: usize = 16;
+
+fn sonic_pc(c: &mut Criterion) {
+    let rng = &mut test_rng();
+
+    let universal_params = PC::setup(1 << MAX_LOG_DEGREE, rng).unwrap();
+
+    let mut group = c.benchmark_group("SonicKZG10");
+    for log_degree in MIN_LOG_DEGREE..=MAX_LOG_DEGREE {
+        let degree = (1 << log_degree) - 1;
+        let (ck, vk) = PC::trim(&universal_params, degree, 0, None).unwrap();
+
+        let polynomial = LabeledPolynomial::new("p".into(), Polynomial::rand(degree, rng), None, None);
+        let point = Fr::rand(rng);
+        let opening_challenge = Fr::rand(rng);
+        let value = polynomial.evaluate(point);
+
+        group.bench_with_input(BenchmarkId::new("commit", degree), &degree, |b, _| {
+            b.iter(|| PC::commit(&ck, vec![&polynomial], None).unwrap())
+        });
+
+        let (commitments, randomness) = PC::commit(&ck, vec![&polynomial], None).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("open", degree), &degree, |b, _| {
+            b.iter(|| {
+                PC::open(
+                    &ck,
+                    vec![&polynomial],
+                    &commitments,
+                    point,
+                    opening_challenge,
+                    &randomness,
+                    None,
+                )
+                .unwrap()
+            })
+        });
+
+        let proof = PC::open(
+            &ck,
+            vec![&polynomial],
+            &commitments,
+            point,
+            opening_challenge,
+            &randomness,
+            None,
+        )
+        .unwrap();
+
+        group.bench_with_input(BenchmarkId::new("check", degree), &degree, |b, _| {
+            b.iter(|| {
+                assert!(PC::check(&vk, &commitments, point, vec![value], &proof, opening_challenge, rng).unwrap())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = polycommit;
+    config = Criterion::default().sample_size(10);
+    targets = sonic_pc
+}
+
+criterion_main!(polycommit);
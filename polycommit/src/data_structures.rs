@@ -16,7 +16,7 @@
 
 use crate::{Arc, String, Vec};
 pub use snarkvm_algorithms::fft::DensePolynomial as Polynomial;
-use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField, Zero};
 use snarkvm_utilities::{error as error_fn, errors::SerializationError, serialize::*, FromBytes, ToBytes};
 
 use core::{
@@ -24,11 +24,30 @@ use core::{
     fmt::Debug,
     ops::{AddAssign, MulAssign, SubAssign},
 };
+use hashbrown::HashMap;
 use rand_core::RngCore;
 
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
+/// A fixed set of polynomial labels, such as the polynomials of a protocol.
+///
+/// A protocol that names its polynomials with a label set, instead of with raw strings,
+/// turns a misspelled label into a compile error, rather than a `MissingPolynomial` error
+/// when the polynomials are opened.
+pub trait PolynomialLabelSet: Copy + Eq + Debug + Into<PolynomialLabel> + Into<LCTerm> + 'static {
+    /// Every label in the set.
+    const ALL: &'static [Self];
+
+    /// Returns the polynomial label.
+    fn as_str(&self) -> &'static str;
+
+    /// Returns the label in the set with the given polynomial label, if it exists.
+    fn from_label(label: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|candidate| candidate.as_str() == label)
+    }
+}
+
 /// Defines the minimal interface for public params for any polynomial
 /// commitment scheme.
 pub trait PCUniversalParams: CanonicalSerialize + CanonicalDeserialize + Clone + Debug + ToBytes + FromBytes {
@@ -318,6 +337,114 @@ impl<F: Field> LinearCombination<F> {
         self.terms.push(term);
         self
     }
+
+    /// Reserves capacity for at least `additional` more terms.
+    pub fn reserve(&mut self, additional: usize) {
+        self.terms.reserve(additional);
+    }
+
+    ///
+    /// Merges the coefficients of repeated terms, and removes the terms with a zero coefficient.
+    /// The remaining terms keep the order of their first occurrence.
+    ///
+    pub fn compress(&mut self) {
+        let mut builder = LinearCombinationBuilder::with_capacity(core::mem::take(&mut self.label), self.terms.len());
+        for (coeff, term) in self.terms.drain(..) {
+            builder.add_term(coeff, term);
+        }
+        *self = builder.build();
+    }
+}
+
+///
+/// A builder for a labeled linear combination, which merges the coefficient of a repeated term
+/// into its first occurrence in constant time, and defers the removal of zero coefficients
+/// and any sorting of the terms to `build`.
+///
+#[derive(Clone, Debug)]
+pub struct LinearCombinationBuilder<F> {
+    /// The label.
+    label: String,
+    /// The terms, in order of their first occurrence.
+    terms: Vec<(F, LCTerm)>,
+    /// The index of each term in `terms`.
+    indices: HashMap<LCTerm, usize>,
+}
+
+impl<F: Field> LinearCombinationBuilder<F> {
+    /// Initializes a builder for a linear combination with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self::with_capacity(label, 0)
+    }
+
+    /// Initializes a builder for a linear combination with the given label, with capacity for `capacity` distinct terms.
+    pub fn with_capacity(label: impl Into<String>, capacity: usize) -> Self {
+        Self {
+            label: label.into(),
+            terms: Vec::with_capacity(capacity),
+            indices: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of distinct terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns `true` if the builder has no terms.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Adds the given coefficient to the coefficient of the given term.
+    pub fn add_term(&mut self, coeff: F, term: impl Into<LCTerm>) -> &mut Self {
+        let term = term.into();
+        match self.indices.get(&term) {
+            Some(&index) => self.terms[index].0 += coeff,
+            None => {
+                self.indices.insert(term.clone(), self.terms.len());
+                self.terms.push((coeff, term));
+            }
+        }
+        self
+    }
+
+    /// Adds the terms of the given linear combination, scaled by the given coefficient.
+    pub fn add_linear_combination(&mut self, coeff: F, other: &LinearCombination<F>) -> &mut Self {
+        for (c, term) in other.iter() {
+            self.add_term(coeff * c, term.clone());
+        }
+        self
+    }
+
+    /// Returns the linear combination, with the terms in order of their first occurrence
+    /// and without the terms with a zero coefficient.
+    pub fn build(mut self) -> LinearCombination<F> {
+        self.terms.retain(|(coeff, _)| !coeff.is_zero());
+        LinearCombination {
+            label: self.label,
+            terms: self.terms,
+        }
+    }
+
+    /// Returns the linear combination, with the terms sorted and without the terms with a zero coefficient.
+    pub fn build_sorted(self) -> LinearCombination<F> {
+        let mut linear_combination = self.build();
+        linear_combination.terms.sort_unstable_by(|(_, a), (_, b)| a.cmp(b));
+        linear_combination
+    }
+}
+
+impl<F: Field> MulAssign<F> for LinearCombinationBuilder<F> {
+    fn mul_assign(&mut self, coeff: F) {
+        self.terms.iter_mut().for_each(|(c, _)| *c *= &coeff);
+    }
+}
+
+impl<F: Field> From<LinearCombinationBuilder<F>> for LinearCombination<F> {
+    fn from(builder: LinearCombinationBuilder<F>) -> Self {
+        builder.build()
+    }
 }
 
 impl<'a, F: Field> AddAssign<(F, &'a LinearCombination<F>)> for LinearCombination<F> {
@@ -361,6 +488,7 @@ impl<F: Field> SubAssign<F> for LinearCombination<F> {
 }
 
 impl<F: Field> MulAssign<F> for LinearCombination<F> {
+    /// Scales the coefficients in place, without reallocating the terms.
     fn mul_assign(&mut self, coeff: F) {
         self.terms.iter_mut().for_each(|(c, _)| *c *= &coeff);
     }
@@ -391,3 +519,50 @@ macro_rules! impl_bytes {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_linear_combination_builder() {
+        let (one, two) = (Fr::one(), Fr::one() + Fr::one());
+
+        let mut builder = LinearCombinationBuilder::new("lc");
+        builder
+            .add_term(one, "b")
+            .add_term(two, LCTerm::One)
+            .add_term(one, "a")
+            .add_term(one, "b")
+            .add_term(-two, LCTerm::One);
+        assert_eq!(3, builder.len());
+
+        // Ensure repeated terms are merged into their first occurrence, and zero coefficients are removed.
+        let lc = builder.clone().build();
+        assert_eq!("lc", lc.label());
+        assert_eq!(&[(two, LCTerm::from("b")), (one, LCTerm::from("a"))][..], &lc[..]);
+
+        // Ensure the sorted linear combination has the same terms, in sorted order.
+        let lc = builder.build_sorted();
+        assert_eq!(&[(one, LCTerm::from("a")), (two, LCTerm::from("b"))][..], &lc[..]);
+    }
+
+    #[test]
+    fn test_linear_combination_compress() {
+        let (one, two) = (Fr::one(), Fr::one() + Fr::one());
+
+        let mut lc = LinearCombination::new("lc", vec![(one, "a"), (two, "b"), (one, "a")]);
+        lc -= &LinearCombination::new("other", vec![(two, "b")]);
+        lc += one;
+        assert_eq!(5, lc.len());
+
+        lc.compress();
+        assert_eq!("lc", lc.label());
+        assert_eq!(&[(two, LCTerm::from("a")), (one, LCTerm::One)][..], &lc[..]);
+
+        lc *= two;
+        assert_eq!(&[(two + two, LCTerm::from("a")), (two, LCTerm::One)][..], &lc[..]);
+    }
+}
@@ -99,6 +99,9 @@ pub enum Error {
     /// The commitment was generated incorrectly, tampered with, or doesn't support the polynomial.
     MalformedCommitment(String),
 
+    /// The powers-of-tau transcript could not be read or failed verification.
+    InvalidTranscript(String),
+
     Terminated,
 }
 
@@ -158,6 +161,7 @@ impl core::fmt::Display for Error {
             ),
             Error::IncorrectInputLength(err) => write!(f, "{}", err),
             Error::MalformedCommitment(err) => write!(f, "{}", err),
+            Error::InvalidTranscript(err) => write!(f, "invalid powers of tau transcript: {}", err),
             Error::Terminated => write!(f, "terminated"),
         }
     }
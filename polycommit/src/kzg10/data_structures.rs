@@ -16,6 +16,7 @@
 
 use crate::{impl_bytes, BTreeMap, *};
 use core::ops::{Add, AddAssign};
+use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_curves::{
     traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve},
     Group,
@@ -25,6 +26,7 @@ use snarkvm_utilities::{
     error,
     errors::SerializationError,
     serialize::{CanonicalDeserialize, CanonicalSerialize},
+    vec_with_bounded_capacity,
     FromBytes,
     ToBytes,
 };
@@ -64,7 +66,7 @@ impl<E: PairingEngine> FromBytes for UniversalParams<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         // Deserialize `powers_of_g`.
         let powers_of_g_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_g = Vec::with_capacity(powers_of_g_len as usize);
+        let mut powers_of_g = vec_with_bounded_capacity(powers_of_g_len as usize);
         for _ in 0..powers_of_g_len {
             let power_of_g: E::G1Affine = FromBytes::read_le(&mut reader)?;
             powers_of_g.push(power_of_g);
@@ -88,7 +90,7 @@ impl<E: PairingEngine> FromBytes for UniversalParams<E> {
 
         // Deserialize `supported_degree_bounds`.
         let supported_degree_bounds_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut supported_degree_bounds = Vec::with_capacity(supported_degree_bounds_len as usize);
+        let mut supported_degree_bounds = vec_with_bounded_capacity(supported_degree_bounds_len as usize);
         for _ in 0..supported_degree_bounds_len {
             let degree_bound: u32 = FromBytes::read_le(&mut reader)?;
             supported_degree_bounds.push(degree_bound as usize);
@@ -195,6 +197,29 @@ impl<E: PairingEngine> PCUniversalParams for UniversalParams<E> {
     }
 }
 
+impl<E: PairingEngine> UniversalParams<E> {
+    /// Returns the powers shifted by `max_degree - degree_bound`, which are used to compute
+    /// a degree proof for a polynomial of degree at most `degree_bound`.
+    pub fn shifted_powers(&self, degree_bound: usize) -> Result<Powers<'_, E>, Error> {
+        let max_degree = self.max_degree();
+        if degree_bound > max_degree {
+            return Err(Error::UnsupportedDegreeBound(degree_bound));
+        }
+
+        let shift_degree = max_degree - degree_bound;
+        let powers_of_gamma_g = self
+            .powers_of_gamma_g
+            .range(shift_degree..)
+            .map(|(_, power_of_gamma_g)| *power_of_gamma_g)
+            .collect::<Vec<_>>();
+
+        Ok(Powers {
+            powers_of_g: Cow::Borrowed(&self.powers_of_g[shift_degree..]),
+            powers_of_gamma_g: Cow::Owned(powers_of_gamma_g),
+        })
+    }
+}
+
 /// `Powers` is used to commit to and create evaluation proofs for a given
 /// polynomial.
 #[derive(Derivative)]
@@ -211,6 +236,24 @@ impl<E: PairingEngine> Powers<'_, E> {
     pub fn size(&self) -> usize {
         self.powers_of_g.len()
     }
+
+    /// Returns the Lagrange basis `{ L_i(β) G }` of the given domain, which is used to commit to
+    /// a polynomial from its evaluations over the domain.
+    pub fn lagrange_basis(&self, domain: &EvaluationDomain<E::Fr>) -> Result<Vec<E::G1Affine>, Error> {
+        if domain.size() > self.size() {
+            return Err(Error::TooManyCoefficients {
+                num_coefficients: domain.size(),
+                num_powers: self.size(),
+            });
+        }
+
+        let powers_of_g = self.powers_of_g[..domain.size()]
+            .iter()
+            .map(|power_of_g| power_of_g.into_projective())
+            .collect::<Vec<_>>();
+        let lagrange_basis = domain.ifft_g1(&powers_of_g);
+        Ok(E::G1Projective::batch_normalization_into_affine(lagrange_basis))
+    }
 }
 
 /// `VerifierKey` is used to check evaluation proofs for a given commitment.
@@ -310,9 +353,10 @@ pub struct Commitment<E: PairingEngine>(
 
 impl_bytes!(Commitment);
 
+/// The version 1 encoding of a commitment is the encoding of its point.
 impl<E: PairingEngine> ToMinimalBits for Commitment<E> {
-    fn to_minimal_bits(&self) -> Vec<bool> {
-        self.0.to_minimal_bits()
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
+        self.0.to_minimal_bits_v1()
     }
 }
 
@@ -485,3 +529,22 @@ impl<E: PairingEngine> PCProof for Proof<E> {
         self.random_v.is_some()
     }
 }
+
+/// `DegreeProof` is a proof that a committed polynomial has degree at most a given degree bound,
+/// which is output by `KZG10::open_degree_proof`.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = ""),
+    Hash(bound = ""),
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct DegreeProof<E: PairingEngine> {
+    /// This is a commitment to the polynomial under the powers shifted by `max_degree - degree_bound`.
+    pub shifted_commitment: E::G1Affine,
+}
+impl_bytes!(DegreeProof);
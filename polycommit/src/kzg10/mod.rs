@@ -43,6 +43,11 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+#[cfg(feature = "std")]
+mod powers_of_tau;
+#[cfg(feature = "std")]
+pub use powers_of_tau::*;
+
 #[derive(Debug, PartialEq, Eq)]
 #[allow(deprecated)]
 pub enum KZG10DegreeBoundsConfig {
@@ -231,6 +236,7 @@ impl<E: PairingEngine> KZG10<E> {
     ) -> Result<(Commitment<E>, Randomness<E>), Error> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
 
+        let _span = debug_span!("KZG10::commit", degree = polynomial.degree()).entered();
         let commit_time = start_timer!(|| format!(
             "Committing to polynomial of degree {} with hiding_bound: {:?}",
             polynomial.degree(),
@@ -361,6 +367,55 @@ impl<E: PairingEngine> KZG10<E> {
         proof
     }
 
+    /// On input a polynomial `p` and the randomness of its commitment, outputs a proof that `p`
+    /// has degree at most `degree_bound`, where `shifted_powers` is `UniversalParams::shifted_powers(degree_bound)`.
+    ///
+    /// The proof is a commitment to `p` under the powers shifted by `max_degree - degree_bound`,
+    /// which can only be computed if `p` has degree at most `degree_bound`.
+    pub fn open_degree_proof(
+        shifted_powers: &Powers<E>,
+        polynomial: &Polynomial<E::Fr>,
+        rand: &Randomness<E>,
+    ) -> Result<DegreeProof<E>, Error> {
+        Self::check_degree_is_too_large(polynomial.degree(), shifted_powers.size())?;
+        let open_time = start_timer!(|| format!("Opening degree of polynomial of degree {}", polynomial.degree()));
+
+        let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
+        let mut shifted_commitment =
+            VariableBaseMSM::multi_scalar_mul(&shifted_powers.powers_of_g[num_leading_zeros..], &plain_coeffs);
+
+        if rand.is_hiding() {
+            Self::check_hiding_bound(
+                rand.blinding_polynomial.degree(),
+                shifted_powers.powers_of_gamma_g.len(),
+            )?;
+            let random_ints = convert_to_bigints(&rand.blinding_polynomial.coeffs);
+            shifted_commitment += &VariableBaseMSM::multi_scalar_mul(&shifted_powers.powers_of_gamma_g, &random_ints);
+        }
+
+        end_timer!(open_time);
+        Ok(DegreeProof {
+            shifted_commitment: shifted_commitment.into_affine(),
+        })
+    }
+
+    /// Verifies that the polynomial committed inside `commitment` has degree at most `degree_bound`,
+    /// where `neg_power_of_h` is `UniversalParams::inverse_neg_powers_of_h[degree_bound]`,
+    /// that is, `\beta^{-(max_degree - degree_bound)} H`.
+    pub fn check_degree_proof(
+        vk: &VerifierKey<E>,
+        neg_power_of_h: E::G2Affine,
+        commitment: &Commitment<E>,
+        proof: &DegreeProof<E>,
+    ) -> Result<bool, Error> {
+        let check_time = start_timer!(|| "Checking degree proof");
+        let lhs = E::pairing(commitment.0, vk.h);
+        let rhs = E::pairing(proof.shifted_commitment, neg_power_of_h);
+
+        end_timer!(check_time, || format!("Result: {}", lhs == rhs));
+        Ok(lhs == rhs)
+    }
+
     /// Verifies that `value` is the evaluation at `point` of the polynomial
     /// committed inside `commitment`.
     pub fn check(
@@ -520,7 +575,11 @@ fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
 mod tests {
     #![allow(non_camel_case_types)]
     use crate::{kzg10::*, *};
-    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_algorithms::fft::EvaluationDomain;
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fr},
+        bls12_381::Bls12_381,
+    };
     use snarkvm_utilities::rand::test_rng;
 
     type KZG_Bls12_377 = KZG10<Bls12_377>;
@@ -565,6 +624,32 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn lagrange_basis_test() {
+        let rng = &mut test_rng();
+
+        let degree = 7;
+        let pp = KZG_Bls12_377::setup(degree, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, degree);
+
+        // Ensure committing to the evaluations of a polynomial with the Lagrange basis
+        // matches committing to its coefficients with the powers.
+        let domain = EvaluationDomain::<Fr>::new(degree + 1).unwrap();
+        let lagrange_basis = powers.lagrange_basis(&domain).unwrap();
+        let p = Polynomial::rand(degree, rng);
+        let (comm, _) = KZG10::commit(&powers, &p, None, &AtomicBool::new(false), None).unwrap();
+
+        let mut candidate = <Bls12_377 as PairingEngine>::G1Projective::zero();
+        for (basis, eval) in lagrange_basis.iter().zip(domain.fft(&p.coeffs)) {
+            candidate += basis.mul(eval).into_projective();
+        }
+        assert_eq!(comm.0, candidate.into_affine());
+
+        // Ensure the Lagrange basis of a domain larger than the powers is rejected.
+        let domain = EvaluationDomain::<Fr>::new(2 * (degree + 1)).unwrap();
+        assert!(powers.lagrange_basis(&domain).is_err());
+    }
+
     #[test]
     fn add_commitments_test() {
         let rng = &mut test_rng();
@@ -675,19 +760,70 @@ mod tests {
         Ok(())
     }
 
+    fn degree_proof_test_template<E: PairingEngine>() -> Result<(), Error> {
+        let rng = &mut test_rng();
+        let degree = 32;
+        let config = KZG10DegreeBoundsConfig::LIST(vec![15, 20]);
+        let pp = KZG10::<E>::setup(degree, &config, true, rng)?;
+        let (ck, vk) = KZG10::trim(&pp, degree);
+
+        for hiding_bound in [None, Some(1)] {
+            let p = Polynomial::rand(15, rng);
+            let (comm, rand) = KZG10::<E>::commit(&ck, &p, hiding_bound, &AtomicBool::new(false), Some(rng))?;
+
+            // Ensure the degree proof verifies for the degree bound.
+            let proof = KZG10::<E>::open_degree_proof(&pp.shifted_powers(15)?, &p, &rand)?;
+            assert!(KZG10::<E>::check_degree_proof(
+                &vk,
+                pp.inverse_neg_powers_of_h[&15],
+                &comm,
+                &proof
+            )?);
+
+            // Ensure the degree proof for a larger degree bound does not verify for the degree bound.
+            let proof = KZG10::<E>::open_degree_proof(&pp.shifted_powers(20)?, &p, &rand)?;
+            assert!(KZG10::<E>::check_degree_proof(
+                &vk,
+                pp.inverse_neg_powers_of_h[&20],
+                &comm,
+                &proof
+            )?);
+            assert!(!KZG10::<E>::check_degree_proof(
+                &vk,
+                pp.inverse_neg_powers_of_h[&15],
+                &comm,
+                &proof
+            )?);
+
+            // Ensure a degree proof cannot be computed for a polynomial that exceeds the degree bound.
+            let q = Polynomial::rand(16, rng);
+            assert!(KZG10::<E>::open_degree_proof(&pp.shifted_powers(15)?, &q, &rand).is_err());
+        }
+        Ok(())
+    }
+
     #[test]
     fn end_to_end_test() {
         end_to_end_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        end_to_end_test_template::<Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn linear_polynomial_test() {
         linear_polynomial_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        linear_polynomial_test_template::<Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn batch_check_test() {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        batch_check_test_template::<Bls12_381>().expect("test failed for bls12-381");
+    }
+
+    #[test]
+    fn degree_proof_test() {
+        degree_proof_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        degree_proof_test_template::<Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
@@ -0,0 +1,520 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Import of phase-1 powers-of-tau transcripts into KZG10 universal parameters.
+//!
+//! The transcript layout follows the Zcash `powersoftau` ceremony, which is shared by
+//! the Perpetual Powers of Tau and its derivatives:
+//!
+//! ```text
+//! [ BLAKE2b hash of the previous transcript (64 bytes) ]
+//! [ tau^i G1, for i in 0..2^{n+1} - 1                  ]
+//! [ tau^i G2, for i in 0..2^n                          ]
+//! [ alpha tau^i G1, for i in 0..2^n                    ]
+//! [ beta tau^i G1, for i in 0..2^n                     ]
+//! [ beta G2                                            ]
+//! [ any trailing data, such as a contribution key      ]
+//! ```
+//!
+//! Ceremonies differ in how curve points are encoded, which is captured by [`TranscriptEncoding`].
+//! The challenge transcripts of the Zcash ceremony on BLS12-381, and of the Perpetual Powers of Tau,
+//! use the uncompressed encoding of [`ZcashEncoding`].
+
+use crate::{
+    kzg10::{KZG10DegreeBoundsConfig, UniversalParams},
+    BTreeMap, Error, ToString, Vec,
+};
+use snarkvm_algorithms::msm::VariableBaseMSM;
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{rand::UniformRand, FromBytes, ToBytes};
+
+use blake2::{Blake2b, Digest};
+use core::marker::PhantomData;
+use rand_core::RngCore;
+use std::io::{self, Read};
+
+/// The size of a BLAKE2b transcript hash in bytes.
+pub const TRANSCRIPT_HASH_SIZE_IN_BYTES: usize = 64;
+
+/// The encoding of curve points in a powers-of-tau transcript.
+pub trait TranscriptEncoding<E: PairingEngine> {
+    /// Reads a point in G1.
+    fn read_g1<R: Read>(reader: R) -> io::Result<E::G1Affine>;
+
+    /// Reads a point in G2.
+    fn read_g2<R: Read>(reader: R) -> io::Result<E::G2Affine>;
+}
+
+/// The native snarkVM encoding of curve points, as produced by `ToBytes`.
+pub struct NativeEncoding;
+
+impl<E: PairingEngine> TranscriptEncoding<E> for NativeEncoding {
+    fn read_g1<R: Read>(reader: R) -> io::Result<E::G1Affine> {
+        E::G1Affine::read_le(reader)
+    }
+
+    fn read_g2<R: Read>(reader: R) -> io::Result<E::G2Affine> {
+        E::G2Affine::read_le(reader)
+    }
+}
+
+/// The uncompressed encoding of curve points in the Zcash `powersoftau` ceremony.
+///
+/// Each coordinate is encoded as big-endian field elements, with the coefficients of an extension field element
+/// in descending order. The most significant bit of an encoded point is the compression flag, which must be unset,
+/// and the second most significant bit is the point-at-infinity flag.
+pub struct ZcashEncoding;
+
+impl ZcashEncoding {
+    /// The compression flag, in the first byte of an encoded point.
+    const COMPRESSION_FLAG: u8 = 1 << 7;
+    /// The point-at-infinity flag, in the first byte of an encoded point.
+    const INFINITY_FLAG: u8 = 1 << 6;
+
+    /// Reads a point whose coordinates are elements of a degree `extension_degree` extension of `F`.
+    fn read_point<F: PrimeField, G: AffineCurve, R: Read>(mut reader: R, extension_degree: usize) -> io::Result<G> {
+        let field_size_in_bytes = F::zero().to_bytes_le()?.len();
+        let coordinate_size_in_bytes = extension_degree * field_size_in_bytes;
+
+        let mut bytes = vec![0u8; 2 * coordinate_size_in_bytes];
+        reader.read_exact(&mut bytes)?;
+
+        let flags = bytes[0];
+        if flags & Self::COMPRESSION_FLAG != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "compressed points are not supported",
+            ));
+        }
+        bytes[0] &= !(Self::COMPRESSION_FLAG | Self::INFINITY_FLAG);
+
+        if flags & Self::INFINITY_FLAG != 0 {
+            return match bytes.iter().all(|byte| *byte == 0) {
+                true => Ok(G::zero()),
+                false => Err(io::Error::new(io::ErrorKind::InvalidData, "invalid point at infinity")),
+            };
+        }
+
+        // Rewrite the coordinates in the native encoding, which is little-endian with the coefficients of an
+        // extension field element in ascending order, followed by the infinity flag.
+        let mut native_bytes = Vec::with_capacity(bytes.len() + 1);
+        for coordinate in bytes.chunks(coordinate_size_in_bytes) {
+            for coefficient in coordinate.chunks(field_size_in_bytes).rev() {
+                native_bytes.extend(coefficient.iter().rev());
+            }
+        }
+        native_bytes.push(0u8);
+
+        let point = G::read_le(&native_bytes[..])?;
+        if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the point is not in the prime order subgroup",
+            ));
+        }
+        Ok(point)
+    }
+}
+
+impl<E: PairingEngine> TranscriptEncoding<E> for ZcashEncoding {
+    fn read_g1<R: Read>(reader: R) -> io::Result<E::G1Affine> {
+        Self::read_point::<E::Fq, _, _>(reader, 1)
+    }
+
+    fn read_g2<R: Read>(reader: R) -> io::Result<E::G2Affine> {
+        let extension_degree = E::Fqe::zero().to_bytes_le()?.len() / E::Fq::zero().to_bytes_le()?.len();
+        Self::read_point::<E::Fq, _, _>(reader, extension_degree)
+    }
+}
+
+/// A reader that computes the BLAKE2b hash of all bytes read through it.
+struct HashReader<R: Read> {
+    reader: R,
+    hasher: Blake2b,
+}
+
+impl<R: Read> Read for HashReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let num_bytes = self.reader.read(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
+
+/// The elements of a phase-1 powers-of-tau transcript that are needed for KZG10.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct PowersOfTau<E: PairingEngine> {
+    /// The BLAKE2b hash of the previous transcript in the ceremony.
+    pub previous_hash: Vec<u8>,
+    /// The BLAKE2b hash of this transcript.
+    pub hash: Vec<u8>,
+    /// Group elements of the form `{ \tau^i G }`, where `i` ranges from 0 to `2^{n+1} - 2`.
+    pub tau_powers_g1: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \tau^i H }`, where `i` ranges from 0 to `2^n - 1`.
+    pub tau_powers_g2: Vec<E::G2Affine>,
+    /// Group elements of the form `{ \alpha \tau^i G }`, where `i` ranges from 0 to `2^n - 1`.
+    pub alpha_tau_powers_g1: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> PowersOfTau<E> {
+    /// Reads a transcript of size `2^log_size` using the given point encoding.
+    ///
+    /// The reader is consumed to its end, so that the transcript hash covers the entire transcript.
+    /// If `expected_hash` is provided, the transcript hash must match it.
+    pub fn read<R: Read, T: TranscriptEncoding<E>>(
+        reader: R,
+        log_size: usize,
+        expected_hash: Option<&[u8]>,
+        _encoding: PhantomData<T>,
+    ) -> Result<Self, Error> {
+        let read_time = start_timer!(|| format!("Reading powers of tau of size 2^{}", log_size));
+        let mut reader = HashReader {
+            reader,
+            hasher: Blake2b::new(),
+        };
+        let size = 1usize << log_size;
+
+        let mut previous_hash = vec![0u8; TRANSCRIPT_HASH_SIZE_IN_BYTES];
+        reader.read_exact(&mut previous_hash).map_err(invalid_transcript)?;
+
+        let tau_powers_g1 = (0..2 * size - 1)
+            .map(|_| T::read_g1(&mut reader))
+            .collect::<io::Result<Vec<_>>>()
+            .map_err(invalid_transcript)?;
+        let tau_powers_g2 = (0..size)
+            .map(|_| T::read_g2(&mut reader))
+            .collect::<io::Result<Vec<_>>>()
+            .map_err(invalid_transcript)?;
+        let alpha_tau_powers_g1 = (0..size)
+            .map(|_| T::read_g1(&mut reader))
+            .collect::<io::Result<Vec<_>>>()
+            .map_err(invalid_transcript)?;
+
+        // Skip the remaining elements and trailing data, while including them in the transcript hash.
+        io::copy(&mut reader, &mut io::sink()).map_err(invalid_transcript)?;
+
+        let hash = reader.hasher.finalize().to_vec();
+        if let Some(expected_hash) = expected_hash {
+            if hash[..] != expected_hash[..] {
+                return Err(Error::InvalidTranscript(
+                    "the transcript hash does not match".to_string(),
+                ));
+            }
+        }
+        end_timer!(read_time);
+
+        Ok(Self {
+            previous_hash,
+            hash,
+            tau_powers_g1,
+            tau_powers_g2,
+            alpha_tau_powers_g1,
+        })
+    }
+
+    /// Checks that the transcript elements are consistent powers of the same `tau`,
+    /// using random linear combinations of each sequence.
+    pub fn verify<R: RngCore>(&self, rng: &mut R) -> Result<(), Error> {
+        let verify_time = start_timer!(|| "Verifying powers of tau");
+        if self.tau_powers_g1.len() < 2 || self.tau_powers_g2.len() < 2 || self.alpha_tau_powers_g1.len() < 2 {
+            return Err(Error::InvalidTranscript("the transcript is too small".to_string()));
+        }
+
+        let g = self.tau_powers_g1[0];
+        let h = self.tau_powers_g2[0];
+        let tau_g = self.tau_powers_g1[1];
+        let tau_h = self.tau_powers_g2[1];
+        if g.is_zero() || h.is_zero() || tau_g.is_zero() || tau_h.is_zero() {
+            return Err(Error::InvalidTranscript(
+                "the transcript contains degenerate elements".to_string(),
+            ));
+        }
+
+        // Ensure `tau` is consistent across G1 and G2.
+        if !same_ratio::<E>((g, tau_g), (h, tau_h)) {
+            return Err(Error::InvalidTranscript("tau differs between G1 and G2".to_string()));
+        }
+
+        // Ensure each sequence is a geometric progression in `tau`.
+        let (lhs, rhs) = power_pairs(&self.tau_powers_g1, rng);
+        if !same_ratio::<E>((lhs, rhs), (h, tau_h)) {
+            return Err(Error::InvalidTranscript("invalid powers of tau in G1".to_string()));
+        }
+        let (lhs, rhs) = power_pairs(&self.alpha_tau_powers_g1, rng);
+        if !same_ratio::<E>((lhs, rhs), (h, tau_h)) {
+            return Err(Error::InvalidTranscript(
+                "invalid powers of alpha tau in G1".to_string(),
+            ));
+        }
+        let (lhs, rhs) = power_pairs(&self.tau_powers_g2, rng);
+        if !same_ratio::<E>((g, tau_g), (lhs, rhs)) {
+            return Err(Error::InvalidTranscript("invalid powers of tau in G2".to_string()));
+        }
+
+        end_timer!(verify_time);
+        Ok(())
+    }
+
+    /// Converts the transcript into KZG10 universal parameters for the given maximum degree.
+    ///
+    /// The powers of `alpha tau` serve as the powers of `gamma beta` used for hiding commitments.
+    /// Transcripts do not contain negative powers of `tau` in G2, so `inverse_neg_powers_of_h` is left empty.
+    pub fn to_universal_params(
+        &self,
+        max_degree: usize,
+        supported_degree_bounds_config: &KZG10DegreeBoundsConfig,
+    ) -> Result<UniversalParams<E>, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+        // One additional power of `gamma beta` is required to support `max_degree` queries.
+        if self.tau_powers_g1.len() < max_degree + 1 || self.alpha_tau_powers_g1.len() < max_degree + 2 {
+            return Err(Error::TrimmingDegreeTooLarge);
+        }
+        if self.tau_powers_g2.len() < 2 {
+            return Err(Error::InvalidTranscript("the transcript is too small".to_string()));
+        }
+
+        let powers_of_g = self.tau_powers_g1[..=max_degree].to_vec();
+        let powers_of_gamma_g = self.alpha_tau_powers_g1[..max_degree + 2]
+            .iter()
+            .copied()
+            .enumerate()
+            .collect();
+
+        let (supported_degree_bounds, inverse_powers_of_g) =
+            if *supported_degree_bounds_config != KZG10DegreeBoundsConfig::NONE {
+                let list = supported_degree_bounds_config.get_list::<E::Fr>(max_degree);
+                let inverse_powers_of_g = list.iter().map(|i| (*i, powers_of_g[max_degree - i])).collect();
+                (list, inverse_powers_of_g)
+            } else {
+                (vec![], BTreeMap::new())
+            };
+
+        let h = self.tau_powers_g2[0];
+        let beta_h = self.tau_powers_g2[1];
+
+        Ok(UniversalParams {
+            powers_of_g,
+            powers_of_gamma_g,
+            h,
+            beta_h,
+            supported_degree_bounds,
+            inverse_powers_of_g,
+            inverse_neg_powers_of_h: BTreeMap::new(),
+            prepared_h: h.prepare(),
+            prepared_beta_h: beta_h.prepare(),
+        })
+    }
+}
+
+fn invalid_transcript(error: io::Error) -> Error {
+    Error::InvalidTranscript(error.to_string())
+}
+
+/// Returns random linear combinations `(\sum r_i P_i, \sum r_i P_{i+1})` of consecutive elements.
+fn power_pairs<G: AffineCurve, R: RngCore>(elements: &[G], rng: &mut R) -> (G, G) {
+    let scalars = (0..elements.len() - 1)
+        .map(|_| G::ScalarField::rand(rng).to_repr())
+        .collect::<Vec<_>>();
+    let lhs = VariableBaseMSM::multi_scalar_mul(&elements[..elements.len() - 1], &scalars);
+    let rhs = VariableBaseMSM::multi_scalar_mul(&elements[1..], &scalars);
+    (lhs.into_affine(), rhs.into_affine())
+}
+
+/// Returns `true` if `g1.1 / g1.0 == g2.1 / g2.0` in the exponent.
+fn same_ratio<E: PairingEngine>(g1: (E::G1Affine, E::G1Affine), g2: (E::G2Affine, E::G2Affine)) -> bool {
+    E::pairing(g1.0, g2.1) == E::pairing(g1.1, g2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::{bls12_377::Bls12_377, bls12_381::Bls12_381};
+    use snarkvm_fields::{Field, One};
+    use snarkvm_utilities::ToBytes;
+
+    use rand::thread_rng;
+
+    type E = Bls12_377;
+    type Fr = <E as PairingEngine>::Fr;
+
+    const LOG_SIZE: usize = 3;
+
+    /// Writes a point in the native encoding.
+    fn write_native<G: AffineCurve>(point: &G, bytes: &mut Vec<u8>) {
+        point.write_le(bytes).unwrap();
+    }
+
+    /// Writes a point in the Zcash encoding, with coordinates in a degree `extension_degree` extension field.
+    fn write_zcash<G: AffineCurve>(point: &G, extension_degree: usize, bytes: &mut Vec<u8>) {
+        let native_bytes = point.to_bytes_le().unwrap();
+        let coordinate_size_in_bytes = (native_bytes.len() - 1) / 2;
+        if point.is_zero() {
+            bytes.push(ZcashEncoding::INFINITY_FLAG);
+            bytes.extend(vec![0u8; 2 * coordinate_size_in_bytes - 1]);
+            return;
+        }
+        for coordinate in native_bytes[..2 * coordinate_size_in_bytes].chunks(coordinate_size_in_bytes) {
+            for coefficient in coordinate.chunks(coordinate_size_in_bytes / extension_degree).rev() {
+                bytes.extend(coefficient.iter().rev());
+            }
+        }
+    }
+
+    /// Returns a transcript of size `2^LOG_SIZE` for a random `tau` and `alpha`, with the given point encodings.
+    fn sample_transcript<E: PairingEngine, R: RngCore>(
+        rng: &mut R,
+        write_g1: impl Fn(&E::G1Affine, &mut Vec<u8>),
+        write_g2: impl Fn(&E::G2Affine, &mut Vec<u8>),
+    ) -> Vec<u8> {
+        let size = 1usize << LOG_SIZE;
+        let tau = E::Fr::rand(rng);
+        let alpha = E::Fr::rand(rng);
+        let beta = E::Fr::rand(rng);
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        let powers_of_tau = (0..2 * size - 1)
+            .scan(E::Fr::one(), |power, _| {
+                let current = *power;
+                *power *= &tau;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+
+        let mut bytes = vec![0u8; TRANSCRIPT_HASH_SIZE_IN_BYTES];
+        for power in &powers_of_tau {
+            write_g1(&(g * *power), &mut bytes);
+        }
+        for power in &powers_of_tau[..size] {
+            write_g2(&(h * *power), &mut bytes);
+        }
+        for power in &powers_of_tau[..size] {
+            write_g1(&(g * (alpha * power)), &mut bytes);
+        }
+        for power in &powers_of_tau[..size] {
+            write_g1(&(g * (beta * power)), &mut bytes);
+        }
+        write_g2(&(h * beta), &mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn test_read_and_verify() {
+        let rng = &mut thread_rng();
+        let bytes = sample_transcript::<E, _>(rng, write_native, write_native);
+        let expected_hash = Blake2b::digest(&bytes).to_vec();
+
+        let transcript = PowersOfTau::<E>::read(
+            &bytes[..],
+            LOG_SIZE,
+            Some(&expected_hash),
+            PhantomData::<NativeEncoding>,
+        )
+        .unwrap();
+        assert_eq!(expected_hash, transcript.hash);
+        assert_eq!(2 * (1 << LOG_SIZE) - 1, transcript.tau_powers_g1.len());
+        transcript.verify(rng).unwrap();
+
+        // Ensure a mismatching hash is rejected.
+        assert!(PowersOfTau::<E>::read(&bytes[..], LOG_SIZE, Some(&[0u8; 64]), PhantomData::<NativeEncoding>).is_err());
+
+        // Ensure a truncated transcript is rejected.
+        assert!(PowersOfTau::<E>::read(&bytes[..100], LOG_SIZE, None, PhantomData::<NativeEncoding>).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_inconsistent_powers() {
+        let rng = &mut thread_rng();
+        let bytes = sample_transcript::<E, _>(rng, write_native, write_native);
+        let mut transcript = PowersOfTau::<E>::read(&bytes[..], LOG_SIZE, None, PhantomData::<NativeEncoding>).unwrap();
+
+        transcript.tau_powers_g1[3] = transcript.tau_powers_g1[3] * Fr::one().double();
+        assert!(transcript.verify(rng).is_err());
+    }
+
+    #[test]
+    fn test_to_universal_params() {
+        let rng = &mut thread_rng();
+        let bytes = sample_transcript::<E, _>(rng, write_native, write_native);
+        let transcript = PowersOfTau::<E>::read(&bytes[..], LOG_SIZE, None, PhantomData::<NativeEncoding>).unwrap();
+
+        let max_degree = (1 << LOG_SIZE) - 2;
+        let pp = transcript
+            .to_universal_params(max_degree, &KZG10DegreeBoundsConfig::MARLIN)
+            .unwrap();
+        assert_eq!(max_degree + 1, pp.powers_of_g.len());
+        assert_eq!(max_degree + 2, pp.powers_of_gamma_g.len());
+        assert_eq!(transcript.tau_powers_g2[1], pp.beta_h);
+
+        // Ensure the maximum degree is bounded by the number of powers of alpha tau.
+        assert!(transcript
+            .to_universal_params(max_degree + 1, &KZG10DegreeBoundsConfig::NONE)
+            .is_err());
+    }
+    #[test]
+    fn test_zcash_encoding() {
+        // The generator of G1 on BLS12-381, in the uncompressed Zcash encoding.
+        let x = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        let y = "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+        let mut bytes = (0..2 * 48)
+            .map(|i| u8::from_str_radix(&[x, y].concat()[2 * i..2 * i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+
+        let g = <ZcashEncoding as TranscriptEncoding<Bls12_381>>::read_g1(&bytes[..]).unwrap();
+        assert_eq!(<Bls12_381 as PairingEngine>::G1Affine::prime_subgroup_generator(), g);
+
+        // Ensure a compressed point is rejected.
+        bytes[0] |= ZcashEncoding::COMPRESSION_FLAG;
+        assert!(<ZcashEncoding as TranscriptEncoding<Bls12_381>>::read_g1(&bytes[..]).is_err());
+
+        // Ensure a point at infinity with a nonzero coordinate is rejected.
+        bytes[0] ^= ZcashEncoding::COMPRESSION_FLAG | ZcashEncoding::INFINITY_FLAG;
+        assert!(<ZcashEncoding as TranscriptEncoding<Bls12_381>>::read_g1(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_read_zcash_transcript() {
+        type E = Bls12_381;
+
+        let rng = &mut thread_rng();
+        let bytes = sample_transcript::<E, _>(
+            rng,
+            |point, bytes| write_zcash(point, 1, bytes),
+            |point, bytes| write_zcash(point, 2, bytes),
+        );
+
+        let transcript = PowersOfTau::<E>::read(&bytes[..], LOG_SIZE, None, PhantomData::<ZcashEncoding>).unwrap();
+        assert_eq!(
+            <E as PairingEngine>::G1Affine::prime_subgroup_generator(),
+            transcript.tau_powers_g1[0]
+        );
+        assert_eq!(
+            <E as PairingEngine>::G2Affine::prime_subgroup_generator(),
+            transcript.tau_powers_g2[0]
+        );
+        transcript.verify(rng).unwrap();
+
+        // Ensure the points at infinity are read.
+        let mut bytes = vec![0u8; TRANSCRIPT_HASH_SIZE_IN_BYTES];
+        write_zcash(&<E as PairingEngine>::G1Affine::zero(), 1, &mut bytes);
+        let point = <ZcashEncoding as TranscriptEncoding<E>>::read_g1(&bytes[TRANSCRIPT_HASH_SIZE_IN_BYTES..]).unwrap();
+        assert!(point.is_zero());
+    }
+}
@@ -27,6 +27,9 @@ extern crate derivative;
 #[macro_use]
 extern crate snarkvm_profiler;
 
+#[macro_use]
+extern crate tracing;
+
 pub use snarkvm_algorithms::fft::DensePolynomial as Polynomial;
 use snarkvm_fields::{Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::{
@@ -127,6 +130,61 @@ pub type QuerySet<'a, T> = BTreeSet<(String, (String, T))>;
 /// should equal `p[label].evaluate(query)`.
 pub type Evaluations<'a, F> = BTreeMap<(String, F), F>;
 
+/// `QueryEvaluations` provides the evaluations of a set of labeled polynomials or equations
+/// at the points of a `QuerySet`, without requiring them to be materialized in an `Evaluations` map.
+/// It is implemented for `Evaluations`, and for closures that compute each evaluation on demand,
+/// which avoids building a large intermediate map for protocols with many queries.
+pub trait QueryEvaluations<F: Field> {
+    /// Returns the evaluation of the polynomial or equation with the given label at `point`,
+    /// or `None` if there is no such evaluation.
+    fn evaluation(&self, label: &str, point: F) -> Option<F>;
+}
+
+impl<F: Field> QueryEvaluations<F> for BTreeMap<(String, F), F> {
+    fn evaluation(&self, label: &str, point: F) -> Option<F> {
+        self.get(&(label.to_string(), point)).copied()
+    }
+}
+
+impl<F: Field, E: Fn(&str, F) -> Option<F>> QueryEvaluations<F> for E {
+    fn evaluation(&self, label: &str, point: F) -> Option<F> {
+        self(label, point)
+    }
+}
+
+/// The evaluations of a set of linear combinations, less the constant terms of the linear combinations.
+/// This is used to check the evaluations of the linear combinations against their commitments,
+/// which do not include the constant terms.
+pub(crate) struct LCEvaluations<'a, F: Field, E: QueryEvaluations<F>> {
+    evaluations: &'a E,
+    constants: BTreeMap<String, F>,
+}
+
+impl<'a, F: Field, E: QueryEvaluations<F>> LCEvaluations<'a, F, E> {
+    /// Initializes the evaluations of the linear combinations from the given evaluations.
+    pub(crate) fn new(evaluations: &'a E) -> Self {
+        Self {
+            evaluations,
+            constants: BTreeMap::new(),
+        }
+    }
+
+    /// Subtracts the given constant term from the evaluations of the given linear combination.
+    pub(crate) fn subtract_constant(&mut self, lc_label: &str, constant: F) {
+        *self.constants.entry(lc_label.to_string()).or_insert_with(F::zero) += constant;
+    }
+}
+
+impl<'a, F: Field, E: QueryEvaluations<F>> QueryEvaluations<F> for LCEvaluations<'a, F, E> {
+    fn evaluation(&self, label: &str, point: F) -> Option<F> {
+        let evaluation = self.evaluations.evaluation(label, point)?;
+        match self.constants.get(label) {
+            Some(constant) => Some(evaluation - constant),
+            None => Some(evaluation),
+        }
+    }
+}
+
 /// A proof of satisfaction of linear combinations.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchLCProof<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> {
@@ -348,7 +406,7 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
         vk: &Self::VerifierKey,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<F>,
-        evaluations: &Evaluations<F>,
+        evaluations: &impl QueryEvaluations<F>,
         proof: &Self::BatchProof,
         opening_challenge: F,
         rng: &mut R,
@@ -379,14 +437,12 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
                     label: label.to_string(),
                 })?;
 
-                let v_i = evaluations
-                    .get(&(label.clone(), *query))
-                    .ok_or(Error::MissingEvaluation {
-                        label: label.to_string(),
-                    })?;
+                let v_i = evaluations.evaluation(label, *query).ok_or(Error::MissingEvaluation {
+                    label: label.to_string(),
+                })?;
 
                 comms.push(commitment);
-                values.push(*v_i);
+                values.push(v_i);
             }
 
             let proof_time = start_timer!(|| "Checking per-query proof");
@@ -441,7 +497,7 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<F>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         eqn_query_set: &QuerySet<F>,
-        eqn_evaluations: &Evaluations<F>,
+        eqn_evaluations: &impl QueryEvaluations<F>,
         proof: &BatchLCProof<F, CF, Self>,
         opening_challenge: F,
         rng: &mut R,
@@ -466,8 +522,8 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
 
         for &(ref lc_label, (_, point)) in eqn_query_set {
             if let Some(lc) = lc_s.get(lc_label) {
-                let claimed_rhs = *eqn_evaluations
-                    .get(&(lc_label.clone(), point))
+                let claimed_rhs = eqn_evaluations
+                    .evaluation(lc_label, point)
                     .ok_or(Error::MissingEvaluation {
                         label: lc_label.to_string(),
                     })?;
@@ -508,6 +564,159 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
         Ok(true)
     }
 
+    /// On input a list of labeled polynomials and a query point, outputs a proof of evaluation
+    /// of the polynomials at the query point, where the `i`-th term of the combined polynomials
+    /// is scaled by `opening_challenges(i)`.
+    fn open_individual_opening_challenges<'a>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: F,
+        opening_challenges: &dyn Fn(u64) -> F,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+    ) -> Result<Self::Proof, Error>
+    where
+        Self::Randomness: 'a,
+        Self::Commitment: 'a;
+
+    /// On input a list of labeled polynomials and a query set, outputs a proof of evaluation
+    /// of the polynomials at the points in the query set, with individual opening challenges.
+    fn batch_open_individual_opening_challenges<'a>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        opening_challenges: &dyn Fn(u64) -> F,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+    ) -> Result<Self::BatchProof, Error>
+    where
+        Self::Randomness: 'a,
+        Self::Commitment: 'a,
+    {
+        let poly_rand_comm: BTreeMap<_, _> = labeled_polynomials
+            .into_iter()
+            .zip(rands)
+            .zip(commitments.into_iter())
+            .map(|((poly, r), comm)| (poly.label(), (poly, r, comm)))
+            .collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at query set of size {}",
+            poly_rand_comm.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map
+                .entry(point_name)
+                .or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        // As a result of BTreeMap, the query is sorted by names of the points.
+
+        let mut proofs = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (query, labels)) in query_to_labels_map.into_iter() {
+            let mut query_polys = Vec::with_capacity(labels.len());
+            let mut query_rands = Vec::with_capacity(labels.len());
+            let mut query_comms = Vec::with_capacity(labels.len());
+
+            for label in labels {
+                let (polynomial, rand, comm) = poly_rand_comm.get(label).ok_or(Error::MissingPolynomial {
+                    label: label.to_string(),
+                })?;
+
+                query_polys.push(*polynomial);
+                query_rands.push(*rand);
+                query_comms.push(*comm);
+            }
+
+            let proof_time = start_timer!(|| "Creating proof");
+            let proof = Self::open_individual_opening_challenges(
+                ck,
+                query_polys,
+                query_comms,
+                *query,
+                opening_challenges,
+                query_rands,
+            )?;
+
+            end_timer!(proof_time);
+
+            proofs.push(proof);
+        }
+        end_timer!(open_time);
+
+        Ok(proofs.into())
+    }
+
+    /// Verifies that `values` are the evaluations at `point` of the polynomials
+    /// committed inside `commitments`, with individual opening challenges.
+    fn check_individual_opening_challenges<'a>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: F,
+        values: impl IntoIterator<Item = F>,
+        proof: &Self::Proof,
+        opening_challenges: &dyn Fn(u64) -> F,
+    ) -> Result<bool, Error>
+    where
+        Self::Commitment: 'a;
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `labeled_commitments`, with individual opening challenges.
+    fn batch_check_individual_opening_challenges<'a, R: RngCore>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        evaluations: &impl QueryEvaluations<F>,
+        proof: &Self::BatchProof,
+        opening_challenges: &dyn Fn(u64) -> F,
+        _rng: &mut R,
+    ) -> Result<bool, Error>
+    where
+        Self::Commitment: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label(), c)).collect();
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map
+                .entry(point_name)
+                .or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        // Implicit assumption: proofs are order in same manner as queries in
+        // `query_to_labels_map`.
+        let proofs: Vec<_> = proof.clone().into();
+        assert_eq!(proofs.len(), query_to_labels_map.len());
+
+        let mut result = true;
+        for ((_point_name, (query, labels)), proof) in query_to_labels_map.into_iter().zip(proofs) {
+            let mut comms: Vec<&'_ LabeledCommitment<_>> = Vec::with_capacity(labels.len());
+            let mut values = Vec::with_capacity(labels.len());
+            for label in labels.into_iter() {
+                let commitment = commitments.get(label).ok_or(Error::MissingPolynomial {
+                    label: label.to_string(),
+                })?;
+
+                let v_i = evaluations.evaluation(label, *query).ok_or(Error::MissingEvaluation {
+                    label: label.to_string(),
+                })?;
+
+                comms.push(commitment);
+                values.push(v_i);
+            }
+
+            let proof_time = start_timer!(|| "Checking per-query proof");
+            result &= Self::check_individual_opening_challenges(vk, comms, *query, values, &proof, opening_challenges)?;
+            end_timer!(proof_time);
+        }
+        Ok(result)
+    }
+
     /// On input a list of polynomials, linear combinations of those polynomials,
     /// and a query set, `open_combination` outputs a proof of evaluation of
     /// the combinations at the points in the query set.
@@ -530,7 +739,7 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<F>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         eqn_query_set: &QuerySet<F>,
-        eqn_evaluations: &Evaluations<F>,
+        eqn_evaluations: &impl QueryEvaluations<F>,
         proof: &BatchLCProof<F, CF, Self>,
         opening_challenges: &dyn Fn(u64) -> F,
         rng: &mut R,
@@ -963,6 +1172,19 @@ pub mod tests {
             }
             assert!(result, "proof was incorrect, equations: {:#?}", linear_combinations);
 
+            // Ensure the proof also checks against evaluations that are provided on demand.
+            let evaluations = |label: &str, point: F| values.evaluation(label, point);
+            assert!(PC::check_combinations(
+                &vk,
+                &linear_combinations,
+                &comms,
+                &query_set,
+                &evaluations,
+                &proof,
+                opening_challenge,
+                rng,
+            )?);
+
             test_components.push(TestComponents {
                 verification_key: vk,
                 commitments: comms,
@@ -234,12 +234,14 @@ pub struct Commitment<E: PairingEngine> {
 }
 impl_bytes!(Commitment);
 
+/// The version 1 encoding of a commitment is the encoding of the commitment,
+/// followed by the encoding of the shifted commitment, if it exists.
 impl<E: PairingEngine> ToMinimalBits for Commitment<E> {
-    fn to_minimal_bits(&self) -> Vec<bool> {
-        let comm_bits = self.comm.to_minimal_bits();
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
+        let comm_bits = self.comm.to_minimal_bits_v1();
 
         if let Some(shifted_comm) = &self.shifted_comm {
-            [comm_bits, shifted_comm.to_minimal_bits()].concat()
+            [comm_bits, shifted_comm.to_minimal_bits_v1()].concat()
         } else {
             comm_bits
         }
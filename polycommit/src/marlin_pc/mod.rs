@@ -20,7 +20,7 @@ use crate::{
     BTreeSet,
     BatchLCProof,
     Error,
-    Evaluations,
+    LCEvaluations,
     LabeledCommitment,
     LabeledPolynomial,
     LinearCombination,
@@ -29,6 +29,7 @@ use crate::{
     PCUniversalParams,
     Polynomial,
     PolynomialCommitment,
+    QueryEvaluations,
     QuerySet,
     String,
     ToOwned,
@@ -208,6 +209,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(Vec<LabeledCommitment<Self::Commitment>>, Vec<Self::Randomness>), Error> {
+        let _span = debug_span!("MarlinKZG10::commit").entered();
         let rng = &mut crate::optional_rng::OptionalRng(rng);
         let commit_time = start_timer!(|| "Committing to polynomials");
 
@@ -378,7 +380,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
         vk: &Self::VerifierKey,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        values: &Evaluations<E::Fr>,
+        values: &impl QueryEvaluations<E::Fr>,
         proof: &Self::BatchProof,
         opening_challenge: E::Fr,
         rng: &mut R,
@@ -411,12 +413,12 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
                 let degree_bound = commitment.degree_bound();
                 assert_eq!(degree_bound.is_some(), commitment.commitment().shifted_comm.is_some());
 
-                let v_i = values.get(&(label.clone(), *query)).ok_or(Error::MissingEvaluation {
+                let v_i = values.evaluation(label, *query).ok_or(Error::MissingEvaluation {
                     label: label.to_string(),
                 })?;
 
                 comms_to_combine.push(*commitment);
-                values_to_combine.push(*v_i);
+                values_to_combine.push(v_i);
             }
             let (c, v) =
                 Self::accumulate_commitments_and_values(vk, comms_to_combine, values_to_combine, opening_challenge)?;
@@ -539,7 +541,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
         lc_s: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        evaluations: &Evaluations<E::Fr>,
+        evaluations: &impl QueryEvaluations<E::Fr>,
         proof: &BatchLCProof<E::Fr, E::Fq, Self>,
         opening_challenge: E::Fr,
         rng: &mut R,
@@ -555,7 +557,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
 
         let mut lc_commitments = Vec::new();
         let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
+        let mut evaluations = LCEvaluations::new(evaluations);
 
         let lc_processing_time = start_timer!(|| "Combining commitments");
         for lc in lc_s {
@@ -567,11 +569,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
 
             for (coeff, label) in lc.iter() {
                 if label.is_one() {
-                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
-                        if label == &lc_label {
-                            **eval -= coeff;
-                        }
-                    }
+                    evaluations.subtract_constant(&lc_label, *coeff);
                 } else {
                     let label: String = label.to_owned().try_into().unwrap();
                     let cur_comm = label_comm_map.get(&label).ok_or(Error::MissingPolynomial {
@@ -704,7 +702,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        evaluations: &Evaluations<E::Fr>,
+        evaluations: &impl QueryEvaluations<E::Fr>,
         proof: &BatchLCProof<E::Fr, E::Fq, Self>,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
         rng: &mut R,
@@ -720,7 +718,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
 
         let mut lc_commitments = Vec::new();
         let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
+        let mut evaluations = LCEvaluations::new(evaluations);
 
         let lc_processing_time = start_timer!(|| "Combining commitments");
         for lc in linear_combinations {
@@ -732,11 +730,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
 
             for (coeff, label) in lc.iter() {
                 if label.is_one() {
-                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
-                        if label == &lc_label {
-                            **eval -= coeff;
-                        }
-                    }
+                    evaluations.subtract_constant(&lc_label, *coeff);
                 } else {
                     let label: &String = label.try_into().unwrap();
                     let &cur_comm = label_comm_map.get(label).ok_or(Error::MissingPolynomial {
@@ -777,23 +771,19 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
             rng,
         )
     }
-}
 
-impl<E: PairingEngine> MarlinKZG10<E> {
     /// On input a polynomial `p` and a point `point`, outputs a proof for the same.
     fn open_individual_opening_challenges<'a>(
-        ck: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::CommitterKey,
+        ck: &Self::CommitterKey,
         labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
-        _commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        point: &'a E::Fr,
+        _commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: E::Fr,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
-        rands: impl IntoIterator<Item = &'a <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness>,
-    ) -> Result<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Proof, Error>
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+    ) -> Result<Self::Proof, Error>
     where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness: 'a,
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
+        Self::Randomness: 'a,
+        Self::Commitment: 'a,
     {
         let mut p = Polynomial::zero();
         let mut r = kzg10::Randomness::empty();
@@ -830,7 +820,7 @@ impl<E: PairingEngine> MarlinKZG10<E> {
                 enforce_degree_bound = true;
                 let shifted_rand = rand.shifted_rand.as_ref().unwrap();
                 let (witness, shifted_rand_witness) =
-                    kzg10::KZG10::<E>::compute_witness_polynomial(polynomial.polynomial(), *point, shifted_rand)?;
+                    kzg10::KZG10::<E>::compute_witness_polynomial(polynomial.polynomial(), point, shifted_rand)?;
 
                 let challenge_j_1 = opening_challenges(opening_challenge_counter);
                 opening_challenge_counter += 1;
@@ -845,7 +835,7 @@ impl<E: PairingEngine> MarlinKZG10<E> {
             }
         }
         let proof_time = start_timer!(|| "Creating proof for unshifted polynomials");
-        let proof = kzg10::KZG10::open(&ck.powers(), &p, *point, &r)?;
+        let proof = kzg10::KZG10::open(&ck.powers(), &p, point, &r)?;
         let mut w = proof.w.into_projective();
         let mut random_v = proof.random_v;
         end_timer!(proof_time);
@@ -854,7 +844,7 @@ impl<E: PairingEngine> MarlinKZG10<E> {
             let proof_time = start_timer!(|| "Creating proof for shifted polynomials");
             let shifted_proof = kzg10::KZG10::open_with_witness_polynomial(
                 &ck.shifted_powers(None).unwrap(),
-                *point,
+                point,
                 &shifted_r,
                 &shifted_w,
                 Some(&shifted_r_witness),
@@ -873,97 +863,18 @@ impl<E: PairingEngine> MarlinKZG10<E> {
         })
     }
 
-    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
-    /// of the polynomials at the points in the query set.
-    fn batch_open_individual_opening_challenges<'a>(
-        ck: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::CommitterKey,
-        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
-        commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        query_set: &QuerySet<E::Fr>,
-        opening_challenges: &dyn Fn(u64) -> E::Fr,
-        rands: impl IntoIterator<Item = &'a <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness>,
-    ) -> Result<<Self as PolynomialCommitment<E::Fr, E::Fq>>::BatchProof, Error>
-    where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness: 'a,
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
-    {
-        let poly_rand_comm: BTreeMap<_, _> = labeled_polynomials
-            .into_iter()
-            .zip(rands)
-            .zip(commitments.into_iter())
-            .map(|((poly, r), comm)| (poly.label(), (poly, r, comm)))
-            .collect();
-
-        let open_time = start_timer!(|| format!(
-            "Opening {} polynomials at query set of size {}",
-            poly_rand_comm.len(),
-            query_set.len(),
-        ));
-
-        let mut query_to_labels_map = BTreeMap::new();
-
-        for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map
-                .entry(point_name)
-                .or_insert((point, BTreeSet::new()));
-            labels.1.insert(label);
-        }
-
-        // As a result of BTreeMap, the query is sorted by names of the points.
-
-        let mut proofs = Vec::new();
-        for (_point_name, (query, labels)) in query_to_labels_map.into_iter() {
-            let mut query_polys: Vec<&'a LabeledPolynomial<_>> = Vec::new();
-            let mut query_rands: Vec<&'a <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness> = Vec::new();
-            let mut query_comms: Vec<&'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>> =
-                Vec::new();
-
-            for label in labels {
-                let (polynomial, rand, comm) = poly_rand_comm.get(&label).ok_or(Error::MissingPolynomial {
-                    label: label.to_string(),
-                })?;
-
-                query_polys.push(polynomial);
-                query_rands.push(rand);
-                query_comms.push(comm);
-            }
-
-            let proof_time = start_timer!(|| "Creating proof");
-            let proof = Self::open_individual_opening_challenges(
-                ck,
-                query_polys,
-                query_comms,
-                query,
-                opening_challenges,
-                query_rands,
-            )?;
-
-            end_timer!(proof_time);
-
-            proofs.push(proof);
-        }
-        end_timer!(open_time);
-
-        Ok(proofs)
-    }
-
     /// Verifies that `value` is the evaluation at `x` of the polynomial
     /// committed inside `comm`.
-    #[allow(dead_code)]
     fn check_individual_opening_challenges<'a>(
-        vk: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::VerifierKey,
-        commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        point: &'a E::Fr,
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: E::Fr,
         values: impl IntoIterator<Item = E::Fr>,
-        proof: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::Proof,
+        proof: &Self::Proof,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
     ) -> Result<bool, Error>
     where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
+        Self::Commitment: 'a,
     {
         let check_time = start_timer!(|| "Checking evaluations");
         let (combined_comm, combined_value) = Self::accumulate_commitments_and_values_individual_opening_challenges(
@@ -973,25 +884,23 @@ impl<E: PairingEngine> MarlinKZG10<E> {
             opening_challenges,
         )?;
         let combined_comm = kzg10::Commitment(combined_comm.into());
-        let result = kzg10::KZG10::check(&vk.vk, &combined_comm, *point, combined_value, proof)?;
+        let result = kzg10::KZG10::check(&vk.vk, &combined_comm, point, combined_value, proof)?;
         end_timer!(check_time);
         Ok(result)
     }
 
     /// batch_check but with individual challenges
     fn batch_check_individual_opening_challenges<'a, R: RngCore>(
-        vk: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::VerifierKey,
-        commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        values: &Evaluations<E::Fr>,
-        proof: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::BatchProof,
+        values: &impl QueryEvaluations<E::Fr>,
+        proof: &Self::BatchProof,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
         rng: &mut R,
     ) -> Result<bool, Error>
     where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
+        Self::Commitment: 'a,
     {
         let (combined_comms, combined_queries, combined_evals) =
             Self::combine_and_normalize(commitments, query_set, values, opening_challenges, vk)?;
@@ -1057,7 +966,7 @@ impl<E: PairingEngine> MarlinKZG10<E> {
     fn combine_and_normalize<'a>(
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         query_set: &QuerySet<E::Fr>,
-        evaluations: &Evaluations<E::Fr>,
+        evaluations: &impl QueryEvaluations<E::Fr>,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
         vk: &VerifierKey<E>,
     ) -> Result<(Vec<kzg10::Commitment<E>>, Vec<E::Fr>, Vec<E::Fr>), Error>
@@ -1088,14 +997,12 @@ impl<E: PairingEngine> MarlinKZG10<E> {
                 let degree_bound = commitment.degree_bound();
                 assert_eq!(degree_bound.is_some(), commitment.commitment().shifted_comm.is_some());
 
-                let v_i = evaluations
-                    .get(&(label.clone(), *point))
-                    .ok_or(Error::MissingEvaluation {
-                        label: label.to_string(),
-                    })?;
+                let v_i = evaluations.evaluation(label, *point).ok_or(Error::MissingEvaluation {
+                    label: label.to_string(),
+                })?;
 
                 comms_to_combine.push(commitment);
-                values_to_combine.push(*v_i);
+                values_to_combine.push(v_i);
             }
 
             let (c, v) = Self::accumulate_commitments_and_values_individual_opening_challenges(
@@ -1207,80 +1114,92 @@ mod tests {
     #![allow(non_camel_case_types)]
 
     use super::MarlinKZG10;
-    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_curves::{bls12_377::Bls12_377, bls12_381::Bls12_381};
 
     type PC<E> = MarlinKZG10<E>;
     type PC_Bls12_377 = PC<Bls12_377>;
+    type PC_Bls12_381 = PC<Bls12_381>;
 
     #[test]
     fn single_poly_test() {
         use crate::tests::*;
         single_poly_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        single_poly_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn quadratic_poly_degree_bound_multiple_queries_test() {
         use crate::tests::*;
         quadratic_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        quadratic_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn linear_poly_degree_bound_test() {
         use crate::tests::*;
         linear_poly_degree_bound_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        linear_poly_degree_bound_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn single_poly_degree_bound_test() {
         use crate::tests::*;
         single_poly_degree_bound_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        single_poly_degree_bound_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn single_poly_degree_bound_multiple_queries_test() {
         use crate::tests::*;
         single_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        single_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn two_polys_degree_bound_single_query_test() {
         use crate::tests::*;
         two_polys_degree_bound_single_query_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        two_polys_degree_bound_single_query_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn full_end_to_end_test() {
         use crate::tests::*;
         full_end_to_end_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        full_end_to_end_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn single_equation_test() {
         use crate::tests::*;
         single_equation_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        single_equation_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn two_equation_test() {
         use crate::tests::*;
         two_equation_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        two_equation_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn two_equation_degree_bound_test() {
         use crate::tests::*;
         two_equation_degree_bound_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        two_equation_degree_bound_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn full_end_to_end_equation_test() {
         use crate::tests::*;
         full_end_to_end_equation_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        full_end_to_end_equation_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
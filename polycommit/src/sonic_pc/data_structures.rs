@@ -14,14 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{impl_bytes, kzg10, BTreeMap, PCCommitterKey, PCVerifierKey, Vec};
-use snarkvm_algorithms::{crh::sha256::sha256, Prepare};
+use crate::{impl_bytes, kzg10, BTreeMap, Error, PCCommitterKey, PCVerifierKey, Vec};
+use snarkvm_algorithms::{crh::sha256::sha256, fft::EvaluationDomain, Prepare};
 use snarkvm_curves::{
     traits::{PairingCurve, PairingEngine},
     Group,
 };
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
-use snarkvm_utilities::{error, errors::SerializationError, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{
+    error,
+    errors::SerializationError,
+    serialize::*,
+    vec_with_bounded_capacity,
+    FromBytes,
+    ToBytes,
+};
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 pub type UniversalParams<E> = kzg10::UniversalParams<E>;
@@ -82,7 +89,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         // Deserialize `powers`.
         let powers_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers = Vec::with_capacity(powers_len as usize);
+        let mut powers = vec_with_bounded_capacity(powers_len as usize);
         for _ in 0..powers_len {
             let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
             powers.push(power);
@@ -90,7 +97,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
 
         // Deserialize `powers_of_gamma_g`.
         let powers_of_gamma_g_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_gamma_g = Vec::with_capacity(powers_of_gamma_g_len as usize);
+        let mut powers_of_gamma_g = vec_with_bounded_capacity(powers_of_gamma_g_len as usize);
         for _ in 0..powers_of_gamma_g_len {
             let powers_of_g: E::G1Affine = FromBytes::read_le(&mut reader)?;
             powers_of_gamma_g.push(powers_of_g);
@@ -101,7 +108,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let shifted_powers = match has_shifted_powers {
             true => {
                 let shifted_powers_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut shifted_powers = Vec::with_capacity(shifted_powers_len as usize);
+                let mut shifted_powers = vec_with_bounded_capacity(shifted_powers_len as usize);
                 for _ in 0..shifted_powers_len {
                     let shifted_power: E::G1Affine = FromBytes::read_le(&mut reader)?;
                     shifted_powers.push(shifted_power);
@@ -122,7 +129,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                     let key: u32 = FromBytes::read_le(&mut reader)?;
 
                     let value_len: u32 = FromBytes::read_le(&mut reader)?;
-                    let mut value = Vec::with_capacity(value_len as usize);
+                    let mut value = vec_with_bounded_capacity(value_len as usize);
                     for _ in 0..value_len {
                         let val: E::G1Affine = FromBytes::read_le(&mut reader)?;
                         value.push(val);
@@ -141,7 +148,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let enforced_degree_bounds = match has_enforced_degree_bounds {
             true => {
                 let enforced_degree_bounds_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut enforced_degree_bounds = Vec::with_capacity(enforced_degree_bounds_len as usize);
+                let mut enforced_degree_bounds = vec_with_bounded_capacity(enforced_degree_bounds_len as usize);
                 for _ in 0..enforced_degree_bounds_len {
                     let enforced_degree_bound: u32 = FromBytes::read_le(&mut reader)?;
                     enforced_degree_bounds.push(enforced_degree_bound as usize);
@@ -296,6 +303,11 @@ impl<E: PairingEngine> CommitterKey<E> {
         }
     }
 
+    /// Obtain the Lagrange basis of the given domain, for committing to polynomials in evaluation form.
+    pub fn lagrange_basis(&self, domain: &EvaluationDomain<E::Fr>) -> Result<Vec<E::G1Affine>, Error> {
+        self.powers().lagrange_basis(domain)
+    }
+
     /// Obtain powers for committing to shifted polynomials.
     pub fn shifted_powers(&self, degree_bound: impl Into<Option<usize>>) -> Option<kzg10::Powers<E>> {
         match (&self.shifted_powers, &self.shifted_powers_of_gamma_g) {
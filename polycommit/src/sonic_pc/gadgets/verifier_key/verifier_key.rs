@@ -16,7 +16,7 @@
 
 use core::borrow::Borrow;
 
-use snarkvm_curves::{AffineCurve, Group, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_curves::{AffineCurve, Group, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
     bits::{Boolean, ToBytesGadget},
@@ -395,18 +395,10 @@ where
             }
         }
 
-        let prepared_h = self
-            .h
-            .get_value()
-            .map(|h| h.into_affine().prepare())
-            .ok_or(SynthesisError::AssignmentMissing);
-        let prepared_beta_h = self
-            .beta_h
-            .get_value()
-            .map(|beta_h| beta_h.into_affine().prepare())
-            .ok_or(SynthesisError::AssignmentMissing);
-        let prepared_h = PG::G2PreparedGadget::alloc_constant(cs.ns(|| "prepared_h"), || prepared_h)?;
-        let prepared_beta_h = PG::G2PreparedGadget::alloc_constant(cs.ns(|| "prepared_beta_h"), || prepared_beta_h)?;
+        let h = self.h.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        let beta_h = self.beta_h.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        let prepared_h = PG::prepare_g2_constant(cs.ns(|| "prepared_h"), &h.into_affine())?;
+        let prepared_beta_h = PG::prepare_g2_constant(cs.ns(|| "prepared_beta_h"), &beta_h.into_affine())?;
 
         Ok(PreparedVerifierKeyVar::<TargetCurve, BaseCurve, PG> {
             prepared_g,
@@ -20,7 +20,7 @@ use crate::{
     BTreeSet,
     BatchLCProof,
     Error,
-    Evaluations,
+    LCEvaluations,
     LabeledCommitment,
     LabeledPolynomial,
     LinearCombination,
@@ -29,6 +29,7 @@ use crate::{
     PCUniversalParams,
     Polynomial,
     PolynomialCommitment,
+    QueryEvaluations,
     QuerySet,
     String,
     ToOwned,
@@ -92,35 +93,56 @@ impl<E: PairingEngine> SonicKZG10<E> {
         let comms = E::G1Projective::batch_normalization_into_affine(comms);
         comms.into_iter().map(|c| Commitment { 0: c })
     }
-}
 
-impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
-    type BatchProof = Vec<Self::Proof>;
-    type Commitment = Commitment<E>;
-    type CommitterKey = CommitterKey<E>;
-    type PreparedCommitment = PreparedCommitment<E>;
-    type PreparedVerifierKey = PreparedVerifierKey<E>;
-    type Proof = kzg10::Proof<E>;
-    type Randomness = Randomness<E>;
-    type UniversalParams = UniversalParams<E>;
-    type VerifierKey = VerifierKey<E>;
-
-    fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self::UniversalParams, Error> {
-        kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true, rng).map_err(Into::into)
+    /// Specializes the public parameters for polynomials up to the given `supported_degree`,
+    /// and for the given `enforced_degree_bounds`, which must be admitted by the policy.
+    ///
+    /// Unlike `trim`, the verifier key only contains the powers of `h` for the degree bounds
+    /// admitted by the policy, rather than for every degree bound in the universal parameters.
+    pub fn trim_with_degree_bounds_config(
+        pp: &UniversalParams<E>,
+        supported_degree: usize,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+        degree_bounds_config: &kzg10::KZG10DegreeBoundsConfig,
+    ) -> Result<(CommitterKey<E>, VerifierKey<E>), Error> {
+        let supported_degree_bounds = degree_bounds_config.get_list::<E::Fr>(pp.max_degree());
+        Self::trim_inner(
+            pp,
+            supported_degree,
+            supported_hiding_bound,
+            enforced_degree_bounds,
+            Some(&supported_degree_bounds),
+        )
     }
 
-    fn trim(
-        pp: &Self::UniversalParams,
+    /// Specializes the public parameters, restricting the verifier key to the
+    /// `supported_degree_bounds`, if they are given.
+    fn trim_inner(
+        pp: &UniversalParams<E>,
         supported_degree: usize,
         supported_hiding_bound: usize,
         enforced_degree_bounds: Option<&[usize]>,
-    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Error> {
+        supported_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<E>, VerifierKey<E>), Error> {
         let trim_time = start_timer!(|| "Trimming public parameters");
         let max_degree = pp.max_degree();
         if supported_degree > max_degree {
             return Err(Error::TrimmingDegreeTooLarge);
         }
 
+        // Ensure the enforced degree bounds are supported.
+        if let (Some(enforced_degree_bounds), Some(supported_degree_bounds)) =
+            (enforced_degree_bounds, supported_degree_bounds)
+        {
+            if let Some(bound) = enforced_degree_bounds
+                .iter()
+                .find(|bound| !supported_degree_bounds.contains(*bound))
+            {
+                return Err(Error::UnsupportedDegreeBound(*bound));
+            }
+        }
+
         let enforced_degree_bounds = enforced_degree_bounds.map(|bounds| {
             let mut v = bounds.to_vec();
             v.sort_unstable();
@@ -197,6 +219,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
             Some(
                 pp.inverse_neg_powers_of_h
                     .iter()
+                    .filter(|(d, _)| supported_degree_bounds.map_or(true, |bounds| bounds.contains(*d)))
                     .map(|(d, affine)| (*d, *affine))
                     .collect::<Vec<(usize, E::G2Affine)>>(),
             )
@@ -232,6 +255,37 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
         end_timer!(trim_time);
         Ok((ck, vk))
     }
+}
+
+impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
+    type BatchProof = Vec<Self::Proof>;
+    type Commitment = Commitment<E>;
+    type CommitterKey = CommitterKey<E>;
+    type PreparedCommitment = PreparedCommitment<E>;
+    type PreparedVerifierKey = PreparedVerifierKey<E>;
+    type Proof = kzg10::Proof<E>;
+    type Randomness = Randomness<E>;
+    type UniversalParams = UniversalParams<E>;
+    type VerifierKey = VerifierKey<E>;
+
+    fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self::UniversalParams, Error> {
+        kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true, rng).map_err(Into::into)
+    }
+
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Error> {
+        Self::trim_inner(
+            pp,
+            supported_degree,
+            supported_hiding_bound,
+            enforced_degree_bounds,
+            None,
+        )
+    }
 
     /// Outputs a commitment to `polynomial`.
     #[allow(clippy::type_complexity)]
@@ -241,6 +295,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(Vec<LabeledCommitment<Self::Commitment>>, Vec<Self::Randomness>), Error> {
+        let _span = debug_span!("SonicKZG10::commit").entered();
         let rng = &mut crate::optional_rng::OptionalRng(rng);
         let commit_time = start_timer!(|| "Committing to polynomials");
         let mut labeled_comms: Vec<LabeledCommitment<Self::Commitment>> = Vec::new();
@@ -368,7 +423,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
         vk: &Self::VerifierKey,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        values: &Evaluations<E::Fr>,
+        values: &impl QueryEvaluations<E::Fr>,
         proof: &Self::BatchProof,
         opening_challenge: E::Fr,
         rng: &mut R,
@@ -402,12 +457,12 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
                     label: label.to_string(),
                 })?;
 
-                let v_i = values.get(&(label.clone(), *query)).ok_or(Error::MissingEvaluation {
+                let v_i = values.evaluation(label, *query).ok_or(Error::MissingEvaluation {
                     label: label.to_string(),
                 })?;
 
                 comms_to_combine.push(commitment);
-                values_to_combine.push(*v_i);
+                values_to_combine.push(v_i);
             }
 
             Self::accumulate_elems(
@@ -525,7 +580,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
         lc_s: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        evaluations: &Evaluations<E::Fr>,
+        evaluations: &impl QueryEvaluations<E::Fr>,
         proof: &BatchLCProof<E::Fr, E::Fq, Self>,
         opening_challenge: E::Fr,
         rng: &mut R,
@@ -541,7 +596,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
 
         let mut lc_commitments = Vec::new();
         let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
+        let mut evaluations = LCEvaluations::new(evaluations);
         for lc in lc_s {
             let lc_label = lc.label().clone();
             let num_polys = lc.len();
@@ -551,11 +606,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
 
             for (coeff, label) in lc.iter() {
                 if label.is_one() {
-                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
-                        if label == &lc_label {
-                            **eval -= coeff;
-                        }
-                    }
+                    evaluations.subtract_constant(&lc_label, *coeff);
                 } else {
                     let label: String = label.to_owned().try_into().unwrap();
                     let cur_comm = label_comm_map.get(&label).ok_or(Error::MissingPolynomial {
@@ -688,7 +739,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
         query_set: &QuerySet<E::Fr>,
-        evaluations: &Evaluations<E::Fr>,
+        evaluations: &impl QueryEvaluations<E::Fr>,
         proof: &BatchLCProof<E::Fr, E::Fq, Self>,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
         rng: &mut R,
@@ -704,7 +755,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
 
         let mut lc_commitments = Vec::new();
         let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
+        let mut evaluations = LCEvaluations::new(evaluations);
 
         let lc_processing_time = start_timer!(|| "Combining commitments");
         for lc in linear_combinations {
@@ -716,11 +767,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
 
             for (coeff, label) in lc.iter() {
                 if label.is_one() {
-                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
-                        if label == &lc_label {
-                            **eval -= coeff;
-                        }
-                    }
+                    evaluations.subtract_constant(&lc_label, *coeff);
                 } else {
                     let label: &String = label.try_into().unwrap();
                     let &cur_comm = label_comm_map.get(label).ok_or(Error::MissingPolynomial {
@@ -761,22 +808,19 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
             rng,
         )
     }
-}
 
-impl<E: PairingEngine> SonicKZG10<E> {
+    /// On input a list of polynomials and a point `point`, outputs a proof for the same.
     fn open_individual_opening_challenges<'a>(
-        ck: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::CommitterKey,
+        ck: &Self::CommitterKey,
         labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
-        _commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        point: &'a E::Fr,
+        _commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: E::Fr,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
-        rands: impl IntoIterator<Item = &'a <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness>,
-    ) -> Result<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Proof, Error>
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+    ) -> Result<Self::Proof, Error>
     where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness: 'a,
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
+        Self::Randomness: 'a,
+        Self::Commitment: 'a,
     {
         let mut combined_polynomial = Polynomial::zero();
         let mut combined_rand = kzg10::Randomness::empty();
@@ -797,100 +841,24 @@ impl<E: PairingEngine> SonicKZG10<E> {
         }
 
         let proof_time = start_timer!(|| "Creating proof for polynomials");
-        let proof = kzg10::KZG10::open(&ck.powers(), &combined_polynomial, *point, &combined_rand)?;
+        let proof = kzg10::KZG10::open(&ck.powers(), &combined_polynomial, point, &combined_rand)?;
         end_timer!(proof_time);
 
         Ok(proof)
     }
 
-    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
-    /// of the polynomials at the points in the query set.
-    fn batch_open_individual_opening_challenges<'a>(
-        ck: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::CommitterKey,
-        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
-        commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        query_set: &QuerySet<E::Fr>,
-        opening_challenges: &dyn Fn(u64) -> E::Fr,
-        rands: impl IntoIterator<Item = &'a <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness>,
-    ) -> Result<<Self as PolynomialCommitment<E::Fr, E::Fq>>::BatchProof, Error>
-    where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness: 'a,
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
-    {
-        let poly_rand_comm: BTreeMap<_, _> = labeled_polynomials
-            .into_iter()
-            .zip(rands)
-            .zip(commitments.into_iter())
-            .map(|((poly, r), comm)| (poly.label(), (poly, r, comm)))
-            .collect();
-
-        let open_time = start_timer!(|| format!(
-            "Opening {} polynomials at query set of size {}",
-            poly_rand_comm.len(),
-            query_set.len(),
-        ));
-
-        let mut query_to_labels_map = BTreeMap::new();
-
-        for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map
-                .entry(point_name)
-                .or_insert((point, BTreeSet::new()));
-            labels.1.insert(label);
-        }
-
-        let mut proofs = Vec::new();
-        for (_query_name, (query, labels)) in query_to_labels_map.into_iter() {
-            let mut query_polys: Vec<&'a LabeledPolynomial<_>> = Vec::new();
-            let mut query_rands: Vec<&'a <Self as PolynomialCommitment<E::Fr, E::Fq>>::Randomness> = Vec::new();
-            let mut query_comms: Vec<&'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>> =
-                Vec::new();
-
-            for label in labels {
-                let (polynomial, rand, comm) = poly_rand_comm.get(label).ok_or(Error::MissingPolynomial {
-                    label: label.to_string(),
-                })?;
-
-                query_polys.push(polynomial);
-                query_rands.push(rand);
-                query_comms.push(comm);
-            }
-
-            let proof_time = start_timer!(|| "Creating proof");
-            let proof = Self::open_individual_opening_challenges(
-                ck,
-                query_polys,
-                query_comms,
-                query,
-                opening_challenges,
-                query_rands,
-            )?;
-
-            end_timer!(proof_time);
-
-            proofs.push(proof);
-        }
-        end_timer!(open_time);
-
-        Ok(proofs)
-    }
-
     /// Verifies that `value` is the evaluation at `x` of the polynomial
     /// committed inside `comm`.
     fn check_individual_opening_challenges<'a>(
-        vk: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::VerifierKey,
-        commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        point: &'a E::Fr,
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: E::Fr,
         values: impl IntoIterator<Item = E::Fr>,
-        proof: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::Proof,
+        proof: &Self::Proof,
         opening_challenges: &dyn Fn(u64) -> E::Fr,
     ) -> Result<bool, Error>
     where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
+        Self::Commitment: 'a,
     {
         let check_time = start_timer!(|| "Checking evaluations");
         let mut combined_comms: BTreeMap<Option<usize>, E::G1Projective> = BTreeMap::new();
@@ -903,7 +871,7 @@ impl<E: PairingEngine> SonicKZG10<E> {
             &mut combined_adjusted_witness,
             vk,
             commitments,
-            *point,
+            point,
             values,
             proof,
             opening_challenges,
@@ -914,62 +882,6 @@ impl<E: PairingEngine> SonicKZG10<E> {
         end_timer!(check_time);
         res
     }
-
-    /// batch_check but with individual challenges
-    fn batch_check_individual_opening_challenges<'a, R: RngCore>(
-        vk: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::VerifierKey,
-        commitments: impl IntoIterator<
-            Item = &'a LabeledCommitment<<Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment>,
-        >,
-        query_set: &QuerySet<E::Fr>,
-        evaluations: &Evaluations<E::Fr>,
-        proof: &<Self as PolynomialCommitment<E::Fr, E::Fq>>::BatchProof,
-        opening_challenges: &dyn Fn(u64) -> E::Fr,
-        _rng: &mut R,
-    ) -> Result<bool, Error>
-    where
-        <Self as PolynomialCommitment<E::Fr, E::Fq>>::Commitment: 'a,
-    {
-        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label(), c)).collect();
-
-        let mut query_to_labels_map = BTreeMap::new();
-        for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map
-                .entry(point_name)
-                .or_insert((point, BTreeSet::new()));
-            labels.1.insert(label);
-        }
-
-        // Implicit assumption: proofs are order in same manner as queries in
-        // `query_to_labels_map`.
-        let proofs: Vec<_> = proof.clone();
-        assert_eq!(proofs.len(), query_to_labels_map.len());
-
-        let mut result = true;
-        for ((_point_name, (point, labels)), proof) in query_to_labels_map.into_iter().zip(proofs) {
-            let mut comms: Vec<&'_ LabeledCommitment<_>> = Vec::new();
-            let mut values = Vec::new();
-            for label in labels {
-                let commitment = commitments.get(label).ok_or(Error::MissingPolynomial {
-                    label: label.to_string(),
-                })?;
-
-                let v_i = evaluations
-                    .get(&(label.clone(), *point))
-                    .ok_or(Error::MissingEvaluation {
-                        label: label.to_string(),
-                    })?;
-
-                comms.push(commitment);
-                values.push(*v_i);
-            }
-
-            let proof_time = start_timer!(|| "Checking per-query proof");
-            result &= Self::check_individual_opening_challenges(vk, comms, point, values, &proof, opening_challenges)?;
-            end_timer!(proof_time);
-        }
-        Ok(result)
-    }
 }
 
 impl<E: PairingEngine> SonicKZG10<E> {
@@ -1128,13 +1040,15 @@ mod tests {
     #![allow(non_camel_case_types)]
 
     use super::{CommitterKey, PolynomialCommitment, SonicKZG10};
-    use snarkvm_curves::bls12_377::Bls12_377;
+    use crate::{kzg10::KZG10DegreeBoundsConfig, Error};
+    use snarkvm_curves::{bls12_377::Bls12_377, bls12_381::Bls12_381};
 
     use rand::distributions::Distribution;
     use snarkvm_utilities::{rand::test_rng, FromBytes, ToBytes};
 
     type PC<E> = SonicKZG10<E>;
     type PC_Bls12_377 = PC<Bls12_377>;
+    type PC_Bls12_381 = PC<Bls12_381>;
 
     #[test]
     fn committer_key_serialization_test() {
@@ -1153,75 +1067,119 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn trim_with_degree_bounds_config_test() {
+        let rng = &mut test_rng();
+        let max_degree = 64;
+
+        let pp = PC_Bls12_377::setup(max_degree, rng).unwrap();
+        let config = KZG10DegreeBoundsConfig::LIST(vec![6, 14]);
+
+        // Ensure the verifier key is restricted to the degree bounds admitted by the policy.
+        let (ck, vk) = PC_Bls12_377::trim_with_degree_bounds_config(&pp, max_degree, 1, Some(&[14]), &config).unwrap();
+        let degree_bounds: Vec<usize> = vk
+            .degree_bounds_and_neg_powers_of_h
+            .unwrap()
+            .iter()
+            .map(|(d, _)| *d)
+            .collect();
+        assert_eq!(vec![6, 14], degree_bounds);
+        assert_eq!(Some(vec![14]), ck.enforced_degree_bounds);
+
+        // Ensure the unrestricted verifier key contains every degree bound in the universal parameters.
+        let (_ck, vk) = PC_Bls12_377::trim(&pp, max_degree, 1, Some(&[14])).unwrap();
+        assert_eq!(
+            pp.inverse_neg_powers_of_h.len(),
+            vk.degree_bounds_and_neg_powers_of_h.unwrap().len()
+        );
+
+        // Ensure a degree bound outside of the policy is rejected.
+        assert!(matches!(
+            PC_Bls12_377::trim_with_degree_bounds_config(&pp, max_degree, 1, Some(&[30]), &config),
+            Err(Error::UnsupportedDegreeBound(30))
+        ));
+    }
+
     #[test]
     fn single_poly_test() {
         use crate::tests::*;
         single_poly_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        single_poly_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn quadratic_poly_degree_bound_multiple_queries_test() {
         use crate::tests::*;
         quadratic_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        quadratic_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn linear_poly_degree_bound_test() {
         use crate::tests::*;
         linear_poly_degree_bound_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        linear_poly_degree_bound_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn single_poly_degree_bound_test() {
         use crate::tests::*;
         single_poly_degree_bound_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        single_poly_degree_bound_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn single_poly_degree_bound_multiple_queries_test() {
         use crate::tests::*;
         single_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        single_poly_degree_bound_multiple_queries_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn two_polys_degree_bound_single_query_test() {
         use crate::tests::*;
         two_polys_degree_bound_single_query_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
+        two_polys_degree_bound_single_query_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
     }
 
     #[test]
     fn full_end_to_end_test() {
         use crate::tests::*;
         full_end_to_end_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        full_end_to_end_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn single_equation_test() {
         use crate::tests::*;
         single_equation_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        single_equation_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn two_equation_test() {
         use crate::tests::*;
         two_equation_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        two_equation_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn two_equation_degree_bound_test() {
         use crate::tests::*;
         two_equation_degree_bound_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        two_equation_degree_bound_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
     fn full_end_to_end_equation_test() {
         use crate::tests::*;
         full_end_to_end_equation_test::<_, _, PC_Bls12_377>().expect("test failed for bls12-377");
-        println!("Finished bls12-377");
+        full_end_to_end_equation_test::<_, _, PC_Bls12_381>().expect("test failed for bls12-381");
+        println!("Finished bls12-377 and bls12-381");
     }
 
     #[test]
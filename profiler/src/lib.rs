@@ -17,17 +17,19 @@
 #![allow(unused_imports)]
 pub use inner::*;
 
+/// With `print-trace` enabled, each timer enters a `TRACE`-level `tracing` span named `timer`,
+/// so that nested timers and spans are recorded as its children. When the timer ends,
+/// its elapsed time is recorded as an event within the span, and the span is exited.
+/// Timings are collected by the `tracing` subscriber installed by the caller.
 #[cfg(feature = "print-trace")]
 #[macro_use]
 pub mod inner {
-    use std::{sync::atomic::AtomicUsize, time::Instant};
+    pub use tracing;
 
-    pub use colored::Colorize;
-
-    pub static NUM_INDENT: AtomicUsize = AtomicUsize::new(0);
-    pub const PAD_CHAR: &str = "·";
+    use std::time::Instant;
 
     pub struct TimerInfo {
+        pub span: tracing::span::EnteredSpan,
         pub msg: String,
         pub time: Instant,
     }
@@ -35,19 +37,11 @@ pub mod inner {
     #[macro_export]
     macro_rules! start_timer {
         ($msg:expr) => {{
-            use std::{sync::atomic::Ordering, time::Instant};
-            use $crate::{compute_indent, Colorize, NUM_INDENT, PAD_CHAR};
-
-            let msg = $msg();
-            let start_info = "Start:".yellow().bold();
-            let indent_amount = 2 * NUM_INDENT.fetch_add(0, Ordering::Relaxed);
-            let indent = compute_indent(indent_amount);
-
-            println!("{}{:8} {}", indent, start_info, msg);
-            NUM_INDENT.fetch_add(1, Ordering::Relaxed);
+            let msg = $msg().to_string();
             $crate::TimerInfo {
-                msg: msg.to_string(),
-                time: Instant::now(),
+                span: $crate::tracing::trace_span!("timer", message = %msg).entered(),
+                msg,
+                time: std::time::Instant::now(),
             }
         }};
     }
@@ -58,103 +52,19 @@ pub mod inner {
             end_timer!($time, || "");
         }};
         ($time:expr, $msg:expr) => {{
-            use std::sync::atomic::Ordering;
-            use $crate::{compute_indent, Colorize, NUM_INDENT, PAD_CHAR};
-
-            let time = $time.time;
-            let final_time = time.elapsed();
-            let final_time = {
-                let secs = final_time.as_secs();
-                let millis = final_time.subsec_millis();
-                let micros = final_time.subsec_micros() % 1000;
-                let nanos = final_time.subsec_nanos() % 1000;
-                if secs != 0 {
-                    format!("{}.{}s", secs, millis).bold()
-                } else if millis > 0 {
-                    format!("{}.{}ms", millis, micros).bold()
-                } else if micros > 0 {
-                    format!("{}.{}µs", micros, nanos).bold()
-                } else {
-                    format!("{}ns", final_time.subsec_nanos()).bold()
-                }
-            };
-
-            let end_info = "End:".green().bold();
-            let message = format!("{} {}", $time.msg, $msg());
-
-            NUM_INDENT.fetch_sub(1, Ordering::Relaxed);
-            let indent_amount = 2 * NUM_INDENT.fetch_add(0, Ordering::Relaxed);
-            let indent = compute_indent(indent_amount);
-
-            // Todo: Recursively ensure that *entire* string is of appropriate
-            // width (not just message).
-            println!(
-                "{}{:8} {:.<pad$}{}",
-                indent,
-                end_info,
-                message,
-                final_time,
-                pad = 75 - indent_amount
-            );
+            let timer: $crate::TimerInfo = $time;
+            let elapsed = timer.time.elapsed();
+            $crate::tracing::trace!(elapsed = ?elapsed, "{} {}", timer.msg, $msg());
+            timer.span.exit();
         }};
     }
 
     #[macro_export]
     macro_rules! add_to_trace {
         ($title:expr, $msg:expr) => {{
-            use std::sync::atomic::Ordering;
-            use $crate::{compute_indent, compute_indent_whitespace, Colorize, NUM_INDENT, PAD_CHAR};
-
-            let start_msg = "StartMsg".yellow().bold();
-            let end_msg = "EndMsg".green().bold();
-            let title = $title();
-            let start_msg = format!("{}: {}", start_msg, title);
-            let end_msg = format!("{}: {}", end_msg, title);
-
-            let start_indent_amount = 2 * NUM_INDENT.fetch_add(0, Ordering::Relaxed);
-            let start_indent = compute_indent(start_indent_amount);
-
-            let msg_indent_amount = 2 * NUM_INDENT.fetch_add(0, Ordering::Relaxed) + 2;
-            let msg_indent = compute_indent_whitespace(msg_indent_amount);
-            let mut final_message = "\n".to_string();
-            for line in $msg().lines() {
-                final_message += &format!("{}{}\n", msg_indent, line,);
-            }
-
-            // Todo: Recursively ensure that *entire* string is of appropriate
-            // width (not just message).
-            println!("{}{}", start_indent, start_msg);
-            println!("{}{}", msg_indent, final_message,);
-            println!("{}{}", start_indent, end_msg);
+            $crate::tracing::trace!(title = %$title(), "{}", $msg());
         }};
     }
-
-    pub fn compute_indent_whitespace(indent_amount: usize) -> String {
-        let mut indent = String::new();
-        for _ in 0..indent_amount {
-            indent.push(' ');
-        }
-        indent
-    }
-
-    pub fn compute_indent(indent_amount: usize) -> String {
-        use std::env::var;
-        let mut indent = String::new();
-        let pad_string = match var("CLICOLOR") {
-            Ok(val) => {
-                if val == "0" {
-                    " "
-                } else {
-                    PAD_CHAR
-                }
-            }
-            Err(_) => PAD_CHAR,
-        };
-        for _ in 0..indent_amount {
-            indent.push_str(&pad_string.white());
-        }
-        indent
-    }
 }
 
 #[cfg(not(feature = "print-trace"))]
@@ -42,7 +42,7 @@ mod optional_vec;
 pub use optional_vec::*;
 
 mod test_constraint_system;
-pub use test_constraint_system::{Fr, TestConstraintSystem};
+pub use test_constraint_system::{Fr, MutationStatistics, TestConstraintSystem};
 
 mod test_constraint_checker;
 pub use test_constraint_checker::TestConstraintChecker;
@@ -21,6 +21,7 @@ use cfg_if::cfg_if;
 use fxhash::{FxBuildHasher, FxHashMap};
 use indexmap::{map::Entry, IndexMap, IndexSet};
 use itertools::Itertools;
+use std::{collections::BTreeMap, fmt};
 
 /// This field is the scalar field (Fr) of BLS12-377.
 pub type Fr = snarkvm_curves::bls12_377::Fr;
@@ -80,6 +81,40 @@ impl CurrentNamespace {
     }
 }
 
+/// The outcome of mutating the assignment of a satisfied constraint system, one variable at a time.
+///
+/// A mutation of a public variable that leaves the constraint system satisfied means the circuit
+/// accepts a different statement for the same witness, which breaks soundness. A mutation of a private
+/// variable that leaves the constraint system satisfied is another witness for the same statement,
+/// such as the unchecked witness of a dummy record.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MutationStatistics {
+    /// The number of public variables that were mutated.
+    pub num_public_mutations: usize,
+    /// The paths of the public variables whose mutation left the constraint system satisfied.
+    pub accepted_public_mutations: Vec<String>,
+    /// The number of private variables that were mutated.
+    pub num_private_mutations: usize,
+    /// The paths of the private variables whose mutation left the constraint system satisfied.
+    pub accepted_private_mutations: Vec<String>,
+    /// The number of variables that are not in any constraint, and were not mutated.
+    pub num_unconstrained_variables: usize,
+}
+
+impl fmt::Display for MutationStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rejected {}/{} public mutations, rejected {}/{} private mutations, skipped {} unconstrained variables",
+            self.num_public_mutations - self.accepted_public_mutations.len(),
+            self.num_public_mutations,
+            self.num_private_mutations - self.accepted_private_mutations.len(),
+            self.num_private_mutations,
+            self.num_unconstrained_variables
+        )
+    }
+}
+
 /// Constraint system for testing purposes.
 pub struct TestConstraintSystem<F: Field> {
     // used to intern full paths in test scenarios, for get and set purposes
@@ -217,15 +252,15 @@ impl<F: Field> TestConstraintSystem<F> {
         }
     }
 
-    fn eval_lc(&self, terms: &[(Variable, InternedField)]) -> F {
+    /// Evaluates the given linear combination, with the given variable optionally assigned to a different value.
+    fn eval_lc(&self, terms: &[(Variable, InternedField)], mutation: Option<(Index, F)>) -> F {
         let mut acc = F::zero();
 
         for &(var, interned_coeff) in terms {
-            let interned_tmp = match var.get_unchecked() {
-                Index::Public(index) => self.public_variables[index],
-                Index::Private(index) => self.private_variables[index],
+            let mut tmp = match mutation {
+                Some((index, value)) if index == var.get_unchecked() => value,
+                _ => self.value_of(var.get_unchecked()),
             };
-            let mut tmp = *self.interned_fields.get_index(interned_tmp).unwrap();
             let coeff = self.interned_fields.get_index(interned_coeff).unwrap();
 
             tmp.mul_assign(coeff);
@@ -236,19 +271,109 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 
     pub fn which_is_unsatisfied(&self) -> Option<String> {
-        for TestConstraint { interned_path, a, b, c } in self.constraints.iter() {
-            let mut a = self.eval_lc(a.as_ref());
-            let b = self.eval_lc(b.as_ref());
-            let c = self.eval_lc(c.as_ref());
+        for constraint in self.constraints.iter() {
+            if !self.is_constraint_satisfied(constraint, None) {
+                return Some(self.unintern_path(constraint.interned_path));
+            }
+        }
+
+        None
+    }
 
-            a.mul_assign(&b);
+    /// Returns `true` if the given constraint is satisfied, with the given variable optionally reassigned.
+    fn is_constraint_satisfied(&self, constraint: &TestConstraint, mutation: Option<(Index, F)>) -> bool {
+        let mut a = self.eval_lc(&constraint.a, mutation);
+        let b = self.eval_lc(&constraint.b, mutation);
+        let c = self.eval_lc(&constraint.c, mutation);
+
+        a.mul_assign(&b);
+
+        a == c
+    }
 
-            if a != c {
-                return Some(self.unintern_path(*interned_path));
+    /// Returns the value assigned to the variable with the given index.
+    fn value_of(&self, index: Index) -> F {
+        let interned_field = match index {
+            Index::Public(index) => self.public_variables[index],
+            Index::Private(index) => self.private_variables[index],
+        };
+        *self.interned_fields.get_index(interned_field).unwrap()
+    }
+
+    /// Returns the path of the variable with the given index.
+    fn variable_path(&self, index: Index) -> String {
+        self.named_objects
+            .iter()
+            .find_map(|(interned_path, named_object)| match named_object {
+                NamedObject::Var(var) if var.get_unchecked() == index => Some(self.unintern_path(*interned_path)),
+                _ => None,
+            })
+            .unwrap_or_else(|| format!("{:?}", index))
+    }
+
+    ///
+    /// Mutates the assignment of every public variable, and of up to `max_private_mutations` private variables
+    /// spread evenly across the constraint system, one at a time, and records the mutations that leave the
+    /// constraint system satisfied. Each mutation adds one to the value of the variable.
+    ///
+    /// The constraint system must be satisfied, and is left unchanged. Variables that are not in any constraint
+    /// are counted, but not mutated.
+    ///
+    pub fn mutate_assignments(&self, max_private_mutations: usize) -> MutationStatistics {
+        assert!(self.is_satisfied(), "Mutations must start from a satisfying assignment");
+
+        // Index the constraints of each variable, so that each mutation only checks the affected constraints.
+        let mut constraints_by_variable: BTreeMap<Index, Vec<&TestConstraint>> = BTreeMap::new();
+        for constraint in self.constraints.iter() {
+            for (var, _) in constraint.a.iter().chain(&constraint.b).chain(&constraint.c) {
+                let constraints = constraints_by_variable.entry(var.get_unchecked()).or_default();
+                if !constraints.last().map_or(false, |last| std::ptr::eq(*last, constraint)) {
+                    constraints.push(constraint);
+                }
             }
         }
 
-        None
+        // Skip the public variable for the constant one.
+        let public_variables: Vec<_> = constraints_by_variable
+            .iter()
+            .filter(|(index, _)| matches!(index, Index::Public(i) if *i != 0))
+            .collect();
+        let private_variables: Vec<_> = constraints_by_variable
+            .iter()
+            .filter(|(index, _)| matches!(index, Index::Private(_)))
+            .collect();
+
+        let num_unconstrained_variables = (self.public_variables.len() - 1 - public_variables.len())
+            + (self.private_variables.len() - private_variables.len());
+
+        // Select the private variables to mutate, spread evenly across the constraint system.
+        let num_private_mutations = std::cmp::min(max_private_mutations, private_variables.len());
+        let private_variables = (0..num_private_mutations)
+            .map(|i| private_variables[i * private_variables.len() / num_private_mutations])
+            .collect::<Vec<_>>();
+
+        let is_accepted = |(index, constraints): &(&Index, &Vec<&TestConstraint>)| {
+            let mutation = Some((**index, self.value_of(**index) + F::one()));
+            constraints
+                .iter()
+                .all(|constraint| self.is_constraint_satisfied(constraint, mutation))
+        };
+
+        MutationStatistics {
+            num_public_mutations: public_variables.len(),
+            accepted_public_mutations: public_variables
+                .iter()
+                .filter(|variable| is_accepted(variable))
+                .map(|(index, _)| self.variable_path(**index))
+                .collect(),
+            num_private_mutations,
+            accepted_private_mutations: private_variables
+                .iter()
+                .filter(|variable| is_accepted(variable))
+                .map(|(index, _)| self.variable_path(**index))
+                .collect(),
+            num_unconstrained_variables,
+        }
     }
 
     #[inline]
@@ -518,3 +643,41 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::Zero;
+
+    #[test]
+    fn test_mutate_assignments() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        // Enforce `a * b = c`, where `c` is public.
+        let a = cs.alloc(|| "a", || Ok(Fr::from(3u64))).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::from(4u64))).unwrap();
+        let c = cs.alloc_input(|| "c", || Ok(Fr::from(12u64))).unwrap();
+        cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+
+        // Enforce `condition * x = 0`, which does not constrain `x` when `condition` is zero.
+        let condition = cs.alloc(|| "condition", || Ok(Fr::zero())).unwrap();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(5u64))).unwrap();
+        cs.enforce(|| "condition * x = 0", |lc| lc + condition, |lc| lc + x, |lc| lc);
+
+        // Allocate a variable that is not in any constraint.
+        cs.alloc(|| "d", || Ok(Fr::from(7u64))).unwrap();
+
+        let statistics = cs.mutate_assignments(10);
+        assert_eq!(1, statistics.num_public_mutations);
+        assert!(statistics.accepted_public_mutations.is_empty());
+        assert_eq!(4, statistics.num_private_mutations);
+        assert_eq!(vec!["x".to_string()], statistics.accepted_private_mutations);
+        assert_eq!(1, statistics.num_unconstrained_variables);
+
+        // Ensure the number of private mutations is bounded.
+        assert_eq!(2, cs.mutate_assignments(2).num_private_mutations);
+
+        // Ensure the constraint system is unchanged.
+        assert!(cs.is_satisfied());
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_utilities::ScratchVec;
+
+use criterion::{BenchmarkId, Criterion};
+use std::thread;
+
+/// An element the size of a projective BLS12-377 G1 point, as in the MSM buckets.
+type Element = [u64; 18];
+
+/// The number of threads that allocate temporary vectors concurrently.
+const NUM_THREADS: usize = 8;
+
+/// The number of temporary vectors that each thread allocates.
+const ALLOCATIONS_PER_THREAD: usize = 64;
+
+/// Fills a temporary vector of the given size, as the FFT and MSM hot paths do.
+fn touch(buffer: &mut [Element]) -> u64 {
+    buffer.iter_mut().step_by(64).for_each(|elem| elem[0] += 1);
+    buffer[buffer.len() - 1][0]
+}
+
+/// Allocates temporary vectors on the given number of threads, with either `vec!` or a scratch vector.
+fn allocate_on_threads(num_threads: usize, len: usize, use_scratch: bool) -> u64 {
+    let handles: Vec<_> = (0..num_threads)
+        .map(|_| {
+            thread::spawn(move || {
+                (0..ALLOCATIONS_PER_THREAD)
+                    .map(|_| match use_scratch {
+                        true => touch(&mut ScratchVec::from_elem([0u64; 18], len)),
+                        false => touch(&mut vec![[0u64; 18]; len]),
+                    })
+                    .sum::<u64>()
+            })
+        })
+        .collect();
+    handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+}
+
+fn scratch_single_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scratch_single_thread");
+    for log_len in [10, 14, 16].iter() {
+        let len = 1 << log_len;
+        group.bench_with_input(BenchmarkId::new("vec", len), &len, |b, &len| {
+            b.iter(|| touch(&mut vec![[0u64; 18]; len]))
+        });
+        group.bench_with_input(BenchmarkId::new("scratch_vec", len), &len, |b, &len| {
+            b.iter(|| touch(&mut ScratchVec::from_elem([0u64; 18], len)))
+        });
+    }
+    group.finish();
+}
+
+fn scratch_multi_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scratch_multi_thread");
+    group.sample_size(10);
+    for log_len in [10, 14, 16].iter() {
+        let len = 1 << log_len;
+        group.bench_with_input(BenchmarkId::new("vec", len), &len, |b, &len| {
+            b.iter(|| allocate_on_threads(NUM_THREADS, len, false))
+        });
+        group.bench_with_input(BenchmarkId::new("scratch_vec", len), &len, |b, &len| {
+            b.iter(|| allocate_on_threads(NUM_THREADS, len, true))
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = scratch;
+    config = Criterion::default();
+    targets = scratch_single_thread, scratch_multi_thread
+}
+
+criterion_main!(scratch);
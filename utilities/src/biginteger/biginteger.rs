@@ -107,6 +107,20 @@ pub trait BigInteger:
 
     /// Returns a vector for wnaf.
     fn find_wnaf(&self) -> Vec<i64>;
+
+    /// Returns the BigInteger representation of the given `u128`.
+    /// If this BigInteger has a single limb, the upper 64 bits are discarded.
+    fn from_u128(value: u128) -> Self;
+
+    /// Returns the `u128` representation of this number, or `None` if it does not fit in 128 bits.
+    fn to_u128_checked(&self) -> Option<u128>;
+
+    /// Multiplies this number by another, returning the low and high halves of the full product.
+    fn mul_wide(&self, other: &Self) -> (Self, Self);
+
+    /// Divides this number by another, returning the quotient and remainder,
+    /// or `None` if the divisor is zero.
+    fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)>;
 }
 
 pub mod arithmetic {
@@ -145,13 +159,10 @@ pub mod arithmetic {
 }
 
 impl BigInteger256 {
+    /// Returns the BigInteger representation of the given `u128`.
+    /// This forwards to `BigInteger::from_u128`, so callers need not import the trait.
     pub fn from_u128(num: u128) -> Self {
-        // Takes a 256 bit buffer
-        let mut bytes = [0u8; 32];
-
-        num.write_le(bytes.as_mut()).unwrap();
-
-        Self::read_le(&bytes[..]).unwrap()
+        <Self as BigInteger>::from_u128(num)
     }
 
     pub fn to_u128(&self) -> u128 {
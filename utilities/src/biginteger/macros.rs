@@ -224,6 +224,69 @@ macro_rules! biginteger {
 
                 res
             }
+
+            #[inline]
+            fn from_u128(value: u128) -> Self {
+                let mut result = Self::default();
+                result.0[0] = value as u64;
+                if let Some(limb) = result.0.get_mut(1) {
+                    *limb = (value >> 64) as u64;
+                }
+                result
+            }
+
+            #[inline]
+            fn to_u128_checked(&self) -> Option<u128> {
+                if self.0.iter().skip(2).any(|&limb| limb != 0) {
+                    return None;
+                }
+
+                let low = self.0[0] as u128;
+                let high = self.0.get(1).copied().unwrap_or(0) as u128;
+                Some(low | (high << 64))
+            }
+
+            #[inline]
+            fn mul_wide(&self, other: &Self) -> (Self, Self) {
+                let mut product = [0u64; 2 * $num_limbs];
+                for i in 0..$num_limbs {
+                    let mut carry = 0;
+                    for j in 0..$num_limbs {
+                        product[i + j] = arithmetic::mac_with_carry(product[i + j], self.0[i], other.0[j], &mut carry);
+                    }
+                    product[i + $num_limbs] = carry;
+                }
+
+                let mut low = Self::default();
+                let mut high = Self::default();
+                low.0.copy_from_slice(&product[..$num_limbs]);
+                high.0.copy_from_slice(&product[$num_limbs..]);
+                (low, high)
+            }
+
+            #[inline]
+            fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+                if divisor.is_zero() {
+                    return None;
+                }
+
+                // Schoolbook binary long division, from the most significant bit down.
+                let mut quotient = Self::default();
+                let mut remainder = Self::default();
+                for i in (0..self.num_bits() as usize).rev() {
+                    // The remainder is less than the divisor, so doubling it overflows by at most one bit.
+                    let overflow = remainder.get_bit(64 * $num_limbs - 1);
+                    remainder.mul2();
+                    remainder.0[0] |= self.get_bit(i) as u64;
+
+                    if overflow || remainder >= *divisor {
+                        remainder.sub_noborrow(divisor);
+                        quotient.0[i / 64] |= 1 << (i % 64);
+                    }
+                }
+
+                Some((quotient, remainder))
+            }
         }
 
         impl ToBits for $name {
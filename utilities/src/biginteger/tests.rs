@@ -16,7 +16,7 @@
 
 use crate::{biginteger::*, rand::UniformRand};
 
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
 #[allow(clippy::eq_op)]
@@ -112,6 +112,63 @@ fn biginteger_to_string_test<B: BigInteger>() {
     }
 }
 
+fn biginteger_u128_test<B: BigInteger>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for value in [0u128, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX, rng.gen()] {
+        let candidate = B::from_u128(value);
+        match B::NUM_LIMBS {
+            1 => assert_eq!(Some(value as u64 as u128), candidate.to_u128_checked()),
+            _ => {
+                assert_eq!(Some(value), candidate.to_u128_checked());
+                assert_eq!(value.to_string(), candidate.to_string());
+            }
+        }
+    }
+
+    // Ensure values beyond 128 bits are rejected.
+    if B::NUM_LIMBS > 2 {
+        let mut candidate = B::from_u128(u128::MAX);
+        candidate.add_nocarry(&B::from(1u64));
+        assert_eq!(None, candidate.to_u128_checked());
+    }
+}
+
+fn biginteger_mul_div_test<B: BigInteger>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let zero = B::from(0u64);
+    let one = B::from(1u64);
+
+    for _ in 0..100 {
+        let a: B = UniformRand::rand(&mut rng);
+        let b: B = UniformRand::rand(&mut rng);
+
+        // Check the wide product against num-bigint.
+        let (low, high) = a.mul_wide(&b);
+        let expected = a.to_biguint() * b.to_biguint();
+        assert_eq!(expected, low.to_biguint() + (high.to_biguint() << (64 * B::NUM_LIMBS)));
+
+        // Check the quotient and remainder against num-bigint.
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+        assert_eq!(a.to_biguint() / b.to_biguint(), quotient.to_biguint());
+        assert_eq!(a.to_biguint() % b.to_biguint(), remainder.to_biguint());
+        assert!(remainder < b);
+
+        // Check small divisors, which exercise the full width of the quotient.
+        let divisor = B::from(rng.gen_range(1..u64::MAX));
+        let (quotient, remainder) = a.div_rem(&divisor).unwrap();
+        assert_eq!(a.to_biguint() / divisor.to_biguint(), quotient.to_biguint());
+        assert_eq!(a.to_biguint() % divisor.to_biguint(), remainder.to_biguint());
+
+        assert_eq!((zero, zero), a.mul_wide(&zero));
+        assert_eq!((a, zero), a.mul_wide(&one));
+        assert_eq!(Some((a, zero)), a.div_rem(&one));
+        assert_eq!(Some((one, zero)), a.div_rem(&a));
+        assert_eq!(None, a.div_rem(&zero));
+    }
+}
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: B = UniformRand::rand(&mut rng);
@@ -120,6 +177,8 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
     biginteger_to_string_test::<B>();
+    biginteger_u128_test::<B>();
+    biginteger_mul_div_test::<B>();
 }
 
 #[test]
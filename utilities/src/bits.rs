@@ -53,16 +53,29 @@ pub trait FromBits: Sized {
     fn from_bits_be(bits: &[bool]) -> Self;
 }
 
+///
+/// A minimal bit encoding, which is hashed to derive the IDs of circuits and functions from their verifying keys.
+///
+/// As any change to an encoding changes every ID derived from it, each version of the encoding is frozen
+/// once it is released. An optimized encoding is added as a new `to_minimal_bits_v{N}` method,
+/// and IDs are derived from an explicit version, so that existing IDs are reproducible.
+///
 pub trait ToMinimalBits: Sized {
-    /// Returns `self` as a minimal boolean array.
-    fn to_minimal_bits(&self) -> Vec<bool>;
+    /// Returns `self` as a minimal boolean array, in the latest version of the encoding.
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        self.to_minimal_bits_v1()
+    }
+
+    /// Returns `self` as a minimal boolean array, in version 1 of the encoding.
+    fn to_minimal_bits_v1(&self) -> Vec<bool>;
 }
 
+/// The version 1 encoding of a list is the concatenation of the encodings of its elements, without a length.
 impl<T: ToMinimalBits> ToMinimalBits for Vec<T> {
-    fn to_minimal_bits(&self) -> Vec<bool> {
+    fn to_minimal_bits_v1(&self) -> Vec<bool> {
         let mut res_bits = vec![];
         for elem in self.iter() {
-            res_bits.extend(elem.to_minimal_bits());
+            res_bits.extend(elem.to_minimal_bits_v1());
         }
         res_bits
     }
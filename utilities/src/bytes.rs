@@ -103,6 +103,24 @@ impl<T: ToBytes> ToBytesSerializer<T> {
     }
 }
 
+/// The maximum number of bytes in a dynamically-sized byte array read by `FromBytesDeserializer`,
+/// for types that do not specify a tighter bound.
+pub const MAX_SIZE_ENCODED_BYTES: usize = 256 * 1024 * 1024;
+
+/// The maximum number of bytes reserved in advance by `vec_with_bounded_capacity`.
+pub const MAX_PREALLOCATED_BYTES: usize = 1024 * 1024;
+
+///
+/// Returns an empty vector with capacity for `length` elements, up to `MAX_PREALLOCATED_BYTES` bytes.
+///
+/// A length read from a reader is untrusted until its elements are read, so a malformed length
+/// fails when the reader runs out of elements, instead of attempting a large allocation up front.
+///
+pub fn vec_with_bounded_capacity<T>(length: usize) -> Vec<T> {
+    let max_length = MAX_PREALLOCATED_BYTES / core::cmp::max(core::mem::size_of::<T>(), 1);
+    Vec::with_capacity(core::cmp::min(length, max_length))
+}
+
 pub struct FromBytesDeserializer<T: FromBytes>(String, Option<usize>, PhantomData<T>);
 
 impl<'de, T: FromBytes> FromBytesDeserializer<T> {
@@ -118,11 +136,26 @@ impl<'de, T: FromBytes> FromBytesDeserializer<T> {
     }
 
     ///
-    /// Deserializes a dynamically-sized byte array.
+    /// Deserializes a dynamically-sized byte array of at most `MAX_SIZE_ENCODED_BYTES` bytes.
     ///
     pub fn deserialize_with_size_encoding<D: Deserializer<'de>>(deserializer: D, name: &str) -> Result<T, D::Error> {
+        Self::deserialize_with_size_encoding_bounded(deserializer, name, MAX_SIZE_ENCODED_BYTES)
+    }
+
+    ///
+    /// Deserializes a dynamically-sized byte array of at most `max_size` bytes.
+    ///
+    /// This method fails if the encoded size exceeds `max_size`, before reserving a buffer for the bytes.
+    /// The bytes are requested as a sequence, which has the same encoding as a byte array in bincode,
+    /// so that a deserializer reading from a stream yields the length before it reads the bytes.
+    ///
+    pub fn deserialize_with_size_encoding_bounded<D: Deserializer<'de>>(
+        deserializer: D,
+        name: &str,
+        max_size: usize,
+    ) -> Result<T, D::Error> {
         let mut buffer = Vec::with_capacity(32);
-        deserializer.deserialize_bytes(FromBytesVisitor::new(&mut buffer, name))?;
+        deserializer.deserialize_seq(FromBytesVisitor::new_bounded(&mut buffer, name, max_size))?;
         FromBytes::read_le(&buffer[..]).map_err(de::Error::custom)
     }
 
@@ -166,22 +199,47 @@ impl<'a, 'de> FromBytesVisitor<'a> {
     pub fn new(buffer: &'a mut Vec<u8>, name: &str) -> Self {
         Self(buffer, name.to_string(), None)
     }
+
+    /// Initializes a visitor that rejects byte arrays with more than `max_size` bytes.
+    pub fn new_bounded(buffer: &'a mut Vec<u8>, name: &str, max_size: usize) -> Self {
+        Self(buffer, name.to_string(), Some(max_size))
+    }
+
+    /// Returns the maximum number of bytes the visitor accepts.
+    fn max_size(&self) -> usize {
+        self.2.unwrap_or(usize::MAX)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for FromBytesVisitor<'a> {
     type Value = ();
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str(&format!("a valid {} ", self.1))
+        match self.2 {
+            Some(max_size) => formatter.write_str(&format!("a valid {} of at most {} bytes ", self.1, max_size)),
+            None => formatter.write_str(&format!("a valid {} ", self.1)),
+        }
     }
 
-    fn visit_borrowed_bytes<E: serde::de::Error>(self, bytes: &'de [u8]) -> Result<Self::Value, E> {
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        if bytes.len() > self.max_size() {
+            return Err(E::invalid_length(bytes.len(), &self));
+        }
         self.0.extend_from_slice(bytes);
         Ok(())
     }
 
     fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let max_size = self.max_size();
+        if let Some(size) = seq.size_hint() {
+            if size > max_size {
+                return Err(de::Error::invalid_length(size, &self));
+            }
+        }
         while let Some(byte) = seq.next_element()? {
+            if self.0.len() == max_size {
+                return Err(de::Error::invalid_length(max_size + 1, &self));
+            }
             self.0.push(byte);
         }
         Ok(())
@@ -386,8 +444,21 @@ pub fn from_bits_le_to_bytes_le(bits: &[bool]) -> Vec<u8> {
 
 #[cfg(test)]
 mod test {
-    use super::{from_bits_le_to_bytes_le, from_bytes_le_to_bits_le, ToBytes};
-    use crate::Vec;
+    use super::{
+        from_bits_le_to_bytes_le,
+        from_bytes_le_to_bits_le,
+        vec_with_bounded_capacity,
+        FromBytes,
+        FromBytesDeserializer,
+        ToBytes,
+        MAX_PREALLOCATED_BYTES,
+        MAX_SIZE_ENCODED_BYTES,
+    };
+    use crate::{
+        io::{Read, Result as IoResult},
+        Vec,
+    };
+    use serde::Deserializer;
 
     use rand::{Rng, SeedableRng};
     use rand_xorshift::XorShiftRng;
@@ -451,4 +522,78 @@ mod test {
             assert_eq!(given_bytes.to_vec(), recovered_bytes);
         }
     }
+
+    /// A payload of bytes, which is read until the end of the reader.
+    #[derive(Debug)]
+    struct Payload(Vec<u8>);
+
+    impl FromBytes for Payload {
+        fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+            Ok(Self(bytes))
+        }
+    }
+
+    /// A payload of at most 4 bytes.
+    #[derive(Debug)]
+    struct BoundedPayload(Payload);
+
+    impl<'de> serde::Deserialize<'de> for BoundedPayload {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(Self(
+                FromBytesDeserializer::<Payload>::deserialize_with_size_encoding_bounded(deserializer, "payload", 4)?,
+            ))
+        }
+    }
+
+    /// A payload of at most `MAX_SIZE_ENCODED_BYTES` bytes.
+    #[derive(Debug)]
+    struct DefaultBoundedPayload(Payload);
+
+    impl<'de> serde::Deserialize<'de> for DefaultBoundedPayload {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(Self(FromBytesDeserializer::<Payload>::deserialize_with_size_encoding(
+                deserializer,
+                "payload",
+            )?))
+        }
+    }
+
+    #[test]
+    fn test_deserialize_with_size_encoding_bounded() {
+        let bytes = bincode::serialize(&vec![1u8, 2, 3, 4]).unwrap();
+        let payload: BoundedPayload = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(vec![1u8, 2, 3, 4], (payload.0).0);
+
+        // Ensure a payload that exceeds the bound is rejected.
+        let bytes = bincode::serialize(&vec![1u8, 2, 3, 4, 5]).unwrap();
+        assert!(bincode::deserialize::<BoundedPayload>(&bytes).is_err());
+
+        // Ensure a length prefix just above the bound is rejected for its length, before the bytes are read.
+        let bytes = bincode::serialize(&5u64).unwrap();
+        let error = bincode::deserialize::<BoundedPayload>(&bytes).unwrap_err();
+        assert!(error.to_string().contains("invalid length 5"), "{}", error);
+
+        // Ensure a length prefix just above the bound is rejected for its length when reading from a stream.
+        let error = bincode::deserialize_from::<_, BoundedPayload>(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("invalid length 5"), "{}", error);
+    }
+
+    #[test]
+    fn test_deserialize_with_size_encoding() {
+        // Ensure a length prefix just above `MAX_SIZE_ENCODED_BYTES` is rejected for its length,
+        // before a buffer is reserved or the bytes are read.
+        let bytes = bincode::serialize(&(MAX_SIZE_ENCODED_BYTES as u64 + 1)).unwrap();
+        let error = bincode::deserialize_from::<_, DefaultBoundedPayload>(&bytes[..]).unwrap_err();
+        let expected = format!("invalid length {}", MAX_SIZE_ENCODED_BYTES + 1);
+        assert!(error.to_string().contains(&expected), "{}", error);
+    }
+
+    #[test]
+    fn test_vec_with_bounded_capacity() {
+        assert_eq!(16, vec_with_bounded_capacity::<u8>(16).capacity());
+        assert_eq!(MAX_PREALLOCATED_BYTES, vec_with_bounded_capacity::<u8>(u32::MAX as usize).capacity());
+        assert_eq!(MAX_PREALLOCATED_BYTES / 8, vec_with_bounded_capacity::<u64>(u32::MAX as usize).capacity());
+    }
 }
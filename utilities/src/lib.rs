@@ -74,6 +74,9 @@ pub use math::*;
 pub mod rand;
 pub use self::rand::*;
 
+pub mod scratch;
+pub use scratch::*;
+
 pub mod serialize;
 pub use serialize::*;
 
@@ -14,10 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use core::fmt;
 use rand::{
     distributions::{Distribution, Standard},
+    CryptoRng,
     Rng,
+    RngCore,
+    SeedableRng,
 };
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 pub trait UniformRand: Sized {
     fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self;
@@ -35,11 +40,240 @@ where
 
 /// Should be used only for tests, not for any real world usage.
 pub fn test_rng() -> rand::rngs::StdRng {
-    use rand::SeedableRng;
-
     // arbitrary seed
     let seed = [
         1, 0, 0, 0, 23, 0, 0, 0, 200, 1, 0, 0, 210, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     rand::rngs::StdRng::from_seed(seed)
 }
+
+/// The number of 32-bit words in a ChaCha20 block.
+const BLOCK_WORDS: usize = 16;
+
+///
+/// A seedable ChaCha20 CSPRNG, for use wherever a `Rng + CryptoRng` is accepted.
+///
+/// # Entropy policy
+///
+/// An `AleoRng` must be seeded from OS entropy (`AleoRng::from_entropy`) when it is used to sample
+/// secrets, namely account private keys, record and encryption randomness, PoSW nonces, and the
+/// blinding factors of zero-knowledge proofs. A fixed seed (`AleoRng::from_seed`) may only be used
+/// to reproduce test vectors, benchmarks, and non-secret setups, such as a development SRS.
+///
+/// The keystream matches `rand_chacha::ChaCha20Rng` for the same seed. The key and keystream are
+/// held in this struct, so that they are zeroized when the `AleoRng` is dropped. For the same reason,
+/// an `AleoRng` cannot be cloned, as a clone would duplicate the secret keystream.
+///
+pub struct AleoRng {
+    /// The ChaCha20 key, which is the seed.
+    key: [u32; 8],
+    /// The counter of the next keystream block.
+    counter: u64,
+    /// The current keystream block.
+    block: [u32; BLOCK_WORDS],
+    /// The index of the next unused word in the current keystream block.
+    index: usize,
+}
+
+impl AleoRng {
+    /// Returns the word position of the keystream, which identifies how much randomness has been used.
+    pub fn word_pos(&self) -> u128 {
+        self.counter as u128 * BLOCK_WORDS as u128 + self.index as u128 - BLOCK_WORDS as u128
+    }
+
+    /// Returns the next word of the keystream.
+    #[inline]
+    fn next_word(&mut self) -> u32 {
+        if self.index == BLOCK_WORDS {
+            self.refill();
+        }
+        let word = self.block[self.index];
+        self.index += 1;
+        word
+    }
+
+    /// Computes the keystream block at the current counter, and advances the counter.
+    fn refill(&mut self) {
+        let mut state = [0u32; BLOCK_WORDS];
+        state[..4].copy_from_slice(&[0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574]);
+        state[4..12].copy_from_slice(&self.key);
+        state[12] = self.counter as u32;
+        state[13] = (self.counter >> 32) as u32;
+        // The stream ID in words 14 and 15 is always zero.
+
+        self.block = state;
+        for _ in 0..10 {
+            // Column round.
+            quarter_round(&mut self.block, 0, 4, 8, 12);
+            quarter_round(&mut self.block, 1, 5, 9, 13);
+            quarter_round(&mut self.block, 2, 6, 10, 14);
+            quarter_round(&mut self.block, 3, 7, 11, 15);
+            // Diagonal round.
+            quarter_round(&mut self.block, 0, 5, 10, 15);
+            quarter_round(&mut self.block, 1, 6, 11, 12);
+            quarter_round(&mut self.block, 2, 7, 8, 13);
+            quarter_round(&mut self.block, 3, 4, 9, 14);
+        }
+        for (word, initial) in self.block.iter_mut().zip(state.iter()) {
+            *word = word.wrapping_add(*initial);
+        }
+        state.zeroize();
+
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+/// The ChaCha quarter round on the words at the given indices of the state.
+#[inline]
+fn quarter_round(state: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+impl RngCore for AleoRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_word()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let low = self.next_word() as u64;
+        let high = self.next_word() as u64;
+        (high << 32) | low
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // As in `rand_chacha`, a partially used word at the end is discarded.
+        for chunk in dest.chunks_mut(4) {
+            let word = self.next_word().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for AleoRng {}
+
+impl SeedableRng for AleoRng {
+    type Seed = [u8; 32];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut key = [0u32; 8];
+        for (word, bytes) in key.iter_mut().zip(seed.chunks(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        Self {
+            key,
+            counter: 0,
+            block: [0u32; BLOCK_WORDS],
+            index: BLOCK_WORDS,
+        }
+    }
+}
+
+impl fmt::Debug for AleoRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key and keystream are secret, and are not printed.
+        f.debug_struct("AleoRng").field("word_pos", &self.word_pos()).finish()
+    }
+}
+
+impl Zeroize for AleoRng {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.counter.zeroize();
+        self.block.zeroize();
+        self.index.zeroize();
+    }
+}
+
+impl Drop for AleoRng {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for AleoRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aleo_rng_is_deterministic() {
+        let mut first = AleoRng::from_seed([7u8; 32]);
+        let mut second = AleoRng::from_seed([7u8; 32]);
+        let mut other = AleoRng::from_seed([8u8; 32]);
+
+        let expected: [u64; 4] = UniformRand::rand(&mut first);
+        assert_eq!(expected, <[u64; 4]>::rand(&mut second));
+        assert_ne!(expected, <[u64; 4]>::rand(&mut other));
+        assert_eq!(first.word_pos(), second.word_pos());
+    }
+
+    #[test]
+    fn test_aleo_rng_true_values() {
+        // The first block of the ChaCha20 keystream for the zero key, as in `rand_chacha`.
+        let expected = [
+            0xade0b876, 0x903df1a0, 0xe56a5d40, 0x28bd8653, 0xb819d2bd, 0x1aed8da0, 0xccef36a8, 0xc70d778b, 0x7c5941da,
+            0x8d485751, 0x3fe02477, 0x374ad8b8, 0xf4b8436a, 0x1ca11815, 0x69b687c3, 0x8665eeb2,
+        ];
+        let mut rng = AleoRng::from_seed([0u8; 32]);
+        for word in expected.iter() {
+            assert_eq!(*word, rng.next_u32());
+        }
+        assert_eq!(16, rng.word_pos());
+    }
+
+    #[test]
+    fn test_aleo_rng_matches_chacha20() {
+        let seed: [u8; 32] = UniformRand::rand(&mut test_rng());
+        let mut rng = AleoRng::from_seed(seed);
+        let mut expected = rand_chacha::ChaCha20Rng::from_seed(seed);
+
+        // Ensure the keystream matches across block boundaries, including partially used words.
+        for size in 0..40 {
+            let (mut bytes, mut expected_bytes) = (vec![0u8; size], vec![0u8; size]);
+            rng.fill_bytes(&mut bytes);
+            expected.fill_bytes(&mut expected_bytes);
+            assert_eq!(expected_bytes, bytes);
+            assert_eq!(expected.next_u32(), rng.next_u32());
+            assert_eq!(expected.next_u64(), rng.next_u64());
+            assert_eq!(expected.get_word_pos(), rng.word_pos());
+        }
+    }
+
+    #[test]
+    fn test_aleo_rng_zeroize() {
+        let mut rng = AleoRng::from_seed([7u8; 32]);
+        let _ = rng.next_u64();
+
+        rng.zeroize();
+        assert_eq!([0u32; 8], rng.key);
+        assert_eq!([0u32; BLOCK_WORDS], rng.block);
+        assert_eq!(0, rng.counter);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_aleo_rng_from_entropy() {
+        let mut first = AleoRng::from_entropy();
+        let mut second = AleoRng::from_entropy();
+        assert_ne!(u128::rand(&mut first), u128::rand(&mut second));
+    }
+}
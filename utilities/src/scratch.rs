@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// The maximum number of free buffers of each type that a thread keeps in its scratch arena.
+pub const MAX_SCRATCH_BUFFERS_PER_TYPE: usize = 8;
+/// The maximum total size of the free buffers that a thread keeps in its scratch arena, in bytes.
+pub const MAX_SCRATCH_ARENA_SIZE_IN_BYTES: usize = 256 * 1024 * 1024;
+
+thread_local! {
+    /// The scratch arena of this thread.
+    static SCRATCH_ARENA: RefCell<ScratchArena> = RefCell::new(ScratchArena::default());
+}
+
+/// The free buffers of a scratch arena, by element type.
+#[derive(Default)]
+struct ScratchArena {
+    buffers: HashMap<TypeId, Vec<Box<dyn Any>>>,
+    /// The total size of the allocations of the free buffers, in bytes.
+    size_in_bytes: usize,
+}
+
+/// Returns the size of the allocation of the given buffer, in bytes.
+fn allocation_size_in_bytes<T>(buffer: &Vec<T>) -> usize {
+    buffer.capacity().saturating_mul(std::mem::size_of::<T>())
+}
+
+///
+/// A temporary vector whose allocation is taken from, and returned to, the scratch arena of the current thread.
+///
+/// Hot paths that allocate a large temporary vector on every call, such as the FFT and MSM,
+/// contend on the global allocator when run on many threads. A scratch vector reuses
+/// the allocation of a previous scratch vector of the same type that was dropped on this thread.
+///
+pub struct ScratchVec<T: 'static> {
+    buffer: Vec<T>,
+}
+
+impl<T: Clone + 'static> ScratchVec<T> {
+    /// Returns a scratch vector with `len` copies of `elem`, as `vec![elem; len]` does.
+    pub fn from_elem(elem: T, len: usize) -> Self {
+        let mut buffer = take_buffer::<T>();
+        buffer.resize(len, elem);
+        Self { buffer }
+    }
+}
+
+impl<T: 'static> ScratchVec<T> {
+    /// Returns an empty scratch vector.
+    pub fn new() -> Self {
+        Self {
+            buffer: take_buffer::<T>(),
+        }
+    }
+
+    /// Returns the scratch vector as a vector.
+    pub fn as_mut_vec(&mut self) -> &mut Vec<T> {
+        &mut self.buffer
+    }
+}
+
+impl<T: 'static> Default for ScratchVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> Deref for ScratchVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.buffer
+    }
+}
+
+impl<T: 'static> DerefMut for ScratchVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.buffer
+    }
+}
+
+impl<T: 'static> Drop for ScratchVec<T> {
+    fn drop(&mut self) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        if buffer.capacity() == 0 {
+            return;
+        }
+        buffer.clear();
+
+        // The arena may already be destroyed if this runs during thread shutdown.
+        let _ = SCRATCH_ARENA.try_with(|arena| {
+            if let Ok(mut arena) = arena.try_borrow_mut() {
+                // Free the buffer instead if the arena would exceed its size.
+                let size_in_bytes = arena.size_in_bytes.saturating_add(allocation_size_in_bytes(&buffer));
+                if size_in_bytes > MAX_SCRATCH_ARENA_SIZE_IN_BYTES {
+                    return;
+                }
+
+                let buffers = arena.buffers.entry(TypeId::of::<T>()).or_default();
+                if buffers.len() < MAX_SCRATCH_BUFFERS_PER_TYPE {
+                    buffers.push(Box::new(buffer));
+                    arena.size_in_bytes = size_in_bytes;
+                }
+            }
+        });
+    }
+}
+
+/// Frees the buffers in the scratch arena of the current thread.
+pub fn clear_scratch_arena() {
+    let _ = SCRATCH_ARENA.try_with(|arena| *arena.borrow_mut() = ScratchArena::default());
+}
+
+/// Returns an empty buffer from the scratch arena of the current thread, or a new buffer if there is none.
+/// The largest free buffer is returned, as the arena is mostly used for buffers of a few recurring sizes.
+fn take_buffer<T: 'static>() -> Vec<T> {
+    SCRATCH_ARENA
+        .try_with(|arena| {
+            let mut arena = arena.try_borrow_mut().ok()?;
+            let buffers = arena.buffers.get_mut(&TypeId::of::<T>())?;
+            let (index, _) = buffers
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, buffer)| buffer.downcast_ref::<Vec<T>>().map_or(0, |buffer| buffer.capacity()))?;
+            let buffer = *buffers.swap_remove(index).downcast::<Vec<T>>().ok()?;
+            arena.size_in_bytes -= allocation_size_in_bytes(&buffer);
+            Some(buffer)
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scratch_vec_reuses_allocation() {
+        clear_scratch_arena();
+
+        let pointer = {
+            let scratch = ScratchVec::from_elem(7u64, 1024);
+            assert_eq!(1024, scratch.len());
+            assert!(scratch.iter().all(|elem| *elem == 7));
+            scratch.as_ptr()
+        };
+
+        // Ensure the next scratch vector of the same type reuses the allocation, and is initialized.
+        let scratch = ScratchVec::from_elem(3u64, 512);
+        assert_eq!(pointer, scratch.as_ptr());
+        assert_eq!(512, scratch.len());
+        assert!(scratch.iter().all(|elem| *elem == 3));
+
+        // Ensure a scratch vector of a different type does not reuse the allocation.
+        let other = ScratchVec::from_elem(3u32, 512);
+        assert_ne!(pointer as *const u8, other.as_ptr() as *const u8);
+    }
+
+    #[test]
+    fn test_scratch_vec_nested() {
+        clear_scratch_arena();
+
+        let mut outer = ScratchVec::from_elem(1u8, 16);
+        {
+            let mut inner = ScratchVec::from_elem(2u8, 16);
+            inner.as_mut_vec().push(3);
+            assert_eq!(17, inner.len());
+            outer.copy_from_slice(&inner[..16]);
+        }
+        assert!(outer.iter().all(|elem| *elem == 2));
+    }
+
+    #[test]
+    fn test_scratch_arena_is_bounded() {
+        clear_scratch_arena();
+
+        let scratches: Vec<_> = (0..2 * MAX_SCRATCH_BUFFERS_PER_TYPE)
+            .map(|_| ScratchVec::from_elem(0u16, 8))
+            .collect();
+        drop(scratches);
+
+        SCRATCH_ARENA.with(|arena| {
+            let arena = arena.borrow();
+            assert_eq!(MAX_SCRATCH_BUFFERS_PER_TYPE, arena.buffers[&TypeId::of::<u16>()].len());
+            assert!(arena.size_in_bytes >= MAX_SCRATCH_BUFFERS_PER_TYPE * 8 * 2);
+        });
+    }
+
+    #[test]
+    fn test_scratch_arena_size_is_bounded() {
+        clear_scratch_arena();
+
+        // Ensure a buffer that exceeds the size of the arena is freed, instead of kept.
+        drop(ScratchVec::from_elem(0u8, MAX_SCRATCH_ARENA_SIZE_IN_BYTES + 1));
+        SCRATCH_ARENA.with(|arena| {
+            let arena = arena.borrow();
+            assert!(!arena.buffers.contains_key(&TypeId::of::<u8>()));
+            assert_eq!(0, arena.size_in_bytes);
+        });
+
+        // Ensure the size of the arena is released when a buffer is taken from it.
+        drop(ScratchVec::from_elem(0u64, 1024));
+        SCRATCH_ARENA.with(|arena| assert!(arena.borrow().size_in_bytes >= 1024 * 8));
+        let _scratch = ScratchVec::from_elem(0u64, 1024);
+        SCRATCH_ARENA.with(|arena| assert_eq!(0, arena.borrow().size_in_bytes));
+    }
+}
@@ -24,8 +24,9 @@ pub use crate::{
     ToBytes,
     Vec,
 };
-use crate::{serialize::traits::*, SerializationError};
+use crate::{serialize::traits::*, vec_with_bounded_capacity, SerializationError, MAX_SIZE_ENCODED_BYTES};
 
+use bincode::Options;
 use std::{borrow::Cow, collections::BTreeMap, rc::Rc, sync::Arc};
 
 impl CanonicalSerialize for bool {
@@ -62,7 +63,12 @@ impl CanonicalSerialize for String {
 impl CanonicalDeserialize for String {
     #[inline]
     fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
-        Ok(bincode::deserialize_from(reader)?)
+        // The options of `bincode::deserialize_from`, with a limit on the length read from the stream.
+        Ok(bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(MAX_SIZE_ENCODED_BYTES as u64)
+            .deserialize_from(reader)?)
     }
 }
 
@@ -302,7 +308,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
     #[inline]
     fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         let len = u64::deserialize(reader)?;
-        let mut values = Vec::with_capacity(len as usize);
+        let mut values = vec_with_bounded_capacity(len as usize);
         for _ in 0..len {
             values.push(T::deserialize(reader)?);
         }
@@ -312,7 +318,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
     #[inline]
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         let len = u64::deserialize(reader)?;
-        let mut values = Vec::with_capacity(len as usize);
+        let mut values = vec_with_bounded_capacity(len as usize);
         for _ in 0..len {
             values.push(T::deserialize_uncompressed(reader)?);
         }